@@ -1,20 +1,82 @@
-use anyhow::{anyhow, Context, Result};
+use anyhow::{Context, Result};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::env;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::db::{AiCache, AiUsageLog};
+use crate::domains::{self, EmployerLinks};
+use crate::error::HuntError;
+use crate::flags::{self, Flag};
+use crate::location;
+use crate::models::{CompBand, JobKeyword, JobSummaryBullets, NegotiationAnchors, PrepQuestion};
+use crate::pay;
 
 // --- Provider trait ---
 
-pub trait AIProvider {
+/// Providers are shared across worker threads by `run_batch`.
+pub trait AIProvider: Send + Sync {
     fn complete(&self, prompt: &str, max_tokens: u32) -> Result<String>;
+
+    /// Like `complete`, plus token counts when the API reports them.
+    fn complete_with_usage(&self, prompt: &str, max_tokens: u32) -> Result<(String, Option<TokenUsage>)> {
+        Ok((self.complete(prompt, max_tokens)?, None))
+    }
+
+    /// Like `complete_with_usage`, handing text to `on_text` as it arrives. Providers
+    /// that can't stream deliver the whole response in one piece.
+    fn complete_streaming(
+        &self,
+        prompt: &str,
+        max_tokens: u32,
+        on_text: &mut dyn FnMut(&str),
+    ) -> Result<(String, Option<TokenUsage>)> {
+        let (text, usage) = self.complete_with_usage(prompt, max_tokens)?;
+        on_text(&text);
+        Ok((text, usage))
+    }
+
     #[allow(dead_code)]
     fn model_name(&self) -> &str;
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct TokenUsage {
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+}
+
+impl TokenUsage {
+    /// Cost in USD at (input, output) prices per million tokens.
+    pub fn cost(&self, price_per_mtok: (f64, f64)) -> f64 {
+        (self.input_tokens as f64 * price_per_mtok.0 + self.output_tokens as f64 * price_per_mtok.1) / 1_000_000.0
+    }
+}
+
 #[derive(Debug, Clone)]
 pub enum ProviderKind {
     Anthropic,
     OpenAI,
     ClaudeCode,
+    Ollama,
+    Mock,
+}
+
+impl ProviderKind {
+    /// Minimum spacing between request starts when running a batch in parallel,
+    /// keeping well under each API's default per-minute request limit.
+    pub fn min_request_interval(&self) -> Duration {
+        match self {
+            ProviderKind::Anthropic => Duration::from_millis(1200), // ~50 requests/min
+            ProviderKind::OpenAI => Duration::from_millis(500),     // ~120 requests/min
+            ProviderKind::ClaudeCode => Duration::from_millis(250), // local CLI processes
+            ProviderKind::Ollama => Duration::ZERO,                 // the local server queues requests itself
+            ProviderKind::Mock => Duration::ZERO,
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -24,6 +86,28 @@ pub struct ModelSpec {
     pub short_name: String,
 }
 
+impl ModelSpec {
+    /// List price in USD per million (input, output) tokens. None for the claude CLI,
+    /// which bills against the subscription; local Ollama models are free.
+    pub fn price_per_mtok(&self) -> Option<(f64, f64)> {
+        match self.provider {
+            ProviderKind::ClaudeCode => return None,
+            ProviderKind::Ollama | ProviderKind::Mock => return Some((0.0, 0.0)),
+            _ => {}
+        }
+        match self.model_id.as_str() {
+            "claude-sonnet-4-5-20250929" => Some((3.0, 15.0)),
+            "claude-opus-4-6" => Some((5.0, 25.0)),
+            "claude-haiku-4-5-20251001" => Some((1.0, 5.0)),
+            "gpt-5.2" => Some((1.75, 14.0)),
+            "gpt-5.2-pro" => Some((21.0, 168.0)),
+            "gpt-4o" => Some((2.5, 10.0)),
+            "o3" => Some((2.0, 8.0)),
+            _ => None,
+        }
+    }
+}
+
 pub fn resolve_model(name: &str) -> Result<ModelSpec> {
     match name {
         // Claude Code provider (uses `claude` CLI — no API key needed)
@@ -79,16 +163,38 @@ pub fn resolve_model(name: &str) -> Result<ModelSpec> {
             model_id: "o3".to_string(),
             short_name: "o3".to_string(),
         }),
-        _ => Err(anyhow!(
+        // Offline: replays responses from $HUNT_AI_FIXTURES (see MockProvider)
+        "mock" => Ok(ModelSpec {
+            provider: ProviderKind::Mock,
+            model_id: "mock".to_string(),
+            short_name: "mock".to_string(),
+        }),
+        // Local Ollama server, e.g. ollama:llama3.1 (no API key, no cost)
+        _ if name.starts_with("ollama:") => {
+            let model_id = name["ollama:".len()..].trim();
+            if model_id.is_empty() {
+                return Err(HuntError::Validation(
+                    "Missing Ollama model name. Use e.g. ollama:llama3.1".to_string()
+                ).into());
+            }
+            Ok(ModelSpec {
+                provider: ProviderKind::Ollama,
+                model_id: model_id.to_string(),
+                short_name: format!("ollama:{}", model_id),
+            })
+        }
+        _ => Err(HuntError::Validation(format!(
             "Unknown model '{}'. Available: claude-sonnet (default), claude-opus, claude-haiku, \
-             api-sonnet, api-opus, api-haiku, gpt-5.2, gpt-5.2-pro, gpt-4o, o3",
+             api-sonnet, api-opus, api-haiku, gpt-5.2, gpt-5.2-pro, gpt-4o, o3, ollama:<model>, mock",
             name
-        )),
+        )).into()),
     }
 }
 
+/// The provider for a model; with `HUNT_AI_RECORD` set, wrapped to save every response
+/// there as a fixture.
 pub fn create_provider(spec: &ModelSpec) -> Result<Box<dyn AIProvider>> {
-    match spec.provider {
+    let provider: Box<dyn AIProvider> = match spec.provider {
         ProviderKind::ClaudeCode => {
             // Pass short alias (e.g. "sonnet") to claude CLI — full model IDs route through API billing
             let cli_model = match spec.short_name.as_str() {
@@ -98,17 +204,421 @@ pub fn create_provider(spec: &ModelSpec) -> Result<Box<dyn AIProvider>> {
                 _ => &spec.short_name,
             };
             let provider = ClaudeCodeProvider::new(cli_model.to_string())?;
-            Ok(Box::new(provider))
+            Box::new(provider)
         }
         ProviderKind::Anthropic => {
             let provider = AnthropicProvider::new(spec.model_id.clone())?;
-            Ok(Box::new(provider))
+            Box::new(provider)
         }
         ProviderKind::OpenAI => {
             let provider = OpenAIProvider::new(spec.model_id.clone())?;
-            Ok(Box::new(provider))
+            Box::new(provider)
+        }
+        ProviderKind::Ollama => {
+            let provider = OllamaProvider::new(spec.model_id.clone())?;
+            Box::new(provider)
+        }
+        ProviderKind::Mock => Box::new(MockProvider::from_env()?),
+    };
+    Ok(match env::var_os(RECORD_ENV) {
+        Some(dir) => Box::new(RecordingProvider::new(provider, dir.into())),
+        None => provider,
+    })
+}
+
+// --- Response cache ---
+
+/// Cache key for a completion: sha256 over the model, token limit and prompt.
+pub fn cache_key(model: &str, prompt: &str, max_tokens: u32) -> String {
+    use sha2::{Digest, Sha256};
+    let mut hasher = Sha256::new();
+    hasher.update(model.as_bytes());
+    hasher.update([0]);
+    hasher.update(max_tokens.to_le_bytes());
+    hasher.update([0]);
+    hasher.update(prompt.as_bytes());
+    hasher.finalize().iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Serves repeated prompts (same job text, same model) from `ai_cache` instead of
+/// calling the provider again. Cache failures never fail the call.
+pub struct CachedProvider {
+    inner: Box<dyn AIProvider>,
+    cache: AiCache,
+    model: String,
+    refresh: bool,
+}
+
+impl CachedProvider {
+    pub fn new(inner: Box<dyn AIProvider>, cache: AiCache, model: &str) -> Self {
+        Self { inner, cache, model: model.to_string(), refresh: false }
+    }
+
+    /// Skip cache lookups but still store fresh responses (`--no-cache`).
+    pub fn refresh(mut self) -> Self {
+        self.refresh = true;
+        self
+    }
+
+    fn store(&self, key: &str, response: &str) {
+        if let Err(e) = self.cache.put(key, &self.model, response) {
+            tracing::warn!("Could not cache AI response: {}", e);
+        }
+    }
+}
+
+impl AIProvider for CachedProvider {
+    fn complete(&self, prompt: &str, max_tokens: u32) -> Result<String> {
+        let key = cache_key(&self.model, prompt, max_tokens);
+        if !self.refresh
+            && let Ok(Some(response)) = self.cache.get(&key)
+        {
+            return Ok(response);
+        }
+        let response = self.inner.complete(prompt, max_tokens)?;
+        self.store(&key, &response);
+        Ok(response)
+    }
+
+    fn complete_streaming(
+        &self,
+        prompt: &str,
+        max_tokens: u32,
+        on_text: &mut dyn FnMut(&str),
+    ) -> Result<(String, Option<TokenUsage>)> {
+        let key = cache_key(&self.model, prompt, max_tokens);
+        if !self.refresh
+            && let Ok(Some(response)) = self.cache.get(&key)
+        {
+            on_text(&response);
+            return Ok((response, None));
+        }
+        let (response, usage) = self.inner.complete_streaming(prompt, max_tokens, on_text)?;
+        self.store(&key, &response);
+        Ok((response, usage))
+    }
+
+    fn model_name(&self) -> &str {
+        self.inner.model_name()
+    }
+}
+
+// --- Usage tracking ---
+
+/// Records every call's tokens and estimated cost in `ai_usage`, attributed to
+/// `command`. Recording failures never fail the call.
+pub struct MeteredProvider {
+    inner: Box<dyn AIProvider>,
+    log: AiUsageLog,
+    command: String,
+    model: String,
+    price_per_mtok: Option<(f64, f64)>,
+}
+
+impl MeteredProvider {
+    pub fn new(inner: Box<dyn AIProvider>, log: AiUsageLog, spec: &ModelSpec, command: &str) -> Self {
+        Self {
+            inner,
+            log,
+            command: command.to_string(),
+            model: spec.short_name.clone(),
+            price_per_mtok: spec.price_per_mtok(),
+        }
+    }
+
+    fn record(&self, usage: Option<TokenUsage>) {
+        let cost = usage.zip(self.price_per_mtok).map(|(usage, price)| usage.cost(price));
+        let tokens = usage.map(|u| (u.input_tokens, u.output_tokens));
+        if let Err(e) = self.log.record(&self.command, &self.model, tokens, cost) {
+            tracing::warn!("Could not record AI usage: {}", e);
+        }
+    }
+}
+
+impl AIProvider for MeteredProvider {
+    fn complete(&self, prompt: &str, max_tokens: u32) -> Result<String> {
+        self.complete_with_usage(prompt, max_tokens).map(|(text, _)| text)
+    }
+
+    fn complete_with_usage(&self, prompt: &str, max_tokens: u32) -> Result<(String, Option<TokenUsage>)> {
+        let (text, usage) = self.inner.complete_with_usage(prompt, max_tokens)?;
+        self.record(usage);
+        Ok((text, usage))
+    }
+
+    fn complete_streaming(
+        &self,
+        prompt: &str,
+        max_tokens: u32,
+        on_text: &mut dyn FnMut(&str),
+    ) -> Result<(String, Option<TokenUsage>)> {
+        let (text, usage) = self.inner.complete_streaming(prompt, max_tokens, on_text)?;
+        self.record(usage);
+        Ok((text, usage))
+    }
+
+    fn model_name(&self) -> &str {
+        self.inner.model_name()
+    }
+}
+
+// --- Retries ---
+
+/// A non-success HTTP reply from a hosted provider API. Its own type (reported as an
+/// AI provider error) so `RetryingProvider` can tell rate limits and outages apart
+/// from requests that will never succeed.
+#[derive(Debug)]
+pub struct ApiStatusError {
+    pub provider: &'static str,
+    pub status: reqwest::StatusCode,
+    pub body: String,
+    pub retry_after: Option<Duration>,
+}
+
+impl ApiStatusError {
+    fn from_response(provider: &'static str, response: reqwest::blocking::Response) -> Self {
+        let retry_after = response
+            .headers()
+            .get(reqwest::header::RETRY_AFTER)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<u64>().ok())
+            .map(Duration::from_secs);
+        let status = response.status();
+        let body = response.text().unwrap_or_default();
+        Self { provider, status, body, retry_after }
+    }
+}
+
+impl std::fmt::Display for ApiStatusError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} API request failed with status {}: {}", self.provider, self.status, self.body)
+    }
+}
+
+impl std::error::Error for ApiStatusError {}
+
+/// Why a failed call is worth repeating (rate limit, server error, timeout, dropped
+/// connection) and how long the server asked us to wait, or None if it isn't.
+fn transient_failure(err: &anyhow::Error) -> Option<(String, Option<Duration>)> {
+    err.chain().find_map(|cause| {
+        if let Some(e) = cause.downcast_ref::<ApiStatusError>() {
+            let transient = e.status == reqwest::StatusCode::TOO_MANY_REQUESTS
+                || e.status == reqwest::StatusCode::REQUEST_TIMEOUT
+                || e.status.is_server_error();
+            transient.then(|| (format!("{} returned {}", e.provider, e.status), e.retry_after))
+        } else if let Some(e) = cause.downcast_ref::<reqwest::Error>() {
+            if e.is_timeout() {
+                Some(("request timed out".to_string(), None))
+            } else if e.is_connect() {
+                Some(("connection failed".to_string(), None))
+            } else {
+                None
+            }
+        } else {
+            None
+        }
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RetryPolicy {
+    pub max_attempts: u32, // including the first call
+    pub base_delay: Duration,
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self { max_attempts: 4, base_delay: Duration::from_secs(2), max_delay: Duration::from_secs(60) }
+    }
+}
+
+impl RetryPolicy {
+    /// The default policy, with `max_attempts` from `HUNT_AI_MAX_ATTEMPTS` if set.
+    pub fn from_env() -> Result<Self> {
+        let max_attempts = parse_max_attempts(env::var("HUNT_AI_MAX_ATTEMPTS").ok().as_deref())?;
+        Ok(Self { max_attempts, ..Self::default() })
+    }
+
+    /// Wait before retry number `retry` (1-based): `base_delay` doubled per retry up to
+    /// `max_delay`, scaled by `jitter` (0.0-1.0) into its upper half so parallel workers
+    /// spread out, and never shorter than a server's Retry-After.
+    pub fn delay(&self, retry: u32, retry_after: Option<Duration>, jitter: f64) -> Duration {
+        let backoff = self.base_delay.saturating_mul(2u32.saturating_pow(retry.saturating_sub(1))).min(self.max_delay);
+        let jittered = backoff.mul_f64(0.5 + 0.5 * jitter.clamp(0.0, 1.0));
+        retry_after.map_or(jittered, |after| jittered.max(after))
+    }
+}
+
+fn parse_max_attempts(value: Option<&str>) -> Result<u32> {
+    let Some(value) = value.map(str::trim).filter(|v| !v.is_empty()) else {
+        return Ok(RetryPolicy::default().max_attempts);
+    };
+    match value.parse::<u32>() {
+        Ok(n) if n >= 1 => Ok(n),
+        _ => Err(HuntError::Config(format!(
+            "Invalid HUNT_AI_MAX_ATTEMPTS '{}': expected a whole number of at least 1 (1 disables retries)",
+            value
+        )).into()),
+    }
+}
+
+/// Repeats calls that failed for transient reasons (429, 5xx, timeouts) with
+/// exponential backoff, so one overloaded minute doesn't end a long batch.
+pub struct RetryingProvider {
+    inner: Box<dyn AIProvider>,
+    policy: RetryPolicy,
+}
+
+impl RetryingProvider {
+    pub fn new(inner: Box<dyn AIProvider>, policy: RetryPolicy) -> Self {
+        Self { inner, policy }
+    }
+}
+
+impl AIProvider for RetryingProvider {
+    fn complete(&self, prompt: &str, max_tokens: u32) -> Result<String> {
+        self.complete_with_usage(prompt, max_tokens).map(|(text, _)| text)
+    }
+
+    fn complete_with_usage(&self, prompt: &str, max_tokens: u32) -> Result<(String, Option<TokenUsage>)> {
+        self.retry(|| self.inner.complete_with_usage(prompt, max_tokens), || true)
+    }
+
+    fn complete_streaming(
+        &self,
+        prompt: &str,
+        max_tokens: u32,
+        on_text: &mut dyn FnMut(&str),
+    ) -> Result<(String, Option<TokenUsage>)> {
+        // Once text has been shown, a retry would print it twice
+        let streamed = std::cell::Cell::new(false);
+        let mut on_text = |text: &str| {
+            streamed.set(true);
+            on_text(text);
+        };
+        self.retry(|| self.inner.complete_streaming(prompt, max_tokens, &mut on_text), || !streamed.get())
+    }
+
+    fn model_name(&self) -> &str {
+        self.inner.model_name()
+    }
+}
+
+impl RetryingProvider {
+    fn retry<T>(&self, mut call: impl FnMut() -> Result<T>, can_retry: impl Fn() -> bool) -> Result<T> {
+        let mut attempt = 1;
+        loop {
+            let err = match call() {
+                Ok(result) => return Ok(result),
+                Err(e) => e,
+            };
+            let Some((reason, retry_after)) =
+                transient_failure(&err).filter(|_| attempt < self.policy.max_attempts && can_retry())
+            else {
+                return Err(err);
+            };
+            let delay = self.policy.delay(attempt, retry_after, rand::random());
+            tracing::warn!(
+                "{} (attempt {}/{}), retrying in {:.1}s",
+                reason,
+                attempt,
+                self.policy.max_attempts,
+                delay.as_secs_f64()
+            );
+            std::thread::sleep(delay);
+            attempt += 1;
+        }
+    }
+}
+
+// --- Batch execution ---
+
+/// Spaces request starts at least `interval` apart across threads.
+pub struct RateLimiter {
+    interval: Duration,
+    next_slot: Mutex<Option<Instant>>,
+}
+
+impl RateLimiter {
+    pub fn new(interval: Duration) -> Self {
+        Self { interval, next_slot: Mutex::new(None) }
+    }
+
+    /// Block until this caller's slot comes up.
+    pub fn wait(&self) {
+        let start = {
+            let mut next_slot = self.next_slot.lock().unwrap_or_else(|e| e.into_inner());
+            let now = Instant::now();
+            let start = next_slot.map_or(now, |slot| slot.max(now));
+            *next_slot = Some(start + self.interval);
+            start
+        };
+        std::thread::sleep(start.saturating_duration_since(Instant::now()));
+    }
+}
+
+/// Run `task` over `items` on up to `concurrency` threads, starting at most one call
+/// per `min_interval`. `on_result` runs on the calling thread in input order, so
+/// progress output stays ordered and non-`Sync` state (the database) stays put.
+pub fn run_batch<T, R, F, G>(items: &[T], concurrency: usize, min_interval: Duration, task: F, mut on_result: G)
+where
+    T: Sync,
+    R: Send,
+    F: Fn(&T) -> R + Sync,
+    G: FnMut(usize, &T, R),
+{
+    let limiter = RateLimiter::new(min_interval);
+    let next_item = AtomicUsize::new(0);
+    let (tx, rx) = mpsc::channel::<(usize, R)>();
+
+    std::thread::scope(|scope| {
+        for _ in 0..concurrency.clamp(1, items.len().max(1)) {
+            let tx = tx.clone();
+            let (task, limiter, next_item) = (&task, &limiter, &next_item);
+            scope.spawn(move || loop {
+                let i = next_item.fetch_add(1, Ordering::SeqCst);
+                if i >= items.len() {
+                    break;
+                }
+                limiter.wait();
+                if tx.send((i, task(&items[i]))).is_err() {
+                    break;
+                }
+            });
+        }
+        drop(tx);
+
+        let mut pending: HashMap<usize, R> = HashMap::new();
+        let mut next_emit = 0;
+        for (i, result) in rx {
+            pending.insert(i, result);
+            while let Some(result) = pending.remove(&next_emit) {
+                on_result(next_emit, &items[next_emit], result);
+                next_emit += 1;
+            }
+        }
+    });
+}
+
+// --- Streaming ---
+
+/// Long generations (a full tailored resume) can take minutes to stream, well past
+/// the clients' whole-request timeout.
+const STREAM_TIMEOUT: Duration = Duration::from_secs(600);
+
+/// Pass each `data:` payload of a server-sent-events body to `on_data`, stopping at
+/// OpenAI's `[DONE]` marker.
+fn read_sse(body: impl std::io::BufRead, mut on_data: impl FnMut(&str) -> Result<()>) -> Result<()> {
+    for line in body.lines() {
+        let line = line.context("Failed to read streamed AI response")?;
+        let Some(data) = line.strip_prefix("data:").map(str::trim_start) else { continue };
+        if data == "[DONE]" {
+            break;
         }
+        on_data(data)?;
     }
+    Ok(())
 }
 
 // --- Anthropic provider ---
@@ -126,6 +636,8 @@ struct AnthropicRequest {
     model: String,
     max_tokens: u32,
     messages: Vec<AnthropicMessage>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    stream: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -136,9 +648,48 @@ struct AnthropicContentBlock {
     text: String,
 }
 
+#[derive(Debug, Deserialize)]
+struct AnthropicUsage {
+    input_tokens: i64,
+    output_tokens: i64,
+}
+
 #[derive(Debug, Deserialize)]
 struct AnthropicResponse {
     content: Vec<AnthropicContentBlock>,
+    usage: Option<AnthropicUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicStreamMessage {
+    usage: Option<AnthropicUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicStreamDelta {
+    text: Option<String>, // absent for non-text deltas
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicStreamUsage {
+    output_tokens: i64,
+}
+
+#[derive(Debug, Deserialize)]
+struct AnthropicStreamError {
+    message: String,
+}
+
+/// The server-sent events of a streamed Messages API response that we use.
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum AnthropicStreamEvent {
+    MessageStart { message: AnthropicStreamMessage },
+    ContentBlockDelta { delta: AnthropicStreamDelta },
+    MessageDelta { usage: Option<AnthropicStreamUsage> },
+    Error { error: AnthropicStreamError },
+    #[serde(other)]
+    Other,
 }
 
 #[derive(Debug)]
@@ -159,8 +710,8 @@ impl AnthropicProvider {
     }
 }
 
-impl AIProvider for AnthropicProvider {
-    fn complete(&self, prompt: &str, max_tokens: u32) -> Result<String> {
+impl AnthropicProvider {
+    fn send(&self, prompt: &str, max_tokens: u32, stream: bool) -> Result<reqwest::blocking::Response> {
         let request = AnthropicRequest {
             model: self.model_id.clone(),
             max_tokens,
@@ -168,37 +719,58 @@ impl AIProvider for AnthropicProvider {
                 role: "user".to_string(),
                 content: prompt.to_string(),
             }],
+            stream,
         };
 
-        let response = self
+        let mut builder = self
             .client
             .post(ANTHROPIC_API_URL)
             .header("x-api-key", &self.api_key)
             .header("anthropic-version", "2023-06-01")
             .header("content-type", "application/json")
-            .json(&request)
-            .send()
-            .context("Failed to send request to Anthropic API")?;
+            .json(&request);
+        if stream {
+            builder = builder.timeout(STREAM_TIMEOUT);
+        }
+        let response = builder.send().context("Failed to send request to Anthropic API")?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().unwrap_or_default();
-            return Err(anyhow!(
-                "Anthropic API request failed with status {}: {}",
-                status,
-                error_text
-            ));
+            return Err(ApiStatusError::from_response("Anthropic", response).into());
         }
+        Ok(response)
+    }
+}
 
+impl AIProvider for AnthropicProvider {
+    fn complete(&self, prompt: &str, max_tokens: u32) -> Result<String> {
+        self.complete_with_usage(prompt, max_tokens).map(|(text, _)| text)
+    }
+
+    fn complete_with_usage(&self, prompt: &str, max_tokens: u32) -> Result<(String, Option<TokenUsage>)> {
+        let response = self.send(prompt, max_tokens, false)?;
         let api_response: AnthropicResponse = response
             .json()
             .context("Failed to parse Anthropic API response")?;
 
-        api_response
+        let text = api_response
             .content
             .first()
             .map(|block| block.text.clone())
-            .ok_or_else(|| anyhow!("No content in Anthropic API response"))
+            .ok_or_else(|| HuntError::AiProvider("No content in Anthropic API response".to_string()))?;
+        let usage = api_response.usage.map(|u| TokenUsage {
+            input_tokens: u.input_tokens,
+            output_tokens: u.output_tokens,
+        });
+        Ok((text, usage))
+    }
+
+    fn complete_streaming(
+        &self,
+        prompt: &str,
+        max_tokens: u32,
+        on_text: &mut dyn FnMut(&str),
+    ) -> Result<(String, Option<TokenUsage>)> {
+        read_anthropic_stream(std::io::BufReader::new(self.send(prompt, max_tokens, true)?), on_text)
     }
 
     fn model_name(&self) -> &str {
@@ -206,6 +778,43 @@ impl AIProvider for AnthropicProvider {
     }
 }
 
+/// Collect a streamed Messages API response, passing text deltas to `on_text`.
+fn read_anthropic_stream(
+    body: impl std::io::BufRead,
+    on_text: &mut dyn FnMut(&str),
+) -> Result<(String, Option<TokenUsage>)> {
+    let mut text = String::new();
+    let mut usage: Option<TokenUsage> = None;
+    read_sse(body, |data| {
+        let event: AnthropicStreamEvent =
+            serde_json::from_str(data).context("Failed to parse Anthropic stream event")?;
+        match event {
+            AnthropicStreamEvent::MessageStart { message } => {
+                usage = message.usage.map(|u| TokenUsage { input_tokens: u.input_tokens, output_tokens: u.output_tokens });
+            }
+            AnthropicStreamEvent::ContentBlockDelta { delta: AnthropicStreamDelta { text: Some(chunk) } } => {
+                on_text(&chunk);
+                text.push_str(&chunk);
+            }
+            AnthropicStreamEvent::MessageDelta { usage: Some(delta) } => {
+                if let Some(usage) = usage.as_mut() {
+                    usage.output_tokens = delta.output_tokens;
+                }
+            }
+            AnthropicStreamEvent::Error { error } => {
+                return Err(HuntError::AiProvider(format!("Anthropic API stream failed: {}", error.message)).into());
+            }
+            _ => {}
+        }
+        Ok(())
+    })?;
+
+    if text.is_empty() {
+        return Err(HuntError::AiProvider("No content in Anthropic API response".to_string()).into());
+    }
+    Ok((text, usage))
+}
+
 // --- Claude Code provider (shells out to `claude` CLI) ---
 
 #[derive(Debug)]
@@ -261,14 +870,14 @@ impl AIProvider for ClaudeCodeProvider {
             } else {
                 format!("exit code: {}", output.status)
             };
-            return Err(anyhow!("claude CLI failed: {}", detail));
+            return Err(HuntError::AiProvider(format!("claude CLI failed: {}", detail)).into());
         }
 
         let response = String::from_utf8(output.stdout)
             .context("Invalid UTF-8 in claude CLI output")?;
 
         if response.trim().is_empty() {
-            return Err(anyhow!("Empty response from claude CLI"));
+            return Err(HuntError::AiProvider("Empty response from claude CLI".to_string()).into());
         }
 
         Ok(response)
@@ -289,11 +898,20 @@ struct OpenAIMessage {
     content: String,
 }
 
+#[derive(Debug, Serialize)]
+struct OpenAIStreamOptions {
+    include_usage: bool,
+}
+
 #[derive(Debug, Serialize)]
 struct OpenAIRequest {
     model: String,
     max_completion_tokens: u32,
     messages: Vec<OpenAIMessage>,
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    stream: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stream_options: Option<OpenAIStreamOptions>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -306,9 +924,35 @@ struct OpenAIChoice {
     message: OpenAIResponseMessage,
 }
 
+#[derive(Debug, Deserialize)]
+struct OpenAIUsage {
+    prompt_tokens: i64,
+    completion_tokens: i64,
+}
+
 #[derive(Debug, Deserialize)]
 struct OpenAIResponse {
     choices: Vec<OpenAIChoice>,
+    usage: Option<OpenAIUsage>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIStreamDelta {
+    content: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OpenAIStreamChoice {
+    delta: OpenAIStreamDelta,
+}
+
+/// One `chat.completion.chunk`; with `include_usage` the last one has no choices
+/// and carries the token counts.
+#[derive(Debug, Deserialize)]
+struct OpenAIStreamChunk {
+    #[serde(default)]
+    choices: Vec<OpenAIStreamChoice>,
+    usage: Option<OpenAIUsage>,
 }
 
 #[derive(Debug)]
@@ -329,8 +973,8 @@ impl OpenAIProvider {
     }
 }
 
-impl AIProvider for OpenAIProvider {
-    fn complete(&self, prompt: &str, max_tokens: u32) -> Result<String> {
+impl OpenAIProvider {
+    fn send(&self, prompt: &str, max_tokens: u32, stream: bool) -> Result<reqwest::blocking::Response> {
         let request = OpenAIRequest {
             model: self.model_id.clone(),
             max_completion_tokens: max_tokens,
@@ -338,36 +982,58 @@ impl AIProvider for OpenAIProvider {
                 role: "user".to_string(),
                 content: prompt.to_string(),
             }],
+            stream,
+            stream_options: stream.then_some(OpenAIStreamOptions { include_usage: true }),
         };
 
-        let response = self
+        let mut builder = self
             .client
             .post(OPENAI_API_URL)
             .header("Authorization", format!("Bearer {}", self.api_key))
             .header("Content-Type", "application/json")
-            .json(&request)
-            .send()
-            .context("Failed to send request to OpenAI API")?;
+            .json(&request);
+        if stream {
+            builder = builder.timeout(STREAM_TIMEOUT);
+        }
+        let response = builder.send().context("Failed to send request to OpenAI API")?;
 
         if !response.status().is_success() {
-            let status = response.status();
-            let error_text = response.text().unwrap_or_default();
-            return Err(anyhow!(
-                "OpenAI API request failed with status {}: {}",
-                status,
-                error_text
-            ));
+            return Err(ApiStatusError::from_response("OpenAI", response).into());
         }
+        Ok(response)
+    }
+}
+
+impl AIProvider for OpenAIProvider {
+    fn complete(&self, prompt: &str, max_tokens: u32) -> Result<String> {
+        self.complete_with_usage(prompt, max_tokens).map(|(text, _)| text)
+    }
 
+    fn complete_with_usage(&self, prompt: &str, max_tokens: u32) -> Result<(String, Option<TokenUsage>)> {
+        let response = self.send(prompt, max_tokens, false)?;
         let api_response: OpenAIResponse = response
             .json()
             .context("Failed to parse OpenAI API response")?;
 
-        api_response
+        let text = api_response
             .choices
             .first()
             .map(|choice| choice.message.content.clone())
-            .ok_or_else(|| anyhow!("No choices in OpenAI API response"))
+            .ok_or_else(|| HuntError::AiProvider("No choices in OpenAI API response".to_string()))?;
+        let usage = api_response.usage.map(|u| TokenUsage {
+            input_tokens: u.prompt_tokens,
+            output_tokens: u.completion_tokens,
+        });
+        Ok((text, usage))
+    }
+
+    fn complete_streaming(
+        &self,
+        prompt: &str,
+        max_tokens: u32,
+        on_text: &mut dyn FnMut(&str),
+    ) -> Result<(String, Option<TokenUsage>)> {
+        read_openai_stream(std::io::BufReader::new(self.send(prompt, max_tokens, true)?), on_text)
     }
 
     fn model_name(&self) -> &str {
@@ -375,38 +1041,345 @@ impl AIProvider for OpenAIProvider {
     }
 }
 
-// --- Standalone AI functions ---
+/// Collect a streamed chat completion, passing content deltas to `on_text`.
+fn read_openai_stream(
+    body: impl std::io::BufRead,
+    on_text: &mut dyn FnMut(&str),
+) -> Result<(String, Option<TokenUsage>)> {
+    let mut text = String::new();
+    let mut usage = None;
+    read_sse(body, |data| {
+        let chunk: OpenAIStreamChunk = serde_json::from_str(data).context("Failed to parse OpenAI stream chunk")?;
+        for content in chunk.choices.into_iter().filter_map(|c| c.delta.content) {
+            on_text(&content);
+            text.push_str(&content);
+        }
+        if let Some(u) = chunk.usage {
+            usage = Some(TokenUsage { input_tokens: u.prompt_tokens, output_tokens: u.completion_tokens });
+        }
+        Ok(())
+    })?;
 
-pub fn analyze_job(provider: &dyn AIProvider, job_text: &str) -> Result<String> {
-    let prompt = format!(
-        "Analyze this job posting and provide:\n\
-        1. Required skills and experience\n\
-        2. Nice-to-have qualifications\n\
-        3. Red flags or concerns\n\
-        4. Estimated seniority level\n\
-        5. Overall assessment (1-10 scale with brief reasoning)\n\n\
-        Job posting:\n{}",
-        job_text
-    );
-    provider.complete(&prompt, 4096)
+    if text.is_empty() {
+        return Err(HuntError::AiProvider("No choices in OpenAI API response".to_string()).into());
+    }
+    Ok((text, usage))
 }
 
-#[allow(dead_code)]
-pub fn extract_keywords(provider: &dyn AIProvider, job_text: &str) -> Result<Vec<String>> {
-    let prompt = format!(
-        "Analyze this job posting and extract key technical skills, technologies, and requirements. Return ONLY a comma-separated list of keywords, no explanations.\n\nJob posting:\n{}",
-        job_text
-    );
-
-    let response = provider.complete(&prompt, 4096)?;
+// --- Ollama provider (local server) ---
 
-    let keywords: Vec<String> = response
-        .split(',')
-        .map(|s| s.trim().to_string())
-        .filter(|s| !s.is_empty())
-        .collect();
+const OLLAMA_DEFAULT_HOST: &str = "http://localhost:11434";
 
-    Ok(keywords)
+#[derive(Debug, Serialize)]
+struct OllamaOptions {
+    num_predict: u32,
+}
+
+#[derive(Debug, Serialize)]
+struct OllamaRequest {
+    model: String,
+    prompt: String,
+    stream: bool,
+    options: OllamaOptions,
+}
+
+#[derive(Debug, Deserialize)]
+struct OllamaResponse {
+    response: String,
+    prompt_eval_count: Option<i64>,
+    eval_count: Option<i64>,
+}
+
+/// Base URL from `OLLAMA_HOST` (as the ollama CLI reads it), default localhost:11434.
+fn ollama_base_url(host: Option<&str>) -> String {
+    let host = host.map(str::trim).filter(|h| !h.is_empty()).unwrap_or(OLLAMA_DEFAULT_HOST);
+    let host = host.trim_end_matches('/');
+    if host.starts_with("http://") || host.starts_with("https://") {
+        host.to_string()
+    } else {
+        format!("http://{}", host)
+    }
+}
+
+#[derive(Debug)]
+pub struct OllamaProvider {
+    base_url: String,
+    model_id: String,
+    client: reqwest::blocking::Client,
+}
+
+impl OllamaProvider {
+    pub fn new(model_id: String) -> Result<Self> {
+        let base_url = ollama_base_url(env::var("OLLAMA_HOST").ok().as_deref());
+        // Local models can be slow on CPU; allow much longer than the hosted APIs
+        let client = reqwest::blocking::Client::builder()
+            .timeout(std::time::Duration::from_secs(600))
+            .build()?;
+        Ok(Self { base_url, model_id, client })
+    }
+}
+
+impl AIProvider for OllamaProvider {
+    fn complete(&self, prompt: &str, max_tokens: u32) -> Result<String> {
+        self.complete_with_usage(prompt, max_tokens).map(|(text, _)| text)
+    }
+
+    fn complete_with_usage(&self, prompt: &str, max_tokens: u32) -> Result<(String, Option<TokenUsage>)> {
+        let request = OllamaRequest {
+            model: self.model_id.clone(),
+            prompt: prompt.to_string(),
+            stream: false,
+            options: OllamaOptions { num_predict: max_tokens },
+        };
+
+        let url = format!("{}/api/generate", self.base_url);
+        let response = self.client.post(&url).json(&request).send().map_err(|e| {
+            HuntError::Network(format!(
+                "Could not reach Ollama at {} (is `ollama serve` running?): {}",
+                self.base_url, e
+            ))
+        })?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let error_text = response.text().unwrap_or_default();
+            return Err(HuntError::AiProvider(format!(
+                "Ollama request failed with status {}: {} (pull the model with `ollama pull {}`)",
+                status,
+                error_text,
+                self.model_id
+            )).into());
+        }
+
+        let api_response: OllamaResponse = response
+            .json()
+            .context("Failed to parse Ollama response")?;
+
+        if api_response.response.trim().is_empty() {
+            return Err(HuntError::AiProvider("Empty response from Ollama".to_string()).into());
+        }
+        let usage = match (api_response.prompt_eval_count, api_response.eval_count) {
+            (Some(input_tokens), Some(output_tokens)) => Some(TokenUsage { input_tokens, output_tokens }),
+            _ => None,
+        };
+        Ok((api_response.response, usage))
+    }
+
+    fn model_name(&self) -> &str {
+        &self.model_id
+    }
+}
+
+// --- Offline mock and record/replay ---
+
+/// Directory `--model mock` replays fixtures from.
+pub const FIXTURES_ENV: &str = "HUNT_AI_FIXTURES";
+/// Directory to save every provider response to as a fixture.
+pub const RECORD_ENV: &str = "HUNT_AI_RECORD";
+/// Served by `mock` for prompts without a fixture of their own.
+pub const FALLBACK_FIXTURE: &str = "fallback.txt";
+
+/// A prompt's fixture file: the response cache's hash without the model, so responses
+/// recorded from any model replay under `mock`.
+pub fn fixture_name(prompt: &str, max_tokens: u32) -> String {
+    format!("{}.txt", cache_key("", prompt, max_tokens))
+}
+
+enum MockResponses {
+    #[cfg(test)]
+    Fixed(String),
+    Fixtures(PathBuf),
+}
+
+/// Offline provider: `--model mock` answers from recorded fixtures (`fixture_name`,
+/// else `FALLBACK_FIXTURE`), so commands can be exercised without network or cost.
+/// Unit tests use a fixed response.
+pub struct MockProvider {
+    responses: MockResponses,
+}
+
+impl MockProvider {
+    #[cfg(test)]
+    pub fn new(response: &str) -> Self {
+        Self { responses: MockResponses::Fixed(response.to_string()) }
+    }
+
+    pub fn replay(dir: PathBuf) -> Self {
+        Self { responses: MockResponses::Fixtures(dir) }
+    }
+
+    pub fn from_env() -> Result<Self> {
+        match env::var_os(FIXTURES_ENV) {
+            Some(dir) => Ok(Self::replay(dir.into())),
+            None => Err(HuntError::Config(format!(
+                "--model mock replays recorded responses: set {} to a fixtures directory (record one with {}=<dir>)",
+                FIXTURES_ENV, RECORD_ENV
+            )).into()),
+        }
+    }
+}
+
+impl AIProvider for MockProvider {
+    fn complete(&self, prompt: &str, max_tokens: u32) -> Result<String> {
+        #[cfg(test)]
+        if let MockResponses::Fixed(response) = &self.responses {
+            return Ok(response.clone());
+        }
+        #[allow(irrefutable_let_patterns)]
+        let MockResponses::Fixtures(dir) = &self.responses else { unreachable!() };
+        let fixture = dir.join(fixture_name(prompt, max_tokens));
+        for path in [&fixture, &dir.join(FALLBACK_FIXTURE)] {
+            match std::fs::read_to_string(path) {
+                Ok(response) => return Ok(response),
+                Err(e) if e.kind() == std::io::ErrorKind::NotFound => continue,
+                Err(e) => return Err(e).with_context(|| format!("Failed to read fixture {}", path.display())),
+            }
+        }
+        Err(HuntError::AiProvider(format!(
+            "No fixture for this prompt: {} (record it with {}=<dir>, or add {} to answer everything)",
+            fixture.display(), RECORD_ENV, FALLBACK_FIXTURE
+        )).into())
+    }
+
+    fn model_name(&self) -> &str {
+        "mock"
+    }
+}
+
+/// Saves each response as a fixture `mock` can replay. Failing to save is only a warning.
+pub struct RecordingProvider {
+    inner: Box<dyn AIProvider>,
+    dir: PathBuf,
+}
+
+impl RecordingProvider {
+    pub fn new(inner: Box<dyn AIProvider>, dir: PathBuf) -> Self {
+        Self { inner, dir }
+    }
+
+    fn save(&self, prompt: &str, max_tokens: u32, response: &str) {
+        let path = self.dir.join(fixture_name(prompt, max_tokens));
+        let saved = std::fs::create_dir_all(&self.dir).and_then(|_| std::fs::write(&path, response));
+        if let Err(e) = saved {
+            tracing::warn!("Could not record AI response to {}: {}", path.display(), e);
+        }
+    }
+}
+
+impl AIProvider for RecordingProvider {
+    fn complete(&self, prompt: &str, max_tokens: u32) -> Result<String> {
+        self.complete_with_usage(prompt, max_tokens).map(|(text, _)| text)
+    }
+
+    fn complete_with_usage(&self, prompt: &str, max_tokens: u32) -> Result<(String, Option<TokenUsage>)> {
+        let (text, usage) = self.inner.complete_with_usage(prompt, max_tokens)?;
+        self.save(prompt, max_tokens, &text);
+        Ok((text, usage))
+    }
+
+    fn complete_streaming(
+        &self,
+        prompt: &str,
+        max_tokens: u32,
+        on_text: &mut dyn FnMut(&str),
+    ) -> Result<(String, Option<TokenUsage>)> {
+        let (text, usage) = self.inner.complete_streaming(prompt, max_tokens, on_text)?;
+        self.save(prompt, max_tokens, &text);
+        Ok((text, usage))
+    }
+
+    fn model_name(&self) -> &str {
+        self.inner.model_name()
+    }
+}
+
+// --- Standalone AI functions ---
+
+/// `complete`, or when `on_text` is given, stream the response to it as it arrives.
+fn complete_or_stream(
+    provider: &dyn AIProvider,
+    prompt: &str,
+    max_tokens: u32,
+    on_text: Option<&mut dyn FnMut(&str)>,
+) -> Result<String> {
+    match on_text {
+        Some(on_text) => provider.complete_streaming(prompt, max_tokens, on_text).map(|(text, _)| text),
+        None => provider.complete(prompt, max_tokens),
+    }
+}
+
+pub fn analyze_job(provider: &dyn AIProvider, job_text: &str, on_text: Option<&mut dyn FnMut(&str)>) -> Result<String> {
+    let prompt = format!(
+        "Analyze this job posting and provide:\n\
+        1. Required skills and experience\n\
+        2. Nice-to-have qualifications\n\
+        3. Red flags or concerns\n\
+        4. Estimated seniority level\n\
+        5. Overall assessment (1-10 scale with brief reasoning)\n\n\
+        Job posting:\n{}",
+        job_text
+    );
+    complete_or_stream(provider, &prompt, 4096, on_text)
+}
+
+/// Five one-line points on a posting, so it can be skimmed instead of read.
+pub fn summarize_job(provider: &dyn AIProvider, job_text: &str, title: &str) -> Result<JobSummaryBullets> {
+    let prompt = format!(
+        "Summarize this job posting for a candidate skimming many of them.\n\n\
+        Return EXACTLY these five lines:\n\
+        RESPONSIBILITIES: <what the person will actually do, one sentence>\n\
+        STACK: <main languages, platforms and tools, comma-separated>\n\
+        SENIORITY: <level and years of experience asked for>\n\
+        COMP: <pay, equity and benefits as stated, or \"Not stated\">\n\
+        RED_FLAGS: <concerns such as on-call load, vague scope, unrealistic asks, or \"None\">\n\n\
+        RULES:\n\
+        - Use only what the posting says; do not guess pay or stack\n\
+        - Each line under 30 words\n\n\
+        Job Title: {}\n\n\
+        Job Posting:\n{}",
+        title, job_text
+    );
+    let response = provider.complete(&prompt, 1024)?;
+    parse_job_summary(&response)
+        .ok_or_else(|| HuntError::AiProvider("AI response had no summary lines".to_string()).into())
+}
+
+fn parse_job_summary(response: &str) -> Option<JobSummaryBullets> {
+    let mut summary = JobSummaryBullets::default();
+    let mut found = false;
+    for line in response.lines() {
+        let line = line.trim().trim_start_matches(['-', '*', ' ']);
+        let Some((label, text)) = line.split_once(':') else { continue };
+        let field = match label.trim().to_uppercase().replace(' ', "_").as_str() {
+            "RESPONSIBILITIES" => &mut summary.responsibilities,
+            "STACK" => &mut summary.stack,
+            "SENIORITY" => &mut summary.seniority,
+            "COMP" => &mut summary.comp,
+            "RED_FLAGS" => &mut summary.red_flags,
+            _ => continue,
+        };
+        *field = text.trim().to_string();
+        found = true;
+    }
+    found.then_some(summary)
+}
+
+#[allow(dead_code)]
+pub fn extract_keywords(provider: &dyn AIProvider, job_text: &str) -> Result<Vec<String>> {
+    let prompt = format!(
+        "Analyze this job posting and extract key technical skills, technologies, and requirements. Return ONLY a comma-separated list of keywords, no explanations.\n\nJob posting:\n{}",
+        job_text
+    );
+
+    let response = provider.complete(&prompt, 4096)?;
+
+    let keywords: Vec<String> = response
+        .split(',')
+        .map(|s| s.trim().to_string())
+        .filter(|s| !s.is_empty())
+        .collect();
+
+    Ok(keywords)
 }
 
 pub struct DomainKeywords {
@@ -610,6 +1583,199 @@ pub fn analyze_fit(
     })
 }
 
+/// Likely interview questions for a job, aimed at its weighted keywords and at the gaps
+/// between it and the resume (from a fit analysis when there is one).
+pub fn generate_prep_questions(
+    provider: &dyn AIProvider,
+    resume: &str,
+    job_text: &str,
+    title: &str,
+    keywords: &[JobKeyword],
+    gaps: &[String],
+) -> Result<Vec<PrepQuestion>> {
+    let keywords = if keywords.is_empty() {
+        "(none extracted; read them from the posting)".to_string()
+    } else {
+        let mut keywords: Vec<&JobKeyword> = keywords.iter().collect();
+        keywords.sort_by_key(|k| std::cmp::Reverse(k.weight));
+        keywords.iter().map(|k| format!("{} ({})", k.keyword, "*".repeat(k.weight as usize))).collect::<Vec<_>>().join(", ")
+    };
+    let gaps = if gaps.is_empty() {
+        "(no fit analysis; compare the resume to the posting yourself)".to_string()
+    } else {
+        gaps.join(", ")
+    };
+    let prompt = format!(
+        "You are preparing a candidate for interviews for the job below. Write the questions \
+        interviewers are most likely to ask: technical ones on the job's key skills (*** = required), \
+        weighted towards the candidate's gaps, and behavioral ones drawn from the posting's \
+        responsibilities and the candidate's history.\n\n\
+        Return 6-8 technical and 4-5 behavioral questions, EXACTLY in this format:\n\
+        TECHNICAL: <question>\n\
+        PREP: <one line: what the interviewer is probing and what to review or which experience to use>\n\
+        BEHAVIORAL: <question>\n\
+        PREP: <one line>\n\n\
+        Job Title: {}\n\n\
+        Key skills: {}\n\n\
+        Resume gaps: {}\n\n\
+        Job Posting:\n{}\n\n\
+        Resume:\n{}",
+        title, keywords, gaps, job_text, resume
+    );
+    let response = provider.complete(&prompt, 4096)?;
+    let questions = parse_prep_questions(&response);
+    if questions.is_empty() {
+        return Err(HuntError::AiProvider("AI response had no TECHNICAL:/BEHAVIORAL: questions".to_string()).into());
+    }
+    Ok(questions)
+}
+
+fn parse_prep_questions(response: &str) -> Vec<PrepQuestion> {
+    let mut questions: Vec<PrepQuestion> = Vec::new();
+    for line in response.lines() {
+        let line = line.trim().trim_start_matches(['-', '*', ' ']);
+        let Some((label, text)) = line.split_once(':') else { continue };
+        let text = text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        match label.trim().to_uppercase().as_str() {
+            kind @ ("TECHNICAL" | "BEHAVIORAL") => questions.push(PrepQuestion {
+                kind: kind.to_lowercase(),
+                question: text.to_string(),
+                prep: None,
+            }),
+            "PREP" => {
+                if let Some(last) = questions.last_mut() {
+                    last.prep = Some(text.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+    questions
+}
+
+/// A question to ask the interviewer, and the research or posting detail behind it.
+pub struct InterviewerQuestion {
+    pub question: String,
+    pub basis: Option<String>,
+}
+
+/// Questions for the candidate to ask at interview, grounded in the employer research
+/// brief (see `interview::employer_brief`) as well as the posting.
+pub fn interviewer_questions(
+    provider: &dyn AIProvider,
+    job_text: Option<&str>,
+    title: &str,
+    employer: &str,
+    brief: &str,
+) -> Result<Vec<InterviewerQuestion>> {
+    let brief = if brief.is_empty() { "(no research stored)" } else { brief };
+    let prompt = format!(
+        "A candidate is interviewing for the job below. Write 6-8 thoughtful questions for them to \
+        ask the interviewer. Use the employer research: probe funding runway and plans, recurring \
+        complaints in employee reviews, controversies and ownership changes, tactfully and without \
+        accusing, so the answers tell the candidate whether those concerns apply to this team. \
+        Add a couple about the role itself from the posting. No generic questions a candidate \
+        could ask anywhere.\n\n\
+        Return EXACTLY in this format, one pair per question:\n\
+        QUESTION: <question as the candidate would say it>\n\
+        BASIS: <the research point or posting detail it follows up on>\n\n\
+        Job Title: {}\n\
+        Employer: {}\n\n\
+        Employer research:\n{}\n\n\
+        Job Posting:\n{}",
+        title, employer, brief, job_text.unwrap_or("(not fetched)")
+    );
+    let response = provider.complete(&prompt, 2048)?;
+    let questions = parse_interviewer_questions(&response);
+    if questions.is_empty() {
+        return Err(HuntError::AiProvider("AI response had no QUESTION: lines".to_string()).into());
+    }
+    Ok(questions)
+}
+
+fn parse_interviewer_questions(response: &str) -> Vec<InterviewerQuestion> {
+    let mut questions: Vec<InterviewerQuestion> = Vec::new();
+    for line in response.lines() {
+        let line = line.trim().trim_start_matches(['-', '*', ' ']);
+        let Some((label, text)) = line.split_once(':') else { continue };
+        let text = text.trim();
+        if text.is_empty() {
+            continue;
+        }
+        match label.trim().to_uppercase().as_str() {
+            "QUESTION" => questions.push(InterviewerQuestion { question: text.to_string(), basis: None }),
+            "BASIS" => {
+                if let Some(last) = questions.last_mut() {
+                    last.basis = Some(text.to_string());
+                }
+            }
+            _ => {}
+        }
+    }
+    questions
+}
+
+/// A negotiation brief from what's known about the offer side (see
+/// `interview::negotiation_facts`): suggested anchor, target and walk-away numbers and
+/// the reasoning and talking points behind them.
+pub fn negotiation_brief(
+    provider: &dyn AIProvider,
+    title: &str,
+    employer: &str,
+    facts: &str,
+) -> Result<(NegotiationAnchors, String)> {
+    let prompt = format!(
+        "Prepare a salary negotiation brief for a candidate with an offer expected for the job below. \
+        Weigh the posted range against the employer's comp bands for the level, use the fit score \
+        and strong matches as leverage, and read the employer's funding and employee sentiment for \
+        how much room there is. All numbers are annual base salary in USD.\n\n\
+        Return EXACTLY in this format:\n\
+        ANCHOR: $<the opening ask>\n\
+        TARGET: $<a good outcome to settle at>\n\
+        WALK_AWAY: $<the lowest base worth accepting>\n\
+        BRIEF:\n\
+        <markdown: why these numbers, 3-5 talking points, what to trade for if base is capped \
+        (equity, signing bonus, level, remote), and risks to watch>\n\n\
+        Job Title: {}\n\
+        Employer: {}\n\n\
+        What we know:\n{}",
+        title, employer, facts
+    );
+    let response = provider.complete(&prompt, 4096)?;
+    parse_negotiation_brief(&response)
+        .ok_or_else(|| HuntError::AiProvider("AI response had no ANCHOR:/TARGET:/BRIEF: sections".to_string()).into())
+}
+
+fn parse_negotiation_brief(response: &str) -> Option<(NegotiationAnchors, String)> {
+    let amount = |text: &str| pay::parse(text).and_then(|p| p.annual_usd().0);
+    let mut anchors = NegotiationAnchors::default();
+    let mut brief: Option<Vec<&str>> = None;
+    for line in response.lines() {
+        if let Some(brief) = brief.as_mut() {
+            brief.push(line);
+            continue;
+        }
+        let trimmed = line.trim();
+        if let Some(rest) = trimmed.strip_prefix("ANCHOR:") {
+            anchors.anchor = amount(rest);
+        } else if let Some(rest) = trimmed.strip_prefix("TARGET:") {
+            anchors.target = amount(rest);
+        } else if let Some(rest) = trimmed.strip_prefix("WALK_AWAY:") {
+            anchors.walk_away = amount(rest);
+        } else if let Some(rest) = trimmed.strip_prefix("BRIEF:") {
+            brief = Some(vec![rest]);
+        }
+    }
+    let brief = brief.map(|lines| lines.join("\n").trim().to_string()).unwrap_or_default();
+    if brief.is_empty() && anchors == NegotiationAnchors::default() {
+        return None;
+    }
+    Some((anchors, brief))
+}
+
 #[allow(dead_code)]
 pub fn tailor_resume_suggestions(
     provider: &dyn AIProvider,
@@ -640,6 +1806,7 @@ pub fn tailor_resume_full(
     title: &str,
     employer: Option<&str>,
     output_format: &str,
+    on_text: Option<&mut dyn FnMut(&str)>,
 ) -> Result<String> {
     let mut resume_sections = String::new();
     for (i, (name, content)) in all_resumes.iter().enumerate() {
@@ -660,109 +1827,765 @@ pub fn tailor_resume_full(
     };
 
     let prompt = format!(
-        "You are an expert resume writer. Generate a COMPLETE, TAILORED resume for the job below.\n\n\
-        IMPORTANT RULES:\n\
-        - Mine ALL provided resumes for relevant experience, skills, and achievements\n\
-        - Stay 100% truthful — only use facts from the provided resumes\n\
-        - Tailor language, emphasis, and ordering for this specific role\n\
-        - Include ALL relevant experience across all resumes — don't omit anything useful\n\
-        - {format_instruction}\n\n\
-        Job Title: {title}\n\
-        Employer: {employer_str}\n\n\
-        Job Posting:\n{job_text}\n\n\
-        {resume_sections}\n\
-        Generate the complete tailored resume now:",
+        "You are an expert resume writer. Generate a COMPLETE, TAILORED resume for the job below.\n\n\
+        IMPORTANT RULES:\n\
+        - Mine ALL provided resumes for relevant experience, skills, and achievements\n\
+        - Stay 100% truthful — only use facts from the provided resumes\n\
+        - Tailor language, emphasis, and ordering for this specific role\n\
+        - Include ALL relevant experience across all resumes — don't omit anything useful\n\
+        - {format_instruction}\n\n\
+        Job Title: {title}\n\
+        Employer: {employer_str}\n\n\
+        Job Posting:\n{job_text}\n\n\
+        {resume_sections}\n\
+        Generate the complete tailored resume now:",
+    );
+
+    complete_or_stream(provider, &prompt, 8192, on_text)
+}
+
+#[derive(Debug)]
+pub struct GlassdoorReviewData {
+    pub rating: f64,
+    pub title: String,
+    pub pros: String,
+    pub cons: String,
+    pub sentiment: String, // "positive", "negative", "neutral"
+    pub review_date: String,
+}
+
+#[derive(Debug)]
+pub struct GlassdoorResearch {
+    pub reviews: Vec<GlassdoorReviewData>,
+}
+
+pub fn research_glassdoor(
+    provider: &dyn AIProvider,
+    employer_name: &str,
+) -> Result<GlassdoorResearch> {
+    let prompt = format!(
+        "Research what employees say about working at \"{employer_name}\" on Glassdoor and similar \
+        review sites. Based on your knowledge, generate 5-8 representative employee reviews that \
+        reflect the actual reputation and common themes for this company.\n\n\
+        For EACH review, return a line in this EXACT format:\n\
+        REVIEW: <rating 1.0-5.0> | <sentiment: positive/negative/neutral> | <date YYYY-MM-DD> | <short title> | <pros> | <cons>\n\n\
+        RULES:\n\
+        - Ratings should reflect the company's actual Glassdoor reputation\n\
+        - Include a realistic mix of positive, negative, and neutral reviews\n\
+        - Pros and cons should be specific to this company, not generic\n\
+        - Dates should be recent (2025-2026)\n\
+        - Each field separated by \" | \" (space-pipe-space)\n\
+        - If you don't know anything about this company, return exactly: UNKNOWN\n\n\
+        Return ONLY REVIEW: lines (or UNKNOWN), nothing else."
+    );
+
+    let response = provider.complete(&prompt, 4096)?;
+
+    let trimmed = response.trim();
+    if trimmed == "UNKNOWN" || trimmed.is_empty() {
+        return Err(HuntError::NotFound(format!("No Glassdoor data available for '{}'", employer_name)).into());
+    }
+
+    let mut reviews = Vec::new();
+
+    for line in response.lines() {
+        let line = line.trim();
+        let Some(rest) = line.strip_prefix("REVIEW:") else { continue };
+        let parts: Vec<&str> = rest.split(" | ").map(|s| s.trim()).collect();
+        if parts.len() < 6 {
+            continue;
+        }
+
+        let rating = parts[0].parse::<f64>().unwrap_or(3.0).clamp(1.0, 5.0);
+        let sentiment = match parts[1] {
+            "positive" | "negative" | "neutral" => parts[1].to_string(),
+            _ => {
+                if rating >= 4.0 { "positive".to_string() }
+                else if rating <= 2.0 { "negative".to_string() }
+                else { "neutral".to_string() }
+            }
+        };
+        let review_date = parts[2].to_string();
+        let title = parts[3].to_string();
+        let pros = parts[4].to_string();
+        let cons = parts[5].to_string();
+
+        reviews.push(GlassdoorReviewData {
+            rating,
+            title,
+            pros,
+            cons,
+            sentiment,
+            review_date,
+        });
+    }
+
+    if reviews.is_empty() {
+        return Err(HuntError::AiProvider(format!("Could not parse Glassdoor reviews for '{}'", employer_name)).into());
+    }
+
+    Ok(GlassdoorResearch { reviews })
+}
+
+/// Typical compensation at an employer for `levels` (from `comp::LEVELS`), for roles like
+/// `roles` (the employer's open job titles), as Levels.fyi-style bands in annual USD.
+pub fn research_comp(
+    provider: &dyn AIProvider,
+    employer_name: &str,
+    levels: &[&str],
+    roles: &[String],
+) -> Result<Vec<CompBand>> {
+    let roles = if roles.is_empty() { "software/infrastructure engineering".to_string() } else { roles.join("; ") };
+    let prompt = format!(
+        "Estimate typical compensation at \"{employer_name}\" from what Levels.fyi, Glassdoor salary \
+        pages and similar sources report, for these levels: {levels}.\n\
+        Roles of interest: {roles}\n\n\
+        For EACH level, return a line in this EXACT format:\n\
+        BAND: <level, one of: {levels}> | <role> | <base low> | <base high> | <total comp low> | <total comp high>\n\n\
+        RULES:\n\
+        - Amounts are yearly US dollars written like $185,000\n\
+        - Total comp is base + stock + bonus per year\n\
+        - Use NONE for an amount you can't estimate\n\
+        - Each field separated by \" | \" (space-pipe-space)\n\
+        - If you don't know anything about this company's pay, return exactly: UNKNOWN\n\n\
+        Return ONLY BAND: lines (or UNKNOWN), nothing else.",
+        levels = levels.join(", "),
+    );
+
+    let response = provider.complete(&prompt, 2048)?;
+    if response.trim() == "UNKNOWN" || response.trim().is_empty() {
+        return Err(HuntError::NotFound(format!("No compensation data available for '{}'", employer_name)).into());
+    }
+
+    let usd = |text: &str| pay::parse(text).and_then(|p| p.annual_usd().0);
+    let bands: Vec<CompBand> = response
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("BAND:"))
+        .filter_map(|rest| {
+            let parts: Vec<&str> = rest.split(" | ").map(|p| p.trim()).collect();
+            if parts.len() < 6 || parts[0].is_empty() {
+                return None;
+            }
+            Some(CompBand {
+                level: parts[0].to_lowercase(),
+                role: parts[1].to_string(),
+                base_min: usd(parts[2]),
+                base_max: usd(parts[3]),
+                total_min: usd(parts[4]),
+                total_max: usd(parts[5]),
+            })
+        })
+        .filter(|band| band.base_min.is_some() || band.total_min.is_some())
+        .collect();
+
+    if bands.is_empty() {
+        return Err(HuntError::AiProvider(format!("Could not parse compensation bands for '{}'", employer_name)).into());
+    }
+    Ok(bands)
+}
+
+#[derive(Debug, Default)]
+pub struct EngineeringCultureResearch {
+    pub blog_url: Option<String>,
+    pub rss_url: Option<String>,
+    pub talks: Vec<String>, // "title | event | year | url"
+    pub summary: Option<String>,
+}
+
+pub fn research_engineering_culture(
+    provider: &dyn AIProvider,
+    employer_name: &str,
+) -> Result<EngineeringCultureResearch> {
+    let prompt = format!(
+        "Research the engineering culture at \"{employer_name}\" from public signals: their \
+        engineering blog, its RSS/Atom feed, and recent conference talks or meetup presentations \
+        given by their engineers.\n\n\
+        Return lines in this EXACT format:\n\
+        BLOG: <engineering blog URL, or NONE>\n\
+        RSS: <blog RSS/Atom feed URL, or NONE>\n\
+        TALK: <talk title> | <conference or event> | <year> | <URL or NONE>\n\
+        SUMMARY: <2-4 sentences on engineering culture signals: what they write and talk about, \
+        technical depth, open source involvement, how often they publish>\n\n\
+        RULES:\n\
+        - Up to 5 TALK: lines, most recent first (2023 or later preferred)\n\
+        - Only include URLs you are confident exist; use NONE otherwise\n\
+        - Each TALK field separated by \" | \" (space-pipe-space)\n\
+        - If you don't know anything about this company, return exactly: UNKNOWN\n\n\
+        Return ONLY these lines (or UNKNOWN), nothing else."
+    );
+
+    let response = provider.complete(&prompt, 2048)?;
+
+    let trimmed = response.trim();
+    if trimmed == "UNKNOWN" || trimmed.is_empty() {
+        return Err(HuntError::NotFound(format!("No engineering culture signals found for '{}'", employer_name)).into());
+    }
+
+    let parsed = parse_engineering_culture(&response);
+    if parsed.blog_url.is_none() && parsed.talks.is_empty() && parsed.summary.is_none() {
+        return Err(HuntError::AiProvider(format!("Could not parse engineering culture signals for '{}'", employer_name)).into());
+    }
+    Ok(parsed)
+}
+
+fn parse_engineering_culture(response: &str) -> EngineeringCultureResearch {
+    let url_or_none = |value: &str| {
+        let value = value.trim();
+        if value.is_empty() || value.eq_ignore_ascii_case("none") {
+            None
+        } else {
+            Some(value.to_string())
+        }
+    };
+
+    let mut research = EngineeringCultureResearch::default();
+    for line in response.lines() {
+        let line = line.trim();
+        if let Some(rest) = line.strip_prefix("BLOG:") {
+            research.blog_url = url_or_none(rest);
+        } else if let Some(rest) = line.strip_prefix("RSS:") {
+            research.rss_url = url_or_none(rest);
+        } else if let Some(rest) = line.strip_prefix("TALK:") {
+            let mut parts: Vec<&str> = rest.split(" | ").map(|s| s.trim()).collect();
+            while parts.last().is_some_and(|p| p.is_empty() || p.eq_ignore_ascii_case("none")) {
+                parts.pop();
+            }
+            if parts.first().is_none_or(|p| p.is_empty()) {
+                continue;
+            }
+            research.talks.push(parts.join(" | "));
+        } else if let Some(rest) = line.strip_prefix("SUMMARY:") {
+            let rest = rest.trim();
+            if !rest.is_empty() {
+                research.summary = Some(rest.to_string());
+            }
+        }
+    }
+    research
+}
+
+/// Ask for an employer's website domain and careers page. `job_urls` are postings we
+/// already have, to help tell apart companies with similar names.
+pub fn research_employer_links(
+    provider: &dyn AIProvider,
+    employer_name: &str,
+    job_urls: &[String],
+) -> Result<EmployerLinks> {
+    let hint = if job_urls.is_empty() {
+        String::new()
+    } else {
+        format!("\nSome of their job postings:\n{}\n", job_urls.iter().take(3).map(|u| format!("- {}", u)).collect::<Vec<_>>().join("\n"))
+    };
+    let prompt = format!(
+        "What is the official website domain and careers page of the company \"{employer_name}\"?\n{hint}\n\
+        Return lines in this EXACT format:\n\
+        DOMAIN: <bare domain like example.com, or NONE>\n\
+        CAREERS: <careers/jobs page URL, or NONE>\n\n\
+        RULES:\n\
+        - Only answer if you are confident it is this company; use NONE otherwise\n\
+        - If you don't know this company, return exactly: UNKNOWN\n\n\
+        Return ONLY these lines (or UNKNOWN), nothing else."
+    );
+
+    let response = provider.complete(&prompt, 256)?;
+    let trimmed = response.trim();
+    if trimmed == "UNKNOWN" || trimmed.is_empty() {
+        return Err(HuntError::NotFound(format!("No website found for '{}'", employer_name)).into());
+    }
+    Ok(parse_employer_links(&response))
+}
+
+fn parse_employer_links(response: &str) -> EmployerLinks {
+    let mut links = EmployerLinks::default();
+    for line in response.lines() {
+        let line = line.trim();
+        if let Some(rest) = line.strip_prefix("DOMAIN:") {
+            links.domain = domains::normalize_domain(rest);
+        } else if let Some(rest) = line.strip_prefix("CAREERS:") {
+            let rest = rest.trim();
+            if rest.starts_with("http://") || rest.starts_with("https://") {
+                links.careers_url = Some(rest.to_string());
+            }
+        }
+    }
+    links
+}
+
+#[derive(Debug, Clone)]
+pub struct FundingEvent {
+    pub kind: String, // "down_round", "layoff", "acquisition"
+    pub date: Option<String>,
+    pub summary: String,
+}
+
+#[derive(Debug, Default)]
+pub struct FundingResearch {
+    pub stage: Option<String>,
+    pub total_funding: Option<i64>,
+    pub last_round_date: Option<String>,
+    pub events: Vec<FundingEvent>,
+}
+
+pub fn research_funding(provider: &dyn AIProvider, employer_name: &str) -> Result<FundingResearch> {
+    let prompt = format!(
+        "What is the current funding situation of \"{employer_name}\"? Include the latest \
+        funding round and any down rounds, layoffs or acquisitions in the last 12 months.\n\n\
+        Return lines in this EXACT format:\n\
+        STAGE: <latest stage, e.g. Seed, Series A, Series C, Public, Bootstrapped, Acquired>\n\
+        TOTAL: <total raised in USD as a plain integer, or UNKNOWN>\n\
+        LAST_ROUND: <date of the most recent round as YYYY-MM, or NONE>\n\
+        EVENT: <down_round|layoff|acquisition> | <YYYY-MM> | <one-line description>\n\n\
+        RULES:\n\
+        - Zero or more EVENT: lines, most recent first, last 12 months only\n\
+        - Only report rounds and events you are confident happened\n\
+        - If you don't know anything about this company, return exactly: UNKNOWN\n\n\
+        Return ONLY these lines (or UNKNOWN), nothing else."
+    );
+
+    let response = provider.complete(&prompt, 1024)?;
+    let trimmed = response.trim();
+    if trimmed == "UNKNOWN" || trimmed.is_empty() {
+        return Err(HuntError::NotFound(format!("No funding information found for '{}'", employer_name)).into());
+    }
+    Ok(parse_funding(&response))
+}
+
+fn parse_funding(response: &str) -> FundingResearch {
+    let known = |value: &str| {
+        let value = value.trim();
+        if value.is_empty() || value.eq_ignore_ascii_case("none") || value.eq_ignore_ascii_case("unknown") {
+            None
+        } else {
+            Some(value.to_string())
+        }
+    };
+
+    let mut research = FundingResearch::default();
+    for line in response.lines() {
+        let line = line.trim();
+        if let Some(rest) = line.strip_prefix("STAGE:") {
+            research.stage = known(rest);
+        } else if let Some(rest) = line.strip_prefix("TOTAL:") {
+            research.total_funding = rest.trim().replace([',', '$'], "").parse().ok();
+        } else if let Some(rest) = line.strip_prefix("LAST_ROUND:") {
+            research.last_round_date = known(rest);
+        } else if let Some(rest) = line.strip_prefix("EVENT:") {
+            let parts: Vec<&str> = rest.splitn(3, '|').map(|p| p.trim()).collect();
+            let kind = parts[0].to_lowercase().replace([' ', '-'], "_");
+            if !["down_round", "layoff", "acquisition"].contains(&kind.as_str()) {
+                continue;
+            }
+            let summary = parts.get(2).copied().unwrap_or_default();
+            research.events.push(FundingEvent {
+                kind,
+                date: parts.get(1).and_then(|d| known(d)),
+                summary: if summary.is_empty() { parts[0].to_string() } else { summary.to_string() },
+            });
+        }
+    }
+    research
+}
+
+/// Pull a skill profile (technologies, platforms, practices) out of resume text.
+pub fn extract_skills(provider: &dyn AIProvider, resume_text: &str) -> Result<Vec<String>> {
+    let prompt = format!(
+        "List the concrete skills this resume demonstrates: languages, frameworks, tools, \
+        platforms, cloud services and engineering practices. Use short canonical names \
+        (e.g. \"kubernetes\", \"postgresql\", \"ci/cd\"). Skip soft skills and job titles.\n\n\
+        Return ONLY one line in this format:\n\
+        SKILLS: skill1, skill2, skill3\n\n\
+        Resume:\n{}",
+        resume_text
+    );
+
+    let response = provider.complete(&prompt, 1024)?;
+    let skills: Vec<String> = response
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("SKILLS:"))
+        .map(|list| {
+            list.split(',')
+                .map(|s| s.trim().to_string())
+                .filter(|s| !s.is_empty())
+                .collect()
+        })
+        .unwrap_or_default();
+    if skills.is_empty() {
+        return Err(HuntError::AiProvider("Could not parse skills from the AI response".to_string()).into());
+    }
+    Ok(skills)
+}
+
+/// Where a job is and how it's worked, as the AI read the posting.
+#[derive(Debug, Default, PartialEq)]
+pub struct JobLocation {
+    pub location: Option<String>,
+    pub work_mode: Option<&'static str>, // one of `location::WORK_MODES`
+}
+
+/// Location and remote/hybrid/onsite for postings the text heuristics couldn't place.
+pub fn extract_location(provider: &dyn AIProvider, job_text: &str) -> Result<JobLocation> {
+    let prompt = format!(
+        "Where is this job based, and is it remote, hybrid or onsite? Answer only from \
+        the posting; say unknown rather than guessing.\n\n\
+        Return ONLY two lines in this format:\n\
+        LOCATION: city, state/region or country (or unknown)\n\
+        WORK_MODE: remote, hybrid, onsite or unknown\n\n\
+        Job posting:\n{}",
+        job_text
+    );
+
+    let response = provider.complete(&prompt, 256)?;
+    let value = |prefix: &str| {
+        response.lines()
+            .find_map(|line| line.trim().strip_prefix(prefix))
+            .map(|v| v.trim().trim_matches('"').to_string())
+            .filter(|v| !v.is_empty() && !v.eq_ignore_ascii_case("unknown"))
+    };
+    Ok(JobLocation {
+        location: value("LOCATION:"),
+        work_mode: value("WORK_MODE:").and_then(|mode| location::parse_work_mode(&mode).ok()),
+    })
+}
+
+/// A job listed in an alert email, as the AI read it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertJob {
+    pub title: String,
+    pub employer: Option<String>,
+    pub url: Option<String>,
+    pub pay: Option<String>, // as advertised, e.g. "$150K - $180K/yr"
+}
+
+/// The jobs an alert email lists, for emails the HTML parsers found nothing in.
+pub fn extract_alert_jobs(provider: &dyn AIProvider, email_text: &str) -> Result<Vec<AlertJob>> {
+    let prompt = format!(
+        "This is a job alert email. List every job posting it advertises.\n\n\
+        Return one line per job in this EXACT format:\n\
+        JOB: <title> | <employer, or NONE> | <link to the posting, or NONE> | <pay as written, or NONE>\n\n\
+        RULES:\n\
+        - Only jobs the email actually lists; skip search links, ads and newsletter content\n\
+        - Copy links exactly from the email's link list; never make one up\n\
+        - If the email lists no jobs, return exactly: NONE\n\n\
+        Email:\n{}",
+        email_text
+    );
+
+    let response = provider.complete(&prompt, 2048)?;
+    Ok(parse_alert_jobs(&response))
+}
+
+fn parse_alert_jobs(response: &str) -> Vec<AlertJob> {
+    let optional = |value: Option<&&str>| {
+        value
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty() && !v.eq_ignore_ascii_case("none"))
+            .map(|v| v.to_string())
+    };
+    response
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("JOB:"))
+        .filter_map(|rest| {
+            let parts: Vec<&str> = rest.split('|').map(|p| p.trim()).collect();
+            let title = optional(parts.first())?;
+            Some(AlertJob {
+                title,
+                employer: optional(parts.get(1)),
+                url: optional(parts.get(2)),
+                pay: optional(parts.get(3)),
+            })
+        })
+        .collect()
+}
+
+/// Red flags in a posting the phrase rules can miss (`flags::detect`), from the same
+/// list of kinds.
+pub fn red_flags(provider: &dyn AIProvider, title: &str, job_text: &str) -> Result<Vec<Flag>> {
+    let kinds: Vec<String> = flags::KINDS.iter().map(|k| format!("- {}: {}", k.name, k.meaning)).collect();
+    let prompt = format!(
+        "Read this job posting the way a skeptical, experienced candidate would and list the \
+        red flags it shows, including ones only implied (\"comfortable with ambiguity and \
+        shifting priorities\", \"be there when production needs you\").\n\n\
+        Flag kinds:\n{}\n\n\
+        Return one line per red flag in this EXACT format:\n\
+        FLAG: <kind> | <short quote from the posting>\n\n\
+        RULES:\n\
+        - Only the kinds above; each kind at most once\n\
+        - Quote the posting's own words; never invent them\n\
+        - If there are no red flags, return exactly: NONE\n\n\
+        Job Title: {}\n\n\
+        Job Posting:\n{}",
+        kinds.join("\n"), title, job_text
+    );
+    let response = provider.complete(&prompt, 1024)?;
+    let found = parse_red_flags(&response);
+    if found.is_empty() && !response.trim().eq_ignore_ascii_case("none") {
+        return Err(HuntError::AiProvider("AI response had no FLAG: lines".to_string()).into());
+    }
+    Ok(found)
+}
+
+fn parse_red_flags(response: &str) -> Vec<Flag> {
+    let mut found: Vec<Flag> = Vec::new();
+    for rest in response.lines().filter_map(|line| line.trim().trim_start_matches(['-', '*', ' ']).strip_prefix("FLAG:")) {
+        let (name, quote) = rest.split_once('|').unwrap_or((rest, ""));
+        let name = name.trim().to_lowercase();
+        if flags::kind(&name).is_none() || found.iter().any(|f| f.name == name) {
+            continue;
+        }
+        let quote = quote.trim().trim_matches('"').trim();
+        found.push(Flag { name, evidence: (!quote.is_empty()).then(|| quote.to_string()) });
+    }
+    found
+}
+
+/// A short label for a group of similar jobs, e.g. "Staff SRE - AWS heavy".
+pub fn name_cluster(provider: &dyn AIProvider, titles: &[String], keywords: &[String]) -> Result<String> {
+    let prompt = format!(
+        "These job postings were grouped together because their requirements overlap. \
+        Give the group a short label (at most 6 words) naming the role and what sets it \
+        apart, e.g. \"Staff SRE - AWS heavy\" or \"Platform eng - k8s/Go\".\n\n\
+        Return ONLY one line in this format:\n\
+        NAME: label\n\n\
+        Titles:\n{}\n\n\
+        Top keywords: {}",
+        titles.iter().map(|t| format!("- {}", t)).collect::<Vec<_>>().join("\n"),
+        keywords.join(", ")
+    );
+
+    let response = provider.complete(&prompt, 256)?;
+    response
+        .lines()
+        .find_map(|line| line.trim().strip_prefix("NAME:"))
+        .map(|name| name.trim().trim_matches('"').to_string())
+        .filter(|name| !name.is_empty())
+        .ok_or_else(|| HuntError::AiProvider("Could not parse a cluster name from the AI response".to_string()).into())
+}
+
+/// A review question for a keyword, with its answer.
+#[derive(Debug, Clone)]
+pub struct ReviewQuestion {
+    pub question: String,
+    pub answer: String,
+}
+
+/// Quiz question on a keyword's meaning or practical use, pitched at interview level.
+/// `job_titles` are the roles asking for it, for context.
+pub fn review_question(provider: &dyn AIProvider, keyword: &str, job_titles: &[String]) -> Result<ReviewQuestion> {
+    let context = if job_titles.is_empty() {
+        String::new()
+    } else {
+        format!("\nRoles asking for it: {}\n", job_titles.join("; "))
+    };
+    let prompt = format!(
+        "I'm preparing for job interviews and don't know \"{}\" well yet.{}\n\
+        Ask me ONE question that checks I understand what it is or how it's used in practice, \
+        the kind an interviewer might ask. Vary the angle: definition, trade-offs, a concrete \
+        scenario, or a common pitfall. Then give a concise model answer (2-5 sentences).\n\n\
+        Return ONLY this format:\n\
+        QUESTION: the question\n\
+        ANSWER: the answer",
+        keyword, context
     );
 
-    provider.complete(&prompt, 8192)
+    let response = provider.complete(&prompt, 1024)?;
+    parse_review_question(&response)
+        .ok_or_else(|| HuntError::AiProvider("Could not parse a review question from the AI response".to_string()).into())
 }
 
-#[derive(Debug)]
-pub struct GlassdoorReviewData {
-    pub rating: f64,
-    pub title: String,
-    pub pros: String,
-    pub cons: String,
-    pub sentiment: String, // "positive", "negative", "neutral"
-    pub review_date: String,
+fn parse_review_question(response: &str) -> Option<ReviewQuestion> {
+    let q_start = response.find("QUESTION:")? + "QUESTION:".len();
+    let a_pos = response[q_start..].find("ANSWER:")? + q_start;
+    let question = response[q_start..a_pos].trim();
+    let answer = response[a_pos + "ANSWER:".len()..].trim();
+    if question.is_empty() || answer.is_empty() {
+        return None;
+    }
+    Some(ReviewQuestion { question: question.to_string(), answer: answer.to_string() })
 }
 
-#[derive(Debug)]
-pub struct GlassdoorResearch {
-    pub reviews: Vec<GlassdoorReviewData>,
+/// One step of an AI-generated learning plan, before it's stored.
+#[derive(Debug, Clone)]
+pub struct LearningStep {
+    pub week: String,
+    pub keyword: String,
+    pub task: String,
+    pub resource: Option<String>,
+    pub milestone: Option<String>,
 }
 
-pub fn research_glassdoor(
+/// Turn missing keywords (keyword, summed weight, job count) into a sequenced study plan.
+pub fn generate_learning_plan(
     provider: &dyn AIProvider,
-    employer_name: &str,
-) -> Result<GlassdoorResearch> {
+    gaps: &[(String, i64, i64)],
+    hours_per_week: u32,
+) -> Result<Vec<LearningStep>> {
+    let gap_list = gaps
+        .iter()
+        .map(|(keyword, weight, jobs)| format!("- {} (demand score {}, in {} job(s))", keyword, weight, jobs))
+        .collect::<Vec<_>>()
+        .join("\n");
     let prompt = format!(
-        "Research what employees say about working at \"{employer_name}\" on Glassdoor and similar \
-        review sites. Based on your knowledge, generate 5-8 representative employee reviews that \
-        reflect the actual reputation and common themes for this company.\n\n\
-        For EACH review, return a line in this EXACT format:\n\
-        REVIEW: <rating 1.0-5.0> | <sentiment: positive/negative/neutral> | <date YYYY-MM-DD> | <short title> | <pros> | <cons>\n\n\
+        "I'm job hunting and these skills show up in the roles I want but not on my resume. \
+        Build a sequenced learning plan for someone with {hours_per_week} hours per week, \
+        highest-demand skills first, with prerequisites before the things that build on them.\n\n\
+        Missing skills:\n{gap_list}\n\n\
+        Return one line per step in this EXACT format:\n\
+        STEP: <week or week range, e.g. 1 or 2-3> | <skill> | <concrete task> | <resource: course, book or URL, or NONE> | <milestone that proves progress, or NONE>\n\n\
         RULES:\n\
-        - Ratings should reflect the company's actual Glassdoor reputation\n\
-        - Include a realistic mix of positive, negative, and neutral reviews\n\
-        - Pros and cons should be specific to this company, not generic\n\
-        - Dates should be recent (2025-2026)\n\
-        - Each field separated by \" | \" (space-pipe-space)\n\
-        - If you don't know anything about this company, return exactly: UNKNOWN\n\n\
-        Return ONLY REVIEW: lines (or UNKNOWN), nothing else."
+        - 5 to 15 steps; each step should fit within the weekly hours\n\
+        - Prefer free, well-known resources; only give URLs you are confident exist\n\
+        - Milestones should be demonstrable (a small project, a certification, a blog post)\n\n\
+        Return ONLY STEP: lines, nothing else."
     );
 
-    let response = provider.complete(&prompt, 4096)?;
+    let response = provider.complete(&prompt, 2048)?;
+    let steps = parse_learning_plan(&response);
+    if steps.is_empty() {
+        return Err(HuntError::AiProvider("Could not parse a learning plan from the AI response".to_string()).into());
+    }
+    Ok(steps)
+}
+
+fn parse_learning_plan(response: &str) -> Vec<LearningStep> {
+    let optional = |value: Option<&&str>| {
+        value
+            .map(|v| v.trim())
+            .filter(|v| !v.is_empty() && !v.eq_ignore_ascii_case("none"))
+            .map(|v| v.to_string())
+    };
+    response
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("STEP:"))
+        .filter_map(|rest| {
+            let parts: Vec<&str> = rest.split('|').map(|p| p.trim()).collect();
+            if parts.len() < 3 || parts[1].is_empty() || parts[2].is_empty() {
+                return None;
+            }
+            Some(LearningStep {
+                week: parts[0].to_string(),
+                keyword: parts[1].to_string(),
+                task: parts[2].to_string(),
+                resource: optional(parts.get(3)),
+                milestone: optional(parts.get(4)),
+            })
+        })
+        .collect()
+}
 
+/// Rewrite a factual activity log as a short first-person journal narrative.
+pub fn polish_journal(provider: &dyn AIProvider, activity: &str) -> Result<String> {
+    let prompt = format!(
+        "Below is a log of my job-search activity for a period. Write it up as a short \
+        first-person journal entry (1-3 paragraphs) I could reread for motivation or use to \
+        answer \"what have you been doing?\". Mention concrete roles and companies, note \
+        progress through the pipeline, and end with a sentence on momentum or next steps.\n\n\
+        RULES:\n\
+        - Use ONLY facts from the log; do not invent interviews, companies or outcomes\n\
+        - No headings, bullet lists or preamble; plain paragraphs only\n\
+        - If the log shows no activity, say so briefly and honestly\n\n\
+        Activity log:\n{}",
+        activity
+    );
+    let response = provider.complete(&prompt, 1024)?;
     let trimmed = response.trim();
-    if trimmed == "UNKNOWN" || trimmed.is_empty() {
-        return Err(anyhow!("No Glassdoor data available for '{}'", employer_name));
+    if trimmed.is_empty() {
+        return Err(HuntError::AiProvider("Empty journal entry from AI provider".to_string()).into());
     }
+    Ok(trimmed.to_string())
+}
 
-    let mut reviews = Vec::new();
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-    for line in response.lines() {
-        let line = line.trim();
-        let Some(rest) = line.strip_prefix("REVIEW:") else { continue };
-        let parts: Vec<&str> = rest.split(" | ").map(|s| s.trim()).collect();
-        if parts.len() < 6 {
-            continue;
-        }
+    #[test]
+    fn test_parse_job_summary() {
+        let summary = parse_job_summary(
+            "Here you go:\n\
+             RESPONSIBILITIES: Run the Kubernetes platform\n\
+             - STACK: Kubernetes, Terraform, Go\n\
+             SENIORITY: Senior, 7+ years\n\
+             COMP: $180k-$220k + equity\n\
+             Red flags: 1-in-3 on-call",
+        )
+        .unwrap();
+        assert_eq!(summary.responsibilities, "Run the Kubernetes platform");
+        assert_eq!(summary.stack, "Kubernetes, Terraform, Go");
+        assert_eq!(summary.comp, "$180k-$220k + equity");
+        assert_eq!(summary.red_flags, "1-in-3 on-call");
+        assert!(parse_job_summary("I can't summarize this.").is_none());
+    }
 
-        let rating = parts[0].parse::<f64>().unwrap_or(3.0).clamp(1.0, 5.0);
-        let sentiment = match parts[1] {
-            "positive" | "negative" | "neutral" => parts[1].to_string(),
-            _ => {
-                if rating >= 4.0 { "positive".to_string() }
-                else if rating <= 2.0 { "negative".to_string() }
-                else { "neutral".to_string() }
-            }
-        };
-        let review_date = parts[2].to_string();
-        let title = parts[3].to_string();
-        let pros = parts[4].to_string();
-        let cons = parts[5].to_string();
+    #[test]
+    fn test_parse_prep_questions() {
+        let questions = parse_prep_questions(
+            "PREP: orphaned, ignored\n\
+             TECHNICAL: How would you roll out a Kubernetes upgrade with no downtime?\n\
+             PREP: Probing cluster ops; review surge upgrades and PodDisruptionBudgets\n\
+             - Behavioral: Tell me about an outage you led the response to.\n\
+             TECHNICAL: Explain Terraform state locking.\n",
+        );
+        assert_eq!(questions.len(), 3);
+        assert_eq!(questions[0].kind, "technical");
+        assert_eq!(questions[0].prep.as_deref(), Some("Probing cluster ops; review surge upgrades and PodDisruptionBudgets"));
+        assert_eq!(questions[1].kind, "behavioral");
+        assert_eq!(questions[1].question, "Tell me about an outage you led the response to.");
+        assert_eq!(questions[2].prep, None);
+        assert!(parse_prep_questions("Sorry, no.").is_empty());
+    }
 
-        reviews.push(GlassdoorReviewData {
-            rating,
-            title,
-            pros,
-            cons,
-            sentiment,
-            review_date,
-        });
+    #[test]
+    fn test_parse_interviewer_questions() {
+        let questions = parse_interviewer_questions(
+            "QUESTION: With the Series B closed in June, what does the next 18 months of hiring look like?\n\
+             BASIS: Funding: Series B, last round 2025-06\n\
+             QUESTION: How has the team handled reorgs?\n",
+        );
+        assert_eq!(questions.len(), 2);
+        assert_eq!(questions[0].basis.as_deref(), Some("Funding: Series B, last round 2025-06"));
+        assert_eq!(questions[1].question, "How has the team handled reorgs?");
+        assert!(questions[1].basis.is_none());
     }
 
-    if reviews.is_empty() {
-        return Err(anyhow!("Could not parse Glassdoor reviews for '{}'", employer_name));
+    #[test]
+    fn test_parse_red_flags() {
+        let found = parse_red_flags(
+            "FLAG: on-call | \"be there when production needs you\"\n\
+             - FLAG: Long-Hours | whatever it takes\n\
+             FLAG: on-call | pager\n\
+             FLAG: micromanagement | daily status reports\n\
+             FLAG: clearance\n",
+        );
+        let names: Vec<&str> = found.iter().map(|f| f.name.as_str()).collect();
+        assert_eq!(names, vec!["on-call", "long-hours", "clearance"], "known kinds, once each");
+        assert_eq!(found[0].evidence.as_deref(), Some("be there when production needs you"));
+        assert!(found[2].evidence.is_none());
+        assert!(parse_red_flags("NONE").is_empty());
     }
 
-    Ok(GlassdoorResearch { reviews })
-}
+    #[test]
+    fn test_parse_negotiation_brief() {
+        let (anchors, brief) = parse_negotiation_brief(
+            "ANCHOR: $235,000\n\
+             TARGET: $220k\n\
+             WALK_AWAY: unsure\n\
+             BRIEF:\n\
+             ## Why\n\
+             - Band tops out at $220k\n",
+        )
+        .unwrap();
+        assert_eq!(anchors, NegotiationAnchors { anchor: Some(235_000), target: Some(220_000), walk_away: None });
+        assert_eq!(brief, "## Why\n- Band tops out at $220k");
+        assert!(parse_negotiation_brief("I can't help with that.").is_none());
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    #[test]
+    fn test_parse_employer_links() {
+        let links = parse_employer_links("DOMAIN: https://www.Acme.com/\nCAREERS: https://acme.com/careers\n");
+        assert_eq!(links.domain.as_deref(), Some("acme.com"));
+        assert_eq!(links.careers_url.as_deref(), Some("https://acme.com/careers"));
+        assert_eq!(parse_employer_links("DOMAIN: NONE\nCAREERS: NONE"), EmployerLinks::default());
+    }
 
     #[test]
     fn test_resolve_model_claude_code() {
@@ -810,6 +2633,52 @@ mod tests {
         assert!(matches!(spec.provider, ProviderKind::OpenAI));
     }
 
+    #[test]
+    fn test_resolve_model_ollama() {
+        let spec = resolve_model("ollama:llama3.1:8b").unwrap();
+        assert!(matches!(spec.provider, ProviderKind::Ollama));
+        assert_eq!(spec.model_id, "llama3.1:8b");
+        assert_eq!(spec.short_name, "ollama:llama3.1:8b");
+        assert_eq!(spec.price_per_mtok(), Some((0.0, 0.0)));
+        assert!(resolve_model("ollama:").is_err());
+    }
+
+    #[test]
+    fn test_record_then_replay() {
+        let dir = std::env::temp_dir().join(format!("hunt-fixtures-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let mock = resolve_model("mock").unwrap();
+        assert!(matches!(mock.provider, ProviderKind::Mock));
+        assert_eq!(mock.price_per_mtok(), Some((0.0, 0.0)));
+
+        let recorder = RecordingProvider::new(Box::new(MockProvider::new("TECH: Rust/3")), dir.clone());
+        assert_eq!(recorder.complete("Extract keywords", 100).unwrap(), "TECH: Rust/3");
+        assert!(dir.join(fixture_name("Extract keywords", 100)).exists());
+
+        let replay = MockProvider::replay(dir.clone());
+        assert_eq!(replay.complete("Extract keywords", 100).unwrap(), "TECH: Rust/3");
+        let missing = replay.complete("Analyze fit", 100).unwrap_err().to_string();
+        assert!(missing.contains("No fixture"), "{}", missing);
+        assert!(replay.complete("Extract keywords", 200).is_err(), "the token limit is part of the key");
+
+        std::fs::write(dir.join(FALLBACK_FIXTURE), "SCORE: 80").unwrap();
+        assert_eq!(replay.complete("Analyze fit", 100).unwrap(), "SCORE: 80");
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_ollama_base_url_and_response() {
+        assert_eq!(ollama_base_url(None), "http://localhost:11434");
+        assert_eq!(ollama_base_url(Some("127.0.0.1:11434")), "http://127.0.0.1:11434");
+        assert_eq!(ollama_base_url(Some("https://gpu-box:11434/")), "https://gpu-box:11434");
+
+        let response: OllamaResponse = serde_json::from_str(
+            r#"{"model":"llama3.1","response":"KEYWORDS: go","done":true,"prompt_eval_count":40,"eval_count":5}"#,
+        ).unwrap();
+        assert_eq!(response.response, "KEYWORDS: go");
+        assert_eq!((response.prompt_eval_count, response.eval_count), (Some(40), Some(5)));
+    }
+
     #[test]
     fn test_resolve_model_unknown() {
         let result = resolve_model("gpt-3");
@@ -930,29 +2799,32 @@ mod tests {
         assert!(matches!(spec.provider, ProviderKind::OpenAI));
     }
 
-    // --- Mock AIProvider for testing parsing logic ---
-
-    struct MockProvider {
-        response: String,
-    }
-
-    impl MockProvider {
-        fn new(response: &str) -> Self {
-            Self { response: response.to_string() }
-        }
-    }
+    // --- Parsing logic, against MockProvider's fixed responses ---
 
-    impl AIProvider for MockProvider {
-        fn complete(&self, _prompt: &str, _max_tokens: u32) -> Result<String> {
-            Ok(self.response.clone())
-        }
-        fn model_name(&self) -> &str { "mock" }
+    #[test]
+    fn test_extract_alert_jobs() {
+        let provider = MockProvider::new(
+            "JOB: Staff SRE | Acme | https://acme.com/jobs/1 | $180K - $220K/yr\n\
+             JOB: Platform Engineer | NONE | NONE | NONE\n\
+             JOB:  | Nobody | NONE | NONE\n\
+             Some chatter",
+        );
+        let jobs = extract_alert_jobs(&provider, "email").unwrap();
+        assert_eq!(jobs.len(), 2, "a job needs a title");
+        assert_eq!(jobs[0], AlertJob {
+            title: "Staff SRE".to_string(),
+            employer: Some("Acme".to_string()),
+            url: Some("https://acme.com/jobs/1".to_string()),
+            pay: Some("$180K - $220K/yr".to_string()),
+        });
+        assert_eq!((jobs[1].employer.as_deref(), jobs[1].url.as_deref()), (None, None));
+        assert!(extract_alert_jobs(&MockProvider::new("NONE"), "email").unwrap().is_empty());
     }
 
     #[test]
     fn test_analyze_job_returns_response() {
         let provider = MockProvider::new("Analysis: This is a senior role requiring Kubernetes.");
-        let result = analyze_job(&provider, "Senior DevOps Engineer needed").unwrap();
+        let result = analyze_job(&provider, "Senior DevOps Engineer needed", None).unwrap();
         assert!(result.contains("senior role"));
     }
 
@@ -1097,7 +2969,7 @@ mod tests {
     fn test_tailor_resume_full_markdown() {
         let provider = MockProvider::new("# John Doe\n## Experience\n- DevOps at Acme");
         let resumes = vec![("main".to_string(), "John Doe resume content".to_string())];
-        let result = tailor_resume_full(&provider, &resumes, "job text", "DevOps", Some("Acme"), "markdown").unwrap();
+        let result = tailor_resume_full(&provider, &resumes, "job text", "DevOps", Some("Acme"), "markdown", None).unwrap();
         assert!(result.contains("John Doe"));
     }
 
@@ -1108,7 +2980,7 @@ mod tests {
             ("main".to_string(), "primary resume".to_string()),
             ("extra".to_string(), "secondary resume".to_string()),
         ];
-        let result = tailor_resume_full(&provider, &resumes, "job text", "DevOps", None, "latex").unwrap();
+        let result = tailor_resume_full(&provider, &resumes, "job text", "DevOps", None, "latex", None).unwrap();
         assert!(result.contains("\\documentclass"));
     }
 
@@ -1130,6 +3002,28 @@ mod tests {
         assert!((result.reviews[2].rating - 3.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_research_comp_parses_bands() {
+        let provider = MockProvider::new(
+            "BAND: Senior | Site Reliability Engineer | $180,000 | $220,000 | $260,000 | $340,000\n\
+             BAND: staff | SRE | $215k | $260k | NONE | NONE\n\
+             BAND: mid | SRE | NONE | NONE | NONE | NONE\n\
+             BAND: broken line",
+        );
+        let bands = research_comp(&provider, "Acme", &["senior", "staff", "mid"], &["Senior SRE".to_string()]).unwrap();
+        assert_eq!(bands.len(), 2, "bands need an amount");
+        assert_eq!(bands[0], CompBand {
+            level: "senior".to_string(),
+            role: "Site Reliability Engineer".to_string(),
+            base_min: Some(180_000),
+            base_max: Some(220_000),
+            total_min: Some(260_000),
+            total_max: Some(340_000),
+        });
+        assert_eq!((bands[1].base_min, bands[1].total_min), (Some(215_000), None));
+        assert!(research_comp(&MockProvider::new("UNKNOWN"), "Mystery", &["senior"], &[]).is_err());
+    }
+
     #[test]
     fn test_research_glassdoor_unknown() {
         let provider = MockProvider::new("UNKNOWN");
@@ -1179,4 +3073,414 @@ mod tests {
         let result = research_glassdoor(&provider, "Test Corp").unwrap();
         assert_eq!(result.reviews.len(), 1);
     }
+
+    #[test]
+    fn test_research_engineering_culture_parses_signals() {
+        let provider = MockProvider::new(
+            "BLOG: https://eng.example.com\n\
+             RSS: https://eng.example.com/feed.xml\n\
+             TALK: Scaling Postgres | KubeCon NA | 2024 | https://youtu.be/abc\n\
+             TALK: Chaos at Scale | SREcon | 2023 | NONE\n\
+             SUMMARY: Frequent deep technical posts on databases and reliability.",
+        );
+        let research = research_engineering_culture(&provider, "Example").unwrap();
+        assert_eq!(research.blog_url.as_deref(), Some("https://eng.example.com"));
+        assert_eq!(research.rss_url.as_deref(), Some("https://eng.example.com/feed.xml"));
+        assert_eq!(research.talks, vec![
+            "Scaling Postgres | KubeCon NA | 2024 | https://youtu.be/abc",
+            "Chaos at Scale | SREcon | 2023",
+        ]);
+        assert!(research.summary.unwrap().contains("reliability"));
+    }
+
+    #[test]
+    fn test_research_engineering_culture_none_urls() {
+        let provider = MockProvider::new("BLOG: NONE\nRSS: none\nSUMMARY: Little public engineering presence.");
+        let research = research_engineering_culture(&provider, "Quiet Co").unwrap();
+        assert!(research.blog_url.is_none());
+        assert!(research.rss_url.is_none());
+        assert!(research.talks.is_empty());
+        assert!(research.summary.is_some());
+    }
+
+    #[test]
+    fn test_research_engineering_culture_unknown() {
+        let provider = MockProvider::new("UNKNOWN");
+        assert!(research_engineering_culture(&provider, "Nobody Inc").is_err());
+    }
+
+    #[test]
+    fn test_polish_journal_trims_response() {
+        let provider = MockProvider::new("\n  This week I applied to Acme.  \n");
+        assert_eq!(polish_journal(&provider, "- applied: SRE @ Acme").unwrap(), "This week I applied to Acme.");
+        assert!(polish_journal(&MockProvider::new("   "), "").is_err());
+    }
+
+    #[test]
+    fn test_research_funding_parses_events() {
+        let provider = MockProvider::new(
+            "STAGE: Series C\nTOTAL: $120,000,000\nLAST_ROUND: 2026-02\n\
+             EVENT: layoff | 2026-05 | Cut 15% of staff\n\
+             EVENT: ipo | 2026-06 | Filed S-1\n\
+             EVENT: Down round | NONE | Raised at lower valuation",
+        );
+        let funding = research_funding(&provider, "Acme").unwrap();
+        assert_eq!(funding.stage.as_deref(), Some("Series C"));
+        assert_eq!(funding.total_funding, Some(120_000_000));
+        assert_eq!(funding.last_round_date.as_deref(), Some("2026-02"));
+        assert_eq!(funding.events.len(), 2);
+        assert_eq!(funding.events[0].kind, "layoff");
+        assert_eq!(funding.events[0].date.as_deref(), Some("2026-05"));
+        assert_eq!(funding.events[0].summary, "Cut 15% of staff");
+        assert_eq!(funding.events[1].kind, "down_round");
+        assert!(funding.events[1].date.is_none());
+    }
+
+    #[test]
+    fn test_research_funding_unknown() {
+        let provider = MockProvider::new("UNKNOWN");
+        assert!(research_funding(&provider, "Nobody Inc").is_err());
+        let funding = parse_funding("STAGE: UNKNOWN\nTOTAL: UNKNOWN\nLAST_ROUND: NONE");
+        assert!(funding.stage.is_none() && funding.total_funding.is_none() && funding.last_round_date.is_none());
+    }
+
+    #[test]
+    fn test_generate_learning_plan_parses_steps() {
+        let provider = MockProvider::new(
+            "Here is your plan:\n\
+             STEP: 1 | Rust | Work through chapters 1-8 | The Rust Book | NONE\n\
+             STEP: 2-3 | Kafka | Build a producer/consumer pair | NONE | Demo repo on GitHub\n\
+             STEP: 4 | | missing skill |",
+        );
+        let gaps = vec![("Rust".to_string(), 5, 2), ("Kafka".to_string(), 1, 1)];
+        let steps = generate_learning_plan(&provider, &gaps, 5).unwrap();
+        assert_eq!(steps.len(), 2);
+        assert_eq!(steps[0].week, "1");
+        assert_eq!(steps[0].resource.as_deref(), Some("The Rust Book"));
+        assert!(steps[0].milestone.is_none());
+        assert_eq!(steps[1].keyword, "Kafka");
+        assert!(steps[1].resource.is_none());
+        assert_eq!(steps[1].milestone.as_deref(), Some("Demo repo on GitHub"));
+
+        assert!(generate_learning_plan(&MockProvider::new("Sorry, no plan."), &gaps, 5).is_err());
+    }
+
+    #[test]
+    fn test_extract_skills() {
+        let provider = MockProvider::new("Sure.\nSKILLS: kubernetes, Terraform , ,aws");
+        let skills = extract_skills(&provider, "resume").unwrap();
+        assert_eq!(skills, vec!["kubernetes", "Terraform", "aws"]);
+        assert!(extract_skills(&MockProvider::new("no idea"), "resume").is_err());
+    }
+
+    #[test]
+    fn test_run_batch_reports_in_order() {
+        let items: Vec<u64> = (0..12).collect();
+        let mut seen = Vec::new();
+        run_batch(
+            &items,
+            4,
+            Duration::ZERO,
+            |n| {
+                // Later items finish first
+                std::thread::sleep(Duration::from_millis(12 - n));
+                n * 10
+            },
+            |i, item, result| seen.push((i, *item, result)),
+        );
+        let expected: Vec<(usize, u64, u64)> = (0..12).map(|n| (n as usize, n, n * 10)).collect();
+        assert_eq!(seen, expected);
+    }
+
+    #[test]
+    fn test_run_batch_shares_provider_across_threads() {
+        let provider = MockProvider::new("SKILLS: rust");
+        let items = vec!["a", "b", "c"];
+        let mut results = Vec::new();
+        run_batch(&items, 3, Duration::ZERO, |text| extract_skills(&provider, text), |_, _, r| {
+            results.push(r.unwrap());
+        });
+        assert_eq!(results, vec![vec!["rust"]; 3]);
+    }
+
+    #[test]
+    fn test_rate_limiter_spaces_starts() {
+        let limiter = RateLimiter::new(Duration::from_millis(20));
+        let start = Instant::now();
+        for _ in 0..4 {
+            limiter.wait();
+        }
+        // First call is immediate, the next three wait one interval each
+        assert!(start.elapsed() >= Duration::from_millis(60));
+    }
+
+    struct CountingProvider {
+        calls: AtomicUsize,
+    }
+
+    impl AIProvider for CountingProvider {
+        fn complete(&self, prompt: &str, _max_tokens: u32) -> Result<String> {
+            let n = self.calls.fetch_add(1, Ordering::SeqCst) + 1;
+            Ok(format!("{} #{}", prompt, n))
+        }
+        fn model_name(&self) -> &str { "counting" }
+    }
+
+    #[test]
+    fn test_review_question_parsing() {
+        let provider = MockProvider::new(
+            "QUESTION: What problem does a Kafka consumer group solve?\nANSWER: It spreads partitions\nacross consumers.\n",
+        );
+        let q = review_question(&provider, "kafka", &["SRE".to_string()]).unwrap();
+        assert_eq!(q.question, "What problem does a Kafka consumer group solve?");
+        assert_eq!(q.answer, "It spreads partitions\nacross consumers.");
+
+        assert!(parse_review_question("ANSWER: x\nQUESTION: y").is_none());
+        assert!(review_question(&MockProvider::new("QUESTION: only"), "kafka", &[]).is_err());
+    }
+
+    #[test]
+    fn test_extract_location() {
+        let provider = MockProvider::new("LOCATION: Austin, TX\nWORK_MODE: On-site\n");
+        let found = extract_location(&provider, "posting").unwrap();
+        assert_eq!(found, JobLocation { location: Some("Austin, TX".to_string()), work_mode: Some("onsite") });
+
+        let found = extract_location(&MockProvider::new("LOCATION: unknown\nWORK_MODE: sometimes"), "posting").unwrap();
+        assert_eq!(found, JobLocation::default());
+    }
+
+    #[test]
+    fn test_name_cluster() {
+        let provider = MockProvider::new("Sure!\nNAME: \"Platform eng - k8s/Go\"\n");
+        let name = name_cluster(&provider, &["Platform Engineer".to_string()], &["kubernetes".to_string()]).unwrap();
+        assert_eq!(name, "Platform eng - k8s/Go");
+        assert!(name_cluster(&MockProvider::new("no idea"), &[], &[]).is_err());
+    }
+
+    #[test]
+    fn test_cache_key_varies_by_input() {
+        let key = cache_key("gpt-5.2", "prompt", 1024);
+        assert_eq!(key.len(), 64);
+        assert_eq!(key, cache_key("gpt-5.2", "prompt", 1024));
+        assert_ne!(key, cache_key("claude-sonnet", "prompt", 1024));
+        assert_ne!(key, cache_key("gpt-5.2", "prompt", 2048));
+        assert_ne!(key, cache_key("gpt-5.2", "prompt!", 1024));
+    }
+
+    struct UsageProvider;
+
+    impl AIProvider for UsageProvider {
+        fn complete(&self, prompt: &str, max_tokens: u32) -> Result<String> {
+            self.complete_with_usage(prompt, max_tokens).map(|(text, _)| text)
+        }
+        fn complete_with_usage(&self, _prompt: &str, _max_tokens: u32) -> Result<(String, Option<TokenUsage>)> {
+            Ok(("ok".to_string(), Some(TokenUsage { input_tokens: 2_000_000, output_tokens: 100_000 })))
+        }
+        fn model_name(&self) -> &str { "usage" }
+    }
+
+    #[test]
+    fn test_token_usage_cost_and_pricing() {
+        let usage = TokenUsage { input_tokens: 2_000_000, output_tokens: 100_000 };
+        let price = resolve_model("api-sonnet").unwrap().price_per_mtok().unwrap();
+        assert!((usage.cost(price) - 7.5).abs() < 1e-9);
+        assert!(resolve_model("claude-sonnet").unwrap().price_per_mtok().is_none(), "subscription");
+        assert!(resolve_model("gpt-5.2").unwrap().price_per_mtok().is_some());
+    }
+
+    #[test]
+    fn test_metered_provider_passes_usage_through() {
+        let log = AiUsageLog::open(std::path::Path::new(":memory:")).unwrap();
+        let spec = resolve_model("gpt-5.2").unwrap();
+        let provider = MeteredProvider::new(Box::new(UsageProvider), log, &spec, "fit");
+        let (text, usage) = provider.complete_with_usage("p", 10).unwrap();
+        assert_eq!(text, "ok");
+        assert_eq!(usage.map(|u| u.input_tokens), Some(2_000_000));
+
+        let log = AiUsageLog::open(std::path::Path::new(":memory:")).unwrap();
+        let provider = MeteredProvider::new(Box::new(MockProvider::new("plain")), log, &spec, "fit");
+        assert_eq!(provider.complete("p", 10).unwrap(), "plain");
+    }
+
+    #[test]
+    fn test_api_responses_parse_usage() {
+        let anthropic: AnthropicResponse = serde_json::from_str(
+            r#"{"content":[{"type":"text","text":"hi"}],"usage":{"input_tokens":12,"output_tokens":3}}"#,
+        ).unwrap();
+        assert_eq!(anthropic.usage.map(|u| (u.input_tokens, u.output_tokens)), Some((12, 3)));
+
+        let openai: OpenAIResponse = serde_json::from_str(
+            r#"{"choices":[{"message":{"content":"hi"}}],"usage":{"prompt_tokens":12,"completion_tokens":3,"total_tokens":15}}"#,
+        ).unwrap();
+        assert_eq!(openai.usage.map(|u| (u.prompt_tokens, u.completion_tokens)), Some((12, 3)));
+        let openai: OpenAIResponse = serde_json::from_str(r#"{"choices":[]}"#).unwrap();
+        assert!(openai.usage.is_none());
+    }
+
+    #[test]
+    fn test_cached_provider_reuses_responses() {
+        let cache = AiCache::open(std::path::Path::new(":memory:")).unwrap();
+        let provider = CachedProvider::new(Box::new(CountingProvider { calls: AtomicUsize::new(0) }), cache, "m");
+        assert_eq!(provider.complete("a", 100).unwrap(), "a #1");
+        assert_eq!(provider.complete("a", 100).unwrap(), "a #1", "served from cache");
+        assert_eq!(provider.complete("b", 100).unwrap(), "b #2");
+        assert_eq!(provider.complete("a", 200).unwrap(), "a #3", "token limit is part of the key");
+
+        let cache = AiCache::open(std::path::Path::new(":memory:")).unwrap();
+        let provider = CachedProvider::new(Box::new(CountingProvider { calls: AtomicUsize::new(0) }), cache, "m").refresh();
+        assert_eq!(provider.complete("a", 100).unwrap(), "a #1");
+        assert_eq!(provider.complete("a", 100).unwrap(), "a #2", "refresh always calls the provider");
+    }
+
+    struct FlakyProvider {
+        failures: Vec<Option<reqwest::StatusCode>>, // None = succeed
+        calls: AtomicUsize,
+    }
+
+    impl AIProvider for FlakyProvider {
+        fn complete(&self, _prompt: &str, _max_tokens: u32) -> Result<String> {
+            let n = self.calls.fetch_add(1, Ordering::SeqCst);
+            match self.failures.get(n).copied().flatten() {
+                Some(status) => Err(ApiStatusError { provider: "Test", status, body: String::new(), retry_after: None }.into()),
+                None => Ok(format!("ok after {}", n + 1)),
+            }
+        }
+        fn model_name(&self) -> &str { "flaky" }
+    }
+
+    fn instant_retries(max_attempts: u32) -> RetryPolicy {
+        RetryPolicy { max_attempts, base_delay: Duration::ZERO, max_delay: Duration::ZERO }
+    }
+
+    #[test]
+    fn test_retrying_provider_retries_transient_errors() {
+        use reqwest::StatusCode;
+        let flaky = FlakyProvider {
+            failures: vec![Some(StatusCode::TOO_MANY_REQUESTS), Some(StatusCode::BAD_GATEWAY), None],
+            calls: AtomicUsize::new(0),
+        };
+        let provider = RetryingProvider::new(Box::new(flaky), instant_retries(4));
+        assert_eq!(provider.complete("p", 10).unwrap(), "ok after 3");
+
+        let flaky = FlakyProvider { failures: vec![Some(StatusCode::SERVICE_UNAVAILABLE); 5], calls: AtomicUsize::new(0) };
+        let provider = RetryingProvider::new(Box::new(flaky), instant_retries(3));
+        let err = provider.complete("p", 10).unwrap_err();
+        assert!(err.to_string().contains("503"), "{}", err);
+        assert_eq!(crate::error::report(&err).kind, "ai_provider");
+    }
+
+    #[test]
+    fn test_retrying_provider_gives_up_on_permanent_errors() {
+        let flaky = FlakyProvider { failures: vec![Some(reqwest::StatusCode::BAD_REQUEST), None], calls: AtomicUsize::new(0) };
+        let provider = RetryingProvider::new(Box::new(flaky), instant_retries(4));
+        assert!(provider.complete("p", 10).is_err());
+        assert!(transient_failure(&anyhow::anyhow!("Could not parse skills")).is_none());
+    }
+
+    #[test]
+    fn test_retry_delay_backs_off_with_jitter() {
+        let policy = RetryPolicy::default();
+        assert_eq!(policy.delay(1, None, 1.0), Duration::from_secs(2));
+        assert_eq!(policy.delay(3, None, 1.0), Duration::from_secs(8));
+        assert_eq!(policy.delay(3, None, 0.0), Duration::from_secs(4));
+        assert_eq!(policy.delay(20, None, 1.0), Duration::from_secs(60), "capped");
+        assert_eq!(policy.delay(1, Some(Duration::from_secs(30)), 0.5), Duration::from_secs(30), "Retry-After wins");
+    }
+
+    #[test]
+    fn test_parse_max_attempts() {
+        assert_eq!(parse_max_attempts(None).unwrap(), 4);
+        assert_eq!(parse_max_attempts(Some(" 6 ")).unwrap(), 6);
+        assert_eq!(parse_max_attempts(Some("1")).unwrap(), 1);
+        assert!(parse_max_attempts(Some("0")).is_err());
+        assert!(parse_max_attempts(Some("lots")).is_err());
+    }
+
+    #[test]
+    fn test_read_anthropic_stream() {
+        let body = "event: message_start\n\
+            data: {\"type\":\"message_start\",\"message\":{\"id\":\"m\",\"usage\":{\"input_tokens\":25,\"output_tokens\":1}}}\n\n\
+            event: content_block_delta\n\
+            data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\"Hello\"}}\n\n\
+            event: ping\n\
+            data: {\"type\": \"ping\"}\n\n\
+            data: {\"type\":\"content_block_delta\",\"index\":0,\"delta\":{\"type\":\"text_delta\",\"text\":\" world\"}}\n\n\
+            data: {\"type\":\"message_delta\",\"delta\":{\"stop_reason\":\"end_turn\"},\"usage\":{\"output_tokens\":15}}\n\n\
+            data: {\"type\":\"message_stop\"}\n";
+        let mut chunks = Vec::new();
+        let (text, usage) = read_anthropic_stream(body.as_bytes(), &mut |t| chunks.push(t.to_string())).unwrap();
+        assert_eq!(text, "Hello world");
+        assert_eq!(chunks, vec!["Hello", " world"]);
+        assert_eq!(usage, Some(TokenUsage { input_tokens: 25, output_tokens: 15 }));
+
+        let overloaded = "data: {\"type\":\"error\",\"error\":{\"type\":\"overloaded_error\",\"message\":\"Overloaded\"}}\n";
+        let err = read_anthropic_stream(overloaded.as_bytes(), &mut |_| {}).unwrap_err();
+        assert!(err.to_string().contains("Overloaded"));
+    }
+
+    #[test]
+    fn test_read_openai_stream() {
+        let body = "data: {\"choices\":[{\"index\":0,\"delta\":{\"role\":\"assistant\",\"content\":\"\"}}]}\n\n\
+            data: {\"choices\":[{\"index\":0,\"delta\":{\"content\":\"Hi\"}}]}\n\n\
+            data: {\"choices\":[{\"index\":0,\"delta\":{},\"finish_reason\":\"stop\"}]}\n\n\
+            data: {\"choices\":[],\"usage\":{\"prompt_tokens\":9,\"completion_tokens\":2,\"total_tokens\":11}}\n\n\
+            data: [DONE]\n";
+        let mut chunks = Vec::new();
+        let (text, usage) = read_openai_stream(body.as_bytes(), &mut |t| chunks.push(t.to_string())).unwrap();
+        assert_eq!(text, "Hi");
+        assert_eq!(chunks, vec!["", "Hi"]);
+        assert_eq!(usage, Some(TokenUsage { input_tokens: 9, output_tokens: 2 }));
+        assert!(read_openai_stream("data: [DONE]\n".as_bytes(), &mut |_| {}).is_err());
+    }
+
+    #[test]
+    fn test_streaming_through_wrappers() {
+        // Non-streaming providers hand over the whole response at once
+        let mut chunks = Vec::new();
+        let (text, _) = MockProvider::new("whole").complete_streaming("p", 10, &mut |t| chunks.push(t.to_string())).unwrap();
+        assert_eq!((text.as_str(), chunks), ("whole", vec!["whole".to_string()]));
+
+        // Cache hits are streamed too
+        let cache = AiCache::open(std::path::Path::new(":memory:")).unwrap();
+        let provider = CachedProvider::new(Box::new(CountingProvider { calls: AtomicUsize::new(0) }), cache, "m");
+        provider.complete("a", 100).unwrap();
+        let mut chunks = Vec::new();
+        provider.complete_streaming("a", 100, &mut |t| chunks.push(t.to_string())).unwrap();
+        assert_eq!(chunks, vec!["a #1"]);
+
+        // Transient failures are retried only before anything was shown
+        let flaky = FlakyProvider {
+            failures: vec![Some(reqwest::StatusCode::TOO_MANY_REQUESTS), None],
+            calls: AtomicUsize::new(0),
+        };
+        let provider = RetryingProvider::new(Box::new(flaky), instant_retries(3));
+        let (text, _) = provider.complete_streaming("p", 10, &mut |_| {}).unwrap();
+        assert_eq!(text, "ok after 2");
+    }
+
+    struct HalfStreamProvider;
+
+    impl AIProvider for HalfStreamProvider {
+        fn complete(&self, _prompt: &str, _max_tokens: u32) -> Result<String> {
+            unreachable!()
+        }
+        fn complete_streaming(
+            &self,
+            _prompt: &str,
+            _max_tokens: u32,
+            on_text: &mut dyn FnMut(&str),
+        ) -> Result<(String, Option<TokenUsage>)> {
+            on_text("partial");
+            Err(ApiStatusError { provider: "Test", status: reqwest::StatusCode::BAD_GATEWAY, body: String::new(), retry_after: None }.into())
+        }
+        fn model_name(&self) -> &str { "half" }
+    }
+
+    #[test]
+    fn test_retrying_provider_does_not_repeat_streamed_output() {
+        let provider = RetryingProvider::new(Box::new(HalfStreamProvider), instant_retries(3));
+        let mut chunks = Vec::new();
+        assert!(provider.complete_streaming("p", 10, &mut |t| chunks.push(t.to_string())).is_err());
+        assert_eq!(chunks, vec!["partial"]);
+    }
 }
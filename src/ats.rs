@@ -0,0 +1,187 @@
+use crate::models::JobKeyword;
+
+/// Weight the keyword extractor gives must-have requirements.
+pub const REQUIRED_WEIGHT: i32 = 3;
+
+/// Lowercased words, split on whitespace, punctuation and markup (LaTeX braces,
+/// markdown emphasis). Keeps `+`, `#` and inner dots so C++, C# and Node.js survive.
+fn tokens(text: &str) -> Vec<String> {
+    text.to_lowercase()
+        .split(|c: char| c.is_whitespace() || "/,()-_;:!?\"'`[]{}<>*|\\=~".contains(c))
+        .map(|t| t.trim_matches('.'))
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_string())
+        .collect()
+}
+
+/// Whether the keyword's words appear consecutively in the text, the way a literal
+/// ATS match would see them. "CI/CD" matches "ci-cd"; "Go" doesn't match "Google".
+fn mentions(text: &[String], keyword: &str) -> bool {
+    let keyword = tokens(keyword);
+    !keyword.is_empty() && text.windows(keyword.len()).any(|w| w == keyword.as_slice())
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct DomainCoverage {
+    pub domain: String,
+    pub found: usize,
+    pub total: usize,
+    pub found_weight: i32,
+    pub total_weight: i32,
+}
+
+impl DomainCoverage {
+    /// Share of the domain's keyword weight found in the text.
+    pub fn ratio(&self) -> f64 {
+        if self.total_weight == 0 { 0.0 } else { self.found_weight as f64 / self.total_weight as f64 }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Coverage {
+    pub domains: Vec<DomainCoverage>, // in keyword order (grouped by domain)
+    pub missing: Vec<JobKeyword>,     // heaviest first
+}
+
+impl Coverage {
+    pub fn found_weight(&self) -> i32 {
+        self.domains.iter().map(|d| d.found_weight).sum()
+    }
+
+    pub fn total_weight(&self) -> i32 {
+        self.domains.iter().map(|d| d.total_weight).sum()
+    }
+
+    pub fn missing_required(&self) -> impl Iterator<Item = &JobKeyword> {
+        self.missing.iter().filter(|k| k.weight >= REQUIRED_WEIGHT)
+    }
+}
+
+/// Which of a job's keywords appear in `text`, overall and per domain.
+pub fn check(text: &str, keywords: &[JobKeyword]) -> Coverage {
+    let text = tokens(text);
+    let mut domains: Vec<DomainCoverage> = Vec::new();
+    let mut missing = Vec::new();
+    for kw in keywords {
+        let index = match domains.iter().position(|d| d.domain == kw.domain) {
+            Some(index) => index,
+            None => {
+                domains.push(DomainCoverage {
+                    domain: kw.domain.clone(),
+                    found: 0,
+                    total: 0,
+                    found_weight: 0,
+                    total_weight: 0,
+                });
+                domains.len() - 1
+            }
+        };
+        let domain = &mut domains[index];
+        domain.total += 1;
+        domain.total_weight += kw.weight;
+        if mentions(&text, &kw.keyword) {
+            domain.found += 1;
+            domain.found_weight += kw.weight;
+        } else {
+            missing.push(kw.clone());
+        }
+    }
+    missing.sort_by(|a, b| b.weight.cmp(&a.weight).then(a.keyword.cmp(&b.keyword)));
+    Coverage { domains, missing }
+}
+
+/// Plain-language next steps for a coverage report.
+pub fn suggestions(coverage: &Coverage) -> Vec<String> {
+    let mut out = Vec::new();
+    if coverage.missing.is_empty() {
+        out.push("Every extracted keyword appears in this variant.".to_string());
+        return out;
+    }
+
+    let required: Vec<&str> = coverage.missing_required().map(|k| k.keyword.as_str()).collect();
+    if !required.is_empty() {
+        out.push(format!(
+            "Use the exact wording for required keywords you have experience with ({}); many ATS filters match literally",
+            required.join(", ")
+        ));
+    }
+    for domain in &coverage.domains {
+        if domain.total >= 2 && domain.ratio() < 0.5 {
+            let gaps: Vec<&str> = coverage.missing.iter()
+                .filter(|k| k.domain == domain.domain)
+                .take(3)
+                .map(|k| k.keyword.as_str())
+                .collect();
+            out.push(format!(
+                "Weak {} coverage ({}/{}): a bullet touching on {} would help",
+                domain.domain, domain.found, domain.total, gaps.join(", ")
+            ));
+        }
+    }
+    let preferred: Vec<&str> = coverage.missing.iter()
+        .filter(|k| k.weight == REQUIRED_WEIGHT - 1)
+        .take(5)
+        .map(|k| k.keyword.as_str())
+        .collect();
+    if !preferred.is_empty() {
+        out.push(format!("Nice-to-haves worth adding to a skills line: {}", preferred.join(", ")));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kw(keyword: &str, domain: &str, weight: i32) -> JobKeyword {
+        JobKeyword {
+            id: 0,
+            job_id: 1,
+            keyword: keyword.to_string(),
+            domain: domain.to_string(),
+            weight,
+            source_model: "m".to_string(),
+            created_at: String::new(),
+        }
+    }
+
+    #[test]
+    fn test_mentions() {
+        let text = tokens("Ran \\textbf{Kubernetes} clusters. Built CI-CD in **Go**; C++ and Node.js services.");
+        assert!(mentions(&text, "kubernetes"));
+        assert!(mentions(&text, "CI/CD"));
+        assert!(mentions(&text, "Go"));
+        assert!(mentions(&text, "c++"));
+        assert!(mentions(&text, "node.js"));
+        assert!(!mentions(&text, "Google Cloud"));
+        assert!(!mentions(&text, "c#"));
+        assert!(!mentions(&text, ""));
+    }
+
+    #[test]
+    fn test_check_and_suggestions() {
+        let keywords = vec![
+            kw("AWS", "tech", 3),
+            kw("Terraform", "tech", 3),
+            kw("Python", "tech", 2),
+            kw("on-call", "discipline", 3),
+            kw("incident response", "discipline", 2),
+            kw("mentoring", "soft", 1),
+        ];
+        let coverage = check("Managed AWS with Terraform. Mentoring junior engineers.", &keywords);
+        assert_eq!(coverage.domains.iter().map(|d| (d.domain.as_str(), d.found, d.total)).collect::<Vec<_>>(),
+                   vec![("tech", 2, 3), ("discipline", 0, 2), ("soft", 1, 1)]);
+        assert_eq!((coverage.found_weight(), coverage.total_weight()), (7, 14));
+        assert_eq!(coverage.missing_required().map(|k| k.keyword.as_str()).collect::<Vec<_>>(), vec!["on-call"]);
+        assert_eq!(coverage.missing[0].keyword, "on-call", "heaviest missing first");
+
+        let tips = suggestions(&coverage);
+        assert!(tips[0].contains("on-call"));
+        assert!(tips.iter().any(|t| t.starts_with("Weak discipline coverage (0/2)")));
+        assert!(tips.iter().any(|t| t.contains("Python") && t.contains("incident response")));
+
+        let full = check("aws terraform python on call incident response mentoring", &keywords);
+        assert!(full.missing.is_empty());
+        assert_eq!(suggestions(&full), vec!["Every extracted keyword appears in this variant."]);
+    }
+}
@@ -0,0 +1,287 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::browser::JobFetcher;
+use crate::db::{Database, extract_pay_range};
+use crate::error::HuntError;
+
+const GREENHOUSE_API_URL: &str = "https://boards-api.greenhouse.io/v1/boards";
+const LEVER_API_URL: &str = "https://api.lever.co/v0/postings";
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum BoardSource {
+    Greenhouse,
+    Lever,
+}
+
+impl BoardSource {
+    pub fn parse(name: &str) -> Result<Self> {
+        match name.to_lowercase().as_str() {
+            "greenhouse" | "gh" => Ok(BoardSource::Greenhouse),
+            "lever" => Ok(BoardSource::Lever),
+            other => Err(HuntError::Validation(format!("Unknown job board '{}'. Supported: greenhouse, lever", other)).into()),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            BoardSource::Greenhouse => "greenhouse",
+            BoardSource::Lever => "lever",
+        }
+    }
+
+    fn api_url(self, slug: &str) -> String {
+        match self {
+            BoardSource::Greenhouse => format!("{}/{}/jobs?content=true", GREENHOUSE_API_URL, slug),
+            BoardSource::Lever => format!("{}/{}?mode=json", LEVER_API_URL, slug),
+        }
+    }
+}
+
+/// An open role as returned by a job board API, with the description as plain text.
+#[derive(Debug)]
+pub struct BoardJob {
+    pub title: String,
+    pub url: String,
+    pub location: Option<String>,
+    pub description: String,
+}
+
+#[derive(Debug, Default)]
+pub struct BoardStats {
+    pub found: usize,
+    pub added: usize,
+    pub duplicates: usize,
+}
+
+// --- Greenhouse: GET /v1/boards/{slug}/jobs?content=true ---
+
+#[derive(Deserialize)]
+struct GreenhouseResponse {
+    jobs: Vec<GreenhouseJob>,
+}
+
+#[derive(Deserialize)]
+struct GreenhouseJob {
+    title: String,
+    absolute_url: String,
+    location: Option<GreenhouseLocation>,
+    #[serde(default)]
+    content: String, // HTML, entity-escaped
+}
+
+#[derive(Deserialize)]
+struct GreenhouseLocation {
+    name: String,
+}
+
+// --- Lever: GET /v0/postings/{slug}?mode=json ---
+
+#[derive(Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct LeverPosting {
+    text: String,
+    hosted_url: String,
+    #[serde(default)]
+    categories: LeverCategories,
+    #[serde(default)]
+    description_plain: String,
+    #[serde(default)]
+    lists: Vec<LeverList>,
+    #[serde(default)]
+    additional_plain: String,
+}
+
+#[derive(Deserialize, Default)]
+struct LeverCategories {
+    location: Option<String>,
+}
+
+#[derive(Deserialize)]
+struct LeverList {
+    text: String,
+    content: String, // HTML <li> items
+}
+
+pub fn fetch_board_jobs(source: BoardSource, slug: &str) -> Result<Vec<BoardJob>> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .build()?;
+    let response = client
+        .get(source.api_url(slug))
+        .send()
+        .with_context(|| format!("Failed to reach {} API", source.name()))?;
+
+    if response.status() == reqwest::StatusCode::NOT_FOUND {
+        return Err(HuntError::NotFound(format!("No {} board found for '{}'", source.name(), slug)).into());
+    }
+    if !response.status().is_success() {
+        return Err(HuntError::Network(format!("{} API request failed with status {}", source.name(), response.status())).into());
+    }
+
+    let body = response.text()?;
+    match source {
+        BoardSource::Greenhouse => parse_greenhouse(&body),
+        BoardSource::Lever => parse_lever(&body),
+    }
+}
+
+fn parse_greenhouse(json: &str) -> Result<Vec<BoardJob>> {
+    let response: GreenhouseResponse = serde_json::from_str(json)
+        .context("Failed to parse Greenhouse response")?;
+    response.jobs.into_iter().map(|job| {
+        // Greenhouse double-encodes: the HTML itself arrives entity-escaped
+        let html = scraper::Html::parse_fragment(&job.content)
+            .root_element()
+            .text()
+            .collect::<String>();
+        Ok(BoardJob {
+            title: job.title.trim().to_string(),
+            url: job.absolute_url,
+            location: job.location.map(|l| l.name),
+            description: JobFetcher::extract_and_clean_text(&html)?,
+        })
+    }).collect()
+}
+
+fn parse_lever(json: &str) -> Result<Vec<BoardJob>> {
+    let postings: Vec<LeverPosting> = serde_json::from_str(json)
+        .context("Failed to parse Lever response")?;
+    postings.into_iter().map(|posting| {
+        let mut sections = vec![posting.description_plain.trim().to_string()];
+        for list in &posting.lists {
+            let items = JobFetcher::extract_and_clean_text(&format!("<ul>{}</ul>", list.content))?;
+            sections.push(format!("{}\n{}", list.text.trim(), items));
+        }
+        sections.push(posting.additional_plain.trim().to_string());
+        let description = sections.into_iter()
+            .filter(|s| !s.is_empty())
+            .collect::<Vec<_>>()
+            .join("\n\n");
+        Ok(BoardJob {
+            title: posting.text.trim().to_string(),
+            url: posting.hosted_url,
+            location: posting.categories.location,
+            description,
+        })
+    }).collect()
+}
+
+/// Store board jobs under `employer`, skipping ones that already exist.
+pub fn import_board_jobs(
+    db: &Database,
+    jobs: &[BoardJob],
+    employer: &str,
+    source: BoardSource,
+    dry_run: bool,
+) -> Result<BoardStats> {
+    let mut stats = BoardStats { found: jobs.len(), ..Default::default() };
+    for job in jobs {
+        let location = job.location.as_deref().unwrap_or("-");
+        if db.is_duplicate_job(&job.title, Some(employer), Some(&job.url))?.is_some() {
+            stats.duplicates += 1;
+            println!("  [ DUP] {} ({})", job.title, location);
+            continue;
+        }
+        if dry_run {
+            println!("  [ DRY] {} ({})", job.title, location);
+            continue;
+        }
+        let (pay_min, pay_max) = extract_pay_range(&job.description);
+        let job_id = db.add_job_full(&job.title, Some(employer), Some(&job.url), Some(source.name()), None, None, None)?;
+        db.update_job_description(job_id, &job.description, pay_min, pay_max)?;
+        stats.added += 1;
+        println!("  [+ADD] #{} {} ({})", job_id, job.title, location);
+    }
+    Ok(stats)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_board_source_parse() {
+        assert_eq!(BoardSource::parse("greenhouse").unwrap(), BoardSource::Greenhouse);
+        assert_eq!(BoardSource::parse("Lever").unwrap(), BoardSource::Lever);
+        assert!(BoardSource::parse("workday").is_err());
+    }
+
+    #[test]
+    fn test_board_source_api_url() {
+        assert_eq!(
+            BoardSource::Greenhouse.api_url("acme"),
+            "https://boards-api.greenhouse.io/v1/boards/acme/jobs?content=true"
+        );
+        assert_eq!(BoardSource::Lever.api_url("acme"), "https://api.lever.co/v0/postings/acme?mode=json");
+    }
+
+    #[test]
+    fn test_parse_greenhouse_unescapes_content() {
+        let json = r#"{"jobs": [{
+            "id": 1,
+            "title": " Senior SRE ",
+            "absolute_url": "https://boards.greenhouse.io/acme/jobs/1",
+            "location": {"name": "Remote - US"},
+            "content": "&lt;p&gt;Run our Kubernetes fleet.&lt;/p&gt;&lt;ul&gt;&lt;li&gt;Terraform&lt;/li&gt;&lt;/ul&gt;"
+        }], "meta": {"total": 1}}"#;
+        let jobs = parse_greenhouse(json).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].title, "Senior SRE");
+        assert_eq!(jobs[0].location.as_deref(), Some("Remote - US"));
+        assert!(jobs[0].description.contains("Run our Kubernetes fleet."));
+        assert!(jobs[0].description.contains("• Terraform"));
+        assert!(!jobs[0].description.contains("&lt;"));
+    }
+
+    #[test]
+    fn test_parse_lever_builds_description() {
+        let json = r#"[{
+            "id": "abc",
+            "text": "Platform Engineer",
+            "hostedUrl": "https://jobs.lever.co/acme/abc",
+            "categories": {"location": "Berlin", "team": "Infra"},
+            "descriptionPlain": "We build the platform.",
+            "lists": [{"text": "Requirements", "content": "<li>Go</li><li>AWS</li>"}],
+            "additionalPlain": "$150,000 - $190,000"
+        }]"#;
+        let jobs = parse_lever(json).unwrap();
+        assert_eq!(jobs.len(), 1);
+        assert_eq!(jobs[0].title, "Platform Engineer");
+        assert_eq!(jobs[0].location.as_deref(), Some("Berlin"));
+        assert!(jobs[0].description.starts_with("We build the platform."));
+        assert!(jobs[0].description.contains("Requirements\n• Go"));
+        assert!(jobs[0].description.contains("$150,000"));
+    }
+
+    #[test]
+    fn test_parse_lever_invalid_json() {
+        assert!(parse_lever("{\"ok\": false}").is_err());
+    }
+
+    #[test]
+    fn test_import_board_jobs_dedupes() {
+        let db = Database::open_in_memory().unwrap();
+        db.init().unwrap();
+        let jobs = vec![
+            BoardJob {
+                title: "Platform Engineer".to_string(),
+                url: "https://jobs.lever.co/acme/abc".to_string(),
+                location: None,
+                description: "Build things. $150,000 - $190,000".to_string(),
+            },
+        ];
+
+        let stats = import_board_jobs(&db, &jobs, "Acme", BoardSource::Lever, false).unwrap();
+        assert_eq!(stats.added, 1);
+        let stored = db.list_jobs(None, Some("Acme")).unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].source.as_deref(), Some("lever"));
+        assert!(stored[0].fetched_at.is_some());
+        assert_eq!(stored[0].pay_max, Some(190000));
+
+        let stats = import_board_jobs(&db, &jobs, "Acme", BoardSource::Lever, false).unwrap();
+        assert_eq!(stats.added, 0);
+        assert_eq!(stats.duplicates, 1);
+    }
+}
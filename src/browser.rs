@@ -1,7 +1,13 @@
 use anyhow::{anyhow, Context, Result};
-use regex::Regex;
+use std::path::PathBuf;
 use std::process::Command;
+use tracing::{debug, info, warn};
 use thirtyfour::prelude::*;
+use thirtyfour::{ChromeCapabilities, FirefoxCapabilities};
+
+use crate::domains::{self, Application};
+use crate::error::HuntError;
+use crate::extractors::{self, Extracted, SiteAdapter};
 
 pub struct JobDescription {
     pub text: String,
@@ -9,115 +15,240 @@ pub struct JobDescription {
     pub pay_max: Option<i64>,
     pub no_longer_accepting: bool,
     pub employer_name: Option<String>,
+    pub easy_apply: Option<bool>, // LinkedIn only, see `detect_easy_apply`
+    pub application: Option<Application>, // where applications go, see `domains::resolve_application`
+}
+
+/// Which browser to automate, through its WebDriver.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Driver {
+    Firefox, // geckodriver
+    Chrome,  // chromedriver, with Chrome or Chromium
+}
+
+impl Driver {
+    /// In order of preference when auto-detecting.
+    pub const ALL: [Driver; 2] = [Driver::Firefox, Driver::Chrome];
+
+    /// `--driver firefox|chrome` (also accepts the driver binaries' names).
+    pub fn parse(name: &str) -> Result<Driver> {
+        match name.trim().to_lowercase().as_str() {
+            "firefox" | "gecko" | "geckodriver" => Ok(Driver::Firefox),
+            "chrome" | "chromium" | "chromedriver" => Ok(Driver::Chrome),
+            other => Err(HuntError::Validation(format!("Unknown driver '{}' (expected firefox or chrome)", other)).into()),
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Driver::Firefox => "firefox",
+            Driver::Chrome => "chrome",
+        }
+    }
+
+    pub fn binary(self) -> &'static str {
+        match self {
+            Driver::Firefox => "geckodriver",
+            Driver::Chrome => "chromedriver",
+        }
+    }
+
+    /// Each driver's default port, so both can run side by side.
+    pub fn port(self) -> u16 {
+        match self {
+            Driver::Firefox => 4444,
+            Driver::Chrome => 9515,
+        }
+    }
+
+    /// Browser executables that work with this driver.
+    pub fn browsers(self) -> &'static [&'static str] {
+        match self {
+            Driver::Firefox => &["firefox", "firefox-esr", "/snap/bin/firefox"],
+            Driver::Chrome => &["google-chrome", "google-chrome-stable", "chromium", "chromium-browser", "/snap/bin/chromium"],
+        }
+    }
+
+    pub fn is_listening(self) -> bool {
+        std::net::TcpStream::connect(("127.0.0.1", self.port())).is_ok()
+    }
+
+    /// The browser profile hunt keeps for this driver, in its data directory. Cookies
+    /// saved there (e.g. by `hunt auth linkedin`) carry over to headless fetches.
+    pub fn profile_dir(self) -> Result<PathBuf> {
+        let dirs = directories::ProjectDirs::from("", "", "hunt")
+            .ok_or_else(|| HuntError::Config("Could not determine data directory".to_string()))?;
+        Ok(dirs.data_dir().join(format!("{}-profile", self.name())))
+    }
+
+    fn start_command(self) -> Command {
+        let mut command = Command::new(self.binary());
+        match self {
+            Driver::Firefox => command.arg("--port").arg(self.port().to_string()),
+            Driver::Chrome => command.arg(format!("--port={}", self.port())),
+        };
+        command
+    }
+}
+
+/// The driver to use when none was asked for: one that's already running, else the
+/// first whose driver and browser are installed.
+pub fn detect_driver(listening: impl Fn(Driver) -> bool, installed: impl Fn(Driver) -> bool) -> Option<Driver> {
+    Driver::ALL.into_iter()
+        .find(|&d| listening(d))
+        .or_else(|| Driver::ALL.into_iter().find(|&d| installed(d)))
 }
 
+const LINKEDIN_LOGIN_URL: &str = "https://www.linkedin.com/login";
+const LINKEDIN_FEED_URL: &str = "https://www.linkedin.com/feed/";
+
 pub struct JobFetcher {
     driver: WebDriver,
-    _geckodriver: Option<std::process::Child>,
+    _driver_process: Option<std::process::Child>,
 }
 
 impl JobFetcher {
-    pub async fn new(headless: bool) -> Result<Self> {
-        // Check if Firefox is already running with the profile we need
-        if Self::is_firefox_running()? {
-            return Err(anyhow!(
-                "Firefox is already running. Close Firefox and try again immediately.\n\
-                 \n\
-                 Why: geckodriver needs exclusive access to your Firefox profile to use\n\
-                 your logged-in LinkedIn session. The profile can't be used by two processes.\n\
-                 \n\
-                 Steps:\n\
-                 1. Close all Firefox windows (or run: pkill firefox)\n\
-                 2. Run this command again right away\n\
-                 3. geckodriver will start Firefox with your profile and LinkedIn cookies"
-            ));
-        }
-
-        // Firefox profile location (snap Firefox)
-        let home = std::env::var("HOME").unwrap_or_else(|_| "/home".to_string());
-        let firefox_profile_dir = format!("{}/snap/firefox/common/.mozilla/firefox/5krdosdy.default", home);
-
-        println!("Using Firefox profile: {}", firefox_profile_dir);
-
-        // Create Firefox capabilities with user profile
-        let mut caps = DesiredCapabilities::firefox();
+    pub async fn new(driver: Driver, headless: bool) -> Result<Self> {
+        let fetcher = Self::connect(driver, headless).await?;
+        // Minimize to avoid stealing focus during automated fetches
+        if !headless {
+            let _ = fetcher.driver.minimize_window().await;
+        }
+        Ok(fetcher)
+    }
 
-        // Add Firefox args to specify profile
-        caps.add_arg("-profile")?;
-        caps.add_arg(&firefox_profile_dir)?;
+    /// A visible browser window for signing in by hand.
+    pub async fn for_login(driver: Driver) -> Result<Self> {
+        Self::connect(driver, false).await
+    }
+
+    async fn connect(driver: Driver, headless: bool) -> Result<Self> {
+        let caps: Capabilities = match driver {
+            Driver::Firefox => Self::firefox_capabilities(headless)?.into(),
+            Driver::Chrome => Self::chrome_capabilities(headless)?.into(),
+        };
 
+        // Auto-start the driver if it isn't already running
+        let driver_process = Self::ensure_driver_running(driver).await?;
+
+        let webdriver = WebDriver::new(&format!("http://localhost:{}", driver.port()), caps)
+            .await
+            .with_context(|| format!("Failed to connect to {} on port {}", driver.binary(), driver.port()))?;
+
+        Ok(JobFetcher { driver: webdriver, _driver_process: driver_process })
+    }
+
+    /// Firefox uses hunt's own profile unless config.json names an existing one with
+    /// "firefox_profile"; a shared profile can't be open in Firefox at the same time.
+    fn firefox_capabilities(headless: bool) -> Result<FirefoxCapabilities> {
+        let configured = crate::config::load()?.firefox_profile;
+        let profile_dir = match configured {
+            Some(path) => {
+                if Self::is_firefox_running()? {
+                    return Err(HuntError::Config("Firefox is already running. Close Firefox and try again immediately.\n\
+                         \n\
+                         Why: geckodriver needs exclusive access to the Firefox profile set in\n\
+                         config.json (firefox_profile). The profile can't be used by two processes.\n\
+                         \n\
+                         Steps:\n\
+                         1. Close all Firefox windows (or run: pkill firefox)\n\
+                         2. Run this command again right away\n\
+                         Or remove firefox_profile from config.json and sign in once with: hunt auth linkedin".to_string()).into());
+                }
+                crate::expand_home(&path)
+            }
+            None => Self::own_profile(Driver::Firefox)?,
+        };
+        info!("Using Firefox profile: {}", profile_dir.display());
+
+        let mut caps = DesiredCapabilities::firefox();
+        caps.add_arg("-profile")?;
+        caps.add_arg(&profile_dir.to_string_lossy())?;
         if headless {
             caps.set_headless()?;
         }
+        Ok(caps)
+    }
 
-        // Auto-start geckodriver if not already running
-        let geckodriver_child = Self::ensure_geckodriver_running().await?;
+    /// Chrome always gets a profile of its own, so it can run next to your everyday browser.
+    fn chrome_capabilities(headless: bool) -> Result<ChromeCapabilities> {
+        let profile_dir = Self::own_profile(Driver::Chrome)?;
+        info!("Using Chrome profile: {}", profile_dir.display());
 
-        // Connect to geckodriver
-        let driver = WebDriver::new("http://localhost:4444", caps)
-            .await
-            .context("Failed to connect to geckodriver after starting it")?;
-
-        // Minimize to avoid stealing focus during automated fetches
-        if !headless {
-            let _ = driver.minimize_window().await;
+        let mut caps = DesiredCapabilities::chrome();
+        caps.add_arg(&format!("--user-data-dir={}", profile_dir.display()))?;
+        caps.add_arg("--window-size=1280,1024")?;
+        if headless {
+            caps.set_headless()?;
+            caps.set_disable_gpu()?;
         }
+        Ok(caps)
+    }
 
-        Ok(JobFetcher { driver, _geckodriver: geckodriver_child })
+    /// Hunt's profile directory for a driver, created on first use.
+    fn own_profile(driver: Driver) -> Result<PathBuf> {
+        let dir = driver.profile_dir()?;
+        std::fs::create_dir_all(&dir)
+            .with_context(|| format!("Failed to create browser profile directory: {}", dir.display()))?;
+        Ok(dir)
     }
 
-    async fn ensure_geckodriver_running() -> Result<Option<std::process::Child>> {
-        // Check if geckodriver is already listening on port 4444
-        if std::net::TcpStream::connect("127.0.0.1:4444").is_ok() {
-            println!("Using existing geckodriver on port 4444");
+    async fn ensure_driver_running(driver: Driver) -> Result<Option<std::process::Child>> {
+        let (binary, port) = (driver.binary(), driver.port());
+        if driver.is_listening() {
+            info!("Using existing {} on port {}", binary, port);
             return Ok(None);
         }
 
-        println!("Starting geckodriver...");
-        let child = Command::new("geckodriver")
-            .arg("--port")
-            .arg("4444")
+        info!("Starting {}...", binary);
+        let child = driver.start_command()
             .stdout(std::process::Stdio::null())
             .stderr(std::process::Stdio::null())
             .spawn()
-            .context("Failed to start geckodriver. Install it or start manually: geckodriver --port 4444")?;
+            .with_context(|| format!("Failed to start {0}. Install it or start manually: {0} --port {1}", binary, port))?;
 
         // Wait for it to be ready (up to 5 seconds)
         for _ in 0..50 {
-            if std::net::TcpStream::connect("127.0.0.1:4444").is_ok() {
+            if driver.is_listening() {
                 return Ok(Some(child));
             }
             tokio::time::sleep(std::time::Duration::from_millis(100)).await;
         }
 
-        Err(anyhow!("geckodriver started but not responding on port 4444 after 5s"))
+        Err(anyhow!("{} started but not responding on port {} after 5s", binary, port))
+    }
+
+    /// End the browser session (closes the browser window).
+    pub async fn quit(self) -> Result<()> {
+        self.driver.quit().await?;
+        Ok(())
     }
 
     pub async fn fetch_job_description(&self, url: &str) -> Result<JobDescription> {
-        println!("Navigating to: {}", url);
+        debug!("Navigating to: {}", url);
 
         // Navigate to the job URL
         self.driver.goto(url).await
             .context("Failed to navigate to LinkedIn job URL")?;
 
-        println!("Waiting for page to load...");
+        debug!("Waiting for page to load...");
 
         // Wait for page to be ready
         tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
 
         // Check for LinkedIn auth wall
-        println!("Checking authentication status...");
+        debug!("Checking authentication status...");
         let auth_required = self.check_auth_required().await?;
         if auth_required {
-            println!("⚠ LinkedIn auth wall detected, but continuing to try extraction...");
+            warn!("LinkedIn auth wall detected (sign in once with: hunt auth linkedin), continuing...");
         } else {
-            println!("✓ Authenticated");
+            debug!("Authenticated");
         }
 
         // Extract employer name from the page
         let employer_name = self.extract_employer_name().await;
         if let Some(ref name) = employer_name {
-            println!("✓ Employer: {}", name);
+            debug!("Employer: {}", name);
         }
 
         // Check if job is no longer accepting applications
@@ -131,23 +262,38 @@ impl JobFetcher {
             false
         };
         if no_longer_accepting {
-            println!("⚠ Job is no longer accepting applications");
+            debug!("Job is no longer accepting applications");
+        }
+
+        // Where the page ended up after redirects, and where it sends applicants
+        let (easy_apply, application) = match self.driver.source().await {
+            Ok(html) => {
+                let landed = self.driver.current_url().await.map_or_else(|_| url.to_string(), |u| u.to_string());
+                (detect_easy_apply(url, &html), domains::resolve_application(&landed, &html))
+            }
+            Err(_) => (None, None),
+        };
+        if let Some(easy) = easy_apply {
+            debug!("{}", if easy { "Easy Apply" } else { "Applies on the employer's site" });
         }
 
+        let adapter = extractors::adapter_for(url);
+
         // Try to find and click "Show more" button
-        println!("Looking for 'Show more' button...");
-        let show_more_selectors = vec![
+        debug!("Looking for 'Show more' button...");
+        let generic_show_more = [
             "button.show-more-less-html__button",
             "button.show-more-less-html__button--more",
             ".jobs-description__footer-button",
             "button[aria-label*='Show more']",
             "button[aria-label*='See more']",
         ];
+        let show_more_selectors = adapter.map(|a| a.show_more).unwrap_or(&generic_show_more);
 
         let mut found_button = false;
-        for selector in &show_more_selectors {
+        for selector in show_more_selectors {
             if let Ok(element) = self.driver.find(By::Css(*selector)).await {
-                println!("✓ Found 'Show more' button, clicking...");
+                debug!("Found 'Show more' button, clicking...");
                 element.click().await?;
                 tokio::time::sleep(tokio::time::Duration::from_secs(2)).await;
                 found_button = true;
@@ -155,18 +301,31 @@ impl JobFetcher {
             }
         }
         if !found_button {
-            println!("(Show more button not found, continuing anyway)");
+            debug!("(Show more button not found, continuing anyway)");
+        }
+
+        // The site's own layout, when we know it
+        if let Some(adapter) = adapter
+            && let Ok(html) = self.driver.source().await
+            && let Some(mut desc) = adapter_description(adapter, &html, 1)?
+        {
+            debug!("Extracted {} characters with the {} adapter", desc.text.len(), adapter.name);
+            desc.no_longer_accepting |= no_longer_accepting;
+            desc.employer_name = desc.employer_name.or(employer_name);
+            desc.easy_apply = easy_apply;
+            desc.application = application;
+            return Ok(desc);
         }
 
         // Extract job description - use innerHTML to preserve structure
-        println!("Extracting job description...");
+        debug!("Extracting job description...");
 
         // Debug: See what's on the page
         if let Ok(body) = self.driver.find(By::Tag("body")).await {
             if let Ok(body_text) = body.text().await {
-                println!("DEBUG: Page contains {} chars total", body_text.len());
+                debug!("Page contains {} chars total", body_text.len());
                 if body_text.to_lowercase().contains("about the job") {
-                    println!("DEBUG: Found 'About the job' text on page");
+                    debug!("Found 'About the job' text on page");
                 }
             }
         }
@@ -188,9 +347,9 @@ impl JobFetcher {
                         let cleaned = Self::extract_and_clean_text(&html)?;
                         if !cleaned.trim().is_empty() {
                             let (pay_min, pay_max) = Self::parse_pay_range(&cleaned);
-                            println!("✓ Successfully extracted {} characters from {}", cleaned.len(), selector);
+                            debug!("Successfully extracted {} characters from {}", cleaned.len(), selector);
                             if pay_min.is_some() || pay_max.is_some() {
-                                println!("✓ Parsed pay range: ${:?} - ${:?}", pay_min, pay_max);
+                                debug!("Parsed pay range: ${:?} - ${:?}", pay_min, pay_max);
                             }
                             let emp = employer_name.clone()
                                 .or_else(|| Self::extract_employer_from_text(&cleaned));
@@ -200,6 +359,8 @@ impl JobFetcher {
                                 pay_max,
                                 no_longer_accepting,
                                 employer_name: emp,
+                                easy_apply,
+                                application,
                             });
                         }
                     }
@@ -208,15 +369,15 @@ impl JobFetcher {
         }
 
         // Ultimate fallback: get main content area and clean aggressively
-        println!("Using ultimate fallback: extracting and cleaning main content...");
+        debug!("Using ultimate fallback: extracting and cleaning main content...");
         if let Ok(main) = self.driver.find(By::Tag("main")).await {
             if let Ok(html) = main.inner_html().await {
                 let cleaned = Self::extract_and_clean_text(&html)?;
                 if !cleaned.is_empty() {
                     let (pay_min, pay_max) = Self::parse_pay_range(&cleaned);
-                    println!("✓ Extracted {} characters from main element (cleaned)", cleaned.len());
+                    debug!("Extracted {} characters from main element (cleaned)", cleaned.len());
                     if pay_min.is_some() || pay_max.is_some() {
-                        println!("✓ Parsed pay range: ${:?} - ${:?}", pay_min, pay_max);
+                        debug!("Parsed pay range: ${:?} - ${:?}", pay_min, pay_max);
                     }
                     let emp = employer_name.clone()
                         .or_else(|| Self::extract_employer_from_text(&cleaned));
@@ -226,6 +387,8 @@ impl JobFetcher {
                         pay_max,
                         no_longer_accepting,
                         employer_name: emp,
+                        easy_apply,
+                        application,
                     });
                 }
             }
@@ -237,9 +400,9 @@ impl JobFetcher {
                 let cleaned = Self::extract_and_clean_text(&html)?;
                 if !cleaned.is_empty() {
                     let (pay_min, pay_max) = Self::parse_pay_range(&cleaned);
-                    println!("✓ Extracted {} characters from body (cleaned)", cleaned.len());
+                    debug!("Extracted {} characters from body (cleaned)", cleaned.len());
                     if pay_min.is_some() || pay_max.is_some() {
-                        println!("✓ Parsed pay range: ${:?} - ${:?}", pay_min, pay_max);
+                        debug!("Parsed pay range: ${:?} - ${:?}", pay_min, pay_max);
                     }
                     let emp = employer_name.clone()
                         .or_else(|| Self::extract_employer_from_text(&cleaned));
@@ -249,6 +412,8 @@ impl JobFetcher {
                         pay_max,
                         no_longer_accepting,
                         employer_name: emp,
+                        easy_apply,
+                        application,
                     });
                 }
             }
@@ -315,7 +480,7 @@ impl JobFetcher {
         None
     }
 
-    fn detect_no_longer_accepting(text: &str) -> bool {
+    pub fn detect_no_longer_accepting(text: &str) -> bool {
         let lower = text.to_lowercase();
         let phrases = [
             "no longer accepting applications",
@@ -331,60 +496,12 @@ impl JobFetcher {
         phrases.iter().any(|phrase| lower.contains(phrase))
     }
 
+    /// Advertised pay as annual USD, converting hourly, monthly and non-USD rates.
     fn parse_pay_range(text: &str) -> (Option<i64>, Option<i64>) {
-        // Pattern 1: $XXK - $YYK or $XXK/yr - $YYK/yr
-        let pattern1 = Regex::new(r"\$(\d{1,3})K(?:/yr)?\s*[-–—]\s*\$(\d{1,3})K(?:/yr)?").unwrap();
-        if let Some(caps) = pattern1.captures(text) {
-            let min = caps.get(1).and_then(|m| m.as_str().parse::<i64>().ok()).map(|n| n * 1000);
-            let max = caps.get(2).and_then(|m| m.as_str().parse::<i64>().ok()).map(|n| n * 1000);
-            return (min, max);
-        }
-
-        // Pattern 2: Compensation Range: $XXX,XXX - $YYY,YYY
-        let pattern2 = Regex::new(r"(?i)compensation.*?\$(\d{1,3}),?(\d{3})\s*[-–—]\s*\$(\d{1,3}),?(\d{3})").unwrap();
-        if let Some(caps) = pattern2.captures(text) {
-            let min = if let (Some(hundreds), Some(thousands)) = (caps.get(1), caps.get(2)) {
-                format!("{}{}", hundreds.as_str(), thousands.as_str()).parse::<i64>().ok()
-            } else {
-                None
-            };
-            let max = if let (Some(hundreds), Some(thousands)) = (caps.get(3), caps.get(4)) {
-                format!("{}{}", hundreds.as_str(), thousands.as_str()).parse::<i64>().ok()
-            } else {
-                None
-            };
-            return (min, max);
-        }
-
-        // Pattern 3: $XXX,XXX - $YYY,YYY (without "compensation" keyword)
-        let pattern3 = Regex::new(r"\$(\d{1,3}),(\d{3})\s*[-–—]\s*\$(\d{1,3}),(\d{3})").unwrap();
-        if let Some(caps) = pattern3.captures(text) {
-            let min = if let (Some(hundreds), Some(thousands)) = (caps.get(1), caps.get(2)) {
-                format!("{}{}", hundreds.as_str(), thousands.as_str()).parse::<i64>().ok()
-            } else {
-                None
-            };
-            let max = if let (Some(hundreds), Some(thousands)) = (caps.get(3), caps.get(4)) {
-                format!("{}{}", hundreds.as_str(), thousands.as_str()).parse::<i64>().ok()
-            } else {
-                None
-            };
-            return (min, max);
-        }
-
-        // Pattern 4: $XX/hr - $YY/hr (hourly, convert to yearly assuming 2080 hours)
-        let pattern4 = Regex::new(r"\$(\d{1,3})(?:\.\d{2})?/hr\s*[-–—]\s*\$(\d{1,3})(?:\.\d{2})?/hr").unwrap();
-        if let Some(caps) = pattern4.captures(text) {
-            let min = caps.get(1).and_then(|m| m.as_str().parse::<i64>().ok()).map(|n| n * 2080);
-            let max = caps.get(2).and_then(|m| m.as_str().parse::<i64>().ok()).map(|n| n * 2080);
-            return (min, max);
-        }
-
-        // No match found
-        (None, None)
+        crate::db::extract_pay_range(text)
     }
 
-    fn extract_and_clean_text(html: &str) -> Result<String> {
+    pub fn extract_and_clean_text(html: &str) -> Result<String> {
         // Parse HTML and extract text while preserving structure
         use scraper::Html;
 
@@ -576,6 +693,20 @@ impl JobFetcher {
         }
     }
 
+    /// Open the LinkedIn sign-in page.
+    pub async fn open_linkedin_login(&self) -> Result<()> {
+        self.driver.goto(LINKEDIN_LOGIN_URL).await.context("Failed to open the LinkedIn sign-in page")?;
+        Ok(())
+    }
+
+    /// Whether the profile has a live LinkedIn session: the feed loads instead of a
+    /// sign-in page.
+    pub async fn linkedin_signed_in(&self) -> Result<bool> {
+        self.driver.goto(LINKEDIN_FEED_URL).await.context("Failed to open LinkedIn")?;
+        tokio::time::sleep(tokio::time::Duration::from_secs(3)).await;
+        Ok(!self.check_auth_required().await?)
+    }
+
     async fn check_auth_required(&self) -> Result<bool> {
         // Check for common LinkedIn auth/login indicators
         let auth_indicators = vec![
@@ -601,20 +732,575 @@ impl JobFetcher {
     }
 }
 
+// --- Plain HTTP fast path ---
+
+/// Outcome of trying a page without a browser.
+pub enum PlainFetch {
+    Content(JobDescription),
+    NeedsBrowser(String), // why the page can't be read without JavaScript
+}
+
+/// Minimum cleaned text for a plain fetch to count as a real job description.
+const MIN_PLAIN_TEXT_LEN: usize = 300;
+
+const PLAIN_USER_AGENT: &str =
+    "Mozilla/5.0 (X11; Linux x86_64; rv:128.0) Gecko/20100101 Firefox/128.0";
+
+/// Fetch a posting with a single HTTP GET. Returns `NeedsBrowser` when the page
+/// looks JavaScript-rendered, login-walled, or empty.
+pub fn fetch_plain(url: &str) -> Result<PlainFetch> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(15))
+        .user_agent(PLAIN_USER_AGENT)
+        .build()?;
+    let response = client.get(url).send().context("HTTP request failed")?;
+
+    let final_url = response.url().to_string();
+    if final_url.contains("/login") || final_url.contains("/authwall") || final_url.contains("/signin") {
+        return Ok(PlainFetch::NeedsBrowser("redirected to login".to_string()));
+    }
+    if !response.status().is_success() {
+        return Ok(PlainFetch::NeedsBrowser(format!("HTTP {}", response.status())));
+    }
+    let html = response.text()?;
+    extract_plain_for(&final_url, &html)
+}
+
+/// Follow a posting URL's redirects with one HTTP GET and work out where it takes
+/// applications (`hunt resolve`). None when it lands on a login wall or an aggregator
+/// page with no outbound apply link.
+pub fn resolve_application(url: &str) -> Result<Option<Application>> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(15))
+        .user_agent(PLAIN_USER_AGENT)
+        .build()?;
+    let response = client.get(url).send().context("HTTP request failed")?;
+    let final_url = response.url().to_string();
+    if final_url.contains("/login") || final_url.contains("/authwall") || final_url.contains("/signin") {
+        return Ok(None);
+    }
+    if !response.status().is_success() {
+        return Err(HuntError::Network(format!("HTTP {} from {}", response.status(), final_url)).into());
+    }
+    let html = response.text()?;
+    Ok(domains::resolve_application(&final_url, &html))
+}
+
+/// Whether a posting is still up, as far as one HTTP GET can tell.
+#[derive(Debug, PartialEq)]
+pub enum PostingState {
+    Open,
+    Gone(String),      // 404/410, a "no longer accepting" notice, or a redirect away from the job
+    Unchecked(String), // login wall or unexpected status: the page can't say
+}
+
+/// Check a posting with a single HTTP GET (for `hunt prune`).
+pub fn check_posting(url: &str) -> Result<PostingState> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(15))
+        .user_agent(PLAIN_USER_AGENT)
+        .build()?;
+    let response = client.get(url).send().context("HTTP request failed")?;
+    let (status, final_url) = (response.status().as_u16(), response.url().to_string());
+    let html = if response.status().is_success() { response.text()? } else { String::new() };
+    Ok(posting_state(status, &final_url, &html))
+}
+
+fn posting_state(status: u16, final_url: &str, html: &str) -> PostingState {
+    if final_url.contains("/login") || final_url.contains("/authwall") || final_url.contains("/signin") {
+        return PostingState::Unchecked("redirected to login".to_string());
+    }
+    match status {
+        404 | 410 => return PostingState::Gone(format!("HTTP {}", status)),
+        200..=299 => {}
+        _ => return PostingState::Unchecked(format!("HTTP {}", status)),
+    }
+    // Greenhouse and Lever send closed postings back to the board with an error flag
+    if final_url.contains("error=true") {
+        return PostingState::Gone("redirected to the job board".to_string());
+    }
+    let text = scraper::Html::parse_document(html).root_element().text().collect::<String>();
+    if JobFetcher::detect_no_longer_accepting(&text) {
+        PostingState::Gone("no longer accepting applications".to_string())
+    } else {
+        PostingState::Open
+    }
+}
+
+/// `extract_plain`, trying the site's adapter first when the URL has one.
+pub fn extract_plain_for(url: &str, html: &str) -> Result<PlainFetch> {
+    let mut fetched = None;
+    if let Some(adapter) = extractors::adapter_for(url)
+        && detect_requires_javascript(html).is_none()
+    {
+        fetched = adapter_description(adapter, html, MIN_PLAIN_TEXT_LEN)?.map(PlainFetch::Content);
+    }
+    let mut fetched = match fetched {
+        Some(fetched) => fetched,
+        None => extract_plain(html)?,
+    };
+    if let PlainFetch::Content(desc) = &mut fetched {
+        desc.easy_apply = detect_easy_apply(url, html);
+        desc.application = domains::resolve_application(url, html);
+    }
+    Ok(fetched)
+}
+
+/// How a LinkedIn posting takes applications, read off its apply button: through
+/// Easy Apply (true) or on the employer's own site (false). None for other sites, or
+/// when there's no apply button to read (closed postings, some login walls).
+pub fn detect_easy_apply(url: &str, html: &str) -> Option<bool> {
+    use scraper::{Html, Selector};
+
+    if extractors::adapter_for(url).is_none_or(|adapter| adapter.name != "LinkedIn") {
+        return None;
+    }
+    let document = Html::parse_document(html);
+    // Signed in: .jobs-apply-button with an aria-label. Guest pages: a sign-up link
+    // whose tracking name says whether it goes offsite.
+    let selector = Selector::parse(
+        ".jobs-apply-button, .jobs-s-apply button, [data-tracking-control-name*='apply-link'], button[aria-label*='Apply']",
+    ).unwrap();
+    for button in document.select(&selector) {
+        let element = button.value();
+        let label = format!(
+            "{} {} {}",
+            element.attr("aria-label").unwrap_or_default(),
+            element.attr("data-tracking-control-name").unwrap_or_default(),
+            button.text().collect::<String>(),
+        )
+        .to_lowercase();
+        if ["offsite", "company website", "company site"].iter().any(|m| label.contains(m)) {
+            return Some(false);
+        }
+        if ["easy apply", "apply-link-onsite", "apply-link-simple"].iter().any(|m| label.contains(m)) {
+            return Some(true);
+        }
+    }
+    None
+}
+
+/// A description read with a site adapter's selectors, or None if its description
+/// has fewer than `min_len` characters. Pay comes from the pay element when it parses,
+/// else from the text; title, location and pay lead the text unless it already has them.
+fn adapter_description(adapter: &SiteAdapter, html: &str, min_len: usize) -> Result<Option<JobDescription>> {
+    let page = extractors::extract(adapter, html);
+    let Some(description_html) = &page.description_html else { return Ok(None) };
+    let body = JobFetcher::extract_and_clean_text(description_html)?;
+    if body.trim().len() < min_len {
+        return Ok(None);
+    }
+    let (pay_min, pay_max) = match page.pay.as_deref().map(JobFetcher::parse_pay_range) {
+        Some((min, max)) if min.is_some() || max.is_some() => (min, max),
+        _ => JobFetcher::parse_pay_range(&body),
+    };
+    let page_text = scraper::Html::parse_document(html).root_element().text().collect::<String>();
+    Ok(Some(JobDescription {
+        text: format!("{}{}", description_header(&page, &body), body),
+        pay_min,
+        pay_max,
+        no_longer_accepting: JobFetcher::detect_no_longer_accepting(&page_text),
+        employer_name: page.company.clone().or_else(|| JobFetcher::extract_employer_from_text(&body)),
+        easy_apply: None,
+        application: None,
+    }))
+}
+
+fn description_header(page: &Extracted, body: &str) -> String {
+    let opening = body.chars().take(500).collect::<String>().to_lowercase();
+    let mut header = String::new();
+    if let Some(title) = page.title.as_ref().filter(|t| !opening.contains(&t.to_lowercase())) {
+        header.push_str(&format!("{}\n", title));
+    }
+    if let Some(location) = page.location.as_ref().filter(|l| !opening.contains(&l.to_lowercase())) {
+        header.push_str(&format!("Location: {}\n", location));
+    }
+    if let Some(pay) = page.pay.as_ref().filter(|p| !body.to_lowercase().contains(&p.to_lowercase())) {
+        header.push_str(&format!("Pay: {}\n", pay));
+    }
+    if !header.is_empty() {
+        header.push('\n');
+    }
+    header
+}
+
+/// Readability-style extraction: JSON-LD JobPosting first, then known description
+/// containers, then <main>/<article>/<body>.
+pub fn extract_plain(html: &str) -> Result<PlainFetch> {
+    use scraper::{Html, Selector};
+
+    if let Some(reason) = detect_requires_javascript(html) {
+        return Ok(PlainFetch::NeedsBrowser(reason));
+    }
+
+    let document = Html::parse_document(html);
+    let no_longer_accepting = JobFetcher::detect_no_longer_accepting(
+        &document.root_element().text().collect::<String>(),
+    );
+
+    let (mut text, mut employer_name, mut job_location) = (None, None, None);
+
+    // Structured data: most ATS pages embed a schema.org JobPosting
+    let ld_selector = Selector::parse(r#"script[type="application/ld+json"]"#).unwrap();
+    for script in document.select(&ld_selector) {
+        let raw = script.text().collect::<String>();
+        let Ok(value) = serde_json::from_str::<serde_json::Value>(&raw) else { continue };
+        if let Some(posting) = find_job_posting(&value) {
+            if let Some(desc) = posting.get("description").and_then(|d| d.as_str()) {
+                let cleaned = JobFetcher::extract_and_clean_text(desc)?;
+                if cleaned.len() >= MIN_PLAIN_TEXT_LEN {
+                    text = Some(cleaned);
+                }
+            }
+            employer_name = posting.get("hiringOrganization")
+                .and_then(|org| org.get("name"))
+                .and_then(|name| name.as_str())
+                .map(|name| name.trim().to_string());
+            job_location = posting_location(posting);
+            break;
+        }
+    }
+
+    if text.is_none() {
+        let content_selectors = [
+            ".show-more-less-html__markup",
+            ".description__text",
+            "#job-details",
+            "#content .job-description",
+            ".job-description",
+            ".posting-page",
+            "[class*='jobDescription']",
+            "[data-automation-id='jobPostingDescription']",
+            "article",
+            "main",
+            "body",
+        ];
+        for sel in content_selectors {
+            let Ok(selector) = Selector::parse(sel) else { continue };
+            if let Some(element) = document.select(&selector).next() {
+                let cleaned = JobFetcher::extract_and_clean_text(&element.inner_html())?;
+                if cleaned.len() >= MIN_PLAIN_TEXT_LEN {
+                    text = Some(cleaned);
+                    break;
+                }
+            }
+        }
+    }
+
+    let Some(mut text) = text else {
+        return Ok(PlainFetch::NeedsBrowser("too little content without JavaScript".to_string()));
+    };
+    // Lead with the structured location, the same way adapters do, so it's stored with the job
+    if let Some(place) = job_location
+        && !text.chars().take(500).collect::<String>().to_lowercase().contains(&place.to_lowercase())
+    {
+        text = format!("Location: {}\n\n{}", place, text);
+    }
+
+    let (pay_min, pay_max) = JobFetcher::parse_pay_range(&text);
+    let employer_name = employer_name.or_else(|| JobFetcher::extract_employer_from_text(&text));
+    Ok(PlainFetch::Content(JobDescription {
+        text,
+        pay_min,
+        pay_max,
+        no_longer_accepting,
+        employer_name,
+        easy_apply: None,
+        application: None,
+    }))
+}
+
+/// Look for the tell-tale signs of a client-rendered or login-walled page.
+fn detect_requires_javascript(html: &str) -> Option<String> {
+    let lower = html.to_lowercase();
+    let phrases = [
+        "you need to enable javascript",
+        "please enable javascript",
+        "requires javascript",
+        "javascript is required",
+        "javascript must be enabled",
+        "enable javascript to run this app",
+        "turn on javascript",
+    ];
+    if let Some(phrase) = phrases.iter().find(|p| lower.contains(*p)) {
+        // Many real pages carry a <noscript> nag; only trust it when the page is otherwise thin
+        let visible = scraper::Html::parse_document(html)
+            .root_element()
+            .text()
+            .collect::<String>();
+        if visible.split_whitespace().count() < 150 {
+            return Some(format!("page says \"{}\"", phrase));
+        }
+    }
+    if lower.contains("class=\"authwall") || lower.contains("name=\"session_password\"") {
+        return Some("login wall".to_string());
+    }
+    None
+}
+
+fn find_job_posting(value: &serde_json::Value) -> Option<&serde_json::Value> {
+    match value {
+        serde_json::Value::Array(items) => items.iter().find_map(find_job_posting),
+        serde_json::Value::Object(map) => {
+            let is_posting = match map.get("@type") {
+                Some(serde_json::Value::String(t)) => t == "JobPosting",
+                Some(serde_json::Value::Array(types)) => types.iter().any(|t| t == "JobPosting"),
+                _ => false,
+            };
+            if is_posting {
+                Some(value)
+            } else {
+                map.get("@graph").and_then(find_job_posting)
+            }
+        }
+        _ => None,
+    }
+}
+
+/// A JobPosting's place: its first few jobLocation addresses, marked remote when
+/// jobLocationType is TELECOMMUTE.
+fn posting_location(posting: &serde_json::Value) -> Option<String> {
+    let field = |value: &serde_json::Value, key: &str| -> Option<String> {
+        match value.get(key)? {
+            serde_json::Value::String(s) => Some(s.trim().to_string()),
+            other => other.get("name")?.as_str().map(|s| s.trim().to_string()),
+        }
+        .filter(|s| !s.is_empty())
+    };
+    let places: Vec<String> = match posting.get("jobLocation") {
+        Some(serde_json::Value::Array(items)) => items.iter().collect::<Vec<_>>(),
+        Some(item) => vec![item],
+        None => Vec::new(),
+    }
+    .into_iter()
+    .filter_map(|place| {
+        let address = place.get("address")?;
+        let parts: Vec<String> = ["addressLocality", "addressRegion"].iter().filter_map(|k| field(address, k)).collect();
+        let parts = if parts.is_empty() { field(address, "addressCountry").into_iter().collect() } else { parts };
+        (!parts.is_empty()).then(|| parts.join(", "))
+    })
+    .take(3)
+    .collect();
+
+    let remote = posting.get("jobLocationType").and_then(|t| t.as_str()) == Some("TELECOMMUTE");
+    match (places.is_empty(), remote) {
+        (true, true) => Some("Remote".to_string()),
+        (false, true) => Some(format!("{} (Remote)", places.join(" / "))),
+        (false, false) => Some(places.join(" / ")),
+        (true, false) => None,
+    }
+}
+
 // Note: We don't implement Drop to quit the driver because:
 // 1. WebDriver::quit() takes ownership (consumes self)
 // 2. Drop only has &mut self, so we can't call quit()
-// 3. The user should manually close Firefox after use
-// 4. Or the driver will clean up when the process exits
+// Callers that are done with a fetcher call `quit`; otherwise the driver cleans up
+// when the process exits
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn long_text(words: usize) -> String {
+        vec!["Kubernetes platform reliability work."; words / 4].join(" ")
+    }
+
+    #[test]
+    fn test_posting_state() {
+        let page = "<html><body><h1>SRE</h1><p>Apply below.</p></body></html>";
+        assert_eq!(posting_state(200, "https://jobs.lever.co/acme/1", page), PostingState::Open);
+        assert_eq!(posting_state(404, "https://jobs.lever.co/acme/1", ""), PostingState::Gone("HTTP 404".to_string()));
+        assert_eq!(
+            posting_state(200, "https://boards.greenhouse.io/acme?error=true", page),
+            PostingState::Gone("redirected to the job board".to_string())
+        );
+        let closed = "<html><body><p>No longer accepting applications</p></body></html>";
+        assert_eq!(posting_state(200, "https://acme.com/jobs/1", closed), PostingState::Gone("no longer accepting applications".to_string()));
+        assert!(matches!(posting_state(200, "https://www.linkedin.com/authwall?x=1", page), PostingState::Unchecked(_)));
+        assert!(matches!(posting_state(503, "https://acme.com/jobs/1", ""), PostingState::Unchecked(_)));
+    }
+
+    #[test]
+    fn test_driver_parse_and_detect() {
+        assert_eq!(Driver::parse("Chrome").unwrap(), Driver::Chrome);
+        assert_eq!(Driver::parse("chromium").unwrap(), Driver::Chrome);
+        assert_eq!(Driver::parse("geckodriver").unwrap(), Driver::Firefox);
+        assert!(matches!(Driver::parse("safari").unwrap_err().downcast_ref::<HuntError>(), Some(HuntError::Validation(_))));
+
+        // A running driver wins over installed ones; Firefox is preferred among equals
+        assert_eq!(detect_driver(|d| d == Driver::Chrome, |_| true), Some(Driver::Chrome));
+        assert_eq!(detect_driver(|_| false, |_| true), Some(Driver::Firefox));
+        assert_eq!(detect_driver(|_| false, |d| d == Driver::Chrome), Some(Driver::Chrome));
+        assert_eq!(detect_driver(|_| false, |_| false), None);
+
+        let args: Vec<String> = Driver::Chrome.start_command().get_args().map(|a| a.to_string_lossy().to_string()).collect();
+        assert_eq!(args, vec!["--port=9515"]);
+    }
+
+    #[test]
+    fn test_profile_dir() {
+        let firefox = Driver::Firefox.profile_dir().unwrap();
+        let chrome = Driver::Chrome.profile_dir().unwrap();
+        assert!(firefox.ends_with("firefox-profile"));
+        assert!(chrome.ends_with("chrome-profile"));
+        assert_eq!(firefox.parent(), chrome.parent(), "both live in hunt's data directory");
+    }
+
+    #[test]
+    fn test_extract_plain_for_uses_site_adapter() {
+        let html = format!(r#"<html><body>
+            <div class="job__title"><h1>Staff SRE</h1></div>
+            <div class="job__location">Remote (US)</div>
+            <div class="job__description"><p>{}</p><p>Company: Acme</p></div>
+            <div class="pay-range">$180K — $220K</div>
+            <footer>Lots of unrelated footer text</footer>
+        </body></html>"#, long_text(400));
+        match extract_plain_for("https://job-boards.greenhouse.io/acme/jobs/1", &html).unwrap() {
+            PlainFetch::Content(desc) => {
+                assert!(desc.text.starts_with("Staff SRE\nLocation: Remote (US)\nPay: $180K — $220K\n\nKubernetes"), "{}", &desc.text[..60]);
+                assert!(!desc.text.contains("footer"));
+                assert_eq!((desc.pay_min, desc.pay_max), (Some(180000), Some(220000)));
+                assert_eq!(desc.employer_name.as_deref(), Some("Acme"));
+            }
+            PlainFetch::NeedsBrowser(reason) => panic!("expected content, got {}", reason),
+        }
+
+        // Too little text in the adapter's container: the generic extractor takes over
+        let thin = format!(r#"<html><body><div class="job__description">Apply</div><main>{}</main></body></html>"#, long_text(400));
+        match extract_plain_for("https://boards.greenhouse.io/acme/jobs/1", &thin).unwrap() {
+            PlainFetch::Content(desc) => assert!(desc.text.starts_with("Kubernetes")),
+            PlainFetch::NeedsBrowser(reason) => panic!("expected content, got {}", reason),
+        }
+    }
+
+    #[test]
+    fn test_detect_easy_apply() {
+        let url = "https://www.linkedin.com/jobs/view/123";
+        let signed_in = r#"<div class="jobs-s-apply"><button class="jobs-apply-button" aria-label="Easy Apply to Staff SRE at Acme">
+            <span>Easy Apply</span></button></div>"#;
+        assert_eq!(detect_easy_apply(url, signed_in), Some(true));
+        let offsite = r#"<button class="jobs-apply-button" aria-label="Apply to Staff SRE on company website"><span>Apply</span></button>"#;
+        assert_eq!(detect_easy_apply(url, offsite), Some(false));
+        let guest = r#"<a data-tracking-control-name="public_jobs_apply-link-offsite_sign-up-modal" href="/signup">Apply</a>"#;
+        assert_eq!(detect_easy_apply(url, guest), Some(false));
+
+        // "Easy Apply" elsewhere on the page (search filters, similar jobs) isn't the job's button
+        let closed = r#"<button class="search-filter">Easy Apply</button><p>No longer accepting applications</p>"#;
+        assert_eq!(detect_easy_apply(url, closed), None);
+        assert_eq!(detect_easy_apply("https://boards.greenhouse.io/acme/jobs/1", signed_in), None);
+
+        let page = format!(
+            r#"<html><body><div class="show-more-less-html__markup"><p>{}</p></div>{}</body></html>"#,
+            long_text(400), guest
+        );
+        match extract_plain_for(url, &page).unwrap() {
+            PlainFetch::Content(desc) => assert_eq!(desc.easy_apply, Some(false)),
+            PlainFetch::NeedsBrowser(reason) => panic!("expected content, got {}", reason),
+        }
+    }
+
+    #[test]
+    fn test_extract_plain_prefers_json_ld() {
+        let html = format!(
+            r#"<html><head><script type="application/ld+json">
+            {{"@context": "https://schema.org", "@type": "JobPosting", "title": "SRE",
+              "hiringOrganization": {{"@type": "Organization", "name": "Acme"}},
+              "description": "<p>{}</p><p>Salary: $150K - $190K</p>"}}
+            </script></head><body><div id="app"></div></body></html>"#,
+            long_text(80)
+        );
+        match extract_plain(&html).unwrap() {
+            PlainFetch::Content(desc) => {
+                assert!(desc.text.contains("Kubernetes platform"));
+                assert_eq!(desc.employer_name.as_deref(), Some("Acme"));
+                assert_eq!(desc.pay_min, Some(150000));
+                assert_eq!(desc.pay_max, Some(190000));
+            }
+            PlainFetch::NeedsBrowser(reason) => panic!("expected content, got: {}", reason),
+        }
+    }
+
+    #[test]
+    fn test_posting_location() {
+        let posting = |json: &str| posting_location(&serde_json::from_str(json).unwrap());
+        assert_eq!(posting(r#"{"jobLocation": {"address": {"addressLocality": "Austin", "addressRegion": "TX"}}}"#),
+                   Some("Austin, TX".to_string()));
+        assert_eq!(posting(r#"{"jobLocation": [{"address": {"addressLocality": "Denver"}},
+                                               {"address": {"addressCountry": {"name": "Canada"}}}],
+                              "jobLocationType": "TELECOMMUTE"}"#),
+                   Some("Denver / Canada (Remote)".to_string()));
+        assert_eq!(posting(r#"{"jobLocationType": "TELECOMMUTE"}"#), Some("Remote".to_string()));
+        assert_eq!(posting(r#"{"jobLocation": {"@type": "Place"}}"#), None);
+
+        let html = format!(
+            r#"<script type="application/ld+json">{{"@type": "JobPosting", "description": "{}",
+               "jobLocation": {{"address": {{"addressLocality": "Austin", "addressRegion": "TX"}}}}}}</script>"#,
+            long_text(80)
+        );
+        match extract_plain(&html).unwrap() {
+            PlainFetch::Content(desc) => assert!(desc.text.starts_with("Location: Austin, TX\n\nKubernetes")),
+            PlainFetch::NeedsBrowser(reason) => panic!("expected content, got: {}", reason),
+        }
+    }
+
+    #[test]
+    fn test_extract_plain_json_ld_graph() {
+        let html = format!(
+            r#"<script type="application/ld+json">{{"@graph": [{{"@type": "WebPage"}}, {{"@type": ["JobPosting"], "description": "{}"}}]}}</script>"#,
+            long_text(80)
+        );
+        assert!(matches!(extract_plain(&html).unwrap(), PlainFetch::Content(_)));
+    }
+
+    #[test]
+    fn test_extract_plain_falls_back_to_containers() {
+        let html = format!(
+            "<html><body><nav>Home</nav><div class=\"job-description\"><p>{}</p><ul><li>Go</li></ul></div></body></html>",
+            long_text(80)
+        );
+        match extract_plain(&html).unwrap() {
+            PlainFetch::Content(desc) => {
+                assert!(desc.text.contains("• Go"));
+                assert!(!desc.no_longer_accepting);
+            }
+            PlainFetch::NeedsBrowser(reason) => panic!("expected content, got: {}", reason),
+        }
+    }
+
+    #[test]
+    fn test_extract_plain_detects_javascript_shell() {
+        let html = "<html><body><noscript>You need to enable JavaScript to run this app.</noscript><div id=\"root\"></div></body></html>";
+        assert!(matches!(extract_plain(html).unwrap(), PlainFetch::NeedsBrowser(_)));
+    }
+
+    #[test]
+    fn test_extract_plain_ignores_noscript_nag_on_full_page() {
+        let html = format!(
+            "<html><body><noscript>Please enable JavaScript for the best experience.</noscript><main><p>{}</p></main></body></html>",
+            long_text(400)
+        );
+        assert!(matches!(extract_plain(&html).unwrap(), PlainFetch::Content(_)));
+    }
+
+    #[test]
+    fn test_extract_plain_thin_page_needs_browser() {
+        let html = "<html><body><main><p>Loading...</p></main></body></html>";
+        assert!(matches!(extract_plain(html).unwrap(), PlainFetch::NeedsBrowser(_)));
+    }
+
+    #[test]
+    fn test_extract_plain_login_wall() {
+        let html = "<html><body><form><input name=\"session_password\"></form></body></html>";
+        match extract_plain(html).unwrap() {
+            PlainFetch::NeedsBrowser(reason) => assert_eq!(reason, "login wall"),
+            PlainFetch::Content(_) => panic!("expected login wall"),
+        }
+    }
+
     #[tokio::test]
     #[ignore] // Ignore by default since it requires geckodriver running
     async fn test_fetch_job_description() {
-        let fetcher = JobFetcher::new(false).await.expect("Failed to create fetcher");
+        let fetcher = JobFetcher::new(Driver::Firefox, false).await.expect("Failed to create fetcher");
         let url = "https://www.linkedin.com/jobs/view/1234567890";
         let result = fetcher.fetch_job_description(url).await;
 
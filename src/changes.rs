@@ -0,0 +1,76 @@
+use crate::browser::JobFetcher;
+use crate::diff;
+use crate::pay;
+
+/// What changed between two versions of a posting's text: the pay, whether it now says
+/// it's closed, and how many lines moved.
+#[derive(Debug, Default, PartialEq)]
+pub struct PostingChange {
+    pub pay: Option<(Option<String>, Option<String>)>, // (old, new) pay wording when the pay moved
+    pub closed: bool,                                  // newly says it's no longer accepting applications
+    pub reopened: bool,                                // said that before and no longer does
+    pub added: usize,
+    pub removed: usize,
+}
+
+impl PostingChange {
+    pub fn between(old: &str, new: &str) -> PostingChange {
+        let (old_pay, new_pay) = (pay::parse(old), pay::parse(new));
+        let annual = |p: &Option<pay::Pay>| p.as_ref().map(|p| p.annual_usd());
+        let pay = (annual(&old_pay) != annual(&new_pay))
+            .then(|| (old_pay.map(|p| p.text), new_pay.map(|p| p.text)));
+        let (was_closed, is_closed) = (JobFetcher::detect_no_longer_accepting(old), JobFetcher::detect_no_longer_accepting(new));
+        let (added, removed) = diff::stats(&diff::diff_lines(old, new));
+        PostingChange { pay, closed: is_closed && !was_closed, reopened: was_closed && !is_closed, added, removed }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added == 0 && self.removed == 0
+    }
+
+    /// One line, e.g. "pay $150k - $180k → $160k - $200k; now closed; 4 line(s) added, 2 removed".
+    pub fn summary(&self) -> String {
+        if self.is_empty() {
+            return "no changes".to_string();
+        }
+        let mut parts = Vec::new();
+        if let Some((old, new)) = &self.pay {
+            parts.push(format!("pay {} → {}", old.as_deref().unwrap_or("none"), new.as_deref().unwrap_or("none")));
+        }
+        if self.closed {
+            parts.push("now says it's no longer accepting applications".to_string());
+        }
+        if self.reopened {
+            parts.push("no longer says it's closed".to_string());
+        }
+        parts.push(format!("{} line(s) added, {} removed", self.added, self.removed));
+        parts.join("; ")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_between() {
+        let old = "Senior SRE\nPay: $150,000 - $180,000\nRun Kubernetes.";
+        let new = "Senior SRE\nPay: $160,000 - $200,000\nRun Kubernetes.\nNo longer accepting applications.";
+        let change = PostingChange::between(old, new);
+        assert_eq!(change.pay, Some((Some("$150,000 - $180,000".to_string()), Some("$160,000 - $200,000".to_string()))));
+        assert!(change.closed && !change.reopened);
+        assert_eq!((change.added, change.removed), (2, 1));
+        assert_eq!(
+            change.summary(),
+            "pay $150,000 - $180,000 → $160,000 - $200,000; now says it's no longer accepting applications; 2 line(s) added, 1 removed"
+        );
+
+        let reworded = PostingChange::between(old, "Senior SRE\nPay: $150k-$180k\nRun Kubernetes.");
+        assert_eq!(reworded.pay, None, "same pay in other words");
+        assert_eq!(reworded.summary(), "1 line(s) added, 1 removed");
+
+        assert!(PostingChange::between(old, old).is_empty());
+        assert_eq!(PostingChange::between(old, old).summary(), "no changes");
+        assert!(PostingChange::between(new, old).reopened);
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use crate::models::JobKeyword;
+
+/// A job's keywords (lowercased) and their summed weights.
+pub type KeywordVector = HashMap<String, f64>;
+
+pub fn keyword_vector(keywords: &[JobKeyword]) -> KeywordVector {
+    let mut vector = KeywordVector::new();
+    for kw in keywords {
+        *vector.entry(kw.keyword.to_lowercase()).or_default() += kw.weight as f64;
+    }
+    vector
+}
+
+pub fn cosine(a: &KeywordVector, b: &KeywordVector) -> f64 {
+    let dot: f64 = a.iter().filter_map(|(k, w)| b.get(k).map(|v| w * v)).sum();
+    let norm = |v: &KeywordVector| v.values().map(|w| w * w).sum::<f64>().sqrt();
+    let denom = norm(a) * norm(b);
+    if denom == 0.0 { 0.0 } else { dot / denom }
+}
+
+/// Average-linkage agglomerative clustering: keep merging the two most similar groups
+/// while their mean pairwise similarity is at least `threshold`. Returns groups of
+/// indices into `vectors`, largest first; unmatched items come back as singletons.
+pub fn cluster(vectors: &[KeywordVector], threshold: f64) -> Vec<Vec<usize>> {
+    let n = vectors.len();
+    let mut sim = vec![vec![0.0; n]; n];
+    for i in 0..n {
+        for j in (i + 1)..n {
+            let s = cosine(&vectors[i], &vectors[j]);
+            sim[i][j] = s;
+            sim[j][i] = s;
+        }
+    }
+
+    // groups[i] is None once merged into another group
+    let mut groups: Vec<Option<Vec<usize>>> = (0..n).map(|i| Some(vec![i])).collect();
+    loop {
+        let mut best: Option<(usize, usize, f64)> = None;
+        for i in 0..n {
+            if groups[i].is_none() {
+                continue;
+            }
+            for j in (i + 1)..n {
+                if groups[j].is_some() && sim[i][j] >= threshold && best.is_none_or(|(_, _, s)| sim[i][j] > s) {
+                    best = Some((i, j, sim[i][j]));
+                }
+            }
+        }
+        let Some((a, b, _)) = best else { break };
+
+        // Lance-Williams update for average linkage
+        let (size_a, size_b) = (groups[a].as_ref().map_or(0, Vec::len), groups[b].as_ref().map_or(0, Vec::len));
+        for k in 0..n {
+            if k != a && k != b && groups[k].is_some() {
+                let merged = (size_a as f64 * sim[a][k] + size_b as f64 * sim[b][k]) / (size_a + size_b) as f64;
+                sim[a][k] = merged;
+                sim[k][a] = merged;
+            }
+        }
+        let absorbed = groups[b].take().unwrap_or_default();
+        if let Some(group) = groups[a].as_mut() {
+            group.extend(absorbed);
+        }
+    }
+
+    let mut result: Vec<Vec<usize>> = groups.into_iter().flatten().collect();
+    for group in &mut result {
+        group.sort_unstable();
+    }
+    result.sort_by(|a, b| b.len().cmp(&a.len()).then(a[0].cmp(&b[0])));
+    result
+}
+
+/// The `n` heaviest keywords across a group's members, for labels and naming prompts.
+pub fn top_keywords(vectors: &[KeywordVector], members: &[usize], n: usize) -> Vec<String> {
+    let mut totals: HashMap<&str, f64> = HashMap::new();
+    for &i in members {
+        for (keyword, weight) in &vectors[i] {
+            *totals.entry(keyword).or_default() += weight;
+        }
+    }
+    let mut ranked: Vec<(&str, f64)> = totals.into_iter().collect();
+    ranked.sort_by(|a, b| b.1.total_cmp(&a.1).then(a.0.cmp(b.0)));
+    ranked.into_iter().take(n).map(|(k, _)| k.to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vector(keywords: &[(&str, f64)]) -> KeywordVector {
+        keywords.iter().map(|(k, w)| (k.to_string(), *w)).collect()
+    }
+
+    #[test]
+    fn test_keyword_vector_lowercases_and_sums() {
+        let kw = |keyword: &str, weight: i32| JobKeyword {
+            id: 0,
+            job_id: 1,
+            keyword: keyword.to_string(),
+            domain: "tech".to_string(),
+            weight,
+            source_model: "m".to_string(),
+            created_at: String::new(),
+        };
+        let v = keyword_vector(&[kw("AWS", 3), kw("aws", 1), kw("Go", 2)]);
+        assert_eq!(v.get("aws"), Some(&4.0));
+        assert_eq!(v.get("go"), Some(&2.0));
+    }
+
+    #[test]
+    fn test_cosine() {
+        let a = vector(&[("aws", 3.0), ("terraform", 2.0)]);
+        assert!((cosine(&a, &a) - 1.0).abs() < 1e-9);
+        assert_eq!(cosine(&a, &vector(&[("react", 3.0)])), 0.0);
+        assert_eq!(cosine(&a, &KeywordVector::new()), 0.0);
+    }
+
+    #[test]
+    fn test_cluster_groups_similar_jobs() {
+        let vectors = vec![
+            vector(&[("aws", 3.0), ("terraform", 3.0), ("kubernetes", 2.0)]),
+            vector(&[("react", 3.0), ("typescript", 3.0)]),
+            vector(&[("aws", 3.0), ("terraform", 2.0), ("python", 1.0)]),
+            vector(&[("react", 3.0), ("typescript", 2.0), ("css", 1.0)]),
+            vector(&[("cobol", 3.0)]),
+        ];
+        let groups = cluster(&vectors, 0.5);
+        assert_eq!(groups, vec![vec![0, 2], vec![1, 3], vec![4]]);
+
+        assert_eq!(cluster(&vectors, 1.1).len(), 5, "nothing merges above a perfect match");
+        assert_eq!(top_keywords(&vectors, &groups[0], 2), vec!["aws", "terraform"]);
+    }
+}
@@ -0,0 +1,126 @@
+use crate::models::{CompBand, Job};
+use crate::trends;
+
+/// Levels matched against lowercased titles, first match wins (a "Senior Staff Engineer"
+/// is staff, an "Engineering Manager, Senior" a manager). Titles matching none are "mid".
+pub const LEVELS: &[(&str, &[&str])] = &[
+    ("manager", &["manager", "director", "head of", "vp", "vice president"]),
+    ("principal", &["principal", "distinguished", "fellow"]),
+    ("staff", &["staff"]),
+    ("senior", &["senior", "sr", "lead"]),
+    ("junior", &["junior", "jr", "associate", "entry level", "new grad"]),
+    ("intern", &["intern", "internship"]),
+];
+
+/// The level a title (or a band's level label) points at. Single-word patterns must be
+/// whole words, so "Internal Tools Engineer" isn't an intern.
+pub fn level(title: &str) -> &'static str {
+    let title = title.to_lowercase();
+    let words: Vec<&str> = title.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()).collect();
+    LEVELS
+        .iter()
+        .find(|(_, patterns)| {
+            patterns.iter().any(|p| if p.contains(' ') { title.contains(p) } else { words.contains(p) })
+        })
+        .map_or("mid", |(level, _)| *level)
+}
+
+/// The band for a job's level, preferring one for the same role family.
+pub fn band_for<'a>(title: &str, bands: &'a [CompBand]) -> Option<&'a CompBand> {
+    let wanted = level(title);
+    let family = trends::role_family(title);
+    let at_level: Vec<&CompBand> = bands.iter().filter(|b| level(&b.level) == wanted).collect();
+    at_level
+        .iter()
+        .find(|b| family.is_some() && trends::role_family(&b.role) == family)
+        .or(at_level.first())
+        .copied()
+}
+
+/// The band a job's posted pay falls short of: the top of the posting is under the band's
+/// lowest base. Jobs without pay, or without a band for their level, aren't flagged.
+pub fn below_band<'a>(job: &Job, bands: &'a [CompBand]) -> Option<&'a CompBand> {
+    let posted_top = job.pay_max.or(job.pay_min)?;
+    band_for(&job.title, bands).filter(|band| band.base_min.is_some_and(|low| posted_top < low))
+}
+
+/// "$180k - $220k", one end if only one is known, "-" if neither.
+pub fn range(min: Option<i64>, max: Option<i64>) -> String {
+    let thousands = |amount: i64| format!("${}k", (amount as f64 / 1000.0).round());
+    match (min, max) {
+        (Some(min), Some(max)) if min != max => format!("{} - {}", thousands(min), thousands(max)),
+        (Some(amount), _) | (None, Some(amount)) => thousands(amount),
+        (None, None) => "-".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn band(level: &str, role: &str, base: (i64, i64)) -> CompBand {
+        CompBand {
+            level: level.to_string(),
+            role: role.to_string(),
+            base_min: Some(base.0),
+            base_max: Some(base.1),
+            total_min: None,
+            total_max: None,
+        }
+    }
+
+    fn job(title: &str, pay: (Option<i64>, Option<i64>)) -> Job {
+        Job {
+            id: 1,
+            employer_id: Some(1),
+            employer_name: Some("Acme".to_string()),
+            title: title.to_string(),
+            status: "new".to_string(),
+            pay_min: pay.0,
+            pay_max: pay.1,
+            created_at: "2026-01-01T00:00:00Z".to_string(),
+            updated_at: "2026-01-01T00:00:00Z".to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_level() {
+        assert_eq!(level("Senior Staff Engineer"), "staff");
+        assert_eq!(level("Sr. SRE"), "senior");
+        assert_eq!(level("Engineering Manager, Platform"), "manager");
+        assert_eq!(level("Principal Engineer"), "principal");
+        assert_eq!(level("Site Reliability Engineer"), "mid");
+        assert_eq!(level("Senior (L5)"), "senior");
+        assert_eq!(level("Internal Tools Engineer"), "mid", "'intern' inside a word doesn't count");
+    }
+
+    #[test]
+    fn test_band_for_prefers_role_family() {
+        let bands = vec![
+            band("senior", "Frontend Engineer", (150_000, 190_000)),
+            band("senior", "Site Reliability Engineer", (170_000, 210_000)),
+            band("staff", "Site Reliability Engineer", (210_000, 260_000)),
+        ];
+        assert_eq!(band_for("Senior SRE", &bands).unwrap().base_min, Some(170_000));
+        assert_eq!(band_for("Senior Data Engineer", &bands).unwrap().base_min, Some(150_000), "any band at the level");
+        assert!(band_for("Principal Engineer", &bands).is_none());
+    }
+
+    #[test]
+    fn test_range() {
+        assert_eq!(range(Some(180_000), Some(220_500)), "$180k - $221k");
+        assert_eq!(range(None, Some(200_000)), "$200k");
+        assert_eq!(range(None, None), "-");
+    }
+
+    #[test]
+    fn test_below_band() {
+        let bands = vec![band("senior", "Site Reliability Engineer", (170_000, 210_000))];
+        assert!(below_band(&job("Senior SRE", (Some(130_000), Some(160_000))), &bands).is_some());
+        assert!(below_band(&job("Senior SRE", (Some(150_000), Some(180_000))), &bands).is_none(), "overlaps the band");
+        assert!(below_band(&job("Senior SRE", (Some(120_000), None)), &bands).is_some());
+        assert!(below_band(&job("Senior SRE", (None, None)), &bands).is_none());
+        assert!(below_band(&job("Staff SRE", (Some(100_000), Some(120_000))), &bands).is_none(), "no staff band");
+    }
+}
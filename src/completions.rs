@@ -0,0 +1,96 @@
+use std::io::Write;
+
+use clap::ValueEnum;
+use clap_complete::CompletionCandidate;
+use clap_complete::env::{Bash, EnvCompleter, Fish, Zsh};
+
+use crate::db::Database;
+use crate::profile::Profiles;
+
+/// Environment variable the registration scripts set when asking `hunt` for completions.
+pub const VAR: &str = "COMPLETE";
+
+#[derive(Debug, Clone, Copy, ValueEnum)]
+pub enum Shell {
+    Bash,
+    Zsh,
+    Fish,
+}
+
+/// The script to source from the shell's rc file. It calls back into `hunt` on each tab
+/// press, so employer, resume and job completions follow the database.
+pub fn write_registration(shell: Shell, buf: &mut dyn Write) -> std::io::Result<()> {
+    let completer: &dyn EnvCompleter = match shell {
+        Shell::Bash => &Bash,
+        Shell::Zsh => &Zsh,
+        Shell::Fish => &Fish,
+    };
+    completer.write_registration(VAR, "hunt", "hunt", "hunt", buf)
+}
+
+/// The current profile's database. Completion can't see `--db`/`--profile` on the line
+/// being completed, and any error just means no suggestions.
+fn open() -> Option<Database> {
+    let profiles = Profiles::xdg();
+    let profile = profiles.resolve(None).ok()?;
+    Database::open_existing(&profiles.db_path(&profile))
+}
+
+pub fn employers() -> Vec<CompletionCandidate> {
+    let Some(db) = open() else { return Vec::new() };
+    db.list_employers(None)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|emp| CompletionCandidate::new(emp.name))
+        .collect()
+}
+
+pub fn resumes() -> Vec<CompletionCandidate> {
+    let Some(db) = open() else { return Vec::new() };
+    db.list_base_resumes()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|resume| CompletionCandidate::new(resume.name))
+        .collect()
+}
+
+pub fn filters() -> Vec<CompletionCandidate> {
+    let Some(db) = open() else { return Vec::new() };
+    db.list_saved_filters()
+        .unwrap_or_default()
+        .into_iter()
+        .map(|filter| CompletionCandidate::new(filter.name.clone()).help(Some(filter.describe().into())))
+        .collect()
+}
+
+/// Active job IDs, with "title @ employer" as the description where the shell shows one.
+pub fn jobs() -> Vec<CompletionCandidate> {
+    let Some(db) = open() else { return Vec::new() };
+    db.list_jobs(None, None)
+        .unwrap_or_default()
+        .into_iter()
+        .map(|job| {
+            let help = match &job.employer_name {
+                Some(employer) => format!("{} @ {}", job.title, employer),
+                None => job.title.clone(),
+            };
+            CompletionCandidate::new(job.id.to_string()).help(Some(help.into()))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_registration_calls_back_into_hunt() {
+        for shell in [Shell::Bash, Shell::Zsh, Shell::Fish] {
+            let mut script = Vec::new();
+            write_registration(shell, &mut script).unwrap();
+            let script = String::from_utf8(script).unwrap();
+            assert!(script.contains("COMPLETE="), "{:?}: {}", shell, script);
+            assert!(script.contains("hunt"));
+        }
+    }
+}
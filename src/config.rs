@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::error::HuntError;
+use crate::profile::{self, Profiles};
+use crate::smtp::SmtpConfig;
+
+/// Settings from `<config dir>/config.json`. Everything is optional; a missing file
+/// means defaults.
+#[derive(Debug, Default, Deserialize)]
+pub struct Config {
+    /// TUI key overrides: action name -> key, e.g. {"status-applied": "A"}
+    #[serde(default)]
+    pub keys: HashMap<String, String>,
+    /// TUI job list columns, in order, e.g. ["score", "salary", "age"]
+    pub columns: Option<Vec<String>>,
+    /// An existing Firefox profile to fetch with instead of hunt's own, e.g.
+    /// "~/.mozilla/firefox/abcd1234.default-release"
+    pub firefox_profile: Option<String>,
+    /// AI model for commands run without --model, e.g. "api-sonnet"
+    pub model: Option<String>,
+    /// IMAP accounts for `email`/`refresh` when none are given on the command line, as
+    /// --account specs: "USERNAME,PASSWORD_FILE[,SERVER[:PORT]]"
+    #[serde(default)]
+    pub accounts: Vec<String>,
+    /// Where you live, for commute scoring: a city ("Austin, TX") or "lat,lon"
+    pub home: Option<String>,
+    /// Seconds between fetches per domain, on top of `ratelimit::DEFAULT_BUDGETS`,
+    /// e.g. {"linkedin.com": 60, "acme.com": 3}
+    #[serde(default)]
+    pub rate_limits: HashMap<String, u64>,
+    /// Sites `fetch`/`refresh` never request, e.g. ["glassdoor.com"] (subdomains included)
+    #[serde(default)]
+    pub fetch_blocklist: Vec<String>,
+    /// Pay floor (annual USD) the browser's pay filter key turns on, e.g. 180000
+    pub min_pay: Option<i64>,
+    /// Outgoing mail for `digest --email`, `todo --email` and `mail`, e.g. {"server":
+    /// "smtp.gmail.com", "username": "me@gmail.com", "password_file": "~/.gmail.app_password.txt"}
+    pub smtp: Option<SmtpConfig>,
+}
+
+/// Model used when neither --model nor the config names one.
+pub const DEFAULT_MODEL: &str = "gpt-5.2";
+
+impl Config {
+    /// `--model` if given, else the configured model, else gpt-5.2.
+    pub fn model(&self, flag: Option<String>) -> String {
+        flag.or_else(|| self.model.clone()).unwrap_or_else(|| DEFAULT_MODEL.to_string())
+    }
+
+    /// The "smtp" section, required by anything that sends mail.
+    pub fn smtp(&self) -> Result<&SmtpConfig> {
+        self.smtp.as_ref().ok_or_else(|| {
+            HuntError::Config(format!(
+                "Sending email needs an \"smtp\" section in {} (server, username, password_file)",
+                config_path().map_or("config.json".to_string(), |p| p.display().to_string())
+            )).into()
+        })
+    }
+}
+
+/// `~/.config/hunt/config.json` on Linux, or `~/.config/hunt/profiles/<name>/config.json`
+/// for a named profile.
+pub fn config_path() -> Result<PathBuf> {
+    Ok(Profiles::xdg().config_dir(profile::active()).join("config.json"))
+}
+
+pub fn load() -> Result<Config> {
+    load_from(&config_path()?)
+}
+
+/// Settings `hunt config set` can change. The rest (keys, columns, accounts, rate_limits, fetch_blocklist, smtp) are
+/// lists and maps, edited in the file.
+pub const SETTABLE: &[&str] = &["home", "model", "firefox_profile", "min_pay"];
+
+/// The settable settings stored as numbers rather than strings.
+const NUMERIC: &[&str] = &["min_pay"];
+
+/// Set a setting in the config file, or remove it with None, leaving the rest of the
+/// file as it was.
+pub fn set_value(path: &Path, key: &str, value: Option<&str>) -> Result<()> {
+    if !SETTABLE.contains(&key) {
+        return Err(HuntError::Validation(format!(
+            "Unknown setting '{}'. Settable: {} (edit {} for the rest)", key, SETTABLE.join(", "), path.display()
+        )).into());
+    }
+    let mut settings: serde_json::Map<String, serde_json::Value> = match fs::read_to_string(path) {
+        Ok(text) => serde_json::from_str(&text)
+            .map_err(|e| HuntError::Config(format!("Invalid config file {}: {}", path.display(), e)))?,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => serde_json::Map::new(),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read config file: {:?}", path)),
+    };
+    match value {
+        Some(value) if NUMERIC.contains(&key) => {
+            let number: i64 = value.trim().parse()
+                .map_err(|_| HuntError::Validation(format!("'{}' must be a whole number, got '{}'", key, value)))?;
+            settings.insert(key.to_string(), number.into())
+        }
+        Some(value) => settings.insert(key.to_string(), value.into()),
+        None => settings.remove(key),
+    };
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    fs::write(path, serde_json::to_string_pretty(&settings)? + "\n")
+        .with_context(|| format!("Failed to write config file: {:?}", path))
+}
+
+pub fn load_from(path: &Path) -> Result<Config> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(Config::default()),
+        Err(e) => return Err(e).with_context(|| format!("Failed to read config file: {:?}", path)),
+    };
+    serde_json::from_str(&text)
+        .map_err(|e| HuntError::Config(format!("Invalid config file {}: {}", path.display(), e)).into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_from() {
+        let dir = std::env::temp_dir().join(format!("hunt-config-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.json");
+
+        assert!(load_from(&path).unwrap().keys.is_empty(), "missing file means defaults");
+
+        fs::write(&path, r#"{"keys": {"status-applied": "A"}}"#).unwrap();
+        assert_eq!(load_from(&path).unwrap().keys["status-applied"], "A");
+
+        fs::write(&path, r#"{"columns": ["age", "fit"]}"#).unwrap();
+        assert_eq!(load_from(&path).unwrap().columns, Some(vec!["age".to_string(), "fit".to_string()]));
+
+        fs::write(&path, "{}").unwrap();
+        let config = load_from(&path).unwrap();
+        assert!(config.keys.is_empty());
+        assert!(config.columns.is_none());
+        assert!(config.firefox_profile.is_none());
+        assert_eq!(config.model(None), DEFAULT_MODEL);
+        assert!(config.accounts.is_empty());
+
+        fs::write(&path, r#"{"model": "api-sonnet", "accounts": ["me@example.com,~/.pw,imap.example.com"]}"#).unwrap();
+        let config = load_from(&path).unwrap();
+        assert_eq!(config.model(None), "api-sonnet");
+        assert_eq!(config.model(Some("gpt-4o".to_string())), "gpt-4o", "--model wins");
+        assert_eq!(config.accounts.len(), 1);
+
+        fs::write(&path, r#"{"firefox_profile": "~/.mozilla/firefox/x.default"}"#).unwrap();
+        assert_eq!(load_from(&path).unwrap().firefox_profile.as_deref(), Some("~/.mozilla/firefox/x.default"));
+
+        fs::write(&path, r#"{"home": "Austin, TX"}"#).unwrap();
+        assert_eq!(load_from(&path).unwrap().home.as_deref(), Some("Austin, TX"));
+
+        fs::write(&path, r#"{"smtp": {"server": "smtp.gmail.com", "username": "me@gmail.com", "password_file": "~/.pw"}}"#).unwrap();
+        let config = load_from(&path).unwrap();
+        let smtp = config.smtp().unwrap();
+        assert_eq!((smtp.port, smtp.recipient()), (587, "me@gmail.com"));
+        assert!(load_from(&dir.join("none.json")).unwrap().smtp().is_err());
+
+        fs::write(&path, r#"{"keys": ["A"]}"#).unwrap();
+        let err = load_from(&path).unwrap_err();
+        assert!(matches!(err.downcast_ref::<HuntError>(), Some(HuntError::Config(_))));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_set_value() {
+        let dir = std::env::temp_dir().join(format!("hunt-config-set-test-{}", std::process::id()));
+        let path = dir.join("config.json");
+
+        set_value(&path, "home", Some("Austin, TX")).unwrap();
+        assert_eq!(load_from(&path).unwrap().home.as_deref(), Some("Austin, TX"), "creates the file");
+
+        fs::write(&path, r#"{"keys": {"status-applied": "A"}, "home": "Denver"}"#).unwrap();
+        set_value(&path, "model", Some("api-sonnet")).unwrap();
+        set_value(&path, "home", None).unwrap();
+        let config = load_from(&path).unwrap();
+        assert_eq!(config.keys["status-applied"], "A", "other settings kept");
+        assert_eq!(config.model(None), "api-sonnet");
+        assert!(config.home.is_none());
+
+        let err = set_value(&path, "keys", Some("x")).unwrap_err();
+        assert!(matches!(err.downcast_ref::<HuntError>(), Some(HuntError::Validation(_))));
+
+        set_value(&path, "min_pay", Some("180000")).unwrap();
+        assert_eq!(load_from(&path).unwrap().min_pay, Some(180000), "stored as a number");
+        assert!(set_value(&path, "min_pay", Some("180k")).is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
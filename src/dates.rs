@@ -0,0 +1,230 @@
+use std::sync::LazyLock;
+
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use regex::Regex;
+
+use crate::models::Job;
+
+/// Days before its deadline a job counts as closing soon.
+pub const CLOSING_SOON_DAYS: i64 = 7;
+
+/// A date as postings write it: 2025-08-01, 8/1/2025, August 1, 2025, 1 Aug 2025, Aug 1.
+const DATE: &str = r"(\d{4}-\d{2}-\d{2}|\d{1,2}/\d{1,2}/\d{2,4}\b|[a-z]{3,9}\.? \d{1,2}(?:st|nd|rd|th)?\b(?:,? \d{4})?|\d{1,2}(?:st|nd|rd|th)? [a-z]{3,9}\.?(?:,? \d{4})?)";
+
+// "Posted 3 days ago", "Reposted 2 weeks ago", "Posted 30+ days ago"
+static POSTED_AGO: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:re)?posted:? (?:on )?(\d+|an?|one)\+? (minute|hour|day|week|month)s? ago\b").unwrap()
+});
+static POSTED_DAY: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(r"(?i)\b(?:re)?posted:? (today|yesterday|just now)\b").unwrap()
+});
+static POSTED_ON: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(r"(?i)\b(?:date posted|posting date|posted on|posted|published on|published):? {}", DATE)).unwrap()
+});
+static DEADLINE: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)\b(?:apply by|apply before|application deadline|deadline to apply|deadline|closing date|applications? (?:close|closes|due|accepted until)(?: on| by)?|accepting applications (?:until|through)):? {}",
+        DATE
+    )).unwrap()
+});
+
+/// When a posting went up and when applications close, as far as its text says.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PostingDates {
+    pub posted: Option<NaiveDate>,
+    pub deadline: Option<NaiveDate>,
+}
+
+impl PostingDates {
+    pub fn posted_at(&self) -> Option<String> {
+        self.posted.map(format_day)
+    }
+
+    pub fn deadline(&self) -> Option<String> {
+        self.deadline.map(format_day)
+    }
+}
+
+/// Today, in local time: deadlines are calendar days where the employer is.
+pub fn today() -> NaiveDate {
+    Local::now().date_naive()
+}
+
+/// How `jobs.posted_at` and `jobs.apply_deadline` are stored.
+pub fn format_day(date: NaiveDate) -> String {
+    date.format("%Y-%m-%d").to_string()
+}
+
+pub fn parse_day(value: &str) -> Option<NaiveDate> {
+    NaiveDate::parse_from_str(value.trim(), "%Y-%m-%d").ok()
+}
+
+const MONTHS: [&str; 12] = ["jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec"];
+
+/// One `DATE` match. Without a year it's the nearest such day in the future for a
+/// deadline, in the past for a posting date. Slashed dates are month first.
+fn parse_date(text: &str, today: NaiveDate, future: bool) -> Option<NaiveDate> {
+    if let Some(date) = parse_day(text) {
+        return Some(date);
+    }
+    if let [month, day, year] = text.split('/').collect::<Vec<_>>()[..] {
+        let year: i32 = year.parse().ok()?;
+        let year = if year < 100 { 2000 + year } else { year };
+        return NaiveDate::from_ymd_opt(year, month.parse().ok()?, day.parse().ok()?);
+    }
+
+    let cleaned = text.replace([',', '.'], " ").to_lowercase();
+    let words: Vec<&str> = cleaned.split_whitespace().collect();
+    let month_of = |word: &str| MONTHS.iter().position(|m| word.starts_with(m)).map(|i| i as u32 + 1);
+    let day_of = |word: &str| word.trim_end_matches(|c: char| c.is_ascii_alphabetic()).parse::<u32>().ok();
+    let (month, day) = match words[..] {
+        [first, second, ..] if month_of(first).is_some() => (month_of(first)?, day_of(second)?),
+        [first, second, ..] => (month_of(second)?, day_of(first)?),
+        _ => return None,
+    };
+    if let Some(year) = words.get(2).and_then(|y| y.parse().ok()) {
+        return NaiveDate::from_ymd_opt(year, month, day);
+    }
+    let this_year = NaiveDate::from_ymd_opt(today.year(), month, day)?;
+    if future && this_year < today {
+        this_year.with_year(today.year() + 1)
+    } else if !future && this_year > today {
+        this_year.with_year(today.year() - 1)
+    } else {
+        Some(this_year)
+    }
+}
+
+/// The posting date and application deadline a description or alert states, with
+/// relative ones ("posted 3 days ago") counted back from `today`.
+pub fn from_text(text: &str, today: NaiveDate) -> PostingDates {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let ago = POSTED_AGO.captures(&text).and_then(|cap| {
+        let amount = match cap[1].to_lowercase().as_str() {
+            "a" | "an" | "one" => 1,
+            n => n.parse().ok()?,
+        };
+        let days = match &cap[2].to_lowercase()[..] {
+            "day" => amount,
+            "week" => amount * 7,
+            "month" => amount * 30,
+            _ => 0,
+        };
+        Some(today - Duration::days(days))
+    });
+    let named = || {
+        POSTED_DAY.captures(&text).map(|cap| match cap[1].to_lowercase().as_str() {
+            "yesterday" => today - Duration::days(1),
+            _ => today,
+        })
+    };
+    let posted = ago
+        .or_else(named)
+        .or_else(|| POSTED_ON.captures_iter(&text).find_map(|cap| parse_date(&cap[1], today, false)));
+    let deadline = DEADLINE.captures_iter(&text).find_map(|cap| parse_date(&cap[1], today, true));
+    PostingDates { posted, deadline }
+}
+
+/// Whole days from `today` to the job's deadline; negative once it has passed.
+pub fn days_left(job: &Job, today: NaiveDate) -> Option<i64> {
+    let deadline = parse_day(job.apply_deadline.as_deref()?)?;
+    Some((deadline - today).num_days())
+}
+
+/// Days left on a deadline worth warning about: within `CLOSING_SOON_DAYS` or already
+/// passed, on a job that hasn't been applied to yet.
+pub fn closing_soon(job: &Job, today: NaiveDate) -> Option<i64> {
+    if !matches!(job.status.as_str(), "new" | "reviewing") {
+        return None;
+    }
+    days_left(job, today).filter(|&days| days <= CLOSING_SOON_DAYS)
+}
+
+/// "2025-07-17 (3 days ago)"
+pub fn posted_label(job: &Job, today: NaiveDate) -> Option<String> {
+    let posted = job.posted_at.as_deref()?;
+    let days = parse_day(posted).map_or(0, |date| (today - date).num_days());
+    Some(match days {
+        0 => format!("{} (today)", posted),
+        1 => format!("{} (yesterday)", posted),
+        d => format!("{} ({} days ago)", posted, d),
+    })
+}
+
+/// "2025-08-01 (closes in 12 days)"
+pub fn deadline_label(job: &Job, today: NaiveDate) -> Option<String> {
+    let days = days_left(job, today)?;
+    Some(format!("{} ({})", job.apply_deadline.as_deref()?, closing_label(days)))
+}
+
+/// "closes in 3 days", "closes today", "closed 2 days ago".
+pub fn closing_label(days: i64) -> String {
+    match days {
+        0 => "closes today".to_string(),
+        1 => "closes tomorrow".to_string(),
+        -1 => "closed yesterday".to_string(),
+        d if d < 0 => format!("closed {} days ago", -d),
+        d => format!("closes in {} days", d),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_from_text() {
+        let today = day(2025, 7, 20);
+        let dates = |text: &str| from_text(text, today);
+
+        assert_eq!(dates("SRE\nPosted 3 days ago · 120 applicants").posted, Some(day(2025, 7, 17)));
+        assert_eq!(dates("Reposted 2 weeks ago").posted, Some(day(2025, 7, 6)));
+        assert_eq!(dates("Posted 30+ days ago").posted, Some(day(2025, 6, 20)));
+        assert_eq!(dates("Posted an hour ago").posted, Some(today));
+        assert_eq!(dates("Posted yesterday").posted, Some(day(2025, 7, 19)));
+        assert_eq!(dates("Date posted: July 1, 2025").posted, Some(day(2025, 7, 1)));
+        assert_eq!(dates("Posted on 07/02/2025").posted, Some(day(2025, 7, 2)));
+        assert_eq!(dates("Posted: Dec 30").posted, Some(day(2024, 12, 30)), "no year: the last one");
+
+        let text = "Senior SRE\nApplication deadline: August 1, 2025\nApply by 2025-09-01";
+        assert_eq!(dates(text), PostingDates { posted: None, deadline: Some(day(2025, 8, 1)) });
+        assert_eq!(dates("Applications close on 15th August 2025.").deadline, Some(day(2025, 8, 15)));
+        assert_eq!(dates("Closing date: 8/31/25").deadline, Some(day(2025, 8, 31)));
+        assert_eq!(dates("Apply before Jan 5").deadline, Some(day(2026, 1, 5)), "no year: the next one");
+        assert_eq!(dates("accepting applications\nuntil Sept. 3, 2025").deadline, Some(day(2025, 9, 3)));
+
+        // Words that aren't dates, or dates that don't exist
+        assert_eq!(dates("We posted record growth. Deadline driven team, apply by email."), PostingDates::default());
+        assert_eq!(dates("Deadline: 2/30/2025").deadline, None);
+        assert_eq!(dates("Apply by June 2026").deadline, None, "a month isn't a day");
+    }
+
+    #[test]
+    fn test_days_left() {
+        let today = day(2025, 7, 20);
+        let job = |deadline: Option<&str>| Job {
+            status: "new".to_string(),
+            apply_deadline: deadline.map(String::from),
+            ..Default::default()
+        };
+        assert_eq!(days_left(&job(Some("2025-07-23")), today), Some(3));
+        assert_eq!(days_left(&job(Some("2025-07-18")), today), Some(-2));
+        assert_eq!(days_left(&job(None), today), None);
+
+        assert_eq!(closing_soon(&job(Some("2025-07-23")), today), Some(3));
+        assert_eq!(closing_soon(&job(Some("2025-07-18")), today), Some(-2));
+        assert_eq!(closing_soon(&job(Some("2025-09-01")), today), None);
+        let applied = Job { status: "applied".to_string(), ..job(Some("2025-07-23")) };
+        assert_eq!(closing_soon(&applied, today), None);
+        assert_eq!(deadline_label(&applied, today).as_deref(), Some("2025-07-23 (closes in 3 days)"));
+        let posted = Job { posted_at: Some("2025-07-17".to_string()), ..Default::default() };
+        assert_eq!(posted_label(&posted, today).as_deref(), Some("2025-07-17 (3 days ago)"));
+
+        assert_eq!(closing_label(0), "closes today");
+        assert_eq!(closing_label(-2), "closed 2 days ago");
+    }
+}
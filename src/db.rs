@@ -1,8 +1,130 @@
-use anyhow::{anyhow, Context, Result};
+use anyhow::{Context, Result};
 use rusqlite::{params, Connection};
-use std::path::PathBuf;
-
-use crate::models::{BaseResume, Employer, FitAnalysis, GlassdoorReview, Job, JobKeyword, JobKeywordProfile, ResumeVariant};
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use crate::ai::{FundingResearch, LearningStep};
+use crate::dates;
+use crate::domains::{self, EmployerLinks};
+use crate::employers::{normalize_name, EmployerEntry};
+use crate::error::HuntError;
+use crate::flags::{self, Flag};
+use crate::geo;
+use crate::location;
+use crate::pay;
+use crate::review::CardState;
+use crate::timestamps;
+use crate::models::{BaseResume, CompBand, Contact, Employer, EmployerAlert, EmployerAlias, FetchDomain, FitAnalysis, GlassdoorReview, GlassdoorSnapshot, HnStory, Job, JobKeyword, JobCluster, JobFlag, JobKeywordProfile, JobNote, JobSnapshot, JobStatus, JobSummary, JobSummaryBullets, LearningItem, NegotiationAnchors, NegotiationBrief, NextAction, PipelineRun, PrepNote, PrepQuestion, RankRule, ResumeVariant, ReviewCard, SavedFilter, StatusChange};
+
+/// Every timestamp column, so databases from before timestamps were stored as
+/// RFC3339 UTC can be converted in place.
+const TIMESTAMP_COLUMNS: &[(&str, &[&str])] = &[
+    ("employers", &[
+        "created_at", "updated_at", "research_updated_at", "public_research_updated_at",
+        "ownership_research_updated", "last_glassdoor_fetch", "eng_research_updated", "funding_checked_at",
+    ]),
+    ("jobs", &["fetched_at", "created_at", "updated_at", "applied_at", "rejected_at", "closed_at"]),
+    ("job_snapshots", &["captured_at"]),
+    ("base_resumes", &["created_at", "updated_at"]),
+    ("resume_variants", &["created_at"]),
+    ("job_keywords", &["created_at"]),
+    ("job_keyword_profiles", &["created_at"]),
+    ("fit_analyses", &["created_at"]),
+    ("job_summaries", &["created_at"]),
+    ("prep_notes", &["created_at"]),
+    ("negotiation_briefs", &["created_at"]),
+    ("glassdoor_reviews", &["captured_at"]),
+    ("glassdoor_snapshots", &["fetched_at"]),
+    ("job_notes", &["created_at"]),
+    ("job_tags", &["created_at"]),
+    ("status_history", &["changed_at"]),
+    ("employer_alerts", &["created_at"]),
+    ("employer_hn_stories", &["fetched_at"]),
+    ("employer_comp", &["created_at"]),
+    ("contacts", &["created_at"]),
+    ("job_contacts", &["created_at"]),
+    ("job_next_actions", &["created_at"]),
+    ("job_flags", &["created_at"]),
+    ("pipeline_runs", &["started_at", "finished_at"]),
+    ("pipeline_run_steps", &["completed_at"]),
+    ("fetch_domains", &["last_success_at", "last_failure_at", "backoff_until"]),
+    ("employer_aliases", &["created_at"]),
+    ("user_skills", &["created_at"]),
+    ("negative_keywords", &["created_at"]),
+    ("saved_filters", &["created_at"]),
+    ("ai_cache", &["created_at", "last_hit_at"]),
+    ("learning_plans", &["created_at"]),
+    ("learning_items", &["done_at"]),
+    ("job_clusters", &["created_at"]),
+    ("ai_usage", &["created_at"]),
+    ("review_cards", &["due_at", "last_reviewed_at", "created_at"]),
+];
+
+/// The columns `row_to_job` reads, in order, from `jobs j LEFT JOIN employers e`.
+const JOB_COLUMNS: &str = "j.id, j.employer_id, e.name, j.title, j.url, j.source, j.status, \
+    j.pay_min, j.pay_max, j.job_code, j.raw_text, j.fetched_at, j.created_at, j.updated_at, \
+    j.location, j.work_mode, j.pay_text, j.pay_equity, j.archived_at, \
+    j.applied_at, j.rejected_at, j.closed_at, j.posted_at, j.apply_deadline, j.easy_apply, \
+    j.apply_url, j.ats, j.requires_clearance, j.sponsors_visa";
+
+/// Tag that puts a job at the front of the keyword backfill queue.
+const SHORTLIST_TAG: &str = "shortlist";
+
+const SAVED_FILTER_COLUMNS: &str = "name, status, employer, tag, keyword, min_pay, max_pay, remote, location, easy_apply, sponsors_visa, no_clearance, created_at";
+
+const FETCH_DOMAIN_COLUMNS: &str =
+    "domain, successes, failures, failure_streak, last_error, last_success_at, last_failure_at, backoff_until";
+
+/// Also created by `AiCache::open`, which may run against a fresh in-memory connection.
+const AI_CACHE_SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS ai_cache (
+        key TEXT PRIMARY KEY,   -- sha256 of model, max_tokens and prompt (ai::cache_key)
+        model TEXT NOT NULL,
+        response TEXT NOT NULL,
+        hits INTEGER NOT NULL DEFAULT 0,
+        created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+        last_hit_at TEXT
+    );";
+
+/// Also created by `AiUsageLog::open`.
+const AI_USAGE_SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS ai_usage (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        command TEXT NOT NULL,
+        model TEXT NOT NULL,
+        input_tokens INTEGER,   -- NULL when the provider doesn't report usage (claude CLI)
+        output_tokens INTEGER,
+        cost_usd REAL,          -- estimate at list price; NULL when unpriced
+        created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+    );
+    CREATE INDEX IF NOT EXISTS idx_ai_usage_created ON ai_usage(created_at);";
+
+/// Pipeline statuses seeded into a fresh database: (name, position, is_terminal, color)
+const DEFAULT_STATUSES: &[(&str, i64, bool, &str)] = &[
+    ("new", 10, false, "green"),
+    ("reviewing", 20, false, "yellow"),
+    ("applied", 30, false, "cyan"),
+    ("interviewing", 40, false, "magenta"),
+    ("offer", 50, false, "lightgreen"),
+    ("accepted", 60, true, "green"),
+    ("rejected", 70, true, "red"),
+    ("withdrawn", 80, true, "gray"),
+    ("closed", 90, true, "darkgray"),
+];
+
+/// The built-in status pipeline, used to seed `job_statuses`
+pub fn default_job_statuses() -> Vec<JobStatus> {
+    DEFAULT_STATUSES
+        .iter()
+        .map(|(name, position, is_terminal, color)| JobStatus {
+            name: name.to_string(),
+            position: *position,
+            is_terminal: *is_terminal,
+            color: color.to_string(),
+        })
+        .collect()
+}
 
 pub struct DestructionStats {
     pub jobs: i64,
@@ -22,19 +144,263 @@ impl DestructionStats {
     }
 }
 
+/// Employer statuses whose jobs AI commands skip unless `--include-blocked`.
+pub const BLOCKED_EMPLOYER_STATUSES: &[&str] = &["never", "yuck"];
+
+/// Tables with an `employer_id` column, re-pointed when employers are merged.
+const EMPLOYER_CHILD_TABLES: &[&str] = &[
+    "jobs",
+    "glassdoor_reviews",
+    "glassdoor_snapshots",
+    "employer_alerts",
+    "employer_hn_stories",
+    "employer_comp",
+    "employer_aliases",
+    "contacts",
+];
+
+/// Blocking statuses, least to most severe: a merge keeps the more severe one.
+const EMPLOYER_STATUS_SEVERITY: &[&str] = &["ok", "yuck", "never"];
+
+/// Tables whose rows belong to a single job (via `job_id`).
+const JOB_CHILD_TABLES: &[&str] = &[
+    "job_snapshots",
+    "resume_variants",
+    "job_keywords",
+    "job_keyword_profiles",
+    "fit_analyses",
+    "job_summaries",
+    "prep_notes",
+    "negotiation_briefs",
+    "job_notes",
+    "job_tags",
+    "job_cluster_members",
+    "job_contacts",
+    "job_next_actions",
+    "job_flags",
+    "status_history",
+];
+
+#[derive(Debug, Default)]
+pub struct CompactStats {
+    pub snapshots_pruned: i64,
+    pub jobs_archived: i64,
+}
+
+/// A keyword active jobs ask for that none of the base resumes mention.
+#[derive(Debug, Clone)]
+pub struct KeywordGap {
+    pub keyword: String,
+    pub domain: String,
+    pub total_weight: i64, // summed over jobs (3 = required in one job)
+    pub jobs: i64,
+}
+
+/// How much open jobs ask for one keyword, for `hunt keywords --report`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct KeywordDemand {
+    pub keyword: String,
+    pub domain: String,
+    pub total_weight: i64, // summed over jobs
+    pub jobs: i64,
+    pub required: i64, // jobs where it's a hard requirement (weight 3)
+}
+
+#[derive(Debug, Default)]
+pub struct AiCacheStats {
+    pub entries: i64,
+    pub hits: i64,
+    pub bytes: i64,
+    pub by_model: Vec<(String, i64, i64)>, // (model, entries, hits)
+}
+
+/// Cached AI responses. Holds its own connection behind a mutex so the provider
+/// wrapping it can be shared by `ai::run_batch` worker threads.
+pub struct AiCache {
+    conn: Mutex<Connection>,
+}
+
+/// A separate connection for use from worker threads, creating `schema` if needed.
+fn open_shared(path: &Path, schema: &str) -> Result<Mutex<Connection>> {
+    let conn = Connection::open(path)?;
+    conn.busy_timeout(std::time::Duration::from_secs(5))?;
+    conn.execute_batch(schema)?;
+    Ok(Mutex::new(conn))
+}
+
+fn lock_shared(conn: &Mutex<Connection>) -> std::sync::MutexGuard<'_, Connection> {
+    conn.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl AiCache {
+    pub fn open(path: &Path) -> Result<Self> {
+        Ok(Self { conn: open_shared(path, AI_CACHE_SCHEMA)? })
+    }
+
+    fn conn(&self) -> std::sync::MutexGuard<'_, Connection> {
+        lock_shared(&self.conn)
+    }
+
+    pub fn get(&self, key: &str) -> Result<Option<String>> {
+        let conn = self.conn();
+        let response = match conn.query_row(
+            "SELECT response FROM ai_cache WHERE key = ?1",
+            [key],
+            |row| row.get::<_, String>(0),
+        ) {
+            Ok(response) => Some(response),
+            Err(rusqlite::Error::QueryReturnedNoRows) => None,
+            Err(e) => return Err(e.into()),
+        };
+        if response.is_some() {
+            conn.execute(
+                "UPDATE ai_cache SET hits = hits + 1, last_hit_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE key = ?1",
+                [key],
+            )?;
+        }
+        Ok(response)
+    }
+
+    pub fn put(&self, key: &str, model: &str, response: &str) -> Result<()> {
+        self.conn().execute(
+            "INSERT OR REPLACE INTO ai_cache (key, model, response) VALUES (?1, ?2, ?3)",
+            params![key, model, response],
+        )?;
+        Ok(())
+    }
+
+    pub fn stats(&self) -> Result<AiCacheStats> {
+        let conn = self.conn();
+        let (entries, hits, bytes) = conn.query_row(
+            "SELECT COUNT(*), COALESCE(SUM(hits), 0), COALESCE(SUM(LENGTH(response)), 0) FROM ai_cache",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )?;
+        let mut stmt = conn.prepare(
+            "SELECT model, COUNT(*), SUM(hits) FROM ai_cache GROUP BY model ORDER BY COUNT(*) DESC, model",
+        )?;
+        let by_model = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(AiCacheStats { entries, hits, bytes, by_model })
+    }
+
+    /// Delete cached responses (only one model's, if given); returns how many.
+    pub fn clear(&self, model: Option<&str>) -> Result<usize> {
+        let conn = self.conn();
+        let removed = match model {
+            Some(model) => conn.execute("DELETE FROM ai_cache WHERE model = ?1", [model])?,
+            None => conn.execute("DELETE FROM ai_cache", [])?,
+        };
+        Ok(removed)
+    }
+}
+
+/// Token and cost totals for one model or command, for `hunt usage`.
+#[derive(Debug, Clone)]
+pub struct AiUsageTotal {
+    pub key: String, // model or command
+    pub calls: i64,
+    pub input_tokens: i64,
+    pub output_tokens: i64,
+    pub cost_usd: f64,
+    pub unpriced_calls: i64, // calls without a cost estimate
+}
+
+/// Appends one row per AI call to `ai_usage`; like `AiCache`, shareable across threads.
+pub struct AiUsageLog {
+    conn: Mutex<Connection>,
+}
+
+impl AiUsageLog {
+    pub fn open(path: &Path) -> Result<Self> {
+        Ok(Self { conn: open_shared(path, AI_USAGE_SCHEMA)? })
+    }
+
+    pub fn record(
+        &self,
+        command: &str,
+        model: &str,
+        tokens: Option<(i64, i64)>,
+        cost_usd: Option<f64>,
+    ) -> Result<()> {
+        lock_shared(&self.conn).execute(
+            "INSERT INTO ai_usage (command, model, input_tokens, output_tokens, cost_usd)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![command, model, tokens.map(|t| t.0), tokens.map(|t| t.1), cost_usd],
+        )?;
+        Ok(())
+    }
+}
+
+/// What happened in the database since a cutoff, for `hunt journal`.
+#[derive(Debug, Default)]
+pub struct JournalActivity {
+    pub jobs_added: Vec<Job>,
+    pub jobs_progressed: Vec<Job>,      // past "new" and touched since the cutoff
+    pub fit_analyses: Vec<(Job, f64)>,  // job, fit score
+    pub variants: Vec<(Job, String)>,   // job, base resume name
+    pub keywords_extracted: i64,        // jobs with keywords extracted since the cutoff
+    pub employers_researched: Vec<String>,
+    pub notes: Vec<(Job, String)>,      // job, note text
+}
+
+/// What `hunt digest` summarizes for one window.
+#[derive(Debug, Default)]
+pub struct DigestActivity {
+    pub new_jobs: Vec<Job>,
+    pub applied: Vec<Job>,               // first applied to in the window
+    pub interviews: Vec<Job>,            // moved into interviewing in the window
+    pub top_unapplied: Vec<(Job, f64)>,  // best-ranked active jobs never applied to, with their score
+    pub employers_researched: Vec<Employer>,
+}
+
+/// A job for `add_jobs_batch`, as an email alert lists it.
+#[derive(Debug, Clone, Default)]
+pub struct NewJob {
+    pub title: String,
+    pub employer: Option<String>,
+    pub url: Option<String>,
+    pub source: Option<String>,
+    pub pay_min: Option<i64>,
+    pub pay_max: Option<i64>,
+    pub raw_text: Option<String>,
+    pub location: Option<String>,
+    pub work_mode: Option<&'static str>,
+    pub posted_at: Option<String>,      // YYYY-MM-DD
+    pub apply_deadline: Option<String>, // YYYY-MM-DD
+}
+
 pub struct Database {
     conn: Connection,
     path: PathBuf,
 }
 
 impl Database {
-    pub fn open() -> Result<Self> {
-        let path = Self::default_path()?;
+    /// Open `path` if given, else `$HUNT_DB`, else `profile_path` (the profile's database).
+    /// A profile named with `--profile` (`explicit_profile`) wins over `$HUNT_DB`.
+    pub fn open(path: Option<&Path>, profile_path: &Path, explicit_profile: bool) -> Result<Self> {
+        Self::open_at(&Self::resolve_path(path, std::env::var("HUNT_DB").ok().as_deref(), profile_path, explicit_profile))
+    }
+
+    /// Open the database `open` would, without `--db`, only if it already exists: shell
+    /// completion runs on every tab press and mustn't leave empty databases behind.
+    pub fn open_existing(profile_path: &Path) -> Option<Self> {
+        let path = Self::resolve_path(None, std::env::var("HUNT_DB").ok().as_deref(), profile_path, false);
+        if !path.is_file() {
+            return None;
+        }
+        Self::open_at(&path).ok()
+    }
+
+    /// Open the database at `path`. Other connections to the same file (the TUI's
+    /// background actions) may be writing, so wait on locks rather than failing.
+    pub fn open_at(path: &Path) -> Result<Self> {
         if let Some(parent) = path.parent() {
             std::fs::create_dir_all(parent)?;
         }
-        let conn = Connection::open(&path)?;
-        Ok(Self { conn, path })
+        let conn = Connection::open(path)?;
+        conn.busy_timeout(std::time::Duration::from_secs(5))?;
+        Ok(Self { conn, path: path.to_path_buf() })
     }
 
     pub fn path(&self) -> &PathBuf {
@@ -48,13 +414,15 @@ impl Database {
         Ok(Self { conn, path: PathBuf::from(":memory:") })
     }
 
-    fn default_path() -> Result<PathBuf> {
-        // Use XDG data directory or fallback
-        if let Some(proj_dirs) = directories::ProjectDirs::from("", "", "hunt") {
-            Ok(proj_dirs.data_dir().join("hunt.db"))
-        } else {
-            // Fallback to current directory
-            Ok(PathBuf::from("hunt.db"))
+    /// Where to keep the database: the `--db` flag, then an explicit `--profile`'s
+    /// database, then a non-empty `HUNT_DB`, then the current profile's database. The
+    /// profile beats `HUNT_DB` because it also picks the config (accounts, model), which
+    /// must not be paired with another search's data.
+    fn resolve_path(flag: Option<&Path>, env: Option<&str>, profile_path: &Path, explicit_profile: bool) -> PathBuf {
+        match (flag, env.filter(|v| !v.trim().is_empty())) {
+            (Some(path), _) => path.to_path_buf(),
+            (None, Some(path)) if !explicit_profile => PathBuf::from(path),
+            (None, _) => profile_path.to_path_buf(),
         }
     }
 
@@ -67,8 +435,8 @@ impl Database {
                 domain TEXT,
                 status TEXT NOT NULL DEFAULT 'ok' CHECK (status IN ('ok', 'yuck', 'never')),
                 notes TEXT,
-                created_at TEXT NOT NULL DEFAULT (datetime('now')),
-                updated_at TEXT NOT NULL DEFAULT (datetime('now')),
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
                 crunchbase_url TEXT,
                 funding_stage TEXT,
                 total_funding INTEGER,
@@ -94,7 +462,14 @@ impl Database {
                 ownership_research_updated TEXT,
                 glassdoor_rating REAL,
                 glassdoor_review_count INTEGER,
-                last_glassdoor_fetch TEXT
+                last_glassdoor_fetch TEXT,
+                eng_blog_url TEXT,
+                eng_rss_url TEXT,
+                eng_talks TEXT,
+                eng_culture_summary TEXT,
+                eng_research_updated TEXT,
+                funding_checked_at TEXT,
+                careers_url TEXT
             );
 
             CREATE TABLE IF NOT EXISTS jobs (
@@ -103,25 +478,51 @@ impl Database {
                 title TEXT NOT NULL,
                 url TEXT,
                 source TEXT,
-                status TEXT NOT NULL DEFAULT 'new' CHECK (status IN ('new', 'reviewing', 'applied', 'rejected', 'closed')),
+                status TEXT NOT NULL DEFAULT 'new',
                 pay_min INTEGER,
                 pay_max INTEGER,
                 job_code TEXT,
                 raw_text TEXT,
                 fetched_at TEXT,
-                created_at TEXT NOT NULL DEFAULT (datetime('now')),
-                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                location TEXT,
+                work_mode TEXT,
+                pay_text TEXT,
+                pay_equity INTEGER NOT NULL DEFAULT 0,
+                archived_at TEXT,
+                applied_at TEXT,
+                rejected_at TEXT,
+                closed_at TEXT,
+                posted_at TEXT,
+                apply_deadline TEXT,
+                easy_apply INTEGER,
+                apply_url TEXT,
+                ats TEXT,
+                requires_clearance INTEGER, -- NULL until a description is read
+                sponsors_visa INTEGER       -- NULL unless the description says either way
+            );
+
+            CREATE TABLE IF NOT EXISTS job_statuses (
+                name TEXT PRIMARY KEY,
+                position INTEGER NOT NULL,
+                is_terminal INTEGER NOT NULL DEFAULT 0,
+                color TEXT NOT NULL DEFAULT 'white'
             );
 
             CREATE TABLE IF NOT EXISTS job_snapshots (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
                 job_id INTEGER NOT NULL REFERENCES jobs(id),
                 raw_text TEXT NOT NULL,
-                captured_at TEXT NOT NULL DEFAULT (datetime('now'))
+                captured_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
             );
 
             CREATE INDEX IF NOT EXISTS idx_jobs_employer ON jobs(employer_id);
             CREATE INDEX IF NOT EXISTS idx_jobs_status ON jobs(status);
+            CREATE INDEX IF NOT EXISTS idx_jobs_url ON jobs(url);
+            CREATE INDEX IF NOT EXISTS idx_jobs_title_norm ON jobs(lower(trim(title)));
+            CREATE INDEX IF NOT EXISTS idx_jobs_pay_top ON jobs(COALESCE(pay_max, pay_min));
+            CREATE INDEX IF NOT EXISTS idx_jobs_pay_bottom ON jobs(COALESCE(pay_min, pay_max));
             CREATE INDEX IF NOT EXISTS idx_snapshots_job ON job_snapshots(job_id);
 
             CREATE TABLE IF NOT EXISTS base_resumes (
@@ -130,8 +531,9 @@ impl Database {
                 format TEXT NOT NULL CHECK (format IN ('markdown', 'plain', 'json', 'latex')),
                 content TEXT NOT NULL,
                 notes TEXT,
-                created_at TEXT NOT NULL DEFAULT (datetime('now')),
-                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+                content_hash TEXT,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
             );
 
             CREATE TABLE IF NOT EXISTS resume_variants (
@@ -142,8 +544,10 @@ impl Database {
                 tailoring_notes TEXT,
                 source_model TEXT,
                 output_format TEXT,
-                created_at TEXT NOT NULL DEFAULT (datetime('now')),
-                UNIQUE(base_resume_id, job_id, source_model, output_format)
+                version INTEGER NOT NULL DEFAULT 1,
+                base_content_hash TEXT,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                UNIQUE(base_resume_id, job_id, source_model, output_format, version)
             );
 
             CREATE INDEX IF NOT EXISTS idx_variants_base ON resume_variants(base_resume_id);
@@ -156,7 +560,7 @@ impl Database {
                 domain TEXT NOT NULL CHECK (domain IN ('tech', 'discipline', 'cloud', 'soft_skill')),
                 weight INTEGER NOT NULL DEFAULT 2 CHECK (weight BETWEEN 1 AND 3),
                 source_model TEXT NOT NULL,
-                created_at TEXT NOT NULL DEFAULT (datetime('now'))
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
             );
 
             CREATE INDEX IF NOT EXISTS idx_job_keywords_job ON job_keywords(job_id);
@@ -172,12 +576,51 @@ impl Database {
                 gaps TEXT,
                 stretch_areas TEXT,
                 narrative TEXT NOT NULL,
-                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
                 UNIQUE(job_id, base_resume_id, source_model)
             );
 
             CREATE INDEX IF NOT EXISTS idx_fit_analyses_job ON fit_analyses(job_id);
 
+            CREATE TABLE IF NOT EXISTS job_summaries (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                job_id INTEGER NOT NULL REFERENCES jobs(id),
+                source_model TEXT NOT NULL,
+                responsibilities TEXT NOT NULL,
+                stack TEXT NOT NULL,
+                seniority TEXT NOT NULL,
+                comp TEXT NOT NULL,
+                red_flags TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                UNIQUE(job_id, source_model)
+            );
+
+            CREATE TABLE IF NOT EXISTS prep_notes (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                job_id INTEGER NOT NULL REFERENCES jobs(id),
+                base_resume_id INTEGER NOT NULL REFERENCES base_resumes(id),
+                source_model TEXT NOT NULL,
+                kind TEXT NOT NULL CHECK (kind IN ('technical', 'behavioral')),
+                question TEXT NOT NULL,
+                prep TEXT,
+                position INTEGER NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_prep_notes_job ON prep_notes(job_id);
+
+            CREATE TABLE IF NOT EXISTS negotiation_briefs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                job_id INTEGER NOT NULL REFERENCES jobs(id),
+                source_model TEXT NOT NULL,
+                anchor INTEGER,
+                target INTEGER,
+                walk_away INTEGER,
+                brief TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                UNIQUE(job_id, source_model)
+            );
+
             CREATE TABLE IF NOT EXISTS glassdoor_reviews (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
                 employer_id INTEGER NOT NULL REFERENCES employers(id),
@@ -188,20 +631,271 @@ impl Database {
                 review_text TEXT,
                 sentiment TEXT NOT NULL CHECK (sentiment IN ('positive', 'negative', 'neutral')),
                 review_date TEXT,
-                captured_at TEXT NOT NULL DEFAULT (datetime('now'))
+                captured_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
             );
 
             CREATE INDEX IF NOT EXISTS idx_glassdoor_employer ON glassdoor_reviews(employer_id);
             CREATE INDEX IF NOT EXISTS idx_glassdoor_date ON glassdoor_reviews(review_date);
 
+            CREATE TABLE IF NOT EXISTS glassdoor_snapshots (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                employer_id INTEGER NOT NULL REFERENCES employers(id),
+                rating REAL NOT NULL,
+                review_count INTEGER NOT NULL,
+                positive INTEGER NOT NULL,
+                neutral INTEGER NOT NULL,
+                negative INTEGER NOT NULL,
+                fetched_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_glassdoor_snapshots_employer ON glassdoor_snapshots(employer_id, fetched_at);
+
+            CREATE TABLE IF NOT EXISTS job_notes (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                job_id INTEGER NOT NULL REFERENCES jobs(id),
+                note TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS job_tags (
+                job_id INTEGER NOT NULL REFERENCES jobs(id),
+                tag TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                PRIMARY KEY (job_id, tag)
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_job_notes_job ON job_notes(job_id);
+            CREATE INDEX IF NOT EXISTS idx_job_tags_tag ON job_tags(tag);
+
+            CREATE TABLE IF NOT EXISTS status_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                job_id INTEGER NOT NULL REFERENCES jobs(id),
+                from_status TEXT,
+                to_status TEXT NOT NULL,
+                changed_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_status_history_job ON status_history(job_id);
+            CREATE INDEX IF NOT EXISTS idx_status_history_changed ON status_history(changed_at);
+
+            CREATE TABLE IF NOT EXISTS rank_weights (
+                factor TEXT PRIMARY KEY,
+                weight REAL NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS user_skills (
+                skill TEXT PRIMARY KEY,
+                source TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS negative_keywords (
+                keyword TEXT PRIMARY KEY,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS rank_rules (
+                keyword TEXT PRIMARY KEY,
+                points REAL NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS saved_filters (
+                name TEXT PRIMARY KEY,
+                status TEXT,
+                employer TEXT,
+                tag TEXT,
+                keyword TEXT,
+                min_pay INTEGER,
+                max_pay INTEGER,
+                remote INTEGER NOT NULL DEFAULT 0,
+                location TEXT,
+                easy_apply INTEGER,
+                sponsors_visa INTEGER NOT NULL DEFAULT 0,
+                no_clearance INTEGER NOT NULL DEFAULT 0,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS cleanup_decisions (
+                kind TEXT NOT NULL,
+                pattern TEXT NOT NULL,
+                decision TEXT NOT NULL CHECK (decision IN ('remove', 'keep')),
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                PRIMARY KEY (kind, pattern)
+            );
+
+            CREATE TABLE IF NOT EXISTS ingested_emails (
+                message_id TEXT PRIMARY KEY,
+                subject TEXT,
+                jobs_added INTEGER NOT NULL DEFAULT 0,
+                ingested_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS job_clusters (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL,
+                keywords TEXT NOT NULL,
+                source_model TEXT,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS job_cluster_members (
+                job_id INTEGER PRIMARY KEY REFERENCES jobs(id),
+                cluster_id INTEGER NOT NULL REFERENCES job_clusters(id)
+            );
+
+            CREATE TABLE IF NOT EXISTS review_cards (
+                keyword TEXT PRIMARY KEY,
+                interval_days REAL NOT NULL DEFAULT 0,
+                ease REAL NOT NULL DEFAULT 2.5,
+                reps INTEGER NOT NULL DEFAULT 0,
+                lapses INTEGER NOT NULL DEFAULT 0,
+                due_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                last_reviewed_at TEXT,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS learning_plans (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                hours_per_week INTEGER NOT NULL,
+                source_model TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS learning_items (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                plan_id INTEGER NOT NULL REFERENCES learning_plans(id),
+                position INTEGER NOT NULL,
+                week TEXT NOT NULL,
+                keyword TEXT NOT NULL,
+                task TEXT NOT NULL,
+                resource TEXT,
+                milestone TEXT,
+                done_at TEXT
+            );
+
+            CREATE TABLE IF NOT EXISTS employer_alerts (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                employer_id INTEGER NOT NULL REFERENCES employers(id),
+                kind TEXT NOT NULL,
+                summary TEXT NOT NULL,
+                event_date TEXT,
+                acknowledged INTEGER NOT NULL DEFAULT 0,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_employer_alerts_employer ON employer_alerts(employer_id);
+
+            CREATE TABLE IF NOT EXISTS employer_hn_stories (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                employer_id INTEGER NOT NULL REFERENCES employers(id),
+                title TEXT NOT NULL,
+                url TEXT,
+                hn_url TEXT NOT NULL,
+                points INTEGER NOT NULL DEFAULT 0,
+                num_comments INTEGER NOT NULL DEFAULT 0,
+                posted_at TEXT,
+                fetched_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_hn_stories_employer ON employer_hn_stories(employer_id);
+
+            CREATE TABLE IF NOT EXISTS employer_comp (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                employer_id INTEGER NOT NULL REFERENCES employers(id),
+                level TEXT NOT NULL,
+                role TEXT NOT NULL,
+                base_min INTEGER,       -- annual USD
+                base_max INTEGER,
+                total_min INTEGER,      -- base + stock + bonus
+                total_max INTEGER,
+                source_model TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_employer_comp_employer ON employer_comp(employer_id);
+
+            CREATE TABLE IF NOT EXISTS employer_aliases (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                alias TEXT NOT NULL,
+                alias_key TEXT NOT NULL UNIQUE,
+                employer_id INTEGER NOT NULL REFERENCES employers(id),
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_employer_aliases_employer ON employer_aliases(employer_id);
+
             CREATE TABLE IF NOT EXISTS job_keyword_profiles (
                 id INTEGER PRIMARY KEY AUTOINCREMENT,
                 job_id INTEGER NOT NULL REFERENCES jobs(id),
                 source_model TEXT NOT NULL,
                 profile TEXT NOT NULL,
-                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
                 UNIQUE(job_id, source_model)
             );
+
+            CREATE TABLE IF NOT EXISTS contacts (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL,
+                role TEXT,              -- "Recruiter", "Referral", "Hiring manager", ...
+                employer_id INTEGER REFERENCES employers(id),
+                email TEXT,
+                linkedin TEXT,
+                last_touch TEXT,        -- YYYY-MM-DD
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_contacts_employer ON contacts(employer_id);
+
+            CREATE TABLE IF NOT EXISTS job_contacts (
+                job_id INTEGER NOT NULL REFERENCES jobs(id),
+                contact_id INTEGER NOT NULL REFERENCES contacts(id),
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                PRIMARY KEY (job_id, contact_id)
+            );
+
+            CREATE TABLE IF NOT EXISTS job_next_actions (
+                job_id INTEGER PRIMARY KEY REFERENCES jobs(id),
+                action TEXT NOT NULL,
+                due_date TEXT NOT NULL, -- YYYY-MM-DD
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS job_flags (
+                job_id INTEGER NOT NULL REFERENCES jobs(id),
+                flag TEXT NOT NULL,     -- a flags::KINDS name
+                evidence TEXT,          -- the words that raised it
+                source TEXT NOT NULL,   -- 'rules', or the model that found it
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                PRIMARY KEY (job_id, flag, source)
+            );
+
+            CREATE TABLE IF NOT EXISTS pipeline_runs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                started_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                finished_at TEXT -- NULL while running, or if the run was interrupted
+            );
+
+            CREATE TABLE IF NOT EXISTS pipeline_run_steps (
+                run_id INTEGER NOT NULL REFERENCES pipeline_runs(id),
+                stage TEXT NOT NULL,  -- email, fetch, keywords, fit
+                job_id INTEGER,       -- NULL: the whole stage is done
+                ok INTEGER NOT NULL,
+                completed_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_pipeline_run_steps_run ON pipeline_run_steps(run_id, stage);
+
+            CREATE TABLE IF NOT EXISTS fetch_domains (
+                domain TEXT PRIMARY KEY,  -- registrable domain (ratelimit::domain)
+                successes INTEGER NOT NULL DEFAULT 0,
+                failures INTEGER NOT NULL DEFAULT 0,
+                failure_streak INTEGER NOT NULL DEFAULT 0,  -- failures since the last success
+                last_error TEXT,
+                last_success_at TEXT,
+                last_failure_at TEXT,
+                backoff_until TEXT  -- batches skip the domain until then
+            );
             "#,
         )?;
 
@@ -275,6 +969,27 @@ impl Database {
             )?;
         }
 
+        // Check if engineering culture columns exist
+        if !columns.contains(&"eng_blog_url".to_string()) {
+            self.conn.execute_batch(
+                r#"
+                ALTER TABLE employers ADD COLUMN eng_blog_url TEXT;
+                ALTER TABLE employers ADD COLUMN eng_rss_url TEXT;
+                ALTER TABLE employers ADD COLUMN eng_talks TEXT;
+                ALTER TABLE employers ADD COLUMN eng_culture_summary TEXT;
+                ALTER TABLE employers ADD COLUMN eng_research_updated TEXT;
+                "#,
+            )?;
+        }
+
+        if !columns.contains(&"funding_checked_at".to_string()) {
+            self.conn.execute("ALTER TABLE employers ADD COLUMN funding_checked_at TEXT", [])?;
+        }
+
+        if !columns.contains(&"careers_url".to_string()) {
+            self.conn.execute("ALTER TABLE employers ADD COLUMN careers_url TEXT", [])?;
+        }
+
         // Check if job_code column exists in jobs table
         let job_columns: Vec<String> = self.conn
             .prepare("PRAGMA table_info(jobs)")?
@@ -295,6 +1010,73 @@ impl Database {
             )?;
         }
 
+        if !job_columns.contains(&"location".to_string()) {
+            self.conn.execute_batch(
+                "ALTER TABLE jobs ADD COLUMN location TEXT;
+                 ALTER TABLE jobs ADD COLUMN work_mode TEXT;",
+            )?;
+        }
+
+        if !job_columns.contains(&"pay_text".to_string()) {
+            self.conn.execute_batch(
+                "ALTER TABLE jobs ADD COLUMN pay_text TEXT;
+                 ALTER TABLE jobs ADD COLUMN pay_equity INTEGER NOT NULL DEFAULT 0;",
+            )?;
+        }
+
+        if !job_columns.contains(&"archived_at".to_string()) {
+            self.conn.execute("ALTER TABLE jobs ADD COLUMN archived_at TEXT", [])?;
+        }
+
+        // Drop the hardcoded status CHECK constraint from older jobs tables.
+        // Valid statuses now live in job_statuses and are checked in update_job_status.
+        let jobs_sql: String = self.conn.query_row(
+            "SELECT sql FROM sqlite_master WHERE type='table' AND name='jobs'",
+            [],
+            |row| row.get(0),
+        )?;
+        if jobs_sql.contains("CHECK (status IN") {
+            // Create-copy-drop-rename so other tables' references to `jobs` stay intact
+            self.conn.execute_batch(
+                r#"
+                CREATE TABLE jobs_new (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    employer_id INTEGER REFERENCES employers(id),
+                    title TEXT NOT NULL,
+                    url TEXT,
+                    source TEXT,
+                    status TEXT NOT NULL DEFAULT 'new',
+                    pay_min INTEGER,
+                    pay_max INTEGER,
+                    job_code TEXT,
+                    raw_text TEXT,
+                    fetched_at TEXT,
+                    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                    updated_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                    location TEXT,
+                    work_mode TEXT,
+                    pay_text TEXT,
+                    pay_equity INTEGER NOT NULL DEFAULT 0,
+                    archived_at TEXT
+                );
+
+                INSERT INTO jobs_new (id, employer_id, title, url, source, status, pay_min, pay_max,
+                                      job_code, raw_text, fetched_at, created_at, updated_at, location, work_mode,
+                                      pay_text, pay_equity, archived_at)
+                    SELECT id, employer_id, title, url, source, status, pay_min, pay_max,
+                           job_code, raw_text, fetched_at, created_at, updated_at, location, work_mode,
+                           pay_text, pay_equity, archived_at
+                    FROM jobs;
+
+                DROP TABLE jobs;
+                ALTER TABLE jobs_new RENAME TO jobs;
+
+                CREATE INDEX IF NOT EXISTS idx_jobs_employer ON jobs(employer_id);
+                CREATE INDEX IF NOT EXISTS idx_jobs_status ON jobs(status);
+                "#,
+            )?;
+        }
+
         // Migrate resume_variants to add source_model and output_format columns
         let rv_columns: Vec<String> = self.conn
             .prepare("PRAGMA table_info(resume_variants)")?
@@ -315,7 +1097,7 @@ impl Database {
                     tailoring_notes TEXT,
                     source_model TEXT,
                     output_format TEXT,
-                    created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
                     UNIQUE(base_resume_id, job_id, source_model, output_format)
                 );
 
@@ -331,38 +1113,104 @@ impl Database {
             )?;
         }
 
-        // Migrate job_keywords: old schema had `category`, new schema has `domain` + `weight`
-        let jk_columns: Vec<String> = self.conn
-            .prepare("PRAGMA table_info(job_keywords)")?
+        // Migrate resume_variants to keep every generation: version joins the unique key
+        let rv_columns: Vec<String> = self.conn
+            .prepare("PRAGMA table_info(resume_variants)")?
             .query_map([], |row| row.get::<_, String>(1))?
             .collect::<Result<Vec<_>, _>>()?;
 
-        if !jk_columns.is_empty() && jk_columns.contains(&"category".to_string()) {
-            // Rename-copy-drop: migrate from category to domain+weight
+        if !rv_columns.is_empty() && !rv_columns.contains(&"version".to_string()) {
             self.conn.execute_batch(
                 r#"
-                ALTER TABLE job_keywords RENAME TO job_keywords_old;
+                ALTER TABLE resume_variants RENAME TO resume_variants_old;
 
-                CREATE TABLE job_keywords (
+                CREATE TABLE resume_variants (
                     id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    base_resume_id INTEGER NOT NULL REFERENCES base_resumes(id),
                     job_id INTEGER NOT NULL REFERENCES jobs(id),
-                    keyword TEXT NOT NULL,
-                    domain TEXT NOT NULL CHECK (domain IN ('tech', 'discipline', 'cloud', 'soft_skill')),
-                    weight INTEGER NOT NULL DEFAULT 2 CHECK (weight BETWEEN 1 AND 3),
-                    source_model TEXT NOT NULL,
-                    created_at TEXT NOT NULL DEFAULT (datetime('now'))
+                    content TEXT NOT NULL,
+                    tailoring_notes TEXT,
+                    source_model TEXT,
+                    output_format TEXT,
+                    version INTEGER NOT NULL DEFAULT 1,
+                    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                    UNIQUE(base_resume_id, job_id, source_model, output_format, version)
                 );
 
-                INSERT INTO job_keywords (id, job_id, keyword, domain, weight, source_model, created_at)
-                    SELECT id, job_id, keyword, 'tech',
-                           CASE WHEN category = 'mandatory' THEN 3 ELSE 1 END,
-                           source_model, created_at
-                    FROM job_keywords_old;
+                INSERT INTO resume_variants (id, base_resume_id, job_id, content, tailoring_notes, source_model, output_format, created_at)
+                    SELECT id, base_resume_id, job_id, content, tailoring_notes, source_model, output_format, created_at
+                    FROM resume_variants_old;
 
-                DROP TABLE job_keywords_old;
+                DROP TABLE resume_variants_old;
 
-                CREATE INDEX IF NOT EXISTS idx_job_keywords_job ON job_keywords(job_id);
-                CREATE INDEX IF NOT EXISTS idx_job_keywords_keyword ON job_keywords(keyword);
+                CREATE INDEX IF NOT EXISTS idx_variants_base ON resume_variants(base_resume_id);
+                CREATE INDEX IF NOT EXISTS idx_variants_job ON resume_variants(job_id);
+                "#,
+            )?;
+        }
+
+        // Content hashes: which version of a base resume each variant was tailored from
+        let br_columns: Vec<String> = self.conn
+            .prepare("PRAGMA table_info(base_resumes)")?
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if !br_columns.is_empty() && !br_columns.contains(&"content_hash".to_string()) {
+            self.conn.execute_batch("ALTER TABLE base_resumes ADD COLUMN content_hash TEXT;")?;
+            let resumes: Vec<(i64, String)> = self.conn
+                .prepare("SELECT id, content FROM base_resumes")?
+                .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?
+                .collect::<Result<Vec<_>, _>>()?;
+            for (id, content) in resumes {
+                self.conn.execute(
+                    "UPDATE base_resumes SET content_hash = ?1 WHERE id = ?2",
+                    params![content_hash(&content), id],
+                )?;
+            }
+        }
+
+        let rv_columns: Vec<String> = self.conn
+            .prepare("PRAGMA table_info(resume_variants)")?
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if !rv_columns.is_empty() && !rv_columns.contains(&"base_content_hash".to_string()) {
+            // Variants from before tracking stay NULL: we can't know which version they used
+            self.conn.execute_batch("ALTER TABLE resume_variants ADD COLUMN base_content_hash TEXT;")?;
+        }
+
+        // Migrate job_keywords: old schema had `category`, new schema has `domain` + `weight`
+        let jk_columns: Vec<String> = self.conn
+            .prepare("PRAGMA table_info(job_keywords)")?
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        if !jk_columns.is_empty() && jk_columns.contains(&"category".to_string()) {
+            // Rename-copy-drop: migrate from category to domain+weight
+            self.conn.execute_batch(
+                r#"
+                ALTER TABLE job_keywords RENAME TO job_keywords_old;
+
+                CREATE TABLE job_keywords (
+                    id INTEGER PRIMARY KEY AUTOINCREMENT,
+                    job_id INTEGER NOT NULL REFERENCES jobs(id),
+                    keyword TEXT NOT NULL,
+                    domain TEXT NOT NULL CHECK (domain IN ('tech', 'discipline', 'cloud', 'soft_skill')),
+                    weight INTEGER NOT NULL DEFAULT 2 CHECK (weight BETWEEN 1 AND 3),
+                    source_model TEXT NOT NULL,
+                    created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+                );
+
+                INSERT INTO job_keywords (id, job_id, keyword, domain, weight, source_model, created_at)
+                    SELECT id, job_id, keyword, 'tech',
+                           CASE WHEN category = 'mandatory' THEN 3 ELSE 1 END,
+                           source_model, created_at
+                    FROM job_keywords_old;
+
+                DROP TABLE job_keywords_old;
+
+                CREATE INDEX IF NOT EXISTS idx_job_keywords_job ON job_keywords(job_id);
+                CREATE INDEX IF NOT EXISTS idx_job_keywords_keyword ON job_keywords(keyword);
                 "#,
             )?;
         }
@@ -377,7 +1225,7 @@ impl Database {
                 domain TEXT NOT NULL CHECK (domain IN ('tech', 'discipline', 'cloud', 'soft_skill')),
                 weight INTEGER NOT NULL DEFAULT 2 CHECK (weight BETWEEN 1 AND 3),
                 source_model TEXT NOT NULL,
-                created_at TEXT NOT NULL DEFAULT (datetime('now'))
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
             );
 
             CREATE INDEX IF NOT EXISTS idx_job_keywords_job ON job_keywords(job_id);
@@ -388,7 +1236,7 @@ impl Database {
                 job_id INTEGER NOT NULL REFERENCES jobs(id),
                 source_model TEXT NOT NULL,
                 profile TEXT NOT NULL,
-                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
                 UNIQUE(job_id, source_model)
             );
 
@@ -402,14 +1250,466 @@ impl Database {
                 gaps TEXT,
                 stretch_areas TEXT,
                 narrative TEXT NOT NULL,
-                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
                 UNIQUE(job_id, base_resume_id, source_model)
             );
 
             CREATE INDEX IF NOT EXISTS idx_fit_analyses_job ON fit_analyses(job_id);
+
+            CREATE TABLE IF NOT EXISTS job_summaries (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                job_id INTEGER NOT NULL REFERENCES jobs(id),
+                source_model TEXT NOT NULL,
+                responsibilities TEXT NOT NULL,
+                stack TEXT NOT NULL,
+                seniority TEXT NOT NULL,
+                comp TEXT NOT NULL,
+                red_flags TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                UNIQUE(job_id, source_model)
+            );
+
+            CREATE TABLE IF NOT EXISTS prep_notes (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                job_id INTEGER NOT NULL REFERENCES jobs(id),
+                base_resume_id INTEGER NOT NULL REFERENCES base_resumes(id),
+                source_model TEXT NOT NULL,
+                kind TEXT NOT NULL CHECK (kind IN ('technical', 'behavioral')),
+                question TEXT NOT NULL,
+                prep TEXT,
+                position INTEGER NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_prep_notes_job ON prep_notes(job_id);
+
+            CREATE TABLE IF NOT EXISTS negotiation_briefs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                job_id INTEGER NOT NULL REFERENCES jobs(id),
+                source_model TEXT NOT NULL,
+                anchor INTEGER,
+                target INTEGER,
+                walk_away INTEGER,
+                brief TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                UNIQUE(job_id, source_model)
+            );
+
+            CREATE TABLE IF NOT EXISTS job_statuses (
+                name TEXT PRIMARY KEY,
+                position INTEGER NOT NULL,
+                is_terminal INTEGER NOT NULL DEFAULT 0,
+                color TEXT NOT NULL DEFAULT 'white'
+            );
+
+            CREATE TABLE IF NOT EXISTS employer_alerts (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                employer_id INTEGER NOT NULL REFERENCES employers(id),
+                kind TEXT NOT NULL,
+                summary TEXT NOT NULL,
+                event_date TEXT,
+                acknowledged INTEGER NOT NULL DEFAULT 0,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_employer_alerts_employer ON employer_alerts(employer_id);
+
+            CREATE TABLE IF NOT EXISTS employer_hn_stories (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                employer_id INTEGER NOT NULL REFERENCES employers(id),
+                title TEXT NOT NULL,
+                url TEXT,
+                hn_url TEXT NOT NULL,
+                points INTEGER NOT NULL DEFAULT 0,
+                num_comments INTEGER NOT NULL DEFAULT 0,
+                posted_at TEXT,
+                fetched_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_hn_stories_employer ON employer_hn_stories(employer_id);
+
+            CREATE TABLE IF NOT EXISTS employer_comp (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                employer_id INTEGER NOT NULL REFERENCES employers(id),
+                level TEXT NOT NULL,
+                role TEXT NOT NULL,
+                base_min INTEGER,       -- annual USD
+                base_max INTEGER,
+                total_min INTEGER,      -- base + stock + bonus
+                total_max INTEGER,
+                source_model TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_employer_comp_employer ON employer_comp(employer_id);
+
+            CREATE TABLE IF NOT EXISTS employer_aliases (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                alias TEXT NOT NULL,
+                alias_key TEXT NOT NULL UNIQUE,
+                employer_id INTEGER NOT NULL REFERENCES employers(id),
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_employer_aliases_employer ON employer_aliases(employer_id);
+
+            CREATE TABLE IF NOT EXISTS job_notes (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                job_id INTEGER NOT NULL REFERENCES jobs(id),
+                note TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS job_tags (
+                job_id INTEGER NOT NULL REFERENCES jobs(id),
+                tag TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                PRIMARY KEY (job_id, tag)
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_job_notes_job ON job_notes(job_id);
+            CREATE INDEX IF NOT EXISTS idx_job_tags_tag ON job_tags(tag);
+
+            CREATE TABLE IF NOT EXISTS status_history (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                job_id INTEGER NOT NULL REFERENCES jobs(id),
+                from_status TEXT,
+                to_status TEXT NOT NULL,
+                changed_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_status_history_job ON status_history(job_id);
+            CREATE INDEX IF NOT EXISTS idx_status_history_changed ON status_history(changed_at);
+
+            -- Duplicate detection looks jobs up by URL and normalized title
+            CREATE INDEX IF NOT EXISTS idx_jobs_url ON jobs(url);
+            CREATE INDEX IF NOT EXISTS idx_jobs_title_norm ON jobs(lower(trim(title)));
+            CREATE INDEX IF NOT EXISTS idx_jobs_pay_top ON jobs(COALESCE(pay_max, pay_min));
+            CREATE INDEX IF NOT EXISTS idx_jobs_pay_bottom ON jobs(COALESCE(pay_min, pay_max));
+
+            CREATE TABLE IF NOT EXISTS rank_weights (
+                factor TEXT PRIMARY KEY,
+                weight REAL NOT NULL
+            );
+
+            CREATE TABLE IF NOT EXISTS user_skills (
+                skill TEXT PRIMARY KEY,
+                source TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS negative_keywords (
+                keyword TEXT PRIMARY KEY,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS rank_rules (
+                keyword TEXT PRIMARY KEY,
+                points REAL NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS saved_filters (
+                name TEXT PRIMARY KEY,
+                status TEXT,
+                employer TEXT,
+                tag TEXT,
+                keyword TEXT,
+                min_pay INTEGER,
+                max_pay INTEGER,
+                remote INTEGER NOT NULL DEFAULT 0,
+                location TEXT,
+                easy_apply INTEGER,
+                sponsors_visa INTEGER NOT NULL DEFAULT 0,
+                no_clearance INTEGER NOT NULL DEFAULT 0,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS cleanup_decisions (
+                kind TEXT NOT NULL,
+                pattern TEXT NOT NULL,
+                decision TEXT NOT NULL CHECK (decision IN ('remove', 'keep')),
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                PRIMARY KEY (kind, pattern)
+            );
+
+            CREATE TABLE IF NOT EXISTS ingested_emails (
+                message_id TEXT PRIMARY KEY,
+                subject TEXT,
+                jobs_added INTEGER NOT NULL DEFAULT 0,
+                ingested_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS job_clusters (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL,
+                keywords TEXT NOT NULL,
+                source_model TEXT,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS job_cluster_members (
+                job_id INTEGER PRIMARY KEY REFERENCES jobs(id),
+                cluster_id INTEGER NOT NULL REFERENCES job_clusters(id)
+            );
+
+            CREATE TABLE IF NOT EXISTS review_cards (
+                keyword TEXT PRIMARY KEY,
+                interval_days REAL NOT NULL DEFAULT 0,
+                ease REAL NOT NULL DEFAULT 2.5,
+                reps INTEGER NOT NULL DEFAULT 0,
+                lapses INTEGER NOT NULL DEFAULT 0,
+                due_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                last_reviewed_at TEXT,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS learning_plans (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                hours_per_week INTEGER NOT NULL,
+                source_model TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS learning_items (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                plan_id INTEGER NOT NULL REFERENCES learning_plans(id),
+                position INTEGER NOT NULL,
+                week TEXT NOT NULL,
+                keyword TEXT NOT NULL,
+                task TEXT NOT NULL,
+                resource TEXT,
+                milestone TEXT,
+                done_at TEXT
+            );
+
+            CREATE TABLE IF NOT EXISTS glassdoor_snapshots (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                employer_id INTEGER NOT NULL REFERENCES employers(id),
+                rating REAL NOT NULL,
+                review_count INTEGER NOT NULL,
+                positive INTEGER NOT NULL,
+                neutral INTEGER NOT NULL,
+                negative INTEGER NOT NULL,
+                fetched_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_glassdoor_snapshots_employer ON glassdoor_snapshots(employer_id, fetched_at);
+            CREATE TABLE IF NOT EXISTS contacts (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL,
+                role TEXT,              -- "Recruiter", "Referral", "Hiring manager", ...
+                employer_id INTEGER REFERENCES employers(id),
+                email TEXT,
+                linkedin TEXT,
+                last_touch TEXT,        -- YYYY-MM-DD
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_contacts_employer ON contacts(employer_id);
+
+            CREATE TABLE IF NOT EXISTS job_contacts (
+                job_id INTEGER NOT NULL REFERENCES jobs(id),
+                contact_id INTEGER NOT NULL REFERENCES contacts(id),
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                PRIMARY KEY (job_id, contact_id)
+            );
+
+            CREATE TABLE IF NOT EXISTS job_next_actions (
+                job_id INTEGER PRIMARY KEY REFERENCES jobs(id),
+                action TEXT NOT NULL,
+                due_date TEXT NOT NULL, -- YYYY-MM-DD
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE TABLE IF NOT EXISTS job_flags (
+                job_id INTEGER NOT NULL REFERENCES jobs(id),
+                flag TEXT NOT NULL,     -- a flags::KINDS name
+                evidence TEXT,          -- the words that raised it
+                source TEXT NOT NULL,   -- 'rules', or the model that found it
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                PRIMARY KEY (job_id, flag, source)
+            );
+
+            CREATE TABLE IF NOT EXISTS pipeline_runs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                started_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                finished_at TEXT -- NULL while running, or if the run was interrupted
+            );
+
+            CREATE TABLE IF NOT EXISTS pipeline_run_steps (
+                run_id INTEGER NOT NULL REFERENCES pipeline_runs(id),
+                stage TEXT NOT NULL,  -- email, fetch, keywords, fit
+                job_id INTEGER,       -- NULL: the whole stage is done
+                ok INTEGER NOT NULL,
+                completed_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+            );
+
+            CREATE INDEX IF NOT EXISTS idx_pipeline_run_steps_run ON pipeline_run_steps(run_id, stage);
+
+            CREATE TABLE IF NOT EXISTS fetch_domains (
+                domain TEXT PRIMARY KEY,  -- registrable domain (ratelimit::domain)
+                successes INTEGER NOT NULL DEFAULT 0,
+                failures INTEGER NOT NULL DEFAULT 0,
+                failure_streak INTEGER NOT NULL DEFAULT 0,  -- failures since the last success
+                last_error TEXT,
+                last_success_at TEXT,
+                last_failure_at TEXT,
+                backoff_until TEXT  -- batches skip the domain until then
+            );
             "#,
         )?;
 
+        self.conn.execute_batch(AI_CACHE_SCHEMA)?;
+        self.conn.execute_batch(AI_USAGE_SCHEMA)?;
+
+        let filter_columns: Vec<String> = self.conn
+            .prepare("PRAGMA table_info(saved_filters)")?
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<Result<Vec<_>, _>>()?;
+        if !filter_columns.contains(&"max_pay".to_string()) {
+            self.conn.execute("ALTER TABLE saved_filters ADD COLUMN max_pay INTEGER", [])?;
+        }
+        if !filter_columns.contains(&"easy_apply".to_string()) {
+            self.conn.execute("ALTER TABLE saved_filters ADD COLUMN easy_apply INTEGER", [])?;
+        }
+        if !filter_columns.contains(&"sponsors_visa".to_string()) {
+            self.conn.execute_batch(
+                "ALTER TABLE saved_filters ADD COLUMN sponsors_visa INTEGER NOT NULL DEFAULT 0;
+                 ALTER TABLE saved_filters ADD COLUMN no_clearance INTEGER NOT NULL DEFAULT 0;",
+            )?;
+        }
+
+        // Jobs moved before status_history existed get one row for where they are now,
+        // dated by their last update. Only jobs with no history at all, so this runs once per job.
+        self.conn.execute(
+            "INSERT INTO status_history (job_id, from_status, to_status, changed_at)
+             SELECT id, NULL, status, updated_at FROM jobs
+             WHERE status != 'new' AND NOT EXISTS (SELECT 1 FROM status_history h WHERE h.job_id = jobs.id)",
+            [],
+        )?;
+
+        // Status timestamps for jobs tracked before they existed come from status_history:
+        // the first move to applied, the latest to rejected or closed.
+        let job_columns: Vec<String> = self.conn
+            .prepare("PRAGMA table_info(jobs)")?
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<Result<Vec<_>, _>>()?;
+        if !job_columns.contains(&"applied_at".to_string()) {
+            self.conn.execute_batch(
+                "ALTER TABLE jobs ADD COLUMN applied_at TEXT;
+                 ALTER TABLE jobs ADD COLUMN rejected_at TEXT;
+                 ALTER TABLE jobs ADD COLUMN closed_at TEXT;
+                 UPDATE jobs SET
+                     applied_at = (SELECT MIN(changed_at) FROM status_history h WHERE h.job_id = jobs.id AND h.to_status = 'applied'),
+                     rejected_at = (SELECT MAX(changed_at) FROM status_history h WHERE h.job_id = jobs.id AND h.to_status = 'rejected'),
+                     closed_at = (SELECT MAX(changed_at) FROM status_history h WHERE h.job_id = jobs.id AND h.to_status = 'closed');",
+            )?;
+        }
+        if !job_columns.contains(&"posted_at".to_string()) {
+            self.conn.execute_batch(
+                "ALTER TABLE jobs ADD COLUMN posted_at TEXT;
+                 ALTER TABLE jobs ADD COLUMN apply_deadline TEXT;",
+            )?;
+        }
+        if !job_columns.contains(&"easy_apply".to_string()) {
+            self.conn.execute("ALTER TABLE jobs ADD COLUMN easy_apply INTEGER", [])?;
+        }
+        if !job_columns.contains(&"apply_url".to_string()) {
+            self.conn.execute_batch(
+                "ALTER TABLE jobs ADD COLUMN apply_url TEXT;
+                 ALTER TABLE jobs ADD COLUMN ats TEXT;",
+            )?;
+        }
+        if !job_columns.contains(&"requires_clearance".to_string()) {
+            self.conn.execute_batch(
+                "ALTER TABLE jobs ADD COLUMN requires_clearance INTEGER;
+                 ALTER TABLE jobs ADD COLUMN sponsors_visa INTEGER;",
+            )?;
+        }
+        // Dedup looks jobs up by where they were resolved to, too
+        self.conn.execute("CREATE INDEX IF NOT EXISTS idx_jobs_apply_url ON jobs(apply_url)", [])?;
+
+        // Employers fetched before snapshots existed start their trend at the last fetch
+        self.conn.execute(
+            "INSERT INTO glassdoor_snapshots (employer_id, rating, review_count, positive, neutral, negative, fetched_at)
+             SELECT e.id, e.glassdoor_rating, e.glassdoor_review_count,
+                    (SELECT COUNT(*) FROM glassdoor_reviews r WHERE r.employer_id = e.id AND r.sentiment = 'positive'),
+                    (SELECT COUNT(*) FROM glassdoor_reviews r WHERE r.employer_id = e.id AND r.sentiment = 'neutral'),
+                    (SELECT COUNT(*) FROM glassdoor_reviews r WHERE r.employer_id = e.id AND r.sentiment = 'negative'),
+                    COALESCE(e.last_glassdoor_fetch, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+             FROM employers e
+             WHERE e.glassdoor_review_count > 0 AND e.glassdoor_rating IS NOT NULL
+               AND NOT EXISTS (SELECT 1 FROM glassdoor_snapshots s WHERE s.employer_id = e.id)",
+            [],
+        )?;
+
+        self.migrate_timestamps()?;
+
+        // Seed the default pipeline only when the table is empty, so user edits stick
+        let status_count: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM job_statuses",
+            [],
+            |row| row.get(0),
+        )?;
+        if status_count == 0 {
+            for status in default_job_statuses() {
+                self.conn.execute(
+                    "INSERT INTO job_statuses (name, position, is_terminal, color) VALUES (?1, ?2, ?3, ?4)",
+                    params![status.name, status.position, status.is_terminal, status.color],
+                )?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Timestamps used to be written by `datetime('now')`: UTC, but without a zone marker.
+    /// Rewrite those values as RFC3339 and swap the column defaults so new rows match.
+    /// Only runs while some table still has the old default.
+    fn migrate_timestamps(&self) -> Result<()> {
+        let legacy_tables: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE type = 'table' AND sql LIKE '%datetime(''now'')%'",
+            [],
+            |row| row.get(0),
+        )?;
+        if legacy_tables == 0 {
+            return Ok(());
+        }
+
+        let tx = self.conn.unchecked_transaction()?;
+        for (table, columns) in TIMESTAMP_COLUMNS {
+            let existing: Vec<String> = tx
+                .prepare(&format!("PRAGMA table_info({})", table))?
+                .query_map([], |row| row.get::<_, String>(1))?
+                .collect::<Result<Vec<_>, _>>()?;
+            for column in columns.iter().filter(|c| existing.iter().any(|e| e == *c)) {
+                tx.execute(
+                    &format!(
+                        "UPDATE {table} SET {column} = strftime('%Y-%m-%dT%H:%M:%SZ', {column})
+                         WHERE {column} LIKE '____-__-__ __:__:__'"
+                    ),
+                    [],
+                )?;
+            }
+        }
+
+        // SQLite can't ALTER a column default, but a default doesn't affect stored rows,
+        // so editing the schema text directly is safe ("Making Other Kinds Of Table
+        // Schema Changes" in the ALTER TABLE docs).
+        let schema_version: i64 = tx.query_row("PRAGMA schema_version", [], |row| row.get(0))?;
+        tx.execute_batch("PRAGMA writable_schema = ON")?;
+        tx.execute(
+            "UPDATE sqlite_master
+             SET sql = replace(sql, 'datetime(''now'')', 'strftime(''%Y-%m-%dT%H:%M:%SZ'', ''now'')')
+             WHERE type = 'table' AND sql LIKE '%datetime(''now'')%'",
+            [],
+        )?;
+        tx.execute_batch(&format!(
+            "PRAGMA schema_version = {}; PRAGMA writable_schema = OFF;",
+            schema_version + 1
+        ))?;
+        tx.commit()?;
         Ok(())
     }
 
@@ -420,9 +1720,7 @@ impl Database {
             |row| row.get(0),
         )?;
         if tables == 0 {
-            return Err(anyhow!(
-                "Database not initialized. Run 'hunt init' first."
-            ));
+            return Err(HuntError::Config("Database not initialized. Run 'hunt init' first.".to_string()).into());
         }
         // Run migrations in case schema has been updated
         self.migrate()?;
@@ -435,22 +1733,16 @@ impl Database {
         // Try to find existing
         let existing: Option<i64> = self
             .conn
-            .query_row(
-                "SELECT id FROM employers WHERE LOWER(name) = LOWER(?1)",
-                [name],
-                |row| row.get(0),
-            )
+            .prepare_cached("SELECT id FROM employers WHERE LOWER(name) = LOWER(?1)")?
+            .query_row([name], |row| row.get(0))
             .ok();
 
-        if let Some(id) = existing {
+        if let Some(id) = existing.or(self.resolve_employer_alias(name)?) {
             return Ok(id);
         }
 
         // Create new
-        self.conn.execute(
-            "INSERT INTO employers (name) VALUES (?1)",
-            [name],
-        )?;
+        self.conn.prepare_cached("INSERT INTO employers (name) VALUES (?1)")?.execute([name])?;
         Ok(self.conn.last_insert_rowid())
     }
 
@@ -463,7 +1755,9 @@ impl Database {
              evil_summary, public_research_updated_at,
              parent_company, pe_owner, pe_firm_url, vc_investors, key_investors,
              ownership_concerns, ownership_type, ownership_research_updated,
-             glassdoor_rating, glassdoor_review_count, last_glassdoor_fetch
+             glassdoor_rating, glassdoor_review_count, last_glassdoor_fetch,
+             eng_blog_url, eng_rss_url, eng_talks, eng_culture_summary, eng_research_updated,
+             careers_url
              FROM employers",
         );
         if status.is_some() {
@@ -491,9 +1785,13 @@ impl Database {
              evil_summary, public_research_updated_at,
              parent_company, pe_owner, pe_firm_url, vc_investors, key_investors,
              ownership_concerns, ownership_type, ownership_research_updated,
-             glassdoor_rating, glassdoor_review_count, last_glassdoor_fetch
-             FROM employers WHERE LOWER(name) = LOWER(?1)",
-            [name],
+             glassdoor_rating, glassdoor_review_count, last_glassdoor_fetch,
+             eng_blog_url, eng_rss_url, eng_talks, eng_culture_summary, eng_research_updated,
+             careers_url
+             FROM employers WHERE LOWER(name) = LOWER(?1)
+                OR id = (SELECT employer_id FROM employer_aliases WHERE alias_key = ?2)
+             ORDER BY LOWER(name) = LOWER(?1) DESC LIMIT 1",
+            params![name, normalize_name(name)],
             Self::row_to_employer,
         );
         match result {
@@ -503,11 +1801,158 @@ impl Database {
         }
     }
 
+    // --- Employer aliases ---
+
+    /// The employer an alias points at, matching on the normalized name so
+    /// "Alphabet Inc." finds an alias recorded as "Alphabet".
+    pub fn resolve_employer_alias(&self, name: &str) -> Result<Option<i64>> {
+        let result = self.conn
+            .prepare_cached("SELECT employer_id FROM employer_aliases WHERE alias_key = ?1")?
+            .query_row([normalize_name(name)], |row| row.get(0));
+        match result {
+            Ok(id) => Ok(Some(id)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Map `alias` to an employer, replacing any existing mapping for the same name.
+    pub fn add_employer_alias(&self, alias: &str, employer_id: i64) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO employer_aliases (alias, alias_key, employer_id) VALUES (?1, ?2, ?3)
+             ON CONFLICT(alias_key) DO UPDATE SET alias = excluded.alias, employer_id = excluded.employer_id",
+            params![alias, normalize_name(alias), employer_id],
+        )?;
+        Ok(())
+    }
+
+    /// Returns false if there was no such alias.
+    pub fn remove_employer_alias(&self, alias: &str) -> Result<bool> {
+        let count = self.conn.execute("DELETE FROM employer_aliases WHERE alias_key = ?1", [normalize_name(alias)])?;
+        Ok(count > 0)
+    }
+
+    pub fn list_employer_aliases(&self, employer_id: Option<i64>) -> Result<Vec<EmployerAlias>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT a.id, a.alias, a.employer_id, e.name, a.created_at
+             FROM employer_aliases a JOIN employers e ON e.id = a.employer_id
+             WHERE ?1 IS NULL OR a.employer_id = ?1
+             ORDER BY e.name, a.alias",
+        )?;
+        let rows = stmt.query_map([employer_id], |row| {
+            Ok(EmployerAlias {
+                id: row.get(0)?,
+                alias: row.get(1)?,
+                employer_id: row.get(2)?,
+                employer_name: row.get(3)?,
+                created_at: row.get(4)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list employer aliases")
+    }
+
+    /// Fold employer `from_id` into `into_id`: jobs, reviews, alerts, HN stories and
+    /// aliases are re-pointed, research fields `into` lacks are copied over, notes are
+    /// combined and the stricter status wins. `from` is then deleted and its name kept
+    /// as an alias of `into`.
+    pub fn merge_employers(&self, from_id: i64, into_id: i64) -> Result<()> {
+        if from_id == into_id {
+            return Err(HuntError::Validation("Can't merge an employer into itself".to_string()).into());
+        }
+        let load = |id: i64| -> Result<(String, Option<String>)> {
+            self.conn.query_row("SELECT status, notes FROM employers WHERE id = ?1", [id], |row| Ok((row.get(0)?, row.get(1)?)))
+                .map_err(|e| match e {
+                    rusqlite::Error::QueryReturnedNoRows => HuntError::NotFound(format!("Employer #{} not found", id)).into(),
+                    e => e.into(),
+                })
+        };
+        let (from_status, from_notes) = load(from_id)?;
+        let (into_status, into_notes) = load(into_id)?;
+
+        let severity = |status: &str| EMPLOYER_STATUS_SEVERITY.iter().position(|s| *s == status).unwrap_or(0);
+        let status = if severity(&from_status) > severity(&into_status) { from_status } else { into_status };
+        let notes = match (into_notes, from_notes) {
+            (Some(a), Some(b)) if a != b => Some(format!("{}\n{}", a, b)),
+            (a, b) => a.or(b),
+        };
+
+        // Every research/link column: keep into's value, fall back to from's
+        let fill_columns: Vec<String> = self.conn
+            .prepare("PRAGMA table_info(employers)")?
+            .query_map([], |row| row.get::<_, String>(1))?
+            .collect::<Result<Vec<_>, _>>()?
+            .into_iter()
+            .filter(|c| !["id", "name", "status", "notes", "created_at", "updated_at"].contains(&c.as_str()))
+            .collect();
+        let fills: Vec<String> = fill_columns.iter()
+            .map(|c| format!("{c} = COALESCE({c}, (SELECT {c} FROM employers WHERE id = ?1))"))
+            .collect();
+
+        let from_name: String = self.conn.query_row("SELECT name FROM employers WHERE id = ?1", [from_id], |row| row.get(0))?;
+
+        let tx = self.conn.unchecked_transaction()?;
+        for table in EMPLOYER_CHILD_TABLES {
+            tx.execute(&format!("UPDATE {} SET employer_id = ?1 WHERE employer_id = ?2", table), params![into_id, from_id])?;
+        }
+        // Future ingestion of the old name lands on the survivor
+        self.add_employer_alias(&from_name, into_id)?;
+        tx.execute(
+            &format!(
+                "UPDATE employers SET {}, status = ?2, notes = ?3,
+                    created_at = MIN(created_at, (SELECT created_at FROM employers WHERE id = ?1)),
+                    updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+                 WHERE id = ?4",
+                fills.join(", ")
+            ),
+            params![from_id, status, notes, into_id],
+        )?;
+        tx.execute("DELETE FROM employers WHERE id = ?1", [from_id])?;
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Rename an employer, keeping the old name as an alias. Fails if another employer
+    /// already has the name (merge instead).
+    pub fn rename_employer(&self, employer_id: i64, new_name: &str) -> Result<()> {
+        let taken: Option<i64> = self.conn.query_row(
+            "SELECT id FROM employers WHERE LOWER(name) = LOWER(?1) AND id != ?2",
+            params![new_name, employer_id],
+            |row| row.get(0),
+        ).ok();
+        if let Some(other) = taken {
+            return Err(HuntError::Validation(format!(
+                "Employer '{}' already exists (#{}). Use 'hunt employer merge' to combine them", new_name, other
+            )).into());
+        }
+        let old_name: String = self.conn.query_row("SELECT name FROM employers WHERE id = ?1", [employer_id], |row| row.get(0))?;
+        self.conn.execute(
+            "UPDATE employers SET name = ?1, updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = ?2",
+            params![new_name, employer_id],
+        )?;
+        if !old_name.eq_ignore_ascii_case(new_name) {
+            self.add_employer_alias(&old_name, employer_id)?;
+        }
+        Ok(())
+    }
+
+    /// Every employer with its job count, for duplicate detection.
+    pub fn list_employer_job_counts(&self) -> Result<Vec<EmployerEntry>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT e.id, e.name, COUNT(j.id) FROM employers e
+             LEFT JOIN jobs j ON j.employer_id = e.id
+             GROUP BY e.id ORDER BY e.id",
+        )?;
+        let rows = stmt.query_map([], |row| Ok(EmployerEntry { id: row.get(0)?, name: row.get(1)?, jobs: row.get(2)? }))?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to count jobs per employer")
+    }
+
     pub fn set_employer_status(&self, name: &str, status: &str) -> Result<()> {
         // Create employer if doesn't exist
         let id = self.get_or_create_employer(name)?;
         self.conn.execute(
-            "UPDATE employers SET status = ?1, updated_at = datetime('now') WHERE id = ?2",
+            "UPDATE employers SET status = ?1, updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = ?2",
             params![status, id],
         )?;
         Ok(())
@@ -535,8 +1980,8 @@ impl Database {
                 yc_url = ?6,
                 hn_mentions_count = ?7,
                 recent_news = ?8,
-                research_updated_at = datetime('now'),
-                updated_at = datetime('now')
+                research_updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now'),
+                updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
              WHERE id = ?9",
             params![
                 crunchbase_url,
@@ -569,8 +2014,8 @@ impl Database {
                 environmental_issues = ?3,
                 political_donations = ?4,
                 evil_summary = ?5,
-                public_research_updated_at = datetime('now'),
-                updated_at = datetime('now')
+                public_research_updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now'),
+                updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
              WHERE id = ?6",
             params![
                 controversies,
@@ -604,8 +2049,8 @@ impl Database {
                 key_investors = ?5,
                 ownership_concerns = ?6,
                 ownership_type = ?7,
-                ownership_research_updated = datetime('now'),
-                updated_at = datetime('now')
+                ownership_research_updated = strftime('%Y-%m-%dT%H:%M:%SZ', 'now'),
+                updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
              WHERE id = ?8",
             params![
                 parent_company,
@@ -621,6 +2066,733 @@ impl Database {
         Ok(())
     }
 
+    pub fn update_employer_engineering_culture(
+        &self,
+        employer_id: i64,
+        blog_url: Option<&str>,
+        rss_url: Option<&str>,
+        talks: Option<&str>,
+        culture_summary: Option<&str>,
+    ) -> Result<()> {
+        self.conn.execute(
+            "UPDATE employers SET
+                eng_blog_url = ?1,
+                eng_rss_url = ?2,
+                eng_talks = ?3,
+                eng_culture_summary = ?4,
+                eng_research_updated = strftime('%Y-%m-%dT%H:%M:%SZ', 'now'),
+                updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+             WHERE id = ?5",
+            params![blog_url, rss_url, talks, culture_summary, employer_id],
+        )?;
+        Ok(())
+    }
+
+    /// Record an employer's website domain and careers page. With `overwrite`, known
+    /// values replace stored ones; otherwise only empty fields are filled.
+    pub fn set_employer_links(&self, employer_id: i64, links: &EmployerLinks, overwrite: bool) -> Result<()> {
+        let sql = if overwrite {
+            "UPDATE employers SET domain = COALESCE(?1, domain), careers_url = COALESCE(?2, careers_url),
+                updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+             WHERE id = ?3"
+        } else {
+            "UPDATE employers SET domain = COALESCE(domain, ?1), careers_url = COALESCE(careers_url, ?2),
+                updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+             WHERE id = ?3 AND ((domain IS NULL AND ?1 IS NOT NULL) OR (careers_url IS NULL AND ?2 IS NOT NULL))"
+        };
+        self.conn.prepare_cached(sql)?.execute(params![links.domain, links.careers_url, employer_id])?;
+        Ok(())
+    }
+
+    /// Posting URLs of an employer's jobs, newest first.
+    pub fn get_employer_job_urls(&self, employer_id: i64) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT url FROM jobs WHERE employer_id = ?1 AND url IS NOT NULL ORDER BY created_at DESC, id DESC",
+        )?;
+        let urls = stmt.query_map([employer_id], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(urls)
+    }
+
+    // --- User skills ---
+
+    /// Replace the skill profile. Skills are lowercased and deduped; returns how many were stored.
+    pub fn set_user_skills(&self, skills: &[String], source: &str) -> Result<usize> {
+        let normalized = normalize_terms(skills);
+
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM user_skills", [])?;
+        for skill in &normalized {
+            tx.execute(
+                "INSERT INTO user_skills (skill, source) VALUES (?1, ?2)",
+                params![skill, source],
+            )?;
+        }
+        tx.commit()?;
+        Ok(normalized.len())
+    }
+
+    pub fn get_user_skills(&self) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare("SELECT skill FROM user_skills ORDER BY skill")?;
+        let skills = stmt.query_map([], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(skills)
+    }
+
+    /// Share (by weight) of the job's latest keywords covered by the skill profile.
+    pub fn get_skill_match(&self, job_id: i64) -> Result<Option<f64>> {
+        let Some(model) = self.get_latest_keyword_model(job_id)? else { return Ok(None) };
+        let keywords = self.get_job_keywords(job_id, Some(&model))?;
+        Ok(skill_match_ratio(&keywords, &self.get_user_skills()?))
+    }
+
+    // --- Negative keywords ---
+
+    /// Add permanent exclusions; returns how many were new.
+    pub fn add_negative_keywords(&self, keywords: &[String]) -> Result<usize> {
+        let mut added = 0;
+        for keyword in normalize_terms(keywords) {
+            added += self.conn.execute(
+                "INSERT OR IGNORE INTO negative_keywords (keyword) VALUES (?1)",
+                [&keyword],
+            )?;
+        }
+        Ok(added)
+    }
+
+    pub fn remove_negative_keywords(&self, keywords: &[String]) -> Result<usize> {
+        let mut removed = 0;
+        for keyword in normalize_terms(keywords) {
+            removed += self.conn.execute("DELETE FROM negative_keywords WHERE keyword = ?1", [&keyword])?;
+        }
+        Ok(removed)
+    }
+
+    pub fn get_negative_keywords(&self) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare("SELECT keyword FROM negative_keywords ORDER BY keyword")?;
+        let keywords = stmt.query_map([], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(keywords)
+    }
+
+    // --- Cleanup decisions ---
+
+    /// What `hunt cleanup --interactive` was told to always do with candidates like this:
+    /// "remove" or "keep". `kind` is "artifact" or "duplicate".
+    pub fn get_cleanup_decision(&self, kind: &str, pattern: &str) -> Result<Option<String>> {
+        match self.conn.query_row(
+            "SELECT decision FROM cleanup_decisions WHERE kind = ?1 AND pattern = ?2",
+            params![kind, pattern],
+            |row| row.get(0),
+        ) {
+            Ok(decision) => Ok(Some(decision)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn set_cleanup_decision(&self, kind: &str, pattern: &str, decision: &str) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO cleanup_decisions (kind, pattern, decision) VALUES (?1, ?2, ?3)
+             ON CONFLICT(kind, pattern) DO UPDATE SET decision = excluded.decision",
+            params![kind, pattern, decision],
+        )?;
+        Ok(())
+    }
+
+    /// Forget every remembered cleanup decision; returns how many there were.
+    pub fn clear_cleanup_decisions(&self) -> Result<usize> {
+        Ok(self.conn.execute("DELETE FROM cleanup_decisions", [])?)
+    }
+
+    /// Whether an email with this Message-ID has already been ingested.
+    pub fn is_email_ingested(&self, message_id: &str) -> Result<bool> {
+        Ok(self.conn.query_row(
+            "SELECT EXISTS(SELECT 1 FROM ingested_emails WHERE message_id = ?1)",
+            params![message_id.trim()],
+            |row| row.get(0),
+        )?)
+    }
+
+    /// Record an ingested email so later runs skip it. Reprocessing updates the record.
+    pub fn mark_email_ingested(&self, message_id: &str, subject: &str, jobs_added: usize) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO ingested_emails (message_id, subject, jobs_added) VALUES (?1, ?2, ?3)
+             ON CONFLICT(message_id) DO UPDATE SET subject = excluded.subject, jobs_added = excluded.jobs_added,
+                 ingested_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')",
+            params![message_id.trim(), subject, jobs_added as i64],
+        )?;
+        Ok(())
+    }
+
+    /// The first of `excluded` found in the job's title or latest extracted keywords (any weight).
+    pub fn find_excluded_keyword(&self, job: &Job, excluded: &[String]) -> Result<Option<String>> {
+        if excluded.is_empty() {
+            return Ok(None);
+        }
+        let keywords = match self.get_latest_keyword_model(job.id)? {
+            Some(model) => self.get_job_keywords(job.id, Some(&model))?,
+            None => Vec::new(),
+        };
+        Ok(excluded.iter()
+            .find(|term| {
+                skill_covers(term, &job.title) || keywords.iter().any(|k| skill_covers(term, &k.keyword))
+            })
+            .cloned())
+    }
+
+    // --- Review cards ---
+
+    /// Mark keywords as unknown, due for review now; returns how many were new.
+    pub fn add_review_cards(&self, keywords: &[String]) -> Result<usize> {
+        let mut added = 0;
+        for keyword in normalize_terms(keywords) {
+            added += self.conn.execute(
+                "INSERT OR IGNORE INTO review_cards (keyword) VALUES (?1)",
+                [&keyword],
+            )?;
+        }
+        Ok(added)
+    }
+
+    pub fn remove_review_cards(&self, keywords: &[String]) -> Result<usize> {
+        let mut removed = 0;
+        for keyword in normalize_terms(keywords) {
+            removed += self.conn.execute("DELETE FROM review_cards WHERE keyword = ?1", [&keyword])?;
+        }
+        Ok(removed)
+    }
+
+    /// All cards, soonest due first.
+    pub fn list_review_cards(&self) -> Result<Vec<ReviewCard>> {
+        self.query_review_cards("SELECT keyword, interval_days, ease, reps, lapses, due_at, last_reviewed_at, created_at
+             FROM review_cards ORDER BY due_at, keyword", [])
+    }
+
+    /// Cards due at or before `now` (a stored timestamp), most overdue first.
+    pub fn get_due_review_cards(&self, now: &str, limit: usize) -> Result<Vec<ReviewCard>> {
+        self.query_review_cards("SELECT keyword, interval_days, ease, reps, lapses, due_at, last_reviewed_at, created_at
+             FROM review_cards WHERE due_at <= ?1 ORDER BY due_at, keyword LIMIT ?2", params![now, limit as i64])
+    }
+
+    fn query_review_cards(&self, sql: &str, params: impl rusqlite::Params) -> Result<Vec<ReviewCard>> {
+        let mut stmt = self.conn.prepare(sql)?;
+        let cards = stmt.query_map(params, |row| {
+            Ok(ReviewCard {
+                keyword: row.get(0)?,
+                interval_days: row.get(1)?,
+                ease: row.get(2)?,
+                reps: row.get(3)?,
+                lapses: row.get(4)?,
+                due_at: row.get(5)?,
+                last_reviewed_at: row.get(6)?,
+                created_at: row.get(7)?,
+            })
+        })?.collect::<Result<Vec<_>, _>>()?;
+        Ok(cards)
+    }
+
+    /// Store a review's outcome: the new schedule, due at `due_at`.
+    pub fn record_review(&self, keyword: &str, state: &CardState, due_at: &str, lapsed: bool) -> Result<()> {
+        let updated = self.conn.execute(
+            "UPDATE review_cards SET interval_days = ?1, ease = ?2, reps = ?3, lapses = lapses + ?4,
+                due_at = ?5, last_reviewed_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+             WHERE keyword = ?6",
+            params![state.interval_days, state.ease, state.reps, lapsed as i64, due_at, keyword],
+        )?;
+        if updated == 0 {
+            return Err(HuntError::NotFound(format!("No review card for '{}'", keyword)).into());
+        }
+        Ok(())
+    }
+
+    // --- Learning plans ---
+
+    pub fn create_learning_plan(&self, hours_per_week: u32, source_model: &str, steps: &[LearningStep]) -> Result<i64> {
+        self.conn.execute(
+            "INSERT INTO learning_plans (hours_per_week, source_model) VALUES (?1, ?2)",
+            params![hours_per_week, source_model],
+        )?;
+        let plan_id = self.conn.last_insert_rowid();
+        for (position, step) in steps.iter().enumerate() {
+            self.conn.execute(
+                "INSERT INTO learning_items (plan_id, position, week, keyword, task, resource, milestone)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![plan_id, position as i64 + 1, step.week, step.keyword, step.task, step.resource, step.milestone],
+            )?;
+        }
+        Ok(plan_id)
+    }
+
+    /// The most recent plan's id and its items in order.
+    pub fn get_latest_learning_plan(&self) -> Result<Option<(i64, Vec<LearningItem>)>> {
+        let plan_id: Option<i64> = self.conn.query_row(
+            "SELECT MAX(id) FROM learning_plans",
+            [],
+            |row| row.get(0),
+        )?;
+        let Some(plan_id) = plan_id else { return Ok(None) };
+
+        let mut stmt = self.conn.prepare(
+            "SELECT id, plan_id, position, week, keyword, task, resource, milestone, done_at
+             FROM learning_items WHERE plan_id = ?1 ORDER BY position",
+        )?;
+        let items = stmt.query_map([plan_id], |row| {
+            Ok(LearningItem {
+                id: row.get(0)?,
+                plan_id: row.get(1)?,
+                position: row.get(2)?,
+                week: row.get(3)?,
+                keyword: row.get(4)?,
+                task: row.get(5)?,
+                resource: row.get(6)?,
+                milestone: row.get(7)?,
+                done_at: row.get(8)?,
+            })
+        })?.collect::<Result<Vec<_>, _>>()?;
+        Ok(Some((plan_id, items)))
+    }
+
+    /// Check an item off (or back on with `done = false`).
+    pub fn set_learning_item_done(&self, item_id: i64, done: bool) -> Result<()> {
+        let updated = self.conn.execute(
+            "UPDATE learning_items SET done_at = CASE WHEN ?1 THEN strftime('%Y-%m-%dT%H:%M:%SZ', 'now') ELSE NULL END WHERE id = ?2",
+            params![done, item_id],
+        )?;
+        if updated == 0 {
+            return Err(HuntError::NotFound(format!("Learning item #{} not found", item_id)).into());
+        }
+        Ok(())
+    }
+
+    // --- Job clusters ---
+
+    /// Replace all clusters with `clusters` (name, top keywords, member job ids).
+    pub fn replace_job_clusters(&self, clusters: &[(String, Vec<String>, Vec<i64>)], source_model: Option<&str>) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM job_cluster_members", [])?;
+        tx.execute("DELETE FROM job_clusters", [])?;
+        for (name, keywords, job_ids) in clusters {
+            tx.execute(
+                "INSERT INTO job_clusters (name, keywords, source_model) VALUES (?1, ?2, ?3)",
+                params![name, keywords.join(", "), source_model],
+            )?;
+            let cluster_id = tx.last_insert_rowid();
+            for job_id in job_ids {
+                tx.execute(
+                    "INSERT INTO job_cluster_members (job_id, cluster_id) VALUES (?1, ?2)",
+                    params![job_id, cluster_id],
+                )?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Stored clusters, largest first.
+    pub fn list_job_clusters(&self) -> Result<Vec<JobCluster>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, name, keywords, source_model, created_at FROM job_clusters ORDER BY id",
+        )?;
+        let mut clusters = stmt.query_map([], |row| {
+            Ok(JobCluster {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                keywords: row.get(2)?,
+                source_model: row.get(3)?,
+                job_ids: Vec::new(),
+                created_at: row.get(4)?,
+            })
+        })?.collect::<Result<Vec<_>, _>>()?;
+
+        let mut stmt = self.conn.prepare("SELECT cluster_id, job_id FROM job_cluster_members ORDER BY job_id")?;
+        let members = stmt.query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, i64>(1)?)))?;
+        for member in members {
+            let (cluster_id, job_id) = member?;
+            if let Some(cluster) = clusters.iter_mut().find(|c| c.id == cluster_id) {
+                cluster.job_ids.push(job_id);
+            }
+        }
+        clusters.sort_by(|a, b| b.job_ids.len().cmp(&a.job_ids.len()).then(a.id.cmp(&b.id)));
+        Ok(clusters)
+    }
+
+    /// Cluster name by job id, for jobs in a cluster.
+    pub fn get_job_cluster_names(&self) -> Result<HashMap<i64, String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT m.job_id, c.name FROM job_cluster_members m JOIN job_clusters c ON c.id = m.cluster_id",
+        )?;
+        let rows = stmt.query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))?;
+        Ok(rows.collect::<Result<HashMap<_, _>, _>>()?)
+    }
+
+    // --- Rank weights ---
+
+    /// Weight for every ranking factor, in `RANK_FACTORS` order; unset factors use defaults.
+    pub fn get_rank_weights(&self) -> Result<Vec<(String, f64)>> {
+        let mut stmt = self.conn.prepare("SELECT factor, weight FROM rank_weights")?;
+        let overrides = stmt.query_map([], |row| Ok((row.get::<_, String>(0)?, row.get::<_, f64>(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(RANK_FACTORS
+            .iter()
+            .map(|(name, default, _)| {
+                let weight = overrides.iter().find(|(f, _)| f == name).map(|(_, w)| *w).unwrap_or(*default);
+                (name.to_string(), weight)
+            })
+            .collect())
+    }
+
+    pub fn set_rank_weight(&self, factor: &str, weight: f64) -> Result<()> {
+        if !RANK_FACTORS.iter().any(|(name, _, _)| *name == factor) {
+            let names: Vec<&str> = RANK_FACTORS.iter().map(|(name, _, _)| *name).collect();
+            return Err(HuntError::Validation(format!(
+                "Unknown ranking factor '{}'. Available: {}",
+                factor,
+                names.join(", ")
+            )).into());
+        }
+        self.conn.execute(
+            "INSERT INTO rank_weights (factor, weight) VALUES (?1, ?2)
+             ON CONFLICT(factor) DO UPDATE SET weight = excluded.weight",
+            params![factor, weight],
+        )?;
+        Ok(())
+    }
+
+    pub fn reset_rank_weights(&self) -> Result<()> {
+        self.conn.execute("DELETE FROM rank_weights", [])?;
+        Ok(())
+    }
+
+    // --- Rank rules ---
+
+    /// Add or replace the rule for `keyword` (stored normalized, like exclusions).
+    pub fn set_rank_rule(&self, keyword: &str, points: f64) -> Result<()> {
+        let Some(keyword) = normalize_terms(&[keyword.to_string()]).pop() else {
+            return Err(HuntError::Validation("Rule keyword can't be empty".to_string()).into());
+        };
+        self.conn.execute(
+            "INSERT INTO rank_rules (keyword, points) VALUES (?1, ?2)
+             ON CONFLICT(keyword) DO UPDATE SET points = excluded.points",
+            params![keyword, points],
+        )?;
+        Ok(())
+    }
+
+    /// Returns whether there was a rule for `keyword`.
+    pub fn remove_rank_rule(&self, keyword: &str) -> Result<bool> {
+        let keyword = normalize_terms(&[keyword.to_string()]).pop().unwrap_or_default();
+        Ok(self.conn.execute("DELETE FROM rank_rules WHERE keyword = ?1", [keyword])? > 0)
+    }
+
+    /// Rules, biggest boosts first and biggest penalties last.
+    pub fn list_rank_rules(&self) -> Result<Vec<RankRule>> {
+        let mut stmt = self.conn.prepare("SELECT keyword, points, created_at FROM rank_rules ORDER BY points DESC, keyword")?;
+        let rows = stmt.query_map([], |row| {
+            Ok(RankRule { keyword: row.get(0)?, points: row.get(1)?, created_at: row.get(2)? })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list rank rules")
+    }
+
+    /// The rules a job matches: the rule's keyword is among the job's latest extracted
+    /// keywords (any weight) or in its title.
+    pub fn matching_rank_rules(&self, job: &Job) -> Result<Vec<RankRule>> {
+        let rules = self.list_rank_rules()?;
+        if rules.is_empty() {
+            return Ok(rules);
+        }
+        let keywords = match self.get_latest_keyword_model(job.id)? {
+            Some(model) => self.get_job_keywords(job.id, Some(&model))?,
+            None => Vec::new(),
+        };
+        Ok(rules.into_iter()
+            .filter(|r| skill_covers(&r.keyword, &job.title) || keywords.iter().any(|k| skill_covers(&r.keyword, &k.keyword)))
+            .collect())
+    }
+
+    // --- Saved filters ---
+
+    /// Add or replace a saved filter.
+    pub fn save_filter(&self, filter: &SavedFilter) -> Result<()> {
+        if filter.name.trim().is_empty() {
+            return Err(HuntError::Validation("Filter name can't be empty".to_string()).into());
+        }
+        self.conn.execute(
+            "INSERT INTO saved_filters (name, status, employer, tag, keyword, min_pay, max_pay, remote, location, easy_apply,
+                sponsors_visa, no_clearance)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+             ON CONFLICT(name) DO UPDATE SET
+                status = excluded.status, employer = excluded.employer, tag = excluded.tag,
+                keyword = excluded.keyword, min_pay = excluded.min_pay, max_pay = excluded.max_pay,
+                remote = excluded.remote, location = excluded.location, easy_apply = excluded.easy_apply,
+                sponsors_visa = excluded.sponsors_visa, no_clearance = excluded.no_clearance",
+            params![
+                filter.name.trim(), filter.status, filter.employer, filter.tag.as_deref().map(normalize_tag),
+                filter.keyword, filter.min_pay, filter.max_pay, filter.remote, filter.location, filter.easy_apply,
+                filter.sponsors_visa, filter.no_clearance,
+            ],
+        )?;
+        Ok(())
+    }
+
+    pub fn get_saved_filter(&self, name: &str) -> Result<Option<SavedFilter>> {
+        let result = self.conn.query_row(
+            &format!("SELECT {} FROM saved_filters WHERE name = ?1", SAVED_FILTER_COLUMNS),
+            [name.trim()],
+            Self::row_to_saved_filter,
+        );
+        match result {
+            Ok(filter) => Ok(Some(filter)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn list_saved_filters(&self) -> Result<Vec<SavedFilter>> {
+        let mut stmt = self.conn.prepare(&format!("SELECT {} FROM saved_filters ORDER BY name", SAVED_FILTER_COLUMNS))?;
+        let rows = stmt.query_map([], Self::row_to_saved_filter)?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list saved filters")
+    }
+
+    /// Returns whether there was a filter by that name.
+    pub fn remove_saved_filter(&self, name: &str) -> Result<bool> {
+        Ok(self.conn.execute("DELETE FROM saved_filters WHERE name = ?1", [name.trim()])? > 0)
+    }
+
+    fn row_to_saved_filter(row: &rusqlite::Row) -> rusqlite::Result<SavedFilter> {
+        Ok(SavedFilter {
+            name: row.get(0)?,
+            status: row.get(1)?,
+            employer: row.get(2)?,
+            tag: row.get(3)?,
+            keyword: row.get(4)?,
+            min_pay: row.get(5)?,
+            max_pay: row.get(6)?,
+            remote: row.get(7)?,
+            location: row.get(8)?,
+            easy_apply: row.get(9)?,
+            sponsors_visa: row.get(10)?,
+            no_clearance: row.get(11)?,
+            created_at: row.get(12)?,
+        })
+    }
+
+    /// Jobs matching every criterion of `filter`, archived ones instead if `archived`.
+    pub fn filter_jobs(&self, filter: &SavedFilter, archived: bool) -> Result<Vec<Job>> {
+        let jobs = self.query_jobs(filter, archived)?;
+        let keyword = filter.keyword.as_ref().map(|k| normalize_terms(std::slice::from_ref(k)));
+        let mut matching = Vec::with_capacity(jobs.len());
+        for job in jobs {
+            if filter.remote && job.work_mode.as_deref() != Some("remote") {
+                continue;
+            }
+            if filter.location.as_ref().is_some_and(|place| !location::matches(&job, place)) {
+                continue;
+            }
+            if let Some(keyword) = &keyword
+                && self.find_excluded_keyword(&job, keyword)?.is_none()
+            {
+                continue;
+            }
+            matching.push(job);
+        }
+        Ok(matching)
+    }
+
+    // --- Funding alerts ---
+
+    /// Employers with a job still in an active (non-terminal) status, excluding
+    /// blocked ones, whose funding hasn't been checked in `stale_days` days.
+    pub fn get_funding_watchlist(&self, stale_days: i64) -> Result<Vec<(i64, String)>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT DISTINCT e.id, e.name FROM employers e
+             JOIN jobs j ON j.employer_id = e.id
+             JOIN job_statuses s ON s.name = j.status
+             WHERE s.is_terminal = 0 AND e.status != 'never' AND j.archived_at IS NULL
+               AND (e.funding_checked_at IS NULL
+                    OR e.funding_checked_at < strftime('%Y-%m-%dT%H:%M:%SZ', 'now', '-' || ?1 || ' days'))
+             ORDER BY e.name",
+        )?;
+        let rows = stmt.query_map([stale_days], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to build funding watchlist")
+    }
+
+    /// Store a funding re-check and return the alerts it raised: a `new_round` when
+    /// the latest round differs from the one previously on record, plus any
+    /// down-round/layoff/acquisition event not already alerted on.
+    pub fn record_funding_check(&self, employer_id: i64, funding: &FundingResearch) -> Result<Vec<EmployerAlert>> {
+        let (prev_stage, prev_round): (Option<String>, Option<String>) = self.conn.query_row(
+            "SELECT funding_stage, last_funding_date FROM employers WHERE id = ?1",
+            [employer_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+
+        let mut alerts = Vec::new();
+        let had_baseline = prev_stage.is_some() || prev_round.is_some();
+        let round_changed = funding.last_round_date.is_some() && funding.last_round_date != prev_round;
+        if had_baseline && round_changed {
+            let summary = format!(
+                "New funding round: {} ({})",
+                funding.stage.as_deref().unwrap_or("unknown stage"),
+                funding.last_round_date.as_deref().unwrap_or_default()
+            );
+            alerts.push(("new_round", summary, funding.last_round_date.clone()));
+        }
+        for event in &funding.events {
+            alerts.push((event.kind.as_str(), event.summary.clone(), event.date.clone()));
+        }
+
+        let mut raised = Vec::new();
+        for (kind, summary, event_date) in alerts {
+            let exists: i64 = self.conn.query_row(
+                "SELECT COUNT(*) FROM employer_alerts WHERE employer_id = ?1 AND kind = ?2 AND event_date IS ?3",
+                params![employer_id, kind, event_date],
+                |row| row.get(0),
+            )?;
+            if exists > 0 {
+                continue;
+            }
+            self.conn.execute(
+                "INSERT INTO employer_alerts (employer_id, kind, summary, event_date) VALUES (?1, ?2, ?3, ?4)",
+                params![employer_id, kind, summary, event_date],
+            )?;
+            raised.push(self.conn.last_insert_rowid());
+        }
+
+        self.conn.execute(
+            "UPDATE employers SET
+                funding_stage = COALESCE(?1, funding_stage),
+                total_funding = COALESCE(?2, total_funding),
+                last_funding_date = COALESCE(?3, last_funding_date),
+                funding_checked_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now'),
+                updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+             WHERE id = ?4",
+            params![funding.stage, funding.total_funding, funding.last_round_date, employer_id],
+        )?;
+
+        Ok(self.list_employer_alerts(true)?
+            .into_iter()
+            .filter(|a| raised.contains(&a.id))
+            .collect())
+    }
+
+    pub fn list_employer_alerts(&self, include_acknowledged: bool) -> Result<Vec<EmployerAlert>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT a.id, a.employer_id, e.name, a.kind, a.summary, a.event_date, a.acknowledged, a.created_at
+             FROM employer_alerts a
+             JOIN employers e ON a.employer_id = e.id
+             WHERE ?1 OR a.acknowledged = 0
+             ORDER BY a.created_at DESC, a.id DESC",
+        )?;
+        let rows = stmt.query_map([include_acknowledged], |row| {
+            Ok(EmployerAlert {
+                id: row.get(0)?,
+                employer_id: row.get(1)?,
+                employer_name: row.get(2)?,
+                kind: row.get(3)?,
+                summary: row.get(4)?,
+                event_date: row.get(5)?,
+                acknowledged: row.get(6)?,
+                created_at: row.get(7)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list employer alerts")
+    }
+
+    /// Replace the stored HN stories for an employer with a fresh search's results.
+    pub fn replace_employer_hn_stories(&self, employer_id: i64, stories: &[HnStory]) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM employer_hn_stories WHERE employer_id = ?1", [employer_id])?;
+        for story in stories {
+            tx.execute(
+                "INSERT INTO employer_hn_stories (employer_id, title, url, hn_url, points, num_comments, posted_at)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+                params![employer_id, story.title, story.url, story.hn_url, story.points, story.num_comments, story.posted_at],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Stored HN stories for an employer, most points first.
+    pub fn list_employer_hn_stories(&self, employer_id: i64) -> Result<Vec<HnStory>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT title, url, hn_url, points, num_comments, posted_at FROM employer_hn_stories
+             WHERE employer_id = ?1 ORDER BY points DESC, id",
+        )?;
+        let rows = stmt.query_map([employer_id], |row| {
+            Ok(HnStory {
+                title: row.get(0)?,
+                url: row.get(1)?,
+                hn_url: row.get(2)?,
+                points: row.get(3)?,
+                num_comments: row.get(4)?,
+                posted_at: row.get(5)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list HN stories")
+    }
+
+    /// Replace an employer's compensation bands with a fresh `hunt employer comp` run.
+    pub fn replace_employer_comp(&self, employer_id: i64, bands: &[CompBand], source_model: &str) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM employer_comp WHERE employer_id = ?1", [employer_id])?;
+        for band in bands {
+            tx.execute(
+                "INSERT INTO employer_comp (employer_id, level, role, base_min, base_max, total_min, total_max, source_model)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
+                params![employer_id, band.level, band.role, band.base_min, band.base_max, band.total_min, band.total_max, source_model],
+            )?;
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// Stored compensation bands for an employer, lowest base first.
+    pub fn list_employer_comp(&self, employer_id: i64) -> Result<Vec<CompBand>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT level, role, base_min, base_max, total_min, total_max FROM employer_comp
+             WHERE employer_id = ?1 ORDER BY base_min IS NULL, base_min, total_min, id",
+        )?;
+        let rows = stmt.query_map([employer_id], |row| {
+            Ok(CompBand {
+                level: row.get(0)?,
+                role: row.get(1)?,
+                base_min: row.get(2)?,
+                base_max: row.get(3)?,
+                total_min: row.get(4)?,
+                total_max: row.get(5)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list compensation bands")
+    }
+
+    /// When the employer's bands were researched, and by which model.
+    pub fn employer_comp_source(&self, employer_id: i64) -> Result<Option<(String, String)>> {
+        let result = self.conn.query_row(
+            "SELECT created_at, source_model FROM employer_comp WHERE employer_id = ?1 ORDER BY id DESC LIMIT 1",
+            [employer_id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        );
+        match result {
+            Ok(source) => Ok(Some(source)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Mark all open alerts as seen; returns how many were acknowledged.
+    pub fn acknowledge_employer_alerts(&self) -> Result<usize> {
+        let count = self.conn.execute("UPDATE employer_alerts SET acknowledged = 1 WHERE acknowledged = 0", [])?;
+        Ok(count)
+    }
+
     fn row_to_employer(row: &rusqlite::Row) -> rusqlite::Result<Employer> {
         Ok(Employer {
             id: row.get(0)?,
@@ -656,6 +2828,12 @@ impl Database {
             glassdoor_rating: row.get(30)?,
             glassdoor_review_count: row.get(31)?,
             last_glassdoor_fetch: row.get(32)?,
+            eng_blog_url: row.get(33)?,
+            eng_rss_url: row.get(34)?,
+            eng_talks: row.get(35)?,
+            eng_culture_summary: row.get(36)?,
+            eng_research_updated: row.get(37)?,
+            careers_url: row.get(38)?,
         })
     }
 
@@ -690,40 +2868,107 @@ impl Database {
             params![job_id, content],
         )?;
 
+        let (job_location, work_mode) = location::from_description(content);
+        self.set_job_location(job_id, job_location.as_deref(), work_mode)?;
+        self.set_job_pay_details(job_id, content)?;
+        self.set_job_dates_from_text(job_id, content)?;
+        self.set_job_work_authorization(job_id, content)?;
+
         Ok(job_id)
     }
 
     pub fn list_jobs(&self, status: Option<&str>, employer: Option<&str>) -> Result<Vec<Job>> {
-        let mut sql = String::from(
-            "SELECT j.id, j.employer_id, e.name, j.title, j.url, j.source, j.status,
-                    j.pay_min, j.pay_max, j.job_code, j.raw_text, j.fetched_at, j.created_at, j.updated_at
+        self.list_jobs_filtered(status, employer, None)
+    }
+
+    /// Highest job id so far (0 for an empty database); jobs added later have larger ids.
+    pub fn max_job_id(&self) -> Result<i64> {
+        Ok(self.conn.query_row("SELECT COALESCE(MAX(id), 0) FROM jobs", [], |row| row.get(0))?)
+    }
+
+    /// Jobs not in a terminal status (the active pipeline).
+    pub fn list_active_jobs(&self) -> Result<Vec<Job>> {
+        let terminal: Vec<String> = self
+            .list_job_statuses()?
+            .into_iter()
+            .filter(|s| s.is_terminal)
+            .map(|s| s.name)
+            .collect();
+        Ok(self.list_jobs(None, None)?.into_iter().filter(|j| !terminal.contains(&j.status)).collect())
+    }
+
+    pub fn list_jobs_filtered(&self, status: Option<&str>, employer: Option<&str>, tag: Option<&str>) -> Result<Vec<Job>> {
+        let filter = SavedFilter {
+            status: status.map(str::to_string),
+            employer: employer.map(str::to_string),
+            tag: tag.map(str::to_string),
+            ..Default::default()
+        };
+        self.query_jobs(&filter, false)
+    }
+
+    /// The parts of `filter` SQL can answer: status, employer, tag, pay, Easy Apply and
+    /// work authorization. A pay floor needs the top of the advertised range to reach it and a
+    /// ceiling needs the bottom under it, so jobs that don't advertise pay pass neither.
+    fn query_jobs(&self, filter: &SavedFilter, archived: bool) -> Result<Vec<Job>> {
+        let mut sql = format!(
+            "SELECT {JOB_COLUMNS}
              FROM jobs j
              LEFT JOIN employers e ON j.employer_id = e.id
-             WHERE 1=1",
+             WHERE j.archived_at IS {}",
+            if archived { "NOT NULL" } else { "NULL" },
         );
 
-        let mut params: Vec<String> = vec![];
+        let mut params: Vec<rusqlite::types::Value> = vec![];
 
-        if let Some(s) = status {
+        if let Some(s) = &filter.status {
             sql.push_str(&format!(" AND j.status = ?{}", params.len() + 1));
-            params.push(s.to_string());
+            params.push(s.clone().into());
         }
 
-        if let Some(emp) = employer {
+        if let Some(emp) = &filter.employer {
             sql.push_str(&format!(" AND LOWER(e.name) = LOWER(?{})", params.len() + 1));
-            params.push(emp.to_string());
+            params.push(emp.clone().into());
+        }
+
+        if let Some(t) = &filter.tag {
+            sql.push_str(&format!(
+                " AND j.id IN (SELECT job_id FROM job_tags WHERE tag = ?{})",
+                params.len() + 1
+            ));
+            params.push(normalize_tag(t).into());
+        }
+
+        // The same expressions as idx_jobs_pay_top / idx_jobs_pay_bottom, so SQLite can use them
+        if let Some(floor) = filter.min_pay {
+            sql.push_str(&format!(" AND COALESCE(j.pay_max, j.pay_min) >= ?{}", params.len() + 1));
+            params.push(floor.into());
+        }
+
+        if let Some(ceiling) = filter.max_pay {
+            sql.push_str(&format!(" AND COALESCE(j.pay_min, j.pay_max) <= ?{}", params.len() + 1));
+            params.push(ceiling.into());
+        }
+
+        // Jobs that don't say (easy_apply NULL) match neither --easy-apply nor --external-apply
+        if let Some(easy_apply) = filter.easy_apply {
+            sql.push_str(&format!(" AND j.easy_apply = ?{}", params.len() + 1));
+            params.push(easy_apply.into());
+        }
+
+        // Only jobs that say so are left out; most postings never mention either
+        if filter.sponsors_visa {
+            sql.push_str(" AND (j.sponsors_visa IS NOT 0)");
+        }
+
+        if filter.no_clearance {
+            sql.push_str(" AND (j.requires_clearance IS NOT 1)");
         }
 
         sql.push_str(" ORDER BY j.id ASC");
 
         let mut stmt = self.conn.prepare(&sql)?;
-
-        let rows = match params.len() {
-            0 => stmt.query_map([], Self::row_to_job)?,
-            1 => stmt.query_map([&params[0]], Self::row_to_job)?,
-            2 => stmt.query_map([&params[0], &params[1]], Self::row_to_job)?,
-            _ => return Err(anyhow!("Too many parameters")),
-        };
+        let rows = stmt.query_map(rusqlite::params_from_iter(params), Self::row_to_job)?;
 
         rows.collect::<Result<Vec<_>, _>>()
             .context("Failed to list jobs")
@@ -731,11 +2976,12 @@ impl Database {
 
     pub fn get_job(&self, id: i64) -> Result<Option<Job>> {
         let result = self.conn.query_row(
-            "SELECT j.id, j.employer_id, e.name, j.title, j.url, j.source, j.status,
-                    j.pay_min, j.pay_max, j.job_code, j.raw_text, j.fetched_at, j.created_at, j.updated_at
-             FROM jobs j
-             LEFT JOIN employers e ON j.employer_id = e.id
-             WHERE j.id = ?1",
+            &format!(
+                "SELECT {JOB_COLUMNS}
+                 FROM jobs j
+                 LEFT JOIN employers e ON j.employer_id = e.id
+                 WHERE j.id = ?1"
+            ),
             [id],
             Self::row_to_job,
         );
@@ -746,9 +2992,37 @@ impl Database {
         }
     }
 
+    /// Open jobs not updated since `cutoff` (storage format), oldest first. Jobs you've
+    /// applied to are left out: the process goes on after a posting comes down.
+    pub fn stale_jobs(&self, cutoff: &str) -> Result<Vec<Job>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {JOB_COLUMNS}
+             FROM jobs j
+             LEFT JOIN employers e ON j.employer_id = e.id
+             WHERE j.updated_at < ?1 AND j.archived_at IS NULL
+               AND j.status IN (SELECT name FROM job_statuses
+                                WHERE is_terminal = 0
+                                  AND position < COALESCE((SELECT position FROM job_statuses WHERE name = 'applied'), 1e9))
+             ORDER BY j.updated_at, j.id"
+        ))?;
+        let rows = stmt.query_map([cutoff], Self::row_to_job)?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list stale jobs")
+    }
+
+    /// Mark a job as looked at just now, e.g. its posting was checked and is still up.
+    pub fn touch_job(&self, job_id: i64) -> Result<()> {
+        self.conn.execute(
+            "UPDATE jobs SET updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = ?1",
+            [job_id],
+        )?;
+        Ok(())
+    }
+
     pub fn get_jobs_to_fetch(&self, limit: Option<usize>, force: bool, include_closed: bool) -> Result<Vec<Job>> {
         let mut conditions = Vec::new();
         conditions.push("j.url IS NOT NULL".to_string());
+        conditions.push("j.archived_at IS NULL".to_string());
         if !force {
             conditions.push("j.fetched_at IS NULL".to_string());
         }
@@ -759,8 +3033,7 @@ impl Database {
 
         let query = if let Some(lim) = limit {
             format!(
-                "SELECT j.id, j.employer_id, e.name, j.title, j.url, j.source, j.status,
-                        j.pay_min, j.pay_max, j.job_code, j.raw_text, j.fetched_at, j.created_at, j.updated_at
+                "SELECT {JOB_COLUMNS}
                  FROM jobs j
                  LEFT JOIN employers e ON j.employer_id = e.id
                  WHERE {}
@@ -770,8 +3043,7 @@ impl Database {
             )
         } else {
             format!(
-                "SELECT j.id, j.employer_id, e.name, j.title, j.url, j.source, j.status,
-                        j.pay_min, j.pay_max, j.job_code, j.raw_text, j.fetched_at, j.created_at, j.updated_at
+                "SELECT {JOB_COLUMNS}
                  FROM jobs j
                  LEFT JOIN employers e ON j.employer_id = e.id
                  WHERE {}
@@ -787,15 +3059,19 @@ impl Database {
         Ok(jobs)
     }
 
-    pub fn rank_jobs(&self, limit: usize) -> Result<Vec<(Job, f64)>> {
-        // Get all non-closed jobs
-        let jobs = self.list_jobs(None, None)?;
+    /// The top `limit` active jobs by score, limited to those advertising pay between
+    /// `min_pay` and `max_pay` when given (see `query_jobs`).
+    pub fn rank_jobs(&self, limit: usize, filter: &SavedFilter) -> Result<Vec<(Job, f64)>> {
+        // Get all jobs still in an active (non-terminal) status
+        let jobs = self.filter_jobs(filter, false)?;
+        let statuses = self.list_job_statuses()?;
+        let terminal: Vec<&str> = statuses.iter().filter(|s| s.is_terminal).map(|s| s.name.as_str()).collect();
 
         let mut scored: Vec<(Job, f64)> = jobs
             .into_iter()
-            .filter(|j| j.status != "closed" && j.status != "rejected")
+            .filter(|j| !terminal.contains(&j.status.as_str()))
             .map(|job| {
-                let score = calculate_score(&job, self);
+                let score = calculate_score(&job, self, &statuses);
                 (job, score)
             })
             .collect();
@@ -823,6 +3099,21 @@ impl Database {
             fetched_at: row.get(11)?,
             created_at: row.get(12)?,
             updated_at: row.get(13)?,
+            location: row.get(14)?,
+            work_mode: row.get(15)?,
+            pay_text: row.get(16)?,
+            pay_equity: row.get(17)?,
+            archived_at: row.get(18)?,
+            applied_at: row.get(19)?,
+            rejected_at: row.get(20)?,
+            closed_at: row.get(21)?,
+            posted_at: row.get(22)?,
+            apply_deadline: row.get(23)?,
+            easy_apply: row.get(24)?,
+            apply_url: row.get(25)?,
+            ats: row.get(26)?,
+            requires_clearance: row.get(27)?,
+            sponsors_visa: row.get(28)?,
         })
     }
 
@@ -835,26 +3126,134 @@ impl Database {
         Ok(status)
     }
 
+    /// The job's employer status if it rules out AI work ("never" or "yuck").
+    pub fn blocked_employer_status(&self, job: &Job) -> Result<Option<String>> {
+        let Some(employer_id) = job.employer_id else { return Ok(None) };
+        let status = self.get_employer_status(employer_id)?;
+        Ok(BLOCKED_EMPLOYER_STATUSES.contains(&status.as_str()).then_some(status))
+    }
+
+    /// Drop jobs at blocked employers (unless `include_blocked`); returns the rest
+    /// and how many were dropped.
+    pub fn filter_blocked_employers(&self, jobs: Vec<Job>, include_blocked: bool) -> Result<(Vec<Job>, usize)> {
+        if include_blocked {
+            return Ok((jobs, 0));
+        }
+        let mut kept = Vec::with_capacity(jobs.len());
+        let mut blocked = 0;
+        for job in jobs {
+            if self.blocked_employer_status(&job)?.is_some() {
+                blocked += 1;
+            } else {
+                kept.push(job);
+            }
+        }
+        Ok((kept, blocked))
+    }
+
+    /// Archive a job: it drops out of lists, ranking and batch commands but keeps its
+    /// data, and still counts for de-duplication so ingestion won't bring it back.
+    /// `restore_job` undoes it; `purge_job` removes it for good.
     pub fn delete_job(&self, id: i64) -> Result<()> {
+        self.conn.execute(
+            "UPDATE jobs SET archived_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = ?1 AND archived_at IS NULL",
+            [id],
+        )?;
+        Ok(())
+    }
+
+    /// Bring an archived job back.
+    pub fn restore_job(&self, id: i64) -> Result<()> {
+        let job = self.get_job(id)?
+            .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", id)))?;
+        if job.archived_at.is_none() {
+            return Err(HuntError::Validation(format!("Job #{} isn't archived", id)).into());
+        }
+        self.conn.execute("UPDATE jobs SET archived_at = NULL WHERE id = ?1", [id])?;
+        Ok(())
+    }
+
+    /// Delete a job and everything attached to it, for good.
+    pub fn purge_job(&self, id: i64) -> Result<()> {
         // Delete associated data first (foreign key constraints)
-        self.conn.execute("DELETE FROM job_snapshots WHERE job_id = ?1", [id])?;
-        self.conn.execute("DELETE FROM resume_variants WHERE job_id = ?1", [id])?;
-        self.conn.execute("DELETE FROM job_keywords WHERE job_id = ?1", [id])?;
-        self.conn.execute("DELETE FROM job_keyword_profiles WHERE job_id = ?1", [id])?;
-        self.conn.execute("DELETE FROM fit_analyses WHERE job_id = ?1", [id])?;
+        for table in JOB_CHILD_TABLES {
+            self.conn.execute(&format!("DELETE FROM {} WHERE job_id = ?1", table), [id])?;
+        }
 
         // Delete the job
         self.conn.execute("DELETE FROM jobs WHERE id = ?1", [id])?;
         Ok(())
     }
 
+    /// Fold duplicate job `dup_id` into `keep_id`: snapshots, variants, analyses, notes,
+    /// tags and status history are re-pointed, keyword sets `keep` lacks are moved over,
+    /// fields `keep` is missing (description, URL, pay, location...) are copied, and the
+    /// status further along the pipeline wins. A note on `keep` records the merge; `dup`
+    /// itself is left for the caller to archive or purge.
+    pub fn merge_jobs(&self, keep_id: i64, dup_id: i64) -> Result<()> {
+        if keep_id == dup_id {
+            return Err(HuntError::Validation("Can't merge a job into itself".to_string()).into());
+        }
+        let load = |id: i64| -> Result<(String, String)> {
+            self.conn.query_row("SELECT title, status FROM jobs WHERE id = ?1", [id], |row| Ok((row.get(0)?, row.get(1)?)))
+                .map_err(|e| match e {
+                    rusqlite::Error::QueryReturnedNoRows => HuntError::NotFound(format!("Job #{} not found", id)).into(),
+                    e => e.into(),
+                })
+        };
+        let (dup_title, dup_status) = load(dup_id)?;
+        let (_, keep_status) = load(keep_id)?;
+
+        let position = |status: &str| -> Result<i64> {
+            Ok(self.get_job_status(status)?.map_or(0, |s| s.position))
+        };
+        let status = if position(&dup_status)? > position(&keep_status)? { dup_status } else { keep_status.clone() };
+
+        let tx = self.conn.unchecked_transaction()?;
+        // Keywords have no unique key, so take only the models keep hasn't extracted with
+        tx.execute(
+            "UPDATE job_keywords SET job_id = ?1 WHERE job_id = ?2
+               AND source_model NOT IN (SELECT source_model FROM job_keywords WHERE job_id = ?1)",
+            params![keep_id, dup_id],
+        )?;
+        // Rows keep already has its own of (same variant, analysis, tag...) stay behind
+        for table in JOB_CHILD_TABLES.iter().filter(|t| **t != "job_keywords") {
+            tx.execute(&format!("UPDATE OR IGNORE {} SET job_id = ?1 WHERE job_id = ?2", table), params![keep_id, dup_id])?;
+        }
+        let fills: Vec<String> = ["employer_id", "url", "source", "pay_min", "pay_max", "job_code", "raw_text",
+            "fetched_at", "location", "work_mode", "pay_text", "rejected_at", "closed_at", "posted_at", "apply_deadline",
+            "easy_apply", "apply_url", "ats", "requires_clearance", "sponsors_visa"]
+            .iter()
+            .map(|c| format!("{c} = COALESCE({c}, (SELECT {c} FROM jobs WHERE id = ?1))"))
+            .collect();
+        tx.execute(
+            &format!(
+                "UPDATE jobs SET {},
+                    pay_equity = MAX(pay_equity, (SELECT pay_equity FROM jobs WHERE id = ?1)),
+                    created_at = MIN(created_at, (SELECT created_at FROM jobs WHERE id = ?1)),
+                    applied_at = COALESCE(MIN(applied_at, (SELECT applied_at FROM jobs WHERE id = ?1)),
+                                          applied_at, (SELECT applied_at FROM jobs WHERE id = ?1)),
+                    updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
+                 WHERE id = ?2",
+                fills.join(", ")
+            ),
+            params![dup_id, keep_id],
+        )?;
+        if status != keep_status {
+            self.update_job_status(keep_id, &status)?;
+        }
+        self.add_job_note(keep_id, &format!("Merged duplicate job #{} ('{}')", dup_id, dup_title))?;
+        tx.commit()?;
+        Ok(())
+    }
+
     // --- Email ingestion support ---
 
     #[allow(dead_code)]
     pub fn job_exists_by_url(&self, url: &str) -> Result<bool> {
         let count: i64 = self.conn.query_row(
             "SELECT COUNT(*) FROM jobs WHERE url = ?1",
-            [url],
+            [domains::canonical_job_url(url)],
             |row| row.get(0),
         )?;
         Ok(count > 0)
@@ -879,15 +3278,13 @@ impl Database {
         employer: Option<&str>,
         url: Option<&str>,
     ) -> Result<Option<i64>> {
-        // Rule 1: Check by URL if present (exact match)
+        // Rule 1: Check by URL if present (same posting once tracking is stripped), including
+        // where an aggregator's posting was resolved to send applications
         if let Some(url) = url {
             let result: Option<i64> = self
                 .conn
-                .query_row(
-                    "SELECT id FROM jobs WHERE url = ?1",
-                    [url],
-                    |row| row.get(0),
-                )
+                .prepare_cached("SELECT id FROM jobs WHERE url = ?1 OR apply_url = ?1 ORDER BY id LIMIT 1")?
+                .query_row([domains::canonical_job_url(url)], |row| row.get(0))
                 .ok();
             if result.is_some() {
                 return Ok(result);
@@ -896,39 +3293,43 @@ impl Database {
 
         // Rules 2-4: Check by title similarity with same employer
         if let Some(employer) = employer {
+            // Rule 2 first through the title index: most repeats are exact
+            let exact: Option<i64> = self
+                .conn
+                .prepare_cached(
+                    "SELECT j.id
+                     FROM jobs j
+                     JOIN employers e ON j.employer_id = e.id
+                     WHERE lower(trim(j.title)) = lower(trim(?1))
+                       AND (LOWER(e.name) = LOWER(?2)
+                            OR e.id = (SELECT employer_id FROM employer_aliases WHERE alias_key = ?3))
+                     LIMIT 1",
+                )?
+                .query_row(params![title, employer, normalize_name(employer)], |row| row.get(0))
+                .ok();
+            if exact.is_some() {
+                return Ok(exact);
+            }
+
             // Get all jobs from this employer
-            let mut stmt = self.conn.prepare(
+            let mut stmt = self.conn.prepare_cached(
                 "SELECT j.id, j.title
                  FROM jobs j
                  JOIN employers e ON j.employer_id = e.id
-                 WHERE LOWER(e.name) = LOWER(?1)",
+                 WHERE LOWER(e.name) = LOWER(?1)
+                    OR e.id = (SELECT employer_id FROM employer_aliases WHERE alias_key = ?2)",
             )?;
 
-            let jobs = stmt.query_map([employer], |row| {
+            let jobs = stmt.query_map(params![employer, normalize_name(employer)], |row| {
                 Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?))
             })?;
 
             let title_normalized = normalize_title(title);
 
+            // Rules 2-4: exact (as Rust lowercases it), substring, or >80% similar
             for job_result in jobs {
                 let (job_id, existing_title) = job_result?;
-                let existing_normalized = normalize_title(&existing_title);
-
-                // Rule 2: Exact match (case-insensitive, normalized)
-                if title_normalized == existing_normalized {
-                    return Ok(Some(job_id));
-                }
-
-                // Rule 3: Substring match - if new title is substring of existing or vice versa
-                if existing_normalized.contains(&title_normalized)
-                    || title_normalized.contains(&existing_normalized)
-                {
-                    return Ok(Some(job_id));
-                }
-
-                // Rule 4: Fuzzy match - >80% similar
-                let similarity = strsim::jaro_winkler(&title_normalized, &existing_normalized);
-                if similarity > 0.8 {
+                if titles_match(&title_normalized, &normalize_title(&existing_title)) {
                     return Ok(Some(job_id));
                 }
             }
@@ -937,74 +3338,60 @@ impl Database {
         Ok(None)
     }
 
-    /// Find and return all duplicate jobs
+    /// Find all duplicate jobs: (earlier job, duplicate, description). Each job is only
+    /// compared with earlier ones at the same URL or employer, so this stays fast with
+    /// thousands of jobs. Two jobs that both have URLs are duplicates only if the URLs match.
     pub fn find_duplicates(&self) -> Result<Vec<(i64, i64, String)>> {
-        let mut duplicates = Vec::new();
-
-        // Get all jobs with their employer info
         let mut stmt = self.conn.prepare(
-            "SELECT j.id, j.title, j.url, e.name, j.created_at
+            "SELECT j.id, j.title, j.url, e.name, j.apply_url
              FROM jobs j
              LEFT JOIN employers e ON j.employer_id = e.id
+             WHERE j.archived_at IS NULL
              ORDER BY j.created_at ASC",
         )?;
-
-        let jobs: Vec<(i64, String, Option<String>, Option<String>, String)> = stmt
+        let jobs = stmt
             .query_map([], |row| {
                 Ok((
-                    row.get(0)?,
-                    row.get(1)?,
-                    row.get(2)?,
-                    row.get(3)?,
-                    row.get(4)?,
+                    row.get::<_, i64>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, Option<String>>(2)?,
+                    row.get::<_, Option<String>>(3)?,
+                    row.get::<_, Option<String>>(4)?,
                 ))
             })?
             .collect::<Result<Vec<_>, _>>()?;
+        let titles: Vec<String> = jobs.iter().map(|(_, title, ..)| normalize_title(title)).collect();
 
-        // Check each job against earlier jobs
-        for i in 1..jobs.len() {
-            let (job_id, title, url, employer, _) = &jobs[i];
-
-            for j in 0..i {
-                let (earlier_id, earlier_title, earlier_url, earlier_employer, _) = &jobs[j];
-
-                // Skip if already marked as duplicate
-                if duplicates.iter().any(|(_, dup_id, _)| dup_id == job_id) {
-                    continue;
-                }
-
-                // Check if this is a duplicate
-                let is_dup = if let (Some(url), Some(earlier_url)) = (url, earlier_url) {
-                    // URL match
-                    url == earlier_url
-                } else if let (Some(emp), Some(earlier_emp)) = (employer, earlier_employer) {
-                    if emp.to_lowercase() == earlier_emp.to_lowercase() {
-                        let title_norm = normalize_title(title);
-                        let earlier_norm = normalize_title(earlier_title);
-
-                        // Same employer - check title similarity
-                        title_norm == earlier_norm
-                            || title_norm.contains(&earlier_norm)
-                            || earlier_norm.contains(&title_norm)
-                            || strsim::jaro_winkler(&title_norm, &earlier_norm) > 0.8
-                    } else {
-                        false
-                    }
-                } else {
-                    false
-                };
-
-                if is_dup {
-                    duplicates.push((
-                        *earlier_id,
-                        *job_id,
-                        format!(
-                            "Job #{} ('{}') duplicates job #{} ('{}')",
-                            job_id, title, earlier_id, earlier_title
-                        ),
-                    ));
-                    break;
-                }
+        // Earlier jobs by URL and by employer, as indexes into `jobs`
+        // URLs stored before canonicalization still match their cleaned-up twins
+        let mut first_at_url: HashMap<String, usize> = HashMap::new();
+        let mut by_employer: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut duplicates = Vec::new();
+        for (i, (job_id, title, url, employer, apply_url)) in jobs.iter().enumerate() {
+            let employer_key = employer.as_deref().map(str::to_lowercase);
+            // A LinkedIn posting resolved to a Workday page is the same job as that page
+            let url_keys: Vec<String> = url.as_deref().map(domains::canonical_job_url).into_iter().chain(apply_url.clone()).collect();
+            let same_url = url_keys.iter().filter_map(|u| first_at_url.get(u).copied()).min();
+            let same_title = employer_key.as_ref()
+                .and_then(|key| by_employer.get(key))
+                .and_then(|earlier| {
+                    earlier.iter().copied().find(|&j| {
+                        (url.is_none() || jobs[j].2.is_none()) && titles_match(&titles[i], &titles[j])
+                    })
+                });
+            if let Some(j) = same_url.into_iter().chain(same_title).min() {
+                let (earlier_id, earlier_title, ..) = &jobs[j];
+                duplicates.push((
+                    *earlier_id,
+                    *job_id,
+                    format!("Job #{} ('{}') duplicates job #{} ('{}')", job_id, title, earlier_id, earlier_title),
+                ));
+            }
+            for key in url_keys {
+                first_at_url.entry(key).or_insert(i);
+            }
+            if let Some(key) = employer_key {
+                by_employer.entry(key).or_default().push(i);
             }
         }
 
@@ -1021,6 +3408,8 @@ impl Database {
         pay_max: Option<i64>,
         raw_text: Option<&str>,
     ) -> Result<i64> {
+        let url = url.map(domains::canonical_job_url);
+        let url = url.as_deref();
         let employer_id = if let Some(name) = employer {
             Some(self.get_or_create_employer(name)?)
         } else {
@@ -1030,30 +3419,69 @@ impl Database {
         // Extract job code from raw text if available
         let job_code = raw_text.and_then(|text| extract_job_code(text));
 
-        self.conn.execute(
+        self.conn.prepare_cached(
             "INSERT INTO jobs (employer_id, title, url, source, pay_min, pay_max, job_code, raw_text)
              VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)",
-            params![employer_id, title, url, source, pay_min, pay_max, job_code, raw_text],
-        )?;
+        )?.execute(params![employer_id, title, url, source, pay_min, pay_max, job_code, raw_text])?;
 
         let job_id = self.conn.last_insert_rowid();
 
+        // The posting URL often gives away the employer's domain or careers page
+        if let (Some(employer_id), Some(url)) = (employer_id, url) {
+            self.set_employer_links(employer_id, &domains::infer_from_job_url(url), false)?;
+        }
+
         // Create initial snapshot if we have raw text
         if let Some(text) = raw_text {
-            self.conn.execute(
-                "INSERT INTO job_snapshots (job_id, raw_text) VALUES (?1, ?2)",
-                params![job_id, text],
-            )?;
+            self.conn.prepare_cached("INSERT INTO job_snapshots (job_id, raw_text) VALUES (?1, ?2)")?
+                .execute(params![job_id, text])?;
+            self.set_job_pay_details(job_id, text)?;
+            self.set_job_dates_from_text(job_id, text)?;
         }
 
         Ok(job_id)
     }
 
+    /// Add jobs in one transaction, skipping duplicates of stored jobs and of earlier
+    /// ones in the batch. Returns each job's new ID, or None for a duplicate.
+    pub fn add_jobs_batch(&self, jobs: &[NewJob]) -> Result<Vec<Option<i64>>> {
+        let tx = self.conn.unchecked_transaction()?;
+        let mut ids = Vec::with_capacity(jobs.len());
+        for job in jobs {
+            if self.is_duplicate_job(&job.title, job.employer.as_deref(), job.url.as_deref())?.is_some() {
+                ids.push(None);
+                continue;
+            }
+            let job_id = self.add_job_full(
+                &job.title,
+                job.employer.as_deref(),
+                job.url.as_deref(),
+                job.source.as_deref(),
+                job.pay_min,
+                job.pay_max,
+                job.raw_text.as_deref(),
+            )?;
+            self.set_job_location(job_id, job.location.as_deref(), job.work_mode)?;
+            self.set_job_dates(job_id, job.posted_at.as_deref(), job.apply_deadline.as_deref())?;
+            ids.push(Some(job_id));
+        }
+        tx.commit()?;
+        Ok(ids)
+    }
+
+    pub fn update_job_title(&self, job_id: i64, title: &str) -> Result<()> {
+        self.conn.execute(
+            "UPDATE jobs SET title = ?1, updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = ?2",
+            params![title, job_id],
+        )?;
+        Ok(())
+    }
+
     /// Update the employer for a job (find or create the employer, then update the FK)
     pub fn update_job_employer(&self, job_id: i64, employer_name: &str) -> Result<()> {
         let employer_id = self.get_or_create_employer(employer_name)?;
         self.conn.execute(
-            "UPDATE jobs SET employer_id = ?1, updated_at = datetime('now') WHERE id = ?2",
+            "UPDATE jobs SET employer_id = ?1, updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = ?2",
             params![employer_id, job_id],
         )?;
         Ok(())
@@ -1062,28 +3490,322 @@ impl Database {
     pub fn update_job_description(&self, job_id: i64, description: &str, pay_min: Option<i64>, pay_max: Option<i64>) -> Result<()> {
         self.conn.execute(
             "UPDATE jobs
-             SET raw_text = ?1, pay_min = ?2, pay_max = ?3, fetched_at = datetime('now'), updated_at = datetime('now')
+             SET raw_text = ?1, pay_min = ?2, pay_max = ?3, fetched_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now'), updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
              WHERE id = ?4",
             params![description, pay_min, pay_max, job_id],
         )?;
 
-        // Create a snapshot of the new description
+        // Snapshot the new description, unless it's the same as the last one
+        self.conn.execute(
+            "INSERT INTO job_snapshots (job_id, raw_text)
+             SELECT ?1, ?2
+             WHERE ?2 IS NOT (SELECT raw_text FROM job_snapshots WHERE job_id = ?1 ORDER BY id DESC LIMIT 1)",
+            params![job_id, description],
+        )?;
+
+        let (job_location, work_mode) = location::from_description(description);
+        self.set_job_location(job_id, job_location.as_deref(), work_mode)?;
+        self.set_job_dates_from_text(job_id, description)?;
+        self.set_job_work_authorization(job_id, description)?;
+        self.set_job_pay_details(job_id, description)
+    }
+
+    /// Set a job's annual USD pay range and re-read its pay wording from `description`.
+    pub fn update_job_pay(&self, job_id: i64, pay_min: Option<i64>, pay_max: Option<i64>, description: &str) -> Result<()> {
+        self.conn.execute(
+            "UPDATE jobs SET pay_min = ?1, pay_max = ?2, updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = ?3",
+            params![pay_min, pay_max, job_id],
+        )?;
+        self.set_job_pay_details(job_id, description)
+    }
+
+    /// Keep the pay as the description words it, and whether equity is on offer. A
+    /// description with no pay leaves the stored text alone.
+    fn set_job_pay_details(&self, job_id: i64, description: &str) -> Result<()> {
+        let pay_text = pay::parse(description).map(|pay| pay.text);
+        self.conn.prepare_cached("UPDATE jobs SET pay_text = COALESCE(?1, pay_text), pay_equity = ?2 WHERE id = ?3")?
+            .execute(params![pay_text, pay::mentions_equity(description), job_id])?;
+        Ok(())
+    }
+
+    pub fn set_job_url(&self, job_id: i64, url: &str) -> Result<()> {
+        self.conn.execute(
+            "UPDATE jobs SET url = ?1, updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = ?2",
+            params![url, job_id],
+        )?;
+        Ok(())
+    }
+
+    /// Record where a job is and how it's worked. None leaves what's stored alone.
+    pub fn set_job_location(&self, job_id: i64, location: Option<&str>, work_mode: Option<&str>) -> Result<()> {
+        self.conn.prepare_cached("UPDATE jobs SET location = COALESCE(?1, location), work_mode = COALESCE(?2, work_mode) WHERE id = ?3")?
+            .execute(params![location, work_mode, job_id])?;
+        Ok(())
+    }
+
+    /// Record when a job was posted and when applications close (YYYY-MM-DD). None
+    /// leaves what's stored alone.
+    pub fn set_job_dates(&self, job_id: i64, posted_at: Option<&str>, apply_deadline: Option<&str>) -> Result<()> {
+        self.conn.prepare_cached("UPDATE jobs SET posted_at = COALESCE(?1, posted_at), apply_deadline = COALESCE(?2, apply_deadline) WHERE id = ?3")?
+            .execute(params![posted_at, apply_deadline, job_id])?;
+        Ok(())
+    }
+
+    /// Record whether a LinkedIn job takes Easy Apply (true) or sends applicants to the
+    /// employer's site (false). None, when the page didn't say, leaves what's stored alone.
+    pub fn set_job_easy_apply(&self, job_id: i64, easy_apply: Option<bool>) -> Result<()> {
+        self.conn.prepare_cached("UPDATE jobs SET easy_apply = COALESCE(?1, easy_apply) WHERE id = ?2")?
+            .execute(params![easy_apply, job_id])?;
+        Ok(())
+    }
+
+    /// Record where a job takes applications (`hunt resolve`, fetches).
+    pub fn set_job_application(&self, job_id: i64, application: &domains::Application) -> Result<()> {
+        self.conn.execute(
+            "UPDATE jobs SET apply_url = ?1, ats = COALESCE(?2, ats) WHERE id = ?3",
+            params![application.url, application.ats, job_id],
+        )?;
+        Ok(())
+    }
+
+    /// Another job at the same posting or application URL as `job_id`'s application URL.
+    pub fn job_sharing_apply_url(&self, job_id: i64) -> Result<Option<i64>> {
+        let found = self.conn.query_row(
+            "SELECT other.id FROM jobs j JOIN jobs other
+               ON other.id != j.id AND other.archived_at IS NULL AND (other.url = j.apply_url OR other.apply_url = j.apply_url)
+             WHERE j.id = ?1 ORDER BY other.id LIMIT 1",
+            [job_id],
+            |row| row.get(0),
+        );
+        match found {
+            Ok(id) => Ok(Some(id)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Jobs with a URL but no resolved application URL yet, oldest first; archived and
+    /// finished (terminal status) jobs are left out.
+    pub fn get_jobs_to_resolve(&self, limit: Option<usize>) -> Result<Vec<Job>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {JOB_COLUMNS}
+             FROM jobs j
+             LEFT JOIN employers e ON j.employer_id = e.id
+             JOIN job_statuses s ON s.name = j.status
+             WHERE j.url IS NOT NULL AND j.apply_url IS NULL AND j.archived_at IS NULL AND s.is_terminal = 0
+             ORDER BY j.created_at ASC
+             LIMIT ?1"
+        ))?;
+        let jobs = stmt
+            .query_map([limit.map_or(-1, |l| l as i64)], Self::row_to_job)?
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(jobs)
+    }
+
+    /// `set_job_dates` with whatever `text` says, relative dates counted from today.
+    fn set_job_dates_from_text(&self, job_id: i64, text: &str) -> Result<()> {
+        let found = dates::from_text(text, dates::today());
+        self.set_job_dates(job_id, found.posted_at().as_deref(), found.deadline().as_deref())
+    }
+
+    /// Record whether `text`, the job's description, asks for a security clearance and
+    /// what it says about visa sponsorship.
+    fn set_job_work_authorization(&self, job_id: i64, text: &str) -> Result<()> {
+        let found = flags::work_authorization(text);
+        self.conn.prepare_cached("UPDATE jobs SET requires_clearance = ?1, sponsors_visa = ?2 WHERE id = ?3")?
+            .execute(params![found.requires_clearance, found.sponsors_visa, job_id])?;
+        Ok(())
+    }
+
+    /// Set or clear (None) a job's application deadline, for `hunt set --deadline`.
+    pub fn set_job_deadline(&self, job_id: i64, apply_deadline: Option<&str>) -> Result<()> {
+        self.conn.execute(
+            "UPDATE jobs SET apply_deadline = ?1, updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = ?2",
+            params![apply_deadline, job_id],
+        )?;
+        Ok(())
+    }
+
+    pub fn update_job_status(&self, job_id: i64, status: &str) -> Result<()> {
+        if self.get_job_status(status)?.is_none() {
+            let names: Vec<String> = self.list_job_statuses()?.into_iter().map(|s| s.name).collect();
+            return Err(HuntError::Validation(format!(
+                "Unknown status '{}'. Available: {}",
+                status,
+                names.join(", ")
+            )).into());
+        }
+        let previous: Option<String> = match self.conn.query_row(
+            "SELECT status FROM jobs WHERE id = ?1",
+            [job_id],
+            |row| row.get(0),
+        ) {
+            Ok(previous) => Some(previous),
+            Err(rusqlite::Error::QueryReturnedNoRows) => None,
+            Err(e) => return Err(e.into()),
+        };
+        // applied_at keeps the first application, unless the job is (forcibly) moved back to a
+        // non-terminal status ahead of applied; rejected_at and closed_at keep the latest
+        self.conn.execute(
+            "UPDATE jobs SET status = ?1, updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now'),
+                 applied_at = CASE
+                     WHEN ?1 = 'applied' THEN COALESCE(applied_at, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+                     WHEN (SELECT position FROM job_statuses WHERE name = ?1 AND NOT is_terminal)
+                          < (SELECT position FROM job_statuses WHERE name = 'applied') THEN NULL
+                     ELSE applied_at
+                 END,
+                 rejected_at = CASE WHEN ?1 = 'rejected' AND status != ?1 THEN strftime('%Y-%m-%dT%H:%M:%SZ', 'now') ELSE rejected_at END,
+                 closed_at = CASE WHEN ?1 = 'closed' AND status != ?1 THEN strftime('%Y-%m-%dT%H:%M:%SZ', 'now') ELSE closed_at END
+             WHERE id = ?2",
+            params![status, job_id],
+        )?;
+        if let Some(previous) = previous.filter(|p| p != status) {
+            self.conn.execute(
+                "INSERT INTO status_history (job_id, from_status, to_status) VALUES (?1, ?2, ?3)",
+                params![job_id, previous, status],
+            )?;
+        }
+        Ok(())
+    }
+
+    /// Why moving a job from `from` to `to` needs `--force`, if it does: reopening a
+    /// terminal status, or going back to an earlier one once the job has been applied
+    /// for. Moves forward and into terminal statuses are always fine.
+    pub fn transition_refusal(&self, from: &str, to: &str) -> Result<Option<String>> {
+        let (Some(from_status), Some(to_status)) = (self.get_job_status(from)?, self.get_job_status(to)?) else {
+            return Ok(None);
+        };
+        if from == to || to_status.is_terminal {
+            return Ok(None);
+        }
+        if from_status.is_terminal {
+            return Ok(Some(format!("'{}' is terminal; reopening it as '{}'", from, to)));
+        }
+        let applied = self.get_job_status("applied")?.map_or(i64::MIN, |s| s.position);
+        if to_status.position < from_status.position && from_status.position >= applied {
+            return Ok(Some(format!("'{}' comes before '{}'", to, from)));
+        }
+        Ok(None)
+    }
+
+    /// `update_job_status` for a person's request: refuses guarded transitions (see
+    /// `transition_refusal`) unless `force`.
+    pub fn set_job_status(&self, job_id: i64, status: &str, force: bool) -> Result<()> {
+        let job = self.get_job(job_id)?.ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+        if !force && let Some(reason) = self.transition_refusal(&job.status, status)? {
+            return Err(HuntError::Validation(format!(
+                "Job #{} is '{}': {}. Use --force to move it anyway",
+                job_id, job.status, reason
+            )).into());
+        }
+        self.update_job_status(job_id, status)
+    }
+
+    /// Status transitions, oldest first: one job's, or every job's when `job_id` is None.
+    pub fn list_status_history(&self, job_id: Option<i64>) -> Result<Vec<StatusChange>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, job_id, from_status, to_status, changed_at FROM status_history
+             WHERE ?1 IS NULL OR job_id = ?1
+             ORDER BY changed_at, id",
+        )?;
+        let rows = stmt.query_map([job_id], |row| {
+            Ok(StatusChange {
+                id: row.get(0)?,
+                job_id: row.get(1)?,
+                from_status: row.get(2)?,
+                to_status: row.get(3)?,
+                changed_at: row.get(4)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list status history")
+    }
+
+    // --- Job status operations ---
+
+    pub fn list_job_statuses(&self) -> Result<Vec<JobStatus>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT name, position, is_terminal, color FROM job_statuses ORDER BY position, name",
+        )?;
+        let rows = stmt.query_map([], Self::row_to_job_status)?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list job statuses")
+    }
+
+    pub fn get_job_status(&self, name: &str) -> Result<Option<JobStatus>> {
+        let result = self.conn.query_row(
+            "SELECT name, position, is_terminal, color FROM job_statuses WHERE name = ?1",
+            [name],
+            Self::row_to_job_status,
+        );
+        match result {
+            Ok(status) => Ok(Some(status)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Add a status, or update an existing one. Without a position it goes to the end of the pipeline.
+    pub fn upsert_job_status(
+        &self,
+        name: &str,
+        position: Option<i64>,
+        is_terminal: bool,
+        color: &str,
+    ) -> Result<()> {
+        let position = match position {
+            Some(p) => p,
+            None => match self.get_job_status(name)? {
+                Some(existing) => existing.position,
+                None => self.conn.query_row(
+                    "SELECT COALESCE(MAX(position), 0) + 10 FROM job_statuses",
+                    [],
+                    |row| row.get(0),
+                )?,
+            },
+        };
         self.conn.execute(
-            "INSERT INTO job_snapshots (job_id, raw_text) VALUES (?1, ?2)",
-            params![job_id, description],
+            "INSERT INTO job_statuses (name, position, is_terminal, color)
+             VALUES (?1, ?2, ?3, ?4)
+             ON CONFLICT(name) DO UPDATE SET
+                position = excluded.position,
+                is_terminal = excluded.is_terminal,
+                color = excluded.color",
+            params![name, position, is_terminal, color],
         )?;
-
         Ok(())
     }
 
-    pub fn update_job_status(&self, job_id: i64, status: &str) -> Result<()> {
-        self.conn.execute(
-            "UPDATE jobs SET status = ?1, updated_at = datetime('now') WHERE id = ?2",
-            params![status, job_id],
+    /// Remove a status. Refuses if any job still uses it.
+    pub fn remove_job_status(&self, name: &str) -> Result<()> {
+        let in_use: i64 = self.conn.query_row(
+            "SELECT COUNT(*) FROM jobs WHERE status = ?1",
+            [name],
+            |row| row.get(0),
         )?;
+        if in_use > 0 {
+            return Err(HuntError::Validation(format!(
+                "Status '{}' is used by {} job(s); move them to another status first",
+                name,
+                in_use
+            )).into());
+        }
+        let removed = self.conn.execute("DELETE FROM job_statuses WHERE name = ?1", [name])?;
+        if removed == 0 {
+            return Err(HuntError::NotFound(format!("Status '{}' not found", name)).into());
+        }
         Ok(())
     }
 
+    fn row_to_job_status(row: &rusqlite::Row) -> rusqlite::Result<JobStatus> {
+        Ok(JobStatus {
+            name: row.get(0)?,
+            position: row.get(1)?,
+            is_terminal: row.get(2)?,
+            color: row.get(3)?,
+        })
+    }
+
     // --- Base Resume operations ---
 
     pub fn create_base_resume(
@@ -1094,31 +3816,21 @@ impl Database {
         notes: Option<&str>,
     ) -> Result<i64> {
         self.conn.execute(
-            "INSERT INTO base_resumes (name, format, content, notes)
-             VALUES (?1, ?2, ?3, ?4)",
-            params![name, format, content, notes],
+            "INSERT INTO base_resumes (name, format, content, notes, content_hash)
+             VALUES (?1, ?2, ?3, ?4, ?5)",
+            params![name, format, content, notes, content_hash(content)],
         )?;
         Ok(self.conn.last_insert_rowid())
     }
 
     pub fn list_base_resumes(&self) -> Result<Vec<BaseResume>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, name, format, content, notes, created_at, updated_at
+            "SELECT id, name, format, content, notes, content_hash, created_at, updated_at
              FROM base_resumes
              ORDER BY updated_at DESC",
         )?;
 
-        let rows = stmt.query_map([], |row| {
-            Ok(BaseResume {
-                id: row.get(0)?,
-                name: row.get(1)?,
-                format: row.get(2)?,
-                content: row.get(3)?,
-                notes: row.get(4)?,
-                created_at: row.get(5)?,
-                updated_at: row.get(6)?,
-            })
-        })?;
+        let rows = stmt.query_map([], Self::row_to_base_resume)?;
 
         rows.collect::<Result<Vec<_>, _>>()
             .context("Failed to list base resumes")
@@ -1126,20 +3838,10 @@ impl Database {
 
     pub fn get_base_resume(&self, id: i64) -> Result<Option<BaseResume>> {
         let result = self.conn.query_row(
-            "SELECT id, name, format, content, notes, created_at, updated_at
+            "SELECT id, name, format, content, notes, content_hash, created_at, updated_at
              FROM base_resumes WHERE id = ?1",
             [id],
-            |row| {
-                Ok(BaseResume {
-                    id: row.get(0)?,
-                    name: row.get(1)?,
-                    format: row.get(2)?,
-                    content: row.get(3)?,
-                    notes: row.get(4)?,
-                    created_at: row.get(5)?,
-                    updated_at: row.get(6)?,
-                })
-            },
+            Self::row_to_base_resume,
         );
         match result {
             Ok(resume) => Ok(Some(resume)),
@@ -1150,20 +3852,10 @@ impl Database {
 
     pub fn get_base_resume_by_name(&self, name: &str) -> Result<Option<BaseResume>> {
         let result = self.conn.query_row(
-            "SELECT id, name, format, content, notes, created_at, updated_at
+            "SELECT id, name, format, content, notes, content_hash, created_at, updated_at
              FROM base_resumes WHERE name = ?1",
             [name],
-            |row| {
-                Ok(BaseResume {
-                    id: row.get(0)?,
-                    name: row.get(1)?,
-                    format: row.get(2)?,
-                    content: row.get(3)?,
-                    notes: row.get(4)?,
-                    created_at: row.get(5)?,
-                    updated_at: row.get(6)?,
-                })
-            },
+            Self::row_to_base_resume,
         );
         match result {
             Ok(resume) => Ok(Some(resume)),
@@ -1172,7 +3864,19 @@ impl Database {
         }
     }
 
-    #[allow(dead_code)]
+    fn row_to_base_resume(row: &rusqlite::Row) -> rusqlite::Result<BaseResume> {
+        Ok(BaseResume {
+            id: row.get(0)?,
+            name: row.get(1)?,
+            format: row.get(2)?,
+            content: row.get(3)?,
+            notes: row.get(4)?,
+            content_hash: row.get(5)?,
+            created_at: row.get(6)?,
+            updated_at: row.get(7)?,
+        })
+    }
+
     pub fn update_base_resume(
         &self,
         id: i64,
@@ -1195,6 +3899,8 @@ impl Database {
         if let Some(c) = content {
             updates.push("content = ?");
             params.push(Box::new(c.to_string()));
+            updates.push("content_hash = ?");
+            params.push(Box::new(content_hash(c)));
         }
         if let Some(n) = notes {
             updates.push("notes = ?");
@@ -1205,7 +3911,7 @@ impl Database {
             return Ok(());
         }
 
-        updates.push("updated_at = datetime('now')");
+        updates.push("updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')");
         params.push(Box::new(id));
 
         let sql = format!(
@@ -1218,8 +3924,329 @@ impl Database {
         Ok(())
     }
 
+    // --- Notes and tags ---
+
+    pub fn add_job_note(&self, job_id: i64, note: &str) -> Result<i64> {
+        self.conn.execute(
+            "INSERT INTO job_notes (job_id, note) VALUES (?1, ?2)",
+            params![job_id, note],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    /// Replace a note's text; an empty note is deleted instead.
+    pub fn update_job_note(&self, note_id: i64, note: &str) -> Result<()> {
+        let changed = if note.trim().is_empty() {
+            self.conn.execute("DELETE FROM job_notes WHERE id = ?1", [note_id])?
+        } else {
+            self.conn.execute("UPDATE job_notes SET note = ?1 WHERE id = ?2", params![note, note_id])?
+        };
+        if changed == 0 {
+            return Err(HuntError::NotFound(format!("Note {} not found", note_id)).into());
+        }
+        Ok(())
+    }
+
+    pub fn list_job_notes(&self, job_id: i64) -> Result<Vec<JobNote>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, job_id, note, created_at FROM job_notes WHERE job_id = ?1 ORDER BY created_at, id",
+        )?;
+        let rows = stmt.query_map([job_id], |row| {
+            Ok(JobNote {
+                id: row.get(0)?,
+                job_id: row.get(1)?,
+                note: row.get(2)?,
+                created_at: row.get(3)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list job notes")
+    }
+
+    // --- Next actions ---
+
+    /// Set the job's next action, replacing any earlier one.
+    pub fn set_next_action(&self, job_id: i64, action: &str, due_date: &str) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO job_next_actions (job_id, action, due_date) VALUES (?1, ?2, ?3)
+             ON CONFLICT(job_id) DO UPDATE SET action = excluded.action, due_date = excluded.due_date,
+                 created_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')",
+            params![job_id, action, due_date],
+        )?;
+        Ok(())
+    }
+
+    /// Mark the job's next action done; returns false if it had none.
+    pub fn clear_next_action(&self, job_id: i64) -> Result<bool> {
+        Ok(self.conn.execute("DELETE FROM job_next_actions WHERE job_id = ?1", [job_id])? > 0)
+    }
+
+    pub fn get_next_action(&self, job_id: i64) -> Result<Option<NextAction>> {
+        Ok(self.query_next_actions("AND a.job_id = ?1", [job_id])?.pop())
+    }
+
+    /// Next actions on unarchived jobs due on or before `due_by` (all when None), soonest first.
+    pub fn list_next_actions(&self, due_by: Option<&str>) -> Result<Vec<NextAction>> {
+        match due_by {
+            Some(date) => self.query_next_actions("AND a.due_date <= ?1", [date]),
+            None => self.query_next_actions("", []),
+        }
+    }
+
+    fn query_next_actions<P: rusqlite::Params>(&self, filter: &str, params: P) -> Result<Vec<NextAction>> {
+        let sql = format!(
+            "SELECT a.job_id, j.title, e.name, a.action, a.due_date, a.created_at
+             FROM job_next_actions a
+             JOIN jobs j ON j.id = a.job_id
+             LEFT JOIN employers e ON j.employer_id = e.id
+             WHERE j.archived_at IS NULL {}
+             ORDER BY a.due_date, a.job_id",
+            filter,
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let rows = stmt.query_map(params, |row| {
+            Ok(NextAction {
+                job_id: row.get(0)?,
+                title: row.get(1)?,
+                employer_name: row.get(2)?,
+                action: row.get(3)?,
+                due_date: row.get(4)?,
+                created_at: row.get(5)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list next actions")
+    }
+
+    /// When each job entered its current status, from `status_history`.
+    pub fn status_entered_at(&self) -> Result<HashMap<i64, String>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT h.job_id, MAX(h.changed_at) FROM status_history h
+             JOIN jobs j ON j.id = h.job_id AND h.to_status = j.status
+             GROUP BY h.job_id",
+        )?;
+        let rows = stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))?;
+        rows.collect::<Result<HashMap<_, _>, _>>()
+            .context("Failed to read status history")
+    }
+
+    // --- Contacts ---
+
+    pub fn add_contact(
+        &self,
+        name: &str,
+        role: Option<&str>,
+        employer_id: Option<i64>,
+        email: Option<&str>,
+        linkedin: Option<&str>,
+        last_touch: Option<&str>,
+    ) -> Result<i64> {
+        self.conn.execute(
+            "INSERT INTO contacts (name, role, employer_id, email, linkedin, last_touch)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![name, role, employer_id, email, linkedin, last_touch],
+        )?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    pub fn get_contact(&self, id: i64) -> Result<Option<Contact>> {
+        Ok(self.query_contacts("WHERE c.id = ?1", "", [id])?.pop())
+    }
+
+    /// Contacts, optionally at one employer, most recently touched first.
+    pub fn list_contacts(&self, employer_id: Option<i64>) -> Result<Vec<Contact>> {
+        match employer_id {
+            Some(id) => self.query_contacts("WHERE c.employer_id = ?1", "", [id]),
+            None => self.query_contacts("", "", []),
+        }
+    }
+
+    /// Link a contact to a job; a contact without an employer takes the job's.
+    /// Returns false if they were already linked.
+    pub fn link_contact_to_job(&self, contact_id: i64, job_id: i64) -> Result<bool> {
+        let linked = self.conn.execute(
+            "INSERT OR IGNORE INTO job_contacts (job_id, contact_id) VALUES (?1, ?2)",
+            params![job_id, contact_id],
+        )? > 0;
+        self.conn.execute(
+            "UPDATE contacts SET employer_id = (SELECT employer_id FROM jobs WHERE id = ?1)
+             WHERE id = ?2 AND employer_id IS NULL",
+            params![job_id, contact_id],
+        )?;
+        Ok(linked)
+    }
+
+    /// Record when you last heard from or reached out to a contact (YYYY-MM-DD).
+    pub fn touch_contact(&self, contact_id: i64, date: &str) -> Result<()> {
+        if self.conn.execute("UPDATE contacts SET last_touch = ?1 WHERE id = ?2", params![date, contact_id])? == 0 {
+            return Err(HuntError::NotFound(format!("Contact #{} not found", contact_id)).into());
+        }
+        Ok(())
+    }
+
+    /// People to talk to about a job: contacts linked to it, then others at its employer.
+    pub fn list_job_contacts(&self, job: &Job) -> Result<Vec<Contact>> {
+        self.query_contacts(
+            "LEFT JOIN job_contacts jc ON jc.contact_id = c.id AND jc.job_id = ?1
+             WHERE jc.job_id IS NOT NULL OR c.employer_id = ?2",
+            "jc.job_id IS NULL,",
+            params![job.id, job.employer_id],
+        )
+    }
+
+    /// Contacts matching `filter` (extra joins and a WHERE clause), sorted by `order_first`
+    /// (leading ORDER BY terms, each followed by a comma) then most recently touched.
+    fn query_contacts<P: rusqlite::Params>(&self, filter: &str, order_first: &str, params: P) -> Result<Vec<Contact>> {
+        let sql = format!(
+            "SELECT c.id, c.name, c.role, c.employer_id, e.name, c.email, c.linkedin, c.last_touch, c.created_at
+             FROM contacts c
+             LEFT JOIN employers e ON c.employer_id = e.id
+             {}
+             ORDER BY {} c.last_touch IS NULL, c.last_touch DESC, c.name",
+            filter, order_first,
+        );
+        let mut stmt = self.conn.prepare(&sql)?;
+        let rows = stmt.query_map(params, |row| {
+            Ok(Contact {
+                id: row.get(0)?,
+                name: row.get(1)?,
+                role: row.get(2)?,
+                employer_id: row.get(3)?,
+                employer_name: row.get(4)?,
+                email: row.get(5)?,
+                linkedin: row.get(6)?,
+                last_touch: row.get(7)?,
+                created_at: row.get(8)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list contacts")
+    }
+
+    /// Every stored version of a job's description, oldest first.
+    pub fn list_job_snapshots(&self, job_id: i64) -> Result<Vec<JobSnapshot>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, job_id, raw_text, captured_at FROM job_snapshots WHERE job_id = ?1 ORDER BY id",
+        )?;
+        let rows = stmt.query_map([job_id], |row| {
+            Ok(JobSnapshot {
+                id: row.get(0)?,
+                job_id: row.get(1)?,
+                raw_text: row.get(2)?,
+                captured_at: row.get(3)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list job snapshots")
+    }
+
+    pub fn add_job_tags(&self, job_id: i64, tags: &[String]) -> Result<()> {
+        for tag in tags.iter().map(|t| normalize_tag(t)).filter(|t| !t.is_empty()) {
+            self.conn.execute(
+                "INSERT OR IGNORE INTO job_tags (job_id, tag) VALUES (?1, ?2)",
+                params![job_id, tag],
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn remove_job_tags(&self, job_id: i64, tags: &[String]) -> Result<()> {
+        for tag in tags {
+            self.conn.execute(
+                "DELETE FROM job_tags WHERE job_id = ?1 AND tag = ?2",
+                params![job_id, normalize_tag(tag)],
+            )?;
+        }
+        Ok(())
+    }
+
+    pub fn get_job_tags(&self, job_id: i64) -> Result<Vec<String>> {
+        let mut stmt = self.conn.prepare("SELECT tag FROM job_tags WHERE job_id = ?1 ORDER BY tag")?;
+        let rows = stmt.query_map([job_id], |row| row.get(0))?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to get job tags")
+    }
+
+    /// Tags for every tagged job, keyed by job id.
+    pub fn get_all_job_tags(&self) -> Result<HashMap<i64, Vec<String>>> {
+        let mut stmt = self.conn.prepare("SELECT job_id, tag FROM job_tags ORDER BY job_id, tag")?;
+        let rows = stmt.query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))?;
+        let mut tags: HashMap<i64, Vec<String>> = HashMap::new();
+        for row in rows {
+            let (job_id, tag) = row?;
+            tags.entry(job_id).or_default().push(tag);
+        }
+        Ok(tags)
+    }
+
+    /// Replace the flags one source (`flags::RULES_SOURCE` or a model) found on a job.
+    pub fn set_job_flags(&self, job_id: i64, source: &str, flags: &[Flag]) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute("DELETE FROM job_flags WHERE job_id = ?1 AND source = ?2", params![job_id, source])?;
+        for flag in flags {
+            tx.execute(
+                "INSERT OR IGNORE INTO job_flags (job_id, flag, evidence, source) VALUES (?1, ?2, ?3, ?4)",
+                params![job_id, flag.name, flag.evidence, source],
+            )?;
+        }
+        tx.commit().context("Failed to store job flags")
+    }
+
+    /// Run the phrase rules over a job's description and store what they find: its red
+    /// flags, and its clearance and sponsorship requirements.
+    pub fn detect_job_flags(&self, job_id: i64) -> Result<Vec<Flag>> {
+        let job = self.get_job(job_id)?.ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+        let Some(text) = job.raw_text.as_deref() else {
+            self.set_job_flags(job_id, flags::RULES_SOURCE, &[])?;
+            return Ok(Vec::new());
+        };
+        let found = flags::detect(text);
+        self.set_job_flags(job_id, flags::RULES_SOURCE, &found)?;
+        self.set_job_work_authorization(job_id, text)?;
+        Ok(found)
+    }
+
+    /// Every flag on a job, rules first, in `flags::KINDS` order.
+    pub fn get_job_flags(&self, job_id: i64) -> Result<Vec<JobFlag>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT job_id, flag, evidence, source, created_at FROM job_flags WHERE job_id = ?1",
+        )?;
+        let rows = stmt.query_map([job_id], |row| {
+            Ok(JobFlag {
+                job_id: row.get(0)?,
+                flag: row.get(1)?,
+                evidence: row.get(2)?,
+                source: row.get(3)?,
+                created_at: row.get(4)?,
+            })
+        })?;
+        let mut job_flags = rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to get job flags")?;
+        let order = |flag: &JobFlag| flags::KINDS.iter().position(|k| k.name == flag.flag).unwrap_or(usize::MAX);
+        job_flags.sort_by_key(|f| (f.source != flags::RULES_SOURCE, order(f)));
+        Ok(job_flags)
+    }
+
+    /// Distinct flag names for every flagged job, keyed by job id.
+    pub fn get_all_job_flags(&self) -> Result<HashMap<i64, Vec<String>>> {
+        let mut stmt = self.conn.prepare("SELECT DISTINCT job_id, flag FROM job_flags")?;
+        let rows = stmt.query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))?;
+        let mut job_flags: HashMap<i64, Vec<String>> = HashMap::new();
+        for row in rows {
+            let (job_id, flag) = row?;
+            job_flags.entry(job_id).or_default().push(flag);
+        }
+        let order = |flag: &String| flags::KINDS.iter().position(|k| k.name == flag).unwrap_or(usize::MAX);
+        for names in job_flags.values_mut() {
+            names.sort_by_key(order);
+        }
+        Ok(job_flags)
+    }
+
     // --- Resume Variant operations ---
 
+    /// Store a new generation: re-tailoring the same base resume for the same job, model
+    /// and format adds the next version rather than replacing the previous one.
     pub fn create_resume_variant(
         &self,
         base_resume_id: i64,
@@ -1230,11 +4257,12 @@ impl Database {
         output_format: Option<&str>,
     ) -> Result<i64> {
         self.conn.execute(
-            "INSERT INTO resume_variants (base_resume_id, job_id, content, tailoring_notes, source_model, output_format)
-             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
-             ON CONFLICT(base_resume_id, job_id, source_model, output_format) DO UPDATE SET
-                content = excluded.content,
-                tailoring_notes = excluded.tailoring_notes",
+            "INSERT INTO resume_variants
+                (base_resume_id, job_id, content, tailoring_notes, source_model, output_format, version, base_content_hash)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6,
+                (SELECT COALESCE(MAX(version), 0) + 1 FROM resume_variants
+                 WHERE base_resume_id = ?1 AND job_id = ?2 AND source_model IS ?5 AND output_format IS ?6),
+                (SELECT content_hash FROM base_resumes WHERE id = ?1))",
             params![base_resume_id, job_id, content, tailoring_notes, source_model, output_format],
         )?;
         Ok(self.conn.last_insert_rowid())
@@ -1243,8 +4271,9 @@ impl Database {
     #[allow(dead_code)]
     pub fn get_resume_variant(&self, job_id: i64, base_resume_id: i64) -> Result<Option<ResumeVariant>> {
         let result = self.conn.query_row(
-            "SELECT id, base_resume_id, job_id, content, tailoring_notes, source_model, output_format, created_at
-             FROM resume_variants WHERE job_id = ?1 AND base_resume_id = ?2",
+            "SELECT id, base_resume_id, job_id, content, tailoring_notes, source_model, output_format, version, base_content_hash, created_at
+             FROM resume_variants WHERE job_id = ?1 AND base_resume_id = ?2
+             ORDER BY version DESC, id DESC LIMIT 1",
             params![job_id, base_resume_id],
             Self::row_to_resume_variant,
         );
@@ -1255,11 +4284,29 @@ impl Database {
         }
     }
 
+    pub fn get_resume_variant_by_id(&self, id: i64) -> Result<Option<ResumeVariant>> {
+        let result = self.conn.query_row(
+            "SELECT id, base_resume_id, job_id, content, tailoring_notes, source_model, output_format, version, base_content_hash, created_at
+             FROM resume_variants WHERE id = ?1",
+            [id],
+            Self::row_to_resume_variant,
+        );
+        match result {
+            Ok(variant) => Ok(Some(variant)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// The latest version of each variant (base resume, model, format) for a job.
     pub fn list_resume_variants_for_job(&self, job_id: i64) -> Result<Vec<ResumeVariant>> {
         let mut stmt = self.conn.prepare(
-            "SELECT id, base_resume_id, job_id, content, tailoring_notes, source_model, output_format, created_at
-             FROM resume_variants WHERE job_id = ?1
-             ORDER BY created_at DESC",
+            "SELECT id, base_resume_id, job_id, content, tailoring_notes, source_model, output_format, version, base_content_hash, created_at
+             FROM resume_variants v WHERE job_id = ?1
+               AND version = (SELECT MAX(version) FROM resume_variants w
+                              WHERE w.base_resume_id = v.base_resume_id AND w.job_id = v.job_id
+                                AND w.source_model IS v.source_model AND w.output_format IS v.output_format)
+             ORDER BY created_at DESC, id DESC",
         )?;
 
         let rows = stmt.query_map([job_id], Self::row_to_resume_variant)?;
@@ -1268,6 +4315,40 @@ impl Database {
             .context("Failed to list resume variants")
     }
 
+    /// Latest variants (any job) tailored from a different version of this base resume
+    /// than its current content, including ones from before hashes were recorded.
+    pub fn get_stale_resume_variants(&self, base_resume_id: i64) -> Result<Vec<ResumeVariant>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT v.id, v.base_resume_id, v.job_id, v.content, v.tailoring_notes, v.source_model, v.output_format,
+                    v.version, v.base_content_hash, v.created_at
+             FROM resume_variants v JOIN base_resumes b ON b.id = v.base_resume_id
+             WHERE v.base_resume_id = ?1 AND v.base_content_hash IS NOT b.content_hash
+               AND v.version = (SELECT MAX(version) FROM resume_variants w
+                                WHERE w.base_resume_id = v.base_resume_id AND w.job_id = v.job_id
+                                  AND w.source_model IS v.source_model AND w.output_format IS v.output_format)
+             ORDER BY v.job_id, v.id",
+        )?;
+
+        let rows = stmt.query_map([base_resume_id], Self::row_to_resume_variant)?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list stale resume variants")
+    }
+
+    /// Every generation of every variant for a job, newest version first within each.
+    pub fn list_resume_variant_history(&self, job_id: i64) -> Result<Vec<ResumeVariant>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, base_resume_id, job_id, content, tailoring_notes, source_model, output_format, version, base_content_hash, created_at
+             FROM resume_variants WHERE job_id = ?1
+             ORDER BY base_resume_id, source_model, output_format, version DESC",
+        )?;
+
+        let rows = stmt.query_map([job_id], Self::row_to_resume_variant)?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list resume variant history")
+    }
+
     fn row_to_resume_variant(row: &rusqlite::Row) -> rusqlite::Result<ResumeVariant> {
         Ok(ResumeVariant {
             id: row.get(0)?,
@@ -1277,7 +4358,9 @@ impl Database {
             tailoring_notes: row.get(4)?,
             source_model: row.get(5)?,
             output_format: row.get(6)?,
-            created_at: row.get(7)?,
+            version: row.get(7)?,
+            base_content_hash: row.get(8)?,
+            created_at: row.get(9)?,
         })
     }
 
@@ -1361,29 +4444,139 @@ impl Database {
     /// Get jobs that have raw_text but no stored keywords (or all with raw_text if force=true)
     pub fn get_jobs_needing_keywords(&self, force: bool) -> Result<Vec<Job>> {
         let sql = if force {
-            "SELECT j.id, j.employer_id, e.name, j.title, j.url, j.source, j.status,
-                    j.pay_min, j.pay_max, j.job_code, j.raw_text, j.fetched_at, j.created_at, j.updated_at
+            format!("SELECT {JOB_COLUMNS}
              FROM jobs j
              LEFT JOIN employers e ON j.employer_id = e.id
-             WHERE j.raw_text IS NOT NULL AND j.raw_text != ''
-             ORDER BY j.id ASC"
+             WHERE j.raw_text IS NOT NULL AND j.raw_text != '' AND j.archived_at IS NULL
+             ORDER BY j.id ASC")
         } else {
-            "SELECT j.id, j.employer_id, e.name, j.title, j.url, j.source, j.status,
-                    j.pay_min, j.pay_max, j.job_code, j.raw_text, j.fetched_at, j.created_at, j.updated_at
+            format!("SELECT {JOB_COLUMNS}
              FROM jobs j
              LEFT JOIN employers e ON j.employer_id = e.id
-             WHERE j.raw_text IS NOT NULL AND j.raw_text != ''
+             WHERE j.raw_text IS NOT NULL AND j.raw_text != '' AND j.archived_at IS NULL
                AND j.id NOT IN (SELECT DISTINCT job_id FROM job_keywords)
-             ORDER BY j.id ASC"
+             ORDER BY j.id ASC")
         };
 
-        let mut stmt = self.conn.prepare(sql)?;
+        let mut stmt = self.conn.prepare(&sql)?;
         let jobs = stmt
             .query_map([], Self::row_to_job)?
             .collect::<Result<Vec<_>, _>>()?;
         Ok(jobs)
     }
 
+    /// Jobs needing keywords in the order worth spending AI calls on: jobs tagged
+    /// `shortlist` first, then highest rank score, terminal statuses skipped, capped at
+    /// `budget` jobs if given. There is no snoozing yet, so snoozed jobs can't be
+    /// skipped. Also returns how many jobs were left out for being at blocked employers
+    /// (see `filter_blocked_employers`); the budget applies after that.
+    pub fn get_keyword_backfill_queue(&self, force: bool, budget: Option<usize>, include_blocked: bool) -> Result<(Vec<Job>, usize)> {
+        let statuses = self.list_job_statuses()?;
+        let terminal: Vec<&str> = statuses.iter().filter(|s| s.is_terminal).map(|s| s.name.as_str()).collect();
+
+        let active: Vec<Job> = self
+            .get_jobs_needing_keywords(force)?
+            .into_iter()
+            .filter(|j| !terminal.contains(&j.status.as_str()))
+            .collect();
+        let (active, blocked) = self.filter_blocked_employers(active, include_blocked)?;
+
+        let mut scored: Vec<(Job, f64)> = active
+            .into_iter()
+            .map(|j| {
+                let score = calculate_score(&j, self, &statuses);
+                (j, score)
+            })
+            .collect();
+
+        let mut stmt = self.conn.prepare("SELECT job_id FROM job_tags WHERE tag = ?1")?;
+        let shortlisted = stmt
+            .query_map([SHORTLIST_TAG], |row| row.get(0))?
+            .collect::<Result<HashSet<i64>, _>>()?;
+
+        // Shortlisted, then highest score; older jobs first on ties so the order is stable
+        scored.sort_by(|a, b| {
+            shortlisted.contains(&b.0.id).cmp(&shortlisted.contains(&a.0.id))
+                .then(b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal))
+                .then(a.0.id.cmp(&b.0.id))
+        });
+        if let Some(budget) = budget {
+            scored.truncate(budget);
+        }
+        Ok((scored.into_iter().map(|(j, _)| j).collect(), blocked))
+    }
+
+    /// Technical keywords from active jobs that neither a base resume nor the skill
+    /// profile covers, heaviest first. Soft skills are left out: they don't make
+    /// useful study topics.
+    pub fn get_keyword_gaps(&self, limit: usize) -> Result<Vec<KeywordGap>> {
+        let corpus = self.resume_corpus()?;
+        let skills = self.get_user_skills()?;
+
+        let mut gaps: Vec<KeywordGap> = Vec::new();
+        for job in self.list_active_jobs()? {
+            let Some(model) = self.get_latest_keyword_model(job.id)? else { continue };
+            for kw in self.get_job_keywords(job.id, Some(&model))? {
+                let key = kw.keyword.to_lowercase();
+                if kw.domain == "soft_skill"
+                    || corpus.contains(&key)
+                    || skills.iter().any(|s| skill_covers(s, &kw.keyword))
+                {
+                    continue;
+                }
+                match gaps.iter_mut().find(|g| g.keyword.to_lowercase() == key) {
+                    Some(gap) => {
+                        gap.total_weight += kw.weight as i64;
+                        gap.jobs += 1;
+                    }
+                    None => gaps.push(KeywordGap {
+                        keyword: kw.keyword,
+                        domain: kw.domain,
+                        total_weight: kw.weight as i64,
+                        jobs: 1,
+                    }),
+                }
+            }
+        }
+
+        gaps.sort_by(|a, b| b.total_weight.cmp(&a.total_weight).then(b.jobs.cmp(&a.jobs)));
+        gaps.truncate(limit);
+        Ok(gaps)
+    }
+
+    /// Every keyword open jobs ask for, from each job's latest extraction, heaviest first.
+    /// Also returns how many open jobs have keywords at all.
+    pub fn get_keyword_demand(&self) -> Result<(Vec<KeywordDemand>, usize)> {
+        let mut demand: Vec<KeywordDemand> = Vec::new();
+        let mut index: HashMap<(String, String), usize> = HashMap::new();
+        let mut jobs = 0;
+        for job in self.list_active_jobs()? {
+            let Some(model) = self.get_latest_keyword_model(job.id)? else { continue };
+            jobs += 1;
+            for kw in self.get_job_keywords(job.id, Some(&model))? {
+                let key = (kw.keyword.to_lowercase(), kw.domain.clone());
+                let i = *index.entry(key).or_insert_with(|| {
+                    demand.push(KeywordDemand { keyword: kw.keyword.clone(), domain: kw.domain.clone(), total_weight: 0, jobs: 0, required: 0 });
+                    demand.len() - 1
+                });
+                demand[i].total_weight += kw.weight as i64;
+                demand[i].jobs += 1;
+                demand[i].required += (kw.weight == 3) as i64;
+            }
+        }
+        demand.sort_by(|a, b| b.total_weight.cmp(&a.total_weight).then(b.jobs.cmp(&a.jobs)).then(a.keyword.cmp(&b.keyword)));
+        Ok((demand, jobs))
+    }
+
+    /// All base resume text, lowercased, for keyword matching.
+    fn resume_corpus(&self) -> Result<String> {
+        Ok(self.list_base_resumes()?
+            .iter()
+            .map(|r| r.content.to_lowercase())
+            .collect::<Vec<_>>()
+            .join("\n"))
+    }
+
     pub fn save_keyword_profile(
         &self,
         job_id: i64,
@@ -1400,6 +4593,53 @@ impl Database {
         Ok(())
     }
 
+    /// Store a job's summary, replacing one from the same model.
+    pub fn save_job_summary(&self, job_id: i64, source_model: &str, summary: &JobSummaryBullets) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO job_summaries (job_id, source_model, responsibilities, stack, seniority, comp, red_flags)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)
+             ON CONFLICT(job_id, source_model) DO UPDATE SET
+                responsibilities = excluded.responsibilities,
+                stack = excluded.stack,
+                seniority = excluded.seniority,
+                comp = excluded.comp,
+                red_flags = excluded.red_flags,
+                created_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')",
+            params![job_id, source_model, summary.responsibilities, summary.stack, summary.seniority, summary.comp, summary.red_flags],
+        )?;
+        Ok(())
+    }
+
+    /// The job's most recent summary, from whichever model wrote it.
+    pub fn get_job_summary(&self, job_id: i64) -> Result<Option<JobSummary>> {
+        let result = self.conn.query_row(
+            "SELECT id, job_id, source_model, responsibilities, stack, seniority, comp, red_flags, created_at
+             FROM job_summaries WHERE job_id = ?1
+             ORDER BY created_at DESC, id DESC LIMIT 1",
+            [job_id],
+            |row| {
+                Ok(JobSummary {
+                    id: row.get(0)?,
+                    job_id: row.get(1)?,
+                    source_model: row.get(2)?,
+                    bullets: JobSummaryBullets {
+                        responsibilities: row.get(3)?,
+                        stack: row.get(4)?,
+                        seniority: row.get(5)?,
+                        comp: row.get(6)?,
+                        red_flags: row.get(7)?,
+                    },
+                    created_at: row.get(8)?,
+                })
+            },
+        );
+        match result {
+            Ok(summary) => Ok(Some(summary)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
     pub fn get_keyword_profile(
         &self,
         job_id: i64,
@@ -1433,7 +4673,7 @@ impl Database {
             "SELECT jk.job_id, j.title, jk.keyword, jk.domain, jk.weight
              FROM job_keywords jk
              JOIN jobs j ON jk.job_id = j.id
-             WHERE LOWER(jk.keyword) LIKE LOWER(?1)
+             WHERE LOWER(jk.keyword) LIKE LOWER(?1) AND j.archived_at IS NULL
                AND jk.source_model = (
                    SELECT source_model FROM job_keywords
                    WHERE job_id = jk.job_id
@@ -1535,12 +4775,114 @@ impl Database {
             },
         );
         match result {
-            Ok(analysis) => Ok(Some(analysis)),
+            Ok(analysis) => Ok(Some(analysis)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    pub fn save_negotiation_brief(&self, job_id: i64, source_model: &str, anchors: &NegotiationAnchors, brief: &str) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO negotiation_briefs (job_id, source_model, anchor, target, walk_away, brief)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+             ON CONFLICT(job_id, source_model) DO UPDATE SET
+                anchor = excluded.anchor,
+                target = excluded.target,
+                walk_away = excluded.walk_away,
+                brief = excluded.brief,
+                created_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')",
+            params![job_id, source_model, anchors.anchor, anchors.target, anchors.walk_away, brief],
+        )?;
+        Ok(())
+    }
+
+    /// The job's most recent negotiation brief, from whichever model wrote it.
+    pub fn get_negotiation_brief(&self, job_id: i64) -> Result<Option<NegotiationBrief>> {
+        let result = self.conn.query_row(
+            "SELECT id, job_id, source_model, anchor, target, walk_away, brief, created_at
+             FROM negotiation_briefs WHERE job_id = ?1
+             ORDER BY created_at DESC, id DESC LIMIT 1",
+            [job_id],
+            |row| {
+                Ok(NegotiationBrief {
+                    id: row.get(0)?,
+                    job_id: row.get(1)?,
+                    source_model: row.get(2)?,
+                    anchors: NegotiationAnchors { anchor: row.get(3)?, target: row.get(4)?, walk_away: row.get(5)? },
+                    brief: row.get(6)?,
+                    created_at: row.get(7)?,
+                })
+            },
+        );
+        match result {
+            Ok(brief) => Ok(Some(brief)),
             Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
             Err(e) => Err(e.into()),
         }
     }
 
+    /// Replace the job's prep questions for this resume with a new set.
+    pub fn save_prep_notes(&self, job_id: i64, base_resume_id: i64, source_model: &str, questions: &[PrepQuestion]) -> Result<()> {
+        let tx = self.conn.unchecked_transaction()?;
+        tx.execute(
+            "DELETE FROM prep_notes WHERE job_id = ?1 AND base_resume_id = ?2",
+            params![job_id, base_resume_id],
+        )?;
+        {
+            let mut stmt = tx.prepare(
+                "INSERT INTO prep_notes (job_id, base_resume_id, source_model, kind, question, prep, position)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+            )?;
+            for (position, q) in questions.iter().enumerate() {
+                stmt.execute(params![job_id, base_resume_id, source_model, q.kind, q.question, q.prep, position as i64])?;
+            }
+        }
+        tx.commit()?;
+        Ok(())
+    }
+
+    /// The job's most recently generated prep questions (one resume's set), in the order
+    /// the AI gave them.
+    pub fn list_prep_notes(&self, job_id: i64) -> Result<Vec<PrepNote>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT p.id, p.job_id, p.base_resume_id, r.name, p.source_model, p.kind, p.question, p.prep, p.created_at
+             FROM prep_notes p JOIN base_resumes r ON r.id = p.base_resume_id
+             WHERE p.job_id = ?1 AND p.base_resume_id = (
+                 SELECT base_resume_id FROM prep_notes WHERE job_id = ?1 ORDER BY created_at DESC, id DESC LIMIT 1
+             )
+             ORDER BY p.position",
+        )?;
+        let rows = stmt.query_map([job_id], |row| {
+            Ok(PrepNote {
+                id: row.get(0)?,
+                job_id: row.get(1)?,
+                base_resume_id: row.get(2)?,
+                resume_name: row.get(3)?,
+                source_model: row.get(4)?,
+                question: PrepQuestion { kind: row.get(5)?, question: row.get(6)?, prep: row.get(7)? },
+                created_at: row.get(8)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list prep notes")
+    }
+
+    /// Gaps from the latest fit analysis of this job against this resume, by any model.
+    pub fn get_resume_gaps(&self, job_id: i64, base_resume_id: i64) -> Result<Vec<String>> {
+        let result = self.conn.query_row(
+            "SELECT gaps FROM fit_analyses WHERE job_id = ?1 AND base_resume_id = ?2
+             ORDER BY created_at DESC, id DESC LIMIT 1",
+            params![job_id, base_resume_id],
+            |row| row.get::<_, Option<String>>(0),
+        );
+        let gaps = match result {
+            Ok(gaps) => gaps.unwrap_or_default(),
+            Err(rusqlite::Error::QueryReturnedNoRows) => String::new(),
+            Err(e) => return Err(e.into()),
+        };
+        Ok(gaps.split(',').map(str::trim).filter(|g| !g.is_empty()).map(String::from).collect())
+    }
+
     #[allow(dead_code)]
     pub fn get_fit_analysis(
         &self,
@@ -1614,14 +4956,329 @@ impl Database {
         })
     }
 
+    // --- Journal ---
+
+    pub fn ai_cache(&self) -> Result<AiCache> {
+        AiCache::open(&self.path)
+    }
+
+    pub fn ai_usage_log(&self) -> Result<AiUsageLog> {
+        AiUsageLog::open(&self.path)
+    }
+
+    /// AI usage since `since` (a stored timestamp), totalled per command or per model;
+    /// most expensive first.
+    pub fn get_ai_usage(&self, since: &str, by_command: bool) -> Result<Vec<AiUsageTotal>> {
+        let column = if by_command { "command" } else { "model" };
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {column}, COUNT(*), COALESCE(SUM(input_tokens), 0), COALESCE(SUM(output_tokens), 0),
+                    COALESCE(SUM(cost_usd), 0), SUM(cost_usd IS NULL)
+             FROM ai_usage WHERE created_at >= ?1
+             GROUP BY {column} ORDER BY 5 DESC, 2 DESC, 1"
+        ))?;
+        let totals = stmt.query_map([since], |row| {
+            Ok(AiUsageTotal {
+                key: row.get(0)?,
+                calls: row.get(1)?,
+                input_tokens: row.get(2)?,
+                output_tokens: row.get(3)?,
+                cost_usd: row.get(4)?,
+                unpriced_calls: row.get(5)?,
+            })
+        })?.collect::<Result<Vec<_>, _>>()?;
+        Ok(totals)
+    }
+
+    /// Default journal file: `journal.md` next to the working database.
+    pub fn default_journal_path(&self) -> PathBuf {
+        self.path.with_file_name("journal.md")
+    }
+
+    /// Collect activity since `since`, an RFC3339 UTC timestamp (see `timestamps::to_db`).
+    pub fn get_journal_activity(&self, since: &str) -> Result<JournalActivity> {
+        let job_select = format!("SELECT {JOB_COLUMNS}
+             FROM jobs j
+             LEFT JOIN employers e ON j.employer_id = e.id");
+
+        let mut stmt = self.conn.prepare(&format!(
+            "{} WHERE j.created_at >= ?1 ORDER BY j.created_at, j.id", job_select
+        ))?;
+        let jobs_added = stmt.query_map([since], Self::row_to_job)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut stmt = self.conn.prepare(&format!(
+            "{} LEFT JOIN job_statuses s ON s.name = j.status
+             WHERE EXISTS (SELECT 1 FROM status_history h WHERE h.job_id = j.id AND h.changed_at >= ?1)
+             ORDER BY COALESCE(s.position, 999), j.updated_at, j.id",
+            job_select
+        ))?;
+        let jobs_progressed = stmt.query_map([since], Self::row_to_job)?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut stmt = self.conn.prepare(
+            "SELECT job_id, MAX(fit_score) FROM fit_analyses WHERE created_at >= ?1
+             GROUP BY job_id ORDER BY MAX(fit_score) DESC",
+        )?;
+        let fit_scores = stmt.query_map([since], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, f64>(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut fit_analyses = Vec::new();
+        for (job_id, score) in fit_scores {
+            if let Some(job) = self.get_job(job_id)? {
+                fit_analyses.push((job, score));
+            }
+        }
+
+        let mut stmt = self.conn.prepare(
+            "SELECT DISTINCT v.job_id, b.name FROM resume_variants v
+             JOIN base_resumes b ON b.id = v.base_resume_id
+             WHERE v.created_at >= ?1 ORDER BY v.job_id",
+        )?;
+        let variant_rows = stmt.query_map([since], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut variants = Vec::new();
+        for (job_id, resume) in variant_rows {
+            if let Some(job) = self.get_job(job_id)? {
+                variants.push((job, resume));
+            }
+        }
+
+        let keywords_extracted: i64 = self.conn.query_row(
+            "SELECT COUNT(DISTINCT job_id) FROM job_keywords WHERE created_at >= ?1",
+            [since],
+            |row| row.get(0),
+        )?;
+
+        let mut stmt = self.conn.prepare(
+            "SELECT job_id, note FROM job_notes WHERE created_at >= ?1 ORDER BY created_at, id",
+        )?;
+        let note_rows = stmt.query_map([since], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))?
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut notes = Vec::new();
+        for (job_id, note) in note_rows {
+            if let Some(job) = self.get_job(job_id)? {
+                notes.push((job, note));
+            }
+        }
+
+        // Employers are created alongside their first job; only count later updates
+        let mut stmt = self.conn.prepare(
+            "SELECT name FROM employers WHERE updated_at >= ?1 AND updated_at > created_at ORDER BY name",
+        )?;
+        let employers_researched = stmt.query_map([since], |row| row.get(0))?
+            .collect::<Result<Vec<String>, _>>()?;
+
+        Ok(JournalActivity {
+            jobs_added,
+            jobs_progressed,
+            fit_analyses,
+            variants,
+            keywords_extracted,
+            employers_researched,
+            notes,
+        })
+    }
+
+    /// Collect `hunt digest`'s sections since `since` (RFC3339 UTC), with the `top`
+    /// best-ranked jobs not yet applied to.
+    pub fn get_digest_activity(&self, since: &str, top: usize) -> Result<DigestActivity> {
+        let job_select = format!("SELECT {JOB_COLUMNS}
+             FROM jobs j
+             LEFT JOIN employers e ON j.employer_id = e.id");
+        let jobs = |condition: &str, order: &str| -> Result<Vec<Job>> {
+            let mut stmt = self.conn.prepare(&format!(
+                "{} WHERE j.archived_at IS NULL AND {} ORDER BY {}, j.id", job_select, condition, order
+            ))?;
+            let rows = stmt.query_map([since], Self::row_to_job)?;
+            Ok(rows.collect::<Result<Vec<_>, _>>()?)
+        };
+        let new_jobs = jobs("j.created_at >= ?1", "j.created_at")?;
+        let applied = jobs("j.applied_at >= ?1", "j.applied_at")?;
+        let interviews = jobs(
+            "EXISTS (SELECT 1 FROM status_history h WHERE h.job_id = j.id AND h.to_status = 'interviewing' AND h.changed_at >= ?1)",
+            "j.updated_at",
+        )?;
+
+        let mut top_unapplied: Vec<(Job, f64)> = self
+            .rank_jobs(usize::MAX, &SavedFilter::default())?
+            .into_iter()
+            .filter(|(job, _)| job.applied_at.is_none())
+            .collect();
+        top_unapplied.truncate(top);
+
+        let employers_researched = self
+            .list_employers(None)?
+            .into_iter()
+            .filter(|e| {
+                [&e.research_updated_at, &e.public_research_updated_at, &e.ownership_research_updated,
+                    &e.last_glassdoor_fetch, &e.eng_research_updated]
+                    .into_iter()
+                    .flatten()
+                    .any(|at| at.as_str() >= since)
+            })
+            .collect();
+
+        Ok(DigestActivity { new_jobs, applied, interviews, top_unapplied, employers_researched })
+    }
+
+    // --- Retention / compaction ---
+
+    /// Default archive file: `hunt-archive.db` next to the working database.
+    pub fn default_archive_path(&self) -> PathBuf {
+        self.path.with_file_name("hunt-archive.db")
+    }
+
+    /// Prune snapshots captured before `before` (keeping each job's first and last),
+    /// move terminal-status jobs last updated before `before` (with their keywords,
+    /// fit analyses, variants and snapshots) into `archive_path`, then VACUUM.
+    pub fn compact(&self, before: &str, archive_path: &Path, dry_run: bool) -> Result<CompactStats> {
+        chrono::NaiveDate::parse_from_str(before, "%Y-%m-%d")
+            .map_err(|e| HuntError::Validation(format!("Invalid date '{}', expected YYYY-MM-DD: {}", before, e)))?;
+
+        let prunable_snapshots = "FROM job_snapshots
+             WHERE captured_at < ?1
+               AND id NOT IN (SELECT MIN(id) FROM job_snapshots GROUP BY job_id)
+               AND id NOT IN (SELECT MAX(id) FROM job_snapshots GROUP BY job_id)";
+        let archivable_jobs = "FROM jobs
+             WHERE updated_at < ?1
+               AND status IN (SELECT name FROM job_statuses WHERE is_terminal = 1)";
+
+        if dry_run {
+            return Ok(CompactStats {
+                snapshots_pruned: self.conn.query_row(
+                    &format!("SELECT COUNT(*) {}", prunable_snapshots), [before], |row| row.get(0),
+                )?,
+                jobs_archived: self.conn.query_row(
+                    &format!("SELECT COUNT(*) {}", archivable_jobs), [before], |row| row.get(0),
+                )?,
+            });
+        }
+
+        let mut stats = CompactStats::default();
+        self.conn.execute(
+            "ATTACH DATABASE ?1 AS archive",
+            [archive_path.to_string_lossy()],
+        ).with_context(|| format!("Failed to open archive database {:?}", archive_path))?;
+
+        let result = (|| -> Result<()> {
+            self.conn.execute_batch("BEGIN")?;
+            stats.snapshots_pruned = self.conn.execute(
+                &format!("DELETE {}", prunable_snapshots), [before],
+            )? as i64;
+
+            self.conn.execute_batch("DROP TABLE IF EXISTS temp.compact_jobs")?;
+            self.conn.execute(
+                &format!("CREATE TEMP TABLE compact_jobs AS SELECT id {}", archivable_jobs),
+                [before],
+            )?;
+
+            self.archive_rows("employers",
+                "id IN (SELECT employer_id FROM main.jobs WHERE id IN (SELECT id FROM temp.compact_jobs))
+                 AND id NOT IN (SELECT id FROM archive.employers)")?;
+            for table in JOB_CHILD_TABLES {
+                self.archive_rows(table, "job_id IN (SELECT id FROM temp.compact_jobs)")?;
+                self.conn.execute(
+                    &format!("DELETE FROM main.{} WHERE job_id IN (SELECT id FROM temp.compact_jobs)", table),
+                    [],
+                )?;
+            }
+            self.archive_rows("jobs", "id IN (SELECT id FROM temp.compact_jobs)")?;
+            stats.jobs_archived = self.conn.execute(
+                "DELETE FROM main.jobs WHERE id IN (SELECT id FROM temp.compact_jobs)",
+                [],
+            )? as i64;
+
+            self.conn.execute_batch("DROP TABLE temp.compact_jobs; COMMIT")?;
+            Ok(())
+        })();
+
+        if result.is_err() {
+            let _ = self.conn.execute_batch("ROLLBACK");
+        }
+        self.conn.execute_batch("DETACH DATABASE archive")?;
+        result?;
+
+        self.conn.execute_batch("VACUUM")?;
+        Ok(stats)
+    }
+
+    /// Copy rows of `table` matching `filter` into the attached archive, creating the
+    /// archive table (or any columns added since the last compaction) as needed.
+    fn archive_rows(&self, table: &str, filter: &str) -> Result<()> {
+        let columns_of = |schema: &str| -> Result<Vec<String>> {
+            Ok(self.conn
+                .prepare(&format!("PRAGMA {}.table_info({})", schema, table))?
+                .query_map([], |row| row.get::<_, String>(1))?
+                .collect::<Result<Vec<_>, _>>()?)
+        };
+
+        let main_columns = columns_of("main")?;
+        let archive_columns = columns_of("archive")?;
+        if archive_columns.is_empty() {
+            self.conn.execute_batch(&format!(
+                "CREATE TABLE archive.{t} AS SELECT * FROM main.{t} WHERE 0",
+                t = table
+            ))?;
+        } else {
+            for column in main_columns.iter().filter(|c| !archive_columns.contains(c)) {
+                self.conn.execute_batch(&format!(
+                    "ALTER TABLE archive.{} ADD COLUMN {}",
+                    table, column
+                ))?;
+            }
+        }
+
+        let column_list = main_columns.join(", ");
+        self.conn.execute(
+            &format!(
+                "INSERT INTO archive.{t} ({c}) SELECT {c} FROM main.{t} WHERE {f}",
+                t = table,
+                c = column_list,
+                f = filter
+            ),
+            [],
+        )?;
+        Ok(())
+    }
+
     pub fn destroy_all_data(&self) -> Result<()> {
+        self.conn.execute("DELETE FROM ai_cache", [])?;
+        self.conn.execute("DELETE FROM ai_usage", [])?;
         self.conn.execute("DELETE FROM fit_analyses", [])?;
+        self.conn.execute("DELETE FROM job_summaries", [])?;
+        self.conn.execute("DELETE FROM prep_notes", [])?;
+        self.conn.execute("DELETE FROM negotiation_briefs", [])?;
         self.conn.execute("DELETE FROM job_keyword_profiles", [])?;
         self.conn.execute("DELETE FROM job_keywords", [])?;
         self.conn.execute("DELETE FROM resume_variants", [])?;
         self.conn.execute("DELETE FROM base_resumes", [])?;
         self.conn.execute("DELETE FROM job_snapshots", [])?;
         self.conn.execute("DELETE FROM glassdoor_reviews", [])?;
+        self.conn.execute("DELETE FROM glassdoor_snapshots", [])?;
+        self.conn.execute("DELETE FROM employer_alerts", [])?;
+        self.conn.execute("DELETE FROM employer_hn_stories", [])?;
+        self.conn.execute("DELETE FROM employer_comp", [])?;
+        self.conn.execute("DELETE FROM employer_aliases", [])?;
+        self.conn.execute("DELETE FROM job_notes", [])?;
+        self.conn.execute("DELETE FROM job_tags", [])?;
+        self.conn.execute("DELETE FROM job_contacts", [])?;
+        self.conn.execute("DELETE FROM job_next_actions", [])?;
+        self.conn.execute("DELETE FROM job_flags", [])?;
+        self.conn.execute("DELETE FROM pipeline_run_steps", [])?;
+        self.conn.execute("DELETE FROM pipeline_runs", [])?;
+        self.conn.execute("DELETE FROM fetch_domains", [])?;
+        self.conn.execute("DELETE FROM contacts", [])?;
+        self.conn.execute("DELETE FROM status_history", [])?;
+        self.conn.execute("DELETE FROM user_skills", [])?;
+        self.conn.execute("DELETE FROM negative_keywords", [])?;
+        self.conn.execute("DELETE FROM cleanup_decisions", [])?;
+        self.conn.execute("DELETE FROM rank_rules", [])?;
+        self.conn.execute("DELETE FROM saved_filters", [])?;
+        self.conn.execute("DELETE FROM ingested_emails", [])?;
+        self.conn.execute("DELETE FROM learning_items", [])?;
+        self.conn.execute("DELETE FROM learning_plans", [])?;
+        self.conn.execute("DELETE FROM review_cards", [])?;
+        self.conn.execute("DELETE FROM job_cluster_members", [])?;
+        self.conn.execute("DELETE FROM job_clusters", [])?;
         self.conn.execute("DELETE FROM jobs", [])?;
         self.conn.execute("DELETE FROM employers", [])?;
 
@@ -1631,6 +5288,137 @@ impl Database {
         Ok(())
     }
 
+    // --- Pipeline run operations ---
+
+    pub fn start_pipeline_run(&self) -> Result<i64> {
+        self.conn.execute("INSERT INTO pipeline_runs DEFAULT VALUES", [])?;
+        Ok(self.conn.last_insert_rowid())
+    }
+
+    pub fn finish_pipeline_run(&self, run_id: i64) -> Result<()> {
+        self.conn.execute(
+            "UPDATE pipeline_runs SET finished_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now') WHERE id = ?1",
+            [run_id],
+        )?;
+        Ok(())
+    }
+
+    /// The most recent refresh, if it never finished (`hunt refresh --resume`).
+    pub fn interrupted_pipeline_run(&self) -> Result<Option<PipelineRun>> {
+        let run = self.conn.query_row(
+            "SELECT id, started_at, finished_at FROM pipeline_runs ORDER BY id DESC LIMIT 1",
+            [],
+            |row| Ok(PipelineRun { id: row.get(0)?, started_at: row.get(1)?, finished_at: row.get(2)? }),
+        );
+        match run {
+            Ok(run) if run.finished_at.is_none() => Ok(Some(run)),
+            Ok(_) | Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Record a job a run's stage got through (`ok` false if it failed), or with no job,
+    /// that the stage as a whole is done.
+    pub fn record_pipeline_step(&self, run_id: i64, stage: &str, job_id: Option<i64>, ok: bool) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO pipeline_run_steps (run_id, stage, job_id, ok) VALUES (?1, ?2, ?3, ?4)",
+            params![run_id, stage, job_id, ok],
+        )?;
+        Ok(())
+    }
+
+    pub fn pipeline_stage_done(&self, run_id: i64, stage: &str) -> Result<bool> {
+        Ok(self.conn.query_row(
+            "SELECT EXISTS (SELECT 1 FROM pipeline_run_steps WHERE run_id = ?1 AND stage = ?2 AND job_id IS NULL)",
+            params![run_id, stage],
+            |row| row.get(0),
+        )?)
+    }
+
+    /// Jobs a run's stage already got through, whether or not they succeeded.
+    pub fn pipeline_stage_jobs(&self, run_id: i64, stage: &str) -> Result<HashSet<i64>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT job_id FROM pipeline_run_steps WHERE run_id = ?1 AND stage = ?2 AND job_id IS NOT NULL",
+        )?;
+        let jobs = stmt.query_map(params![run_id, stage], |row| row.get(0))?
+            .collect::<rusqlite::Result<HashSet<i64>>>()?;
+        Ok(jobs)
+    }
+
+    // --- Fetch domain operations ---
+
+    pub fn record_fetch_success(&self, domain: &str) -> Result<()> {
+        self.conn.execute(
+            "INSERT INTO fetch_domains (domain, successes, last_success_at)
+             VALUES (?1, 1, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+             ON CONFLICT(domain) DO UPDATE SET
+                 successes = successes + 1,
+                 failure_streak = 0,
+                 last_success_at = excluded.last_success_at,
+                 backoff_until = NULL",
+            [domain],
+        )?;
+        Ok(())
+    }
+
+    /// Count a failed fetch; returns the domain's failure streak including this one.
+    pub fn record_fetch_failure(&self, domain: &str, error: &str) -> Result<i64> {
+        Ok(self.conn.query_row(
+            "INSERT INTO fetch_domains (domain, failures, failure_streak, last_error, last_failure_at)
+             VALUES (?1, 1, 1, ?2, strftime('%Y-%m-%dT%H:%M:%SZ', 'now'))
+             ON CONFLICT(domain) DO UPDATE SET
+                 failures = failures + 1,
+                 failure_streak = failure_streak + 1,
+                 last_error = excluded.last_error,
+                 last_failure_at = excluded.last_failure_at
+             RETURNING failure_streak",
+            params![domain, error],
+            |row| row.get(0),
+        )?)
+    }
+
+    pub fn set_fetch_backoff(&self, domain: &str, until: Option<&str>) -> Result<()> {
+        self.conn.execute("UPDATE fetch_domains SET backoff_until = ?2 WHERE domain = ?1", params![domain, until])?;
+        Ok(())
+    }
+
+    pub fn get_fetch_domain(&self, domain: &str) -> Result<Option<FetchDomain>> {
+        let result = self.conn.query_row(
+            &format!("SELECT {} FROM fetch_domains WHERE domain = ?1", FETCH_DOMAIN_COLUMNS),
+            [domain],
+            Self::row_to_fetch_domain,
+        );
+        match result {
+            Ok(domain) => Ok(Some(domain)),
+            Err(rusqlite::Error::QueryReturnedNoRows) => Ok(None),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    /// Every domain fetched from, those failing most recently first (`hunt fetch --status`).
+    pub fn list_fetch_domains(&self) -> Result<Vec<FetchDomain>> {
+        let mut stmt = self.conn.prepare(&format!(
+            "SELECT {} FROM fetch_domains ORDER BY failure_streak DESC, last_failure_at DESC, domain",
+            FETCH_DOMAIN_COLUMNS
+        ))?;
+        let domains = stmt.query_map([], Self::row_to_fetch_domain)?
+            .collect::<rusqlite::Result<Vec<_>>>()?;
+        Ok(domains)
+    }
+
+    fn row_to_fetch_domain(row: &rusqlite::Row) -> rusqlite::Result<FetchDomain> {
+        Ok(FetchDomain {
+            domain: row.get(0)?,
+            successes: row.get(1)?,
+            failures: row.get(2)?,
+            failure_streak: row.get(3)?,
+            last_error: row.get(4)?,
+            last_success_at: row.get(5)?,
+            last_failure_at: row.get(6)?,
+            backoff_until: row.get(7)?,
+        })
+    }
+
     // --- Glassdoor Review operations ---
 
     pub fn add_glassdoor_review(
@@ -1728,19 +5516,53 @@ impl Database {
         Ok(())
     }
 
+    /// Refresh the employer's rating and review count after a fetch, and record them
+    /// (with the sentiment breakdown) as a `glassdoor_snapshots` row for `hunt glassdoor trend`.
     pub fn update_employer_glassdoor_summary(&self, employer_id: i64) -> Result<()> {
         self.conn.execute(
             "UPDATE employers SET
                 glassdoor_rating = (SELECT AVG(rating) FROM glassdoor_reviews WHERE employer_id = ?1),
                 glassdoor_review_count = (SELECT COUNT(*) FROM glassdoor_reviews WHERE employer_id = ?1),
-                last_glassdoor_fetch = datetime('now'),
-                updated_at = datetime('now')
+                last_glassdoor_fetch = strftime('%Y-%m-%dT%H:%M:%SZ', 'now'),
+                updated_at = strftime('%Y-%m-%dT%H:%M:%SZ', 'now')
              WHERE id = ?1",
             [employer_id],
         )?;
+
+        let (positive, negative, neutral, avg_rating) = self.get_sentiment_summary(employer_id)?;
+        if positive + negative + neutral > 0 {
+            self.conn.execute(
+                "INSERT INTO glassdoor_snapshots (employer_id, rating, review_count, positive, neutral, negative)
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![employer_id, avg_rating, positive + negative + neutral, positive, neutral, negative],
+            )?;
+        }
         Ok(())
     }
 
+    /// Every recorded fetch for an employer, oldest first.
+    pub fn list_glassdoor_snapshots(&self, employer_id: i64) -> Result<Vec<GlassdoorSnapshot>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id, employer_id, rating, review_count, positive, neutral, negative, fetched_at
+             FROM glassdoor_snapshots WHERE employer_id = ?1
+             ORDER BY fetched_at, id",
+        )?;
+        let rows = stmt.query_map([employer_id], |row| {
+            Ok(GlassdoorSnapshot {
+                id: row.get(0)?,
+                employer_id: row.get(1)?,
+                rating: row.get(2)?,
+                review_count: row.get(3)?,
+                positive: row.get(4)?,
+                neutral: row.get(5)?,
+                negative: row.get(6)?,
+                fetched_at: row.get(7)?,
+            })
+        })?;
+        rows.collect::<Result<Vec<_>, _>>()
+            .context("Failed to list Glassdoor snapshots")
+    }
+
     /// Get all employers that have glassdoor reviews
     pub fn list_employers_with_glassdoor(&self) -> Result<Vec<Employer>> {
         let sql = "SELECT id, name, domain, status, notes, created_at, updated_at,
@@ -1750,7 +5572,9 @@ impl Database {
              evil_summary, public_research_updated_at,
              parent_company, pe_owner, pe_firm_url, vc_investors, key_investors,
              ownership_concerns, ownership_type, ownership_research_updated,
-             glassdoor_rating, glassdoor_review_count, last_glassdoor_fetch
+             glassdoor_rating, glassdoor_review_count, last_glassdoor_fetch,
+             eng_blog_url, eng_rss_url, eng_talks, eng_culture_summary, eng_research_updated,
+             careers_url
              FROM employers
              WHERE glassdoor_review_count > 0
              ORDER BY glassdoor_rating DESC";
@@ -1876,98 +5700,282 @@ fn extract_job_code(content: &str) -> Option<String> {
     None
 }
 
+/// Canonical tag form: lowercase, no leading `+`/`#`, inner whitespace as `-`.
+pub fn normalize_tag(tag: &str) -> String {
+    tag.trim()
+        .trim_start_matches(['+', '#'])
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join("-")
+        .to_lowercase()
+}
+
+/// Advertised pay as annual USD, whatever the posting's period and currency.
 pub fn extract_pay_range(content: &str) -> (Option<i64>, Option<i64>) {
-    // Look for salary patterns like "$150,000 - $200,000" or "$150k-200k"
-    let _re_patterns = [
-        r"\$(\d{2,3}),?(\d{3})\s*[-–to]+\s*\$(\d{2,3}),?(\d{3})",  // $150,000 - $200,000
-        r"\$(\d{2,3})k\s*[-–to]+\s*\$?(\d{2,3})k",                  // $150k - $200k
-    ];
+    pay::parse(content).map_or((None, None), |pay| pay.annual_usd())
+}
 
-    // Simple pattern matching without regex for now
-    let lower = content.to_lowercase();
+/// Ranking factors: (name, default weight, how points are earned at weight 1.0).
+/// Each factor's points are multiplied by its weight from `rank_weights`.
+pub const RANK_FACTORS: &[(&str, f64, &str)] = &[
+    ("pay", 1.0, "pay_max / $10k (max 30), or pay_min / $15k (max 20)"),
+    ("fit", 1.0, "best fit score / 2 (0-50)"),
+    ("keywords", 1.0, "share of the job's weighted keywords covered by your skill profile, else your resumes (0-20)"),
+    ("status", 1.0, "non-terminal statuses by pipeline position, up to +25 for the last (by default new +5, reviewing +10, applied +15, interviewing +20, offer +25)"),
+    ("employer_status", 1.0, "yuck -20, never -100"),
+    ("employer_rating", 1.0, "(Glassdoor rating - 3) x 5 (-10 to +10)"),
+    ("recency", 0.0, "+10 for a job posted (else added) today, fading to 0 over 30 days"),
+    ("stale", 1.0, "-5 per week since a job was posted (else added) past two weeks (max -20)"),
+    ("deadline", 1.0, "jobs not yet applied to: +15 on the deadline day, 1 less per day before it; -30 once it has passed"),
+    ("source", 1.0, "company careers site or ATS board +10, job board or alert email -5"),
+    ("negative_keywords", 1.0, "-15 per required keyword on your exclude list (hunt exclude)"),
+    ("rules", 1.0, "the boost or penalty of each of your keyword rules the job matches (hunt rule)"),
+    ("commute", 1.0, "onsite jobs +10 at your home, 0 at 100 miles, -20 at 300+ (hybrid half; needs config home)"),
+];
+
+const BASE_SCORE: f64 = 50.0;
+
+#[derive(Debug, Clone)]
+pub struct ScoreFactor {
+    pub name: &'static str,
+    pub points: f64, // points at weight 1.0
+    pub weight: f64,
+}
 
-    // Look for "$XXXk" patterns
-    let mut pay_min = None;
-    let mut pay_max = None;
-
-    let chars: Vec<char> = lower.chars().collect();
-    for i in 0..chars.len() {
-        if chars[i] == '$' {
-            // Try to parse number after $
-            let mut j = i + 1;
-            let mut num_str = String::new();
-            while j < chars.len() && (chars[j].is_ascii_digit() || chars[j] == ',' || chars[j] == '.') {
-                if chars[j].is_ascii_digit() {
-                    num_str.push(chars[j]);
-                }
-                j += 1;
-            }
+impl ScoreFactor {
+    pub fn contribution(&self) -> f64 {
+        self.points * self.weight
+    }
+}
 
-            if !num_str.is_empty() {
-                if let Ok(num) = num_str.parse::<i64>() {
-                    let value = if j < chars.len() && chars[j] == 'k' {
-                        num * 1000
-                    } else if num < 1000 {
-                        // Likely already in thousands (e.g., $150 meaning $150k)
-                        num * 1000
-                    } else {
-                        num
-                    };
-
-                    if pay_min.is_none() {
-                        pay_min = Some(value);
-                    } else if pay_max.is_none() {
-                        pay_max = Some(value);
-                    }
-                }
+#[derive(Debug, Clone)]
+pub struct ScoreBreakdown {
+    pub base: f64,
+    pub factors: Vec<ScoreFactor>,
+}
+
+impl ScoreBreakdown {
+    pub fn total(&self) -> f64 {
+        (self.base + self.factors.iter().map(ScoreFactor::contribution).sum::<f64>()).max(0.0)
+    }
+}
+
+/// `statuses` is `list_job_statuses()`, loaded once by the caller rather than once per job.
+pub fn calculate_score(job: &Job, db: &Database, statuses: &[JobStatus]) -> f64 {
+    score_breakdown(job, db, statuses).total()
+}
+
+/// Per-factor scoring for `job` using the configured rank weights.
+pub fn score_breakdown(job: &Job, db: &Database, statuses: &[JobStatus]) -> ScoreBreakdown {
+    let weights = db.get_rank_weights().unwrap_or_else(|_| {
+        RANK_FACTORS.iter().map(|(name, weight, _)| (name.to_string(), *weight)).collect()
+    });
+    let factors = RANK_FACTORS
+        .iter()
+        .map(|(name, default, _)| ScoreFactor {
+            name,
+            points: factor_points(name, job, db, statuses),
+            weight: weights.iter().find(|(n, _)| n == name).map(|(_, w)| *w).unwrap_or(*default),
+        })
+        .collect();
+    ScoreBreakdown { base: BASE_SCORE, factors }
+}
+
+fn factor_points(factor: &str, job: &Job, db: &Database, statuses: &[JobStatus]) -> f64 {
+    match factor {
+        // Higher pay = higher score
+        "pay" => match (job.pay_min, job.pay_max) {
+            (_, Some(max)) => (max as f64 / 10000.0).min(30.0),
+            (Some(min), None) => (min as f64 / 15000.0).min(20.0),
+            (None, None) => 0.0,
+        },
+        // 0-100 fit score → 0-50 points
+        "fit" => db.get_best_fit_score(job.id).ok().flatten().map(|f| f * 0.5).unwrap_or(0.0),
+        "keywords" => keyword_match_ratio(job.id, db).map(|r| r * 20.0).unwrap_or(0.0),
+        // Further along the pipeline scores higher; terminal statuses earn nothing
+        "status" => {
+            let active: Vec<&JobStatus> = statuses.iter().filter(|s| !s.is_terminal).collect();
+            active.iter()
+                .position(|s| s.name == job.status)
+                .map_or(0.0, |i| 25.0 * (i + 1) as f64 / active.len() as f64)
+        }
+        "employer_status" => match job.employer_id.and_then(|id| db.get_employer_status(id).ok()).as_deref() {
+            Some("yuck") => -20.0,
+            Some("never") => -100.0, // Should effectively exclude
+            _ => 0.0,
+        },
+        "employer_rating" => job.employer_id
+            .and_then(|id| {
+                db.conn.query_row("SELECT glassdoor_rating FROM employers WHERE id = ?1", [id], |row| {
+                    row.get::<_, Option<f64>>(0)
+                }).ok().flatten()
+            })
+            .map(|rating| ((rating - 3.0) * 5.0).clamp(-10.0, 10.0))
+            .unwrap_or(0.0),
+        "negative_keywords" => {
+            let negatives = db.get_negative_keywords().unwrap_or_default();
+            if negatives.is_empty() {
+                return 0.0;
             }
+            let required = db.get_latest_keyword_model(job.id).ok().flatten()
+                .and_then(|model| db.get_job_keywords(job.id, Some(&model)).ok())
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|k| k.weight == 3 && negatives.iter().any(|n| skill_covers(n, &k.keyword)))
+                .count();
+            required as f64 * -15.0
         }
+        // Folded from 0.0: an empty f64 sum is -0.0, which --explain prints as "-0.0"
+        "rules" => db.matching_rank_rules(job)
+            .map(|rules| rules.iter().fold(0.0, |total, r| total + r.points))
+            .unwrap_or(0.0),
+        "commute" => geo::home()
+            .and_then(|home| geo::commute(job, home, &geo::OfflineCities))
+            .map_or(0.0, |(_, points)| points),
+        "recency" => job_age_days(job).map_or(0.0, |age| (10.0 * (1.0 - age / 30.0)).clamp(0.0, 10.0)),
+        "stale" => job_age_days(job).map_or(0.0, |age| (-5.0 * (age - 14.0) / 7.0).clamp(-20.0, 0.0)),
+        // Only urgent while the job is in a non-terminal status ahead of applied
+        "deadline" => {
+            let applied = statuses.iter().find(|s| s.name == "applied").map_or(i64::MAX, |s| s.position);
+            let unapplied = statuses.iter().any(|s| s.name == job.status && !s.is_terminal && s.position < applied);
+            match dates::days_left(job, dates::today()) {
+                Some(days) if unapplied && days < 0 => -30.0,
+                Some(days) if unapplied => (15 - days).max(0) as f64,
+                _ => 0.0,
+            }
+        }
+        "source" => match posting_source(job) {
+            Some(PostingSource::Company) => 10.0,
+            Some(PostingSource::Aggregator) => -5.0,
+            None => 0.0,
+        },
+        _ => 0.0,
     }
+}
 
-    // Ensure min < max
-    if let (Some(min), Some(max)) = (pay_min, pay_max) {
-        if min > max {
-            return (Some(max), Some(min));
-        }
+/// Days since the job was posted, or added when the posting date isn't known.
+pub fn job_age_days(job: &Job) -> Option<f64> {
+    if let Some(posted) = job.posted_at.as_deref().and_then(dates::parse_day) {
+        return Some((dates::today() - posted).num_days().max(0) as f64);
     }
+    timestamps::parse(&job.created_at).map(|created| (chrono::Utc::now() - created).num_hours() as f64 / 24.0)
+}
+
+/// Days an application can sit in "applied" before the browser flags it as stalled.
+pub const STALLED_DAYS: i64 = 14;
 
-    (pay_min, pay_max)
+/// Whole days since the job was first applied to.
+pub fn days_since_applied(job: &Job) -> Option<i64> {
+    job.applied_at.as_deref().and_then(timestamps::parse).map(|applied| (chrono::Utc::now() - applied).num_days().max(0))
 }
 
-pub fn calculate_score(job: &Job, db: &Database) -> f64 {
-    let mut score = 50.0; // Base score
+/// Alert sources that only relay postings from elsewhere.
+const AGGREGATOR_SOURCES: &[&str] = &["linkedin", "indeed", "glassdoor", "email", "email-ai"];
 
-    // Pay bonus (higher pay = higher score)
-    if let Some(max) = job.pay_max {
-        score += (max as f64 / 10000.0).min(30.0); // Up to 30 points for high pay
-    } else if let Some(min) = job.pay_min {
-        score += (min as f64 / 15000.0).min(20.0); // Up to 20 points if only min
-    }
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostingSource {
+    Company,    // the employer's careers site or its ATS board
+    Aggregator, // a job board, or an alert email without a direct link
+}
 
-    // Employer status penalty
-    if let Some(emp_id) = job.employer_id {
-        if let Ok(status) = db.get_employer_status(emp_id) {
-            match status.as_str() {
-                "yuck" => score -= 20.0,
-                "never" => score -= 100.0, // Should effectively exclude
-                _ => {}
-            }
+impl PostingSource {
+    pub fn label(self) -> &'static str {
+        match self {
+            PostingSource::Company => "company board",
+            PostingSource::Aggregator => "aggregator",
         }
     }
+}
 
-    // Status bonus (reviewing > new)
-    match job.status.as_str() {
-        "reviewing" => score += 10.0,
-        "new" => score += 5.0,
-        _ => {}
-    }
+/// Where a posting comes from: its URL's host when it has one, else the source it was
+/// imported from. None for pasted jobs with no URL.
+pub fn posting_source(job: &Job) -> Option<PostingSource> {
+    let from_url = job.url.as_deref().and_then(domains::is_job_board_url);
+    let aggregator = match from_url {
+        Some(aggregator) => aggregator,
+        None => match job.source.as_deref() {
+            Some(source) if AGGREGATOR_SOURCES.contains(&source) => true,
+            Some("greenhouse" | "lever") => false,
+            _ => return None,
+        },
+    };
+    Some(if aggregator { PostingSource::Aggregator } else { PostingSource::Company })
+}
 
-    // Fit score bonus: up to +50 points based on best fit analysis
-    if let Ok(Some(fit_score)) = db.get_best_fit_score(job.id) {
-        score += fit_score * 0.5; // 0-100 fit score → 0-50 points
-    }
+/// Fraction (by keyword weight) of the job's latest keywords covered by the skill
+/// profile, or, without one, appearing in any base resume.
+fn keyword_match_ratio(job_id: i64, db: &Database) -> Option<f64> {
+    if !db.get_user_skills().ok()?.is_empty() {
+        return db.get_skill_match(job_id).ok().flatten();
+    }
+    let model = db.get_latest_keyword_model(job_id).ok().flatten()?;
+    let keywords = db.get_job_keywords(job_id, Some(&model)).ok()?;
+    let corpus = db.resume_corpus().ok()?;
+    if keywords.is_empty() || corpus.is_empty() {
+        return None;
+    }
+    let total: i32 = keywords.iter().map(|k| k.weight).sum();
+    let matched: i32 = keywords.iter()
+        .filter(|k| corpus.contains(&k.keyword.to_lowercase()))
+        .map(|k| k.weight)
+        .sum();
+    Some(matched as f64 / total as f64)
+}
+
+fn skill_tokens(s: &str) -> Vec<String> {
+    s.to_lowercase()
+        .split(|c: char| c.is_whitespace() || "/,()-_".contains(c))
+        .filter(|t| !t.is_empty())
+        .map(|t| t.to_string())
+        .collect()
+}
 
-    score.max(0.0)
+/// SHA-256 of a base resume's content, recorded on variants to spot stale ones.
+pub fn content_hash(content: &str) -> String {
+    use sha2::{Digest, Sha256};
+    Sha256::digest(content.as_bytes()).iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+/// Lowercase, collapse whitespace, drop empties and duplicates (skills, exclusions).
+pub fn normalize_terms(terms: &[String]) -> Vec<String> {
+    let mut normalized: Vec<String> = terms.iter()
+        .map(|s| s.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase())
+        .filter(|s| !s.is_empty())
+        .collect();
+    normalized.sort();
+    normalized.dedup();
+    normalized
+}
+
+/// Whether a skill covers a keyword: the skill's words appear, in order, among the
+/// keyword's words. "aws" covers "AWS Lambda"; "go" doesn't cover "Google Cloud".
+pub fn skill_covers(skill: &str, keyword: &str) -> bool {
+    let skill = skill_tokens(skill);
+    let keyword = skill_tokens(keyword);
+    !skill.is_empty() && keyword.windows(skill.len()).any(|w| w == skill.as_slice())
+}
+
+/// Keywords some job requires (weight 3) that no skill covers, most-required first.
+pub fn required_skill_gaps<'a>(demand: &'a [KeywordDemand], skills: &[String]) -> Vec<&'a KeywordDemand> {
+    let mut gaps: Vec<&KeywordDemand> = demand.iter()
+        .filter(|d| d.required > 0 && d.domain != "soft_skill")
+        .filter(|d| !skills.iter().any(|s| skill_covers(s, &d.keyword)))
+        .collect();
+    gaps.sort_by(|a, b| b.required.cmp(&a.required).then(b.total_weight.cmp(&a.total_weight)));
+    gaps
+}
+
+/// Share of keyword weight covered by `skills`; None if there's nothing to compare.
+pub fn skill_match_ratio(keywords: &[JobKeyword], skills: &[String]) -> Option<f64> {
+    let total: i32 = keywords.iter().map(|k| k.weight).sum();
+    if skills.is_empty() || total == 0 {
+        return None;
+    }
+    let matched: i32 = keywords.iter()
+        .filter(|k| skills.iter().any(|s| skill_covers(s, &k.keyword)))
+        .map(|k| k.weight)
+        .sum();
+    Some(matched as f64 / total as f64)
 }
 
 /// Normalize title for comparison: trim and lowercase
@@ -1975,6 +5983,12 @@ fn normalize_title(title: &str) -> String {
     title.trim().to_lowercase()
 }
 
+/// Whether two normalized titles at the same employer name the same job: equal, one
+/// inside the other, or more than 80% alike.
+fn titles_match(a: &str, b: &str) -> bool {
+    a == b || a.contains(b) || b.contains(a) || strsim::jaro_winkler(a, b) > 0.8
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -1989,6 +6003,16 @@ mod tests {
         Ok(db)
     }
 
+    #[test]
+    fn test_resolve_path() {
+        let (flag, profile) = (Path::new("/tmp/search-a.db"), Path::new("/tmp/profile/hunt.db"));
+        assert_eq!(Database::resolve_path(Some(flag), Some("/tmp/search-b.db"), profile, true), flag, "flag wins");
+        assert_eq!(Database::resolve_path(None, Some("/tmp/search-b.db"), profile, false), PathBuf::from("/tmp/search-b.db"));
+        assert_eq!(Database::resolve_path(None, Some("/tmp/search-b.db"), profile, true), profile, "--profile beats HUNT_DB");
+        assert_eq!(Database::resolve_path(None, Some(""), profile, false), profile, "empty HUNT_DB is unset");
+        assert_eq!(Database::resolve_path(None, None, profile, false), profile);
+    }
+
     #[test]
     fn test_exact_title_match_same_employer() -> Result<()> {
         let db = create_test_db()?;
@@ -2187,6 +6211,78 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_find_duplicates_buckets() -> Result<()> {
+        let db = create_test_db()?;
+        let add = |title: &str, employer: Option<&str>, url: Option<&str>| {
+            db.add_job_full(title, employer, url, None, None, None, None)
+        };
+        let original = add("Staff SRE", Some("Acme"), Some("https://acme.example/1"))?;
+        let same_url = add("Site Reliability", Some("Acme Inc"), Some("https://acme.example/1"))?;
+        add("Staff SRE", Some("Acme"), Some("https://acme.example/2"))?; // both have URLs: not a dup
+        let fuzzy = add("Staff SRE (Remote)", Some("ACME"), None)?;
+        add("Staff SRE", None, None)?;
+        for i in 0..3000 {
+            add(&format!("Role {}", i), Some(&format!("Employer {}", i)), None)?;
+        }
+
+        let start = std::time::Instant::now();
+        let duplicates = db.find_duplicates()?;
+        assert!(start.elapsed() < std::time::Duration::from_secs(2));
+        let pairs: Vec<(i64, i64)> = duplicates.iter().map(|(a, b, _)| (*a, *b)).collect();
+        assert_eq!(pairs, vec![(original, same_url), (original, fuzzy)]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_job_urls_canonicalized() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job_full("SRE", Some("Acme"), Some("https://www.linkedin.com/comm/jobs/view/42/?refId=a&trk=b"), None, None, None, None)?;
+        assert_eq!(db.get_job(id)?.unwrap().url.as_deref(), Some("https://www.linkedin.com/jobs/view/42"));
+        let other_tracking = "https://linkedin.com/jobs/view/42?trackingId=c";
+        assert_eq!(db.is_duplicate_job("Totally Different", Some("Globex"), Some(other_tracking))?, Some(id));
+        assert!(db.job_exists_by_url(other_tracking)?);
+        Ok(())
+    }
+
+    #[test]
+    fn test_resolved_application_urls_dedupe() -> Result<()> {
+        let db = create_test_db()?;
+        let linkedin = db.add_job_full("Senior SRE", Some("Acme"), Some("https://www.linkedin.com/jobs/view/42"), None, None, None, None)?;
+        let workday = db.add_job_full("SRE III", Some("Acme Corp"), Some("https://acme.wd5.myworkdayjobs.com/External/job/SRE_R1"), None, None, None, None)?;
+        let closed = db.add_job_full("DBA", Some("Acme"), Some("https://acme.com/jobs/7"), None, None, None, None)?;
+        db.update_job_status(closed, "closed")?;
+        let ids: Vec<i64> = db.get_jobs_to_resolve(None)?.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![linkedin, workday], "closed jobs aren't resolved");
+        assert_eq!(db.find_duplicates()?.len(), 0);
+
+        let application = domains::resolve_application("https://acme.wd5.myworkdayjobs.com/External/job/SRE_R1?source=LinkedIn", "").unwrap();
+        db.set_job_application(linkedin, &application)?;
+        let job = db.get_job(linkedin)?.unwrap();
+        assert_eq!(job.apply_url.as_deref(), Some("https://acme.wd5.myworkdayjobs.com/External/job/SRE_R1"));
+        assert_eq!(job.ats.as_deref(), Some("Workday"));
+        assert_eq!(db.get_jobs_to_resolve(Some(5))?.len(), 1);
+
+        assert_eq!(db.job_sharing_apply_url(linkedin)?, Some(workday));
+        let pairs: Vec<(i64, i64)> = db.find_duplicates()?.iter().map(|(a, b, _)| (*a, *b)).collect();
+        assert_eq!(pairs, vec![(linkedin, workday)], "same application URL, different titles");
+        // An alert linking straight to the ATS matches the (earliest) job resolved to it
+        let alert_url = "https://acme.wd5.myworkdayjobs.com/External/job/SRE_R1?utm_source=alert";
+        assert_eq!(db.is_duplicate_job("Reliability Engineer", Some("Globex"), Some(alert_url))?, Some(linkedin));
+        Ok(())
+    }
+
+    #[test]
+    fn test_is_duplicate_job_exact_title_index() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job_full("  Platform Engineer ", Some("Acme"), None, None, None, None, None)?;
+        db.add_employer_alias("Acme Corporation", db.get_or_create_employer("Acme")?)?;
+        assert_eq!(db.is_duplicate_job("platform engineer", Some("ACME"), None)?, Some(id));
+        assert_eq!(db.is_duplicate_job("Platform Engineer", Some("Acme Corporation"), None)?, Some(id));
+        assert_eq!(db.is_duplicate_job("Platform Engineer", Some("Globex"), None)?, None);
+        Ok(())
+    }
+
     // --- Employer CRUD ---
 
     #[test]
@@ -2268,13 +6364,46 @@ mod tests {
     #[test]
     fn test_add_job_full_no_employer() -> Result<()> {
         let db = create_test_db()?;
+        assert_eq!(db.max_job_id()?, 0);
         let id = db.add_job_full("Solo Job", None, None, None, None, None, None)?;
+        assert_eq!(db.max_job_id()?, id);
         let job = db.get_job(id)?.unwrap();
         assert_eq!(job.title, "Solo Job");
         assert!(job.employer_name.is_none());
         Ok(())
     }
 
+    #[test]
+    fn test_add_jobs_batch() -> Result<()> {
+        let db = create_test_db()?;
+        db.add_job_full("Staff SRE", Some("Acme"), None, None, None, None, None)?;
+        let mut jobs: Vec<NewJob> = (0..1000)
+            .map(|i| NewJob {
+                title: "Platform Engineer".to_string(),
+                employer: Some(format!("Employer {}", i)),
+                url: Some(format!("https://example.com/jobs/{}", i)),
+                source: Some("linkedin".to_string()),
+                raw_text: Some("Pay: $150,000 - $180,000".to_string()),
+                location: Some("Remote".to_string()),
+                work_mode: Some("remote"),
+                ..Default::default()
+            })
+            .collect();
+        jobs.push(NewJob { title: "Staff SRE".to_string(), employer: Some("ACME".to_string()), ..Default::default() });
+        jobs.push(NewJob { title: "Platform Engineer".to_string(), url: Some("https://example.com/jobs/7".to_string()), ..Default::default() });
+
+        let ids = db.add_jobs_batch(&jobs)?;
+        assert_eq!(ids.iter().filter(|id| id.is_some()).count(), 1000);
+        assert_eq!(&ids[1000..], &[None, None], "duplicates of stored and batched jobs");
+
+        let job = db.get_job(ids[3].unwrap())?.unwrap();
+        assert_eq!((job.title.as_str(), job.employer_name.as_deref()), ("Platform Engineer", Some("Employer 3")));
+        assert_eq!((job.location.as_deref(), job.work_mode.as_deref()), (Some("Remote"), Some("remote")));
+        assert_eq!(job.pay_text.as_deref(), Some("$150,000 - $180,000"));
+        assert_eq!(db.list_employers(None)?.len(), 1001);
+        Ok(())
+    }
+
     #[test]
     fn test_list_jobs_no_filter() -> Result<()> {
         let db = create_test_db()?;
@@ -2330,6 +6459,253 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_update_job_status_extended_pipeline() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
+        for status in ["interviewing", "offer", "accepted", "withdrawn"] {
+            db.update_job_status(id, status)?;
+            assert_eq!(db.get_job(id)?.unwrap().status, status);
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_job_status_rejects_unknown() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
+        let err = db.update_job_status(id, "ghosted").unwrap_err();
+        assert!(err.to_string().contains("Unknown status 'ghosted'"));
+        assert_eq!(db.get_job(id)?.unwrap().status, "new");
+        Ok(())
+    }
+
+    #[test]
+    fn test_update_job_status_records_history() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
+        let other = db.add_job_full("Other", Some("Co"), None, None, None, None, None)?;
+        db.update_job_status(id, "applied")?;
+        db.update_job_status(id, "applied")?; // no-op, not a transition
+        db.update_job_status(id, "interviewing")?;
+        db.update_job_status(other, "rejected")?;
+
+        let history: Vec<(Option<String>, String)> = db.list_status_history(Some(id))?
+            .into_iter()
+            .map(|h| (h.from_status, h.to_status))
+            .collect();
+        assert_eq!(history, vec![
+            (Some("new".to_string()), "applied".to_string()),
+            (Some("applied".to_string()), "interviewing".to_string()),
+        ]);
+        assert_eq!(db.list_status_history(None)?.len(), 3);
+
+        db.purge_job(id)?;
+        assert_eq!(db.list_status_history(None)?.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_status_transition_guard() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
+        db.set_job_status(id, "reviewing", false)?;
+        db.set_job_status(id, "new", false)?; // not applied yet, free to move back
+        db.set_job_status(id, "applied", false)?;
+        let err = db.set_job_status(id, "new", false).unwrap_err();
+        assert!(err.to_string().contains("--force"), "{}", err);
+        assert_eq!(db.get_job(id)?.unwrap().status, "applied");
+        db.set_job_status(id, "interviewing", false)?;
+        db.set_job_status(id, "rejected", false)?;
+        assert!(db.set_job_status(id, "interviewing", false).is_err(), "reopening a terminal status");
+        db.set_job_status(id, "closed", false)?; // terminal to terminal is fine
+        db.set_job_status(id, "applied", true)?;
+        assert_eq!(db.get_job(id)?.unwrap().status, "applied");
+        assert!(db.set_job_status(9999, "applied", false).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_forced_rollback_clears_applied_at() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
+        db.set_job_status(id, "applied", false)?;
+        db.set_job_status(id, "rejected", false)?;
+        assert!(db.get_job(id)?.unwrap().applied_at.is_some(), "terminal statuses keep it");
+
+        db.set_job_status(id, "new", true)?;
+        let job = db.get_job(id)?.unwrap();
+        assert!(job.applied_at.is_none());
+        assert_eq!(days_since_applied(&job), None);
+        assert!(db.get_digest_activity("2000-01-01T00:00:00Z", 5)?.applied.is_empty());
+
+        db.set_job_status(id, "applied", false)?;
+        assert!(db.get_job(id)?.unwrap().applied_at.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_status_timestamps() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
+        db.update_job_status(id, "applied")?;
+        db.conn.execute("UPDATE jobs SET applied_at = '2026-01-05T12:00:00Z' WHERE id = ?1", [id])?;
+        db.update_job_status(id, "interviewing")?;
+        db.update_job_status(id, "applied")?;
+        let job = db.get_job(id)?.unwrap();
+        assert_eq!(job.applied_at.as_deref(), Some("2026-01-05T12:00:00Z"), "first application kept");
+        assert!(days_since_applied(&job).unwrap() > 100);
+        assert!(job.rejected_at.is_none() && job.closed_at.is_none());
+
+        db.update_job_status(id, "rejected")?;
+        db.update_job_status(id, "closed")?;
+        let job = db.get_job(id)?.unwrap();
+        assert!(job.rejected_at.is_some() && job.closed_at.is_some());
+
+        // Older databases fill them in from status_history
+        db.conn.execute_batch(
+            "UPDATE status_history SET changed_at = '2026-02-01T00:00:00Z' WHERE to_status = 'applied';
+             ALTER TABLE jobs DROP COLUMN applied_at;
+             ALTER TABLE jobs DROP COLUMN rejected_at;
+             ALTER TABLE jobs DROP COLUMN closed_at;",
+        )?;
+        db.migrate()?;
+        let job = db.get_job(id)?.unwrap();
+        assert_eq!(job.applied_at.as_deref(), Some("2026-02-01T00:00:00Z"));
+        assert!(job.rejected_at.is_some() && job.closed_at.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_default_job_statuses_seeded_in_order() -> Result<()> {
+        let db = create_test_db()?;
+        let names: Vec<String> = db.list_job_statuses()?.into_iter().map(|s| s.name).collect();
+        assert_eq!(
+            names,
+            vec!["new", "reviewing", "applied", "interviewing", "offer", "accepted", "rejected", "withdrawn", "closed"]
+        );
+        let closed = db.get_job_status("closed")?.unwrap();
+        assert!(closed.is_terminal);
+        assert!(!db.get_job_status("offer")?.unwrap().is_terminal);
+        Ok(())
+    }
+
+    #[test]
+    fn test_upsert_and_remove_job_status() -> Result<()> {
+        let db = create_test_db()?;
+        db.upsert_job_status("phone-screen", None, false, "blue")?;
+        let added = db.get_job_status("phone-screen")?.unwrap();
+        assert_eq!(added.position, 100); // after "closed" at 90
+        assert_eq!(added.color, "blue");
+
+        db.upsert_job_status("phone-screen", Some(35), false, "lightblue")?;
+        let updated = db.get_job_status("phone-screen")?.unwrap();
+        assert_eq!(updated.position, 35);
+        assert_eq!(updated.color, "lightblue");
+
+        let id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
+        db.update_job_status(id, "phone-screen")?;
+        assert!(db.remove_job_status("phone-screen").is_err());
+
+        db.update_job_status(id, "applied")?;
+        db.remove_job_status("phone-screen")?;
+        assert!(db.get_job_status("phone-screen")?.is_none());
+        assert!(db.remove_job_status("phone-screen").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_drops_status_check_constraint() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE employers (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL UNIQUE);
+             CREATE TABLE jobs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                employer_id INTEGER REFERENCES employers(id),
+                title TEXT NOT NULL,
+                url TEXT,
+                source TEXT,
+                status TEXT NOT NULL DEFAULT 'new' CHECK (status IN ('new', 'reviewing', 'applied', 'rejected', 'closed')),
+                pay_min INTEGER,
+                pay_max INTEGER,
+                job_code TEXT,
+                raw_text TEXT,
+                fetched_at TEXT,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+             );
+             INSERT INTO jobs (title, status) VALUES ('Legacy Job', 'applied');",
+        )?;
+        let db = Database {
+            conn,
+            path: PathBuf::from(":memory:"),
+        };
+        db.init()?;
+
+        let job = db.get_job(1)?.unwrap();
+        assert_eq!(job.title, "Legacy Job");
+        assert_eq!(job.status, "applied");
+        let backfilled = db.list_status_history(Some(1))?;
+        assert_eq!(backfilled.len(), 1);
+        assert_eq!((backfilled[0].from_status.as_deref(), backfilled[0].to_status.as_str()), (None, "applied"));
+        db.update_job_status(1, "interviewing")?;
+        assert_eq!(db.get_job(1)?.unwrap().status, "interviewing");
+        db.migrate()?;
+        assert_eq!(db.list_status_history(Some(1))?.len(), 2, "backfill runs once per job");
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_converts_timestamps_to_rfc3339() -> Result<()> {
+        let conn = Connection::open_in_memory()?;
+        conn.execute_batch(
+            "CREATE TABLE employers (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL UNIQUE,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+             );
+             CREATE TABLE jobs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                employer_id INTEGER REFERENCES employers(id),
+                title TEXT NOT NULL,
+                url TEXT,
+                source TEXT,
+                status TEXT NOT NULL DEFAULT 'new',
+                pay_min INTEGER,
+                pay_max INTEGER,
+                job_code TEXT,
+                raw_text TEXT,
+                fetched_at TEXT,
+                created_at TEXT NOT NULL DEFAULT (datetime('now')),
+                updated_at TEXT NOT NULL DEFAULT (datetime('now'))
+             );
+             INSERT INTO jobs (title, created_at, updated_at) VALUES ('Legacy', '2025-01-02 03:04:05', '2025-01-02 03:04:05');",
+        )?;
+        let db = Database {
+            conn,
+            path: PathBuf::from(":memory:"),
+        };
+        db.init()?;
+
+        let legacy = db.get_job(1)?.unwrap();
+        assert_eq!(legacy.created_at, "2025-01-02T03:04:05Z");
+        assert!(legacy.fetched_at.is_none());
+
+        let id = db.add_job_full("Fresh", Some("Acme"), None, None, None, None, None)?;
+        let fresh = db.get_job(id)?.unwrap();
+        assert!(fresh.created_at.ends_with('Z'), "new rows use the rewritten default: {}", fresh.created_at);
+        assert!(timestamps::parse(&fresh.created_at).is_some());
+
+        let legacy_defaults: i64 = db.conn.query_row(
+            "SELECT COUNT(*) FROM sqlite_master WHERE sql LIKE '%datetime(''now'')%'",
+            [],
+            |row| row.get(0),
+        )?;
+        assert_eq!(legacy_defaults, 0);
+        Ok(())
+    }
+
     #[test]
     fn test_update_job_description() -> Result<()> {
         let db = create_test_db()?;
@@ -2343,11 +6719,97 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_job_location() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job_full("SRE", Some("Co"), None, None, None, None, None)?;
+        db.set_job_location(id, Some("Austin, TX"), None)?;
+        db.update_job_description(id, "SRE\nWe're a remote-first team.", None, None)?;
+        let job = db.get_job(id)?.unwrap();
+        assert_eq!(job.location.as_deref(), Some("Austin, TX"), "a description without a location keeps the old one");
+        assert_eq!(job.work_mode.as_deref(), Some("remote"));
+
+        db.update_job_description(id, "SRE\nLocation: Denver, CO (Hybrid)\n\nRun things.", None, None)?;
+        let job = db.get_job(id)?.unwrap();
+        assert_eq!((job.location.as_deref(), job.work_mode.as_deref()), (Some("Denver, CO (Hybrid)"), Some("hybrid")));
+
+        let manual = db.add_job("Platform Engineer at Acme\nLocation: Remote (US)")?;
+        assert_eq!(db.get_job(manual)?.unwrap().work_mode.as_deref(), Some("remote"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_job_pay_details() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job("Platform Engineer at Acme\nPay: €60.000 - €75.000 per year, plus stock options")?;
+        let job = db.get_job(id)?.unwrap();
+        assert_eq!((job.pay_min, job.pay_max), (Some(64800), Some(81000)));
+        assert_eq!(job.pay_text.as_deref(), Some("€60.000 - €75.000 per year"));
+        assert!(job.pay_equity);
+
+        db.update_job_description(id, "Platform Engineer\nNo pay listed.", None, None)?;
+        let job = db.get_job(id)?.unwrap();
+        assert_eq!(job.pay_text.as_deref(), Some("€60.000 - €75.000 per year"), "a description without pay keeps the old wording");
+        assert!(!job.pay_equity);
+
+        db.update_job_pay(id, Some(104000), Some(156000), "$50/hr - $75/hr")?;
+        let job = db.get_job(id)?.unwrap();
+        assert_eq!((job.pay_min, job.pay_text.as_deref()), (Some(104000), Some("$50/hr - $75/hr")));
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_job_snapshots() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job_full("SRE", Some("Co"), None, None, None, None, Some("v1"))?;
+        db.update_job_description(id, "v2", None, None)?;
+        db.update_job_description(id, "v2", None, None)?;
+        db.update_job_description(id, "v3", None, None)?;
+        let texts: Vec<String> = db.list_job_snapshots(id)?.into_iter().map(|s| s.raw_text).collect();
+        assert_eq!(texts, vec!["v1", "v2", "v3"], "an unchanged re-fetch adds no snapshot");
+        assert!(db.list_job_snapshots(id + 1)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_stale_jobs() -> Result<()> {
+        let db = create_test_db()?;
+        let old_new = db.add_job_full("Old", Some("Co"), None, None, None, None, None)?;
+        let old_applied = db.add_job_full("Applied", Some("Co"), None, None, None, None, None)?;
+        db.update_job_status(old_applied, "applied")?;
+        let old_closed = db.add_job_full("Closed", Some("Co"), None, None, None, None, None)?;
+        db.update_job_status(old_closed, "closed")?;
+        let touched = db.add_job_full("Touched", Some("Co"), None, None, None, None, None)?;
+        db.conn.execute("UPDATE jobs SET updated_at = '2026-01-01T00:00:00Z'", [])?;
+        db.touch_job(touched)?;
+        db.add_job_full("Recent", Some("Co"), None, None, None, None, None)?;
+
+        let ids: Vec<i64> = db.stale_jobs("2026-02-01T00:00:00Z")?.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![old_new]);
+        Ok(())
+    }
+
     #[test]
     fn test_delete_job() -> Result<()> {
         let db = create_test_db()?;
-        let id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
+        let id = db.add_job_full("Job", Some("Co"), Some("https://acme.com/jobs/1"), None, None, None, Some("desc"))?;
+        db.add_job_note(id, "keep me")?;
         db.delete_job(id)?;
+        let job = db.get_job(id)?.expect("archived jobs are still there");
+        assert!(job.archived_at.is_some());
+        assert!(db.list_jobs(None, None)?.is_empty());
+        assert!(db.get_jobs_to_fetch(None, true, true)?.is_empty());
+        let archived_at_co = SavedFilter { employer: Some("co".to_string()), ..Default::default() };
+        assert_eq!(db.filter_jobs(&archived_at_co, true)?.len(), 1);
+        assert_eq!(db.is_duplicate_job("Job", Some("Co"), Some("https://acme.com/jobs/1"))?, Some(id), "archived jobs still block re-ingestion");
+
+        db.restore_job(id)?;
+        assert_eq!(db.list_jobs(None, None)?.len(), 1);
+        assert_eq!(db.list_job_notes(id)?.len(), 1);
+        let err = db.restore_job(id).unwrap_err();
+        assert!(matches!(err.downcast_ref::<HuntError>(), Some(HuntError::Validation(_))));
+
+        db.purge_job(id)?;
         assert!(db.get_job(id)?.is_none());
         Ok(())
     }
@@ -2386,6 +6848,241 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_keyword_backfill_queue_prioritizes_by_score() -> Result<()> {
+        let db = create_test_db()?;
+        let low = db.add_job_full("Low", Some("Co"), None, None, None, None, Some("desc"))?;
+        let high = db.add_job_full("High", Some("Co"), None, None, None, Some(250000), Some("desc"))?;
+        let mid = db.add_job_full("Mid", Some("Co"), None, None, None, Some(120000), Some("desc"))?;
+        let closed = db.add_job_full("Closed", Some("Co"), None, None, None, Some(300000), Some("desc"))?;
+        db.update_job_status(closed, "closed")?;
+
+        let ids: Vec<i64> = db.get_keyword_backfill_queue(false, None, false)?.0.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![high, mid, low]);
+
+        let ids: Vec<i64> = db.get_keyword_backfill_queue(false, Some(2), false)?.0.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![high, mid]);
+
+        db.add_job_tags(low, &["Shortlist".to_string()])?;
+        let ids: Vec<i64> = db.get_keyword_backfill_queue(false, Some(2), false)?.0.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![low, high], "shortlisted jobs go first");
+        Ok(())
+    }
+
+    #[test]
+    fn test_keyword_backfill_queue_ranks_blocked_employer_last() -> Result<()> {
+        let db = create_test_db()?;
+        let blocked = db.add_job_full("Job", Some("Evil"), None, None, None, Some(300000), Some("desc"))?;
+        let ok = db.add_job_full("Job", Some("Fine"), None, None, None, None, Some("desc"))?;
+        db.set_employer_status("Evil", "never")?;
+
+        let ids: Vec<i64> = db.get_keyword_backfill_queue(false, Some(1), true)?.0.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![ok]);
+        let ids: Vec<i64> = db.get_keyword_backfill_queue(false, None, true)?.0.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![ok, blocked]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_keyword_backfill_queue_skips_blocked_employers() -> Result<()> {
+        let db = create_test_db()?;
+        let never = db.add_job_full("Job", Some("Evil"), None, None, None, None, Some("desc"))?;
+        let yuck = db.add_job_full("Job", Some("Meh"), None, None, None, None, Some("desc"))?;
+        let ok = db.add_job_full("Job", Some("Fine"), None, None, None, None, Some("desc"))?;
+        db.set_employer_status("Evil", "never")?;
+        db.set_employer_status("Meh", "yuck")?;
+
+        let (jobs, blocked) = db.get_keyword_backfill_queue(false, None, false)?;
+        assert_eq!(jobs.iter().map(|j| j.id).collect::<Vec<_>>(), vec![ok]);
+        assert_eq!(blocked, 2);
+
+        let yuck_job = db.get_job(yuck)?.unwrap();
+        assert_eq!(db.blocked_employer_status(&yuck_job)?.as_deref(), Some("yuck"));
+        assert!(db.blocked_employer_status(&db.get_job(ok)?.unwrap())?.is_none());
+        assert!(db.blocked_employer_status(&db.get_job(never)?.unwrap())?.is_some());
+        Ok(())
+    }
+
+    // --- Compaction ---
+
+    #[test]
+    fn test_compact_prunes_middle_snapshots_and_archives_closed_jobs() -> Result<()> {
+        let db = create_test_db()?;
+        let archive = std::env::temp_dir().join(format!("hunt_test_archive_{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&archive);
+
+        let open = db.add_job_full("Open", Some("Co"), None, None, None, None, Some("v1"))?;
+        db.update_job_description(open, "v2", None, None)?;
+        db.update_job_description(open, "v3", None, None)?;
+        db.update_job_description(open, "v4", None, None)?;
+        let closed = db.add_job_full("Closed", Some("Gone Inc"), None, None, None, None, Some("old"))?;
+        db.add_job_keywords(closed, &[("rust".to_string(), 3)], "tech", "model")?;
+        db.update_job_status(closed, "closed")?;
+        let recent_closed = db.add_job_full("Recent", Some("Co"), None, None, None, None, None)?;
+        db.update_job_status(recent_closed, "closed")?;
+
+        db.conn.execute("UPDATE job_snapshots SET captured_at = '2023-06-01 00:00:00'", [])?;
+        db.conn.execute(
+            "UPDATE jobs SET updated_at = '2023-06-01 00:00:00' WHERE id IN (?1, ?2)",
+            params![open, closed],
+        )?;
+
+        let preview = db.compact("2024-01-01", &archive, true)?;
+        assert_eq!(preview.snapshots_pruned, 2);
+        assert_eq!(preview.jobs_archived, 1);
+        assert!(db.get_job(closed)?.is_some(), "dry run must not modify");
+
+        let stats = db.compact("2024-01-01", &archive, false)?;
+        assert_eq!(stats.snapshots_pruned, 2);
+        assert_eq!(stats.jobs_archived, 1);
+
+        let texts: Vec<String> = db.conn
+            .prepare("SELECT raw_text FROM job_snapshots WHERE job_id = ?1 ORDER BY id")?
+            .query_map([open], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        assert_eq!(texts, vec!["v1", "v4"]);
+        assert!(db.get_job(closed)?.is_none());
+        assert!(db.get_job(recent_closed)?.is_some());
+        assert!(db.get_job_keywords(closed, None)?.is_empty());
+
+        let archived = Connection::open(&archive)?;
+        let title: String = archived.query_row("SELECT title FROM jobs WHERE id = ?1", [closed], |row| row.get(0))?;
+        assert_eq!(title, "Closed");
+        let keywords: i64 = archived.query_row("SELECT COUNT(*) FROM job_keywords", [], |row| row.get(0))?;
+        assert_eq!(keywords, 1);
+        let employer: String = archived.query_row("SELECT name FROM employers", [], |row| row.get(0))?;
+        assert_eq!(employer, "Gone Inc");
+
+        drop(archived);
+        std::fs::remove_file(&archive)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_compact_rejects_bad_date() -> Result<()> {
+        let db = create_test_db()?;
+        let archive = std::env::temp_dir().join("hunt_test_archive_bad_date.db");
+        assert!(db.compact("last year", &archive, true).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_journal_activity() -> Result<()> {
+        let db = create_test_db()?;
+        let old = db.add_job_full("Old Role", Some("Acme"), None, None, None, None, None)?;
+        let applied = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        db.conn.execute(
+            "UPDATE jobs SET created_at = '2024-01-01 00:00:00', updated_at = '2024-01-01 00:00:00'",
+            [],
+        )?;
+        db.update_job_status(applied, "applied")?;
+        db.update_job_description(old, "Refetched", None, None)?; // touched, but not moved
+        let fresh = db.add_job_full("Platform Engineer", Some("Beta"), None, None, None, None, None)?;
+        let resume = db.create_base_resume("main", "markdown", "# Me", None)?;
+        db.save_fit_analysis(fresh, resume, "model", 0.8, &[], &[], &[], "Good fit")?;
+        db.add_job_keywords(fresh, &[("rust".to_string(), 3)], "tech", "model")?;
+
+        let activity = db.get_journal_activity("2025-01-01 00:00:00")?;
+        assert_eq!(activity.jobs_added.len(), 1);
+        assert_eq!(activity.jobs_added[0].id, fresh);
+        assert_eq!(activity.jobs_progressed.len(), 1);
+        assert_eq!(activity.jobs_progressed[0].id, applied);
+        assert_eq!(activity.fit_analyses.len(), 1);
+        assert_eq!(activity.fit_analyses[0].1, 0.8);
+        assert_eq!(activity.keywords_extracted, 1);
+        assert!(activity.variants.is_empty());
+        assert!(activity.jobs_added.iter().chain(&activity.jobs_progressed).all(|j| j.id != old));
+        Ok(())
+    }
+
+    #[test]
+    fn test_funding_watchlist_and_alerts() -> Result<()> {
+        use crate::ai::{FundingEvent, FundingResearch};
+        let db = create_test_db()?;
+        db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        let closed = db.add_job_full("Old", Some("Gone Inc"), None, None, None, None, None)?;
+        db.update_job_status(closed, "closed")?;
+        let acme = db.get_employer_by_name("Acme")?.unwrap().id;
+
+        let watch = db.get_funding_watchlist(30)?;
+        assert_eq!(watch, vec![(acme, "Acme".to_string())]);
+
+        // First check establishes the baseline: no new_round alert
+        let mut funding = FundingResearch {
+            stage: Some("Series B".to_string()),
+            last_round_date: Some("2025-06".to_string()),
+            ..Default::default()
+        };
+        assert!(db.record_funding_check(acme, &funding)?.is_empty());
+        assert!(db.get_funding_watchlist(30)?.is_empty(), "just checked");
+
+        funding.stage = Some("Series C".to_string());
+        funding.last_round_date = Some("2026-03".to_string());
+        funding.events.push(FundingEvent {
+            kind: "layoff".to_string(),
+            date: Some("2026-05".to_string()),
+            summary: "Cut 10% of staff".to_string(),
+        });
+        let alerts = db.record_funding_check(acme, &funding)?;
+        let kinds: Vec<&str> = alerts.iter().map(|a| a.kind.as_str()).collect();
+        assert_eq!(kinds.len(), 2);
+        assert!(kinds.contains(&"new_round") && kinds.contains(&"layoff"));
+        assert_eq!(db.get_employer_by_name("Acme")?.unwrap().funding_stage.as_deref(), Some("Series C"));
+
+        // Re-reporting the same facts raises nothing new
+        assert!(db.record_funding_check(acme, &funding)?.is_empty());
+        assert_eq!(db.list_employer_alerts(false)?.len(), 2);
+        assert_eq!(db.acknowledge_employer_alerts()?, 2);
+        assert!(db.list_employer_alerts(false)?.is_empty());
+        assert_eq!(db.list_employer_alerts(true)?.len(), 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_job_notes_and_tags() -> Result<()> {
+        let db = create_test_db()?;
+        let go = db.add_job_full("Go Developer", Some("Acme"), None, None, None, None, None)?;
+        let sre = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+
+        db.add_job_note(go, "Recruiter reached out")?;
+        db.add_job_note(go, "Phone screen Tuesday")?;
+        let notes = db.list_job_notes(go)?;
+        assert_eq!(notes.len(), 2);
+        assert_eq!(notes[0].note, "Recruiter reached out");
+        assert!(db.list_job_notes(sre)?.is_empty());
+        db.update_job_note(notes[1].id, "Phone screen Wednesday")?;
+        db.update_job_note(notes[0].id, "  ")?;
+        let notes = db.list_job_notes(go)?;
+        assert_eq!(notes.len(), 1);
+        assert_eq!(notes[0].note, "Phone screen Wednesday");
+        assert!(db.update_job_note(9999, "x").is_err());
+
+        db.add_job_tags(go, &["+Remote".to_string(), "golang".to_string(), "remote".to_string()])?;
+        db.add_job_tags(sre, &["onsite".to_string()])?;
+        assert_eq!(db.get_job_tags(go)?, vec!["golang", "remote"]);
+
+        let remote = db.list_jobs_filtered(None, None, Some("remote"))?;
+        assert_eq!(remote.len(), 1);
+        assert_eq!(remote[0].id, go);
+        assert_eq!(db.list_jobs_filtered(None, Some("Acme"), Some("+ONSITE"))?.len(), 1);
+
+        db.remove_job_tags(go, &["remote".to_string()])?;
+        assert_eq!(db.get_job_tags(go)?, vec!["golang"]);
+        assert_eq!(db.get_all_job_tags()?.len(), 2);
+
+        db.purge_job(go)?;
+        assert!(db.list_job_notes(go)?.is_empty());
+        assert!(db.get_job_tags(go)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_normalize_tag() {
+        assert_eq!(normalize_tag("+Remote"), "remote");
+        assert_eq!(normalize_tag("#golang"), "golang");
+        assert_eq!(normalize_tag("  Series B "), "series-b");
+    }
+
     // --- Resume operations ---
 
     #[test]
@@ -2427,32 +7124,93 @@ mod tests {
     fn test_add_and_get_job_keywords() -> Result<()> {
         let db = create_test_db()?;
         let job_id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
-        let keywords = vec![("Kubernetes".to_string(), 3), ("Python".to_string(), 2)];
-        db.add_job_keywords(job_id, &keywords, "tech", "claude-sonnet")?;
-        let retrieved = db.get_job_keywords(job_id, Some("claude-sonnet"))?;
-        assert_eq!(retrieved.len(), 2);
-        assert_eq!(retrieved[0].domain, "tech");
+        let keywords = vec![("Kubernetes".to_string(), 3), ("Python".to_string(), 2)];
+        db.add_job_keywords(job_id, &keywords, "tech", "claude-sonnet")?;
+        let retrieved = db.get_job_keywords(job_id, Some("claude-sonnet"))?;
+        assert_eq!(retrieved.len(), 2);
+        assert_eq!(retrieved[0].domain, "tech");
+        Ok(())
+    }
+
+    #[test]
+    fn test_get_latest_keyword_model() -> Result<()> {
+        let db = create_test_db()?;
+        let job_id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
+        assert!(db.get_latest_keyword_model(job_id)?.is_none());
+        db.add_job_keywords(job_id, &[("k8s".to_string(), 3)], "tech", "gpt-5.2")?;
+        assert_eq!(db.get_latest_keyword_model(job_id)?, Some("gpt-5.2".to_string()));
+        Ok(())
+    }
+
+    #[test]
+    fn test_save_and_get_keyword_profile() -> Result<()> {
+        let db = create_test_db()?;
+        let job_id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
+        assert!(db.get_keyword_profile(job_id)?.is_none());
+        db.save_keyword_profile(job_id, "claude-sonnet", "Senior DevOps role")?;
+        let profile = db.get_keyword_profile(job_id)?.unwrap();
+        assert_eq!(profile.profile, "Senior DevOps role");
+        Ok(())
+    }
+
+    #[test]
+    fn test_save_and_get_job_summary() -> Result<()> {
+        let db = create_test_db()?;
+        let job_id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
+        assert!(db.get_job_summary(job_id)?.is_none());
+        let mut bullets = JobSummaryBullets { stack: "Go".to_string(), red_flags: "None".to_string(), ..Default::default() };
+        db.save_job_summary(job_id, "claude-sonnet", &bullets)?;
+        bullets.stack = "Go, Kubernetes".to_string();
+        db.save_job_summary(job_id, "claude-sonnet", &bullets)?;
+        let summary = db.get_job_summary(job_id)?.unwrap();
+        assert_eq!(summary.source_model, "claude-sonnet");
+        assert_eq!(summary.bullets, bullets, "re-summarizing with a model replaces its summary");
+
+        db.purge_job(job_id)?;
+        assert!(db.get_job_summary(job_id)?.is_none());
         Ok(())
     }
 
     #[test]
-    fn test_get_latest_keyword_model() -> Result<()> {
+    fn test_save_and_get_negotiation_brief() -> Result<()> {
         let db = create_test_db()?;
         let job_id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
-        assert!(db.get_latest_keyword_model(job_id)?.is_none());
-        db.add_job_keywords(job_id, &[("k8s".to_string(), 3)], "tech", "gpt-5.2")?;
-        assert_eq!(db.get_latest_keyword_model(job_id)?, Some("gpt-5.2".to_string()));
+        assert!(db.get_negotiation_brief(job_id)?.is_none());
+        let anchors = NegotiationAnchors { anchor: Some(240_000), target: Some(220_000), walk_away: None };
+        db.save_negotiation_brief(job_id, "m", &anchors, "Draft")?;
+        db.save_negotiation_brief(job_id, "m", &anchors, "Lead with the platform migration")?;
+        let brief = db.get_negotiation_brief(job_id)?.unwrap();
+        assert_eq!(brief.anchors, anchors);
+        assert_eq!(brief.brief, "Lead with the platform migration", "re-running with a model replaces its brief");
+        db.purge_job(job_id)?;
+        assert!(db.get_negotiation_brief(job_id)?.is_none());
         Ok(())
     }
 
     #[test]
-    fn test_save_and_get_keyword_profile() -> Result<()> {
+    fn test_prep_notes() -> Result<()> {
         let db = create_test_db()?;
+        let main = db.create_base_resume("main", "markdown", "Content", None)?;
+        let other = db.create_base_resume("ml", "markdown", "Content", None)?;
         let job_id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
-        assert!(db.get_keyword_profile(job_id)?.is_none());
-        db.save_keyword_profile(job_id, "claude-sonnet", "Senior DevOps role")?;
-        let profile = db.get_keyword_profile(job_id)?.unwrap();
-        assert_eq!(profile.profile, "Senior DevOps role");
+        assert!(db.list_prep_notes(job_id)?.is_empty());
+        assert!(db.get_resume_gaps(job_id, main)?.is_empty());
+
+        let question = |kind: &str, text: &str| PrepQuestion { kind: kind.to_string(), question: text.to_string(), prep: None };
+        db.save_prep_notes(job_id, main, "m", &[question("technical", "Old?")])?;
+        db.save_prep_notes(job_id, main, "m", &[question("behavioral", "Conflict?"), question("technical", "Kubernetes?")])?;
+        let notes = db.list_prep_notes(job_id)?;
+        assert_eq!(notes.iter().map(|n| n.question.question.as_str()).collect::<Vec<_>>(), ["Conflict?", "Kubernetes?"]);
+        assert_eq!(notes[0].resume_name, "main");
+
+        db.conn.execute("UPDATE prep_notes SET created_at = '2020-01-01T00:00:00Z'", [])?;
+        db.save_prep_notes(job_id, other, "m", &[question("technical", "PyTorch?")])?;
+        let notes = db.list_prep_notes(job_id)?;
+        assert_eq!(notes.len(), 1, "only the latest resume's set");
+        assert_eq!(notes[0].resume_name, "ml");
+
+        db.save_fit_analysis(job_id, main, "m", 70.0, &[], &["Go".to_string(), "Kafka".to_string()], &[], "")?;
+        assert_eq!(db.get_resume_gaps(job_id, main)?, ["Go", "Kafka"]);
         Ok(())
     }
 
@@ -2562,6 +7320,194 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_next_actions() -> Result<()> {
+        let db = create_test_db()?;
+        let first = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        let second = db.add_job_full("Platform Engineer", Some("Globex"), None, None, None, None, None)?;
+        db.set_next_action(first, "Email the recruiter", "2026-03-10")?;
+        db.set_next_action(first, "Follow up with recruiter", "2026-03-12")?;
+        db.set_next_action(second, "Send portfolio", "2026-03-20")?;
+
+        let due: Vec<(i64, String)> = db.list_next_actions(Some("2026-03-15"))?.into_iter().map(|a| (a.job_id, a.action)).collect();
+        assert_eq!(due, vec![(first, "Follow up with recruiter".to_string())], "replaced, and later ones wait");
+        assert_eq!(db.list_next_actions(None)?.len(), 2);
+        assert_eq!(db.get_next_action(second)?.unwrap().employer_name.as_deref(), Some("Globex"));
+
+        db.delete_job(second)?;
+        assert_eq!(db.list_next_actions(None)?.len(), 1, "archived jobs drop out");
+        assert!(db.clear_next_action(first)?);
+        assert!(!db.clear_next_action(first)?);
+        assert!(db.get_next_action(first)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_job_flags() -> Result<()> {
+        let db = create_test_db()?;
+        let job = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        db.update_job_description(job, "A fast-paced team. Unlimited PTO. Join the on-call rotation.", None, None)?;
+        let found = db.detect_job_flags(job)?;
+        assert_eq!(found.len(), 3);
+        db.set_job_flags(job, "claude-x", &[
+            Flag { name: "long-hours".to_string(), evidence: Some("Be there when it matters".to_string()) },
+            Flag { name: "on-call".to_string(), evidence: None },
+        ])?;
+
+        let stored: Vec<(String, String)> = db.get_job_flags(job)?.into_iter().map(|f| (f.flag, f.source)).collect();
+        assert_eq!(stored, vec![
+            ("fast-paced".to_string(), "rules".to_string()),
+            ("unlimited-pto".to_string(), "rules".to_string()),
+            ("on-call".to_string(), "rules".to_string()),
+            ("on-call".to_string(), "claude-x".to_string()),
+            ("long-hours".to_string(), "claude-x".to_string()),
+        ]);
+        assert_eq!(db.get_all_job_flags()?[&job], vec!["fast-paced", "unlimited-pto", "on-call", "long-hours"]);
+
+        // Re-running a source replaces only its own flags
+        db.update_job_description(job, "A calm team.", None, None)?;
+        assert!(db.detect_job_flags(job)?.is_empty());
+        assert_eq!(db.get_all_job_flags()?[&job], vec!["on-call", "long-hours"]);
+
+        db.purge_job(job)?;
+        assert!(db.get_job_flags(job)?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_pipeline_runs() -> Result<()> {
+        let db = create_test_db()?;
+        assert!(db.interrupted_pipeline_run()?.is_none());
+
+        let run = db.start_pipeline_run()?;
+        db.record_pipeline_step(run, "email", None, true)?;
+        db.record_pipeline_step(run, "fetch", Some(1), true)?;
+        db.record_pipeline_step(run, "fetch", Some(2), false)?;
+        assert!(db.pipeline_stage_done(run, "email")?);
+        assert!(!db.pipeline_stage_done(run, "fetch")?, "jobs recorded, stage not finished");
+        assert_eq!(db.pipeline_stage_jobs(run, "fetch")?, HashSet::from([1, 2]));
+        assert!(db.pipeline_stage_jobs(run, "keywords")?.is_empty());
+        assert_eq!(db.interrupted_pipeline_run()?.map(|r| r.id), Some(run));
+
+        db.finish_pipeline_run(run)?;
+        assert!(db.interrupted_pipeline_run()?.is_none());
+        let next = db.start_pipeline_run()?;
+        assert!(db.pipeline_stage_jobs(next, "fetch")?.is_empty(), "steps belong to their run");
+        Ok(())
+    }
+
+    #[test]
+    fn test_fetch_domains() -> Result<()> {
+        let db = create_test_db()?;
+        assert!(db.get_fetch_domain("linkedin.com")?.is_none());
+        db.record_fetch_success("greenhouse.io")?;
+        assert_eq!(db.record_fetch_failure("linkedin.com", "HTTP 429")?, 1);
+        assert_eq!(db.record_fetch_failure("linkedin.com", "timed out")?, 2);
+        db.set_fetch_backoff("linkedin.com", Some("2026-03-01T10:00:00Z"))?;
+
+        let linkedin = db.get_fetch_domain("linkedin.com")?.unwrap();
+        assert_eq!((linkedin.failures, linkedin.failure_streak), (2, 2));
+        assert_eq!(linkedin.last_error.as_deref(), Some("timed out"));
+        assert_eq!(db.list_fetch_domains()?.first().map(|d| d.domain.as_str()), Some("linkedin.com"), "failing first");
+
+        db.record_fetch_success("linkedin.com")?;
+        let linkedin = db.get_fetch_domain("linkedin.com")?.unwrap();
+        assert_eq!((linkedin.successes, linkedin.failures, linkedin.failure_streak), (1, 2, 0));
+        assert!(linkedin.backoff_until.is_none(), "a success ends the backoff");
+        Ok(())
+    }
+
+    #[test]
+    fn test_status_entered_at() -> Result<()> {
+        let db = create_test_db()?;
+        let job_id = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        assert!(db.status_entered_at()?.is_empty());
+        db.update_job_status(job_id, "applied")?;
+        db.conn.execute("UPDATE status_history SET changed_at = '2026-03-01T10:00:00Z'", [])?;
+        db.update_job_status(job_id, "interviewing")?;
+        db.conn.execute("UPDATE status_history SET changed_at = '2026-03-05T10:00:00Z' WHERE to_status = 'interviewing'", [])?;
+        assert_eq!(db.status_entered_at()?.get(&job_id).map(String::as_str), Some("2026-03-05T10:00:00Z"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_contacts_linked_to_jobs() -> Result<()> {
+        let db = create_test_db()?;
+        let job_id = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        let other_job = db.add_job_full("Platform Engineer", Some("Globex"), None, None, None, None, None)?;
+        let acme = db.get_employer_by_name("Acme")?.unwrap().id;
+
+        let recruiter = db.add_contact("Rita Recruiter", Some("recruiter"), Some(acme), Some("rita@acme.com"), None, Some("2026-03-01"))?;
+        let referral = db.add_contact("Ravi Referral", Some("referral"), None, None, Some("https://linkedin.com/in/ravi"), None)?;
+        db.add_contact("Gina Globex", None, db.get_employer_by_name("Globex")?.map(|e| e.id), None, None, None)?;
+
+        assert!(db.link_contact_to_job(referral, job_id)?);
+        assert!(!db.link_contact_to_job(referral, job_id)?, "already linked");
+        assert_eq!(db.get_contact(referral)?.unwrap().employer_name.as_deref(), Some("Acme"), "takes the job's employer");
+
+        let job = db.get_job(job_id)?.unwrap();
+        let names: Vec<String> = db.list_job_contacts(&job)?.into_iter().map(|c| c.name).collect();
+        assert_eq!(names, vec!["Ravi Referral", "Rita Recruiter"], "linked first, then the employer's");
+        assert_eq!(db.list_contacts(Some(acme))?.len(), 2);
+        assert!(db.list_job_contacts(&db.get_job(other_job)?.unwrap())?.iter().all(|c| c.name == "Gina Globex"));
+
+        db.touch_contact(recruiter, "2026-03-05")?;
+        assert_eq!(db.get_contact(recruiter)?.unwrap().last_touch.as_deref(), Some("2026-03-05"));
+        assert!(db.touch_contact(999, "2026-03-05").is_err());
+
+        db.purge_job(job_id)?;
+        let links: i64 = db.conn.query_row("SELECT COUNT(*) FROM job_contacts", [], |r| r.get(0))?;
+        assert_eq!(links, 0, "purging a job drops its links, not the contacts");
+        assert!(db.get_contact(referral)?.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_employer_comp() -> Result<()> {
+        let db = create_test_db()?;
+        let emp_id = db.get_or_create_employer("PayCo")?;
+        let band = |level: &str, base_min: i64| CompBand {
+            level: level.to_string(),
+            role: "SRE".to_string(),
+            base_min: Some(base_min),
+            base_max: Some(base_min + 40_000),
+            total_min: None,
+            total_max: None,
+        };
+        db.replace_employer_comp(emp_id, &[band("staff", 220_000), band("senior", 180_000)], "gpt-5.2")?;
+        db.replace_employer_comp(emp_id, &[band("staff", 230_000), band("senior", 190_000)], "claude-sonnet")?;
+
+        let bands = db.list_employer_comp(emp_id)?;
+        assert_eq!(bands.iter().map(|b| (b.level.as_str(), b.base_min)).collect::<Vec<_>>(),
+                   vec![("senior", Some(190_000)), ("staff", Some(230_000))], "replaced, lowest base first");
+        assert_eq!(db.employer_comp_source(emp_id)?.map(|(_, model)| model).as_deref(), Some("claude-sonnet"));
+        assert!(db.employer_comp_source(db.get_or_create_employer("OtherCo")?)?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_glassdoor_snapshots_per_fetch() -> Result<()> {
+        let db = create_test_db()?;
+        let emp_id = db.get_or_create_employer("TrendCo")?;
+        db.add_glassdoor_review(emp_id, 4.0, None, None, None, None, "positive", None)?;
+        db.update_employer_glassdoor_summary(emp_id)?;
+        db.delete_glassdoor_reviews(emp_id)?;
+        db.add_glassdoor_review(emp_id, 2.0, None, None, None, None, "negative", None)?;
+        db.add_glassdoor_review(emp_id, 3.0, None, None, None, None, "neutral", None)?;
+        db.update_employer_glassdoor_summary(emp_id)?;
+
+        let snapshots = db.list_glassdoor_snapshots(emp_id)?;
+        assert_eq!(snapshots.len(), 2, "re-fetches keep the earlier summary");
+        assert_eq!((snapshots[0].rating, snapshots[0].review_count, snapshots[0].positive), (4.0, 1, 1));
+        assert_eq!((snapshots[1].rating, snapshots[1].review_count), (2.5, 2));
+        assert_eq!((snapshots[1].positive, snapshots[1].neutral, snapshots[1].negative), (0, 1, 1));
+
+        let empty = db.get_or_create_employer("NoReviewsCo")?;
+        db.update_employer_glassdoor_summary(empty)?;
+        assert!(db.list_glassdoor_snapshots(empty)?.is_empty());
+        Ok(())
+    }
+
     // --- Destruction ---
 
     #[test]
@@ -2592,7 +7538,7 @@ mod tests {
         let db = create_test_db()?;
         db.add_job_full("Low Pay", Some("Co"), None, None, None, Some(80000), None)?;
         db.add_job_full("High Pay", Some("Co"), None, None, None, Some(200000), None)?;
-        let ranked = db.rank_jobs(10)?;
+        let ranked = db.rank_jobs(10, &SavedFilter::default())?;
         assert_eq!(ranked.len(), 2);
         assert!(ranked[0].1 >= ranked[1].1, "Higher pay should rank higher");
         Ok(())
@@ -2603,7 +7549,7 @@ mod tests {
         let db = create_test_db()?;
         let id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
         let job = db.get_job(id)?.unwrap();
-        let score = calculate_score(&job, &db);
+        let score = calculate_score(&job, &db, &db.list_job_statuses()?);
         assert!(score >= 50.0, "Base score should be at least 50");
         Ok(())
     }
@@ -2614,7 +7560,7 @@ mod tests {
         let id = db.add_job_full("Job", Some("BadCo"), None, None, None, None, None)?;
         db.set_employer_status("BadCo", "yuck")?;
         let job = db.get_job(id)?.unwrap();
-        let score = calculate_score(&job, &db);
+        let score = calculate_score(&job, &db, &db.list_job_statuses()?);
         assert!(score < 50.0, "Yuck employer should reduce score below base");
         Ok(())
     }
@@ -2734,6 +7680,20 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_update_employer_engineering_culture() -> Result<()> {
+        let db = create_test_db()?;
+        let emp_id = db.get_or_create_employer("BlogCo")?;
+        db.update_employer_engineering_culture(emp_id, Some("https://eng.blogco.com"), None, Some("Talk A | KubeCon | 2024\nTalk B"), Some("Writes a lot"))?;
+        let emp = db.get_employer_by_name("BlogCo")?.unwrap();
+        assert_eq!(emp.eng_blog_url, Some("https://eng.blogco.com".to_string()));
+        assert!(emp.eng_rss_url.is_none());
+        assert_eq!(emp.eng_talks.unwrap().lines().count(), 2);
+        assert_eq!(emp.eng_culture_summary, Some("Writes a lot".to_string()));
+        assert!(emp.eng_research_updated.is_some());
+        Ok(())
+    }
+
     #[test]
     fn test_job_exists_by_url() -> Result<()> {
         let db = create_test_db()?;
@@ -2874,6 +7834,8 @@ mod tests {
         db.create_resume_variant(resume_id, job_id, "tailored content", Some("notes"), Some("claude-sonnet"), Some("markdown"))?;
         let variant = db.get_resume_variant(job_id, resume_id)?.unwrap();
         assert_eq!(variant.content, "tailored content");
+        assert_eq!(db.get_resume_variant_by_id(variant.id)?.map(|v| v.content), Some("tailored content".to_string()));
+        assert!(db.get_resume_variant_by_id(variant.id + 1)?.is_none());
         Ok(())
     }
 
@@ -2944,12 +7906,509 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_rank_weights_scale_factors() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job_full("Engineer", Some("Acme"), None, None, None, Some(200000), None)?;
+        let job = db.get_job(id)?.unwrap();
+        // Base 50 + new 5 + pay 20
+        assert!((calculate_score(&job, &db, &db.list_job_statuses()?) - 75.0).abs() < 0.1);
+
+        db.set_rank_weight("pay", 2.0)?;
+        db.set_rank_weight("status", 0.0)?;
+        let breakdown = score_breakdown(&job, &db, &db.list_job_statuses()?);
+        let pay = breakdown.factors.iter().find(|f| f.name == "pay").unwrap();
+        assert_eq!(pay.points, 20.0);
+        assert_eq!(pay.contribution(), 40.0);
+        assert!((breakdown.total() - 90.0).abs() < 0.1);
+
+        assert!(db.set_rank_weight("vibes", 1.0).is_err());
+        db.reset_rank_weights()?;
+        assert!((calculate_score(&job, &db, &db.list_job_statuses()?) - 75.0).abs() < 0.1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_keyword_and_rating_factors() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        db.add_job_keywords(id, &[("Kubernetes".to_string(), 3), ("Go".to_string(), 1)], "tech", "model")?;
+        db.create_base_resume("main", "markdown", "Ran kubernetes clusters", None)?;
+        db.conn.execute("UPDATE employers SET glassdoor_rating = 4.0 WHERE name = 'Acme'", [])?;
+
+        let job = db.get_job(id)?.unwrap();
+        let breakdown = score_breakdown(&job, &db, &db.list_job_statuses()?);
+        let factor = |name: &str| breakdown.factors.iter().find(|f| f.name == name).unwrap().clone();
+        assert!((factor("keywords").points - 15.0).abs() < 0.01, "3 of 4 keyword weight matched");
+        assert!((factor("employer_rating").points - 5.0).abs() < 0.01);
+        assert!(factor("recency").points > 9.0);
+        assert_eq!(factor("recency").contribution(), 0.0, "recency is off by default");
+        Ok(())
+    }
+
+    #[test]
+    fn test_stale_and_source_factors() -> Result<()> {
+        let db = create_test_db()?;
+        let board = db.add_job_full("SRE", Some("Acme"), Some("https://boards.greenhouse.io/acme/jobs/1"), Some("linkedin"), None, None, None)?;
+        let alert = db.add_job_full("SRE", Some("Beta"), None, Some("email"), None, None, None)?;
+        let pasted = db.add_job_full("SRE", Some("Gamma"), None, None, None, None, None)?;
+        db.conn.execute("UPDATE jobs SET created_at = datetime('now', '-28 days') WHERE id = ?1", [alert])?;
+
+        let factor = |id: i64, name: &str| -> Result<f64> {
+            let job = db.get_job(id)?.unwrap();
+            Ok(score_breakdown(&job, &db, &db.list_job_statuses()?).factors.iter().find(|f| f.name == name).unwrap().contribution())
+        };
+        assert_eq!(factor(board, "source")?, 10.0, "the URL outranks the alert it came from");
+        assert_eq!(factor(alert, "source")?, -5.0);
+        assert_eq!(factor(pasted, "source")?, 0.0);
+        assert_eq!(factor(board, "stale")?, 0.0);
+        assert!((factor(alert, "stale")? + 10.0).abs() < 0.1, "two weeks past the grace period");
+
+        let board = db.get_job(board)?.unwrap();
+        let alert = db.get_job(alert)?.unwrap();
+        assert!(calculate_score(&board, &db, &db.list_job_statuses()?) > calculate_score(&alert, &db, &db.list_job_statuses()?));
+        Ok(())
+    }
+
+    #[test]
+    fn test_posting_dates_and_deadline_factor() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job_full("SRE", Some("Acme"), None, None, None, None, Some("SRE\nPosted today"))?;
+        let today = dates::today();
+        assert_eq!(db.get_job(id)?.unwrap().posted_at, Some(dates::format_day(today)));
+
+        let deadline = today + chrono::Duration::days(5);
+        let text = format!("SRE\nApplication deadline: {}", dates::format_day(deadline));
+        db.update_job_description(id, &text, None, None)?;
+        let job = db.get_job(id)?.unwrap();
+        assert_eq!(job.apply_deadline, Some(dates::format_day(deadline)));
+        assert_eq!(job.posted_at, Some(dates::format_day(today)), "a refetch without a date keeps it");
+
+        let factor = |id: i64| -> Result<f64> {
+            let job = db.get_job(id)?.unwrap();
+            Ok(score_breakdown(&job, &db, &db.list_job_statuses()?).factors.iter().find(|f| f.name == "deadline").unwrap().contribution())
+        };
+        assert_eq!(factor(id)?, 10.0);
+        db.set_job_deadline(id, Some(&dates::format_day(today - chrono::Duration::days(1))))?;
+        assert_eq!(factor(id)?, -30.0, "missed");
+        db.upsert_job_status("screening", Some(25), false, "blue")?;
+        db.update_job_status(id, "screening")?;
+        assert_eq!(factor(id)?, -30.0, "custom statuses ahead of applied count too");
+        db.update_job_status(id, "applied")?;
+        assert_eq!(factor(id)?, 0.0, "only urgent until applied");
+        db.set_job_deadline(id, None)?;
+        assert!(db.get_job(id)?.unwrap().apply_deadline.is_none());
+
+        // Merging fills dates the kept job lacks
+        let keep = db.add_job_full("Platform", Some("Acme"), None, None, None, None, None)?;
+        let dup = db.add_job_full("Platform", Some("Acme"), None, None, None, None, None)?;
+        db.set_job_dates(dup, Some("2025-07-01"), Some("2025-08-01"))?;
+        db.merge_jobs(keep, dup)?;
+        let kept = db.get_job(keep)?.unwrap();
+        assert_eq!((kept.posted_at.as_deref(), kept.apply_deadline.as_deref()), (Some("2025-07-01"), Some("2025-08-01")));
+        assert!(job_age_days(&kept).unwrap() > 30.0, "aged from the posting date");
+        Ok(())
+    }
+
+    #[test]
+    fn test_keyword_gaps_skip_resume_terms_and_closed_jobs() -> Result<()> {
+        let db = create_test_db()?;
+        db.create_base_resume("main", "markdown", "Kubernetes and Terraform on AWS", None)?;
+        let a = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        db.add_job_keywords(a, &[("Kubernetes".to_string(), 3), ("Rust".to_string(), 3)], "tech", "m")?;
+        db.add_job_keywords(a, &[("mentoring".to_string(), 3)], "soft_skill", "m")?;
+        let b = db.add_job_full("Platform", Some("Beta"), None, None, None, None, None)?;
+        db.add_job_keywords(b, &[("rust".to_string(), 2), ("Kafka".to_string(), 1)], "tech", "m")?;
+        let closed = db.add_job_full("Old", Some("Gone"), None, None, None, None, None)?;
+        db.add_job_keywords(closed, &[("Haskell".to_string(), 3)], "tech", "m")?;
+        db.update_job_status(closed, "closed")?;
+
+        let gaps = db.get_keyword_gaps(10)?;
+        let names: Vec<&str> = gaps.iter().map(|g| g.keyword.as_str()).collect();
+        assert_eq!(names, vec!["Rust", "Kafka"]);
+        assert_eq!(gaps[0].total_weight, 5);
+        assert_eq!(gaps[0].jobs, 2);
+        Ok(())
+    }
+
+    #[test]
+    fn test_keyword_demand_and_required_gaps() -> Result<()> {
+        let db = create_test_db()?;
+        let a = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        db.add_job_keywords(a, &[("Kubernetes".to_string(), 3), ("Rust".to_string(), 3)], "tech", "m")?;
+        db.add_job_keywords(a, &[("AWS".to_string(), 2)], "cloud", "m")?;
+        db.add_job_keywords(a, &[("Terraform".to_string(), 3)], "tech", "old")?;
+        db.conn.execute("UPDATE job_keywords SET created_at = '2020-01-01T00:00:00Z' WHERE source_model = 'old'", [])?;
+        let b = db.add_job_full("Platform", Some("Beta"), None, None, None, None, None)?;
+        db.add_job_keywords(b, &[("kubernetes".to_string(), 2), ("Go".to_string(), 1)], "tech", "m")?;
+        db.add_job_keywords(b, &[("mentoring".to_string(), 3)], "soft_skill", "m")?;
+        let closed = db.add_job_full("Old", Some("Gone"), None, None, None, None, None)?;
+        db.add_job_keywords(closed, &[("Haskell".to_string(), 3)], "tech", "m")?;
+        db.update_job_status(closed, "closed")?;
+        db.add_job_full("No keywords", Some("Gamma"), None, None, None, None, None)?;
+
+        let (demand, jobs) = db.get_keyword_demand()?;
+        assert_eq!(jobs, 2);
+        let summary: Vec<(&str, i64, i64, i64)> = demand.iter().map(|d| (d.keyword.as_str(), d.total_weight, d.jobs, d.required)).collect();
+        assert_eq!(summary, vec![
+            ("Kubernetes", 5, 2, 1),
+            ("Rust", 3, 1, 1),
+            ("mentoring", 3, 1, 1),
+            ("AWS", 2, 1, 0),
+            ("Go", 1, 1, 0),
+        ], "latest extraction per job, closed jobs left out");
+
+        let gaps: Vec<&str> = required_skill_gaps(&demand, &["kubernetes".to_string()]).iter().map(|d| d.keyword.as_str()).collect();
+        assert_eq!(gaps, vec!["Rust"], "soft skills and optional keywords aren't gaps");
+        Ok(())
+    }
+
+    #[test]
+    fn test_skill_covers() {
+        assert!(skill_covers("aws", "AWS Lambda"));
+        assert!(skill_covers("ci/cd", "CI/CD pipelines"));
+        assert!(skill_covers("Node.js", "node.js"));
+        assert!(!skill_covers("go", "Google Cloud"));
+        assert!(!skill_covers("rust", "trust and safety"));
+        assert!(!skill_covers("", "anything"));
+    }
+
+    #[test]
+    fn test_skill_profile_drives_match_and_gaps() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        db.add_job_keywords(id, &[("Kubernetes".to_string(), 3), ("Go".to_string(), 1)], "tech", "m")?;
+        db.create_base_resume("main", "markdown", "Go services", None)?;
+        assert!(db.get_skill_match(id)?.is_none(), "no profile yet");
+
+        let stored = db.set_user_skills(&["Kubernetes".to_string(), " kubernetes ".to_string(), "rust".to_string()], "manual")?;
+        assert_eq!(stored, 2);
+        assert_eq!(db.get_user_skills()?, vec!["kubernetes", "rust"]);
+        assert!((db.get_skill_match(id)?.unwrap() - 0.75).abs() < 0.01);
+
+        // The profile replaces resumes for the ranking factor...
+        let job = db.get_job(id)?.unwrap();
+        let breakdown = score_breakdown(&job, &db, &db.list_job_statuses()?);
+        let keywords = breakdown.factors.iter().find(|f| f.name == "keywords").unwrap();
+        assert!((keywords.points - 15.0).abs() < 0.01);
+        // ...while gaps count either source as known
+        assert!(db.get_keyword_gaps(10)?.is_empty());
+
+        db.set_user_skills(&[], "manual")?;
+        assert!(db.get_user_skills()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_negative_keywords_filter_and_penalize() -> Result<()> {
+        let db = create_test_db()?;
+        let php = db.add_job_full("PHP Developer", Some("Acme"), None, None, None, None, None)?;
+        let sre = db.add_job_full("SRE", Some("Beta"), None, None, None, None, None)?;
+        db.add_job_keywords(sre, &[("On-call rotation".to_string(), 3), ("Kubernetes".to_string(), 3)], "tech", "m")?;
+        let nice = db.add_job_full("Platform", Some("Gamma"), None, None, None, None, None)?;
+        db.add_job_keywords(nice, &[("on-call".to_string(), 1)], "discipline", "m")?;
+
+        assert_eq!(db.add_negative_keywords(&["On-Call".to_string(), "php".to_string(), "php".to_string()])?, 2);
+        assert_eq!(db.add_negative_keywords(&["php".to_string()])?, 0);
+        let negatives = db.get_negative_keywords()?;
+        assert_eq!(negatives, vec!["on-call", "php"]);
+
+        let job = |id| db.get_job(id).unwrap().unwrap();
+        assert_eq!(db.find_excluded_keyword(&job(php), &negatives)?.as_deref(), Some("php"), "title match");
+        assert_eq!(db.find_excluded_keyword(&job(sre), &negatives)?.as_deref(), Some("on-call"));
+        assert!(db.find_excluded_keyword(&job(sre), &["rust".to_string()])?.is_none());
+
+        let statuses = db.list_job_statuses()?;
+        let penalty = |id| score_breakdown(&job(id), &db, &statuses).factors.iter()
+            .find(|f| f.name == "negative_keywords").unwrap().points;
+        assert_eq!(penalty(sre), -15.0);
+        assert_eq!(penalty(nice), 0.0, "only required keywords are penalized");
+
+        assert_eq!(db.remove_negative_keywords(&["ON-CALL".to_string()])?, 1);
+        assert_eq!(penalty(sre), 0.0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_ingested_emails() -> Result<()> {
+        let db = create_test_db()?;
+        assert!(!db.is_email_ingested("<abc@linkedin.com>")?);
+        db.mark_email_ingested("<abc@linkedin.com>", "3 new jobs", 3)?;
+        assert!(db.is_email_ingested(" <abc@linkedin.com>\n")?, "header whitespace is ignored");
+        db.mark_email_ingested("<abc@linkedin.com>", "3 new jobs", 0)?;
+        let jobs_added: i64 = db.conn.query_row("SELECT jobs_added FROM ingested_emails", [], |r| r.get(0))?;
+        assert_eq!(jobs_added, 0, "reprocessing updates the record");
+        Ok(())
+    }
+
+    #[test]
+    fn test_work_authorization_filters() -> Result<()> {
+        let db = create_test_db()?;
+        let cleared = db.add_job_full("Cloud Engineer", Some("Defense Co"), None, None, None, None, None)?;
+        let citizens = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        let sponsor = db.add_job_full("Platform Engineer", Some("Globex"), None, None, None, None, None)?;
+        let silent = db.add_job_full("DevOps Engineer", Some("Initech"), None, None, None, None, None)?;
+        db.update_job_description(cleared, "Must hold an active Secret clearance.", None, None)?;
+        db.update_job_description(citizens, "We are unable to sponsor visas. US citizenship required.", None, None)?;
+        db.update_job_description(sponsor, "Visa sponsorship is available.", None, None)?;
+        db.update_job_description(silent, "Kubernetes, Terraform.", None, None)?;
+        let job = |id| db.get_job(id).map(Option::unwrap);
+        assert_eq!((job(cleared)?.requires_clearance, job(cleared)?.sponsors_visa), (Some(true), None));
+        assert_eq!((job(citizens)?.requires_clearance, job(citizens)?.sponsors_visa), (Some(false), Some(false)));
+        assert_eq!(job(sponsor)?.sponsors_visa, Some(true));
+
+        let filter = SavedFilter { name: "can-take".to_string(), sponsors_visa: true, no_clearance: true, ..Default::default() };
+        db.save_filter(&filter)?;
+        let saved = db.get_saved_filter("can-take")?.unwrap();
+        assert!(saved.sponsors_visa && saved.no_clearance);
+        let ids: Vec<i64> = db.filter_jobs(&saved, false)?.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![sponsor, silent], "jobs that don't say stay in");
+        let ranked: Vec<i64> = db.rank_jobs(10, &SavedFilter { no_clearance: true, ..Default::default() })?.iter().map(|(j, _)| j.id).collect();
+        assert!(!ranked.contains(&cleared) && ranked.contains(&citizens));
+
+        // Keyword extraction (through detect_job_flags) fills jobs stored before the columns
+        db.conn.execute("UPDATE jobs SET requires_clearance = NULL, sponsors_visa = NULL", [])?;
+        db.detect_job_flags(citizens)?;
+        assert_eq!(job(citizens)?.sponsors_visa, Some(false));
+        Ok(())
+    }
+
+    #[test]
+    fn test_saved_filters() -> Result<()> {
+        let db = create_test_db()?;
+        let sre = db.add_job_full("Senior SRE", Some("Acme"), None, None, None, Some(200000), None)?;
+        let devops = db.add_job_full("DevOps Engineer", Some("Globex"), None, None, None, Some(150000), None)?;
+        db.add_job_full("Platform Engineer", Some("Initech"), None, None, None, None, None)?;
+        db.add_job_keywords(devops, &[("Kubernetes".to_string(), 3)], "tech", "m")?;
+        db.add_job_tags(sre, &["remote".to_string()])?;
+        db.add_job_tags(devops, &["remote".to_string()])?;
+
+        let filter = SavedFilter {
+            name: "remote-k8s".to_string(),
+            tag: Some("#Remote".to_string()),
+            keyword: Some("kubernetes".to_string()),
+            ..Default::default()
+        };
+        db.save_filter(&filter)?;
+        let saved = db.get_saved_filter("remote-k8s")?.unwrap();
+        assert_eq!(saved.tag.as_deref(), Some("remote"), "tags are stored normalized");
+        let ids: Vec<i64> = db.filter_jobs(&saved, false)?.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![devops]);
+
+        let paid = SavedFilter { name: "paid".to_string(), min_pay: Some(180000), ..Default::default() };
+        let ids: Vec<i64> = db.filter_jobs(&paid, false)?.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![sre], "jobs without advertised pay don't pass a pay floor");
+        let band = SavedFilter { name: "band".to_string(), min_pay: Some(100000), max_pay: Some(160000), ..Default::default() };
+        let ids: Vec<i64> = db.filter_jobs(&band, false)?.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![devops]);
+        let ranked: Vec<i64> = db.rank_jobs(10, &SavedFilter { max_pay: Some(160000), ..Default::default() })?.iter().map(|(j, _)| j.id).collect();
+        assert_eq!(ranked, vec![devops]);
+
+        db.set_job_easy_apply(sre, Some(true))?;
+        db.set_job_easy_apply(devops, Some(false))?;
+        db.set_job_easy_apply(devops, None)?; // a fetch that couldn't tell
+        let easy = SavedFilter { name: "easy".to_string(), easy_apply: Some(true), ..Default::default() };
+        db.save_filter(&easy)?;
+        assert_eq!(db.get_saved_filter("easy")?.unwrap().easy_apply, Some(true));
+        let ids: Vec<i64> = db.filter_jobs(&easy, false)?.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![sre]);
+        let external = SavedFilter { easy_apply: Some(false), ..easy };
+        let ids: Vec<i64> = db.filter_jobs(&external, false)?.iter().map(|j| j.id).collect();
+        assert_eq!(ids, vec![devops], "jobs never read from LinkedIn match neither");
+        assert!(db.remove_saved_filter("easy")?);
+
+        db.save_filter(&SavedFilter { status: Some("new".to_string()), ..saved })?;
+        assert_eq!(db.list_saved_filters()?.len(), 1, "saving again replaces");
+        assert!(db.save_filter(&SavedFilter { name: " ".to_string(), ..Default::default() }).is_err());
+        assert!(db.remove_saved_filter("remote-k8s")?);
+        assert!(!db.remove_saved_filter("remote-k8s")?);
+        assert!(db.get_saved_filter("remote-k8s")?.is_none());
+        Ok(())
+    }
+
+    #[test]
+    fn test_rank_rules_boost_and_penalize() -> Result<()> {
+        let db = create_test_db()?;
+        let rust = db.add_job_full("Rust Engineer", Some("Acme"), None, None, None, None, None)?;
+        let sre = db.add_job_full("SRE", Some("Beta"), None, None, None, None, None)?;
+        db.add_job_keywords(sre, &[("On-call rotation".to_string(), 1), ("Rust".to_string(), 2)], "tech", "m")?;
+        let other = db.add_job_full("Platform", Some("Gamma"), None, None, None, None, None)?;
+
+        db.set_rank_rule("Rust", 10.0)?;
+        db.set_rank_rule(" on-call ", -15.0)?;
+        db.set_rank_rule("rust", 12.0)?;
+        let rules: Vec<(String, f64)> = db.list_rank_rules()?.into_iter().map(|r| (r.keyword, r.points)).collect();
+        assert_eq!(rules, vec![("rust".to_string(), 12.0), ("on-call".to_string(), -15.0)], "replaced, not added");
+
+        let job = |id| db.get_job(id).unwrap().unwrap();
+        let statuses = db.list_job_statuses()?;
+        let points = |id| score_breakdown(&job(id), &db, &statuses).factors.iter()
+            .find(|f| f.name == "rules").unwrap().points;
+        assert_eq!(points(rust), 12.0, "title match");
+        assert_eq!(points(sre), -3.0, "keyword matches at any weight");
+        assert_eq!(points(other), 0.0);
+        assert!(calculate_score(&job(rust), &db, &db.list_job_statuses()?) > calculate_score(&job(other), &db, &db.list_job_statuses()?));
+
+        assert!(db.remove_rank_rule("On-Call")?);
+        assert!(!db.remove_rank_rule("on-call")?);
+        assert_eq!(points(sre), 12.0);
+        assert!(db.set_rank_rule("  ", 5.0).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ai_cache_get_put_stats_clear() -> Result<()> {
+        let cache = AiCache::open(Path::new(":memory:"))?;
+        assert!(cache.get("k1")?.is_none());
+        cache.put("k1", "gpt-5.2", "first")?;
+        cache.put("k2", "claude-sonnet", "second!")?;
+        assert_eq!(cache.get("k1")?.as_deref(), Some("first"));
+        assert_eq!(cache.get("k1")?.as_deref(), Some("first"));
+
+        let stats = cache.stats()?;
+        assert_eq!(stats.entries, 2);
+        assert_eq!(stats.hits, 2);
+        assert_eq!(stats.bytes, 12);
+        assert_eq!(stats.by_model.len(), 2);
+
+        assert_eq!(cache.clear(Some("gpt-5.2"))?, 1);
+        assert!(cache.get("k1")?.is_none());
+        assert_eq!(cache.clear(None)?, 1);
+        assert_eq!(cache.stats()?.entries, 0);
+        Ok(())
+    }
+
+    #[test]
+    fn test_learning_plan_roundtrip() -> Result<()> {
+        let db = create_test_db()?;
+        assert!(db.get_latest_learning_plan()?.is_none());
+        let steps = vec![
+            LearningStep {
+                week: "1".to_string(),
+                keyword: "Rust".to_string(),
+                task: "Read the book".to_string(),
+                resource: Some("https://doc.rust-lang.org/book/".to_string()),
+                milestone: None,
+            },
+            LearningStep {
+                week: "2-3".to_string(),
+                keyword: "Kafka".to_string(),
+                task: "Build a consumer".to_string(),
+                resource: None,
+                milestone: Some("Working demo".to_string()),
+            },
+        ];
+        let plan_id = db.create_learning_plan(5, "model", &steps)?;
+        let (latest, items) = db.get_latest_learning_plan()?.unwrap();
+        assert_eq!(latest, plan_id);
+        assert_eq!(items.len(), 2);
+        assert_eq!(items[1].position, 2);
+        assert!(items[0].done_at.is_none());
+
+        db.set_learning_item_done(items[0].id, true)?;
+        let (_, items) = db.get_latest_learning_plan()?.unwrap();
+        assert!(items[0].done_at.is_some());
+        db.set_learning_item_done(items[0].id, false)?;
+        let (_, items) = db.get_latest_learning_plan()?.unwrap();
+        assert!(items[0].done_at.is_none());
+        assert!(db.set_learning_item_done(999, true).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_ai_usage_totals() -> Result<()> {
+        // AiUsageLog opens its own connection, so this needs a real file
+        let path = std::env::temp_dir().join(format!("hunt_test_usage_{}.db", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let db = Database { conn: Connection::open(&path)?, path: path.clone() };
+        db.init()?;
+
+        let log = db.ai_usage_log()?;
+        log.record("fit", "api-sonnet", Some((1000, 200)), Some(0.006))?;
+        log.record("fit", "api-sonnet", Some((3000, 400)), Some(0.015))?;
+        log.record("keywords", "claude-sonnet", None, None)?;
+        db.conn.execute(
+            "INSERT INTO ai_usage (command, model, input_tokens, output_tokens, cost_usd, created_at)
+             VALUES ('journal', 'gpt-5.2', 10, 10, 1.0, '2020-01-01T00:00:00Z')",
+            [],
+        )?;
+
+        let by_model = db.get_ai_usage("2025-01-01T00:00:00Z", false)?;
+        assert_eq!(by_model.len(), 2, "old rows are outside the window");
+        assert_eq!(by_model[0].key, "api-sonnet");
+        assert_eq!(by_model[0].calls, 2);
+        assert_eq!(by_model[0].input_tokens, 4000);
+        assert!((by_model[0].cost_usd - 0.021).abs() < 1e-9);
+        assert_eq!(by_model[1].unpriced_calls, 1);
+
+        let by_command = db.get_ai_usage("2000-01-01T00:00:00Z", true)?;
+        assert_eq!(by_command.iter().map(|t| t.key.as_str()).collect::<Vec<_>>(), vec!["journal", "fit", "keywords"]);
+
+        drop(log);
+        drop(db);
+        std::fs::remove_file(&path)?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_review_cards_schedule() -> Result<()> {
+        let db = create_test_db()?;
+        assert_eq!(db.add_review_cards(&["Kafka".to_string(), "kafka".to_string(), "eBPF".to_string()])?, 2);
+        assert_eq!(db.add_review_cards(&["kafka".to_string()])?, 0);
+
+        let now = "2100-01-01T00:00:00Z";
+        let due = db.get_due_review_cards(now, 10)?;
+        assert_eq!(due.iter().map(|c| c.keyword.as_str()).collect::<Vec<_>>(), vec!["ebpf", "kafka"]);
+        assert_eq!(due[0].ease, 2.5);
+
+        let state = CardState { interval_days: 3.0, ease: 2.3, reps: 0 };
+        db.record_review("kafka", &state, "2100-01-04T00:00:00Z", true)?;
+        let due = db.get_due_review_cards(now, 10)?;
+        assert_eq!(due.len(), 1, "kafka is scheduled later");
+        let kafka = db.list_review_cards()?.into_iter().find(|c| c.keyword == "kafka").unwrap();
+        assert_eq!((kafka.interval_days, kafka.lapses), (3.0, 1));
+        assert!(kafka.last_reviewed_at.is_some());
+
+        assert!(db.record_review("rust", &state, now, false).is_err());
+        assert_eq!(db.remove_review_cards(&["KAFKA".to_string()])?, 1);
+        assert_eq!(db.list_review_cards()?.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_job_clusters_replace_and_list() -> Result<()> {
+        let db = create_test_db()?;
+        let a = db.add_job("SRE")?;
+        let b = db.add_job("Platform Engineer")?;
+        let c = db.add_job("Frontend Engineer")?;
+        let clusters = vec![
+            ("Frontend".to_string(), vec!["react".to_string()], vec![c]),
+            ("Infra".to_string(), vec!["aws".to_string(), "terraform".to_string()], vec![a, b]),
+        ];
+        db.replace_job_clusters(&clusters, Some("model"))?;
+
+        let stored = db.list_job_clusters()?;
+        assert_eq!(stored.len(), 2);
+        assert_eq!(stored[0].name, "Infra", "largest first");
+        assert_eq!(stored[0].job_ids, vec![a, b]);
+        assert_eq!(stored[0].keywords, "aws, terraform");
+        assert_eq!(db.get_job_cluster_names()?.get(&c).map(String::as_str), Some("Frontend"));
+
+        db.replace_job_clusters(&clusters[..1], None)?;
+        assert_eq!(db.list_job_clusters()?.len(), 1);
+        assert!(!db.get_job_cluster_names()?.contains_key(&a));
+
+        db.purge_job(c)?;
+        assert!(db.get_job_cluster_names()?.is_empty());
+        Ok(())
+    }
+
     #[test]
     fn test_calculate_score_with_pay() -> Result<()> {
         let db = create_test_db()?;
         let job_id = db.add_job_full("Engineer", Some("Acme"), None, None, None, Some(200000), None)?;
         let job = db.get_job(job_id)?.unwrap();
-        let score = calculate_score(&job, &db);
+        let score = calculate_score(&job, &db, &db.list_job_statuses()?);
         // Base 50 + status bonus 5 (new) + pay bonus (200k is high)
         assert!(score > 50.0);
         Ok(())
@@ -2961,12 +8420,31 @@ mod tests {
         let job_id = db.add_job_full("Engineer", Some("Acme"), None, None, None, None, None)?;
         db.update_job_status(job_id, "reviewing")?;
         let job = db.get_job(job_id)?.unwrap();
-        let score = calculate_score(&job, &db);
+        let score = calculate_score(&job, &db, &db.list_job_statuses()?);
         // Base 50 + reviewing bonus 10
         assert!((score - 60.0).abs() < 0.1);
         Ok(())
     }
 
+    #[test]
+    fn test_status_points_follow_the_pipeline() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job_full("Engineer", Some("Acme"), None, None, None, None, None)?;
+        let points = |status: &str| -> Result<f64> {
+            db.update_job_status(id, status)?;
+            Ok(factor_points("status", &db.get_job(id)?.unwrap(), &db, &db.list_job_statuses()?))
+        };
+        assert_eq!(points("applied")?, 15.0);
+        assert_eq!(points("offer")?, 25.0);
+        assert_eq!(points("rejected")?, 0.0);
+
+        // A custom step spreads the same +25 over six non-terminal statuses
+        db.upsert_job_status("phone-screen", Some(35), false, "blue")?;
+        assert_eq!(points("phone-screen")?, 25.0 * 4.0 / 6.0);
+        assert_eq!(points("offer")?, 25.0);
+        Ok(())
+    }
+
     #[test]
     fn test_extract_pay_range_single_value() {
         let (min, max) = extract_pay_range("Salary: $150,000");
@@ -2976,10 +8454,9 @@ mod tests {
 
     #[test]
     fn test_extract_pay_range_hourly() {
-        // $50 is < 1000, so the parser interprets it as $50k (50000)
-        let (min, _max) = extract_pay_range("$50/hr - $75/hr");
-        // Parser multiplies small numbers by 1000
-        assert_eq!(min, Some(50000));
+        // 2080 working hours a year
+        let (min, max) = extract_pay_range("$50/hr - $75/hr");
+        assert_eq!((min, max), (Some(104000), Some(156000)));
     }
 
     #[test]
@@ -3024,16 +8501,255 @@ mod tests {
     }
 
     #[test]
-    fn test_resume_variant_upsert() -> Result<()> {
+    fn test_resume_variant_versions() -> Result<()> {
         let db = create_test_db()?;
         let resume_id = db.create_base_resume("test", "markdown", "content", None)?;
         let job_id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
-        db.create_resume_variant(resume_id, job_id, "v1", None, Some("claude"), Some("md"))?;
-        // Upsert with same key should update content
-        db.create_resume_variant(resume_id, job_id, "v2-updated", Some("new notes"), Some("claude"), Some("md"))?;
+        let first = db.create_resume_variant(resume_id, job_id, "v1", None, Some("claude"), Some("md"))?;
+        // Same key again adds a version instead of overwriting
+        let second = db.create_resume_variant(resume_id, job_id, "v2-updated", Some("new notes"), Some("claude"), Some("md"))?;
+        db.create_resume_variant(resume_id, job_id, "other", None, Some("gpt-5.2"), Some("md"))?;
+        assert_ne!(first, second);
+
         let variants = db.list_resume_variants_for_job(job_id)?;
-        assert_eq!(variants.len(), 1);
-        assert_eq!(variants[0].content, "v2-updated");
+        assert_eq!(variants.len(), 2, "latest version of each model only");
+        let claude = variants.iter().find(|v| v.source_model.as_deref() == Some("claude")).unwrap();
+        assert_eq!((claude.id, claude.version, claude.content.as_str()), (second, 2, "v2-updated"));
+
+        let history = db.list_resume_variant_history(job_id)?;
+        assert_eq!(history.len(), 3);
+        assert_eq!(db.get_resume_variant_by_id(first)?.map(|v| (v.version, v.content)), Some((1, "v1".to_string())));
+
+        // Variants without a model still version separately from each other
+        db.create_resume_variant(resume_id, job_id, "a", None, None, None)?;
+        db.create_resume_variant(resume_id, job_id, "b", None, None, None)?;
+        assert_eq!(db.get_resume_variant(job_id, resume_id)?.map(|v| v.version), Some(2));
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_adds_resume_variant_versions() -> Result<()> {
+        let db = create_test_db()?;
+        let resume_id = db.create_base_resume("test", "markdown", "content", None)?;
+        let job_id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
+        assert_eq!((resume_id, job_id), (1, 1));
+        db.conn.execute_batch(
+            "DROP TABLE resume_variants;
+             CREATE TABLE resume_variants (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                base_resume_id INTEGER NOT NULL,
+                job_id INTEGER NOT NULL,
+                content TEXT NOT NULL,
+                tailoring_notes TEXT,
+                source_model TEXT,
+                output_format TEXT,
+                created_at TEXT NOT NULL DEFAULT (strftime('%Y-%m-%dT%H:%M:%SZ', 'now')),
+                UNIQUE(base_resume_id, job_id, source_model, output_format)
+             );
+             INSERT INTO resume_variants (base_resume_id, job_id, content, source_model, output_format)
+                VALUES (1, 1, 'old', 'claude', 'md');",
+        )?;
+        db.init()?;
+        db.create_resume_variant(1, 1, "new", None, Some("claude"), Some("md"))?;
+        let history = db.list_resume_variant_history(1)?;
+        assert_eq!(history.iter().map(|v| (v.version, v.content.as_str())).collect::<Vec<_>>(), vec![(2, "new"), (1, "old")]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_employers() -> Result<()> {
+        let db = create_test_db()?;
+        let job_a = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        let job_b = db.add_job_full("DevOps", Some("Acme Inc."), Some("https://careers.acme.com/1"), None, None, None, None)?;
+        let acme = db.get_employer_by_name("Acme")?.unwrap().id;
+        let dup = db.get_employer_by_name("Acme Inc.")?.unwrap().id;
+        db.set_employer_status("Acme Inc.", "never")?;
+        db.conn.execute("UPDATE employers SET notes = 'dup note', hn_mentions_count = 7 WHERE id = ?1", [dup])?;
+        db.conn.execute(
+            "INSERT INTO employer_alerts (employer_id, kind, summary) VALUES (?1, 'layoff', 'Cut 10%')",
+            [dup],
+        )?;
+
+        db.merge_employers(dup, acme)?;
+        assert_eq!(db.get_employer_by_name("Acme Inc.")?.map(|e| e.id), Some(acme), "now an alias");
+        let merged = db.get_employer_by_name("Acme")?.unwrap();
+        assert_eq!(merged.status, "never", "the stricter status wins");
+        assert_eq!(merged.notes.as_deref(), Some("dup note"));
+        assert_eq!(merged.hn_mentions_count, Some(7));
+        assert_eq!(merged.domain.as_deref(), Some("acme.com"));
+        for job in [job_a, job_b] {
+            assert_eq!(db.get_job(job)?.unwrap().employer_id, Some(acme));
+        }
+        assert_eq!(db.list_employer_alerts(true)?[0].employer_name, "Acme");
+
+        assert!(db.merge_employers(acme, acme).is_err());
+        assert!(db.merge_employers(dup, acme).is_err(), "already gone");
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_jobs() -> Result<()> {
+        let db = create_test_db()?;
+        let keep = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        let dup = db.add_job_full("Site Reliability Engineer", Some("Acme"), Some("https://acme.com/jobs/1"), None, None, None, None)?;
+        db.update_job_description(dup, "Run our platform.\nPay: $150,000 - $180,000", Some(150_000), Some(180_000))?;
+        db.add_job_keywords(keep, &[("kubernetes".to_string(), 3)], "tech", "gpt-5.2")?;
+        db.add_job_keywords(dup, &[("terraform".to_string(), 2)], "tech", "gpt-5.2")?;
+        db.add_job_keywords(dup, &[("aws".to_string(), 2)], "cloud", "claude")?;
+        db.add_job_tags(keep, &["infra".to_string()])?;
+        db.add_job_tags(dup, &["infra".to_string(), "referral".to_string()])?;
+        db.add_job_note(dup, "Recruiter reached out")?;
+        db.update_job_status(dup, "applied")?;
+
+        db.merge_jobs(keep, dup)?;
+        let merged = db.get_job(keep)?.unwrap();
+        assert_eq!(merged.title, "SRE", "keep's own fields stay");
+        assert_eq!(merged.url.as_deref(), Some("https://acme.com/jobs/1"));
+        assert_eq!(merged.raw_text.as_deref(), Some("Run our platform.\nPay: $150,000 - $180,000"));
+        assert_eq!((merged.pay_min, merged.pay_max), (Some(150_000), Some(180_000)));
+        assert_eq!(merged.status, "applied", "the status further along wins");
+        assert_eq!(db.list_job_snapshots(keep)?.len(), 1);
+        let keywords: Vec<String> = db.get_job_keywords(keep, None)?.into_iter().map(|k| k.keyword).collect();
+        assert!(keywords.contains(&"kubernetes".to_string()) && keywords.contains(&"aws".to_string()));
+        assert!(!keywords.contains(&"terraform".to_string()), "keep already had gpt-5.2 keywords");
+        assert_eq!(db.get_job_tags(keep)?, vec!["infra", "referral"]);
+        let notes: Vec<String> = db.list_job_notes(keep)?.into_iter().map(|n| n.note).collect();
+        assert!(notes.contains(&"Recruiter reached out".to_string()));
+        assert!(notes.contains(&format!("Merged duplicate job #{} ('Site Reliability Engineer')", dup)));
+        let history: Vec<String> = db.list_status_history(Some(keep))?.into_iter().map(|h| h.to_status).collect();
+        assert_eq!(history, vec!["applied", "applied"], "dup's history plus keep's own move");
+
+        assert!(db.merge_jobs(keep, keep).is_err());
+        let err = db.merge_jobs(keep, 999).unwrap_err();
+        assert!(matches!(err.downcast_ref::<HuntError>(), Some(HuntError::NotFound(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn test_employer_aliases_resolve_on_ingest() -> Result<()> {
+        let db = create_test_db()?;
+        let google = db.get_or_create_employer("Google")?;
+        db.add_employer_alias("Alphabet", google)?;
+        assert_eq!(db.get_or_create_employer("Alphabet Inc.")?, google);
+        assert_eq!(db.get_employer_by_name("alphabet")?.unwrap().name, "Google");
+
+        let job = db.add_job_full("SRE", Some("Alphabet"), None, None, None, None, None)?;
+        assert_eq!(db.get_job(job)?.unwrap().employer_id, Some(google));
+        assert_eq!(db.is_duplicate_job("SRE", Some("Alphabet, Inc"), None)?, Some(job));
+
+        assert!(db.remove_employer_alias("ALPHABET")?);
+        assert!(!db.remove_employer_alias("Alphabet")?);
+        assert_ne!(db.get_or_create_employer("Alphabet")?, google);
+        Ok(())
+    }
+
+    #[test]
+    fn test_merge_and_rename_record_aliases() -> Result<()> {
+        let db = create_test_db()?;
+        let acme = db.get_or_create_employer("Acme")?;
+        let dup = db.get_or_create_employer("Acme Widgets")?;
+        db.add_employer_alias("AW", dup)?;
+        db.merge_employers(dup, acme)?;
+        assert_eq!(db.get_or_create_employer("Acme Widgets")?, acme, "merged name doesn't come back");
+        assert_eq!(db.get_or_create_employer("AW")?, acme, "the duplicate's aliases move too");
+
+        db.rename_employer(acme, "Acme Corp")?;
+        assert_eq!(db.get_or_create_employer("Acme")?, acme);
+        let aliases: Vec<String> = db.list_employer_aliases(Some(acme))?.into_iter().map(|a| a.alias).collect();
+        assert_eq!(aliases, vec!["AW", "Acme", "Acme Widgets"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rename_employer() -> Result<()> {
+        let db = create_test_db()?;
+        let acme = db.get_or_create_employer("acme")?;
+        db.get_or_create_employer("Globex")?;
+        db.rename_employer(acme, "Acme")?;
+        assert_eq!(db.get_employer_by_name("acme")?.unwrap().name, "Acme");
+        assert!(db.rename_employer(acme, "globex").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_replace_employer_hn_stories() -> Result<()> {
+        let db = create_test_db()?;
+        let acme = db.get_or_create_employer("Acme")?;
+        let story = |title: &str, points: i64| HnStory {
+            title: title.to_string(),
+            url: None,
+            hn_url: format!("https://news.ycombinator.com/item?id={}", points),
+            points,
+            num_comments: 0,
+            posted_at: None,
+        };
+        db.replace_employer_hn_stories(acme, &[story("Old", 5)])?;
+        db.replace_employer_hn_stories(acme, &[story("Launch HN: Acme", 80), story("Acme raises", 300)])?;
+        let stories = db.list_employer_hn_stories(acme)?;
+        assert_eq!(stories.iter().map(|s| s.title.as_str()).collect::<Vec<_>>(), vec!["Acme raises", "Launch HN: Acme"]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_job_urls_fill_employer_links() -> Result<()> {
+        let db = create_test_db()?;
+        db.add_job_full("SRE", Some("Acme"), Some("https://www.linkedin.com/jobs/view/1"), None, None, None, None)?;
+        let acme = db.get_employer_by_name("Acme")?.unwrap();
+        assert_eq!((acme.domain, acme.careers_url), (None, None));
+
+        db.add_job_full("SRE II", Some("Acme"), Some("https://jobs.lever.co/acme/abc"), None, None, None, None)?;
+        db.add_job_full("SRE III", Some("Acme"), Some("https://careers.acme.com/jobs/9"), None, None, None, None)?;
+        let acme = db.get_employer_by_name("Acme")?.unwrap();
+        assert_eq!(acme.domain.as_deref(), Some("acme.com"));
+        assert_eq!(acme.careers_url.as_deref(), Some("https://jobs.lever.co/acme"), "first answer is kept");
+        assert_eq!(db.get_employer_job_urls(acme.id)?.len(), 3);
+
+        let links = EmployerLinks { domain: None, careers_url: Some("https://acme.com/careers".to_string()) };
+        db.set_employer_links(acme.id, &links, true)?;
+        let acme = db.get_employer_by_name("Acme")?.unwrap();
+        assert_eq!(acme.domain.as_deref(), Some("acme.com"), "unknown values don't clear stored ones");
+        assert_eq!(acme.careers_url.as_deref(), Some("https://acme.com/careers"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_stale_resume_variants_after_update() -> Result<()> {
+        let db = create_test_db()?;
+        let resume_id = db.create_base_resume("test", "markdown", "v1 content", None)?;
+        let job_a = db.add_job_full("Job A", Some("Co"), None, None, None, None, None)?;
+        let job_b = db.add_job_full("Job B", Some("Co"), None, None, None, None, None)?;
+        assert_eq!(db.get_base_resume(resume_id)?.unwrap().content_hash, Some(content_hash("v1 content")));
+
+        db.create_resume_variant(resume_id, job_a, "a1", None, Some("claude"), None)?;
+        db.create_resume_variant(resume_id, job_b, "b1", None, Some("claude"), None)?;
+        assert!(db.get_stale_resume_variants(resume_id)?.is_empty());
+
+        db.update_base_resume(resume_id, None, None, Some("v2 content"), None)?;
+        assert_eq!(db.get_base_resume(resume_id)?.unwrap().content_hash, Some(content_hash("v2 content")));
+        let stale = db.get_stale_resume_variants(resume_id)?;
+        assert_eq!(stale.iter().map(|v| v.job_id).collect::<Vec<_>>(), vec![job_a, job_b]);
+
+        // Re-tailoring job A from the new content clears it; only the latest version counts
+        db.create_resume_variant(resume_id, job_a, "a2", None, Some("claude"), None)?;
+        let stale = db.get_stale_resume_variants(resume_id)?;
+        assert_eq!(stale.iter().map(|v| v.job_id).collect::<Vec<_>>(), vec![job_b]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_backfills_content_hashes() -> Result<()> {
+        let db = create_test_db()?;
+        let resume_id = db.create_base_resume("test", "markdown", "content", None)?;
+        let job_id = db.add_job_full("Job", Some("Co"), None, None, None, None, None)?;
+        db.create_resume_variant(resume_id, job_id, "tailored", None, Some("claude"), None)?;
+        db.conn.execute_batch(
+            "ALTER TABLE base_resumes DROP COLUMN content_hash;
+             ALTER TABLE resume_variants DROP COLUMN base_content_hash;",
+        )?;
+        db.init()?;
+        assert_eq!(db.get_base_resume(resume_id)?.unwrap().content_hash, Some(content_hash("content")));
+        // Untracked variants count as stale: we can't tell what they were made from
+        assert_eq!(db.get_stale_resume_variants(resume_id)?.len(), 1);
         Ok(())
     }
 
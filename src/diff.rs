@@ -0,0 +1,124 @@
+use crossterm::style::Stylize;
+
+/// One line of a line-level diff between two texts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DiffLine<'a> {
+    Same(&'a str),
+    Added(&'a str),
+    Removed(&'a str),
+}
+
+/// Longest-common-subsequence diff by line. Quadratic in the differing middle, which
+/// is fine for resumes and postings (hundreds of lines, not millions).
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<DiffLine<'a>> {
+    let old: Vec<&str> = old.lines().collect();
+    let new: Vec<&str> = new.lines().collect();
+
+    // Common prefix and suffix don't need the table
+    let prefix = old.iter().zip(&new).take_while(|(a, b)| a == b).count();
+    let suffix = old[prefix..].iter().rev().zip(new[prefix..].iter().rev()).take_while(|(a, b)| a == b).count();
+    let (a, b) = (&old[prefix..old.len() - suffix], &new[prefix..new.len() - suffix]);
+
+    // lcs[i][j] = LCS length of a[i..] and b[j..]
+    let mut lcs = vec![vec![0u32; b.len() + 1]; a.len() + 1];
+    for i in (0..a.len()).rev() {
+        for j in (0..b.len()).rev() {
+            lcs[i][j] = if a[i] == b[j] { lcs[i + 1][j + 1] + 1 } else { lcs[i + 1][j].max(lcs[i][j + 1]) };
+        }
+    }
+
+    let mut result: Vec<DiffLine> = old[..prefix].iter().map(|l| DiffLine::Same(l)).collect();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() || j < b.len() {
+        if i < a.len() && j < b.len() && a[i] == b[j] {
+            result.push(DiffLine::Same(a[i]));
+            i += 1;
+            j += 1;
+        } else if i < a.len() && (j == b.len() || lcs[i + 1][j] >= lcs[i][j + 1]) {
+            result.push(DiffLine::Removed(a[i]));
+            i += 1;
+        } else {
+            result.push(DiffLine::Added(b[j]));
+            j += 1;
+        }
+    }
+    result.extend(old[old.len() - suffix..].iter().map(|l| DiffLine::Same(l)));
+    result
+}
+
+/// (lines added, lines removed)
+pub fn stats(diff: &[DiffLine]) -> (usize, usize) {
+    let added = diff.iter().filter(|l| matches!(l, DiffLine::Added(_))).count();
+    let removed = diff.iter().filter(|l| matches!(l, DiffLine::Removed(_))).count();
+    (added, removed)
+}
+
+/// Render as `+`/`-`/` ` lines, keeping `context` unchanged lines around each change
+/// and eliding the rest. With `color`, additions are green and removals red.
+pub fn render(diff: &[DiffLine], context: usize, color: bool) -> String {
+    let changed: Vec<usize> = diff
+        .iter()
+        .enumerate()
+        .filter(|(_, l)| !matches!(l, DiffLine::Same(_)))
+        .map(|(i, _)| i)
+        .collect();
+    let near_change = |i: usize| changed.iter().any(|&c| c.abs_diff(i) <= context);
+
+    let mut out = String::new();
+    let mut skipped = 0;
+    for (i, line) in diff.iter().enumerate() {
+        if !near_change(i) {
+            skipped += 1;
+            continue;
+        }
+        if skipped > 0 {
+            out.push_str(&format!("   ... {} unchanged line(s)\n", skipped));
+            skipped = 0;
+        }
+        let rendered = match line {
+            DiffLine::Same(text) => format!("  {}", text),
+            DiffLine::Added(text) if color => format!("+ {}", text).green().to_string(),
+            DiffLine::Added(text) => format!("+ {}", text),
+            DiffLine::Removed(text) if color => format!("- {}", text).red().to_string(),
+            DiffLine::Removed(text) => format!("- {}", text),
+        };
+        out.push_str(&rendered);
+        out.push('\n');
+    }
+    if skipped > 0 {
+        out.push_str(&format!("   ... {} unchanged line(s)\n", skipped));
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_diff_lines() {
+        let old = "Jane Doe\nSkills: Go, AWS\nExperience\n- Built things\n- Ran things";
+        let new = "Jane Doe\nSkills: Go, AWS, Kubernetes\nExperience\n- Built things\n- Led a team\n- Ran things";
+        assert_eq!(diff_lines(old, new), vec![
+            DiffLine::Same("Jane Doe"),
+            DiffLine::Removed("Skills: Go, AWS"),
+            DiffLine::Added("Skills: Go, AWS, Kubernetes"),
+            DiffLine::Same("Experience"),
+            DiffLine::Same("- Built things"),
+            DiffLine::Added("- Led a team"),
+            DiffLine::Same("- Ran things"),
+        ]);
+        assert_eq!(stats(&diff_lines(old, new)), (2, 1));
+        assert_eq!(stats(&diff_lines(old, old)), (0, 0));
+        assert_eq!(diff_lines("", "a"), vec![DiffLine::Added("a")]);
+    }
+
+    #[test]
+    fn test_render_elides_unchanged_runs() {
+        let old = "1\n2\n3\n4\n5\n6\n7\n8";
+        let new = "1\n2\n3\n4\n5\n6\n7\nEIGHT";
+        let out = render(&diff_lines(old, new), 1, false);
+        assert_eq!(out, "   ... 6 unchanged line(s)\n  7\n- 8\n+ EIGHT\n");
+        assert!(render(&diff_lines(old, new), 1, true).contains("\u{1b}["));
+    }
+}
@@ -0,0 +1,180 @@
+use chrono::NaiveDate;
+use clap::ValueEnum;
+
+use crate::db::DigestActivity;
+use crate::models::{Employer, Job};
+use crate::pay;
+
+/// `hunt digest --format`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Markdown,
+    Html,
+}
+
+/// A heading and its bullets; both renderers walk the same list.
+struct Section {
+    title: String,
+    items: Vec<String>,
+}
+
+fn describe_job(job: &Job) -> String {
+    let mut line = match &job.employer_name {
+        Some(employer) => format!("#{} {} @ {}", job.id, job.title, employer),
+        None => format!("#{} {}", job.id, job.title),
+    };
+    if let Some(pay) = pay::label(job) {
+        line.push_str(&format!(" ({})", pay));
+    }
+    line
+}
+
+/// The research worth a line in the digest: funding, Glassdoor, ownership and the
+/// AI summaries, whichever the employer has.
+pub fn employer_highlights(employer: &Employer) -> String {
+    let first_sentence = |text: &str| {
+        let text = text.trim();
+        text.split_once(". ").map_or(text.to_string(), |(first, _)| format!("{}.", first))
+    };
+    let mut facts = Vec::new();
+    match (&employer.funding_stage, employer.total_funding) {
+        (Some(stage), Some(total)) => facts.push(format!("{} (${}M raised)", stage, total / 1_000_000)),
+        (Some(stage), None) => facts.push(stage.clone()),
+        (None, Some(total)) => facts.push(format!("${}M raised", total / 1_000_000)),
+        (None, None) => {}
+    }
+    if let Some(batch) = &employer.yc_batch {
+        facts.push(format!("YC {}", batch));
+    }
+    if let Some(rating) = employer.glassdoor_rating {
+        facts.push(format!("Glassdoor {:.1}/5 ({} reviews)", rating, employer.glassdoor_review_count.unwrap_or(0)));
+    }
+    if let Some(owner) = &employer.pe_owner {
+        facts.push(format!("PE-owned by {}", owner));
+    } else if let Some(parent) = &employer.parent_company {
+        facts.push(format!("part of {}", parent));
+    }
+    for summary in [&employer.ownership_concerns, &employer.evil_summary, &employer.eng_culture_summary].into_iter().flatten() {
+        if !summary.trim().is_empty() {
+            facts.push(first_sentence(summary));
+        }
+    }
+    if facts.is_empty() {
+        return employer.name.clone();
+    }
+    format!("{}: {}", employer.name, facts.join("; "))
+}
+
+fn sections(activity: &DigestActivity) -> Vec<Section> {
+    let jobs = |jobs: &[Job]| jobs.iter().map(describe_job).collect::<Vec<_>>();
+    vec![
+        Section { title: format!("New jobs ({})", activity.new_jobs.len()), items: jobs(&activity.new_jobs) },
+        Section { title: format!("Applications sent ({})", activity.applied.len()), items: jobs(&activity.applied) },
+        Section { title: format!("Interviews ({})", activity.interviews.len()), items: jobs(&activity.interviews) },
+        Section {
+            title: "Top unapplied jobs".to_string(),
+            items: activity
+                .top_unapplied
+                .iter()
+                .map(|(job, score)| format!("{} — score {:.0}", describe_job(job), score))
+                .collect(),
+        },
+        Section {
+            title: "Employer research".to_string(),
+            items: activity.employers_researched.iter().map(employer_highlights).collect(),
+        },
+    ]
+}
+
+fn headline(activity: &DigestActivity) -> String {
+    format!(
+        "{} new job(s), {} application(s) sent, {} interview(s)",
+        activity.new_jobs.len(),
+        activity.applied.len(),
+        activity.interviews.len()
+    )
+}
+
+/// The digest for `from` through `to` (local dates) as a markdown or standalone HTML document.
+pub fn render(activity: &DigestActivity, from: NaiveDate, to: NaiveDate, format: Format) -> String {
+    let title = format!("Job hunt digest: {} to {}", from.format("%Y-%m-%d"), to.format("%Y-%m-%d"));
+    match format {
+        Format::Markdown => {
+            let mut out = format!("# {}\n\n{}\n", title, headline(activity));
+            for section in sections(activity) {
+                out.push_str(&format!("\n## {}\n\n", section.title));
+                if section.items.is_empty() {
+                    out.push_str("- None\n");
+                }
+                for item in &section.items {
+                    out.push_str(&format!("- {}\n", item));
+                }
+            }
+            out
+        }
+        Format::Html => {
+            let mut out = format!(
+                "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{0}</title>\n</head>\n<body>\n<h1>{0}</h1>\n<p>{1}</p>\n",
+                escape_html(&title),
+                escape_html(&headline(activity))
+            );
+            for section in sections(activity) {
+                out.push_str(&format!("<h2>{}</h2>\n", escape_html(&section.title)));
+                if section.items.is_empty() {
+                    out.push_str("<p>None</p>\n");
+                    continue;
+                }
+                out.push_str("<ul>\n");
+                for item in &section.items {
+                    out.push_str(&format!("<li>{}</li>\n", escape_html(item)));
+                }
+                out.push_str("</ul>\n");
+            }
+            out.push_str("</body>\n</html>\n");
+            out
+        }
+    }
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;").replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+
+    fn day(d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(2026, 3, d).unwrap()
+    }
+
+    #[test]
+    fn test_render_digest() -> anyhow::Result<()> {
+        let db = Database::open_in_memory()?;
+        db.init()?;
+        let applied = db.add_job_full("SRE", Some("Acme"), None, None, Some(150000), Some(180000), None)?;
+        db.add_job_full("Platform <Lead>", Some("Globex"), None, None, None, None, None)?;
+        db.update_job_status(applied, "applied")?;
+        db.update_job_status(applied, "interviewing")?;
+        let acme = db.get_job(applied)?.unwrap().employer_id.unwrap();
+        db.update_employer_research(acme, None, Some("Series B"), Some(40_000_000), None, Some("W21"), None, None, None)?;
+
+        let activity = db.get_digest_activity("2000-01-01T00:00:00Z", 5)?;
+        let markdown = render(&activity, day(8), day(15), Format::Markdown);
+        assert!(markdown.starts_with("# Job hunt digest: 2026-03-08 to 2026-03-15\n\n2 new job(s), 1 application(s) sent, 1 interview(s)\n"));
+        assert!(markdown.contains("## Applications sent (1)\n\n- #1 SRE @ Acme ($150000 - $180000)\n"), "{}", markdown);
+        assert!(markdown.contains("## Top unapplied jobs\n\n- #2 Platform <Lead> @ Globex — score"), "{}", markdown);
+        assert!(markdown.contains("## Employer research\n\n- Acme: Series B ($40M raised); YC W21\n"), "{}", markdown);
+
+        let html = render(&activity, day(8), day(15), Format::Html);
+        assert!(html.contains("<li>#2 Platform &lt;Lead&gt; @ Globex</li>"), "{}", html);
+        assert!(html.ends_with("</body>\n</html>\n"));
+
+        let quiet = db.get_digest_activity("2999-01-01T00:00:00Z", 0)?;
+        let markdown = render(&quiet, day(8), day(15), Format::Markdown);
+        assert!(markdown.contains("## New jobs (0)\n\n- None\n"));
+        assert!(!markdown.contains("Acme"));
+        Ok(())
+    }
+}
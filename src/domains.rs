@@ -0,0 +1,386 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+use reqwest::Url;
+
+use crate::models::Job;
+
+/// Aggregators whose URLs say nothing about the employer's own site.
+const JOB_BOARD_HOSTS: &[&str] = &[
+    "linkedin.com", "indeed.com", "glassdoor.com", "ziprecruiter.com", "dice.com", "monster.com",
+    "simplyhired.com", "wellfound.com", "angel.co", "builtin.com", "ycombinator.com",
+    "workatastartup.com", "remoteok.com", "weworkremotely.com", "otta.com", "hired.com",
+    "google.com", "welcometothejungle.com", "hnhiring.com",
+];
+
+/// Applicant tracking systems by the hosts their postings live on.
+const ATS_HOSTS: &[(&str, &str)] = &[
+    ("myworkdayjobs.com", "Workday"), ("myworkdaysite.com", "Workday"), ("taleo.net", "Taleo"),
+    ("icims.com", "iCIMS"), ("greenhouse.io", "Greenhouse"), ("lever.co", "Lever"), ("ashbyhq.com", "Ashby"),
+    ("smartrecruiters.com", "SmartRecruiters"), ("workable.com", "Workable"), ("jobvite.com", "Jobvite"),
+    ("bamboohr.com", "BambooHR"), ("successfactors.com", "SuccessFactors"), ("oraclecloud.com", "Oracle"),
+    ("ultipro.com", "UKG"), ("breezy.hr", "Breezy"), ("recruitee.com", "Recruitee"),
+    ("teamtailor.com", "Teamtailor"), ("applytojob.com", "JazzHR"),
+];
+
+/// Query parameters aggregators wrap the outbound link in.
+const WRAPPER_PARAMS: &[&str] = &["url", "u", "dest", "destination", "redirect", "target"];
+
+// Absolute URLs anywhere in a page: attributes, scripts, comments (LinkedIn keeps its
+// apply URL in an HTML comment)
+static PAGE_URL: LazyLock<Regex> = LazyLock::new(|| Regex::new(r#"https?://[^\s"'<>\\]+"#).unwrap());
+
+/// Second-level labels under country TLDs (acme.co.uk): the registrable domain keeps three labels.
+const SECOND_LEVEL_LABELS: &[&str] = &["co", "com", "org", "net", "ac", "gov", "ltd"];
+
+/// An employer's website domain and careers page, as far as we can tell.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct EmployerLinks {
+    pub domain: Option<String>,
+    pub careers_url: Option<String>,
+}
+
+impl EmployerLinks {
+    pub fn is_complete(&self) -> bool {
+        self.domain.is_some() && self.careers_url.is_some()
+    }
+
+    /// Fill whatever is still missing from `other`.
+    pub fn or(self, other: EmployerLinks) -> EmployerLinks {
+        EmployerLinks {
+            domain: self.domain.or(other.domain),
+            careers_url: self.careers_url.or(other.careers_url),
+        }
+    }
+}
+
+fn host_is(host: &str, domain: &str) -> bool {
+    host == domain || host.ends_with(&format!(".{}", domain))
+}
+
+/// acme.com for jobs.eu.acme.com; acme.co.uk for careers.acme.co.uk.
+pub fn registrable_domain(host: &str) -> String {
+    let labels: Vec<&str> = host.trim_end_matches('.').split('.').collect();
+    let keep = match labels.as_slice() {
+        [.., second, tld] if tld.len() == 2 && SECOND_LEVEL_LABELS.contains(second) => 3,
+        _ => 2,
+    };
+    labels[labels.len().saturating_sub(keep)..].join(".").to_lowercase()
+}
+
+/// Accept "https://www.Acme.com/about", "acme.com" or "www.acme.com" and return "acme.com".
+pub fn normalize_domain(value: &str) -> Option<String> {
+    let value = value.trim();
+    let with_scheme = if value.contains("://") { value.to_string() } else { format!("https://{}", value) };
+    let url = Url::parse(&with_scheme).ok()?;
+    let host = url.host_str()?.to_lowercase();
+    if !host.contains('.') {
+        return None;
+    }
+    Some(host.strip_prefix("www.").unwrap_or(&host).to_string())
+}
+
+/// Whether a posting URL is on an aggregator rather than the employer's own site or
+/// ATS board; None when it doesn't parse.
+pub fn is_job_board_url(url: &str) -> Option<bool> {
+    let url = Url::parse(url.trim()).ok()?;
+    let host = url.host_str()?.to_lowercase();
+    Some(JOB_BOARD_HOSTS.iter().any(|board| host_is(&host, board)))
+}
+
+/// What a single job posting URL tells us. Hosted ATS pages (Greenhouse, Lever, ...)
+/// give a careers page but not the company's domain; the company's own careers site
+/// gives both; job boards give nothing.
+pub fn infer_from_job_url(url: &str) -> EmployerLinks {
+    let Ok(url) = Url::parse(url.trim()) else { return EmployerLinks::default() };
+    let Some(host) = url.host_str().map(str::to_lowercase) else { return EmployerLinks::default() };
+    let segments: Vec<&str> = url.path_segments().map(|s| s.filter(|s| !s.is_empty()).collect()).unwrap_or_default();
+    let origin = format!("{}://{}", url.scheme(), host);
+    let first_segment = |prefix: &str| segments.first().map(|slug| format!("{}{}/{}", origin, prefix, slug));
+
+    if JOB_BOARD_HOSTS.iter().any(|board| host_is(&host, board)) {
+        return EmployerLinks::default();
+    }
+
+    let ats_careers = if host_is(&host, "greenhouse.io") {
+        // boards.greenhouse.io/embed/job_app?for=acme has the slug in the query
+        match url.query_pairs().find(|(k, _)| k == "for") {
+            Some((_, slug)) => Some(format!("https://boards.greenhouse.io/{}", slug)),
+            None => first_segment(""),
+        }
+    } else if ["lever.co", "ashbyhq.com", "smartrecruiters.com", "workable.com", "jobvite.com"]
+        .iter()
+        .any(|ats| host_is(&host, ats))
+    {
+        first_segment("")
+    } else if host_is(&host, "myworkdayjobs.com") {
+        // acme.wd5.myworkdayjobs.com/en-US/External/job/... -> the "External" site
+        segments.iter()
+            .find(|s| !(s.len() == 5 && s.as_bytes()[2] == b'-'))
+            .map(|site| format!("{}/{}", origin, site))
+    } else if host_is(&host, "bamboohr.com") {
+        Some(format!("{}/careers", origin))
+    } else if host_is(&host, "breezy.hr") || host_is(&host, "recruitee.com") || host_is(&host, "teamtailor.com") {
+        Some(origin.clone())
+    } else {
+        None
+    };
+    if ats_careers.is_some() {
+        return EmployerLinks { domain: None, careers_url: ats_careers };
+    }
+
+    // The employer's own site
+    let careers_url = if ["careers.", "jobs.", "work.", "join."].iter().any(|p| host.starts_with(p)) {
+        Some(origin)
+    } else {
+        segments.iter()
+            .position(|s| matches!(s.to_lowercase().as_str(), "careers" | "jobs" | "join-us" | "join" | "work-with-us"))
+            .map(|i| format!("{}/{}", origin, segments[..=i].join("/")))
+    };
+    EmployerLinks { domain: Some(registrable_domain(&host)), careers_url }
+}
+
+/// Query parameters that only say how someone got to a posting.
+const TRACKING_PARAMS: &[&str] = &[
+    "trk", "trkinfo", "refid", "trackingid", "lipi", "midtoken", "midsig", "eid", "otptoken", "alid",
+    "tk", "from", "advn", "adid", "ebp", "gh_src", "lever-source", "source", "src", "ref", "referrer",
+    "gclid", "fbclid", "msclkid", "mc_cid", "mc_eid",
+];
+
+/// One URL per posting, so tracking links to the same job dedupe: LinkedIn postings become
+/// `https://www.linkedin.com/jobs/view/<id>`, Indeed ones `https://<host>/viewjob?jk=<id>`,
+/// and anything else loses its fragment, trailing slash and tracking parameters (utm_*,
+/// trk, refId, ...). Text that isn't a URL comes back trimmed.
+pub fn canonical_job_url(url: &str) -> String {
+    let Ok(mut parsed) = Url::parse(url.trim()) else { return url.trim().to_string() };
+    let Some(host) = parsed.host_str().map(str::to_lowercase) else { return url.trim().to_string() };
+
+    if host_is(&host, "linkedin.com") {
+        let segments: Vec<&str> = parsed.path_segments().map(|s| s.filter(|s| !s.is_empty()).collect()).unwrap_or_default();
+        // /jobs/view/<id>, /comm/jobs/view/<id>, /jobs/view/sre-at-acme-<id>
+        let from_path = segments.windows(3)
+            .find(|w| w[0] == "jobs" && w[1] == "view")
+            .and_then(|w| w[2].rsplit('-').next())
+            .filter(|id| !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()))
+            .map(str::to_string);
+        // /jobs/search/?currentJobId=<id>
+        let from_query = || parsed.query_pairs()
+            .find(|(k, v)| k == "currentJobId" && !v.is_empty() && v.chars().all(|c| c.is_ascii_digit()))
+            .map(|(_, v)| v.into_owned());
+        if let Some(id) = from_path.or_else(from_query) {
+            return format!("https://www.linkedin.com/jobs/view/{}", id);
+        }
+    }
+    if host_is(&host, "indeed.com") {
+        // /viewjob, /rc/clk, /pagead/clk and /m/viewjob all carry the job key
+        if let Some((_, jk)) = parsed.query_pairs().find(|(k, v)| k == "jk" && !v.is_empty()) {
+            return format!("https://{}/viewjob?jk={}", host, jk);
+        }
+    }
+
+    let kept: Vec<(String, String)> = parsed.query_pairs()
+        .filter(|(k, _)| {
+            let key = k.to_lowercase();
+            !key.starts_with("utm_") && !TRACKING_PARAMS.contains(&key.as_str())
+        })
+        .map(|(k, v)| (k.into_owned(), v.into_owned()))
+        .collect();
+    if kept.is_empty() {
+        parsed.set_query(None);
+    } else {
+        parsed.query_pairs_mut().clear().extend_pairs(kept);
+    }
+    parsed.set_fragment(None);
+    let path = parsed.path().to_string();
+    if path.len() > 1 && path.ends_with('/') {
+        parsed.set_path(path.trim_end_matches('/'));
+    }
+    parsed.to_string()
+}
+
+/// The applicant tracking system a posting URL is on ("Workday", "Greenhouse", ...).
+pub fn identify_ats(url: &str) -> Option<&'static str> {
+    let url = Url::parse(url.trim()).ok()?;
+    let host = url.host_str()?.to_lowercase();
+    ATS_HOSTS.iter().find(|(ats, _)| host_is(&host, ats)).map(|(_, name)| *name)
+}
+
+/// Where you actually apply for a posting, and the ATS behind it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Application {
+    pub url: String,
+    pub ats: Option<&'static str>,
+}
+
+/// The first link on an aggregator's page that leads to an ATS, unwrapped from redirect
+/// parameters (`externalApply?url=...`) and canonicalized.
+pub fn find_ats_link(html: &str) -> Option<String> {
+    let html = html.replace("&amp;", "&");
+    PAGE_URL.find_iter(&html).find_map(|found| {
+        let url = Url::parse(found.as_str()).ok()?;
+        let wrapped = url.query_pairs()
+            .find(|(k, v)| WRAPPER_PARAMS.contains(&k.to_lowercase().as_str()) && v.starts_with("http"))
+            .map(|(_, v)| v.into_owned());
+        let target = wrapped.unwrap_or_else(|| url.to_string());
+        identify_ats(&target).map(|_| canonical_job_url(&target))
+    })
+}
+
+/// Where a posting that ended up at `final_url` (after redirects) takes applications:
+/// that page itself when it's off the job boards, else the ATS link on it. None when an
+/// aggregator posting has no outbound link (Easy Apply, or a login wall).
+pub fn resolve_application(final_url: &str, html: &str) -> Option<Application> {
+    let url = match is_job_board_url(final_url)? {
+        false => canonical_job_url(final_url),
+        true => find_ats_link(html)?,
+    };
+    Some(Application { ats: identify_ats(&url), url })
+}
+
+/// How to apply, from what fetches and `hunt resolve` found: "LinkedIn Easy Apply", the
+/// resolved URL and its ATS, or just the ATS when that's the job's own URL.
+pub fn apply_label(job: &Job) -> Option<String> {
+    if job.easy_apply == Some(true) {
+        return Some("LinkedIn Easy Apply".to_string());
+    }
+    let elsewhere = job.apply_url.as_deref().filter(|url| job.url.as_deref() != Some(*url));
+    match (elsewhere, job.ats.as_deref()) {
+        (Some(url), Some(ats)) => Some(format!("{} ({})", url, ats)),
+        (Some(url), None) => Some(url.to_string()),
+        (None, Some(ats)) => Some(format!("on {}", ats)),
+        (None, None) => (job.easy_apply == Some(false)).then(|| "employer's site (via LinkedIn)".to_string()),
+    }
+}
+
+/// Combine what every known posting URL says; the first URL to answer each field wins.
+pub fn infer_from_job_urls<'a>(urls: impl IntoIterator<Item = &'a str>) -> EmployerLinks {
+    urls.into_iter()
+        .fold(EmployerLinks::default(), |links, url| links.or(infer_from_job_url(url)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn links(domain: Option<&str>, careers: Option<&str>) -> EmployerLinks {
+        EmployerLinks { domain: domain.map(String::from), careers_url: careers.map(String::from) }
+    }
+
+    #[test]
+    fn test_infer_from_ats_urls() {
+        assert_eq!(infer_from_job_url("https://boards.greenhouse.io/acme/jobs/123"),
+                   links(None, Some("https://boards.greenhouse.io/acme")));
+        assert_eq!(infer_from_job_url("https://boards.greenhouse.io/embed/job_app?for=acme&token=1"),
+                   links(None, Some("https://boards.greenhouse.io/acme")));
+        assert_eq!(infer_from_job_url("https://jobs.lever.co/acme/5f1c-uuid"),
+                   links(None, Some("https://jobs.lever.co/acme")));
+        assert_eq!(infer_from_job_url("https://acme.wd5.myworkdayjobs.com/en-US/External/job/Remote/SRE_R123"),
+                   links(None, Some("https://acme.wd5.myworkdayjobs.com/External")));
+        assert_eq!(infer_from_job_url("https://acme.bamboohr.com/careers/42"),
+                   links(None, Some("https://acme.bamboohr.com/careers")));
+    }
+
+    #[test]
+    fn test_infer_from_company_urls() {
+        assert_eq!(infer_from_job_url("https://careers.acme.com/jobs/123"),
+                   links(Some("acme.com"), Some("https://careers.acme.com")));
+        assert_eq!(infer_from_job_url("https://www.acme.co.uk/about/careers/sre-lead"),
+                   links(Some("acme.co.uk"), Some("https://www.acme.co.uk/about/careers")));
+        assert_eq!(infer_from_job_url("https://acme.io/blog/hiring"), links(Some("acme.io"), None));
+    }
+
+    #[test]
+    fn test_job_boards_and_garbage_tell_nothing() {
+        assert_eq!(infer_from_job_url("https://www.linkedin.com/jobs/view/123"), EmployerLinks::default());
+        assert_eq!(infer_from_job_url("https://news.ycombinator.com/item?id=1"), EmployerLinks::default());
+        assert_eq!(infer_from_job_url("not a url"), EmployerLinks::default());
+    }
+
+    #[test]
+    fn test_canonical_job_url() {
+        for url in [
+            "https://www.linkedin.com/comm/jobs/view/4210614397/?refId=abc&trackingId=xyz&trk=eml",
+            "https://linkedin.com/jobs/view/senior-sre-at-acme-4210614397?lipi=urn",
+            "https://www.linkedin.com/jobs/search/?currentJobId=4210614397&geoId=103644278",
+        ] {
+            assert_eq!(canonical_job_url(url), "https://www.linkedin.com/jobs/view/4210614397", "{}", url);
+        }
+        for url in [
+            "https://www.indeed.com/rc/clk?jk=abc123&from=ja&tk=1h2",
+            "https://www.indeed.com/viewjob?tk=9&jk=abc123",
+            "https://www.indeed.com/pagead/clk?mo=r&ad=xyz&jk=abc123",
+        ] {
+            assert_eq!(canonical_job_url(url), "https://www.indeed.com/viewjob?jk=abc123", "{}", url);
+        }
+        assert_eq!(
+            canonical_job_url("https://boards.greenhouse.io/acme/jobs/123/?gh_src=abc&utm_source=hn#apply"),
+            "https://boards.greenhouse.io/acme/jobs/123"
+        );
+        assert_eq!(
+            canonical_job_url("https://acme.wd5.myworkdayjobs.com/External/job?id=123&utm_medium=email"),
+            "https://acme.wd5.myworkdayjobs.com/External/job?id=123"
+        );
+        assert_eq!(canonical_job_url("https://Jobs.Example.com/"), "https://jobs.example.com/");
+        assert_eq!(canonical_job_url("  not a url "), "not a url");
+    }
+
+    #[test]
+    fn test_resolve_application() {
+        assert_eq!(identify_ats("https://acme.wd5.myworkdayjobs.com/en-US/External/job/SRE_R123"), Some("Workday"));
+        assert_eq!(identify_ats("https://acme.taleo.net/careersection/2/jobdetail.ftl?job=42"), Some("Taleo"));
+        assert_eq!(identify_ats("https://careers-acme.icims.com/jobs/1234/sre/job"), Some("iCIMS"));
+        assert_eq!(identify_ats("https://acme.com/careers/1"), None);
+
+        // Redirected off the aggregator: the landing page is the application
+        let direct = resolve_application("https://boards.greenhouse.io/acme/jobs/123?gh_src=li", "").unwrap();
+        assert_eq!(direct, Application { url: "https://boards.greenhouse.io/acme/jobs/123".to_string(), ats: Some("Greenhouse") });
+        let own_site = resolve_application("https://careers.acme.com/jobs/42", "").unwrap();
+        assert_eq!((own_site.url.as_str(), own_site.ats), ("https://careers.acme.com/jobs/42", None));
+
+        // Still on the aggregator: its outbound apply link, unwrapped
+        let linkedin = r#"<code id="applyUrl" style="display: none"><!--"https://www.linkedin.com/jobs/view/externalApply/123?url=https%3A%2F%2Facme%2Ewd5%2Emyworkdayjobs%2Ecom%2FExternal%2Fjob%2FSRE_R123%3Fsource%3DLinkedIn&amp;urlHash=abc"--></code>"#;
+        let resolved = resolve_application("https://www.linkedin.com/jobs/view/123", linkedin).unwrap();
+        assert_eq!(resolved.url, "https://acme.wd5.myworkdayjobs.com/External/job/SRE_R123");
+        assert_eq!(resolved.ats, Some("Workday"));
+        let plain_link = r#"<a href="https://www.linkedin.com/company/acme">Acme</a> <a href="https://acme.taleo.net/careersection/jobdetail.ftl?job=42&amp;lang=en">Apply</a>"#;
+        assert_eq!(find_ats_link(plain_link).as_deref(), Some("https://acme.taleo.net/careersection/jobdetail.ftl?job=42&lang=en"));
+
+        assert_eq!(resolve_application("https://www.linkedin.com/jobs/view/123", "<button>Easy Apply</button>"), None);
+        assert_eq!(resolve_application("not a url", ""), None);
+
+        let job = |easy_apply: Option<bool>, apply_url: Option<&str>, ats: Option<&str>| Job {
+            url: Some("https://www.linkedin.com/jobs/view/123".to_string()),
+            easy_apply,
+            apply_url: apply_url.map(String::from),
+            ats: ats.map(String::from),
+            ..Default::default()
+        };
+        assert_eq!(apply_label(&job(Some(true), None, None)).as_deref(), Some("LinkedIn Easy Apply"));
+        assert_eq!(apply_label(&job(Some(false), None, None)).as_deref(), Some("employer's site (via LinkedIn)"));
+        assert_eq!(
+            apply_label(&job(Some(false), Some("https://acme.taleo.net/j/1"), Some("Taleo"))).as_deref(),
+            Some("https://acme.taleo.net/j/1 (Taleo)")
+        );
+        let own = Job { url: Some("https://jobs.lever.co/acme/1".to_string()), ..job(None, Some("https://jobs.lever.co/acme/1"), Some("Lever")) };
+        assert_eq!(apply_label(&own).as_deref(), Some("on Lever"));
+        assert_eq!(apply_label(&job(None, None, None)), None);
+    }
+
+    #[test]
+    fn test_infer_from_job_urls_combines() {
+        let combined = infer_from_job_urls([
+            "https://www.linkedin.com/jobs/view/1",
+            "https://jobs.lever.co/acme/2",
+            "https://acme.com/team",
+        ]);
+        assert_eq!(combined, links(Some("acme.com"), Some("https://jobs.lever.co/acme")));
+    }
+
+    #[test]
+    fn test_normalize_domain() {
+        assert_eq!(normalize_domain("https://www.Acme.com/about").as_deref(), Some("acme.com"));
+        assert_eq!(normalize_domain("acme.io").as_deref(), Some("acme.io"));
+        assert_eq!(normalize_domain("NONE"), None);
+        assert_eq!(registrable_domain("jobs.eu.acme.com"), "acme.com");
+    }
+}
@@ -1,17 +1,161 @@
 use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
 use mailparse::{parse_mail, MailHeaderMap};
 use scraper::{Html, Selector};
 use std::collections::HashSet;
 use std::fs;
 use std::io::Write;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use tracing::{debug, info, warn};
+
+use crate::ai::{self, AIProvider};
+use crate::dates;
+use crate::db::{Database, NewJob, extract_pay_range};
+use crate::error::HuntError;
+use crate::domains;
+use crate::location;
+use crate::output;
+use crate::profile::{self, Profiles};
+
+/// Messages requested per IMAP FETCH.
+const FETCH_BATCH: usize = 25;
+
+/// Most of an email's text sent to the AI fallback; alerts list their jobs well before this.
+const AI_FALLBACK_CHARS: usize = 12_000;
+
+/// IMAP sequence set for `ids` (sorted), with runs as ranges: [1, 2, 3, 7] -> "1:3,7".
+fn sequence_set(ids: &[u32]) -> String {
+    let mut parts: Vec<String> = Vec::new();
+    let mut i = 0;
+    while i < ids.len() {
+        let start = ids[i];
+        while i + 1 < ids.len() && ids[i + 1] == ids[i] + 1 {
+            i += 1;
+        }
+        parts.push(if ids[i] == start { start.to_string() } else { format!("{}:{}", start, ids[i]) });
+        i += 1;
+    }
+    parts.join(",")
+}
+
+/// `ids` without the messages whose Message-ID is in the ingestion history, read from
+/// their envelopes in batches. Messages without a Message-ID are kept.
+fn skip_ingested<T: std::io::Read + std::io::Write>(
+    session: &mut imap::Session<T>, db: &Database, ids: &[u32],
+) -> Result<Vec<u32>> {
+    let mut ingested: HashSet<u32> = HashSet::new();
+    for batch in ids.chunks(FETCH_BATCH) {
+        let set = sequence_set(batch);
+        debug!("Fetching envelopes {}", set);
+        let messages = session.fetch(&set, "ENVELOPE")
+            .with_context(|| format!("Failed to fetch envelopes {}", set))?;
+        for message in messages.iter() {
+            let message_id = message.envelope().and_then(|e| e.message_id);
+            if let Some(message_id) = message_id
+                && db.is_email_ingested(&String::from_utf8_lossy(message_id))?
+            {
+                ingested.insert(message.message);
+            }
+        }
+    }
+    Ok(ids.iter().copied().filter(|id| !ingested.contains(id)).collect())
+}
+
+/// Mail synced to disk (mbsync, offlineimap, a client's export) to ingest instead of IMAP.
+pub enum LocalMail {
+    Maildir(PathBuf),
+    Mbox(PathBuf),
+}
+
+impl LocalMail {
+    pub fn path(&self) -> &Path {
+        match self {
+            LocalMail::Maildir(path) | LocalMail::Mbox(path) => path,
+        }
+    }
+
+    /// Every message, raw.
+    fn read(&self) -> Result<Vec<Vec<u8>>> {
+        match self {
+            LocalMail::Maildir(dir) => read_maildir(dir),
+            LocalMail::Mbox(file) => {
+                let data = fs::read(file).with_context(|| format!("Failed to read mbox {}", file.display()))?;
+                Ok(split_mbox(&data))
+            }
+        }
+    }
+}
 
-use crate::db::{Database, extract_pay_range};
+/// The messages in a maildir's `new/` and `cur/`, oldest first by file name.
+fn read_maildir(dir: &Path) -> Result<Vec<Vec<u8>>> {
+    let subdirs = [dir.join("new"), dir.join("cur")];
+    if !subdirs.iter().any(|d| d.is_dir()) {
+        return Err(HuntError::NotFound(format!("{} is not a maildir (no cur/ or new/)", dir.display())).into());
+    }
+    let mut files: Vec<PathBuf> = Vec::new();
+    for subdir in subdirs.iter().filter(|d| d.is_dir()) {
+        for entry in fs::read_dir(subdir).with_context(|| format!("Failed to list {}", subdir.display()))? {
+            let entry = entry?;
+            if entry.file_type()?.is_file() {
+                files.push(entry.path());
+            }
+        }
+    }
+    files.sort_by(|a, b| a.file_name().cmp(&b.file_name()));
+    files.iter()
+        .map(|f| fs::read(f).with_context(|| format!("Failed to read {}", f.display())))
+        .collect()
+}
+
+/// Split an mbox into messages at its "From " separator lines, undoing mboxrd
+/// quoting (">From " in a body is "From ").
+fn split_mbox(data: &[u8]) -> Vec<Vec<u8>> {
+    let mut messages = Vec::new();
+    let mut current: Option<Vec<u8>> = None;
+    for line in data.split_inclusive(|&b| b == b'\n') {
+        if line.starts_with(b"From ") {
+            messages.extend(current.replace(Vec::new()));
+            continue;
+        }
+        // Anything before the first separator isn't a message
+        let Some(message) = current.as_mut() else { continue };
+        let quotes = line.iter().take_while(|&&b| b == b'>').count();
+        let quoted_from = quotes > 0 && line[quotes..].starts_with(b"From ");
+        message.extend_from_slice(if quoted_from { &line[1..] } else { line });
+    }
+    messages.extend(current);
+    messages
+}
+
+/// Whether a message is one of the alerts the IMAP searches in `fetch_account` find:
+/// LinkedIn job mail and anything from Indeed.
+fn is_job_alert(from: &str, subject: &str) -> bool {
+    let from = from.to_lowercase();
+    (from.contains("linkedin.com")
+        && (from.contains("jobs-noreply@") || from.contains("jobalerts-noreply@") || subject.to_lowercase().contains("job")))
+        || from.contains("indeed.com")
+}
 
-/// Run a blocking operation while printing dots to stderr every second.
+fn progress_bar(len: usize) -> indicatif::ProgressBar {
+    if output::quiet() {
+        return indicatif::ProgressBar::hidden();
+    }
+    let bar = indicatif::ProgressBar::new(len as u64);
+    bar.set_style(
+        indicatif::ProgressStyle::with_template("  {bar:30.cyan/blue} {pos}/{len} emails  {elapsed} elapsed, ETA {eta}")
+            .expect("valid progress template")
+            .progress_chars("=> "),
+    );
+    bar
+}
+
+/// Run a blocking operation while printing dots to stderr every second (quietly with `--quiet`).
 fn spin<T, F: FnOnce() -> T>(label: &str, f: F) -> T {
+    if output::quiet() {
+        return f();
+    }
     eprint!("  {} ", label);
     let _ = std::io::stderr().flush();
     let stop = Arc::new(AtomicBool::new(false));
@@ -31,49 +175,426 @@ fn spin<T, F: FnOnce() -> T>(label: &str, f: F) -> T {
     result
 }
 
+/// End the line `spin` started (" ok", " 12 emails"); nothing with `--quiet`, like `spin`.
+fn spun(outcome: impl std::fmt::Display) {
+    if !output::quiet() {
+        eprintln!(" {}", outcome);
+    }
+}
+
 pub struct EmailConfig {
     pub server: String,
     pub port: u16,
     pub username: String,
     pub password: String,
+    pub oauth_token: Option<String>, // XOAUTH2 access token; used instead of the password when set
 }
 
 impl EmailConfig {
     pub fn gmail(username: &str, app_password: &str) -> Self {
+        Self::imap("imap.gmail.com", 993, username, app_password)
+    }
+
+    pub fn imap(server: &str, port: u16, username: &str, password: &str) -> Self {
         Self {
-            server: "imap.gmail.com".to_string(),
-            port: 993,
+            server: server.to_string(),
+            port,
             username: username.to_string(),
-            password: app_password.trim().to_string(),
+            password: password.trim().to_string(),
+            oauth_token: None,
         }
     }
 
+    /// Gmail with XOAUTH2, using the refresh token stored by `hunt email auth`.
+    pub fn gmail_oauth(username: &str) -> Result<Self> {
+        let path = oauth_token_path(username)?;
+        let stored = load_oauth_token(&path).with_context(|| {
+            format!("No OAuth token for {}. Run 'hunt email auth --username {}' first", username, username)
+        })?;
+        let access_token = refresh_access_token(&stored)?;
+        let mut config = Self::gmail(username, "");
+        config.oauth_token = Some(access_token);
+        Ok(config)
+    }
+
     pub fn from_gmail_password_file(username: &str, password_file: &Path) -> Result<Self> {
         let password = fs::read_to_string(password_file)
             .with_context(|| format!("Failed to read password file: {:?}", password_file))?;
         Ok(Self::gmail(username, &password))
     }
+
+    pub fn from_password_file(server: &str, port: u16, username: &str, password_file: &Path) -> Result<Self> {
+        let password = fs::read_to_string(password_file)
+            .with_context(|| format!("Failed to read password file: {:?}", password_file))?;
+        Ok(Self::imap(server, port, username, &password))
+    }
+}
+
+/// An account given on the command line as `USERNAME,PASSWORD_FILE[,SERVER[:PORT]]`.
+#[derive(Debug, PartialEq)]
+pub struct AccountSpec {
+    pub username: String,
+    pub password_file: String,
+    pub server: String,
+    pub port: u16,
+}
+
+impl AccountSpec {
+    pub fn parse(spec: &str) -> Result<Self> {
+        let parts: Vec<&str> = spec.split(',').map(|p| p.trim()).collect();
+        if parts.len() < 2 || parts.len() > 3 || parts[0].is_empty() || parts[1].is_empty() {
+            return Err(HuntError::Validation(format!(
+                "Invalid account '{}'. Expected USERNAME,PASSWORD_FILE[,SERVER[:PORT]]",
+                spec
+            )).into());
+        }
+        let (server, port) = match parts.get(2) {
+            Some(server) => match server.split_once(':') {
+                Some((host, port)) => {
+                    let port = port.parse::<u16>()
+                        .with_context(|| format!("Invalid IMAP port in account '{}'", spec))?;
+                    (host.to_string(), port)
+                }
+                None => (server.to_string(), 993),
+            },
+            None => (default_imap_server(parts[0]), 993),
+        };
+        Ok(Self {
+            username: parts[0].to_string(),
+            password_file: parts[1].to_string(),
+            server,
+            port,
+        })
+    }
+}
+
+/// Guess the IMAP server from the address domain (gmail.com -> imap.gmail.com).
+pub fn default_imap_server(username: &str) -> String {
+    match username.rsplit_once('@').map(|(_, domain)| domain.to_lowercase()) {
+        Some(domain) if domain == "googlemail.com" => "imap.gmail.com".to_string(),
+        Some(domain) if domain == "icloud.com" || domain == "me.com" => "imap.mail.me.com".to_string(),
+        Some(domain) if !domain.is_empty() => format!("imap.{}", domain),
+        _ => "imap.gmail.com".to_string(),
+    }
+}
+
+// --- OAuth2 (XOAUTH2) for Gmail ---
+
+const GOOGLE_AUTH_URL: &str = "https://accounts.google.com/o/oauth2/v2/auth";
+const GOOGLE_TOKEN_URL: &str = "https://oauth2.googleapis.com/token";
+const GMAIL_IMAP_SCOPE: &str = "https://mail.google.com/";
+
+/// How long `hunt email auth` waits for the browser to come back.
+const AUTHORIZATION_TIMEOUT: std::time::Duration = std::time::Duration::from_secs(300);
+
+/// Long-lived credentials saved by `hunt email auth`.
+#[derive(Debug, Serialize, Deserialize, PartialEq)]
+pub struct OAuthToken {
+    pub client_id: String,
+    pub client_secret: String,
+    pub refresh_token: String,
+}
+
+/// An installed-app authorization in progress: the user approves at `url`, and Google
+/// redirects the browser to a listener on 127.0.0.1 with the code.
+pub struct LoopbackAuthorization {
+    pub url: String,
+    server: tiny_http::Server,
+    redirect_uri: String,
+    verifier: String,
+    state: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct TokenResponse {
+    access_token: Option<String>,
+    refresh_token: Option<String>,
+    error: Option<String>,
+    error_description: Option<String>,
+}
+
+/// Where the refresh token for an account is stored (`<config dir>/oauth/<username>.json`,
+/// using the active profile's config directory so profiles don't share tokens).
+pub fn oauth_token_path(username: &str) -> Result<PathBuf> {
+    let file = username.replace(['/', '\\'], "_");
+    Ok(Profiles::xdg().config_dir(profile::active()).join("oauth").join(format!("{}.json", file)))
+}
+
+/// Write the token file readable only by the current user.
+pub fn save_oauth_token(path: &Path, token: &OAuthToken) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    let json = serde_json::to_string_pretty(token)?;
+    let mut options = fs::OpenOptions::new();
+    options.write(true).create(true).truncate(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)
+        .with_context(|| format!("Failed to write token file: {:?}", path))?;
+    file.write_all(json.as_bytes())?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(path, fs::Permissions::from_mode(0o600))?;
+    }
+    Ok(())
+}
+
+pub fn load_oauth_token(path: &Path) -> Result<OAuthToken> {
+    let json = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read token file: {:?}", path))?;
+    serde_json::from_str(&json).context("Invalid token file")
+}
+
+fn oauth_client() -> Result<reqwest::blocking::Client> {
+    Ok(reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .build()?)
+}
+
+fn random_token(len: usize) -> String {
+    use rand::Rng;
+    rand::thread_rng().sample_iter(rand::distributions::Alphanumeric).take(len).map(char::from).collect()
+}
+
+/// PKCE S256 challenge: base64url (unpadded) SHA-256 of the verifier.
+fn pkce_challenge(verifier: &str) -> String {
+    use base64::Engine;
+    use sha2::{Digest, Sha256};
+    base64::engine::general_purpose::URL_SAFE_NO_PAD.encode(Sha256::digest(verifier.as_bytes()))
+}
+
+/// Listen on a free loopback port and build the consent URL that redirects back to it.
+pub fn start_loopback_authorization(client_id: &str) -> Result<LoopbackAuthorization> {
+    let server = tiny_http::Server::http("127.0.0.1:0")
+        .map_err(|e| HuntError::Network(format!("Can't listen on 127.0.0.1: {}", e)))?;
+    let port = server.server_addr().to_ip().map(|addr| addr.port())
+        .ok_or_else(|| anyhow!("Loopback listener has no port"))?;
+    let redirect_uri = format!("http://127.0.0.1:{}", port);
+    let verifier = random_token(64);
+    let state = random_token(24);
+    let url = reqwest::Url::parse_with_params(GOOGLE_AUTH_URL, &[
+        ("client_id", client_id),
+        ("redirect_uri", redirect_uri.as_str()),
+        ("response_type", "code"),
+        ("scope", GMAIL_IMAP_SCOPE),
+        ("code_challenge", pkce_challenge(&verifier).as_str()),
+        ("code_challenge_method", "S256"),
+        ("state", state.as_str()),
+        ("access_type", "offline"),
+        ("prompt", "consent"), // so Google issues a refresh token even on re-authorization
+    ])?;
+    Ok(LoopbackAuthorization { url: url.to_string(), server, redirect_uri, verifier, state })
+}
+
+/// The authorization code in a redirect's request path, or None for a request that isn't
+/// the redirect (a favicon fetch, say).
+fn redirect_code(path: &str, state: &str) -> Result<Option<String>> {
+    let url = reqwest::Url::parse("http://127.0.0.1")?.join(path)?;
+    let param = |name: &str| url.query_pairs().find(|(k, _)| k == name).map(|(_, v)| v.into_owned());
+    if let Some(error) = param("error") {
+        return Err(HuntError::Auth(format!("Authorization failed: {}", error)).into());
+    }
+    let Some(code) = param("code") else {
+        return Ok(None);
+    };
+    if param("state").as_deref() != Some(state) {
+        return Err(HuntError::Auth("Authorization redirect has the wrong state; try again".to_string()).into());
+    }
+    Ok(Some(code))
+}
+
+/// Wait for the browser's redirect, then trade the code (and PKCE verifier) for a refresh token.
+pub fn finish_loopback_authorization(auth: LoopbackAuthorization, client_id: &str, client_secret: &str) -> Result<String> {
+    let deadline = std::time::Instant::now() + AUTHORIZATION_TIMEOUT;
+    let code = loop {
+        let remaining = deadline.saturating_duration_since(std::time::Instant::now());
+        let Some(request) = auth.server.recv_timeout(remaining)? else {
+            return Err(anyhow!("Timed out waiting for authorization in the browser"));
+        };
+        let outcome = redirect_code(request.url(), &auth.state);
+        let page = match &outcome {
+            Ok(Some(_)) => "hunt is authorized. You can close this tab.",
+            Ok(None) => "Waiting for authorization.",
+            Err(_) => "Authorization failed. See the terminal.",
+        };
+        if let Err(e) = request.respond(tiny_http::Response::from_string(page)) {
+            debug!("Couldn't answer the authorization redirect: {}", e);
+        }
+        if let Some(code) = outcome? {
+            break code;
+        }
+    };
+
+    let response: TokenResponse = oauth_client()?
+        .post(GOOGLE_TOKEN_URL)
+        .form(&[
+            ("client_id", client_id),
+            ("client_secret", client_secret),
+            ("code", code.as_str()),
+            ("code_verifier", auth.verifier.as_str()),
+            ("redirect_uri", auth.redirect_uri.as_str()),
+            ("grant_type", "authorization_code"),
+        ])
+        .send()
+        .context("Failed to exchange authorization code")?
+        .json()
+        .context("Failed to parse token response")?;
+    if let Some(error) = response.error {
+        return Err(HuntError::Auth(format!(
+            "Authorization failed: {} {}",
+            error,
+            response.error_description.unwrap_or_default()
+        )).into());
+    }
+    response.refresh_token
+        .ok_or_else(|| anyhow!("Token response did not include a refresh token"))
+}
+
+/// Exchange the stored refresh token for a short-lived access token.
+pub fn refresh_access_token(token: &OAuthToken) -> Result<String> {
+    let response: TokenResponse = oauth_client()?
+        .post(GOOGLE_TOKEN_URL)
+        .form(&[
+            ("client_id", token.client_id.as_str()),
+            ("client_secret", token.client_secret.as_str()),
+            ("refresh_token", token.refresh_token.as_str()),
+            ("grant_type", "refresh_token"),
+        ])
+        .send()
+        .context("Failed to refresh OAuth token")?
+        .json()
+        .context("Failed to parse token response")?;
+
+    if let Some(error) = response.error {
+        return Err(HuntError::Auth(format!(
+            "OAuth token refresh failed: {} {}. Run 'hunt email auth' again",
+            error,
+            response.error_description.unwrap_or_default()
+        )).into());
+    }
+    response.access_token
+        .ok_or_else(|| anyhow!("Token response did not include an access token"))
+}
+
+/// SASL XOAUTH2 initial response (before base64, which the imap crate applies).
+fn xoauth2_response(username: &str, access_token: &str) -> String {
+    format!("user={}\x01auth=Bearer {}\x01\x01", username, access_token)
+}
+
+struct XOAuth2 {
+    username: String,
+    access_token: String,
+}
+
+impl imap::Authenticator for XOAuth2 {
+    type Response = String;
+
+    fn process(&self, _challenge: &[u8]) -> Self::Response {
+        xoauth2_response(&self.username, &self.access_token)
+    }
 }
 
 pub struct EmailIngester {
-    config: EmailConfig,
+    accounts: Vec<EmailConfig>,
+    reprocess: bool,
+    ai_fallback: Option<Box<dyn AIProvider>>,
 }
 
 impl EmailIngester {
-    pub fn new(config: EmailConfig) -> Self {
-        Self { config }
+    pub fn with_accounts(accounts: Vec<EmailConfig>) -> Self {
+        Self { accounts, reprocess: false, ai_fallback: None }
+    }
+
+    /// Ask `provider` for the jobs in emails the HTML parsers find none in.
+    pub fn ai_fallback(mut self, provider: Box<dyn AIProvider>) -> Self {
+        self.ai_fallback = Some(provider);
+        self
+    }
+
+    /// Process emails again even if their Message-ID was ingested on an earlier run.
+    pub fn reprocess(mut self, reprocess: bool) -> Self {
+        self.reprocess = reprocess;
+        self
+    }
+
+    /// Ingest job alerts from every configured account. A failing account is
+    /// recorded in its `AccountIngestStats` and does not stop the others.
+    pub fn fetch_job_alerts(&self, db: &Database, days: u32, dry_run: bool) -> Vec<AccountIngestStats> {
+        self.accounts.iter().map(|config| {
+            if self.accounts.len() > 1 {
+                info!("── {} ({}) ──", config.username, config.server);
+            }
+            match self.fetch_account(config, db, days, dry_run) {
+                Ok(stats) => AccountIngestStats { account: config.username.clone(), stats, error: None },
+                Err(e) => {
+                    spun("failed");
+                    AccountIngestStats {
+                        account: config.username.clone(),
+                        stats: IngestStats::default(),
+                        error: Some(e),
+                    }
+                }
+            }
+        }).collect()
     }
 
-    pub fn fetch_job_alerts(&self, db: &Database, days: u32, dry_run: bool, verbose: bool) -> Result<IngestStats> {
+    /// Ingest job alerts from local mail: the alerts among its messages from the last
+    /// `days` days, skipping ones already ingested unless reprocessing.
+    pub fn ingest_local(&self, source: &LocalMail, db: &Database, days: u32, dry_run: bool) -> Result<IngestStats> {
+        let messages = source.read()?;
+        let cutoff = (chrono::Utc::now() - chrono::Duration::days(days as i64)).timestamp();
+        let mut stats = IngestStats::default();
+        let mut alerts: Vec<&[u8]> = Vec::new();
+        for raw in &messages {
+            let Ok((headers, _)) = mailparse::parse_headers(raw) else {
+                stats.errors += 1;
+                continue;
+            };
+            let header = |name: &str| headers.get_first_value(name).unwrap_or_default();
+            if !is_job_alert(&header("From"), &header("Subject")) {
+                continue;
+            }
+            if mailparse::dateparse(&header("Date")).is_ok_and(|date| date < cutoff) {
+                continue;
+            }
+            if !self.reprocess && let Some(message_id) = headers.get_first_value("Message-ID")
+                && db.is_email_ingested(&message_id)?
+            {
+                debug!("Already ingested: {}", message_id);
+                stats.skipped += 1;
+                continue;
+            }
+            alerts.push(raw);
+        }
+        info!("{} messages in {}, {} job alerts to process, {} already processed",
+            messages.len(), source.path().display(), alerts.len(), stats.skipped);
+        if alerts.is_empty() {
+            return Ok(stats);
+        }
+
+        let bar = progress_bar(alerts.len());
+        for raw in alerts {
+            stats.emails_found += 1;
+            self.report_email(raw, db, dry_run, &mut stats, &bar);
+            bar.inc(1);
+        }
+        bar.finish_and_clear();
+        info!("Processed {} emails in {}", stats.emails_found, indicatif::HumanDuration(bar.elapsed()));
+        Ok(stats)
+    }
+
+    fn fetch_account(&self, config: &EmailConfig, db: &Database, days: u32, dry_run: bool) -> Result<IngestStats> {
         let tls = native_tls::TlsConnector::builder().build()?;
         let timeout = std::time::Duration::from_secs(120);
 
-        let server = self.config.server.clone();
-        let port = self.config.port;
-        if verbose {
-            eprintln!("  [verbose] Timeout: {}s", timeout.as_secs());
-            eprintln!("  [verbose] Server: {}:{}", server, port);
-        }
+        let server = config.server.clone();
+        let port = config.port;
+        debug!("Server: {}:{}, timeout {}s", server, port, timeout.as_secs());
         let (tcp, tls_stream) = spin("Connecting...", || -> Result<_> {
             let tcp = std::net::TcpStream::connect((server.as_str(), port))
                 .context("TCP connection failed — check network/firewall")?;
@@ -84,36 +605,40 @@ impl EmailIngester {
             Ok((tcp, tls_stream))
         })?;
         let _ = tcp; // keep tcp alive
-        eprintln!(" ok");
+        spun("ok");
 
         let client = imap::Client::new(tls_stream);
-        let username = self.config.username.clone();
-        let password = self.config.password.clone();
-        if verbose {
-            eprintln!("  [verbose] Authenticating as: {}", username);
-        }
+        let username = config.username.clone();
+        let password = config.password.clone();
+        debug!("Authenticating as: {} ({})", username,
+            if config.oauth_token.is_some() { "XOAUTH2" } else { "password" });
         let mut session = spin("Logging in...", || {
-            client.login(&username, &password)
+            let result = match &config.oauth_token {
+                Some(access_token) => {
+                    let auth = XOAuth2 { username: username.clone(), access_token: access_token.clone() };
+                    client.authenticate("XOAUTH2", &auth)
+                }
+                None => client.login(&username, &password),
+            };
+            result
                 .map_err(|e| {
                     let msg = e.0.to_string();
                     if msg.contains("os error 11") || msg.contains("temporarily unavailable") {
-                        anyhow!("Login timed out after {}s (server not responding). \
-                                 Try again or check credentials.\n  Raw error: {}", timeout.as_secs(), msg)
+                        HuntError::Network(format!("Login timed out after {}s (server not responding). \
+                                 Try again or check credentials.\n  Raw error: {}", timeout.as_secs(), msg))
                     } else if msg.contains("Invalid credentials") || msg.contains("AUTHENTICATIONFAILED") {
-                        anyhow!("Authentication failed — bad username or app password.\n  Raw error: {}", msg)
+                        HuntError::Auth(format!("Authentication failed — bad username or app password.\n  Raw error: {}", msg))
                     } else {
-                        anyhow!("Login failed: {}", msg)
+                        HuntError::Network(format!("Login failed: {}", msg))
                     }
                 })
         })?;
-        eprintln!(" ok");
+        spun("ok");
 
-        if verbose {
-            eprintln!("  [verbose] Login successful, selecting INBOX");
-        }
+        debug!("Login successful, selecting INBOX");
         spin("Selecting INBOX...", || session.select("INBOX"))
             .context("Failed to select INBOX")?;
-        eprintln!(" ok");
+        spun("ok");
 
         let since_date = chrono::Utc::now() - chrono::Duration::days(days as i64);
         let date_str = since_date.format("%d-%b-%Y").to_string();
@@ -126,12 +651,12 @@ impl EmailIngester {
         ];
 
         let mut stats = IngestStats::default();
-        let mut seen_message_ids: HashSet<String> = HashSet::new();
+        let mut seen_message_ids: HashSet<u32> = HashSet::new();
+        let mut to_fetch: Vec<u32> = Vec::new();
 
+        // Search everything first so the progress bar knows the total
         for (label, query) in &search_queries {
-            if verbose {
-                eprintln!("  [verbose] IMAP SEARCH: {}", query);
-            }
+            debug!("IMAP SEARCH: {}", query);
             let query_clone = query.clone();
             let message_ids = spin(&format!("Searching {}...", label), || {
                 session.search(&query_clone)
@@ -141,94 +666,107 @@ impl EmailIngester {
                 Err(e) => {
                     let msg = e.to_string();
                     if msg.contains("os error 11") || msg.contains("temporarily unavailable") {
-                        eprintln!(" timed out (server too slow)");
+                        spun("timed out");
+                        warn!("Searching {} timed out (server too slow)", label);
                     } else {
-                        eprintln!(" failed: {}", msg);
-                    }
-                    if verbose {
-                        eprintln!("  [verbose] Search error detail: {:?}", e);
+                        spun("failed");
+                        warn!("Searching {} failed: {}", label, msg);
                     }
+                    debug!("Search error detail: {:?}", e);
                     continue;
                 }
             };
 
-            let new_ids: Vec<_> = message_ids.iter()
-                .filter(|id| seen_message_ids.insert(id.to_string()))
+            let mut new_ids: Vec<u32> = message_ids.into_iter()
+                .filter(|id| seen_message_ids.insert(*id))
                 .collect();
-            eprintln!(" {} emails", new_ids.len());
+            new_ids.sort_unstable();
+            spun(format!("{} emails", new_ids.len()));
+            to_fetch.extend(new_ids);
+        }
+        if !self.reprocess && !to_fetch.is_empty() {
+            let before = to_fetch.len();
+            to_fetch = spin("Checking ingestion history...", || {
+                skip_ingested(&mut session, db, &to_fetch)
+            })?;
+            stats.skipped = before - to_fetch.len();
+            spun(format!("{} already processed", stats.skipped));
+        }
+        if to_fetch.is_empty() {
+            session.logout()?;
+            return Ok(stats);
+        }
 
-            if new_ids.is_empty() {
-                continue;
+        let bar = progress_bar(to_fetch.len());
+        // Several messages per FETCH: one round trip per batch instead of per message
+        for batch in to_fetch.chunks(FETCH_BATCH) {
+            let set = sequence_set(batch);
+            bar.suspend(|| debug!("Fetching messages {}", set));
+            let messages = match session.fetch(&set, "RFC822") {
+                Ok(msgs) => msgs,
+                Err(e) => {
+                    stats.emails_found += batch.len();
+                    stats.errors += batch.len();
+                    let msg = e.to_string();
+                    if msg.contains("os error 11") || msg.contains("temporarily unavailable") {
+                        bar.suspend(|| warn!("Error fetching messages {}: timed out", set));
+                    } else {
+                        bar.suspend(|| warn!("Error fetching messages {}: {}", set, msg));
+                    }
+                    bar.suspend(|| debug!("Fetch error detail: {:?}", e));
+                    bar.inc(batch.len() as u64);
+                    continue;
+                }
+            };
+            for message in messages.iter() {
+                stats.emails_found += 1;
+                if let Some(body) = message.body() {
+                    self.report_email(body, db, dry_run, &mut stats, &bar);
+                }
+                bar.inc(1);
             }
+        }
+        bar.finish_and_clear();
+        info!("Fetched {} emails in {}", stats.emails_found, indicatif::HumanDuration(bar.elapsed()));
 
-            for id in new_ids {
-                stats.emails_found += 1;
+        session.logout()?;
+        Ok(stats)
+    }
 
-                if verbose {
-                    eprintln!("  [verbose] Fetching message ID {}", id);
+    /// Process one fetched email and log what it added, above the progress bar (inside
+    /// `suspend`, so the bar doesn't draw over it).
+    fn report_email(&self, body: &[u8], db: &Database, dry_run: bool, stats: &mut IngestStats, bar: &indicatif::ProgressBar) {
+        match self.process_email(body, db, dry_run) {
+            Ok(result) => {
+                let mut lines = vec![format!("{} | {} | {}", result.date, result.from, result.subject)];
+                if result.jobs_found.is_empty() {
+                    lines.push("  (no jobs parsed from this email)".to_string());
                 }
-                let messages = match session.fetch(id.to_string(), "RFC822") {
-                    Ok(msgs) => msgs,
-                    Err(e) => {
-                        stats.errors += 1;
-                        let msg = e.to_string();
-                        if msg.contains("os error 11") || msg.contains("temporarily unavailable") {
-                            eprintln!("\n    Error fetching message {}: timed out", id);
-                        } else {
-                            eprintln!("\n    Error fetching message {}: {}", id, msg);
-                        }
-                        if verbose {
-                            eprintln!("  [verbose] Fetch error detail: {:?}", e);
-                        }
-                        continue;
-                    }
-                };
-                for message in messages.iter() {
-                    if let Some(body) = message.body() {
-                        match self.process_email(body, db, dry_run) {
-                            Ok(result) => {
-                                // Print email header
-                                eprintln!("\n    {} | {} | {}",
-                                    &result.date,
-                                    &result.from,
-                                    &result.subject,
-                                );
-
-                                if result.jobs_found.is_empty() {
-                                    eprintln!("      (no jobs parsed from this email)");
-                                }
-
-                                for jr in &result.jobs_found {
-                                    let tag = match jr.status {
-                                        JobResultStatus::Added => "+ADD",
-                                        JobResultStatus::Duplicate => " DUP",
-                                        JobResultStatus::DryRun => " DRY",
-                                    };
-                                    eprintln!("      [{}] {} at {}", tag, jr.title, jr.employer);
-                                    match jr.status {
-                                        JobResultStatus::Added => stats.jobs_added += 1,
-                                        JobResultStatus::Duplicate => stats.duplicates += 1,
-                                        JobResultStatus::DryRun => {}
-                                    }
-                                }
-                            }
-                            Err(e) => {
-                                stats.errors += 1;
-                                eprintln!("\n    Error processing email: {}", e);
-                                if verbose {
-                                    eprintln!("  [verbose] Processing error detail: {:?}", e);
-                                }
-                            }
-                        }
+                for jr in &result.jobs_found {
+                    let tag = match jr.status {
+                        JobResultStatus::Added => "+ADD",
+                        JobResultStatus::Duplicate => " DUP",
+                        JobResultStatus::DryRun => " DRY",
+                    };
+                    lines.push(format!("  [{}] {} at {}", tag, jr.title, jr.employer));
+                    match jr.status {
+                        JobResultStatus::Added => stats.jobs_added += 1,
+                        JobResultStatus::Duplicate => stats.duplicates += 1,
+                        JobResultStatus::DryRun => {}
                     }
                 }
+                bar.suspend(|| lines.iter().for_each(|line| info!("{}", line)));
+            }
+            Err(e) => {
+                stats.errors += 1;
+                bar.suspend(|| warn!("Error processing email: {}", e));
+                bar.suspend(|| debug!("Processing error detail: {:?}", e));
             }
         }
-
-        session.logout()?;
-        Ok(stats)
     }
 
+    /// Parse one email and add its jobs. Unless `dry_run`, the email's Message-ID is
+    /// recorded so later runs skip it.
     fn process_email(&self, raw: &[u8], db: &Database, dry_run: bool) -> Result<EmailResult> {
         let parsed = parse_mail(raw)?;
 
@@ -244,6 +782,7 @@ impl EmailIngester {
             .headers
             .get_first_value("Date")
             .unwrap_or_default();
+        let message_id = parsed.headers.get_first_value("Message-ID");
 
         let from_lower = from.to_lowercase();
 
@@ -251,38 +790,41 @@ impl EmailIngester {
         let body = get_email_body(&parsed)?;
 
         // Determine source and parse accordingly
-        let jobs = if from_lower.contains("linkedin.com") {
+        let mut jobs = if from_lower.contains("linkedin.com") {
             parse_linkedin_email(&subject, &body)?
         } else if from_lower.contains("indeed.com") {
             parse_indeed_email(&subject, &body)?
         } else {
             parse_generic_job_email(&subject, &body)?
         };
+        if jobs.is_empty() && let Some(provider) = &self.ai_fallback {
+            jobs = parse_email_with_ai(provider.as_ref(), &body)?;
+        }
 
-        let mut job_results = Vec::new();
-        for job in jobs {
-            let employer = job.employer.as_deref().unwrap_or("?").to_string();
-            if dry_run {
-                job_results.push(JobResult {
-                    title: job.title.clone(),
-                    employer,
-                    status: JobResultStatus::DryRun,
-                });
-            } else if job_exists(db, &job)? {
-                job_results.push(JobResult {
-                    title: job.title.clone(),
-                    employer,
-                    status: JobResultStatus::Duplicate,
-                });
-            } else {
-                add_job_from_email(db, &job)?;
-                job_results.push(JobResult {
-                    title: job.title.clone(),
-                    employer,
-                    status: JobResultStatus::Added,
-                });
-            }
+        let received = mailparse::dateparse(&date).ok()
+            .and_then(|at| chrono::DateTime::from_timestamp(at, 0))
+            .map_or_else(dates::today, |at| at.with_timezone(&chrono::Local).date_naive());
+
+        // One transaction per email: an alert can list dozens of jobs
+        let added = if dry_run {
+            vec![None; jobs.len()]
+        } else {
+            db.add_jobs_batch(&jobs.iter().map(|job| job.to_new_job(received)).collect::<Vec<_>>())?
+        };
+        if !dry_run && let Some(message_id) = &message_id {
+            db.mark_email_ingested(message_id, &subject, added.iter().flatten().count())?;
         }
+        let job_results = jobs.iter().zip(added)
+            .map(|(job, id)| JobResult {
+                title: job.title.clone(),
+                employer: job.employer.as_deref().unwrap_or("?").to_string(),
+                status: match (dry_run, id) {
+                    (true, _) => JobResultStatus::DryRun,
+                    (false, Some(_)) => JobResultStatus::Added,
+                    (false, None) => JobResultStatus::Duplicate,
+                },
+            })
+            .collect();
 
         Ok(EmailResult {
             subject,
@@ -336,7 +878,6 @@ pub struct ParsedJob {
     pub title: String,
     pub employer: Option<String>,
     pub url: Option<String>,
-    #[allow(dead_code)]
     pub location: Option<String>,
     pub pay_min: Option<i64>,
     pub pay_max: Option<i64>,
@@ -344,6 +885,31 @@ pub struct ParsedJob {
     pub raw_text: String,
 }
 
+impl ParsedJob {
+    /// The row to store, with the work mode from the location or, failing that, the text.
+    /// Dates in the text count from `received`; alerts announce new postings, so a job
+    /// that doesn't say when it was posted counts as posted the day the email came.
+    fn to_new_job(&self, received: chrono::NaiveDate) -> NewJob {
+        let work_mode = self.location.as_deref()
+            .and_then(location::work_mode_of_location)
+            .or_else(|| location::work_mode_of_text(&self.raw_text));
+        let found = dates::from_text(&self.raw_text, received);
+        NewJob {
+            title: self.title.clone(),
+            employer: self.employer.clone(),
+            url: self.url.clone(),
+            source: Some(self.source.clone()),
+            pay_min: self.pay_min,
+            pay_max: self.pay_max,
+            raw_text: Some(self.raw_text.clone()),
+            location: self.location.clone(),
+            work_mode,
+            posted_at: Some(dates::format_day(found.posted.unwrap_or(received))),
+            apply_deadline: found.deadline(),
+        }
+    }
+}
+
 fn is_navigation_artifact(text: &str) -> bool {
     let text_lower = text.to_lowercase();
     let text_trimmed = text.trim();
@@ -525,6 +1091,46 @@ fn parse_generic_job_email(_subject: &str, body: &str) -> Result<Vec<ParsedJob>>
     extract_jobs_from_text(&text, "email")
 }
 
+/// Jobs the AI finds in an email the parsers couldn't read, sourced "email-ai". It sees
+/// the email's text and links; a link that isn't in the email is dropped, not stored.
+fn parse_email_with_ai(provider: &dyn AIProvider, body: &str) -> Result<Vec<ParsedJob>> {
+    let document = Html::parse_document(body);
+    let text: Vec<&str> = document.root_element().text().map(str::trim).filter(|t| !t.is_empty()).collect();
+    let selector = Selector::parse("a[href]").unwrap();
+    let links: Vec<(String, &str)> = document.select(&selector)
+        .filter_map(|a| {
+            let href = a.value().attr("href")?;
+            href.starts_with("http").then(|| (a.text().collect::<String>().trim().to_string(), href))
+        })
+        .collect();
+    let mut email_text = text.join("\n");
+    if !links.is_empty() {
+        email_text.push_str("\n\nLinks:\n");
+        email_text.push_str(&links.iter().map(|(text, href)| format!("{} -> {}", text, href)).collect::<Vec<_>>().join("\n"));
+    }
+    let email_text: String = email_text.chars().take(AI_FALLBACK_CHARS).collect();
+
+    let in_email = |url: &str| links.iter().any(|(_, href)| *href == url) || body.contains(url);
+    Ok(ai::extract_alert_jobs(provider, &email_text)?
+        .into_iter()
+        .map(|job| {
+            let (pay_min, pay_max) = job.pay.as_deref().map_or((None, None), extract_pay_range);
+            let raw_text = [Some(job.title.as_str()), job.employer.as_deref(), job.pay.as_deref()]
+                .into_iter().flatten().collect::<Vec<_>>().join(" · ");
+            ParsedJob {
+                title: job.title,
+                employer: job.employer,
+                url: job.url.filter(|url| in_email(url)).and_then(|url| clean_tracking_url(&url)),
+                location: None,
+                pay_min,
+                pay_max,
+                source: "email-ai".to_string(),
+                raw_text,
+            }
+        })
+        .collect())
+}
+
 fn extract_jobs_from_text(text: &str, source: &str) -> Result<Vec<ParsedJob>> {
     let mut jobs = Vec::new();
 
@@ -647,42 +1253,10 @@ fn parse_title_at_company(text: &str) -> (String, Option<String>) {
 
 fn clean_tracking_url(url: &str) -> Option<String> {
     // LinkedIn and Indeed wrap URLs in tracking redirects
-    // Strip query parameters (everything after ?) as they are tracking garbage
     if url.is_empty() {
         return None;
     }
-
-    // Remove everything after the ? (query parameters)
-    let clean_url = if let Some(idx) = url.find('?') {
-        &url[..idx]
-    } else {
-        url
-    };
-
-    Some(clean_url.to_string())
-}
-
-fn job_exists(db: &Database, job: &ParsedJob) -> Result<bool> {
-    // Use sophisticated duplicate detection
-    let duplicate_id = db.is_duplicate_job(
-        &job.title,
-        job.employer.as_deref(),
-        job.url.as_deref(),
-    )?;
-
-    Ok(duplicate_id.is_some())
-}
-
-fn add_job_from_email(db: &Database, job: &ParsedJob) -> Result<i64> {
-    db.add_job_full(
-        &job.title,
-        job.employer.as_deref(),
-        job.url.as_deref(),
-        Some(&job.source),
-        job.pay_min,
-        job.pay_max,
-        Some(&job.raw_text),
-    )
+    Some(domains::canonical_job_url(url))
 }
 
 #[derive(Debug, Default)]
@@ -691,6 +1265,53 @@ pub struct IngestStats {
     pub jobs_added: usize,
     pub duplicates: usize,
     pub errors: usize,
+    pub skipped: usize, // already ingested on an earlier run
+}
+
+impl IngestStats {
+    /// Sum stats across accounts. An account that failed to connect counts as one error.
+    pub fn total(accounts: &[AccountIngestStats]) -> Self {
+        let mut total = Self::default();
+        for a in accounts {
+            total.emails_found += a.stats.emails_found;
+            total.jobs_added += a.stats.jobs_added;
+            total.duplicates += a.stats.duplicates;
+            total.skipped += a.stats.skipped;
+            total.errors += a.stats.errors + usize::from(a.error.is_some());
+        }
+        total
+    }
+}
+
+#[derive(Debug)]
+pub struct AccountIngestStats {
+    pub account: String,
+    pub stats: IngestStats,
+    pub error: Option<anyhow::Error>, // connection/login failure for this account
+}
+
+impl AccountIngestStats {
+    /// How a run over these accounts failed, if it did: when every account failed, an auth
+    /// error if all were rejected logins (else a network error); when only some did, a
+    /// partial failure. Callers print the per-account results either way.
+    pub fn failure(results: &[AccountIngestStats]) -> Option<HuntError> {
+        let failed: Vec<&AccountIngestStats> = results.iter().filter(|r| r.error.is_some()).collect();
+        if failed.is_empty() {
+            return None;
+        }
+        let reasons: Vec<String> = failed.iter()
+            .filter_map(|r| r.error.as_ref().map(|e| format!("{}: {}", r.account, e)))
+            .collect();
+        if failed.len() < results.len() {
+            return Some(HuntError::Partial(format!(
+                "{} of {} email accounts failed:\n  {}", failed.len(), results.len(), reasons.join("\n  ")
+            )));
+        }
+        let message = format!("Email fetch failed:\n  {}", reasons.join("\n  "));
+        let rejected = failed.iter()
+            .all(|r| r.error.as_ref().is_some_and(|e| crate::error::report(e).kind == "auth"));
+        Some(if rejected { HuntError::Auth(message) } else { HuntError::Network(message) })
+    }
 }
 
 #[derive(Debug)]
@@ -877,11 +1498,11 @@ mod tests {
             Some("https://www.linkedin.com/jobs/view/123456".to_string())
         );
 
-        // Test with Indeed URL
+        // Test with Indeed URL: the job key stays
         let url2 = "https://www.indeed.com/viewjob?jk=123&tk=456&from=email";
         assert_eq!(
             clean_tracking_url(url2),
-            Some("https://www.indeed.com/viewjob".to_string())
+            Some("https://www.indeed.com/viewjob?jk=123".to_string())
         );
 
         // Test URL without query params (should remain unchanged)
@@ -894,11 +1515,11 @@ mod tests {
         // Test empty URL
         assert_eq!(clean_tracking_url(""), None);
 
-        // Test URL with fragment after query (should strip both)
-        let url4 = "https://example.com/job?id=123#section";
+        // Test URL with fragment after query (keeps the ID, strips the rest)
+        let url4 = "https://example.com/job?id=123&utm_source=alert#section";
         assert_eq!(
             clean_tracking_url(url4),
-            Some("https://example.com/job".to_string())
+            Some("https://example.com/job?id=123".to_string())
         );
     }
 
@@ -939,6 +1560,88 @@ mod tests {
         assert_eq!(config.password, "test-password");
     }
 
+    #[test]
+    fn test_account_spec_parse_defaults_server_from_domain() {
+        let spec = AccountSpec::parse("me@fastmail.com,~/.fastmail.pw").unwrap();
+        assert_eq!(spec.username, "me@fastmail.com");
+        assert_eq!(spec.password_file, "~/.fastmail.pw");
+        assert_eq!(spec.server, "imap.fastmail.com");
+        assert_eq!(spec.port, 993);
+    }
+
+    #[test]
+    fn test_account_spec_parse_explicit_server_and_port() {
+        let spec = AccountSpec::parse("me@example.org, /tmp/pw, mail.example.org:1993").unwrap();
+        assert_eq!(spec.server, "mail.example.org");
+        assert_eq!(spec.port, 1993);
+
+        let spec = AccountSpec::parse("me@example.org,/tmp/pw,mail.example.org").unwrap();
+        assert_eq!(spec.port, 993);
+    }
+
+    #[test]
+    fn test_account_spec_parse_invalid() {
+        assert!(AccountSpec::parse("me@example.org").is_err());
+        assert!(AccountSpec::parse(",/tmp/pw").is_err());
+        assert!(AccountSpec::parse("me@example.org,/tmp/pw,host:notaport").is_err());
+        assert!(AccountSpec::parse("a,b,c,d").is_err());
+    }
+
+    #[test]
+    fn test_default_imap_server() {
+        assert_eq!(default_imap_server("a@gmail.com"), "imap.gmail.com");
+        assert_eq!(default_imap_server("a@googlemail.com"), "imap.gmail.com");
+        assert_eq!(default_imap_server("a@icloud.com"), "imap.mail.me.com");
+        assert_eq!(default_imap_server("a@Fastmail.com"), "imap.fastmail.com");
+        assert_eq!(default_imap_server("nodomain"), "imap.gmail.com");
+    }
+
+    #[test]
+    fn test_ingest_stats_total() {
+        let accounts = vec![
+            AccountIngestStats {
+                account: "a@gmail.com".to_string(),
+                stats: IngestStats { emails_found: 3, jobs_added: 2, duplicates: 1, errors: 0, skipped: 4 },
+                error: None,
+            },
+            AccountIngestStats {
+                account: "b@fastmail.com".to_string(),
+                stats: IngestStats { emails_found: 1, jobs_added: 1, duplicates: 0, errors: 1, skipped: 0 },
+                error: None,
+            },
+            AccountIngestStats {
+                account: "c@example.org".to_string(),
+                stats: IngestStats::default(),
+                error: Some(anyhow!("Login failed")),
+            },
+        ];
+        let total = IngestStats::total(&accounts);
+        assert_eq!(total.emails_found, 4);
+        assert_eq!(total.jobs_added, 3);
+        assert_eq!(total.duplicates, 1);
+        assert_eq!(total.errors, 2);
+        assert_eq!(total.skipped, 4);
+    }
+
+    #[test]
+    fn test_account_failure() {
+        let account = |name: &str, error: Option<anyhow::Error>| AccountIngestStats {
+            account: name.to_string(),
+            stats: IngestStats::default(),
+            error,
+        };
+        let rejected = || Some(HuntError::Auth("Authentication failed".to_string()).into());
+
+        assert!(AccountIngestStats::failure(&[account("a", None)]).is_none());
+        let some = AccountIngestStats::failure(&[account("a", None), account("b", rejected())]).unwrap();
+        assert_eq!(some.kind(), "partial");
+        assert_eq!(some.to_string(), "1 of 2 email accounts failed:\n  b: Authentication failed");
+        let all = AccountIngestStats::failure(&[account("a", rejected()), account("b", rejected())]).unwrap();
+        assert_eq!(all.kind(), "auth");
+        let mixed = AccountIngestStats::failure(&[account("a", rejected()), account("b", Some(anyhow!("timed out")))]).unwrap();
+        assert_eq!(mixed.kind(), "network");
+    }
+
     #[test]
     fn test_email_config_trims_password() {
         let config = EmailConfig::gmail("user@gmail.com", "test-password\n\r  ");
@@ -980,7 +1683,7 @@ mod tests {
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].title, "Staff DevOps Engineer, DevInfra");
         assert_eq!(result[0].employer, Some("SandboxAQ".to_string()));
-        assert_eq!(result[0].url, Some("https://www.linkedin.com/comm/jobs/view/12345".to_string()));
+        assert_eq!(result[0].url, Some("https://www.linkedin.com/jobs/view/12345".to_string()));
         assert_eq!(result[0].source, "linkedin");
         assert_eq!(result[1].title, "Senior Platform Engineer");
         assert_eq!(result[1].employer, Some("Sully.ai".to_string()));
@@ -1053,7 +1756,7 @@ mod tests {
         assert_eq!(result.len(), 2);
         assert_eq!(result[0].title, "Senior DevOps Engineer");
         assert_eq!(result[0].employer, Some("Amazon".to_string()));
-        assert_eq!(result[0].url, Some("https://www.indeed.com/viewjob".to_string()));
+        assert_eq!(result[0].url, Some("https://www.indeed.com/viewjob?jk=abc123".to_string()));
         assert_eq!(result[0].source, "indeed");
         assert_eq!(result[1].title, "Platform Engineer");
         assert_eq!(result[1].employer, Some("Netflix".to_string()));
@@ -1140,10 +1843,10 @@ PDF bytes\r\n\
     }
 
     #[test]
-    fn test_clean_tracking_url_preserves_path() {
+    fn test_clean_tracking_url_normalizes_linkedin_path() {
         assert_eq!(
             clean_tracking_url("https://www.linkedin.com/comm/jobs/view/4210614397"),
-            Some("https://www.linkedin.com/comm/jobs/view/4210614397".to_string())
+            Some("https://www.linkedin.com/jobs/view/4210614397".to_string())
         );
     }
 
@@ -1183,7 +1886,7 @@ PDF bytes\r\n\
     fn test_process_email_linkedin() {
         let db = test_db();
         let config = EmailConfig::gmail("test@gmail.com", "pass");
-        let ingester = EmailIngester::new(config);
+        let ingester = EmailIngester::with_accounts(vec![config]);
 
         let raw = format!(
             "From: jobs-noreply@linkedin.com\r\n\
@@ -1208,7 +1911,7 @@ PDF bytes\r\n\
     fn test_process_email_indeed() {
         let db = test_db();
         let config = EmailConfig::gmail("test@gmail.com", "pass");
-        let ingester = EmailIngester::new(config);
+        let ingester = EmailIngester::with_accounts(vec![config]);
 
         let raw = "From: noreply@indeed.com\r\n\
              Subject: New jobs for you\r\n\
@@ -1229,7 +1932,7 @@ PDF bytes\r\n\
     fn test_process_email_dry_run() {
         let db = test_db();
         let config = EmailConfig::gmail("test@gmail.com", "pass");
-        let ingester = EmailIngester::new(config);
+        let ingester = EmailIngester::with_accounts(vec![config]);
 
         let raw = "From: jobs-noreply@linkedin.com\r\n\
              Subject: Jobs\r\n\
@@ -1253,7 +1956,7 @@ PDF bytes\r\n\
     fn test_process_email_duplicate_detection() {
         let db = test_db();
         let config = EmailConfig::gmail("test@gmail.com", "pass");
-        let ingester = EmailIngester::new(config);
+        let ingester = EmailIngester::with_accounts(vec![config]);
 
         // Add a job with a specific URL first
         db.add_job_full("Platform Engineer", Some("Acme"), Some("https://www.linkedin.com/comm/jobs/view/333"), None, None, None, None).unwrap();
@@ -1277,7 +1980,7 @@ PDF bytes\r\n\
     fn test_process_email_generic() {
         let db = test_db();
         let config = EmailConfig::gmail("test@gmail.com", "pass");
-        let ingester = EmailIngester::new(config);
+        let ingester = EmailIngester::with_accounts(vec![config]);
 
         let raw = "From: recruiter@company.com\r\n\
              Subject: Job opportunity\r\n\
@@ -1292,7 +1995,7 @@ PDF bytes\r\n\
     }
 
     #[test]
-    fn test_job_exists_returns_false_for_new() {
+    fn test_batch_adds_new_job() {
         let db = test_db();
         let job = ParsedJob {
             title: "New Unique Job".to_string(),
@@ -1302,11 +2005,11 @@ PDF bytes\r\n\
             source: "test".to_string(),
             raw_text: "test".to_string(),
         };
-        assert!(!job_exists(&db, &job).unwrap());
+        assert!(db.add_jobs_batch(&[job.to_new_job(dates::today())]).unwrap()[0].is_some());
     }
 
     #[test]
-    fn test_job_exists_returns_true_for_duplicate() {
+    fn test_batch_skips_duplicate() {
         let db = test_db();
         db.add_job_full("DevOps Engineer", Some("Acme"), None, None, None, None, None).unwrap();
 
@@ -1318,11 +2021,11 @@ PDF bytes\r\n\
             source: "test".to_string(),
             raw_text: "test".to_string(),
         };
-        assert!(job_exists(&db, &job).unwrap());
+        assert_eq!(db.add_jobs_batch(&[job.to_new_job(dates::today())]).unwrap(), vec![None]);
     }
 
     #[test]
-    fn test_add_job_from_email_stores_job() {
+    fn test_batch_stores_job() {
         let db = test_db();
         let job = ParsedJob {
             title: "Platform Engineer".to_string(),
@@ -1332,16 +2035,18 @@ PDF bytes\r\n\
             pay_min: Some(150000),
             pay_max: Some(250000),
             source: "linkedin".to_string(),
-            raw_text: "Full job description".to_string(),
+            raw_text: "Full job description\nApply by August 1, 2025".to_string(),
         };
 
-        let id = add_job_from_email(&db, &job).unwrap();
-        assert!(id > 0);
+        let received = chrono::NaiveDate::from_ymd_opt(2025, 7, 10).unwrap();
+        let id = db.add_jobs_batch(&[job.to_new_job(received)]).unwrap()[0].unwrap();
 
         let stored = db.get_job(id).unwrap().unwrap();
         assert_eq!(stored.title, "Platform Engineer");
         assert_eq!(stored.pay_min, Some(150000));
         assert_eq!(stored.pay_max, Some(250000));
+        assert_eq!(stored.posted_at.as_deref(), Some("2025-07-10"), "posted the day the alert came");
+        assert_eq!(stored.apply_deadline.as_deref(), Some("2025-08-01"));
     }
 
     #[test]
@@ -1351,6 +2056,56 @@ PDF bytes\r\n\
         assert_eq!(result, 42);
     }
 
+    #[test]
+    fn test_sequence_set() {
+        assert_eq!(sequence_set(&[1, 2, 3, 7, 9, 10]), "1:3,7,9:10");
+        assert_eq!(sequence_set(&[42]), "42");
+        assert_eq!(sequence_set(&[]), "");
+    }
+
+    #[test]
+    fn test_split_mbox() {
+        let mbox = b"From a@x Mon Feb  9 12:00:00 2026\nSubject: one\n\n>From the top\n>>From here\n\n\
+                     From b@x Tue Feb 10 12:00:00 2026\nSubject: two\n\nbody\n";
+        let messages = split_mbox(mbox);
+        assert_eq!(messages.len(), 2);
+        assert_eq!(messages[0], b"Subject: one\n\nFrom the top\n>From here\n\n");
+        assert_eq!(messages[1], b"Subject: two\n\nbody\n");
+        assert!(split_mbox(b"no separator\n").is_empty());
+    }
+
+    #[test]
+    fn test_ingest_local_maildir() {
+        let dir = std::env::temp_dir().join(format!("hunt-maildir-test-{}", std::process::id()));
+        fs::create_dir_all(dir.join("cur")).unwrap();
+        fs::create_dir_all(dir.join("new")).unwrap();
+        let email = |from: &str, date: &str, id: &str, job: u32| format!(
+            "From: {}\r\nSubject: New jobs\r\nDate: {}\r\nMessage-ID: <{}@mail>\r\nContent-Type: text/html\r\n\r\n\
+             <a href=\"https://www.linkedin.com/comm/jobs/view/{}\">Site Reliability Engineer             Acme · Remote</a>",
+            from, date, id, job
+        );
+        let now = chrono::Utc::now().to_rfc2822();
+        fs::write(dir.join("cur/1"), email("jobs-noreply@linkedin.com", &now, "a", 111)).unwrap();
+        fs::write(dir.join("new/2"), email("friend@example.com", &now, "b", 222)).unwrap();
+        fs::write(dir.join("new/3"), email("jobs-noreply@linkedin.com", "Mon, 10 Feb 2020 12:00:00 +0000", "c", 333)).unwrap();
+
+        let db = test_db();
+        let ingester = EmailIngester::with_accounts(Vec::new());
+        let maildir = LocalMail::Maildir(dir.clone());
+        let stats = ingester.ingest_local(&maildir, &db, 7, false).unwrap();
+        assert_eq!((stats.emails_found, stats.jobs_added, stats.skipped), (1, 1, 0), "not an alert, too old");
+
+        let stats = ingester.ingest_local(&maildir, &db, 7, false).unwrap();
+        assert_eq!((stats.emails_found, stats.skipped), (0, 1));
+        let stats = EmailIngester::with_accounts(Vec::new()).reprocess(true)
+            .ingest_local(&maildir, &db, 7, false).unwrap();
+        assert_eq!((stats.emails_found, stats.duplicates), (1, 1));
+
+        let err = ingester.ingest_local(&LocalMail::Maildir(dir.join("cur")), &db, 7, false).unwrap_err();
+        assert!(matches!(err.downcast_ref::<HuntError>(), Some(HuntError::NotFound(_))));
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
     #[test]
     fn test_ingest_stats_default() {
         let stats = IngestStats::default();
@@ -1375,6 +2130,62 @@ PDF bytes\r\n\
         std::fs::remove_file(&path).unwrap();
     }
 
+    #[test]
+    fn test_xoauth2_response_format() {
+        assert_eq!(
+            xoauth2_response("user@gmail.com", "ya29.token"),
+            "user=user@gmail.com\x01auth=Bearer ya29.token\x01\x01"
+        );
+    }
+
+    #[test]
+    fn test_oauth_token_roundtrip() {
+        let path = std::env::temp_dir().join("hunt_test_oauth").join("user@gmail.com.json");
+        let token = OAuthToken {
+            client_id: "id".to_string(),
+            client_secret: "secret".to_string(),
+            refresh_token: "1//refresh".to_string(),
+        };
+        save_oauth_token(&path, &token).unwrap();
+        assert_eq!(load_oauth_token(&path).unwrap(), token);
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_pkce_challenge() {
+        assert_eq!(pkce_challenge("dBjftJeZ4CVP-mJ92IeQ6PNJCD84e3X4s9QhaeYNlPE"), "cranU8lYNIA_gmRIGR4Tr5mUbl-3TVSyVL2EV9_bvug");
+        assert_eq!(random_token(64).len(), 64);
+    }
+
+    #[test]
+    fn test_redirect_code() {
+        assert_eq!(redirect_code("/?state=s1&code=4%2Fabc&scope=x", "s1").unwrap().as_deref(), Some("4/abc"));
+        assert_eq!(redirect_code("/favicon.ico", "s1").unwrap(), None);
+        assert!(redirect_code("/?state=other&code=abc", "s1").is_err());
+        assert!(redirect_code("/?error=access_denied&state=s1", "s1").is_err());
+    }
+
+    #[test]
+    fn test_loopback_authorization_reports_denial() {
+        let auth = start_loopback_authorization("client").unwrap();
+        assert!(auth.url.starts_with(GOOGLE_AUTH_URL));
+        assert!(auth.url.contains("code_challenge_method=S256"));
+        assert!(auth.url.contains(&format!("state={}", auth.state)));
+        let denied = format!("{}/?error=access_denied&state={}", auth.redirect_uri, auth.state);
+        let browser = std::thread::spawn(move || reqwest::blocking::get(denied).map(|r| r.status()));
+        let err = finish_loopback_authorization(auth, "client", "secret").unwrap_err();
+        assert!(err.to_string().contains("access_denied"), "{}", err);
+        assert!(browser.join().unwrap().unwrap().is_success());
+    }
+
     #[test]
     fn test_from_gmail_password_file_missing() {
         let path = std::path::Path::new("/tmp/nonexistent_password_file_xyz.txt");
@@ -1386,7 +2197,7 @@ PDF bytes\r\n\
     fn test_process_email_no_jobs() {
         let db = test_db();
         let config = EmailConfig::gmail("test@gmail.com", "pass");
-        let ingester = EmailIngester::new(config);
+        let ingester = EmailIngester::with_accounts(vec![config]);
 
         let raw = "From: jobs-noreply@linkedin.com\r\n\
              Subject: Updates\r\n\
@@ -1399,11 +2210,48 @@ PDF bytes\r\n\
         assert!(result.jobs_found.is_empty());
     }
 
+    struct AlertProvider;
+
+    impl AIProvider for AlertProvider {
+        fn complete(&self, prompt: &str, _max_tokens: u32) -> Result<String> {
+            assert!(prompt.contains("Head of Reliability -> https://careers.acme.com/jobs/42?utm_source=alert&x=1"), "links are listed");
+            Ok("JOB: Head of Reliability | Acme | https://careers.acme.com/jobs/42?utm_source=alert&x=1 | $180K - $220K/yr\n\
+                JOB: Database Administrator | Globex | https://globex.com/made-up | NONE".to_string())
+        }
+        fn model_name(&self) -> &str { "mock" }
+    }
+
+    #[test]
+    fn test_process_email_ai_fallback() {
+        let db = test_db();
+        let raw = "From: alerts@jobboard.example\r\n\
+             Subject: Your weekly matches\r\n\
+             Content-Type: text/html\r\n\
+             \r\n\
+             <html><body><p>Matches for you</p>\
+             <a href=\"https://careers.acme.com/jobs/42?utm_source=alert&amp;x=1\">Head of Reliability</a> Acme, $180K - $220K/yr\
+             <p>Database Administrator at Globex</p></body></html>";
+
+        let plain = EmailIngester::with_accounts(Vec::new());
+        assert!(plain.process_email(raw.as_bytes(), &db, false).unwrap().jobs_found.is_empty());
+
+        let ingester = EmailIngester::with_accounts(Vec::new()).ai_fallback(Box::new(AlertProvider));
+        let result = ingester.process_email(raw.as_bytes(), &db, false).unwrap();
+        assert_eq!(result.jobs_found.len(), 2);
+        let jobs = db.list_jobs(None, None).unwrap();
+        let head = jobs.iter().find(|j| j.title == "Head of Reliability").unwrap();
+        assert_eq!(head.source.as_deref(), Some("email-ai"));
+        assert_eq!(head.url.as_deref(), Some("https://careers.acme.com/jobs/42?x=1"));
+        assert_eq!((head.pay_min, head.pay_max), (Some(180_000), Some(220_000)));
+        let dba = jobs.iter().find(|j| j.title == "Database Administrator").unwrap();
+        assert_eq!(dba.url, None, "links not in the email are dropped");
+    }
+
     #[test]
     fn test_process_email_multiple_linkedin_jobs() {
         let db = test_db();
         let config = EmailConfig::gmail("test@gmail.com", "pass");
-        let ingester = EmailIngester::new(config);
+        let ingester = EmailIngester::with_accounts(vec![config]);
 
         let raw = "From: jobs-noreply@linkedin.com\r\n\
              Subject: 3 new jobs\r\n\
@@ -0,0 +1,124 @@
+/// Legal-form suffixes and filler that don't distinguish one company from another.
+const NOISE_WORDS: &[&str] = &[
+    "inc", "incorporated", "llc", "llp", "ltd", "limited", "corp", "corporation", "co", "company",
+    "gmbh", "plc", "sa", "ag", "bv", "pty", "the",
+];
+
+/// Similarity at or above which two different names are suggested as duplicates.
+pub const FUZZY_THRESHOLD: f64 = 0.88;
+
+/// Comparison key for an employer name: lowercase words without punctuation or legal
+/// suffixes. "Acme, Inc." and "ACME Inc" both become "acme".
+pub fn normalize_name(name: &str) -> String {
+    let lowered = name.to_lowercase().replace('&', " and ");
+    let words: Vec<&str> = lowered
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty() && !NOISE_WORDS.contains(w))
+        .collect();
+    if words.is_empty() {
+        // A name made only of noise ("The Company") is its own key
+        return lowered.split_whitespace().collect::<Vec<_>>().join(" ");
+    }
+    words.join(" ")
+}
+
+/// 1.0 for identical normalized names, falling with edit distance.
+pub fn similarity(a: &str, b: &str) -> f64 {
+    strsim::normalized_levenshtein(&normalize_name(a), &normalize_name(b))
+}
+
+/// An employer as far as dedupe cares.
+#[derive(Debug, Clone)]
+pub struct EmployerEntry {
+    pub id: i64,
+    pub name: String,
+    pub jobs: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct MergeSuggestion {
+    pub from: EmployerEntry,
+    pub into: EmployerEntry,
+    pub similarity: f64, // 1.0 = same normalized name
+}
+
+impl MergeSuggestion {
+    pub fn is_exact(&self) -> bool {
+        self.similarity >= 1.0
+    }
+}
+
+/// Pairs of employers that look like the same company. Each duplicate is merged into
+/// the entry with the most jobs (then the oldest), so chains collapse onto one survivor.
+pub fn suggest_merges(employers: &[EmployerEntry]) -> Vec<MergeSuggestion> {
+    let keys: Vec<String> = employers.iter().map(|e| normalize_name(&e.name)).collect();
+    let keeper_rank = |e: &EmployerEntry| (std::cmp::Reverse(e.jobs), e.id);
+
+    let mut suggestions: Vec<MergeSuggestion> = Vec::new();
+    let mut merged = vec![false; employers.len()];
+    let mut order: Vec<usize> = (0..employers.len()).collect();
+    order.sort_by_key(|&i| keeper_rank(&employers[i]));
+
+    for (pos, &keep) in order.iter().enumerate() {
+        if merged[keep] {
+            continue;
+        }
+        for &other in &order[pos + 1..] {
+            if merged[other] {
+                continue;
+            }
+            let score = if keys[keep] == keys[other] { 1.0 } else { similarity(&employers[keep].name, &employers[other].name) };
+            if score >= FUZZY_THRESHOLD {
+                merged[other] = true;
+                suggestions.push(MergeSuggestion {
+                    from: employers[other].clone(),
+                    into: employers[keep].clone(),
+                    similarity: score,
+                });
+            }
+        }
+    }
+    suggestions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: i64, name: &str, jobs: i64) -> EmployerEntry {
+        EmployerEntry { id, name: name.to_string(), jobs }
+    }
+
+    #[test]
+    fn test_normalize_name() {
+        assert_eq!(normalize_name("Acme, Inc."), "acme");
+        assert_eq!(normalize_name("ACME Inc"), "acme");
+        assert_eq!(normalize_name("The Acme Company LLC"), "acme");
+        assert_eq!(normalize_name("Procter & Gamble Co."), "procter and gamble");
+        assert_eq!(normalize_name("The Company"), "the company");
+    }
+
+    #[test]
+    fn test_similarity() {
+        assert_eq!(similarity("Acme", "Acme Inc."), 1.0);
+        assert!(similarity("Datadog", "DataDog Inc") >= FUZZY_THRESHOLD);
+        assert!(similarity("Stripe", "Strype") < FUZZY_THRESHOLD, "short names need an exact match");
+        assert!(similarity("Shopify", "Spotify") < FUZZY_THRESHOLD);
+        assert!(similarity("Cloudflare", "Cloudfare") >= FUZZY_THRESHOLD);
+    }
+
+    #[test]
+    fn test_suggest_merges_picks_busiest_survivor() {
+        let employers = vec![
+            entry(1, "Acme Inc.", 1),
+            entry(2, "Acme", 4),
+            entry(3, "Acme, Inc", 0),
+            entry(4, "Globex", 2),
+            entry(5, "Cloudfare", 1),
+            entry(6, "Cloudflare", 3),
+        ];
+        let suggestions = suggest_merges(&employers);
+        let pairs: Vec<(i64, i64, bool)> = suggestions.iter().map(|s| (s.from.id, s.into.id, s.is_exact())).collect();
+        assert_eq!(pairs, vec![(1, 2, true), (3, 2, true), (5, 6, false)]);
+    }
+}
@@ -0,0 +1,184 @@
+use serde::Serialize;
+use std::fmt;
+
+/// Failure categories that scripts wrapping `hunt` can react to. Each maps to a
+/// distinct process exit code; anything uncategorized exits with 1.
+#[derive(Debug)]
+pub enum HuntError {
+    Config(String),     // missing/invalid settings, credentials, dependencies
+    Network(String),    // connection, TLS, IMAP, HTTP transport failures
+    AiProvider(String), // AI provider rejected the request or returned unusable output
+    NotFound(String),   // job, employer, resume, status... doesn't exist
+    Validation(String), // bad user input or a record missing data the command needs
+    Auth(String),       // credentials rejected: IMAP login, OAuth token, AI API key
+    Partial(String),    // a batch finished but some of its items failed
+}
+
+impl HuntError {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            HuntError::Config(_) => "config",
+            HuntError::Network(_) => "network",
+            HuntError::AiProvider(_) => "ai_provider",
+            HuntError::NotFound(_) => "not_found",
+            HuntError::Validation(_) => "validation",
+            HuntError::Auth(_) => "auth",
+            HuntError::Partial(_) => "partial",
+        }
+    }
+
+    pub fn exit_code(&self) -> i32 {
+        match self {
+            HuntError::Partial(_) => 3,
+            HuntError::Auth(_) => 4,
+            HuntError::AiProvider(_) => 5,
+            HuntError::NotFound(_) => 6,
+            HuntError::Validation(_) => 7,
+            HuntError::Config(_) => 8,
+            HuntError::Network(_) => 9,
+        }
+    }
+}
+
+impl fmt::Display for HuntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            HuntError::Config(msg)
+            | HuntError::Network(msg)
+            | HuntError::AiProvider(msg)
+            | HuntError::NotFound(msg)
+            | HuntError::Validation(msg)
+            | HuntError::Auth(msg)
+            | HuntError::Partial(msg) => write!(f, "{}", msg),
+        }
+    }
+}
+
+impl std::error::Error for HuntError {}
+
+/// What `main` prints (and exits with) when a command fails.
+#[derive(Debug, Serialize)]
+pub struct ErrorReport {
+    pub kind: &'static str,
+    pub exit_code: i32,
+    pub message: String,
+}
+
+/// Classify an error by walking its chain: an explicit `HuntError` wins, then
+/// well-known library errors, otherwise "error" with exit code 1.
+pub fn report(err: &anyhow::Error) -> ErrorReport {
+    let message = format!("{:#}", err);
+    for cause in err.chain() {
+        if let Some(e) = cause.downcast_ref::<HuntError>() {
+            return ErrorReport { kind: e.kind(), exit_code: e.exit_code(), message };
+        }
+    }
+
+    let classified = err.chain().find_map(|cause| {
+        if let Some(api) = cause.downcast_ref::<crate::ai::ApiStatusError>() {
+            if matches!(api.status.as_u16(), 401 | 403) {
+                Some(HuntError::Auth(String::new()))
+            } else {
+                Some(HuntError::AiProvider(String::new()))
+            }
+        } else if cause.is::<reqwest::Error>() || cause.is::<imap::error::Error>() || cause.is::<native_tls::Error>() {
+            Some(HuntError::Network(String::new()))
+        } else if cause.is::<std::env::VarError>() {
+            Some(HuntError::Config(String::new()))
+        } else if let Some(io) = cause.downcast_ref::<std::io::Error>() {
+            use std::io::ErrorKind::*;
+            match io.kind() {
+                ConnectionRefused | ConnectionReset | ConnectionAborted | NotConnected
+                | TimedOut | WouldBlock | HostUnreachable | NetworkUnreachable => {
+                    Some(HuntError::Network(String::new()))
+                }
+                _ => None,
+            }
+        } else {
+            None
+        }
+    });
+
+    match classified {
+        Some(e) => ErrorReport { kind: e.kind(), exit_code: e.exit_code(), message },
+        None => ErrorReport { kind: "error", exit_code: 1, message },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::Context;
+
+    #[test]
+    fn test_report_uses_hunt_error_kind() {
+        let err: anyhow::Error = HuntError::NotFound("Job #5 not found".to_string()).into();
+        let report = report(&err);
+        assert_eq!(report.kind, "not_found");
+        assert_eq!(report.exit_code, 6);
+        assert_eq!(report.message, "Job #5 not found");
+    }
+
+    #[test]
+    fn test_report_sees_through_context() {
+        let err = Err::<(), _>(HuntError::Validation("bad date".to_string()))
+            .context("Compaction failed")
+            .unwrap_err();
+        let report = report(&err);
+        assert_eq!(report.kind, "validation");
+        assert_eq!(report.exit_code, 7);
+        assert_eq!(report.message, "Compaction failed: bad date");
+    }
+
+    #[test]
+    fn test_report_classifies_env_var_as_config() {
+        let err = std::env::var("HUNT_TEST_SURELY_UNSET_VAR")
+            .context("API key not set")
+            .unwrap_err();
+        assert_eq!(report(&err).kind, "config");
+    }
+
+    #[test]
+    fn test_report_classifies_io_timeout_as_network() {
+        let err = anyhow::Error::new(std::io::Error::new(std::io::ErrorKind::TimedOut, "timed out"));
+        assert_eq!(report(&err).exit_code, 9);
+    }
+
+    #[test]
+    fn test_report_classifies_rejected_api_key_as_auth() {
+        let api = |status| crate::ai::ApiStatusError { provider: "Test", status, body: String::new(), retry_after: None };
+        let err = anyhow::Error::new(api(reqwest::StatusCode::UNAUTHORIZED)).context("Keyword extraction failed");
+        assert_eq!(report(&err).kind, "auth");
+        assert_eq!(report(&err).exit_code, 4);
+        let err = anyhow::Error::new(api(reqwest::StatusCode::TOO_MANY_REQUESTS));
+        assert_eq!(report(&err).kind, "ai_provider");
+    }
+
+    #[test]
+    fn test_report_defaults_to_generic() {
+        let err = anyhow::anyhow!("something odd");
+        let report = report(&err);
+        assert_eq!(report.kind, "error");
+        assert_eq!(report.exit_code, 1);
+    }
+
+    #[test]
+    fn test_exit_codes_are_distinct() {
+        let errors = [
+            HuntError::Config(String::new()),
+            HuntError::Network(String::new()),
+            HuntError::AiProvider(String::new()),
+            HuntError::NotFound(String::new()),
+            HuntError::Validation(String::new()),
+            HuntError::Auth(String::new()),
+            HuntError::Partial(String::new()),
+        ];
+        let mut codes: Vec<i32> = errors.iter().map(|e| e.exit_code()).collect();
+        codes.push(1);
+        codes.push(2); // clap usage errors
+        let count = codes.len();
+        codes.sort();
+        codes.dedup();
+        assert_eq!(codes.len(), count);
+    }
+}
@@ -0,0 +1,204 @@
+use reqwest::Url;
+use scraper::{ElementRef, Html, Selector};
+
+/// Where one job site keeps each part of a posting. Selectors are tried in order and
+/// the first element with text wins.
+pub struct SiteAdapter {
+    pub name: &'static str,
+    hosts: &'static [&'static str], // matched as the host or a parent domain
+    title: &'static [&'static str],
+    company: &'static [&'static str],
+    location: &'static [&'static str],
+    pay: &'static [&'static str],
+    description: &'static [&'static str],
+    pub show_more: &'static [&'static str], // buttons to click first (browser only)
+}
+
+pub const ADAPTERS: &[SiteAdapter] = &[
+    SiteAdapter {
+        name: "LinkedIn",
+        hosts: &["linkedin.com"],
+        title: &[
+            ".job-details-jobs-unified-top-card__job-title h1",
+            ".top-card-layout__title",
+            "h1.topcard__title",
+        ],
+        company: &[
+            ".job-details-jobs-unified-top-card__company-name a",
+            ".job-details-jobs-unified-top-card__company-name",
+            ".jobs-unified-top-card__company-name a",
+            ".jobs-unified-top-card__company-name",
+            ".topcard__org-name-link",
+            "a[data-tracking-control-name='public_jobs_topcard-org-name']",
+        ],
+        location: &[
+            ".job-details-jobs-unified-top-card__primary-description-container .tvm__text",
+            ".job-details-jobs-unified-top-card__bullet",
+            ".topcard__flavor--bullet",
+        ],
+        pay: &[".compensation__salary", ".salary"],
+        description: &[
+            ".jobs-description__content",
+            ".show-more-less-html__markup",
+            ".jobs-box__html-content",
+            "div.jobs-description-content__text",
+            "#job-details",
+            "article.jobs-description",
+        ],
+        show_more: &[
+            "button.show-more-less-html__button",
+            "button.show-more-less-html__button--more",
+            ".jobs-description__footer-button",
+            "button[aria-label*='Show more']",
+            "button[aria-label*='See more']",
+        ],
+    },
+    SiteAdapter {
+        name: "Indeed",
+        hosts: &["indeed.com"],
+        title: &["[data-testid='jobsearch-JobInfoHeader-title']", "h1.jobsearch-JobInfoHeader-title"],
+        company: &["[data-testid='inlineHeader-companyName']", "[data-company-name='true']"],
+        location: &["[data-testid='inlineHeader-companyLocation']", "[data-testid='job-location']"],
+        pay: &["#salaryInfoAndJobType", "[data-testid='jobsearch-OtherJobDetailsContainer']"],
+        description: &["#jobDescriptionText"],
+        show_more: &[],
+    },
+    SiteAdapter {
+        name: "Greenhouse",
+        hosts: &["greenhouse.io"],
+        // job-boards.greenhouse.io first, then the classic boards.greenhouse.io layout
+        title: &[".job__title h1", "h1.section-header", "h1.app-title"],
+        company: &[".company-name"],
+        location: &[".job__location", "#header .location", ".location"],
+        pay: &[".pay-range", ".pay-input"],
+        description: &[".job__description", "#content"],
+        show_more: &[],
+    },
+    SiteAdapter {
+        name: "Lever",
+        hosts: &["lever.co"],
+        title: &[".posting-headline h2"],
+        company: &[],
+        location: &[".posting-categories .location", ".posting-category.location"],
+        pay: &[".posting-salary", "[data-qa='salary-range']"],
+        description: &["[data-qa='job-description']", ".posting-page .content"],
+        show_more: &[],
+    },
+    SiteAdapter {
+        name: "Ashby",
+        hosts: &["ashbyhq.com"],
+        // Class names carry build hashes, so match on their stable prefixes
+        title: &["h1[class*='title']", "h1"],
+        company: &[],
+        location: &["[class*='_location_']", "[class*='location']"],
+        pay: &["[class*='compensation']"],
+        description: &["[class*='descriptionText']", "#overview"],
+        show_more: &[],
+    },
+    SiteAdapter {
+        name: "Workday",
+        hosts: &["myworkdayjobs.com", "myworkdaysite.com"],
+        title: &["[data-automation-id='jobPostingHeader']"],
+        company: &[],
+        location: &["[data-automation-id='locations'] dd", "[data-automation-id='locations']"],
+        pay: &[],
+        description: &["[data-automation-id='jobPostingDescription']"],
+        show_more: &[],
+    },
+];
+
+/// The adapter for a posting URL's site, if there is one.
+pub fn adapter_for(url: &str) -> Option<&'static SiteAdapter> {
+    let url = Url::parse(url.trim()).ok()?;
+    let host = url.host_str()?.to_lowercase();
+    ADAPTERS.iter().find(|adapter| {
+        adapter.hosts.iter().any(|h| host == *h || host.ends_with(&format!(".{}", h)))
+    })
+}
+
+/// What an adapter's selectors found on a page.
+#[derive(Debug, Default, PartialEq)]
+pub struct Extracted {
+    pub title: Option<String>,
+    pub company: Option<String>,
+    pub location: Option<String>,
+    pub pay: Option<String>,
+    pub description_html: Option<String>,
+}
+
+pub fn extract(adapter: &SiteAdapter, html: &str) -> Extracted {
+    let document = Html::parse_document(html);
+    let text = |selectors: &[&str]| first_match(&document, selectors).map(|el| element_text(&el));
+    Extracted {
+        title: text(adapter.title),
+        company: text(adapter.company).filter(|name| name.len() < 100),
+        location: text(adapter.location),
+        pay: text(adapter.pay),
+        description_html: first_match(&document, adapter.description).map(|el| el.inner_html()),
+    }
+}
+
+fn first_match<'a>(document: &'a Html, selectors: &[&str]) -> Option<ElementRef<'a>> {
+    selectors.iter()
+        .filter_map(|s| Selector::parse(s).ok())
+        .find_map(|selector| document.select(&selector).find(|el| !element_text(el).is_empty()))
+}
+
+/// An element's text with whitespace collapsed.
+fn element_text(element: &ElementRef) -> String {
+    element.text().collect::<Vec<_>>().join(" ").split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_adapter_for() {
+        let name = |url: &str| adapter_for(url).map(|a| a.name);
+        assert_eq!(name("https://www.linkedin.com/jobs/view/123"), Some("LinkedIn"));
+        assert_eq!(name("https://www.indeed.com/viewjob?jk=abc"), Some("Indeed"));
+        assert_eq!(name("https://job-boards.greenhouse.io/acme/jobs/1"), Some("Greenhouse"));
+        assert_eq!(name("https://jobs.lever.co/acme/uuid"), Some("Lever"));
+        assert_eq!(name("https://jobs.ashbyhq.com/acme/uuid"), Some("Ashby"));
+        assert_eq!(name("https://acme.wd5.myworkdayjobs.com/en-US/External/job/SRE_R1"), Some("Workday"));
+        assert_eq!(name("https://notlinkedin.com/jobs/1"), None);
+        assert_eq!(name("https://careers.acme.com/jobs/1"), None);
+        assert_eq!(name("not a url"), None);
+    }
+
+    #[test]
+    fn test_extract_greenhouse() {
+        let html = r#"<html><body>
+            <div class="job__title"><h1>Senior  SRE</h1></div>
+            <div class="job__location">Remote (US)</div>
+            <div class="job__description"><p>Run our platform.</p>
+              <div class="pay-range"><span>$180,000</span> — <span>$220,000 USD</span></div></div>
+        </body></html>"#;
+        let page = extract(adapter_for("https://job-boards.greenhouse.io/acme/jobs/1").unwrap(), html);
+        assert_eq!(page.title.as_deref(), Some("Senior SRE"));
+        assert_eq!(page.location.as_deref(), Some("Remote (US)"));
+        assert_eq!(page.pay.as_deref(), Some("$180,000 — $220,000 USD"));
+        assert_eq!(page.company, None);
+        assert!(page.description_html.unwrap().contains("Run our platform."));
+    }
+
+    #[test]
+    fn test_extract_workday_and_indeed() {
+        let html = r#"<div data-automation-id="jobPostingHeader">Platform Engineer</div>
+            <div data-automation-id="locations"><dt>locations</dt><dd>Austin, TX</dd></div>
+            <div data-automation-id="jobPostingDescription"><p>Terraform all day.</p></div>"#;
+        let page = extract(adapter_for("https://acme.wd1.myworkdayjobs.com/External/job/1").unwrap(), html);
+        assert_eq!((page.title.as_deref(), page.location.as_deref()), (Some("Platform Engineer"), Some("Austin, TX")));
+
+        let html = r#"<h1 data-testid="jobsearch-JobInfoHeader-title"><span>DevOps Engineer</span></h1>
+            <div data-testid="inlineHeader-companyName"><a>Globex</a></div>
+            <div data-testid="inlineHeader-companyLocation"></div>
+            <div data-testid="job-location">Denver, CO</div>
+            <div id="jobDescriptionText">Kubernetes.</div>"#;
+        let page = extract(adapter_for("https://www.indeed.com/viewjob?jk=1").unwrap(), html);
+        assert_eq!(page.company.as_deref(), Some("Globex"));
+        assert_eq!(page.location.as_deref(), Some("Denver, CO"), "empty matches fall through");
+        assert_eq!(page.pay, None);
+    }
+}
@@ -0,0 +1,224 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::models::Job;
+
+/// `job_flags.source` for flags found by the phrase rules (AI flags store the model).
+pub const RULES_SOURCE: &str = "rules";
+
+/// Longest evidence quote kept with a flag.
+const MAX_EVIDENCE_CHARS: usize = 140;
+
+/// One kind of red flag: its stored name, what it means (shown to people and the AI),
+/// and the phrases that give it away.
+pub struct FlagKind {
+    pub name: &'static str,
+    pub meaning: &'static str,
+    pattern: &'static str,
+}
+
+pub const KINDS: &[FlagKind] = &[
+    FlagKind {
+        name: "fast-paced",
+        meaning: "pace and pressure: fast-paced, high-pressure, hit the ground running",
+        pattern: r"\bfast[- ]paced\b|\bhigh[- ]pressure\b|\bhit the ground running\b|\bsense of urgency\b",
+    },
+    FlagKind {
+        name: "many-hats",
+        meaning: "an unscoped role: wear many hats, jack of all trades, other duties as assigned",
+        pattern: r"\bwear(?:s|ing)? (?:many|multiple|lots of|a lot of|several) (?:different )?hats\b|\bjack of all trades\b|\bother duties as assigned\b",
+    },
+    FlagKind {
+        name: "unlimited-pto",
+        meaning: "unlimited or discretionary time off, which often means less of it",
+        pattern: r"\b(?:unlimited|discretionary|open) (?:pto|paid time off|vacation|time off)\b",
+    },
+    FlagKind {
+        name: "on-call",
+        meaning: "on-call rotations, pagers or after-hours incident response",
+        pattern: r"\bon[- ]call\b|\bpager ?duty\b|\bafter[- ]hours (?:support|incidents?|escalations?)\b|\b24/7 (?:support|coverage)\b",
+    },
+    FlagKind {
+        name: "long-hours",
+        meaning: "long hours: nights and weekends, work hard play hard, whatever it takes",
+        pattern: r"\bnights and weekends\b|\blong hours\b|\bwork hard,? play hard\b|\bwhatever it takes\b|\b(?:50|55|60)\+? hours? (?:a|per) week\b",
+    },
+    FlagKind {
+        name: "clearance",
+        meaning: "a security clearance, or eligibility for one, is required",
+        pattern: r"\b(?:security|secret|top secret|active|dod|government) clearance\b|\bclearance (?:is )?required\b|\bts/sci\b|\bpolygraph\b",
+    },
+    FlagKind {
+        name: "rockstar",
+        meaning: "rockstar/ninja/10x language",
+        pattern: r"\brock ?stars?\b|\bninjas?\b|\b10x (?:engineer|developer)s?\b|\bsuperstars?\b",
+    },
+    FlagKind {
+        name: "family",
+        meaning: "\"we're a family\" culture talk",
+        pattern: r"\b(?:we're|we are) (?:like )?(?:a|one big) (?:happy )?family\b",
+    },
+    FlagKind {
+        name: "travel",
+        meaning: "heavy travel requirements",
+        pattern: r"\b(?:[2-9]\d|100)% travel\b|\btravel (?:up to |of )?(?:[2-9]\d|100)%",
+    },
+];
+
+static PATTERNS: LazyLock<Vec<Regex>> = LazyLock::new(|| {
+    KINDS.iter().map(|kind| Regex::new(&format!("(?i){}", kind.pattern)).unwrap()).collect()
+});
+
+/// A red flag found in a posting, with the words that raised it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Flag {
+    pub name: String,
+    pub evidence: Option<String>,
+}
+
+pub fn kind(name: &str) -> Option<&'static FlagKind> {
+    KINDS.iter().find(|kind| kind.name == name)
+}
+
+/// The sentence or bullet around `start..end`, clipped to `MAX_EVIDENCE_CHARS`.
+fn sentence_around(text: &str, start: usize, end: usize) -> String {
+    let is_break = |c: char| matches!(c, '.' | '!' | '?' | '\n' | '•');
+    let from = text[..start].char_indices().rev().find(|&(_, c)| is_break(c)).map_or(0, |(i, c)| i + c.len_utf8());
+    let to = text[end..].find(is_break).map_or(text.len(), |i| end + i);
+    let sentence = text[from..to].split_whitespace().collect::<Vec<_>>().join(" ");
+    let sentence = sentence.trim_start_matches(['-', '*', ' ']);
+    if sentence.chars().count() <= MAX_EVIDENCE_CHARS {
+        return sentence.to_string();
+    }
+    format!("{}...", sentence.chars().take(MAX_EVIDENCE_CHARS).collect::<String>().trim_end())
+}
+
+/// The red flags the phrase rules find in a posting, in `KINDS` order.
+pub fn detect(text: &str) -> Vec<Flag> {
+    KINDS
+        .iter()
+        .zip(PATTERNS.iter())
+        .filter_map(|(kind, pattern)| {
+            let found = pattern.find(text)?;
+            Some(Flag { name: kind.name.to_string(), evidence: Some(sentence_around(text, found.start(), found.end())) })
+        })
+        .collect()
+}
+
+// "We are unable to sponsor", "does not offer visa sponsorship", "authorized to work
+// without current or future sponsorship", "U.S. citizens only"
+static NO_SPONSORSHIP: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r"(?i)\b(?:no|not|unable to|cannot|can ?not|can't|won't|will not|do not|does not|don't|doesn't)",
+        r"(?: able to)? (?:currently )?(?:(?:provide|offer|support|consider)(?:ing)? )?(?:(?:employment|work|visa|h-?1b|immigration) )*sponsor",
+        r"|\bwithout (?:the need for |requiring )?(?:(?:current|present|now) or (?:in the )?future |any )?(?:(?:employment|work|visa|h-?1b|immigration) )*sponsorship",
+        r"|\bsponsorship (?:is )?(?:not|unavailable)",
+        r"|\bu\.?s\.? citizen(?:ship)? (?:is )?required|\bmust be (?:a )?u\.?s\.? citizen|\bu\.?s\.? citizens only|\brequires? u\.?s\.? citizenship",
+    )).unwrap()
+});
+static SPONSORSHIP: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(concat!(
+        r"(?i)\b(?:visa|h-?1b|immigration) sponsorship (?:is )?(?:available|provided|offered|possible)",
+        r"|\b(?:will|can|able to|happy to|willing to) sponsor\b|\bsponsors? (?:visas|h-?1b)|\boffers? (?:visa|h-?1b) sponsorship",
+    )).unwrap()
+});
+
+/// What a posting says about who can legally take the job.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct WorkAuthorization {
+    pub requires_clearance: bool,
+    /// None when the posting doesn't say; false for "no sponsorship" and citizens-only jobs.
+    pub sponsors_visa: Option<bool>,
+}
+
+/// Clearance and visa sponsorship requirements in a posting. A refusal wins over an
+/// offer, so "happy to sponsor transfers, but can't sponsor new visas" reads as no.
+pub fn work_authorization(text: &str) -> WorkAuthorization {
+    let text = text.replace('\u{2019}', "'");
+    let clearance = KINDS.iter().position(|kind| kind.name == "clearance").map(|i| &PATTERNS[i]);
+    let sponsors_visa = if NO_SPONSORSHIP.is_match(&text) {
+        Some(false)
+    } else if SPONSORSHIP.is_match(&text) {
+        Some(true)
+    } else {
+        None
+    };
+    WorkAuthorization { requires_clearance: clearance.is_some_and(|pattern| pattern.is_match(&text)), sponsors_visa }
+}
+
+/// "security clearance required, no visa sponsorship", or None if the description
+/// says neither.
+pub fn authorization_label(job: &Job) -> Option<String> {
+    let mut parts = Vec::new();
+    if job.requires_clearance == Some(true) {
+        parts.push("security clearance required");
+    }
+    match job.sponsors_visa {
+        Some(true) => parts.push("sponsors visas"),
+        Some(false) => parts.push("no visa sponsorship"),
+        None => {}
+    }
+    (!parts.is_empty()).then(|| parts.join(", "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn names(text: &str) -> Vec<String> {
+        detect(text).into_iter().map(|f| f.name).collect()
+    }
+
+    #[test]
+    fn test_detect() {
+        let posting = "Senior SRE\n\
+            We're a fast-paced startup where everyone wears many hats.\n\
+            - Join the on-call rotation (one week in six)\n\
+            - Unlimited PTO!\n\
+            Must hold an active TS/SCI clearance.";
+        assert_eq!(names(posting), vec!["fast-paced", "many-hats", "unlimited-pto", "on-call", "clearance"]);
+        let flags = detect(posting);
+        assert_eq!(flags[3].evidence.as_deref(), Some("Join the on-call rotation (one week in six)"));
+        assert_eq!(flags[0].evidence.as_deref(), Some("We're a fast-paced startup where everyone wears many hats"));
+
+        assert_eq!(names("Travel up to 50% to customer sites. We're like a family here."), vec!["family", "travel"]);
+        assert_eq!(names("Looking for a rockstar who'll put in nights and weekends"), vec!["long-hours", "rockstar"]);
+
+        // Near misses
+        assert!(names("We call on customers. Travel 10% of the time. Generous PTO. Clearance sale.").is_empty());
+        assert!(kind("on-call").is_some() && kind("other").is_none());
+
+        let bulleted = "Benefits\n• We are like a family here and fast-paced\n• Unlimited PTO";
+        let flags = detect(bulleted);
+        assert_eq!(flags[0].evidence.as_deref(), Some("We are like a family here and fast-paced"));
+
+        let long = format!("On-call {}", "and more words ".repeat(20));
+        assert!(detect(&long)[0].evidence.as_deref().unwrap().ends_with("..."));
+    }
+
+    #[test]
+    fn test_work_authorization() {
+        let sponsors = |text: &str| work_authorization(text).sponsors_visa;
+        assert_eq!(sponsors("We are unable to sponsor visas for this role."), Some(false));
+        assert_eq!(sponsors("Acme does not offer visa sponsorship"), Some(false));
+        assert_eq!(sponsors("We are not able to sponsor at this time"), Some(false));
+        assert_eq!(sponsors("Candidates must be authorized to work in the US without current or future sponsorship."), Some(false));
+        assert_eq!(sponsors("We can\u{2019}t sponsor work visas"), Some(false));
+        assert_eq!(sponsors("Sponsorship is not available"), Some(false));
+        assert_eq!(sponsors("Must be a U.S. citizen"), Some(false));
+        assert_eq!(sponsors("US citizenship required"), Some(false));
+        assert_eq!(sponsors("Visa sponsorship is available for the right candidate"), Some(true));
+        assert_eq!(sponsors("We sponsor H-1B transfers"), Some(true));
+        assert_eq!(sponsors("Happy to sponsor transfers, but we cannot sponsor new H-1B petitions"), Some(false), "a refusal wins");
+        assert_eq!(sponsors("Sponsors: our investors. Competitive pay."), None);
+
+        assert!(work_authorization("Active TS/SCI clearance with polygraph").requires_clearance);
+        assert!(!work_authorization("Great benefits. No clearance needed.").requires_clearance);
+        assert_eq!(work_authorization(""), WorkAuthorization::default());
+
+        let job = Job { requires_clearance: Some(true), sponsors_visa: Some(false), ..Default::default() };
+        assert_eq!(authorization_label(&job).as_deref(), Some("security clearance required, no visa sponsorship"));
+        assert_eq!(authorization_label(&Job { requires_clearance: Some(false), ..Default::default() }), None);
+    }
+}
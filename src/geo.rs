@@ -0,0 +1,221 @@
+use std::sync::OnceLock;
+
+use crate::location;
+use crate::models::Job;
+
+/// A point on the map, in degrees.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Coords {
+    pub lat: f64,
+    pub lon: f64,
+}
+
+/// Turns place names ("Austin, TX (Hybrid)") into coordinates.
+pub trait Geocoder {
+    fn locate(&self, place: &str) -> Option<Coords>;
+}
+
+/// Major cities, looked up without network access. Postings name the metro far more
+/// often than a suburb, so this covers most jobs; anything else isn't scored.
+pub struct OfflineCities;
+
+// (city, state or country code, lat, lon)
+const CITIES: &[(&str, &str, f64, f64)] = &[
+    ("new york", "ny", 40.7128, -74.0060),
+    ("brooklyn", "ny", 40.6782, -73.9442),
+    ("los angeles", "ca", 34.0522, -118.2437),
+    ("san francisco", "ca", 37.7749, -122.4194),
+    ("san jose", "ca", 37.3382, -121.8863),
+    ("oakland", "ca", 37.8044, -122.2712),
+    ("palo alto", "ca", 37.4419, -122.1430),
+    ("mountain view", "ca", 37.3861, -122.0839),
+    ("sunnyvale", "ca", 37.3688, -122.0363),
+    ("menlo park", "ca", 37.4530, -122.1817),
+    ("san diego", "ca", 32.7157, -117.1611),
+    ("irvine", "ca", 33.6846, -117.8265),
+    ("sacramento", "ca", 38.5816, -121.4944),
+    ("seattle", "wa", 47.6062, -122.3321),
+    ("bellevue", "wa", 47.6101, -122.2015),
+    ("redmond", "wa", 47.6740, -122.1215),
+    ("portland", "or", 45.5152, -122.6784),
+    ("portland", "me", 43.6591, -70.2568),
+    ("austin", "tx", 30.2672, -97.7431),
+    ("dallas", "tx", 32.7767, -96.7970),
+    ("houston", "tx", 29.7604, -95.3698),
+    ("san antonio", "tx", 29.4241, -98.4936),
+    ("fort worth", "tx", 32.7555, -97.3308),
+    ("plano", "tx", 33.0198, -96.6989),
+    ("chicago", "il", 41.8781, -87.6298),
+    ("boston", "ma", 42.3601, -71.0589),
+    ("cambridge", "ma", 42.3736, -71.1097),
+    ("washington", "dc", 38.9072, -77.0369),
+    ("arlington", "va", 38.8816, -77.0910),
+    ("reston", "va", 38.9586, -77.3570),
+    ("richmond", "va", 37.5407, -77.4360),
+    ("baltimore", "md", 39.2904, -76.6122),
+    ("philadelphia", "pa", 39.9526, -75.1652),
+    ("pittsburgh", "pa", 40.4406, -79.9959),
+    ("atlanta", "ga", 33.7490, -84.3880),
+    ("miami", "fl", 25.7617, -80.1918),
+    ("tampa", "fl", 27.9506, -82.4572),
+    ("orlando", "fl", 28.5383, -81.3792),
+    ("jacksonville", "fl", 30.3322, -81.6557),
+    ("charlotte", "nc", 35.2271, -80.8431),
+    ("raleigh", "nc", 35.7796, -78.6382),
+    ("durham", "nc", 35.9940, -78.8986),
+    ("nashville", "tn", 36.1627, -86.7816),
+    ("denver", "co", 39.7392, -104.9903),
+    ("boulder", "co", 40.0150, -105.2705),
+    ("phoenix", "az", 33.4484, -112.0740),
+    ("scottsdale", "az", 33.4942, -111.9261),
+    ("salt lake city", "ut", 40.7608, -111.8910),
+    ("lehi", "ut", 40.3916, -111.8508),
+    ("las vegas", "nv", 36.1699, -115.1398),
+    ("minneapolis", "mn", 44.9778, -93.2650),
+    ("detroit", "mi", 42.3314, -83.0458),
+    ("ann arbor", "mi", 42.2808, -83.7430),
+    ("columbus", "oh", 39.9612, -82.9988),
+    ("cleveland", "oh", 41.4993, -81.6944),
+    ("cincinnati", "oh", 39.1031, -84.5120),
+    ("indianapolis", "in", 39.7684, -86.1581),
+    ("st. louis", "mo", 38.6270, -90.1994),
+    ("kansas city", "mo", 39.0997, -94.5786),
+    ("milwaukee", "wi", 43.0389, -87.9065),
+    ("madison", "wi", 43.0731, -89.4012),
+    ("new orleans", "la", 29.9511, -90.0715),
+    ("albuquerque", "nm", 35.0844, -106.6504),
+    ("boise", "id", 43.6150, -116.2023),
+    ("honolulu", "hi", 21.3069, -157.8583),
+    ("anchorage", "ak", 61.2181, -149.9003),
+    ("toronto", "on", 43.6532, -79.3832),
+    ("vancouver", "bc", 49.2827, -123.1207),
+    ("montreal", "qc", 45.5017, -73.5673),
+    ("london", "uk", 51.5074, -0.1278),
+    ("dublin", "ie", 53.3498, -6.2603),
+    ("berlin", "de", 52.5200, 13.4050),
+    ("amsterdam", "nl", 52.3676, 4.9041),
+    ("paris", "fr", 48.8566, 2.3522),
+];
+
+// Names postings use for a metro instead of a city
+const ALIASES: &[(&str, &str)] = &[
+    ("nyc", "new york"),
+    ("bay area", "san francisco"),
+    ("silicon valley", "san jose"),
+    ("dfw", "dallas"),
+    ("washington d.c.", "washington"),
+    ("twin cities", "minneapolis"),
+];
+
+impl Geocoder for OfflineCities {
+    fn locate(&self, place: &str) -> Option<Coords> {
+        let words = place.to_lowercase().replace(|c: char| !c.is_alphanumeric() && c != '.', " ");
+        let text = format!(" {} ", words.split_whitespace().collect::<Vec<_>>().join(" "));
+        let text = ALIASES.iter().fold(text, |text, (alias, city)| text.replace(&format!(" {} ", alias), &format!(" {} ", city)));
+        let mentions = |word: &str| text.contains(&format!(" {} ", word));
+        let mut candidates = CITIES.iter().filter(|(city, ..)| mentions(city)).peekable();
+        let first = *candidates.peek()?;
+        // Portland, OR vs Portland, ME: the one whose state is named, else the first listed
+        let (.., lat, lon) = candidates.find(|(_, region, ..)| mentions(region)).unwrap_or(first);
+        Some(Coords { lat: *lat, lon: *lon })
+    }
+}
+
+/// "30.27,-97.74" as coordinates.
+pub fn parse_coords(text: &str) -> Option<Coords> {
+    let (lat, lon) = text.split_once(',')?;
+    let (lat, lon) = (lat.trim().parse::<f64>().ok()?, lon.trim().parse::<f64>().ok()?);
+    ((-90.0..=90.0).contains(&lat) && (-180.0..=180.0).contains(&lon)).then_some(Coords { lat, lon })
+}
+
+/// A place as coordinates ("lat,lon") or a name `geocoder` knows.
+pub fn locate(place: &str, geocoder: &dyn Geocoder) -> Option<Coords> {
+    parse_coords(place).or_else(|| geocoder.locate(place))
+}
+
+/// Great-circle distance in miles.
+pub fn distance_miles(a: Coords, b: Coords) -> f64 {
+    const EARTH_RADIUS_MILES: f64 = 3958.8;
+    let (lat1, lat2) = (a.lat.to_radians(), b.lat.to_radians());
+    let (dlat, dlon) = ((b.lat - a.lat).to_radians(), (b.lon - a.lon).to_radians());
+    let h = (dlat / 2.0).sin().powi(2) + lat1.cos() * lat2.cos() * (dlon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_MILES * h.sqrt().asin()
+}
+
+static HOME: OnceLock<Option<Coords>> = OnceLock::new();
+
+/// Set the home location for this run, from the config's `home`. Called once, before
+/// anything ranks jobs.
+pub fn set_home(home: Option<&str>) {
+    let _ = HOME.set(home.and_then(|h| locate(h, &OfflineCities)));
+}
+
+/// The home location for this run, if one is configured and could be placed.
+pub fn home() -> Option<Coords> {
+    HOME.get().copied().flatten()
+}
+
+/// How far an onsite or hybrid job is from `home`, and the rank points that earns:
+/// +10 next door, 0 at 100 miles, down to -20 at 300 miles or more. Hybrid jobs get
+/// half, since the trip is only some days. None for remote jobs and unplaceable ones.
+pub fn commute(job: &Job, home: Coords, geocoder: &dyn Geocoder) -> Option<(f64, f64)> {
+    let place = job.location.as_deref()?;
+    let mode = job.work_mode.as_deref().or_else(|| location::work_mode_of_location(place))?;
+    let share = match mode {
+        "onsite" => 1.0,
+        "hybrid" => 0.5,
+        _ => return None,
+    };
+    let miles = distance_miles(home, locate(place, geocoder)?);
+    Some((miles, (10.0 - miles / 10.0).clamp(-20.0, 10.0) * share))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_locate() {
+        let austin = OfflineCities.locate("Austin, TX (Hybrid)").unwrap();
+        assert_eq!(austin, Coords { lat: 30.2672, lon: -97.7431 });
+        assert_eq!(OfflineCities.locate("San Francisco Bay Area"), OfflineCities.locate("San Francisco, CA"));
+        assert_eq!(OfflineCities.locate("NYC"), OfflineCities.locate("New York, NY"));
+        assert_eq!(OfflineCities.locate("Portland, ME").unwrap().lat, 43.6591);
+        assert_eq!(OfflineCities.locate("Portland").unwrap().lat, 45.5152, "first listed without a state");
+        assert!(OfflineCities.locate("Remote - US").is_none());
+        assert!(OfflineCities.locate("Houstonia, MO").is_none(), "whole words only");
+
+        assert_eq!(locate("30.5, -97.8", &OfflineCities), Some(Coords { lat: 30.5, lon: -97.8 }));
+        assert!(parse_coords("95,10").is_none());
+    }
+
+    #[test]
+    fn test_distance_miles() {
+        let austin = OfflineCities.locate("Austin").unwrap();
+        let dallas = OfflineCities.locate("Dallas").unwrap();
+        assert!((distance_miles(austin, dallas) - 182.0).abs() < 3.0);
+        assert_eq!(distance_miles(austin, austin), 0.0);
+    }
+
+    #[test]
+    fn test_commute() {
+        let home = OfflineCities.locate("Austin, TX").unwrap();
+        let job = |location: &str, mode: Option<&str>| Job {
+            location: Some(location.to_string()),
+            work_mode: mode.map(str::to_string),
+            ..Job::default()
+        };
+
+        let (miles, points) = commute(&job("Austin, TX", Some("onsite")), home, &OfflineCities).unwrap();
+        assert_eq!((miles, points), (0.0, 10.0));
+        let (miles, points) = commute(&job("Dallas, TX (Hybrid)", None), home, &OfflineCities).unwrap();
+        assert!(miles > 150.0);
+        assert!((points - (10.0 - miles / 10.0) / 2.0).abs() < 1e-9, "hybrid gets half");
+        let (_, points) = commute(&job("Seattle, WA", Some("onsite")), home, &OfflineCities).unwrap();
+        assert_eq!(points, -20.0);
+
+        assert!(commute(&job("Austin, TX", Some("remote")), home, &OfflineCities).is_none());
+        assert!(commute(&job("Austin, TX", None), home, &OfflineCities).is_none(), "work mode unknown");
+        assert!(commute(&job("Smallville", Some("onsite")), home, &OfflineCities).is_none());
+    }
+}
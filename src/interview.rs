@@ -0,0 +1,178 @@
+use crate::comp;
+use crate::models::{CompBand, Employer, FitAnalysis, GlassdoorReview, Job};
+use crate::pay;
+
+/// Glassdoor cons quoted in a brief, most recent first.
+const MAX_CONS: usize = 6;
+/// Longest a quoted con gets before it's cut off.
+const MAX_CON_CHARS: usize = 240;
+
+fn clip(text: &str, max: usize) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if text.chars().count() <= max {
+        return text;
+    }
+    format!("{}...", text.chars().take(max).collect::<String>().trim_end())
+}
+
+/// The stored research on an employer as "Label: text" lines, for prompts that should
+/// use it (`hunt questions`, `hunt negotiate`). Empty when nothing has been researched.
+pub fn employer_brief(employer: &Employer, reviews: &[GlassdoorReview]) -> String {
+    let mut lines = Vec::new();
+    let mut push = |label: &str, text: Option<String>| {
+        if let Some(text) = text.filter(|t| !t.trim().is_empty()) {
+            lines.push(format!("{}: {}", label, text.trim()));
+        }
+    };
+
+    let mut funding = Vec::new();
+    if let Some(stage) = &employer.funding_stage {
+        funding.push(stage.clone());
+    }
+    if let Some(total) = employer.total_funding {
+        funding.push(format!("${}M raised", total / 1_000_000));
+    }
+    if let Some(date) = &employer.last_funding_date {
+        funding.push(format!("last round {}", date));
+    }
+    if let Some(batch) = &employer.yc_batch {
+        funding.push(format!("YC {}", batch));
+    }
+    push("Funding", (!funding.is_empty()).then(|| funding.join(", ")));
+    push("Investors", employer.key_investors.clone().or_else(|| employer.vc_investors.clone()));
+
+    let owner = match (&employer.pe_owner, &employer.parent_company) {
+        (Some(pe), _) => Some(format!("owned by private equity firm {}", pe)),
+        (None, Some(parent)) => Some(format!("part of {}", parent)),
+        (None, None) => employer.ownership_type.clone(),
+    };
+    push("Ownership", owner);
+    push("Ownership concerns", employer.ownership_concerns.clone());
+    push("Recent news", employer.recent_news.clone());
+
+    if let Some(rating) = employer.glassdoor_rating {
+        let mut glassdoor = format!("{:.1}/5 over {} reviews", rating, employer.glassdoor_review_count.unwrap_or(0));
+        if !reviews.is_empty() {
+            let count = |sentiment: &str| reviews.iter().filter(|r| r.sentiment == sentiment).count();
+            glassdoor.push_str(&format!(
+                " ({} positive, {} negative, {} neutral of those stored)",
+                count("positive"),
+                count("negative"),
+                count("neutral")
+            ));
+        }
+        push("Glassdoor", Some(glassdoor));
+    }
+    let cons: Vec<String> = reviews
+        .iter()
+        .filter_map(|r| r.cons.as_deref())
+        .filter(|c| !c.trim().is_empty())
+        .take(MAX_CONS)
+        .map(|c| format!("\"{}\"", clip(c, MAX_CON_CHARS)))
+        .collect();
+    push("Glassdoor cons", (!cons.is_empty()).then(|| cons.join(" / ")));
+
+    push("Controversies", employer.controversies.clone());
+    push("Labor practices", employer.labor_practices.clone());
+    push("Environmental issues", employer.environmental_issues.clone());
+    push("Ethics summary", employer.evil_summary.clone());
+    push("Engineering culture", employer.eng_culture_summary.clone());
+    lines.join("\n")
+}
+
+/// What `hunt negotiate` knows about the offer side, as "Label: text" lines: the posted
+/// pay, the employer's comp bands (the job's own level marked), the fit analysis and
+/// the employer brief.
+pub fn negotiation_facts(job: &Job, bands: &[CompBand], fit: Option<&FitAnalysis>, brief: &str) -> String {
+    let mut lines = vec![format!("Posted pay: {}", pay::label(job).unwrap_or_else(|| "not posted".to_string()))];
+    let level = comp::level(&job.title);
+    lines.push(format!("Level (from title): {}", level));
+    let matched = comp::band_for(&job.title, bands);
+    for band in bands {
+        let mut line = format!("Comp band: {} {}: base {}", band.level, band.role, comp::range(band.base_min, band.base_max));
+        if band.total_min.is_some() || band.total_max.is_some() {
+            line.push_str(&format!(", total {}", comp::range(band.total_min, band.total_max)));
+        }
+        if matched.is_some_and(|m| std::ptr::eq(m, band)) {
+            line.push_str(" (this job's level)");
+        }
+        lines.push(line);
+    }
+    if bands.is_empty() {
+        lines.push("Comp band: none researched".to_string());
+    }
+    if let Some(band) = comp::below_band(job, bands) {
+        lines.push(format!("Warning: posted pay tops out under the {} band's lowest base", band.level));
+    }
+    match fit {
+        Some(fit) => {
+            lines.push(format!("Fit score: {:.0}/100", fit.fit_score));
+            for (label, items) in [("Strong matches", &fit.strong_matches), ("Gaps", &fit.gaps)] {
+                if let Some(items) = items.as_deref().filter(|i| !i.trim().is_empty()) {
+                    lines.push(format!("{}: {}", label, items));
+                }
+            }
+        }
+        None => lines.push("Fit score: not analyzed".to_string()),
+    }
+    if !brief.is_empty() {
+        lines.push(brief.to_string());
+    }
+    lines.join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::Database;
+
+    #[test]
+    fn test_employer_brief() -> anyhow::Result<()> {
+        let db = Database::open_in_memory()?;
+        db.init()?;
+        let job = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        let acme = db.get_job(job)?.unwrap().employer_id.unwrap();
+        let employer = db.get_employer_by_name("Acme")?.unwrap();
+        assert_eq!(employer_brief(&employer, &[]), "");
+
+        db.update_employer_research(acme, None, Some("Series B"), Some(40_000_000), Some("2025-06"), None, None, None, None)?;
+        db.add_glassdoor_review(acme, 2.0, None, None, Some("Reorgs   every\nquarter"), None, "negative", None)?;
+        db.add_glassdoor_review(acme, 5.0, None, Some("Smart people"), None, None, "positive", None)?;
+        db.update_employer_glassdoor_summary(acme)?;
+        let employer = db.get_employer_by_name("Acme")?.unwrap();
+        let reviews = db.list_glassdoor_reviews(Some(acme))?;
+        let brief = employer_brief(&employer, &reviews);
+        assert!(brief.starts_with("Funding: Series B, $40M raised, last round 2025-06\n"), "{}", brief);
+        assert!(brief.contains("Glassdoor: 3.5/5 over 2 reviews (1 positive, 1 negative, 0 neutral of those stored)"), "{}", brief);
+        assert!(brief.ends_with("Glassdoor cons: \"Reorgs every quarter\""), "{}", brief);
+        assert_eq!(clip("a b c d", 3), "a b...");
+        Ok(())
+    }
+
+    #[test]
+    fn test_negotiation_facts() {
+        let job = Job { title: "Senior SRE".to_string(), pay_min: Some(150_000), pay_max: Some(170_000), ..Default::default() };
+        let band = |level: &str, base: (i64, i64)| CompBand {
+            level: level.to_string(),
+            role: "Site Reliability Engineer".to_string(),
+            base_min: Some(base.0),
+            base_max: Some(base.1),
+            total_min: None,
+            total_max: Some(300_000),
+        };
+        let bands = vec![band("mid", (130_000, 160_000)), band("senior", (180_000, 220_000))];
+        let facts = negotiation_facts(&job, &bands, None, "Funding: Series B");
+        assert_eq!(
+            facts,
+            "Posted pay: $150000 - $170000\n\
+             Level (from title): senior\n\
+             Comp band: mid Site Reliability Engineer: base $130k - $160k, total $300k\n\
+             Comp band: senior Site Reliability Engineer: base $180k - $220k, total $300k (this job's level)\n\
+             Warning: posted pay tops out under the senior band's lowest base\n\
+             Fit score: not analyzed\n\
+             Funding: Series B"
+        );
+        let facts = negotiation_facts(&Job { title: "SRE".to_string(), ..Default::default() }, &[], None, "");
+        assert!(facts.starts_with("Posted pay: not posted\nLevel (from title): mid\nComp band: none researched\n"), "{}", facts);
+    }
+}
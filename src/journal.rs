@@ -0,0 +1,192 @@
+use anyhow::Result;
+use chrono::{Duration, NaiveDate, NaiveDateTime};
+use std::io::Write;
+use std::path::Path;
+
+use crate::db::JournalActivity;
+use crate::error::HuntError;
+use crate::models::Job;
+
+/// Parse `--since` (or `--older-than`): a relative window (`36h`, `7d`, `2w`) or a `YYYY-MM-DD` date.
+pub fn parse_since(spec: &str, now: NaiveDateTime) -> Result<NaiveDateTime> {
+    let spec = spec.trim();
+    if let Ok(date) = NaiveDate::parse_from_str(spec, "%Y-%m-%d") {
+        return Ok(date.and_hms_opt(0, 0, 0).unwrap());
+    }
+
+    let invalid = || HuntError::Validation(format!(
+        "Invalid time '{}'. Use e.g. 36h, 7d, 2w or YYYY-MM-DD", spec
+    ));
+    let (amount, unit) = spec.split_at(spec.len().saturating_sub(1));
+    let amount: i64 = amount.parse().map_err(|_| invalid())?;
+    let window = match unit {
+        "h" => Duration::hours(amount),
+        "d" => Duration::days(amount),
+        "w" => Duration::weeks(amount),
+        _ => return Err(invalid().into()),
+    };
+    Ok(now - window)
+}
+
+fn describe_job(job: &Job) -> String {
+    match &job.employer_name {
+        Some(employer) => format!("#{} {} @ {}", job.id, job.title, employer),
+        None => format!("#{} {}", job.id, job.title),
+    }
+}
+
+/// Bullet-point log of the activity; this is also what the AI polishes.
+pub fn render_activity(activity: &JournalActivity) -> String {
+    let mut lines = Vec::new();
+
+    if !activity.jobs_added.is_empty() {
+        lines.push(format!("- Added {} job(s):", activity.jobs_added.len()));
+        for job in &activity.jobs_added {
+            lines.push(format!("  - {}", describe_job(job)));
+        }
+    }
+
+    let mut current_status: Option<&str> = None;
+    for job in &activity.jobs_progressed {
+        if current_status != Some(job.status.as_str()) {
+            lines.push(format!("- Now {}:", job.status));
+            current_status = Some(&job.status);
+        }
+        lines.push(format!("  - {}", describe_job(job)));
+    }
+
+    if !activity.fit_analyses.is_empty() {
+        lines.push(format!("- Ran fit analysis on {} job(s):", activity.fit_analyses.len()));
+        for (job, score) in &activity.fit_analyses {
+            lines.push(format!("  - {} ({:.0}% fit)", describe_job(job), score * 100.0));
+        }
+    }
+
+    if !activity.variants.is_empty() {
+        lines.push(format!("- Tailored {} resume(s):", activity.variants.len()));
+        for (job, resume) in &activity.variants {
+            lines.push(format!("  - {} (from {})", describe_job(job), resume));
+        }
+    }
+
+    if !activity.notes.is_empty() {
+        lines.push(format!("- Wrote {} note(s):", activity.notes.len()));
+        for (job, note) in &activity.notes {
+            lines.push(format!("  - {}: {}", describe_job(job), note));
+        }
+    }
+
+    if activity.keywords_extracted > 0 {
+        lines.push(format!("- Extracted keywords for {} job(s)", activity.keywords_extracted));
+    }
+
+    if !activity.employers_researched.is_empty() {
+        lines.push(format!("- Researched or updated employers: {}", activity.employers_researched.join(", ")));
+    }
+
+    if lines.is_empty() {
+        lines.push("- No recorded activity".to_string());
+    }
+    lines.join("\n")
+}
+
+/// A dated markdown section: optional narrative followed by the activity log.
+pub fn format_entry(today: NaiveDate, since: NaiveDateTime, narrative: Option<&str>, log: &str) -> String {
+    let mut entry = format!("## {} (since {})\n\n", today.format("%Y-%m-%d"), since.format("%Y-%m-%d %H:%M"));
+    if let Some(narrative) = narrative {
+        entry.push_str(narrative.trim());
+        entry.push_str("\n\n");
+    }
+    entry.push_str(log);
+    entry.push('\n');
+    entry
+}
+
+/// Append an entry to the journal file, creating it with a title if needed.
+pub fn append_entry(path: &Path, entry: &str) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let is_new = !path.exists() || std::fs::metadata(path)?.len() == 0;
+    let mut file = std::fs::OpenOptions::new().create(true).append(true).open(path)?;
+    if is_new {
+        writeln!(file, "# Job hunt journal")?;
+    }
+    write!(file, "\n{}", entry)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn now() -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(2026, 3, 15).unwrap().and_hms_opt(12, 0, 0).unwrap()
+    }
+
+    fn job(id: i64, title: &str, employer: Option<&str>, status: &str) -> Job {
+        Job {
+            id,
+            employer_name: employer.map(|e| e.to_string()),
+            title: title.to_string(),
+            status: status.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_since() {
+        let day = |d| NaiveDate::from_ymd_opt(2026, 3, d).unwrap();
+        assert_eq!(parse_since("7d", now()).unwrap(), day(8).and_hms_opt(12, 0, 0).unwrap());
+        assert_eq!(parse_since("1w", now()).unwrap(), day(8).and_hms_opt(12, 0, 0).unwrap());
+        assert_eq!(parse_since("36h", now()).unwrap(), day(14).and_hms_opt(0, 0, 0).unwrap());
+        assert_eq!(parse_since("2026-03-01", now()).unwrap(), day(1).and_hms_opt(0, 0, 0).unwrap());
+        assert!(parse_since("7", now()).is_err());
+        assert!(parse_since("d", now()).is_err());
+        assert!(parse_since("last week", now()).is_err());
+    }
+
+    #[test]
+    fn test_render_activity_groups_by_status() {
+        let activity = JournalActivity {
+            jobs_added: vec![job(3, "SRE", Some("Acme"), "new")],
+            jobs_progressed: vec![
+                job(1, "Platform Engineer", Some("Beta"), "applied"),
+                job(2, "DevOps Lead", None, "applied"),
+                job(4, "Infra Manager", Some("Gamma"), "interviewing"),
+            ],
+            fit_analyses: vec![(job(3, "SRE", Some("Acme"), "new"), 0.82)],
+            keywords_extracted: 2,
+            notes: vec![(job(4, "Infra Manager", Some("Gamma"), "interviewing"), "Onsite went well".to_string())],
+            ..Default::default()
+        };
+        let log = render_activity(&activity);
+        assert!(log.contains("- Added 1 job(s):\n  - #3 SRE @ Acme"));
+        assert!(log.contains("- Now applied:\n  - #1 Platform Engineer @ Beta\n  - #2 DevOps Lead\n- Now interviewing:"));
+        assert!(log.contains("(82% fit)"));
+        assert!(log.contains("Extracted keywords for 2 job(s)"));
+        assert!(log.contains("- Wrote 1 note(s):\n  - #4 Infra Manager @ Gamma: Onsite went well"));
+        assert!(!log.contains("Tailored"));
+    }
+
+    #[test]
+    fn test_render_activity_empty() {
+        assert_eq!(render_activity(&JournalActivity::default()), "- No recorded activity");
+    }
+
+    #[test]
+    fn test_append_entry_creates_and_appends() {
+        let path = std::env::temp_dir().join(format!("hunt_test_journal_{}.md", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let since = now() - Duration::days(7);
+
+        append_entry(&path, &format_entry(now().date(), since, Some("A good week."), "- Added 1 job(s):")).unwrap();
+        append_entry(&path, &format_entry(now().date(), since, None, "- No recorded activity")).unwrap();
+
+        let content = std::fs::read_to_string(&path).unwrap();
+        assert!(content.starts_with("# Job hunt journal\n\n## 2026-03-15 (since 2026-03-08 12:00)\n\nA good week.\n\n- Added"));
+        assert_eq!(content.matches("# Job hunt journal").count(), 1);
+        assert_eq!(content.matches("## 2026-03-15").count(), 2);
+        let _ = std::fs::remove_file(&path);
+    }
+}
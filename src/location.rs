@@ -0,0 +1,135 @@
+use std::sync::LazyLock;
+
+use anyhow::Result;
+use regex::Regex;
+
+use crate::error::HuntError;
+use crate::models::Job;
+
+/// Values of `jobs.work_mode`.
+pub const WORK_MODES: [&str; 3] = ["remote", "hybrid", "onsite"];
+
+/// "Location: Austin, TX" lines, as site adapters put at the top of fetched descriptions.
+const LOCATION_LINE: &str = r"(?im)^[ \t]*(?:job |work )?locations?[ \t]*(?::|[\-–—][ \t])[ \t]*(\S[^\n]*?)[ \t]*$";
+
+// Description phrases. "Hybrid" and "remote" alone are too common ("hybrid cloud",
+// "remote state") to count, so each needs a word saying it's about where you work.
+const TEXT_PATTERNS: [(&str, &str); 3] = [
+    ("hybrid", r"(?i)\(hybrid\)|\bhybrid[- ](?:role|position|job|schedule|work|working|model|arrangement|opportunity)\b|\b\d (?:days?|x) (?:a|per) week in(?: the)? office\b"),
+    ("onsite", r"(?i)\((?:on-?site|in[- ]office)\)|\b(?:fully|100%) (?:on-?site|in[- ]office)\b|\b(?:on-?site|in[- ]office) (?:role|position|job|only)\b|\b(?:role|position|job) is (?:on-?site|in[- ]office)\b"),
+    ("remote", r"(?i)\(remote\)|\b(?:fully|100%) remote\b|\bremote[- ](?:first|friendly|role|position|job|opportunity|only)\b|\bwork from (?:home|anywhere)\b|\b(?:role|position|job) is (?:fully )?remote\b"),
+];
+
+// Compiled once: email ingests check every job's text
+static TEXT_REGEXES: LazyLock<Vec<(&str, Regex)>> = LazyLock::new(|| {
+    TEXT_PATTERNS.iter().map(|(mode, pattern)| (*mode, Regex::new(pattern).unwrap())).collect()
+});
+
+/// The work mode a short location string names, e.g. "Austin, TX (Hybrid)" or
+/// "Remote - US". A bare city says nothing either way.
+pub fn work_mode_of_location(location: &str) -> Option<&'static str> {
+    let lower = location.to_lowercase();
+    if lower.contains("hybrid") {
+        Some("hybrid")
+    } else if ["on-site", "onsite", "on site", "in office", "in-office"].iter().any(|w| lower.contains(w)) {
+        Some("onsite")
+    } else if ["remote", "anywhere", "work from home", "wfh"].iter().any(|w| lower.contains(w)) {
+        Some("remote")
+    } else {
+        None
+    }
+}
+
+/// The work mode a job description spells out. Hybrid wins over remote, since hybrid
+/// postings talk about their remote days.
+pub fn work_mode_of_text(text: &str) -> Option<&'static str> {
+    TEXT_REGEXES.iter()
+        .find(|(_, re)| re.is_match(text))
+        .map(|(mode, _)| *mode)
+}
+
+/// The first "Location: ..." line of a description.
+pub fn location_of_text(text: &str) -> Option<String> {
+    Regex::new(LOCATION_LINE).unwrap().captures_iter(text)
+        .map(|cap| cap[1].trim_matches(|c: char| c.is_whitespace() || c == '.').to_string())
+        .find(|location| !location.is_empty() && location.chars().count() <= 80)
+}
+
+/// Location and work mode from a description: the mode from the location line when it
+/// names one, else from the text.
+pub fn from_description(text: &str) -> (Option<String>, Option<&'static str>) {
+    let location = location_of_text(text);
+    let work_mode = location.as_deref().and_then(work_mode_of_location).or_else(|| work_mode_of_text(text));
+    (location, work_mode)
+}
+
+/// A work mode as people write it: "on-site", "In office", "WFH"...
+pub fn parse_work_mode(value: &str) -> Result<&'static str> {
+    let lower = value.trim().to_lowercase();
+    WORK_MODES.iter()
+        .find(|mode| lower == **mode)
+        .copied()
+        .or_else(|| work_mode_of_location(&lower))
+        .ok_or_else(|| HuntError::Validation(format!("Unknown work mode '{}' (expected one of: {})", value, WORK_MODES.join(", "))).into())
+}
+
+/// Whether the job's location mentions `query`, ignoring case. "remote" also matches
+/// jobs whose work mode is remote.
+pub fn matches(job: &Job, query: &str) -> bool {
+    let query = query.trim().to_lowercase();
+    job.location.as_deref().is_some_and(|l| l.to_lowercase().contains(&query))
+        || job.work_mode.as_deref() == Some(query.as_str())
+}
+
+/// "Austin, TX (hybrid)", "remote" or "-" for lists.
+pub fn label(job: &Job) -> String {
+    match (job.location.as_deref(), job.work_mode.as_deref()) {
+        (Some(location), Some(mode)) if work_mode_of_location(location) != Some(mode) => format!("{} ({})", location, mode),
+        (Some(location), _) => location.to_string(),
+        (None, Some(mode)) => mode.to_string(),
+        (None, None) => "-".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_work_mode_of_location() {
+        assert_eq!(work_mode_of_location("United States (Remote)"), Some("remote"));
+        assert_eq!(work_mode_of_location("Austin, TX (Hybrid)"), Some("hybrid"));
+        assert_eq!(work_mode_of_location("New York, NY (On-site)"), Some("onsite"));
+        assert_eq!(work_mode_of_location("Anywhere"), Some("remote"));
+        assert_eq!(work_mode_of_location("Denver, CO"), None);
+    }
+
+    #[test]
+    fn test_work_mode_of_text() {
+        assert_eq!(work_mode_of_text("This is a fully remote role."), Some("remote"));
+        assert_eq!(work_mode_of_text("We are remote-first."), Some("remote"));
+        assert_eq!(work_mode_of_text("Hybrid schedule: 3 days a week in office, otherwise remote-friendly"), Some("hybrid"));
+        assert_eq!(work_mode_of_text("This position is on-site in Chicago."), Some("onsite"));
+        assert_eq!(work_mode_of_text("Run our hybrid cloud and remote state backends"), None);
+    }
+
+    #[test]
+    fn test_from_description() {
+        let text = "Senior SRE\nLocation: Austin, TX (Hybrid)\n\nRun our platform. Remote-friendly team.";
+        assert_eq!(from_description(text), (Some("Austin, TX (Hybrid)".to_string()), Some("hybrid")));
+
+        let text = "Staff Engineer\nJob Location - Denver, CO.\n\nThis role is fully remote.";
+        assert_eq!(from_description(text), (Some("Denver, CO".to_string()), Some("remote")));
+
+        assert_eq!(from_description("No location here.\nLocation-based pricing"), (None, None));
+    }
+
+    #[test]
+    fn test_parse_work_mode() {
+        assert_eq!(parse_work_mode("Remote").unwrap(), "remote");
+        assert_eq!(parse_work_mode("on-site").unwrap(), "onsite");
+        assert_eq!(parse_work_mode("WFH").unwrap(), "remote");
+        let err = parse_work_mode("sometimes").unwrap_err();
+        assert!(matches!(err.downcast_ref::<HuntError>(), Some(HuntError::Validation(_))));
+    }
+}
@@ -0,0 +1,99 @@
+use std::fmt;
+use std::fs::OpenOptions;
+use std::path::Path;
+use std::sync::Mutex;
+
+use anyhow::{Context, Result};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::filter::{LevelFilter, filter_fn};
+use tracing_subscriber::fmt::format::Writer;
+use tracing_subscriber::fmt::{FmtContext, FormatEvent, FormatFields};
+use tracing_subscriber::prelude::*;
+use tracing_subscriber::registry::LookupSpan;
+
+/// Target for the error a command failed with. Only `--log-file` gets it, since `main`
+/// already prints it to stderr.
+pub const EXIT_TARGET: &str = "hunt::exit";
+
+/// How much reaches stderr: `--quiet` keeps warnings and errors, the default adds progress
+/// (info), `-v` debug detail (IMAP commands, browser steps) and `-vv` everything.
+pub fn stderr_level(verbosity: u8, quiet: bool) -> LevelFilter {
+    match (quiet, verbosity) {
+        (true, _) => LevelFilter::WARN,
+        (false, 0) => LevelFilter::INFO,
+        (false, 1) => LevelFilter::DEBUG,
+        _ => LevelFilter::TRACE,
+    }
+}
+
+/// Install the global subscriber. `--log-file` is appended to with timestamped lines at
+/// info or the `-v` level, whichever says more, however quiet stderr is, so a cron run
+/// can be audited afterwards.
+pub fn init(verbosity: u8, quiet: bool, log_file: Option<&Path>) -> Result<()> {
+    let level = stderr_level(verbosity, quiet);
+    let stderr = tracing_subscriber::fmt::layer()
+        .with_writer(std::io::stderr)
+        .event_format(Plain)
+        .with_filter(filter_fn(move |meta| meta.target() != EXIT_TARGET && *meta.level() <= level));
+
+    let file = match log_file {
+        Some(path) => {
+            let file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(path)
+                .with_context(|| format!("Failed to open log file {}", path.display()))?;
+            let level = stderr_level(verbosity, false).max(LevelFilter::INFO);
+            Some(
+                tracing_subscriber::fmt::layer()
+                    .with_writer(Mutex::new(file))
+                    .with_ansi(false)
+                    .with_target(false)
+                    .with_filter(level),
+            )
+        }
+        None => None,
+    };
+
+    tracing_subscriber::registry()
+        .with(stderr)
+        .with(file)
+        .try_init()
+        .context("Failed to set up logging")
+}
+
+/// Progress (info) prints as the bare message, as it did before there was a logger;
+/// other levels are prefixed ("warning: ...").
+struct Plain;
+
+impl<S, N> FormatEvent<S, N> for Plain
+where
+    S: Subscriber + for<'a> LookupSpan<'a>,
+    N: for<'a> FormatFields<'a> + 'static,
+{
+    fn format_event(&self, ctx: &FmtContext<'_, S, N>, mut writer: Writer<'_>, event: &Event<'_>) -> fmt::Result {
+        let prefix = match *event.metadata().level() {
+            Level::INFO => "",
+            Level::WARN => "warning: ",
+            Level::ERROR => "error: ",
+            Level::DEBUG => "debug: ",
+            _ => "trace: ",
+        };
+        write!(writer, "{}", prefix)?;
+        ctx.field_format().format_fields(writer.by_ref(), event)?;
+        writeln!(writer)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stderr_level() {
+        assert_eq!(stderr_level(0, false), LevelFilter::INFO);
+        assert_eq!(stderr_level(1, false), LevelFilter::DEBUG);
+        assert_eq!(stderr_level(3, false), LevelFilter::TRACE);
+        assert_eq!(stderr_level(2, true), LevelFilter::WARN, "--quiet wins");
+    }
+}
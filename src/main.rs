@@ -1,20 +1,92 @@
 mod ai;
+mod ats;
+mod boards;
 mod browser;
+mod changes;
+mod cluster;
+mod comp;
+mod completions;
+mod config;
+mod dates;
 mod db;
+mod diff;
+mod digest;
+mod domains;
 mod email;
+mod employers;
+mod error;
+mod extractors;
+mod flags;
+mod geo;
+mod interview;
+mod journal;
+mod location;
+mod logging;
 mod models;
+mod notify;
+mod output;
+mod pay;
+mod pipeline;
+mod policy;
+mod profile;
+mod ratelimit;
+mod render;
+mod research;
+mod review;
+mod server;
+mod smtp;
+mod stats;
+mod templates;
+mod timestamps;
+mod todo;
+mod trends;
 mod tui;
 
-use anyhow::{anyhow, Context, Result};
-use clap::{Parser, Subcommand};
+use anyhow::{Context, Result};
+use clap::{CommandFactory, Parser, Subcommand};
+use clap_complete::{ArgValueCandidates, CompleteEnv};
 use db::Database;
-use email::{EmailConfig, EmailIngester};
+use email::{AccountIngestStats, AccountSpec, EmailConfig, EmailIngester, IngestStats, LocalMail};
+use error::HuntError;
+use pipeline::Stage;
+use policy::FetchPolicy;
+use ratelimit::RateLimiter;
+use tracing::{info, warn};
 use std::path::PathBuf;
 
 #[derive(Parser)]
 #[command(name = "hunt")]
 #[command(about = "Job search automation - find, track, and analyze opportunities")]
 struct Cli {
+    /// Print errors as JSON ({"error": {"kind", "exit_code", "message"}}) on stderr, and
+    /// results as JSON on stdout for commands that support it (list, show, rank, employer
+    /// list/show, glassdoor show, stats, report)
+    #[arg(long, global = true)]
+    json: bool,
+
+    /// Only print results, summaries and errors: no per-item progress, spinners or
+    /// countdowns, e.g. for cron. Failures still set the exit code
+    #[arg(short, long, global = true)]
+    quiet: bool,
+
+    /// More detail on stderr: -v for debug output (IMAP commands, browser steps), -vv for everything
+    #[arg(short, long, global = true, action = clap::ArgAction::Count)]
+    verbose: u8,
+
+    /// Also append timestamped log lines (progress, warnings, the final error) to this file
+    #[arg(long, global = true, value_name = "PATH")]
+    log_file: Option<PathBuf>,
+
+    /// Database file to use instead of the profile's or $HUNT_DB, e.g. to keep a
+    /// separate database per job search
+    #[arg(long, global = true, value_name = "PATH")]
+    db: Option<PathBuf>,
+
+    /// Named profile to use (its own database and config, even if $HUNT_DB is set);
+    /// default: the one chosen with `hunt profile switch`
+    #[arg(long, global = true, value_name = "NAME")]
+    profile: Option<String>,
+
     #[command(subcommand)]
     command: Commands,
 }
@@ -32,23 +104,213 @@ enum Commands {
 
     /// List jobs
     List {
-        /// Filter by status (new, reviewing, applied, rejected, closed)
+        /// Filter by status (see `hunt status list`)
         #[arg(short, long)]
         status: Option<String>,
 
         /// Filter by employer
-        #[arg(short, long)]
+        #[arg(short, long, add = ArgValueCandidates::new(completions::employers))]
         employer: Option<String>,
+
+        /// Filter by tag
+        #[arg(short, long)]
+        tag: Option<String>,
+
+        /// Hide jobs whose title or extracted keywords mention this (repeatable)
+        #[arg(short = 'x', long = "exclude-keyword", value_name = "KEYWORD")]
+        exclude_keywords: Vec<String>,
+
+        /// Also show jobs hidden by permanent exclusions (`hunt exclude`)
+        #[arg(long)]
+        show_excluded: bool,
+
+        /// Only remote jobs
+        #[arg(long)]
+        remote: bool,
+
+        /// Only LinkedIn jobs that take Easy Apply
+        #[arg(long, conflicts_with = "external_apply")]
+        easy_apply: bool,
+
+        /// Only LinkedIn jobs that send you to the employer's site to apply
+        #[arg(long)]
+        external_apply: bool,
+
+        /// Leave out jobs whose description rules out visa sponsorship or wants citizens only
+        #[arg(long)]
+        sponsors_visa: bool,
+
+        /// Leave out jobs whose description requires a security clearance
+        #[arg(long)]
+        no_clearance: bool,
+
+        /// Only jobs whose location mentions this, e.g. "Austin"
+        #[arg(long)]
+        location: Option<String>,
+
+        /// Only jobs advertising at least this much (annual USD, top of the range)
+        #[arg(long, value_name = "USD")]
+        min_pay: Option<i64>,
+
+        /// Only jobs advertising at most this much (annual USD, bottom of the range)
+        #[arg(long, value_name = "USD")]
+        max_pay: Option<i64>,
+
+        /// List archived jobs instead (`hunt delete`)
+        #[arg(long)]
+        archived: bool,
+
+        /// Apply a saved filter (`hunt filter save`); other flags override its fields
+        #[arg(short, long, value_name = "NAME", add = ArgValueCandidates::new(completions::filters))]
+        filter: Option<String>,
+
+        /// Render with a minijinja template (gets `jobs`, each like `show --template`)
+        #[arg(long, value_name = "FILE")]
+        template: Option<PathBuf>,
+    },
+
+    /// Save named sets of list filters, e.g. `hunt filter save remote-senior --status new --remote`
+    Filter {
+        #[command(subcommand)]
+        command: FilterCommands,
+    },
+
+    /// Add a note to a job, or list its notes
+    Note {
+        /// Job ID
+        #[arg(add = ArgValueCandidates::new(completions::jobs))]
+        job_id: i64,
+
+        /// Note text (omit to list notes)
+        text: Option<String>,
+    },
+
+    /// Tag a job: +tag (or bare tag) adds, -tag removes; no tags lists them
+    Tag {
+        /// Job ID
+        #[arg(add = ArgValueCandidates::new(completions::jobs))]
+        job_id: i64,
+
+        /// Tags to add or remove, e.g. +remote +golang -onsite
+        #[arg(allow_hyphen_values = true)]
+        tags: Vec<String>,
+    },
+
+    /// Set when a job's applications close or when it was posted; no flags shows both
+    Set {
+        /// Job ID
+        #[arg(add = ArgValueCandidates::new(completions::jobs))]
+        job_id: i64,
+
+        /// Application deadline: YYYY-MM-DD, today, tomorrow, or e.g. 3d or 2w from now
+        #[arg(long, value_name = "DATE")]
+        deadline: Option<String>,
+
+        /// Clear the deadline
+        #[arg(long, conflicts_with = "deadline")]
+        no_deadline: bool,
+
+        /// Posting date: YYYY-MM-DD, or e.g. 3d or 2w ago
+        #[arg(long, value_name = "DATE")]
+        posted: Option<String>,
+    },
+
+    /// Set a job's next action ("follow up with recruiter") and when it's due; no text shows it
+    Next {
+        /// Job ID
+        #[arg(add = ArgValueCandidates::new(completions::jobs))]
+        job_id: i64,
+
+        /// What to do next
+        action: Option<String>,
+
+        /// When: today, tomorrow, e.g. 3d or 2w from now, or YYYY-MM-DD
+        #[arg(long, default_value = "today", requires = "action")]
+        due: String,
+
+        /// Mark the next action done (clears it)
+        #[arg(long, conflicts_with = "action")]
+        done: bool,
+    },
+
+    /// Next actions that are overdue or due today, plus suggested follow-ups
+    Todo {
+        /// Also list actions due later
+        #[arg(long)]
+        all: bool,
+
+        /// Email the list to yourself (config "smtp"), if anything is due
+        #[arg(long)]
+        email: bool,
+    },
+
+    /// Send email through the config's "smtp" server
+    Mail {
+        #[command(subcommand)]
+        command: MailCommands,
+    },
+
+    /// Track recruiters and referrals, linked to jobs and employers
+    Contact {
+        #[command(subcommand)]
+        command: ContactCommands,
+    },
+
+    /// Archive jobs: hidden from lists and ranking, kept for `hunt restore`
+    Delete {
+        /// Job IDs
+        #[arg(required = true, add = ArgValueCandidates::new(completions::jobs))]
+        job_ids: Vec<i64>,
+
+        /// Remove the jobs and everything attached to them for good
+        #[arg(long)]
+        purge: bool,
+    },
+
+    /// Bring archived jobs back
+    Restore {
+        /// Job IDs
+        #[arg(required = true)]
+        job_ids: Vec<i64>,
+    },
+
+    /// Fold a duplicate job into another: its description, snapshots, keywords, notes and
+    /// application history move to the kept job, then the duplicate is archived
+    Merge {
+        /// Job to keep
+        keep_id: i64,
+
+        /// Duplicate to fold in
+        dup_id: i64,
+
+        /// Remove the duplicate for good instead of archiving it
+        #[arg(long)]
+        purge: bool,
     },
 
     /// Show job details
     Show {
         /// Job ID
+        #[arg(add = ArgValueCandidates::new(completions::jobs))]
         id: i64,
 
         /// Show raw job description text even when AI summary exists
         #[arg(long)]
         raw: bool,
+
+        /// Render with a minijinja template instead (path, or a name in templates/ next to hunt.db)
+        #[arg(long, value_name = "FILE")]
+        template: Option<PathBuf>,
+    },
+
+    /// Show how a job's description changed between fetches (pay, closed notices, edits)
+    Diff {
+        /// Job ID
+        job_id: i64,
+
+        /// Only print the one-line summary for each change, not the text diff
+        #[arg(long)]
+        summary: bool,
     },
 
     /// Manage employers
@@ -57,22 +319,139 @@ enum Commands {
         command: EmployerCommands,
     },
 
+    /// Manage job pipeline statuses
+    Status {
+        #[command(subcommand)]
+        command: StatusCommands,
+    },
+
     /// Show ranked jobs
     Rank {
         /// Number of jobs to show
         #[arg(short, long, default_value = "10")]
         limit: usize,
+
+        /// Show the per-factor score breakdown for one job
+        #[arg(long, value_name = "JOB_ID")]
+        explain: Option<i64>,
+
+        /// Only rank jobs advertising at least this much (annual USD)
+        #[arg(long, value_name = "USD", conflicts_with = "explain")]
+        min_pay: Option<i64>,
+
+        /// Only rank jobs advertising at most this much (annual USD)
+        #[arg(long, value_name = "USD", conflicts_with = "explain")]
+        max_pay: Option<i64>,
+
+        /// Leave out jobs whose description rules out visa sponsorship or wants citizens only
+        #[arg(long, conflicts_with = "explain")]
+        sponsors_visa: bool,
+
+        /// Leave out jobs whose description requires a security clearance
+        #[arg(long, conflicts_with = "explain")]
+        no_clearance: bool,
+    },
+
+    /// Change settings in config.json, e.g. `hunt config set home "Austin, TX"`
+    Config {
+        #[command(subcommand)]
+        command: ConfigCommands,
+    },
+
+    /// Configure ranking factor weights
+    Weights {
+        #[command(subcommand)]
+        command: WeightsCommands,
+    },
+
+    /// Manage your skill profile (used for keyword-match scoring) and named profiles
+    /// (separate databases and configs, e.g. one per kind of search)
+    Profile {
+        #[command(subcommand)]
+        command: ProfileCommands,
+    },
+
+    /// Inspect or clear cached AI responses (used by analyze, keywords, fit)
+    Cache {
+        #[command(subcommand)]
+        command: CacheCommands,
+    },
+
+    /// Manage permanent negative keywords (hidden from `list`, penalized in `rank`)
+    Exclude {
+        #[command(subcommand)]
+        command: ExcludeCommands,
+    },
+
+    /// Boost or penalize the rank of jobs that ask for a keyword
+    Rule {
+        #[command(subcommand)]
+        command: RuleCommands,
+    },
+
+    /// Find skill gaps across your target jobs and plan how to close them
+    Learn {
+        #[command(subcommand)]
+        command: LearnCommands,
+    },
+
+    /// Group the active pipeline into clusters of similar roles by keyword overlap
+    Cluster {
+        /// Minimum keyword similarity (cosine, 0-1) for jobs to share a cluster
+        #[arg(short, long, default_value = "0.35")]
+        threshold: f64,
+
+        /// Smallest group kept as a cluster
+        #[arg(long, default_value = "2")]
+        min_size: usize,
+
+        /// AI model used to name clusters (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Name clusters after their top keywords instead of asking the AI
+        #[arg(long)]
+        no_ai: bool,
+
+        /// Show the stored clusters without recomputing
+        #[arg(long)]
+        show: bool,
+    },
+
+    /// Sign in to job sites once in a visible browser; headless fetches reuse the session
+    Auth {
+        #[command(subcommand)]
+        command: AuthCommands,
     },
 
     /// Fetch job alerts from email
     Email {
-        /// Gmail address
-        #[arg(short, long, default_value = "jciispam@gmail.com")]
-        username: String,
+        #[command(subcommand)]
+        command: Option<EmailCommands>,
+
+        /// Gmail address (default: jciispam@gmail.com; skipped if only --account is given)
+        #[arg(short, long)]
+        username: Option<String>,
+
+        /// Path to app password file (default: ~/.gmail.app_password.txt)
+        #[arg(short, long)]
+        password_file: Option<String>,
+
+        /// Additional IMAP account as USERNAME,PASSWORD_FILE[,SERVER[:PORT]] (repeatable)
+        #[arg(long = "account", value_name = "SPEC")]
+        accounts: Vec<String>,
 
-        /// Path to app password file
-        #[arg(short, long, default_value = "~/.gmail.app_password.txt")]
-        password_file: String,
+        /// Log in to the Gmail account with OAuth2 (see `hunt email auth`) instead of an app password
+        #[arg(long)]
+        oauth: bool,
+
+        /// Read alerts from a local maildir (mbsync, offlineimap) instead of IMAP
+        #[arg(long, value_name = "DIR", conflicts_with_all = ["username", "password_file", "accounts", "oauth", "mbox"])]
+        maildir: Option<PathBuf>,
+
+        /// Read alerts from a local mbox file instead of IMAP
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["username", "password_file", "accounts", "oauth"])]
+        mbox: Option<PathBuf>,
 
         /// Number of days to look back
         #[arg(short, long, default_value = "7")]
@@ -82,9 +461,25 @@ enum Commands {
         #[arg(long)]
         dry_run: bool,
 
-        /// Verbose logging (show IMAP commands, timing, error details)
-        #[arg(short, long)]
-        verbose: bool,
+        /// Process emails again even if an earlier run already ingested them
+        #[arg(long)]
+        reprocess: bool,
+
+        /// Ask the AI for the jobs in alerts the parsers find none in (stored with source "email-ai")
+        #[arg(long)]
+        ai_fallback: bool,
+
+        /// AI model for --ai-fallback (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long, requires = "ai_fallback")]
+        model: Option<String>,
+
+        /// Send a desktop notification listing new jobs that rank at or above --notify-score
+        #[arg(long)]
+        notify: bool,
+
+        /// Minimum rank score for --notify (see `hunt rank`)
+        #[arg(long, default_value_t = DEFAULT_NOTIFY_SCORE, requires = "notify")]
+        notify_score: f64,
     },
 
     /// Manage resumes
@@ -103,6 +498,23 @@ enum Commands {
         #[arg(long)]
         duplicates: bool,
 
+        /// With --duplicates: merge each duplicate into the job it duplicates before removing it
+        #[arg(long)]
+        merge: bool,
+
+        /// Strip tracking parameters from stored job URLs (utm_*, trk, refId; LinkedIn and
+        /// Indeed links become their plain job view)
+        #[arg(long)]
+        urls: bool,
+
+        /// Re-read pay from stored descriptions (hourly, monthly and non-USD as annual USD)
+        #[arg(long)]
+        pay: bool,
+
+        /// Delete artifacts and duplicates for good instead of archiving them
+        #[arg(long)]
+        purge: bool,
+
         /// Run all cleanup operations
         #[arg(long)]
         all: bool,
@@ -110,6 +522,148 @@ enum Commands {
         /// Show what would be removed without removing
         #[arg(long)]
         dry_run: bool,
+
+        /// Ask before removing each artifact or duplicate: remove, keep, fix its title, or
+        /// always remove/keep ones like it in later cleanups
+        #[arg(long, short)]
+        interactive: bool,
+
+        /// Forget the always remove/keep answers given with --interactive
+        #[arg(long)]
+        forget_decisions: bool,
+    },
+
+    /// Close stale jobs: re-check postings not updated recently and close the ones that are gone
+    Prune {
+        /// How long without an update makes a job stale (e.g. 45d, 8w, or a YYYY-MM-DD date)
+        #[arg(long, default_value = "45d")]
+        older_than: String,
+
+        /// Close every stale job without checking its posting
+        #[arg(long)]
+        no_check: bool,
+
+        /// Check postings even where robots.txt disallows it
+        #[arg(long)]
+        ignore_robots: bool,
+
+        /// Show what would be closed without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Prune old snapshots, archive old closed jobs, and VACUUM the database
+    Compact {
+        /// Cutoff date (YYYY-MM-DD): only data older than this is touched
+        #[arg(long)]
+        before: String,
+
+        /// Archive database file (default: hunt-archive.db next to hunt.db)
+        #[arg(long)]
+        archive: Option<PathBuf>,
+
+        /// Show what would be pruned/archived without changing anything
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Write a dated journal entry summarizing recent job-search activity
+    Journal {
+        /// How far back to look: 36h, 7d, 2w, or a YYYY-MM-DD date
+        #[arg(long, default_value = "7d")]
+        since: String,
+
+        /// Have the AI turn the activity log into a short narrative
+        #[arg(long)]
+        polish: bool,
+
+        /// AI model to use with --polish (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Journal file to append to (default: journal.md next to hunt.db)
+        #[arg(long)]
+        file: Option<PathBuf>,
+
+        /// Print the entry without appending it
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Weekly summary: new jobs, applications, interviews, top unapplied jobs and employer research
+    Digest {
+        /// Cover the last seven days (the default)
+        #[arg(long, conflicts_with = "since")]
+        week: bool,
+
+        /// How far back to look instead: 36h, 7d, 2w, or a YYYY-MM-DD date
+        #[arg(long)]
+        since: Option<String>,
+
+        /// Output format
+        #[arg(long, value_enum, default_value = "markdown")]
+        format: digest::Format,
+
+        /// Number of unapplied jobs to list
+        #[arg(long, default_value = "5")]
+        top: usize,
+
+        /// Write the digest to this file instead of printing it
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Render with a minijinja template instead of --format (gets `from`, `to`, `new_jobs`, `applied`, `interviews`, `top_unapplied`, `employers_researched`)
+        #[arg(long, conflicts_with = "format")]
+        template: Option<PathBuf>,
+
+        /// Also email it to yourself (config "smtp"), HTML with a markdown fallback
+        #[arg(long)]
+        email: bool,
+    },
+
+    /// Serve jobs, employers, rank and stats (and status/note changes) as a JSON HTTP API
+    Serve {
+        /// Port to listen on
+        #[arg(short, long, default_value = "8080")]
+        port: u16,
+
+        /// Address to listen on; anything but loopback needs --token-file
+        #[arg(long, default_value = "127.0.0.1")]
+        host: String,
+
+        /// File holding the API token clients send as "Authorization: Bearer <token>"
+        #[arg(long, value_name = "FILE")]
+        token_file: Option<String>,
+
+        /// Let pages from this origin read responses (CORS), e.g. http://localhost:3000
+        #[arg(long, value_name = "ORIGIN")]
+        allow_origin: Option<String>,
+    },
+
+    /// Market trends across everything ingested so far
+    Trends {
+        #[command(subcommand)]
+        command: TrendsCommands,
+    },
+
+    /// Pipeline dashboard: status counts, conversion, weekly applications, fit, employers, pay
+    Stats {
+        /// Weeks of application history to show
+        #[arg(long, default_value = "8")]
+        weeks: u32,
+    },
+
+    /// Reports over the job status history
+    Report {
+        #[command(subcommand)]
+        command: ReportCommands,
+    },
+
+    /// Report AI token usage and estimated spend per model and command
+    Usage {
+        /// How far back to look: 36h, 7d, 2w, or a YYYY-MM-DD date
+        #[arg(long, default_value = "30d")]
+        since: String,
     },
 
     /// Track Glassdoor reviews for watched employers
@@ -131,10 +685,16 @@ enum Commands {
         command: StartupCommands,
     },
 
+    /// Import open roles from job board APIs (Greenhouse, Lever)
+    Board {
+        #[command(subcommand)]
+        command: BoardCommands,
+    },
+
     /// Fetch job description from URL
     Fetch {
         /// Job ID to fetch (not used with --all)
-        #[arg(required_unless_present = "all")]
+        #[arg(required_unless_present_any = ["all", "status"])]
         id: Option<i64>,
 
         /// Fetch all jobs without descriptions
@@ -149,7 +709,7 @@ enum Commands {
         #[arg(long)]
         limit: Option<usize>,
 
-        /// Seconds to wait between fetches (default: 5)
+        /// Seconds to wait between browser fetches from a domain without a rate limit (default: 5)
         #[arg(long, default_value_t = 5)]
         delay: u64,
 
@@ -160,107 +720,461 @@ enum Commands {
         /// Show browser window (headless by default)
         #[arg(long)]
         no_headless: bool,
-    },
-
-    /// AI-powered job analysis
-    Analyze {
-        /// Job ID to analyze
-        job_id: i64,
-
-        /// AI model to use (default: claude-sonnet)
-        #[arg(short, long, default_value = "gpt-5.2")]
-        model: String,
-    },
-
-    /// Extract keywords from a job posting
-    Keywords {
-        /// Job ID to extract keywords from
-        #[arg(required_unless_present_any = ["search", "all"])]
-        job_id: Option<i64>,
-
-        /// AI model to use (default: claude-sonnet)
-        #[arg(short, long, default_value = "gpt-5.2")]
-        model: String,
-
-        /// Search for a keyword across all jobs
-        #[arg(short, long)]
-        search: Option<String>,
 
-        /// Show stored keywords without re-running AI
+        /// Skip the plain HTTP attempt and always use the browser
         #[arg(long)]
-        show: bool,
+        browser_only: bool,
 
-        /// Extract keywords from all jobs with descriptions but no stored keywords
+        /// Browser to automate: firefox or chrome (default: whichever WebDriver is running or installed)
         #[arg(long)]
-        all: bool,
+        driver: Option<String>,
 
-        /// Re-extract keywords even if they already exist (use with --all)
+        /// Fetch even where robots.txt disallows it
         #[arg(long)]
-        force: bool,
+        ignore_robots: bool,
+
+        /// Show fetch successes and failures per domain, and which domains are backed off
+        #[arg(long, conflicts_with_all = ["id", "all"])]
+        status: bool,
     },
 
-    /// Analyze resume fit against a job posting
-    Fit {
-        /// Job ID to compare against (not used with --all)
+    /// Follow job URLs through redirects to the employer's ATS (Workday, Taleo, iCIMS,
+    /// Greenhouse...) and store where applications go
+    Resolve {
+        /// Job ID (not used with --all)
         #[arg(required_unless_present = "all")]
         job_id: Option<i64>,
 
-        /// Base resume name or ID
-        #[arg(short, long)]
-        resume: String,
-
-        /// AI model to use (default: claude-sonnet)
-        #[arg(short, long, default_value = "gpt-5.2")]
-        model: String,
+        /// Every open job that hasn't been resolved yet
+        #[arg(long, conflicts_with = "job_id")]
+        all: bool,
 
-        /// Run fit analysis on all jobs with descriptions
+        /// Maximum number of jobs to resolve (used with --all)
         #[arg(long)]
-        all: bool,
+        limit: Option<usize>,
 
-        /// Re-analyze even if fit analysis already exists (use with --all)
+        /// Follow URLs even where robots.txt disallows it
         #[arg(long)]
-        force: bool,
+        ignore_robots: bool,
     },
 
-    /// Browse jobs interactively in a TUI
-    Browse {
-        /// Filter by status (new, reviewing, applied, rejected, closed)
+    /// Fill in job locations and remote/hybrid/onsite from descriptions, asking the AI when they don't say
+    Locate {
+        /// Job ID (default: every job with a description missing either)
+        job_id: Option<i64>,
+
+        /// AI model for descriptions the text rules can't place (default: the config's `model`, else gpt-5.2)
         #[arg(short, long)]
-        status: Option<String>,
+        model: Option<String>,
+
+        /// Only use the text rules, no AI
+        #[arg(long)]
+        no_ai: bool,
+    },
+
+    /// AI-powered job analysis
+    Analyze {
+        /// Job ID to analyze
+        job_id: i64,
+
+        /// AI model to use (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Always call the AI provider, ignoring (but refreshing) cached responses
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Run even on jobs at employers marked never/yuck (skipped by default)
+        #[arg(long)]
+        include_blocked: bool,
+
+        /// Print the analysis only once it's complete instead of as it's generated
+        #[arg(long)]
+        no_stream: bool,
+    },
+
+    /// Store a five-bullet AI summary of a posting, shown by show and the TUI
+    Summarize {
+        /// Job ID to summarize
+        job_id: i64,
+
+        /// AI model to use (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Always call the AI provider, ignoring (but refreshing) cached responses
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Run even on jobs at employers marked never/yuck (skipped by default)
+        #[arg(long)]
+        include_blocked: bool,
+    },
+
+    /// Red flags in a posting (fast-paced, on-call, unlimited PTO, clearance...), from phrase rules plus AI
+    Flags {
+        /// Job ID to check
+        #[arg(required_unless_present = "all")]
+        job_id: Option<i64>,
+
+        /// Re-run the phrase rules (red flags, clearance, sponsorship) on every job with a description (no AI)
+        #[arg(long, conflicts_with_all = ["job_id", "model", "no_cache"])]
+        all: bool,
+
+        /// AI model to use (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Only use the phrase rules, no AI
+        #[arg(long)]
+        no_ai: bool,
+
+        /// Always call the AI provider, ignoring (but refreshing) cached responses
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Run even on jobs at employers marked never/yuck (skipped by default)
+        #[arg(long)]
+        include_blocked: bool,
+    },
+
+    /// Extract keywords from a job posting
+    #[command(args_conflicts_with_subcommands = true, subcommand_negates_reqs = true)]
+    Keywords {
+        #[command(subcommand)]
+        command: Option<KeywordsCommands>,
+
+        /// Job ID to extract keywords from
+        #[arg(required_unless_present_any = ["search", "all", "report"])]
+        job_id: Option<i64>,
+
+        /// AI model to use (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Extract with several models (comma-separated, e.g. claude-sonnet,gpt-4o)
+        #[arg(long, conflicts_with_all = ["search", "show", "all"])]
+        models: Option<String>,
+
+        /// Print which keywords each of --models found or missed, side by side
+        #[arg(long, requires = "models")]
+        compare: bool,
+
+        /// Search for a keyword across all jobs
+        #[arg(short, long)]
+        search: Option<String>,
+
+        /// Show stored keywords without re-running AI
+        #[arg(long)]
+        show: bool,
+
+        /// Extract keywords from all jobs with descriptions but no stored keywords
+        #[arg(long)]
+        all: bool,
+
+        /// Summarize stored keywords across open jobs: most-demanded tech, weight per
+        /// domain, and required keywords missing from your skill profile
+        #[arg(long, conflicts_with_all = ["search", "show", "all", "models"])]
+        report: bool,
+
+        /// Keywords per section of --report
+        #[arg(long, default_value = "15", requires = "report")]
+        top: usize,
+
+        /// Re-extract keywords even if they already exist (use with --all)
+        #[arg(long)]
+        force: bool,
+
+        /// Maximum AI calls to spend this run, on shortlisted, then the highest-ranked jobs (use with --all)
+        #[arg(long)]
+        budget: Option<usize>,
+
+        /// Parallel AI requests (use with --all)
+        #[arg(short = 'j', long, default_value = "1")]
+        concurrency: usize,
+
+        /// Always call the AI provider, ignoring (but refreshing) cached responses
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Run even on jobs at employers marked never/yuck (skipped by default)
+        #[arg(long)]
+        include_blocked: bool,
+    },
+
+    /// Quiz yourself on keywords marked unknown, on a spaced-repetition schedule
+    Review {
+        /// Most cards to review this session
+        #[arg(short, long, default_value = "10")]
+        limit: usize,
+
+        /// AI model that writes the questions (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// List all cards and when they're due instead of reviewing
+        #[arg(long)]
+        list: bool,
+    },
+
+    /// Analyze resume fit against a job posting
+    Fit {
+        /// Job ID to compare against (not used with --all)
+        #[arg(required_unless_present = "all")]
+        job_id: Option<i64>,
+
+        /// Base resume name or ID
+        #[arg(short, long)]
+        resume: String,
+
+        /// AI model to use (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Run fit analysis on all jobs with descriptions
+        #[arg(long)]
+        all: bool,
+
+        /// Re-analyze even if fit analysis already exists (use with --all)
+        #[arg(long)]
+        force: bool,
+
+        /// Parallel AI requests (use with --all)
+        #[arg(short = 'j', long, default_value = "1")]
+        concurrency: usize,
+
+        /// Always call the AI provider, ignoring (but refreshing) cached responses
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Run even on jobs at employers marked never/yuck (skipped by default)
+        #[arg(long)]
+        include_blocked: bool,
+    },
+
+    /// Generate likely interview questions for a job from its keywords and your resume's gaps
+    Prep {
+        /// Job ID to prepare for
+        job_id: i64,
+
+        /// Base resume name or ID
+        #[arg(short, long, required_unless_present = "show")]
+        resume: Option<String>,
+
+        /// Print the stored questions instead of generating new ones
+        #[arg(long, conflicts_with_all = ["resume", "model", "no_cache"])]
+        show: bool,
+
+        /// AI model to use (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Always call the AI provider, ignoring (but refreshing) cached responses
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Run even on jobs at employers marked never/yuck (skipped by default)
+        #[arg(long)]
+        include_blocked: bool,
+    },
+
+    /// Questions to ask the interviewer, drawn from the employer's stored research
+    Questions {
+        /// Job ID you're interviewing for
+        job_id: i64,
+
+        /// AI model to use (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Always call the AI provider, ignoring (but refreshing) cached responses
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Run even on jobs at employers marked never/yuck (skipped by default)
+        #[arg(long)]
+        include_blocked: bool,
+    },
+
+    /// Salary negotiation brief with anchor numbers, from posted pay, employer comp bands, Glassdoor and fit
+    Negotiate {
+        /// Job ID to negotiate
+        job_id: i64,
+
+        /// Print the stored brief instead of writing a new one
+        #[arg(long, conflicts_with_all = ["model", "no_cache"])]
+        show: bool,
+
+        /// AI model to use (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Always call the AI provider, ignoring (but refreshing) cached responses
+        #[arg(long)]
+        no_cache: bool,
+
+        /// Run even on jobs at employers marked never/yuck (skipped by default)
+        #[arg(long)]
+        include_blocked: bool,
+    },
+
+    /// Browse jobs interactively in a TUI
+    Browse {
+        /// Filter by status (see `hunt status list`)
+        #[arg(short, long)]
+        status: Option<String>,
 
         /// Filter by employer
         #[arg(short, long)]
         employer: Option<String>,
+
+        /// AI model for keywords (W) and fit (T) run from the browser (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+
+        /// Base resume name or ID for fit (T); defaults to the only one, if there's just one
+        #[arg(short, long)]
+        resume: Option<String>,
+
+        /// Start with this pay floor on (annual USD); `p` toggles it. Default: the config's `min_pay`, off
+        #[arg(long, value_name = "USD")]
+        min_pay: Option<i64>,
+
+        /// Only load jobs advertising at most this much (annual USD)
+        #[arg(long, value_name = "USD")]
+        max_pay: Option<i64>,
     },
 
     /// Check external dependencies (geckodriver, Firefox, etc.)
     Check,
 
-    /// Run full refresh pipeline: email → fetch → keywords
+    /// Print a shell completion script; e.g. add `source <(hunt completions bash)` to ~/.bashrc
+    Completions {
+        /// Shell to complete in
+        shell: completions::Shell,
+    },
+
+    /// Run full refresh pipeline: email → fetch → keywords (→ fit with --fit)
     Refresh {
-        /// Gmail address
-        #[arg(short, long, default_value = "jciispam@gmail.com")]
-        username: String,
+        /// Gmail address (default: jciispam@gmail.com; skipped if only --account is given)
+        #[arg(short, long)]
+        username: Option<String>,
+
+        /// Path to app password file (default: ~/.gmail.app_password.txt)
+        #[arg(short, long)]
+        password_file: Option<String>,
 
-        /// Path to app password file
-        #[arg(short, long, default_value = "~/.gmail.app_password.txt")]
-        password_file: String,
+        /// Additional IMAP account as USERNAME,PASSWORD_FILE[,SERVER[:PORT]] (repeatable)
+        #[arg(long = "account", value_name = "SPEC")]
+        accounts: Vec<String>,
+
+        /// Log in to the Gmail account with OAuth2 (see `hunt email auth`) instead of an app password
+        #[arg(long)]
+        oauth: bool,
 
         /// Number of days to look back for emails
         #[arg(short, long, default_value = "7")]
         days: u32,
 
-        /// AI model for keyword extraction
-        #[arg(short, long, default_value = "gpt-5.2")]
-        model: String,
+        /// AI model for keyword extraction (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
 
         /// Show browser window (headless by default)
         #[arg(long)]
         no_headless: bool,
 
-        /// Seconds to wait between fetches
+        /// Skip the plain HTTP attempt and always use the browser
+        #[arg(long)]
+        browser_only: bool,
+
+        /// Browser to automate: firefox or chrome (default: whichever WebDriver is running or installed)
+        #[arg(long)]
+        driver: Option<String>,
+
+        /// Seconds to wait between browser fetches from a domain without a rate limit
         #[arg(long, default_value_t = 5)]
         delay: u64,
+
+        /// Extract keywords even for jobs at employers marked never/yuck (skipped by default)
+        #[arg(long)]
+        include_blocked: bool,
+
+        /// Fetch even where robots.txt disallows it
+        #[arg(long)]
+        ignore_robots: bool,
+
+        /// Send a desktop notification listing new jobs that rank at or above --notify-score
+        #[arg(long)]
+        notify: bool,
+
+        /// Minimum rank score for --notify (see `hunt rank`)
+        #[arg(long, default_value_t = DEFAULT_NOTIFY_SCORE, requires = "notify")]
+        notify_score: f64,
+
+        /// Continue the last interrupted refresh, skipping stages and jobs it already got through
+        #[arg(long)]
+        resume: bool,
+
+        /// Don't check email for new job alerts
+        #[arg(long)]
+        skip_email: bool,
+
+        /// Don't fetch job descriptions
+        #[arg(long)]
+        skip_fetch: bool,
+
+        /// Don't extract keywords
+        #[arg(long)]
+        skip_keywords: bool,
+
+        /// Finish with a fit analysis of active jobs not yet analyzed against --fit-resume
+        #[arg(long)]
+        fit: bool,
+
+        /// Base resume name or ID for the fit stage (default: your only base resume)
+        #[arg(long, value_name = "RESUME", add = ArgValueCandidates::new(completions::resumes))]
+        fit_resume: Option<String>,
+
+        /// Run only these stages, e.g. keywords,fit (email, fetch, keywords, fit)
+        #[arg(long, value_name = "STAGES", value_delimiter = ',', conflicts_with_all = ["skip_email", "skip_fetch", "skip_keywords", "fit"])]
+        only: Vec<pipeline::Stage>,
+    },
+}
+
+#[derive(Subcommand)]
+enum EmailCommands {
+    /// Authorize Gmail access via OAuth2 in the browser and store the refresh token
+    ///
+    /// Needs an OAuth client of type "Desktop app": in the Google Cloud console, enable the
+    /// Gmail API, configure the OAuth consent screen (add yourself as a test user), then
+    /// create credentials > OAuth client ID > Desktop app. Pass its ID and secret with
+    /// --client-id/--client-secret or GOOGLE_CLIENT_ID/GOOGLE_CLIENT_SECRET. hunt opens
+    /// the consent page and listens on 127.0.0.1 for Google's redirect, so run it on a
+    /// machine with a browser.
+    Auth {
+        /// Gmail address
+        #[arg(short, long, default_value = "jciispam@gmail.com")]
+        username: String,
+
+        /// OAuth client ID (default: $GOOGLE_CLIENT_ID)
+        #[arg(long)]
+        client_id: Option<String>,
+
+        /// OAuth client secret (default: $GOOGLE_CLIENT_SECRET)
+        #[arg(long)]
+        client_secret: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum AuthCommands {
+    /// Open LinkedIn's sign-in page and keep the session in hunt's browser profile
+    Linkedin {
+        /// Browser to automate: firefox or chrome (default: whichever WebDriver is running or installed)
+        #[arg(long)]
+        driver: Option<String>,
     },
 }
 
@@ -276,110 +1190,310 @@ enum EmployerCommands {
     /// Mark employer as blocked (never apply)
     Block {
         /// Employer name
+        #[arg(add = ArgValueCandidates::new(completions::employers))]
         name: String,
     },
 
     /// Mark employer as undesirable (apply reluctantly)
     Yuck {
         /// Employer name
+        #[arg(add = ArgValueCandidates::new(completions::employers))]
         name: String,
     },
 
     /// Clear employer status (ok to apply)
     Ok {
         /// Employer name
+        #[arg(add = ArgValueCandidates::new(completions::employers))]
         name: String,
     },
 
     /// Show employer details
     Show {
         /// Employer name or ID
+        #[arg(add = ArgValueCandidates::new(completions::employers))]
         name: String,
+
+        /// Render with a minijinja template instead (gets `employer`, `aliases`, `hn_stories`, `comp`, `contacts`, `jobs`)
+        #[arg(long)]
+        template: Option<PathBuf>,
     },
 
     /// Research startup info (funding, YC, HN mentions)
     Research {
         /// Employer name
+        #[arg(add = ArgValueCandidates::new(completions::employers))]
         name: String,
     },
 
     /// Research public company controversies and practices
     Evil {
         /// Employer name
+        #[arg(add = ArgValueCandidates::new(completions::employers))]
         name: String,
     },
 
     /// Research private company ownership (parent, PE/VC, investors)
     Ownership {
         /// Employer name
+        #[arg(add = ArgValueCandidates::new(completions::employers))]
         name: String,
     },
-}
 
-#[derive(Subcommand)]
-enum ResumeCommands {
-    /// Add a base resume
-    Add {
-        /// Name for this resume
+    /// Research engineering culture signals (tech blog, RSS, conference talks)
+    Culture {
+        /// Employer name
+        #[arg(add = ArgValueCandidates::new(completions::employers))]
         name: String,
 
-        /// Format (markdown, plain, json, latex)
-        #[arg(short, long, default_value = "markdown")]
-        format: String,
+        /// AI model to use (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+    },
 
-        /// Path to resume file
-        file: PathBuf,
+    /// Research typical compensation bands (Levels.fyi-style) and flag jobs posted below them
+    Comp {
+        /// Employer name
+        #[arg(add = ArgValueCandidates::new(completions::employers))]
+        name: String,
 
-        /// Optional notes about this resume
+        /// Level to research (repeatable; default: the levels of the employer's open jobs)
+        #[arg(long = "level", value_name = "LEVEL")]
+        levels: Vec<String>,
+
+        /// Research again even if bands are on record
+        #[arg(long)]
+        force: bool,
+
+        /// AI model to use (default: the config's `model`, else gpt-5.2)
         #[arg(short, long)]
-        notes: Option<String>,
+        model: Option<String>,
     },
 
-    /// List base resumes
-    List,
+    /// Merge a duplicate employer into another (jobs, reviews, alerts and research move over)
+    Merge {
+        /// Duplicate employer to remove
+        #[arg(add = ArgValueCandidates::new(completions::employers))]
+        from: String,
 
-    /// Show a base resume
-    Show {
-        /// Resume name or ID
-        name: String,
+        /// Employer to keep
+        #[arg(add = ArgValueCandidates::new(completions::employers))]
+        into: String,
     },
 
-    /// Generate a tailored resume variant for a job
-    Tailor {
-        /// Job ID to tailor resume for
-        job_id: i64,
-
-        /// Base resume name or ID
-        #[arg(short, long)]
-        resume: String,
+    /// Rename an employer
+    Rename {
+        /// Current name
+        #[arg(add = ArgValueCandidates::new(completions::employers))]
+        old: String,
 
-        /// Single AI model to use (default: claude-sonnet)
-        #[arg(long, default_value = "gpt-5.2")]
-        model: String,
+        /// New name
+        new: String,
+    },
 
-        /// Multiple AI models (comma-separated, e.g. claude-sonnet,gpt-4o)
-        #[arg(long)]
-        models: Option<String>,
+    /// Record another name for an employer so ingestion maps it there ("Alphabet" -> Google)
+    Alias {
+        /// The other name
+        alias: String,
 
-        /// Output format: markdown or latex (default: markdown)
-        #[arg(short, long, default_value = "markdown")]
-        format: String,
+        /// Employer it refers to
+        #[arg(add = ArgValueCandidates::new(completions::employers))]
+        employer: String,
+    },
 
-        /// Output file path
-        #[arg(short, long)]
-        output: Option<PathBuf>,
+    /// Remove an alias
+    Unalias {
+        /// Alias to remove
+        alias: String,
+    },
+
+    /// List aliases (merges and renames record them too)
+    Aliases {
+        /// Only this employer's aliases
+        #[arg(add = ArgValueCandidates::new(completions::employers))]
+        employer: Option<String>,
+    },
+
+    /// Find near-duplicate employers ("Acme", "Acme Inc.") and merge them
+    Dedupe {
+        /// Only print suggested merges
+        #[arg(long)]
+        dry_run: bool,
+    },
+
+    /// Find an employer's website domain and careers page from its job URLs (and optionally AI)
+    Enrich {
+        /// Employer name (default: every employer missing a domain or careers page)
+        #[arg(add = ArgValueCandidates::new(completions::employers))]
+        name: Option<String>,
+
+        /// Ask the AI model when the job URLs don't give an answer
+        #[arg(long)]
+        ai: bool,
+
+        /// Replace links already on record
+        #[arg(long)]
+        force: bool,
+
+        /// AI model to use (with --ai) (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+    },
+
+    /// Re-check funding for employers in the pipeline and alert on new rounds, down rounds, layoffs
+    Funding {
+        /// Employer name (default: every employer with an active job)
+        #[arg(add = ArgValueCandidates::new(completions::employers))]
+        name: Option<String>,
+
+        /// Only re-check employers not checked in this many days
+        #[arg(long, default_value = "30")]
+        stale_days: i64,
+
+        /// AI model to use (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+    },
+
+    /// Show funding alerts
+    Alerts {
+        /// Include alerts already acknowledged
+        #[arg(long)]
+        all: bool,
+
+        /// Mark open alerts as acknowledged
+        #[arg(long)]
+        ack: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ResumeCommands {
+    /// Add a base resume
+    Add {
+        /// Name for this resume
+        name: String,
+
+        /// Format (markdown, plain, json, latex)
+        #[arg(short, long, default_value = "markdown")]
+        format: String,
+
+        /// Path to resume file
+        file: PathBuf,
+
+        /// Optional notes about this resume
+        #[arg(short, long)]
+        notes: Option<String>,
+    },
+
+    /// Replace a base resume's content from a file and flag variants tailored from the old version
+    Update {
+        /// Resume name or ID
+        #[arg(add = ArgValueCandidates::new(completions::resumes))]
+        name: String,
+
+        /// Path to the new resume file
+        file: PathBuf,
+
+        /// Replace the notes too
+        #[arg(short, long)]
+        notes: Option<String>,
+    },
+
+    /// List base resumes
+    List,
+
+    /// Show a base resume
+    Show {
+        /// Resume name or ID
+        #[arg(add = ArgValueCandidates::new(completions::resumes))]
+        name: String,
+    },
+
+    /// Generate a tailored resume variant for a job
+    Tailor {
+        /// Job ID to tailor resume for
+        #[arg(add = ArgValueCandidates::new(completions::jobs))]
+        job_id: i64,
+
+        /// Base resume name or ID
+        #[arg(short, long)]
+        #[arg(add = ArgValueCandidates::new(completions::resumes))]
+        resume: String,
+
+        /// Single AI model to use (default: the config's `model`, else gpt-5.2)
+        #[arg(long)]
+        model: Option<String>,
+
+        /// Multiple AI models (comma-separated, e.g. claude-sonnet,gpt-4o)
+        #[arg(long)]
+        models: Option<String>,
+
+        /// Output format: markdown or latex (default: markdown)
+        #[arg(short, long, default_value = "markdown")]
+        format: String,
+
+        /// Output file path
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Run even on jobs at employers marked never/yuck (skipped by default)
+        #[arg(long)]
+        include_blocked: bool,
+
+        /// Print the resume only once it's complete instead of as it's generated
+        #[arg(long)]
+        no_stream: bool,
     },
 
     /// List resume variants for a job
     Variants {
         /// Job ID
         job_id: i64,
+
+        /// Include older generations, not just the latest of each variant
+        #[arg(long)]
+        history: bool,
+    },
+
+    /// Print a resume variant (any version) by ID
+    ShowVariant {
+        /// Variant ID (from 'hunt resume variants')
+        id: i64,
+    },
+
+    /// Render a resume variant to PDF (pdflatex for LaTeX, pandoc for markdown)
+    Render {
+        /// Variant ID (from 'hunt resume variants')
+        variant_id: i64,
+
+        /// PDF path to write (default: resume-<job>-v<variant>.pdf)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+    },
+
+    /// Check a variant's coverage of the job's extracted keywords (no AI call)
+    Check {
+        /// Variant ID (from 'hunt resume variants')
+        variant_id: i64,
+
+        /// Keywords from this model (default: most recent extraction)
+        #[arg(long)]
+        model: Option<String>,
     },
 
     /// Compare resume variants for a job side by side
     Compare {
         /// Job ID
         job_id: i64,
+
+        /// Show line-level changes from the base resume to each variant
+        #[arg(long)]
+        diff: bool,
+
+        /// Diff two variants against each other instead (variant IDs)
+        #[arg(long, num_args = 2, value_names = ["A", "B"], requires = "diff")]
+        between: Option<Vec<i64>>,
     },
 }
 
@@ -399,9 +1513,9 @@ enum GlassdoorCommands {
         #[arg(long)]
         force: bool,
 
-        /// AI model to use
-        #[arg(short, long, default_value = "gpt-5.2")]
-        model: String,
+        /// AI model to use (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
 
         /// Dry run - show what would be fetched without storing
         #[arg(long)]
@@ -416,6 +1530,445 @@ enum GlassdoorCommands {
         /// Employer name
         employer: String,
     },
+
+    /// Show how an employer's rating and sentiment moved across fetches, by month
+    Trend {
+        /// Employer name
+        employer: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum StatusCommands {
+    /// List pipeline statuses in order
+    List,
+
+    /// Add a status (or update an existing one)
+    Add {
+        /// Status name
+        name: String,
+
+        /// Pipeline position (default: after the last status)
+        #[arg(long)]
+        position: Option<i64>,
+
+        /// Terminal status (excluded from ranking, hidden in browse)
+        #[arg(long)]
+        terminal: bool,
+
+        /// Display color in the TUI (e.g. green, yellow, red)
+        #[arg(long, default_value = "white")]
+        color: String,
+    },
+
+    /// Remove a status (must not be used by any job)
+    Remove {
+        /// Status name
+        name: String,
+    },
+
+    /// Move a job to a status
+    Set {
+        /// Job ID
+        job_id: i64,
+
+        /// Status name
+        status: String,
+
+        /// Allow reopening a terminal status or moving an applied job back
+        #[arg(long)]
+        force: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum WeightsCommands {
+    /// Show each ranking factor's weight
+    List,
+
+    /// Set a factor's weight (0 disables it, 2 doubles it)
+    Set {
+        /// Factor name (see `hunt weights list`)
+        factor: String,
+
+        /// Multiplier applied to the factor's points
+        weight: f64,
+    },
+
+    /// Restore default weights
+    Reset,
+}
+
+#[derive(Subcommand)]
+enum ProfileCommands {
+    /// Show your skill profile
+    Skills,
+
+    /// Replace your skill profile with a comma-separated list
+    SetSkills {
+        /// e.g. "kubernetes,rust,aws"
+        skills: String,
+    },
+
+    /// Have the AI derive your skill profile from base resumes (replaces the current one)
+    DeriveSkills {
+        /// Only use this resume (ID or name); default: all base resumes
+        #[arg(short, long)]
+        resume: Option<String>,
+
+        /// AI model to use (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+    },
+
+    /// List named profiles; * marks the current one
+    List,
+
+    /// Create a named profile with its own database and config
+    Create {
+        /// Letters, digits, '-' and '_', e.g. contracting
+        name: String,
+    },
+
+    /// Make a profile the default for commands run without --profile ("default" to go back)
+    Switch {
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum TrendsCommands {
+    /// Median advertised pay per month, optionally for one role family
+    Pay {
+        /// Role family from the job title (sre, devops, platform, management, ...)
+        #[arg(short, long)]
+        role: Option<String>,
+
+        /// How far back to go: months (6m) or years (1y)
+        #[arg(short, long, default_value = "6m")]
+        window: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum ReportCommands {
+    /// Jobs added and moved into each status per week, and how far recent jobs got
+    Funnel {
+        /// Number of weeks to cover, ending with this one
+        #[arg(long, default_value = "8")]
+        weeks: u32,
+    },
+}
+
+#[derive(Subcommand)]
+enum CacheCommands {
+    /// Show cache size and hit counts
+    Stats,
+
+    /// Delete cached responses
+    Clear {
+        /// Only this model's responses (short name, e.g. gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum KeywordsCommands {
+    /// Mark keywords you don't really know, for `hunt review`
+    Unknown {
+        #[arg(required = true)]
+        keywords: Vec<String>,
+    },
+
+    /// Stop reviewing keywords you've learned
+    Known {
+        #[arg(required = true)]
+        keywords: Vec<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ConfigCommands {
+    /// Show the config file's path and settings
+    Show,
+
+    /// Set a setting: home (a city or "lat,lon", for commute scoring), model, firefox_profile
+    Set {
+        key: String,
+        value: String,
+    },
+
+    /// Remove a setting, going back to its default
+    Unset {
+        key: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum RuleCommands {
+    /// Show your rules
+    List,
+
+    /// Add a rule, e.g. `hunt rule add --keyword rust --boost 10` or
+    /// `hunt rule add --keyword on-call --penalty 15`. Replaces any rule for the keyword
+    Add {
+        /// Keyword, matched against the job's extracted keywords and title
+        #[arg(long)]
+        keyword: String,
+
+        /// Points added to matching jobs' scores
+        #[arg(long, required_unless_present = "penalty", conflicts_with = "penalty")]
+        boost: Option<f64>,
+
+        /// Points taken off matching jobs' scores
+        #[arg(long)]
+        penalty: Option<f64>,
+    },
+
+    /// Delete the rule for a keyword
+    Remove {
+        keyword: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum FilterCommands {
+    /// Show your saved filters
+    List,
+
+    /// Save a filter, replacing any of the same name
+    Save {
+        /// Name to use with `hunt list --filter`
+        name: String,
+
+        /// Only jobs in this status
+        #[arg(short, long)]
+        status: Option<String>,
+
+        /// Only jobs at this employer
+        #[arg(short, long, add = ArgValueCandidates::new(completions::employers))]
+        employer: Option<String>,
+
+        /// Only jobs with this tag
+        #[arg(short, long)]
+        tag: Option<String>,
+
+        /// Only jobs whose title or extracted keywords mention this
+        #[arg(short, long)]
+        keyword: Option<String>,
+
+        /// Only jobs advertising at least this much (annual USD)
+        #[arg(long, value_name = "USD")]
+        min_pay: Option<i64>,
+
+        /// Only jobs advertising at most this much (annual USD)
+        #[arg(long, value_name = "USD")]
+        max_pay: Option<i64>,
+
+        /// Only remote jobs
+        #[arg(long)]
+        remote: bool,
+
+        /// Only LinkedIn jobs that take Easy Apply
+        #[arg(long, conflicts_with = "external_apply")]
+        easy_apply: bool,
+
+        /// Only LinkedIn jobs that send you to the employer's site to apply
+        #[arg(long)]
+        external_apply: bool,
+
+        /// Leave out jobs whose description rules out visa sponsorship or wants citizens only
+        #[arg(long)]
+        sponsors_visa: bool,
+
+        /// Leave out jobs whose description requires a security clearance
+        #[arg(long)]
+        no_clearance: bool,
+
+        /// Only jobs whose location mentions this
+        #[arg(long)]
+        location: Option<String>,
+    },
+
+    /// Delete a saved filter
+    Remove {
+        #[arg(add = ArgValueCandidates::new(completions::filters))]
+        name: String,
+    },
+}
+
+#[derive(Subcommand)]
+enum MailCommands {
+    /// Send a test message to yourself to check the SMTP settings
+    Test,
+
+    /// Email an application for a job, with a tailored resume attached
+    Apply {
+        /// Job ID
+        #[arg(add = ArgValueCandidates::new(completions::jobs))]
+        job_id: i64,
+
+        /// Recruiter or hiring address
+        #[arg(long)]
+        to: String,
+
+        /// Resume variant to render to PDF and attach (from 'hunt resume variants')
+        #[arg(long)]
+        variant: Option<i64>,
+
+        /// Other files to attach
+        #[arg(long, value_name = "FILE")]
+        attach: Vec<PathBuf>,
+
+        /// Message body as a minijinja template (gets the same context as `show --template`)
+        #[arg(long)]
+        template: Option<PathBuf>,
+
+        /// Subject line (default: "Application: <title>")
+        #[arg(long)]
+        subject: Option<String>,
+
+        /// Print the email instead of sending it
+        #[arg(long)]
+        dry_run: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum ContactCommands {
+    /// Add a contact
+    Add {
+        /// Contact's name
+        name: String,
+
+        /// Their role, e.g. recruiter, referral, hiring manager
+        #[arg(long)]
+        role: Option<String>,
+
+        /// Employer they work at
+        #[arg(short, long)]
+        employer: Option<String>,
+
+        /// Email address
+        #[arg(long)]
+        email: Option<String>,
+
+        /// LinkedIn profile URL
+        #[arg(long)]
+        linkedin: Option<String>,
+
+        /// When you were last in touch: YYYY-MM-DD or how long ago (e.g. 3d, 2w)
+        #[arg(long)]
+        last_touch: Option<String>,
+
+        /// Also link the contact to this job
+        #[arg(long)]
+        job: Option<i64>,
+    },
+
+    /// List contacts, most recently in touch first
+    List {
+        /// Only contacts at this employer
+        #[arg(short, long)]
+        employer: Option<String>,
+    },
+
+    /// Link a contact to a job (shown in `hunt show` and the TUI)
+    Link {
+        /// Contact ID
+        contact_id: i64,
+
+        /// Job ID
+        job_id: i64,
+    },
+
+    /// Record that you were in touch with a contact
+    Touch {
+        /// Contact ID
+        contact_id: i64,
+
+        /// When: YYYY-MM-DD or how long ago (e.g. 3d); default today
+        #[arg(long)]
+        date: Option<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum ExcludeCommands {
+    /// Show excluded keywords
+    List,
+
+    /// Exclude keywords, e.g. `hunt exclude add php on-call`
+    Add {
+        #[arg(required = true)]
+        keywords: Vec<String>,
+    },
+
+    /// Stop excluding keywords
+    Remove {
+        #[arg(required = true)]
+        keywords: Vec<String>,
+    },
+}
+
+#[derive(Subcommand)]
+enum LearnCommands {
+    /// Keywords active jobs want that none of your base resumes mention
+    Gaps {
+        #[arg(short, long, default_value = "15")]
+        limit: usize,
+    },
+
+    /// Have the AI turn the top gaps into a sequenced learning plan
+    Plan {
+        /// Hours per week you can spend learning
+        #[arg(long, default_value = "5")]
+        hours_per_week: u32,
+
+        /// How many of the top gaps to plan for
+        #[arg(long, default_value = "8")]
+        top: usize,
+
+        /// AI model to use (default: the config's `model`, else gpt-5.2)
+        #[arg(short, long)]
+        model: Option<String>,
+    },
+
+    /// Show the latest learning plan
+    List,
+
+    /// Check off a plan item
+    Done {
+        /// Item ID (from `hunt learn list`)
+        item_id: i64,
+
+        /// Mark the item as not done again
+        #[arg(long)]
+        undo: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum BoardCommands {
+    /// Fetch a company's open roles and add new ones
+    Fetch {
+        /// Company board slug (e.g. "acme" in boards.greenhouse.io/acme)
+        #[arg(short, long)]
+        company: String,
+
+        /// Job board: greenhouse or lever
+        #[arg(short, long)]
+        source: String,
+
+        /// Employer name to file jobs under (default: the slug)
+        #[arg(short, long)]
+        employer: Option<String>,
+
+        /// Show what would be added without adding
+        #[arg(long)]
+        dry_run: bool,
+    },
 }
 
 #[derive(Subcommand)]
@@ -429,18 +1982,6 @@ enum StartupCommands {
 
 // (glassdoor reviews now fetched via AI in ai::research_glassdoor)
 
-#[derive(Debug, Default)]
-struct StartupResearchData {
-    crunchbase_url: Option<String>,
-    funding_stage: Option<String>,
-    total_funding: Option<i64>,
-    last_funding_date: Option<String>,
-    yc_batch: Option<String>,
-    yc_url: Option<String>,
-    hn_mentions_count: Option<i64>,
-    recent_news: Option<String>,
-}
-
 #[derive(Debug, Default)]
 struct PublicCompanyResearchData {
     controversies: Option<String>,
@@ -450,49 +1991,152 @@ struct PublicCompanyResearchData {
     evil_summary: Option<String>,
 }
 
-fn research_startup(name: &str) -> Result<StartupResearchData> {
-    let mut data = StartupResearchData::default();
+/// `hunt employer research` / `hunt startup research`: look the company up and store
+/// what was found, including its top HN stories.
+fn run_startup_research(db: &Database, name: &str) -> Result<()> {
+    println!("Researching startup info for '{}'...", name);
+
+    let employer_id = db.get_or_create_employer(name)?;
+    let research_data = research::research_startup(name)?;
+
+    db.update_employer_research(
+        employer_id,
+        research_data.crunchbase_url.as_deref(),
+        research_data.funding_stage.as_deref(),
+        research_data.total_funding,
+        research_data.last_funding_date.as_deref(),
+        research_data.yc_batch.as_deref(),
+        research_data.yc_url.as_deref(),
+        research_data.hn_mentions_count,
+        research_data.recent_news.as_deref(),
+    )?;
+    db.replace_employer_hn_stories(employer_id, &research_data.hn_stories)?;
+
+    println!("\n✓ Research complete");
+    if let Some(batch) = &research_data.yc_batch {
+        println!("  YC Batch: {}", batch);
+    }
+    if let Some(stage) = &research_data.funding_stage {
+        println!("  Funding Stage: {}", stage);
+    }
+    if let Some(funding) = research_data.total_funding {
+        println!("  Total Funding: ${}", funding);
+    }
+    if let Some(count) = research_data.hn_mentions_count {
+        println!("  HN Mentions: {}", count);
+    }
+    print_hn_stories(&research_data.hn_stories);
+    if let Some(news) = &research_data.recent_news {
+        println!("  Recent News: {}", news);
+    }
+    Ok(())
+}
 
-    // Research YC companies
-    if let Ok(yc_info) = search_yc_company(name) {
-        data.yc_batch = yc_info.batch;
-        data.yc_url = yc_info.url;
+fn print_hn_stories(stories: &[models::HnStory]) {
+    if stories.is_empty() {
+        return;
+    }
+    println!("  Top HN stories:");
+    for story in stories {
+        let date = story.posted_at.as_deref().map(|d| d.get(..10).unwrap_or(d)).unwrap_or("");
+        println!("    {:>5} pts  {}  {}", story.points, truncate(&story.title, 70), date);
+        println!("               {}", hyperlink(story.url.as_deref().unwrap_or(&story.hn_url)));
     }
+}
 
-    // Research HN mentions
-    if let Ok(hn_count) = search_hn_mentions(name) {
-        data.hn_mentions_count = Some(hn_count);
+/// "Jane Doe (recruiter) at Acme · jane@acme.com · linkedin.com/in/jane · last touch 2026-03-02"
+/// "Anchor $235k · target $220k · walk away $200k", leaving out numbers the brief didn't give.
+fn negotiation_anchors_line(anchors: &models::NegotiationAnchors) -> String {
+    [("Anchor", anchors.anchor), ("target", anchors.target), ("walk away", anchors.walk_away)]
+        .iter()
+        .filter_map(|(label, amount)| amount.map(|a| format!("{} {}", label, comp::range(Some(a), None))))
+        .collect::<Vec<_>>()
+        .join(" · ")
+}
+
+fn print_negotiation_brief(brief: &models::NegotiationBrief) {
+    println!("Model: {}  Written: {}", brief.source_model, timestamps::local(&brief.created_at));
+    let anchors = negotiation_anchors_line(&brief.anchors);
+    if !anchors.is_empty() {
+        println!("\n{}", anchors);
+    }
+    if !brief.brief.is_empty() {
+        println!("\n{}", brief.brief);
     }
+}
 
-    // Note: Crunchbase requires API access or scraping, which is more complex
-    // For now, we'll leave this as a placeholder for future implementation
-    // data.crunchbase_url = search_crunchbase(name)?;
+/// Each flag with what it means, then the words that raised it and what found it.
+fn print_job_flags(job_flags: &[models::JobFlag]) {
+    for flag in job_flags {
+        let meaning = flags::kind(&flag.flag).map_or("", |k| k.meaning);
+        println!("  ⚑ {:<14} {}", flag.flag, meaning);
+        if let Some(evidence) = &flag.evidence {
+            println!("    \"{}\" ({})", evidence, flag.source);
+        } else {
+            println!("    ({})", flag.source);
+        }
+    }
+}
 
-    Ok(data)
+/// `hunt prep` questions, technical then behavioral, each with its prep line.
+fn print_prep_notes(notes: &[models::PrepNote]) {
+    if let Some(first) = notes.first() {
+        println!("Resume: {}  Model: {}  Generated: {}", first.resume_name, first.source_model, timestamps::local(&first.created_at));
+    }
+    for (kind, heading) in [("technical", "Technical"), ("behavioral", "Behavioral")] {
+        let questions: Vec<&models::PrepQuestion> = notes.iter().map(|n| &n.question).filter(|q| q.kind == kind).collect();
+        if questions.is_empty() {
+            continue;
+        }
+        println!("\n{} ({})", heading, questions.len());
+        for (i, q) in questions.iter().enumerate() {
+            println!("  {}. {}", i + 1, q.question);
+            if let Some(prep) = &q.prep {
+                for (j, line) in textwrap::fill(prep, 68).lines().enumerate() {
+                    println!("     {} {}", if j == 0 { "→" } else { " " }, line);
+                }
+            }
+        }
+    }
 }
 
-#[derive(Debug)]
-struct YCCompanyInfo {
-    batch: Option<String>,
-    url: Option<String>,
-}
-
-fn search_yc_company(_name: &str) -> Result<YCCompanyInfo> {
-    // YC has a companies list at https://www.ycombinator.com/companies
-    // For now, this is a stub implementation that could be enhanced with actual API/scraping
-    // TODO: Implement actual YC company search
-    Ok(YCCompanyInfo {
-        batch: None,
-        url: None,
-    })
+fn contact_line(contact: &models::Contact) -> String {
+    let mut line = contact.name.clone();
+    if let Some(role) = &contact.role {
+        line.push_str(&format!(" ({})", role));
+    }
+    if let Some(employer) = &contact.employer_name {
+        line.push_str(&format!(" at {}", employer));
+    }
+    for detail in [&contact.email, &contact.linkedin].into_iter().flatten() {
+        line.push_str(&format!(" · {}", detail));
+    }
+    if let Some(date) = &contact.last_touch {
+        line.push_str(&format!(" · last touch {}", date));
+    }
+    line
+}
+
+/// An employer's compensation bands (`hunt employer comp`, `employer show`).
+fn print_comp_bands(db: &Database, emp: &models::Employer, bands: &[models::CompBand]) -> Result<()> {
+    if bands.is_empty() {
+        return Ok(());
+    }
+    println!("{:<10} {:<30} {:>17} {:>17}", "LEVEL", "ROLE", "BASE", "TOTAL");
+    for band in bands {
+        println!("{:<10} {:<30} {:>17} {:>17}", band.level, truncate(&band.role, 28),
+                 comp::range(band.base_min, band.base_max), comp::range(band.total_min, band.total_max));
+    }
+    if let Some((researched, model)) = db.employer_comp_source(emp.id)? {
+        println!("Researched: {} ({}; estimates, not offers)", timestamps::local(&researched), model);
+    }
+    Ok(())
 }
 
-fn search_hn_mentions(_name: &str) -> Result<i64> {
-    // Use HN Algolia API to search for mentions
-    // https://hn.algolia.com/api
-    // For now, this is a stub implementation
-    // TODO: Implement actual HN search via Algolia API
-    Ok(0)
+/// `--json` output: pretty-printed, one document per command.
+fn print_json(value: &impl serde::Serialize) -> Result<()> {
+    println!("{}", serde_json::to_string_pretty(value)?);
+    Ok(())
 }
 
 fn research_public_company(name: &str) -> Result<PublicCompanyResearchData> {
@@ -615,7 +2259,7 @@ fn search_ownership_concerns(_name: &str) -> Result<Vec<String>> {
     Ok(vec![])
 }
 
-fn cleanup_artifacts(db: &Database, dry_run: bool) -> Result<usize> {
+fn cleanup_artifacts(db: &Database, dry_run: bool, purge: bool, review: &mut CleanupReview) -> Result<usize> {
     // Patterns that indicate navigation artifacts
     let artifact_patterns = [
         "view this job",
@@ -639,27 +2283,21 @@ fn cleanup_artifacts(db: &Database, dry_run: bool) -> Result<usize> {
         let title_lower = job.title.to_lowercase();
 
         // Check if title is too short (likely not a real job)
-        if job.title.len() < 5 {
-            if !dry_run {
-                db.delete_job(job.id)?;
-            }
-            removed += 1;
-            continue;
-        }
-
+        let reason = if job.title.len() < 5 {
+            "title too short to be a job"
         // Check if title matches artifact patterns
-        let is_artifact = artifact_patterns.iter().any(|pattern| {
-            title_lower.contains(pattern) && title_lower.len() < 50
-        });
-
+        } else if artifact_patterns.iter().any(|pattern| title_lower.contains(pattern) && title_lower.len() < 50) {
+            "title looks like link text"
         // Check if URL is a non-job link (alerts, search, settings, etc.)
-        let is_non_job_url = job.url.as_ref().is_some_and(|url| {
-            email::is_search_link(url)
-        });
+        } else if job.url.as_ref().is_some_and(|url| email::is_search_link(url)) {
+            "URL is a search or alert link"
+        } else {
+            continue;
+        };
 
-        if is_artifact || is_non_job_url {
+        if review.should_remove(db, "artifact", &cleanup_pattern(&job.title), &job, reason)? {
             if !dry_run {
-                db.delete_job(job.id)?;
+                remove_job(db, job.id, purge)?;
             }
             removed += 1;
         }
@@ -668,7 +2306,12 @@ fn cleanup_artifacts(db: &Database, dry_run: bool) -> Result<usize> {
     Ok(removed)
 }
 
-fn cleanup_duplicates(db: &Database, dry_run: bool) -> Result<usize> {
+/// Archive a job, or with `purge` delete it for good.
+fn remove_job(db: &Database, job_id: i64, purge: bool) -> Result<()> {
+    if purge { db.purge_job(job_id) } else { db.delete_job(job_id) }
+}
+
+fn cleanup_duplicates(db: &Database, dry_run: bool, purge: bool, merge: bool, review: &mut CleanupReview) -> Result<usize> {
     // Use sophisticated duplicate detection that handles:
     // - Exact matches (case-insensitive)
     // - Substring matches
@@ -676,13 +2319,221 @@ fn cleanup_duplicates(db: &Database, dry_run: bool) -> Result<usize> {
     // - URL-based deduplication
     let duplicates = db.find_duplicates()?;
 
-    if !dry_run {
-        for (_, duplicate_id, _) in &duplicates {
-            db.delete_job(*duplicate_id)?;
+    // A job's earlier match may itself be a duplicate: merge into the first of the chain
+    let mut kept_as: std::collections::HashMap<i64, i64> = std::collections::HashMap::new();
+    let mut removed = 0;
+    for (earlier_id, duplicate_id, reason) in &duplicates {
+        let (Some(earlier), Some(duplicate)) = (db.get_job(*earlier_id)?, db.get_job(*duplicate_id)?) else { continue };
+        let pattern = duplicate_pattern(&earlier.title, &duplicate.title);
+        let context = format!("duplicate of #{} {} ({})", earlier.id, earlier.title, reason);
+        if !review.should_remove(db, "duplicate", &pattern, &duplicate, &context)? {
+            continue;
+        }
+        removed += 1;
+        if dry_run {
+            continue;
+        }
+        let keep_id = kept_as.get(earlier_id).copied().unwrap_or(*earlier_id);
+        kept_as.insert(*duplicate_id, keep_id);
+        if merge {
+            db.merge_jobs(keep_id, *duplicate_id)?;
+        }
+        remove_job(db, *duplicate_id, purge)?;
+    }
+
+    Ok(removed)
+}
+
+/// A title with digits and spacing evened out, so "Apply now 1" and "Apply now 2" share
+/// remembered cleanup decisions.
+fn cleanup_pattern(title: &str) -> String {
+    let lower = title.to_lowercase().replace(|c: char| c.is_ascii_digit(), "#");
+    lower.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Two duplicate titles as one pattern, in either order.
+fn duplicate_pattern(a: &str, b: &str) -> String {
+    let (a, b) = (cleanup_pattern(a), cleanup_pattern(b));
+    if a <= b { format!("{} | {}", a, b) } else { format!("{} | {}", b, a) }
+}
+
+/// Prints a prompt and returns the answer (`prompt_line`, or scripted answers in tests).
+type Ask<'a> = Box<dyn FnMut(&str) -> Result<String> + 'a>;
+
+/// How `hunt cleanup` settles each candidate it would remove: a remembered answer for
+/// its pattern first, then with `--interactive` the user, else the heuristics' call.
+struct CleanupReview<'a> {
+    ask: Option<Ask<'a>>,
+    quit: bool,
+}
+
+impl<'a> CleanupReview<'a> {
+    fn heuristic() -> Self {
+        Self { ask: None, quit: false }
+    }
+
+    fn interactive(ask: impl FnMut(&str) -> Result<String> + 'a) -> Self {
+        Self { ask: Some(Box::new(ask)), quit: false }
+    }
+
+    /// Whether to remove `job`. "a"/"k" answers are saved for `pattern`; editing the
+    /// title keeps the job.
+    fn should_remove(&mut self, db: &Database, kind: &str, pattern: &str, job: &models::Job, context: &str) -> Result<bool> {
+        if self.quit {
+            return Ok(false);
+        }
+        match db.get_cleanup_decision(kind, pattern)?.as_deref() {
+            Some("remove") => return Ok(true),
+            Some(_) => return Ok(false),
+            None => {}
+        }
+        let Some(ask) = self.ask.as_mut() else { return Ok(true) };
+
+        println!("\n#{} {} @ {}", job.id, job.title, job.employer_name.as_deref().unwrap_or("-"));
+        println!("  {}", context);
+        if let Some(url) = &job.url {
+            println!("  {}", url);
+        }
+        if let Some(line) = job.raw_text.as_deref().and_then(|t| t.lines().find(|l| !l.trim().is_empty())) {
+            println!("  {}", truncate(line.trim(), 100));
+        }
+        loop {
+            let answer = ask("Remove? [y]es [n]o [a]lways remove these [k]eep these [e]dit title [q]uit: ")?;
+            match answer.to_lowercase().as_str() {
+                "y" => return Ok(true),
+                "n" | "" => return Ok(false),
+                "a" => {
+                    db.set_cleanup_decision(kind, pattern, "remove")?;
+                    return Ok(true);
+                }
+                "k" => {
+                    db.set_cleanup_decision(kind, pattern, "keep")?;
+                    return Ok(false);
+                }
+                "e" => {
+                    let title = ask("New title: ")?;
+                    if !title.is_empty() && title != "q" {
+                        db.update_job_title(job.id, &title)?;
+                        println!("  ✓ Renamed #{} to {}", job.id, title);
+                    }
+                    return Ok(false);
+                }
+                "q" => {
+                    self.quit = true;
+                    return Ok(false);
+                }
+                _ => println!("  Answer y, n, a, k, e or q"),
+            }
+        }
+    }
+}
+
+/// Rewrite stored URLs in canonical form. Returns how many changed.
+fn cleanup_urls(db: &Database, dry_run: bool) -> Result<usize> {
+    let mut updated = 0;
+    for job in db.list_jobs(None, None)? {
+        let Some(url) = job.url.as_deref() else { continue };
+        let canonical = domains::canonical_job_url(url);
+        if canonical != url {
+            if !dry_run {
+                db.set_job_url(job.id, &canonical)?;
+            }
+            updated += 1;
+        }
+    }
+    Ok(updated)
+}
+
+/// Jobs whose stored pay differs from what their description now parses to, e.g.
+/// hourly rates saved as thousands before pay was normalized.
+fn cleanup_pay(db: &Database, dry_run: bool) -> Result<usize> {
+    let mut updated = 0;
+    for job in db.list_jobs(None, None)? {
+        let Some(text) = job.raw_text.as_deref() else { continue };
+        let Some(found) = pay::parse(text) else { continue };
+        let (pay_min, pay_max) = found.annual_usd();
+        if (pay_min, pay_max, Some(found.text.as_str())) != (job.pay_min, job.pay_max, job.pay_text.as_deref()) {
+            if !dry_run {
+                db.update_job_pay(job.id, pay_min, pay_max, text)?;
+            }
+            updated += 1;
+        }
+    }
+    Ok(updated)
+}
+
+/// A keyword and its weight under each compared model (None where the model missed it).
+#[derive(Debug, PartialEq)]
+struct KeywordComparison {
+    keyword: String,
+    domain: String,
+    weights: Vec<Option<i32>>,
+}
+
+/// Line up keywords across models, matched case-insensitively: keywords every model
+/// found come first, then by highest weight.
+fn compare_keywords(per_model: &[Vec<models::JobKeyword>]) -> Vec<KeywordComparison> {
+    let mut rows: Vec<KeywordComparison> = Vec::new();
+    let mut index: std::collections::HashMap<String, usize> = std::collections::HashMap::new();
+    for (m, keywords) in per_model.iter().enumerate() {
+        for kw in keywords {
+            let i = *index.entry(kw.keyword.to_lowercase()).or_insert_with(|| {
+                rows.push(KeywordComparison {
+                    keyword: kw.keyword.clone(),
+                    domain: kw.domain.clone(),
+                    weights: vec![None; per_model.len()],
+                });
+                rows.len() - 1
+            });
+            let weight = &mut rows[i].weights[m];
+            *weight = Some(weight.map_or(kw.weight, |w| w.max(kw.weight)));
+        }
+    }
+    let found_by = |row: &KeywordComparison| row.weights.iter().flatten().count();
+    let top_weight = |row: &KeywordComparison| row.weights.iter().flatten().max().copied().unwrap_or(0);
+    rows.sort_by(|a, b| {
+        found_by(b)
+            .cmp(&found_by(a))
+            .then(top_weight(b).cmp(&top_weight(a)))
+            .then(a.keyword.to_lowercase().cmp(&b.keyword.to_lowercase()))
+    });
+    rows
+}
+
+fn print_keyword_comparison(results: &[(String, Vec<models::JobKeyword>)]) {
+    if results.len() < 2 {
+        println!("Need results from at least two models to compare.");
+        return;
+    }
+    let per_model: Vec<Vec<models::JobKeyword>> = results.iter().map(|(_, kw)| kw.clone()).collect();
+    let rows = compare_keywords(&per_model);
+    let widths: Vec<usize> = results.iter().map(|(model, _)| model.len().max(3)).collect();
+
+    print!("{:<32} {:<12}", "KEYWORD", "DOMAIN");
+    for ((model, _), width) in results.iter().zip(&widths) {
+        print!(" {:<width$}", model, width = width);
+    }
+    println!();
+    println!("{}", "-".repeat(45 + widths.iter().map(|w| w + 1).sum::<usize>()));
+    for row in &rows {
+        print!("{:<32} {:<12}", truncate(&row.keyword, 30), row.domain);
+        for (weight, width) in row.weights.iter().zip(&widths) {
+            let cell = weight.map_or("-".to_string(), |w| "*".repeat(w as usize));
+            print!(" {:<width$}", cell, width = width);
         }
+        println!();
     }
 
-    Ok(duplicates.len())
+    let shared = rows.iter().filter(|r| r.weights.iter().all(Option::is_some)).count();
+    println!("\n{} of {} keywords found by every model", shared, rows.len());
+    for (m, (model, keywords)) in results.iter().enumerate() {
+        let only = rows
+            .iter()
+            .filter(|r| r.weights[m].is_some() && r.weights.iter().flatten().count() == 1)
+            .count();
+        let missed = rows.iter().filter(|r| r.weights[m].is_none()).count();
+        println!("  {}: {} keywords, {} found only by this model, {} missed", model, keywords.len(), only, missed);
+    }
 }
 
 fn display_domain_keywords(keywords: &[models::JobKeyword]) {
@@ -726,9 +2577,66 @@ fn display_domain_keywords(keywords: &[models::JobKeyword]) {
     }
 }
 
-fn main() -> Result<()> {
+fn main() {
+    // Answers the completion scripts' callbacks (COMPLETE=<shell> hunt ...) and exits
+    CompleteEnv::with_factory(Cli::command).var(completions::VAR).complete();
     let cli = Cli::parse();
-    let db = Database::open()?;
+    let json = cli.json;
+    if let Err(e) = run(cli) {
+        let report = error::report(&e);
+        tracing::error!(target: logging::EXIT_TARGET, "{:#} (exit code {})", e, report.exit_code);
+        if json {
+            eprintln!("{}", serde_json::json!({ "error": report }));
+        } else {
+            eprintln!("Error: {:?}", e);
+        }
+        std::process::exit(report.exit_code);
+    }
+}
+
+fn run(cli: Cli) -> Result<()> {
+    output::set_quiet(cli.quiet);
+    logging::init(cli.verbose, cli.quiet, cli.log_file.as_deref())?;
+    if let Commands::Completions { shell } = cli.command {
+        completions::write_registration(shell, &mut std::io::stdout())?;
+        return Ok(());
+    }
+    let profiles = profile::Profiles::xdg();
+    // Managing profiles mustn't depend on the current one still being there
+    if let Commands::Profile { command } = &cli.command {
+        match command {
+            ProfileCommands::List => {
+                let current = match &cli.profile {
+                    Some(name) => name.clone(),
+                    None => profiles.current()?,
+                };
+                for name in profiles.list()? {
+                    let marker = if name == current { "*" } else { " " };
+                    println!("{} {:<20} {}", marker, name, profiles.db_path(&name).display());
+                }
+                return Ok(());
+            }
+            ProfileCommands::Create { name } => {
+                profiles.create(name)?;
+                Database::open_at(&profiles.db_path(name))?.init()?;
+                println!("✓ Created profile '{}' in {}", name, profiles.data_dir(name).display());
+                println!("  Settings go in {}", profiles.config_dir(name).join("config.json").display());
+                println!("  Use it with 'hunt --profile {} <command>' or 'hunt profile switch {}'", name, name);
+                return Ok(());
+            }
+            ProfileCommands::Switch { name } => {
+                profiles.switch(name)?;
+                println!("✓ Switched to profile '{}'", name);
+                return Ok(());
+            }
+            _ => {}
+        }
+    }
+    let profile = profiles.resolve(cli.profile.as_deref())?;
+    profile::set_active(&profile);
+    // Read up front for commute scoring; commands that need the config report a bad file
+    geo::set_home(config::load().ok().and_then(|c| c.home).as_deref());
+    let db = Database::open(cli.db.as_deref(), &profiles.db_path(&profile), cli.profile.is_some())?;
 
     match cli.command {
         Commands::Init => {
@@ -742,14 +2650,74 @@ fn main() -> Result<()> {
             println!("Added job #{}", job_id);
         }
 
-        Commands::List { status, employer } => {
+        Commands::List {
+            status, employer, tag, exclude_keywords, show_excluded, remote, easy_apply, external_apply, sponsors_visa, no_clearance,
+            location, min_pay, max_pay, archived, filter, template,
+        } => {
             db.ensure_initialized()?;
-            let jobs = db.list_jobs(status.as_deref(), employer.as_deref())?;
+            let saved = match &filter {
+                Some(name) => db.get_saved_filter(name)?
+                    .ok_or_else(|| HuntError::NotFound(format!("No saved filter '{}' (see 'hunt filter list')", name)))?,
+                None => models::SavedFilter::default(),
+            };
+            let filter = models::SavedFilter {
+                status: status.or(saved.status),
+                employer: employer.or(saved.employer),
+                tag: tag.or(saved.tag),
+                location: location.or(saved.location),
+                min_pay: min_pay.or(saved.min_pay),
+                max_pay: max_pay.or(saved.max_pay),
+                remote: remote || saved.remote,
+                easy_apply: apply_route(easy_apply, external_apply).or(saved.easy_apply),
+                sponsors_visa: sponsors_visa || saved.sponsors_visa,
+                no_clearance: no_clearance || saved.no_clearance,
+                ..saved
+            };
+            let mut jobs = db.filter_jobs(&filter, archived)?;
+
+            let mut excluded = exclude_keywords;
+            if !show_excluded {
+                excluded.extend(db.get_negative_keywords()?);
+            }
+            let excluded = db::normalize_terms(&excluded);
+            let mut hidden = 0;
+            if !excluded.is_empty() {
+                let mut kept = Vec::with_capacity(jobs.len());
+                for job in jobs {
+                    if db.find_excluded_keyword(&job, &excluded)?.is_some() {
+                        hidden += 1;
+                    } else {
+                        kept.push(job);
+                    }
+                }
+                jobs = kept;
+            }
+
+            if template.is_some() || cli.json {
+                let jobs = jobs.into_iter()
+                    .map(|job| templates::job_context(&db, job))
+                    .collect::<Result<Vec<_>>>()?;
+                match template {
+                    Some(template) => {
+                        let path = templates::resolve(&db, &template)?;
+                        print!("{}", templates::render_file(&path, minijinja::context! { jobs })?);
+                    }
+                    None => print_json(&jobs)?,
+                }
+                return Ok(());
+            }
+
             if jobs.is_empty() {
                 println!("No jobs found.");
             } else {
-                println!("{:<6} {:<10} {:<40} {:<25} {:>15} {:<60}", "ID", "STATUS", "TITLE", "EMPLOYER", "PAY RANGE", "URL");
-                println!("{}", "-".repeat(160));
+                let clusters = db.get_job_cluster_names()?;
+                let job_flags = db.get_all_job_flags()?;
+                let today = dates::today();
+                let closing: Vec<String> = jobs.iter()
+                    .filter_map(|job| dates::closing_soon(job, today).map(|days| format!("#{} {}", job.id, dates::closing_label(days))))
+                    .collect();
+                println!("{:<6} {:<10} {:>7} {:>7} {:<40} {:<25} {:<22} {:>15} {:>6} {:<22} {:<20} {:<60}", "ID", "STATUS", "APPLIED", "CLOSES", "TITLE", "EMPLOYER", "LOCATION", "PAY RANGE", "SKILLS", "CLUSTER", "FLAGS", "URL");
+                println!("{}", "-".repeat(250));
                 for job in jobs {
                     let pay = match (job.pay_min, job.pay_max) {
                         (Some(min), Some(max)) => format!("${}-${}", min / 1000, max / 1000),
@@ -757,21 +2725,375 @@ fn main() -> Result<()> {
                         (None, Some(max)) => format!("<${}", max / 1000),
                         (None, None) => "-".to_string(),
                     };
+                    let skills = match db.get_skill_match(job.id)? {
+                        Some(ratio) => format!("{:.0}%", ratio * 100.0),
+                        None => "-".to_string(),
+                    };
+                    let cluster = clusters.get(&job.id).map(String::as_str).unwrap_or("-");
+                    let red_flags = job_flags.get(&job.id).map_or("-".to_string(), |names| names.join(","));
                     let url = job.url.as_deref().unwrap_or("-");
+                    let applied = db::days_since_applied(&job).map_or("-".to_string(), |days| format!("{}d", days));
+                    let closes = match dates::days_left(&job, today) {
+                        Some(days) if days < 0 => "passed".to_string(),
+                        Some(days) => format!("{}d", days),
+                        None => "-".to_string(),
+                    };
                     println!(
-                        "{:<6} {:<10} {:<40} {:<25} {:>15} {:<60}",
+                        "{:<6} {:<10} {:>7} {:>7} {:<40} {:<25} {:<22} {:>15} {:>6} {:<22} {:<20} {:<60}",
                         job.id,
                         job.status,
+                        applied,
+                        closes,
                         truncate(&job.title, 38),
-                        truncate(&job.employer_name.unwrap_or_default(), 23),
+                        truncate(job.employer_name.as_deref().unwrap_or_default(), 23),
+                        truncate(&location::label(&job), 20),
                         pay,
+                        skills,
+                        truncate(cluster, 20),
+                        truncate(&red_flags, 18),
                         truncate(url, 58)
                     );
                 }
+                if !closing.is_empty() {
+                    println!("\n⚠ Deadline soon or passed: {}", closing.join(", "));
+                }
+            }
+            if hidden > 0 {
+                println!("\n({} job(s) hidden by excluded keywords: {})", hidden, excluded.join(", "));
+            }
+        }
+
+        Commands::Note { job_id, text } => {
+            db.ensure_initialized()?;
+            let job = db.get_job(job_id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+            match text {
+                Some(text) => {
+                    db.add_job_note(job_id, &text)?;
+                    println!("✓ Added note to job #{}: {}", job_id, job.title);
+                }
+                None => {
+                    let notes = db.list_job_notes(job_id)?;
+                    if notes.is_empty() {
+                        println!("No notes for job #{}.", job_id);
+                    }
+                    for note in notes {
+                        println!("[{}] {}", timestamps::local(&note.created_at), note.note);
+                    }
+                }
+            }
+        }
+
+        Commands::Delete { job_ids, purge } => {
+            db.ensure_initialized()?;
+            for job_id in job_ids {
+                let job = db.get_job(job_id)?
+                    .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+                if purge {
+                    db.purge_job(job_id)?;
+                    println!("✓ Purged job #{}: {}", job_id, job.title);
+                } else {
+                    db.delete_job(job_id)?;
+                    println!("✓ Archived job #{}: {} (hunt restore {} to undo)", job_id, job.title, job_id);
+                }
+            }
+        }
+
+        Commands::Merge { keep_id, dup_id, purge } => {
+            db.ensure_initialized()?;
+            let keep = db.get_job(keep_id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", keep_id)))?;
+            let dup = db.get_job(dup_id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", dup_id)))?;
+            db.merge_jobs(keep_id, dup_id)?;
+            remove_job(&db, dup_id, purge)?;
+            println!("✓ Merged job #{} ({}) into #{} ({})", dup_id, dup.title, keep_id, keep.title);
+            if !purge {
+                println!("  #{} is archived (hunt restore {} to bring it back)", dup_id, dup_id);
+            }
+        }
+
+        Commands::Restore { job_ids } => {
+            db.ensure_initialized()?;
+            for job_id in job_ids {
+                db.restore_job(job_id)?;
+                let job = db.get_job(job_id)?
+                    .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+                println!("✓ Restored job #{}: {}", job_id, job.title);
+            }
+        }
+
+        Commands::Tag { job_id, tags } => {
+            db.ensure_initialized()?;
+            db.get_job(job_id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+            let (remove, add): (Vec<String>, Vec<String>) = tags.into_iter().partition(|t| t.starts_with('-'));
+            let remove: Vec<String> = remove.iter().map(|t| t.trim_start_matches('-').to_string()).collect();
+            db.add_job_tags(job_id, &add)?;
+            db.remove_job_tags(job_id, &remove)?;
+
+            let current = db.get_job_tags(job_id)?;
+            if current.is_empty() {
+                println!("Job #{} has no tags.", job_id);
+            } else {
+                let shown: Vec<String> = current.iter().map(|t| format!("+{}", t)).collect();
+                println!("Job #{} tags: {}", job_id, shown.join(" "));
+            }
+        }
+
+        Commands::Set { job_id, deadline, no_deadline, posted } => {
+            db.ensure_initialized()?;
+            db.get_job(job_id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+            let today = dates::today();
+            if let Some(deadline) = &deadline {
+                let deadline = todo::parse_due(deadline, today)?;
+                db.set_job_deadline(job_id, Some(&dates::format_day(deadline)))?;
+            } else if no_deadline {
+                db.set_job_deadline(job_id, None)?;
+            }
+            if let Some(posted) = &posted {
+                let posted = journal::parse_since(posted, chrono::Local::now().naive_local())?.date();
+                db.set_job_dates(job_id, Some(&dates::format_day(posted)), None)?;
+            }
+
+            let job = db.get_job(job_id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+            println!("Job #{}: {}", job.id, job.title);
+            println!("Posted: {}", dates::posted_label(&job, today).unwrap_or_else(|| "unknown".to_string()));
+            println!("Deadline: {}", dates::deadline_label(&job, today).unwrap_or_else(|| "none".to_string()));
+        }
+
+        Commands::Next { job_id, action, due, done } => {
+            db.ensure_initialized()?;
+            let job = db.get_job(job_id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+            if done {
+                if db.clear_next_action(job_id)? {
+                    println!("✓ Done with the next action on job #{}: {}", job_id, job.title);
+                } else {
+                    println!("Job #{} has no next action.", job_id);
+                }
+            } else if let Some(action) = action {
+                let due = todo::parse_due(&due, chrono::Local::now().date_naive())?;
+                db.set_next_action(job_id, action.trim(), &due.format("%Y-%m-%d").to_string())?;
+                println!("✓ Next on job #{} ({}): {} — due {}", job_id, job.title, action.trim(), due.format("%Y-%m-%d"));
+            } else {
+                match db.get_next_action(job_id)? {
+                    Some(next) => println!("Next: {} (due {})", next.action, next.due_date),
+                    None => println!("Job #{} has no next action. Set one with 'hunt next {} \"<action>\" --due 3d'.", job_id, job_id),
+                }
+            }
+        }
+
+        Commands::Todo { all, email } => {
+            use std::fmt::Write as _;
+            db.ensure_initialized()?;
+            let today = chrono::Local::now().date_naive();
+            let today_str = today.format("%Y-%m-%d").to_string();
+            let actions = db.list_next_actions(if all { None } else { Some(&today_str) })?;
+            let label = |employer: Option<&str>| employer.map(|e| format!(" at {}", truncate(e, 25))).unwrap_or_default();
+
+            // Built up as text so --email can send exactly what's printed
+            let mut out = String::new();
+            use std::cmp::Ordering;
+            for (heading, when) in [("Overdue", Ordering::Less), ("Today", Ordering::Equal), ("Upcoming", Ordering::Greater)] {
+                let listed: Vec<&models::NextAction> = actions.iter()
+                    .filter(|a| a.due_date.as_str().cmp(today_str.as_str()) == when)
+                    .collect();
+                if listed.is_empty() {
+                    continue;
+                }
+                writeln!(out, "{}:", heading)?;
+                for next in listed {
+                    writeln!(out, "  {}  #{} {}{} — {}", next.due_date, next.job_id, truncate(&next.title, 40),
+                             label(next.employer_name.as_deref()), next.action)?;
+                }
+                writeln!(out)?;
+            }
+
+            let planned: std::collections::HashSet<i64> = db.list_next_actions(None)?.iter().map(|a| a.job_id).collect();
+            let jobs = db.list_jobs(None, None)?;
+            let suggestions = todo::suggestions(&jobs, &db.status_entered_at()?, &planned, today);
+            if !suggestions.is_empty() {
+                writeln!(out, "Suggested:")?;
+                for suggestion in &suggestions {
+                    if let Some(job) = jobs.iter().find(|j| j.id == suggestion.job_id) {
+                        writeln!(out, "  #{} {}{} — {}", job.id, truncate(&job.title, 40),
+                                 label(job.employer_name.as_deref()), suggestion.text)?;
+                    }
+                }
+                writeln!(out, "\n(hunt next <id> \"<action>\" --due <when> to plan one, which also quiets its suggestion)")?;
+            }
+            print!("{}", out);
+
+            if actions.is_empty() && suggestions.is_empty() {
+                println!("Nothing due{}. Plan follow-ups with 'hunt next <id> \"<action>\" --due 3d'.", if all { "" } else { " today" });
+                if email {
+                    println!("Nothing to email.");
+                }
+            } else if email {
+                let settings = config::load()?;
+                let mail = smtp::Outgoing {
+                    to: settings.smtp()?.recipient().to_string(),
+                    subject: format!("hunt: {} follow-up(s) for {}", actions.len() + suggestions.len(), today_str),
+                    text: out,
+                    ..Default::default()
+                };
+                send_mail(&settings, &mail)?;
+            }
+        }
+
+        Commands::Mail { command } => {
+            db.ensure_initialized()?;
+            let settings = config::load()?;
+            match command {
+                MailCommands::Test => {
+                    let mail = smtp::Outgoing {
+                        to: settings.smtp()?.recipient().to_string(),
+                        subject: "hunt: test message".to_string(),
+                        text: "hunt can send email with these SMTP settings.\n".to_string(),
+                        ..Default::default()
+                    };
+                    send_mail(&settings, &mail)?;
+                }
+                MailCommands::Apply { job_id, to, variant, attach, template, subject, dry_run } => {
+                    let job = db.get_job(job_id)?
+                        .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+                    let text = match template {
+                        Some(template) => {
+                            let path = templates::resolve(&db, &template)?;
+                            templates::render_file(&path, templates::job_context(&db, job.clone())?)?
+                        }
+                        None => application_body(&job),
+                    };
+                    let subject = subject.unwrap_or_else(|| match &job.job_code {
+                        Some(code) => format!("Application: {} ({})", job.title, code),
+                        None => format!("Application: {}", job.title),
+                    });
+                    let mut mail = smtp::Outgoing { to, subject, text, attachments: attach, ..Default::default() };
+
+                    let variant = match variant {
+                        Some(id) => Some(db.get_resume_variant_by_id(id)?
+                            .filter(|v| v.job_id == job.id)
+                            .ok_or_else(|| HuntError::NotFound(format!("Resume variant #{} not found for job #{}", id, job.id)))?),
+                        None => None,
+                    };
+                    if dry_run {
+                        println!("To: {}\nSubject: {}", mail.to, mail.subject);
+                        let mut files: Vec<String> = mail.attachments.iter().map(|p| p.display().to_string()).collect();
+                        if let Some(variant) = &variant {
+                            files.insert(0, format!("resume.pdf (variant #{}, rendered when sent)", variant.id));
+                        }
+                        if !files.is_empty() {
+                            println!("Attachments: {}", files.join(", "));
+                        }
+                        println!("\n{}", mail.text.trim_end());
+                        return Ok(());
+                    }
+
+                    let scratch = std::env::temp_dir().join(format!("hunt-mail-{}", std::process::id()));
+                    if let Some(variant) = &variant {
+                        let format = variant.output_format.as_deref().unwrap_or("markdown");
+                        let renderer = render::choose_renderer(format, |tool| check_binary(tool).is_some())?;
+                        std::fs::create_dir_all(&scratch)?;
+                        let pdf = scratch.join("resume.pdf");
+                        info!("Rendering variant #{} with {}...", variant.id, renderer);
+                        render::render_pdf(&variant.content, &renderer, &pdf)?;
+                        mail.attachments.insert(0, pdf);
+                    }
+                    let sent = send_mail(&settings, &mail);
+                    let _ = std::fs::remove_dir_all(&scratch);
+                    sent?;
+
+                    db.add_job_note(job.id, &format!("Emailed application to {}", mail.to))?;
+                    // Sending the application is applying; don't move a job that's further along
+                    if job.applied_at.is_none() && db.transition_refusal(&job.status, "applied")?.is_none() {
+                        db.update_job_status(job.id, "applied")?;
+                        println!("Job #{} -> applied", job.id);
+                    }
+                }
+            }
+        }
+
+        Commands::Contact { command } => {
+            db.ensure_initialized()?;
+            let touch_date = |spec: &str| -> Result<String> {
+                Ok(journal::parse_since(spec, chrono::Local::now().naive_local())?.format("%Y-%m-%d").to_string())
+            };
+            match command {
+                ContactCommands::Add { name, role, employer, email, linkedin, last_touch, job } => {
+                    let last_touch = last_touch.as_deref().map(touch_date).transpose()?;
+                    let job = match job {
+                        Some(id) => Some(db.get_job(id)?
+                            .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", id)))?),
+                        None => None,
+                    };
+                    let employer_id = match &employer {
+                        Some(name) => Some(db.get_or_create_employer(name)?),
+                        None => None,
+                    };
+                    let id = db.add_contact(&name, role.as_deref(), employer_id, email.as_deref(), linkedin.as_deref(), last_touch.as_deref())?;
+                    if let Some(job) = &job {
+                        db.link_contact_to_job(id, job.id)?;
+                    }
+                    let contact = db.get_contact(id)?
+                        .ok_or_else(|| HuntError::NotFound(format!("Contact #{} not found", id)))?;
+                    println!("✓ Added contact #{}: {}", id, contact_line(&contact));
+                    if let Some(job) = job {
+                        println!("  Linked to job #{} {}", job.id, job.title);
+                    }
+                }
+                ContactCommands::List { employer } => {
+                    let employer_id = match &employer {
+                        Some(name) => Some(db.get_employer_by_name(name)?
+                            .ok_or_else(|| HuntError::NotFound(format!("Employer '{}' not found", name)))?.id),
+                        None => None,
+                    };
+                    let contacts = db.list_contacts(employer_id)?;
+                    if contacts.is_empty() {
+                        println!("No contacts. Add one with 'hunt contact add <name> --employer <employer>'.");
+                    } else {
+                        for contact in &contacts {
+                            println!("{:<5} {}", format!("#{}", contact.id), contact_line(contact));
+                        }
+                    }
+                }
+                ContactCommands::Link { contact_id, job_id } => {
+                    let contact = db.get_contact(contact_id)?
+                        .ok_or_else(|| HuntError::NotFound(format!("Contact #{} not found", contact_id)))?;
+                    let job = db.get_job(job_id)?
+                        .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+                    if db.link_contact_to_job(contact_id, job_id)? {
+                        println!("✓ Linked {} to job #{} {}", contact.name, job.id, job.title);
+                    } else {
+                        println!("{} is already linked to job #{}", contact.name, job.id);
+                    }
+                }
+                ContactCommands::Touch { contact_id, date } => {
+                    let date = touch_date(date.as_deref().unwrap_or("0d"))?;
+                    db.touch_contact(contact_id, &date)?;
+                    println!("✓ Contact #{} last in touch {}", contact_id, date);
+                }
             }
         }
 
-        Commands::Show { id, raw } => {
+        Commands::Show { id, template: Some(template), .. } => {
+            db.ensure_initialized()?;
+            let job = db.get_job(id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", id)))?;
+            let path = templates::resolve(&db, &template)?;
+            print!("{}", templates::render_file(&path, templates::job_context(&db, job)?)?);
+        }
+
+        Commands::Show { id, .. } if cli.json => {
+            db.ensure_initialized()?;
+            let job = db.get_job(id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", id)))?;
+            print_json(&templates::job_context(&db, job)?)?;
+        }
+
+        Commands::Show { id, raw, template: None } => {
             db.ensure_initialized()?;
             match db.get_job(id)? {
                 Some(job) => {
@@ -781,22 +3103,99 @@ fn main() -> Result<()> {
                         println!("Employer: {}", employer);
                     }
                     println!("Status: {}", job.status);
+                    if let (Some(applied_at), Some(days)) = (&job.applied_at, db::days_since_applied(&job)) {
+                        println!("Applied: {} ({} days ago)", timestamps::local(applied_at), days);
+                    }
+                    for (label, at) in [("Rejected", &job.rejected_at), ("Closed", &job.closed_at)] {
+                        if let Some(at) = at {
+                            println!("{}: {}", label, timestamps::local(at));
+                        }
+                    }
+                    if let Some(archived_at) = &job.archived_at {
+                        println!("Archived: {} (hunt restore {} to bring it back)", timestamps::local(archived_at), job.id);
+                    }
                     if let Some(url) = &job.url {
                         println!("URL: {}", url);
                     }
                     if let Some(source) = &job.source {
                         println!("Source: {}", source);
                     }
-                    match (job.pay_min, job.pay_max) {
-                        (Some(min), Some(max)) => println!("Pay: ${} - ${}", min, max),
-                        (Some(min), None) => println!("Pay: ${}+", min),
-                        (None, Some(max)) => println!("Pay: up to ${}", max),
-                        (None, None) => {}
+                    if job.location.is_some() || job.work_mode.is_some() {
+                        println!("Location: {}", location::label(&job));
+                    }
+                    if let Some(pay) = pay::label(&job) {
+                        println!("Pay: {}", pay);
+                    }
+                    if let Some(apply) = domains::apply_label(&job) {
+                        println!("Apply: {}", apply);
+                    }
+                    if let Some(authorization) = flags::authorization_label(&job) {
+                        println!("Work authorization: {}", authorization);
+                    }
+                    let today = dates::today();
+                    if let Some(posted) = dates::posted_label(&job, today) {
+                        println!("Posted: {}", posted);
+                    }
+                    if let Some(deadline) = dates::deadline_label(&job, today) {
+                        let warn = if dates::closing_soon(&job, today).is_some() { "  ⚠" } else { "" };
+                        println!("Deadline: {}{}", deadline, warn);
+                    }
+                    if let Some(employer_id) = job.employer_id {
+                        let bands = db.list_employer_comp(employer_id)?;
+                        if let Some(band) = comp::below_band(&job, &bands) {
+                            println!("  ⚠ Below the employer's typical {} base ({})", band.level, comp::range(band.base_min, band.base_max));
+                        }
+                    }
+                    let job_flags = db.get_job_flags(id)?;
+                    if !job_flags.is_empty() {
+                        println!("Red flags:");
+                        print_job_flags(&job_flags);
+                    }
+                    println!("Created: {}", timestamps::local(&job.created_at));
+                    let tags = db.get_job_tags(id)?;
+                    if !tags.is_empty() {
+                        let shown: Vec<String> = tags.iter().map(|t| format!("+{}", t)).collect();
+                        println!("Tags: {}", shown.join(" "));
+                    }
+                    if let Some(next) = db.get_next_action(id)? {
+                        println!("Next: {} (due {})", next.action, next.due_date);
+                    }
+                    let contacts = db.list_job_contacts(&job)?;
+                    if !contacts.is_empty() {
+                        println!("Contacts:");
+                        for contact in &contacts {
+                            println!("  #{} {}", contact.id, contact_line(contact));
+                        }
+                    }
+                    if let Some(brief) = db.get_negotiation_brief(id)? {
+                        let anchors = negotiation_anchors_line(&brief.anchors);
+                        let anchors = if anchors.is_empty() { "brief on file".to_string() } else { anchors };
+                        println!("Negotiation: {} (hunt negotiate {} --show)", anchors, id);
+                    }
+                    let prep = db.list_prep_notes(id)?;
+                    if !prep.is_empty() {
+                        println!("Prep: {} interview question(s) (hunt prep {} --show)", prep.len(), id);
+                    }
+                    let notes = db.list_job_notes(id)?;
+                    if !notes.is_empty() {
+                        println!("\n--- Notes ---");
+                        for note in &notes {
+                            println!("[{}] {}", timestamps::local(&note.created_at), note.note);
+                        }
+                        println!();
+                    }
+
+                    let summary = db.get_job_summary(id)?;
+                    if let Some(summary) = &summary {
+                        println!("\n--- Summary (model: {}) ---\n", summary.source_model);
+                        for (label, text) in summary.bullets.labeled() {
+                            println!("  • {}: {}", label, text);
+                        }
+                        println!();
                     }
-                    println!("Created: {}", job.created_at);
 
                     // Show AI keywords/profile if available
-                    let has_ai = if let Some(model) = db.get_latest_keyword_model(id)? {
+                    let has_keywords = if let Some(model) = db.get_latest_keyword_model(id)? {
                         let keywords = db.get_job_keywords(id, Some(&model))?;
                         if !keywords.is_empty() {
                             println!("\n--- Keywords (model: {}) ---\n", model);
@@ -815,6 +3214,7 @@ fn main() -> Result<()> {
                     } else {
                         false
                     };
+                    let has_ai = has_keywords || summary.is_some();
 
                     // Show raw text: always if --raw, or if no AI data exists
                     if raw || !has_ai {
@@ -836,7 +3236,9 @@ fn main() -> Result<()> {
             match command {
                 EmployerCommands::List { status } => {
                     let employers = db.list_employers(status.as_deref())?;
-                    if employers.is_empty() {
+                    if cli.json {
+                        print_json(&employers)?;
+                    } else if employers.is_empty() {
                         println!("No employers found.");
                     } else {
                         println!("{:<6} {:<8} {:<30} {:<30}", "ID", "STATUS", "NAME", "DOMAIN");
@@ -868,14 +3270,35 @@ fn main() -> Result<()> {
                     println!("Marked '{}' as OK.", name);
                 }
 
-                EmployerCommands::Show { name } => {
+                EmployerCommands::Show { name, template: Some(template) } => {
+                    let emp = db.get_employer_by_name(&name)?
+                        .ok_or_else(|| HuntError::NotFound(format!("Employer '{}' not found", name)))?;
+                    let path = templates::resolve(&db, &template)?;
+                    print!("{}", templates::render_file(&path, templates::employer_context(&db, emp)?)?);
+                }
+
+                EmployerCommands::Show { name, template: None } if cli.json => {
+                    let emp = db.get_employer_by_name(&name)?
+                        .ok_or_else(|| HuntError::NotFound(format!("Employer '{}' not found", name)))?;
+                    print_json(&templates::employer_context(&db, emp)?)?;
+                }
+
+                EmployerCommands::Show { name, template: None } => {
                     match db.get_employer_by_name(&name)? {
                         Some(emp) => {
                             println!("Employer #{}", emp.id);
                             println!("Name: {}", emp.name);
                             println!("Status: {}", emp.status);
+                            let aliases = db.list_employer_aliases(Some(emp.id))?;
+                            if !aliases.is_empty() {
+                                let names: Vec<&str> = aliases.iter().map(|a| a.alias.as_str()).collect();
+                                println!("Also known as: {}", names.join(", "));
+                            }
                             if let Some(domain) = &emp.domain {
-                                println!("Domain: {}", domain);
+                                println!("Website: {}", hyperlink(&format!("https://{}", domain)));
+                            }
+                            if let Some(careers) = &emp.careers_url {
+                                println!("Careers: {}", hyperlink(careers));
                             }
                             if let Some(notes) = &emp.notes {
                                 println!("Notes: {}", notes);
@@ -905,11 +3328,12 @@ fn main() -> Result<()> {
                                 if let Some(count) = emp.hn_mentions_count {
                                     println!("HN Mentions: {}", count);
                                 }
+                                print_hn_stories(&db.list_employer_hn_stories(emp.id)?);
                                 if let Some(news) = &emp.recent_news {
                                     println!("Recent News: {}", news);
                                 }
                                 if let Some(updated) = &emp.research_updated_at {
-                                    println!("Research Updated: {}", updated);
+                                    println!("Research Updated: {}", timestamps::local(updated));
                                 }
                             }
 
@@ -933,7 +3357,7 @@ fn main() -> Result<()> {
                                     println!("\nEvil Summary:\n{}", summary);
                                 }
                                 if let Some(updated) = &emp.public_research_updated_at {
-                                    println!("Research Updated: {}", updated);
+                                    println!("Research Updated: {}", timestamps::local(updated));
                                 }
                             }
 
@@ -958,11 +3382,40 @@ fn main() -> Result<()> {
                                 if let Some(investors) = &emp.key_investors {
                                     println!("Key Investors: {}", investors);
                                 }
-                                if let Some(concerns) = &emp.ownership_concerns {
-                                    println!("⚠ Concerns: {}", concerns);
+                                if let Some(concerns) = &emp.ownership_concerns {
+                                    println!("⚠ Concerns: {}", concerns);
+                                }
+                                if let Some(updated) = &emp.ownership_research_updated {
+                                    println!("Ownership Research Updated: {}", timestamps::local(updated));
+                                }
+                            }
+
+                            let bands = db.list_employer_comp(emp.id)?;
+                            if !bands.is_empty() {
+                                println!("\n--- Compensation ---");
+                                print_comp_bands(&db, &emp, &bands)?;
+                            }
+
+                            // Show engineering culture signals if available
+                            if emp.eng_blog_url.is_some() || emp.eng_talks.is_some() || emp.eng_culture_summary.is_some() {
+                                println!("\n--- Engineering Culture ---");
+                                if let Some(blog) = &emp.eng_blog_url {
+                                    println!("Engineering Blog: {}", blog);
+                                }
+                                if let Some(rss) = &emp.eng_rss_url {
+                                    println!("RSS Feed: {}", rss);
+                                }
+                                if let Some(talks) = &emp.eng_talks {
+                                    println!("Recent Talks:");
+                                    for talk in talks.lines() {
+                                        println!("  - {}", talk);
+                                    }
                                 }
-                                if let Some(updated) = &emp.ownership_research_updated {
-                                    println!("Ownership Research Updated: {}", updated);
+                                if let Some(summary) = &emp.eng_culture_summary {
+                                    println!("Culture Signals: {}", summary);
+                                }
+                                if let Some(updated) = &emp.eng_research_updated {
+                                    println!("Culture Research Updated: {}", timestamps::local(updated));
                                 }
                             }
 
@@ -981,43 +3434,7 @@ fn main() -> Result<()> {
                 }
 
                 EmployerCommands::Research { name } => {
-                    println!("Researching startup info for '{}'...", name);
-
-                    // Get or create employer
-                    let employer_id = db.get_or_create_employer(&name)?;
-
-                    // Perform research
-                    let research_data = research_startup(&name)?;
-
-                    // Update database
-                    db.update_employer_research(
-                        employer_id,
-                        research_data.crunchbase_url.as_deref(),
-                        research_data.funding_stage.as_deref(),
-                        research_data.total_funding,
-                        research_data.last_funding_date.as_deref(),
-                        research_data.yc_batch.as_deref(),
-                        research_data.yc_url.as_deref(),
-                        research_data.hn_mentions_count,
-                        research_data.recent_news.as_deref(),
-                    )?;
-
-                    println!("\n✓ Research complete");
-                    if let Some(batch) = &research_data.yc_batch {
-                        println!("  YC Batch: {}", batch);
-                    }
-                    if let Some(stage) = &research_data.funding_stage {
-                        println!("  Funding Stage: {}", stage);
-                    }
-                    if let Some(funding) = research_data.total_funding {
-                        println!("  Total Funding: ${}", funding);
-                    }
-                    if let Some(count) = research_data.hn_mentions_count {
-                        println!("  HN Mentions: {}", count);
-                    }
-                    if let Some(news) = &research_data.recent_news {
-                        println!("  Recent News: {}", news);
-                    }
+                    run_startup_research(&db, &name)?;
                 }
 
                 EmployerCommands::Evil { name } => {
@@ -1078,33 +3495,814 @@ fn main() -> Result<()> {
                         ownership_data.ownership_type.as_deref(),
                     )?;
 
-                    println!("\n✓ Ownership research complete");
-                    if let Some(parent) = &ownership_data.parent_company {
-                        println!("  Parent Company: {}", parent);
+                    println!("\n✓ Ownership research complete");
+                    if let Some(parent) = &ownership_data.parent_company {
+                        println!("  Parent Company: {}", parent);
+                    }
+                    if let Some(ownership_type) = &ownership_data.ownership_type {
+                        println!("  Ownership Type: {}", ownership_type);
+                    }
+                    if let Some(pe) = &ownership_data.pe_owner {
+                        println!("  PE Owner: {}", pe);
+                    }
+                    if let Some(vc) = &ownership_data.vc_investors {
+                        println!("  VC Investors: {}", vc);
+                    }
+                    if let Some(investors) = &ownership_data.key_investors {
+                        println!("  Key Investors: {}", investors);
+                    }
+                    if let Some(concerns) = &ownership_data.ownership_concerns {
+                        println!("  ⚠ Concerns: {}", concerns);
+                    }
+                }
+
+                EmployerCommands::Culture { name, model } => {
+                    let emp = db.get_employer_by_name(&name)?
+                        .ok_or_else(|| HuntError::NotFound(format!("Employer '{}' not found", name)))?;
+                    let model = config::load()?.model(model);
+                    let spec = ai::resolve_model(&model)?;
+                    let provider = metered_provider(&db, &spec, "employer culture")?;
+                    println!("Researching engineering culture for '{}' (model: {})...", emp.name, spec.short_name);
+
+                    let culture = ai::research_engineering_culture(provider.as_ref(), &emp.name)?;
+
+                    let talks = if culture.talks.is_empty() {
+                        None
+                    } else {
+                        Some(culture.talks.join("\n"))
+                    };
+                    db.update_employer_engineering_culture(
+                        emp.id,
+                        culture.blog_url.as_deref(),
+                        culture.rss_url.as_deref(),
+                        talks.as_deref(),
+                        culture.summary.as_deref(),
+                    )?;
+
+                    println!("\n✓ Engineering culture research complete");
+                    if let Some(blog) = &culture.blog_url {
+                        println!("  Blog: {}", blog);
+                    }
+                    if let Some(rss) = &culture.rss_url {
+                        println!("  RSS: {}", rss);
+                    }
+                    for talk in &culture.talks {
+                        println!("  Talk: {}", talk);
+                    }
+                    if let Some(summary) = &culture.summary {
+                        println!("\n  Summary:\n{}", summary);
+                    }
+                }
+
+                EmployerCommands::Comp { name, levels, force, model } => {
+                    let emp = db.get_employer_by_name(&name)?
+                        .ok_or_else(|| HuntError::NotFound(format!("Employer '{}' not found", name)))?;
+                    let jobs: Vec<models::Job> = db.list_active_jobs()?
+                        .into_iter()
+                        .filter(|j| j.employer_id == Some(emp.id))
+                        .collect();
+
+                    let mut bands = db.list_employer_comp(emp.id)?;
+                    if bands.is_empty() || force || !levels.is_empty() {
+                        let known: Vec<&str> = comp::LEVELS.iter().map(|(level, _)| *level).chain(["mid"]).collect();
+                        let mut wanted: Vec<&str> = Vec::new();
+                        for level in &levels {
+                            let level = level.to_lowercase();
+                            let Some(known) = known.iter().find(|k| **k == level) else {
+                                return Err(HuntError::Validation(format!(
+                                    "Unknown level '{}'. Available: {}", level, known.join(", ")
+                                )).into());
+                            };
+                            wanted.push(known);
+                        }
+                        if wanted.is_empty() {
+                            wanted = jobs.iter().map(|j| comp::level(&j.title)).collect();
+                        }
+                        if wanted.is_empty() {
+                            wanted = vec!["mid", "senior", "staff"];
+                        }
+                        wanted.sort_by_key(|level| known.iter().position(|k| k == level));
+                        wanted.dedup();
+                        let mut roles: Vec<String> = jobs.iter().map(|j| j.title.clone()).collect();
+                        roles.sort();
+                        roles.dedup();
+                        roles.truncate(10);
+
+                        let model = config::load()?.model(model);
+                        let spec = ai::resolve_model(&model)?;
+                        let provider = metered_provider(&db, &spec, "employer comp")?;
+                        println!("Researching compensation at '{}' for {} (model: {})...\n", emp.name, wanted.join(", "), spec.short_name);
+                        bands = ai::research_comp(provider.as_ref(), &emp.name, &wanted, &roles)?;
+                        db.replace_employer_comp(emp.id, &bands, &spec.short_name)?;
+                        bands = db.list_employer_comp(emp.id)?;
+                    }
+
+                    print_comp_bands(&db, &emp, &bands)?;
+                    let below: Vec<(&models::Job, &models::CompBand)> = jobs.iter()
+                        .filter_map(|job| comp::below_band(job, &bands).map(|band| (job, band)))
+                        .collect();
+                    if below.is_empty() {
+                        println!("\nNo open jobs posted below the typical band.");
+                    } else {
+                        println!("\n⚠ Posted below the typical band:");
+                        for (job, band) in below {
+                            println!("  #{} {} — {} (typical base {})", job.id, truncate(&job.title, 40),
+                                     pay::label(job).unwrap_or_default(), comp::range(band.base_min, band.base_max));
+                        }
+                    }
+                }
+
+                EmployerCommands::Merge { from, into } => {
+                    let find = |name: &str| -> Result<models::Employer> {
+                        Ok(db.get_employer_by_name(name)?
+                            .ok_or_else(|| HuntError::NotFound(format!("Employer '{}' not found", name)))?)
+                    };
+                    let (from, into) = (find(&from)?, find(&into)?);
+                    let jobs = db.list_jobs(None, Some(&from.name))?.len();
+                    db.merge_employers(from.id, into.id)?;
+                    println!("Merged '{}' into '{}' ({} job(s) moved)", from.name, into.name, jobs);
+                    if let Some(merged) = db.get_employer_by_name(&into.name)?.filter(|e| e.status != into.status) {
+                        println!("Status is now '{}' (kept from '{}')", merged.status, from.name);
+                    }
+                }
+
+                EmployerCommands::Rename { old, new } => {
+                    let employer = db.get_employer_by_name(&old)?
+                        .ok_or_else(|| HuntError::NotFound(format!("Employer '{}' not found", old)))?;
+                    db.rename_employer(employer.id, &new)?;
+                    println!("Renamed '{}' to '{}'", employer.name, new);
+                }
+
+                EmployerCommands::Alias { alias, employer } => {
+                    let target = db.get_employer_by_name(&employer)?
+                        .ok_or_else(|| HuntError::NotFound(format!("Employer '{}' not found", employer)))?;
+                    if let Some(existing) = db.get_employer_by_name(&alias)?.filter(|e| e.id != target.id && e.name.eq_ignore_ascii_case(&alias)) {
+                        return Err(HuntError::Validation(format!(
+                            "'{}' is already an employer (#{}). Use 'hunt employer merge \"{}\" \"{}\"' to fold it in",
+                            alias, existing.id, existing.name, target.name
+                        )).into());
+                    }
+                    db.add_employer_alias(&alias, target.id)?;
+                    println!("'{}' now resolves to '{}'", alias, target.name);
+                }
+
+                EmployerCommands::Unalias { alias } => {
+                    if db.remove_employer_alias(&alias)? {
+                        println!("Removed alias '{}'", alias);
+                    } else {
+                        return Err(HuntError::NotFound(format!("No alias '{}'", alias)).into());
+                    }
+                }
+
+                EmployerCommands::Aliases { employer } => {
+                    let employer_id = match employer {
+                        Some(name) => Some(db.get_employer_by_name(&name)?
+                            .ok_or_else(|| HuntError::NotFound(format!("Employer '{}' not found", name)))?.id),
+                        None => None,
+                    };
+                    let aliases = db.list_employer_aliases(employer_id)?;
+                    if aliases.is_empty() {
+                        println!("No employer aliases.");
+                    } else {
+                        println!("{:<30} {:<30}", "ALIAS", "EMPLOYER");
+                        println!("{}", "-".repeat(60));
+                        for alias in aliases {
+                            println!("{:<30} {:<30}", truncate(&alias.alias, 28), truncate(&alias.employer_name, 28));
+                        }
+                    }
+                }
+
+                EmployerCommands::Dedupe { dry_run } => {
+                    let suggestions = employers::suggest_merges(&db.list_employer_job_counts()?);
+                    if suggestions.is_empty() {
+                        println!("No duplicate employers found.");
+                        return Ok(());
+                    }
+
+                    let (exact, fuzzy): (Vec<_>, Vec<_>) = suggestions.iter().partition(|s| s.is_exact());
+                    let describe = |s: &employers::MergeSuggestion| {
+                        format!("'{}' ({} job(s)) -> '{}' ({} job(s))", s.from.name, s.from.jobs, s.into.name, s.into.jobs)
+                    };
+                    if !exact.is_empty() {
+                        println!("Same name apart from punctuation/legal suffix:");
+                        for suggestion in &exact {
+                            if dry_run {
+                                println!("  {}", describe(suggestion));
+                            } else {
+                                db.merge_employers(suggestion.from.id, suggestion.into.id)?;
+                                println!("  Merged {}", describe(suggestion));
+                            }
+                        }
+                    }
+                    if !fuzzy.is_empty() {
+                        println!("\nSimilar names (check, then merge by hand):");
+                        for suggestion in &fuzzy {
+                            println!("  {:.0}%  hunt employer merge \"{}\" \"{}\"",
+                                     suggestion.similarity * 100.0, suggestion.from.name, suggestion.into.name);
+                        }
+                    }
+                    if dry_run && !exact.is_empty() {
+                        println!("\nRun without --dry-run to merge the {} exact match(es).", exact.len());
+                    }
+                }
+
+                EmployerCommands::Enrich { name, ai, force, model } => {
+                    let model = config::load()?.model(model);
+                    let targets: Vec<models::Employer> = match name {
+                        Some(name) => vec![db.get_employer_by_name(&name)?
+                            .ok_or_else(|| HuntError::NotFound(format!("Employer '{}' not found", name)))?],
+                        None => db.list_employers(None)?
+                            .into_iter()
+                            .filter(|e| force || e.domain.is_none() || e.careers_url.is_none())
+                            .collect(),
+                    };
+                    if targets.is_empty() {
+                        println!("Every employer already has a domain and careers page.");
+                        return Ok(());
+                    }
+
+                    let provider = if ai {
+                        let spec = ai::resolve_model(&model)?;
+                        println!("Enriching {} employer(s) (AI fallback: {})...\n", targets.len(), spec.short_name);
+                        Some(metered_provider(&db, &spec, "employer enrich")?)
+                    } else {
+                        println!("Enriching {} employer(s) from job URLs...\n", targets.len());
+                        None
+                    };
+
+                    let mut found = 0;
+                    for employer in &targets {
+                        let urls = db.get_employer_job_urls(employer.id)?;
+                        let mut links = domains::infer_from_job_urls(urls.iter().map(String::as_str));
+                        let known = domains::EmployerLinks {
+                            domain: employer.domain.clone(),
+                            careers_url: employer.careers_url.clone(),
+                        };
+                        if !force {
+                            links = known.clone().or(links);
+                        }
+                        if let Some(provider) = provider.as_ref().filter(|_| !links.is_complete()) {
+                            match ai::research_employer_links(provider.as_ref(), &employer.name, &urls) {
+                                Ok(researched) => links = links.or(researched),
+                                Err(e) => println!("  [ AI ] {}: {}", employer.name, e),
+                            }
+                        }
+
+                        if links == known || links == domains::EmployerLinks::default() {
+                            println!("  [MISS] {}", employer.name);
+                            continue;
+                        }
+                        db.set_employer_links(employer.id, &links, force)?;
+                        found += 1;
+                        println!("  [ OK ] {}: {} | careers: {}",
+                                 employer.name,
+                                 links.domain.as_deref().unwrap_or("-"),
+                                 links.careers_url.as_deref().unwrap_or("-"));
+                    }
+                    println!("\n{} of {} employer(s) updated", found, targets.len());
+                    if !ai && found < targets.len() {
+                        println!("Tip: add --ai to ask a model about the rest");
+                    }
+                }
+
+                EmployerCommands::Funding { name, stale_days, model } => {
+                    let model = config::load()?.model(model);
+                    let targets = match name {
+                        Some(name) => {
+                            let emp = db.get_employer_by_name(&name)?
+                                .ok_or_else(|| HuntError::NotFound(format!("Employer '{}' not found", name)))?;
+                            vec![(emp.id, emp.name)]
+                        }
+                        None => db.get_funding_watchlist(stale_days)?,
+                    };
+                    if targets.is_empty() {
+                        println!("No employers due for a funding check (checked within {} days).", stale_days);
+                        return Ok(());
+                    }
+
+                    let spec = ai::resolve_model(&model)?;
+                    let provider = metered_provider(&db, &spec, "employer funding")?;
+                    println!("Checking funding for {} employer(s) (model: {})...\n", targets.len(), spec.short_name);
+
+                    let mut raised = 0;
+                    for (employer_id, name) in &targets {
+                        let funding = match ai::research_funding(provider.as_ref(), name) {
+                            Ok(funding) => funding,
+                            Err(e) => {
+                                println!("  [SKIP] {}: {}", name, e);
+                                continue;
+                            }
+                        };
+                        let alerts = db.record_funding_check(*employer_id, &funding)?;
+                        println!("  [ OK ] {}: {}", name, funding.stage.as_deref().unwrap_or("stage unknown"));
+                        for alert in &alerts {
+                            println!("    ⚠ {}", alert.summary);
+                        }
+                        raised += alerts.len();
+                    }
+                    println!("\n{} new alert(s). Run 'hunt employer alerts' to review.", raised);
+                }
+
+                EmployerCommands::Alerts { all, ack } => {
+                    let alerts = db.list_employer_alerts(all)?;
+                    if alerts.is_empty() {
+                        println!("No funding alerts.");
+                    } else {
+                        println!("{:<5} {:<25} {:<12} {:<9} SUMMARY", "ID", "EMPLOYER", "KIND", "DATE");
+                        println!("{}", "-".repeat(100));
+                        for alert in &alerts {
+                            println!(
+                                "{:<5} {:<25} {:<12} {:<9} {}{}",
+                                alert.id,
+                                truncate(&alert.employer_name, 25),
+                                alert.kind,
+                                alert.event_date.as_deref().unwrap_or("-"),
+                                alert.summary,
+                                if alert.acknowledged { "" } else { " (new)" }
+                            );
+                        }
+                    }
+                    if ack {
+                        let count = db.acknowledge_employer_alerts()?;
+                        println!("\n✓ Acknowledged {} alert(s)", count);
+                    }
+                }
+            }
+        }
+
+        Commands::Weights { command } => {
+            db.ensure_initialized()?;
+            match command {
+                WeightsCommands::List => {
+                    let weights = db.get_rank_weights()?;
+                    println!("{:<18} {:>7}  POINTS AT WEIGHT 1.0", "FACTOR", "WEIGHT");
+                    println!("{}", "-".repeat(90));
+                    for ((name, weight), (_, _, description)) in weights.iter().zip(db::RANK_FACTORS) {
+                        println!("{:<18} {:>7.2}  {}", name, weight, description);
+                    }
+                }
+                WeightsCommands::Set { factor, weight } => {
+                    db.set_rank_weight(&factor, weight)?;
+                    println!("✓ Set {} weight to {}", factor, weight);
+                }
+                WeightsCommands::Reset => {
+                    db.reset_rank_weights()?;
+                    println!("✓ Restored default ranking weights");
+                }
+            }
+        }
+
+        Commands::Completions { .. } => unreachable!("handled before opening the database"),
+
+        Commands::Profile { command } => {
+            db.ensure_initialized()?;
+            match command {
+                ProfileCommands::Skills => {
+                    let skills = db.get_user_skills()?;
+                    if skills.is_empty() {
+                        println!("No skill profile. Set one with 'hunt profile set-skills' or 'hunt profile derive-skills'.");
+                    } else {
+                        println!("Skills ({}): {}", skills.len(), skills.join(", "));
+                    }
+                }
+                ProfileCommands::SetSkills { skills } => {
+                    let skills: Vec<String> = skills.split(',').map(|s| s.to_string()).collect();
+                    let count = db.set_user_skills(&skills, "manual")?;
+                    println!("✓ Saved {} skill(s)", count);
+                }
+                ProfileCommands::List | ProfileCommands::Create { .. } | ProfileCommands::Switch { .. } => {
+                    unreachable!("handled before opening the database")
+                }
+                ProfileCommands::DeriveSkills { resume, model } => {
+                    let model = config::load()?.model(model);
+                    let resumes = match resume {
+                        Some(resume) => {
+                            let found = if let Ok(id) = resume.parse::<i64>() {
+                                db.get_base_resume(id)?
+                            } else {
+                                db.get_base_resume_by_name(&resume)?
+                            }
+                            .ok_or_else(|| HuntError::NotFound(format!("Resume '{}' not found", resume)))?;
+                            vec![found]
+                        }
+                        None => db.list_base_resumes()?,
+                    };
+                    if resumes.is_empty() {
+                        return Err(HuntError::Validation(
+                            "No base resumes. Add one with 'hunt resume add'.".to_string()
+                        ).into());
+                    }
+                    let text = resumes.iter().map(|r| r.content.as_str()).collect::<Vec<_>>().join("\n\n---\n\n");
+
+                    let spec = ai::resolve_model(&model)?;
+                    let provider = metered_provider(&db, &spec, "profile derive-skills")?;
+                    info!("Deriving skills from {} resume(s) (model: {})...", resumes.len(), spec.short_name);
+                    let skills = ai::extract_skills(provider.as_ref(), &text)?;
+                    db.set_user_skills(&skills, "ai")?;
+                    let skills = db.get_user_skills()?;
+                    println!("✓ Saved {} skill(s): {}", skills.len(), skills.join(", "));
+                }
+            }
+        }
+
+        Commands::Cache { command } => {
+            db.ensure_initialized()?;
+            let cache = db.ai_cache()?;
+            match command {
+                CacheCommands::Stats => {
+                    let stats = cache.stats()?;
+                    println!("Cached responses: {}", stats.entries);
+                    println!("Cache hits:       {}", stats.hits);
+                    println!("Size:             {} KB", stats.bytes / 1024);
+                    if !stats.by_model.is_empty() {
+                        println!("\n{:<20} {:>8} {:>8}", "MODEL", "ENTRIES", "HITS");
+                        println!("{}", "-".repeat(38));
+                        for (model, entries, hits) in &stats.by_model {
+                            println!("{:<20} {:>8} {:>8}", model, entries, hits);
+                        }
+                    }
+                }
+                CacheCommands::Clear { model } => {
+                    let removed = cache.clear(model.as_deref())?;
+                    println!("✓ Removed {} cached response(s)", removed);
+                }
+            }
+        }
+
+        Commands::Review { limit, model, list } => {
+            let model = config::load()?.model(model);
+            db.ensure_initialized()?;
+            if list {
+                let cards = db.list_review_cards()?;
+                if cards.is_empty() {
+                    println!("No keywords to review. Mark some with 'hunt keywords unknown <keyword>'.");
+                    return Ok(());
+                }
+                println!("{:<28} {:<17} {:>9} {:>5} {:>6}", "KEYWORD", "DUE", "INTERVAL", "REPS", "LAPSES");
+                println!("{}", "-".repeat(69));
+                for card in cards {
+                    println!("{:<28} {:<17} {:>8.0}d {:>5} {:>6}",
+                             truncate(&card.keyword, 27), timestamps::local(&card.due_at),
+                             card.interval_days, card.reps, card.lapses);
+                }
+                return Ok(());
+            }
+
+            let now = chrono::Utc::now();
+            let due = db.get_due_review_cards(&timestamps::to_db(now), limit)?;
+            if due.is_empty() {
+                match db.list_review_cards()?.first() {
+                    Some(next) => println!("Nothing due. Next review: {} ({}).", next.keyword, timestamps::local(&next.due_at)),
+                    None => println!("No keywords to review. Mark some with 'hunt keywords unknown <keyword>'."),
+                }
+                return Ok(());
+            }
+
+            let spec = ai::resolve_model(&model)?;
+            let provider = metered_provider(&db, &spec, "review")?;
+            let mut reviewed = 0;
+            for (i, card) in due.iter().enumerate() {
+                let mut titles: Vec<String> = db.search_job_keywords(&card.keyword)?
+                    .into_iter()
+                    .map(|(_, title, _, _, _)| title)
+                    .collect();
+                titles.dedup();
+                titles.truncate(3);
+
+                println!("\n[{}/{}] {}", i + 1, due.len(), card.keyword);
+                let question = ai::review_question(provider.as_ref(), &card.keyword, &titles)?;
+                println!("\nQ: {}", question.question);
+                if prompt_line("\nPress Enter to see the answer (q to stop)... ")?.eq_ignore_ascii_case("q") {
+                    break;
+                }
+                println!("A: {}\n", question.answer);
+
+                let grade = loop {
+                    let input = prompt_line("How well did you know it? [1] again [2] hard [3] good [4] easy (q to stop): ")?;
+                    if input.eq_ignore_ascii_case("q") {
+                        break None;
+                    }
+                    if let Some(grade) = review::Grade::from_input(&input) {
+                        break Some(grade);
+                    }
+                };
+                let Some(grade) = grade else { break };
+
+                let state = review::CardState { interval_days: card.interval_days, ease: card.ease, reps: card.reps };
+                let next = review::next_state(state, grade);
+                let due_at = now + chrono::Duration::seconds((next.interval_days * 86400.0) as i64);
+                let lapsed = grade == review::Grade::Again && card.reps > 0;
+                db.record_review(&card.keyword, &next, &timestamps::to_db(due_at), lapsed)?;
+                println!("Next review in {:.0} day(s)", next.interval_days);
+                reviewed += 1;
+            }
+            println!("\n✓ Reviewed {} keyword(s)", reviewed);
+        }
+
+        Commands::Exclude { command } => {
+            db.ensure_initialized()?;
+            match command {
+                ExcludeCommands::List => {
+                    let keywords = db.get_negative_keywords()?;
+                    if keywords.is_empty() {
+                        println!("No excluded keywords. Add some with 'hunt exclude add'.");
+                    } else {
+                        println!("Excluded keywords: {}", keywords.join(", "));
+                    }
+                }
+                ExcludeCommands::Add { keywords } => {
+                    let added = db.add_negative_keywords(&keywords)?;
+                    println!("✓ Excluding {} new keyword(s)", added);
+                }
+                ExcludeCommands::Remove { keywords } => {
+                    let removed = db.remove_negative_keywords(&keywords)?;
+                    println!("✓ Removed {} keyword(s)", removed);
+                }
+            }
+        }
+
+        Commands::Config { command } => {
+            let path = config::config_path()?;
+            match command {
+                ConfigCommands::Show => {
+                    println!("{}", path.display());
+                    match std::fs::read_to_string(&path) {
+                        Ok(text) => println!("{}", text.trim_end()),
+                        Err(e) if e.kind() == std::io::ErrorKind::NotFound => println!("(no config file; everything is default)"),
+                        Err(e) => return Err(e).with_context(|| format!("Failed to read {:?}", path)),
+                    }
+                }
+                ConfigCommands::Set { key, value } => {
+                    if key == "home" && geo::locate(&value, &geo::OfflineCities).is_none() {
+                        return Err(HuntError::Validation(format!(
+                            "Can't place '{}'. Use a major city (e.g. \"Austin, TX\") or coordinates (\"30.27,-97.74\")", value
+                        )).into());
+                    }
+                    config::set_value(&path, &key, Some(&value))?;
+                    println!("✓ Set {} = {}", key, value);
+                }
+                ConfigCommands::Unset { key } => {
+                    config::set_value(&path, &key, None)?;
+                    println!("✓ Unset {}", key);
+                }
+            }
+        }
+
+        Commands::Filter { command } => {
+            db.ensure_initialized()?;
+            match command {
+                FilterCommands::List => {
+                    let filters = db.list_saved_filters()?;
+                    if cli.json {
+                        print_json(&filters)?;
+                    } else if filters.is_empty() {
+                        println!("No saved filters. Add one with 'hunt filter save <name> --status new ...'.");
+                    } else {
+                        for filter in filters {
+                            println!("  {:<20} {}", filter.name, filter.describe());
+                        }
+                    }
+                }
+                FilterCommands::Save {
+                    name, status, employer, tag, keyword, min_pay, max_pay, remote, easy_apply, external_apply, sponsors_visa, no_clearance, location,
+                } => {
+                    let filter = models::SavedFilter {
+                        name, status, employer, tag, keyword, min_pay, max_pay, remote, location, sponsors_visa, no_clearance,
+                        easy_apply: apply_route(easy_apply, external_apply),
+                        ..Default::default()
+                    };
+                    if filter == (models::SavedFilter { name: filter.name.clone(), ..Default::default() }) {
+                        return Err(HuntError::Validation("A filter needs at least one criterion, e.g. --status new".to_string()).into());
+                    }
+                    db.save_filter(&filter)?;
+                    let count = db.filter_jobs(&filter, false)?.len();
+                    println!("✓ Saved filter '{}': {} ({} job(s) match now)", filter.name.trim(), filter.describe(), count);
+                }
+                FilterCommands::Remove { name } => {
+                    if db.remove_saved_filter(&name)? {
+                        println!("✓ Removed filter '{}'", name);
+                    } else {
+                        return Err(HuntError::NotFound(format!("No saved filter '{}'", name)).into());
+                    }
+                }
+            }
+        }
+
+        Commands::Rule { command } => {
+            db.ensure_initialized()?;
+            match command {
+                RuleCommands::List => {
+                    let rules = db.list_rank_rules()?;
+                    if rules.is_empty() {
+                        println!("No rules. Add one with 'hunt rule add --keyword <keyword> --boost <points>'.");
+                    } else {
+                        for rule in rules {
+                            println!("  {:>+7.1}  {}", rule.points, rule.keyword);
+                        }
+                    }
+                }
+                RuleCommands::Add { keyword, boost, penalty } => {
+                    let points = boost.unwrap_or_else(|| -penalty.unwrap_or(0.0).abs());
+                    db.set_rank_rule(&keyword, points)?;
+                    println!("✓ Jobs asking for '{}' get {:+.1} point(s)", keyword.trim(), points);
+                }
+                RuleCommands::Remove { keyword } => {
+                    if db.remove_rank_rule(&keyword)? {
+                        println!("✓ Removed the rule for '{}'", keyword);
+                    } else {
+                        return Err(HuntError::NotFound(format!("No rule for '{}'", keyword)).into());
+                    }
+                }
+            }
+        }
+
+        Commands::Cluster { threshold, min_size, model, no_ai, show } => {
+            let model = config::load()?.model(model);
+            db.ensure_initialized()?;
+            if show {
+                print_job_clusters(&db)?;
+                return Ok(());
+            }
+            if !(0.0..=1.0).contains(&threshold) {
+                return Err(HuntError::Validation(format!("--threshold must be between 0 and 1, got {}", threshold)).into());
+            }
+
+            let mut jobs = Vec::new();
+            let mut vectors = Vec::new();
+            let mut skipped = 0;
+            for job in db.list_active_jobs()? {
+                let Some(keyword_model) = db.get_latest_keyword_model(job.id)? else {
+                    skipped += 1;
+                    continue;
+                };
+                vectors.push(cluster::keyword_vector(&db.get_job_keywords(job.id, Some(&keyword_model))?));
+                jobs.push(job);
+            }
+            if skipped > 0 {
+                warn!("Skipping {} active job(s) without keywords (run 'hunt keywords --all')", skipped);
+            }
+
+            let groups: Vec<Vec<usize>> = cluster::cluster(&vectors, threshold)
+                .into_iter()
+                .filter(|g| g.len() >= min_size.max(1))
+                .collect();
+            let provider = if no_ai || groups.is_empty() {
+                None
+            } else {
+                let spec = ai::resolve_model(&model)?;
+                info!("Naming {} cluster(s) (model: {})...", groups.len(), spec.short_name);
+                Some((metered_provider(&db, &spec, "cluster")?, spec.short_name))
+            };
+
+            let mut clusters = Vec::new();
+            for group in &groups {
+                let keywords = cluster::top_keywords(&vectors, group, 6);
+                let name = match &provider {
+                    Some((provider, _)) => {
+                        let titles: Vec<String> = group.iter().map(|&i| jobs[i].title.clone()).collect();
+                        ai::name_cluster(provider.as_ref(), &titles, &keywords)?
                     }
-                    if let Some(ownership_type) = &ownership_data.ownership_type {
-                        println!("  Ownership Type: {}", ownership_type);
+                    None => keywords.iter().take(3).cloned().collect::<Vec<_>>().join(" / "),
+                };
+                clusters.push((name, keywords, group.iter().map(|&i| jobs[i].id).collect()));
+            }
+            db.replace_job_clusters(&clusters, provider.as_ref().map(|(_, name)| name.as_str()))?;
+
+            let clustered: usize = groups.iter().map(Vec::len).sum();
+            println!("✓ {} cluster(s) covering {} of {} job(s)\n", clusters.len(), clustered, jobs.len());
+            print_job_clusters(&db)?;
+        }
+
+        Commands::Learn { command } => {
+            db.ensure_initialized()?;
+            match command {
+                LearnCommands::Gaps { limit } => {
+                    let gaps = db.get_keyword_gaps(limit)?;
+                    if gaps.is_empty() {
+                        println!("No gaps found. Extract keywords with 'hunt keywords --all' and add a resume with 'hunt resume add'.");
+                        return Ok(());
                     }
-                    if let Some(pe) = &ownership_data.pe_owner {
-                        println!("  PE Owner: {}", pe);
+                    println!("{:<28} {:<12} {:>7} {:>5}", "KEYWORD", "DOMAIN", "WEIGHT", "JOBS");
+                    println!("{}", "-".repeat(55));
+                    for gap in gaps {
+                        println!("{:<28} {:<12} {:>7} {:>5}", truncate(&gap.keyword, 27), gap.domain, gap.total_weight, gap.jobs);
                     }
-                    if let Some(vc) = &ownership_data.vc_investors {
-                        println!("  VC Investors: {}", vc);
+                }
+                LearnCommands::Plan { hours_per_week, top, model } => {
+                    let model = config::load()?.model(model);
+                    let gaps = db.get_keyword_gaps(top)?;
+                    if gaps.is_empty() {
+                        return Err(HuntError::Validation(
+                            "No keyword gaps to plan for. Run 'hunt learn gaps' to check.".to_string()
+                        ).into());
                     }
-                    if let Some(investors) = &ownership_data.key_investors {
-                        println!("  Key Investors: {}", investors);
+                    let gaps: Vec<(String, i64, i64)> = gaps.into_iter().map(|g| (g.keyword, g.total_weight, g.jobs)).collect();
+
+                    let spec = ai::resolve_model(&model)?;
+                    let provider = metered_provider(&db, &spec, "learn plan")?;
+                    info!("Planning {} skill(s) at {}h/week (model: {})...", gaps.len(), hours_per_week, spec.short_name);
+                    let steps = ai::generate_learning_plan(provider.as_ref(), &gaps, hours_per_week)?;
+                    let plan_id = db.create_learning_plan(hours_per_week, &spec.short_name, &steps)?;
+                    println!("✓ Saved learning plan #{} with {} item(s)\n", plan_id, steps.len());
+                    print_learning_plan(&db)?;
+                }
+                LearnCommands::List => print_learning_plan(&db)?,
+                LearnCommands::Done { item_id, undo } => {
+                    db.set_learning_item_done(item_id, !undo)?;
+                    if undo {
+                        println!("✓ Unchecked item #{}", item_id);
+                    } else {
+                        println!("✓ Checked off item #{}", item_id);
                     }
-                    if let Some(concerns) = &ownership_data.ownership_concerns {
-                        println!("  ⚠ Concerns: {}", concerns);
+                }
+            }
+        }
+
+        Commands::Status { command } => {
+            db.ensure_initialized()?;
+            match command {
+                StatusCommands::List => {
+                    let statuses = db.list_job_statuses()?;
+                    println!("{:<6} {:<16} {:<10} {:<8}", "POS", "NAME", "COLOR", "TERMINAL");
+                    println!("{}", "-".repeat(44));
+                    for s in statuses {
+                        println!(
+                            "{:<6} {:<16} {:<10} {:<8}",
+                            s.position,
+                            s.name,
+                            s.color,
+                            if s.is_terminal { "yes" } else { "" }
+                        );
                     }
                 }
+                StatusCommands::Add { name, position, terminal, color } => {
+                    db.upsert_job_status(&name, position, terminal, &color)?;
+                    println!("Saved status '{}'", name);
+                }
+                StatusCommands::Remove { name } => {
+                    db.remove_job_status(&name)?;
+                    println!("Removed status '{}'", name);
+                }
+                StatusCommands::Set { job_id, status, force } => {
+                    db.set_job_status(job_id, &status, force)?;
+                    println!("Job #{} -> {}", job_id, status);
+                }
             }
         }
 
-        Commands::Rank { limit } => {
+        Commands::Rank { explain: Some(job_id), .. } => {
             db.ensure_initialized()?;
-            let jobs = db.rank_jobs(limit)?;
-            if jobs.is_empty() {
+            let job = db.get_job(job_id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+            let breakdown = db::score_breakdown(&job, &db, &db.list_job_statuses()?);
+
+            println!("Score breakdown for job #{}: {}", job.id, job.title);
+            println!();
+            println!("  {:<18} {:>8} {:>8} {:>8}", "FACTOR", "POINTS", "WEIGHT", "SCORE");
+            println!("  {}", "-".repeat(45));
+            println!("  {:<37}{:>8.1}", "base", breakdown.base);
+            for factor in &breakdown.factors {
+                println!(
+                    "  {:<18} {:>8.1} {:>8.2} {:>8.1}",
+                    factor.name,
+                    factor.points,
+                    factor.weight,
+                    factor.contribution()
+                );
+            }
+            println!("  {}", "-".repeat(45));
+            println!("  {:<37}{:>8.1}", "total", breakdown.total());
+            if let Some(age) = db::job_age_days(&job) {
+                match &job.posted_at {
+                    Some(posted) => println!("\n  Age: {:.0} days (posted {})", age.floor(), posted),
+                    None => println!("\n  Age: {:.0} days (added {})", age.floor(), timestamps::local(&job.created_at)),
+                }
+            }
+            if let Some(kind) = db::posting_source(&job) {
+                let host = job.url.as_deref().and_then(ratelimit::domain);
+                let source = host.or(job.source.clone()).unwrap_or_default();
+                println!("  Source: {} ({})", source, kind.label());
+            }
+            if let Some((miles, _)) = geo::home().and_then(|home| geo::commute(&job, home, &geo::OfflineCities)) {
+                println!("\n  Commute: {:.0} miles to {}", miles, location::label(&job));
+            }
+            let rules = db.matching_rank_rules(&job)?;
+            if !rules.is_empty() {
+                let matched: Vec<String> = rules.iter().map(|r| format!("{} {:+.1}", r.keyword, r.points)).collect();
+                println!("\n  Rules matched: {}", matched.join(", "));
+            }
+        }
+
+        Commands::Rank { limit, explain: None, min_pay, max_pay, sponsors_visa, no_clearance } => {
+            db.ensure_initialized()?;
+            let filter = models::SavedFilter { min_pay, max_pay, sponsors_visa, no_clearance, ..Default::default() };
+            let jobs = db.rank_jobs(limit, &filter)?;
+            if cli.json {
+                let jobs = jobs.into_iter()
+                    .map(|(job, _)| templates::job_context(&db, job))
+                    .collect::<Result<Vec<_>>>()?;
+                print_json(&jobs)?;
+            } else if jobs.is_empty() {
                 println!("No jobs to rank.");
             } else {
                 println!("{:<5} {:<6} {:<12} {:<25} {:<18} {:>10}", "RANK", "ID", "STATUS", "TITLE", "EMPLOYER", "SCORE");
@@ -1124,39 +4322,92 @@ fn main() -> Result<()> {
         }
 
         Commands::Email {
+            command: Some(EmailCommands::Auth { username, client_id, client_secret }),
+            ..
+        } => {
+            let client_id = match client_id {
+                Some(id) => id,
+                None => std::env::var("GOOGLE_CLIENT_ID")
+                    .context("No OAuth client ID. Pass --client-id or set GOOGLE_CLIENT_ID")?,
+            };
+            let client_secret = match client_secret {
+                Some(secret) => secret,
+                None => std::env::var("GOOGLE_CLIENT_SECRET")
+                    .context("No OAuth client secret. Pass --client-secret or set GOOGLE_CLIENT_SECRET")?,
+            };
+
+            let authorization = email::start_loopback_authorization(&client_id)?;
+            println!("To authorize hunt to read {}, sign in as that account at:", username);
+            println!("  {}", authorization.url);
+            if tui::open_url(&authorization.url).is_err() {
+                println!("  (couldn't open a browser; paste the link into one on this machine)");
+            }
+            println!("\nWaiting for authorization...");
+
+            let refresh_token = email::finish_loopback_authorization(authorization, &client_id, &client_secret)?;
+            let path = email::oauth_token_path(&username)?;
+            email::save_oauth_token(&path, &email::OAuthToken { client_id, client_secret, refresh_token })?;
+            println!("✓ Authorized. Refresh token saved to {}", path.display());
+            println!("  Use 'hunt email --oauth --username {}' to fetch alerts", username);
+        }
+
+        Commands::Email {
+            command: None,
             username,
             password_file,
+            accounts,
+            oauth,
+            maildir,
+            mbox,
             days,
             dry_run,
-            verbose,
+            reprocess,
+            ai_fallback,
+            model,
+            notify,
+            notify_score,
         } => {
             db.ensure_initialized()?;
-
-            // Expand ~ in path
-            let password_path = if password_file.starts_with("~/") {
-                let home = std::env::var("HOME").unwrap_or_default();
-                PathBuf::from(format!("{}/{}", home, &password_file[2..]))
-            } else {
-                PathBuf::from(&password_file)
+            let last_job_id = db.max_job_id()?;
+            let settings = config::load()?;
+            let with_options = |ingester: EmailIngester| -> Result<EmailIngester> {
+                let ingester = ingester.reprocess(reprocess);
+                if !ai_fallback {
+                    return Ok(ingester);
+                }
+                let spec = ai::resolve_model(&settings.model(model.clone()))?;
+                Ok(ingester.ai_fallback(cached_provider(&db, &spec, "email", false)?))
             };
 
-            println!("Connecting to Gmail as {}...", username);
-            let config = EmailConfig::from_gmail_password_file(&username, &password_path)?;
-            let ingester = EmailIngester::new(config);
-
-            println!("Searching for job alerts from the last {} days...", days);
-            let stats = ingester.fetch_job_alerts(&db, days, dry_run, verbose)?;
+            let local = maildir.map(LocalMail::Maildir).or(mbox.map(LocalMail::Mbox));
+            let results = if let Some(local) = local {
+                let ingester = with_options(EmailIngester::with_accounts(Vec::new()))?;
+                info!("Reading job alerts from the last {} days in {}...", days, local.path().display());
+                let stats = ingester.ingest_local(&local, &db, days, dry_run)?;
+                vec![AccountIngestStats { account: local.path().display().to_string(), stats, error: None }]
+            } else {
+                let configs = email_accounts(username, password_file, &accounts, oauth, &settings.accounts)?;
+                for config in &configs {
+                    info!("Connecting to {} as {}...", config.server, config.username);
+                }
+                let ingester = with_options(EmailIngester::with_accounts(configs))?;
 
-            println!("\nResults:");
-            println!("  Emails processed: {}", stats.emails_found);
-            println!("  Jobs added:       {}", stats.jobs_added);
-            println!("  Duplicates:       {}", stats.duplicates);
-            if stats.errors > 0 {
-                println!("  Errors:           {}", stats.errors);
+                info!("Searching for job alerts from the last {} days...", days);
+                ingester.fetch_job_alerts(&db, days, dry_run)
+            };
+            let failure = AccountIngestStats::failure(&results);
+            if results.iter().all(|r| r.error.is_some()) {
+                return Err(failure.expect("every account failed").into());
             }
+            print_ingest_results(&results);
 
             if dry_run {
                 println!("\n(Dry run - no jobs were actually added)");
+            } else if notify {
+                notify_new_jobs(&db, last_job_id, notify_score)?;
+            }
+            if let Some(partial) = failure {
+                return Err(partial.into());
             }
         }
 
@@ -1176,6 +4427,50 @@ fn main() -> Result<()> {
                     println!("Added base resume '{}' (ID: {})", name, resume_id);
                 }
 
+                ResumeCommands::Update { name, file, notes } => {
+                    let resume = if let Ok(id) = name.parse::<i64>() {
+                        db.get_base_resume(id)?
+                    } else {
+                        db.get_base_resume_by_name(&name)?
+                    }
+                    .ok_or_else(|| HuntError::NotFound(format!("Resume '{}' not found", name)))?;
+
+                    let content = std::fs::read_to_string(&file)
+                        .with_context(|| format!("Failed to read resume file: {}", file.display()))?;
+
+                    let changed = resume.content_hash.as_deref() != Some(db::content_hash(&content).as_str());
+                    if !changed && notes.is_none() {
+                        println!("Resume '{}' is unchanged.", resume.name);
+                        return Ok(());
+                    }
+                    db.update_base_resume(resume.id, None, None, changed.then_some(content.as_str()), notes.as_deref())?;
+                    if !changed {
+                        println!("Updated notes for resume '{}' (content unchanged).", resume.name);
+                        return Ok(());
+                    }
+                    println!("Updated base resume '{}' (ID: {})", resume.name, resume.id);
+
+                    let stale = db.get_stale_resume_variants(resume.id)?;
+                    if !stale.is_empty() {
+                        println!("\nWarning: {} variant(s) were tailored from an older version of this resume:", stale.len());
+                        println!("{:<6} {:<6} {:<20} {:<4}", "ID", "JOB", "MODEL", "VER");
+                        for variant in &stale {
+                            println!(
+                                "{:<6} {:<6} {:<20} {:<4}",
+                                variant.id,
+                                variant.job_id,
+                                truncate(variant.source_model.as_deref().unwrap_or("-"), 18),
+                                variant.version
+                            );
+                        }
+                        let mut jobs: Vec<i64> = stale.iter().map(|v| v.job_id).collect();
+                        jobs.dedup();
+                        println!("\nRe-tailor with: hunt resume tailor <job> --resume {}  (jobs: {})",
+                            resume.name,
+                            jobs.iter().map(|j| j.to_string()).collect::<Vec<_>>().join(", "));
+                    }
+                }
+
                 ResumeCommands::List => {
                     let resumes = db.list_base_resumes()?;
                     if resumes.is_empty() {
@@ -1189,7 +4484,7 @@ fn main() -> Result<()> {
                                 resume.id,
                                 truncate(&resume.name, 18),
                                 resume.format,
-                                truncate(&resume.updated_at, 18)
+                                timestamps::local(&resume.updated_at)
                             );
                         }
                     }
@@ -1209,8 +4504,8 @@ fn main() -> Result<()> {
                             if let Some(notes) = &resume.notes {
                                 println!("Notes: {}", notes);
                             }
-                            println!("Created: {}", resume.created_at);
-                            println!("Updated: {}", resume.updated_at);
+                            println!("Created: {}", timestamps::local(&resume.created_at));
+                            println!("Updated: {}", timestamps::local(&resume.updated_at));
                             println!("\n--- Content ---\n{}", resume.content);
                         }
                         None => {
@@ -1226,20 +4521,24 @@ fn main() -> Result<()> {
                     models,
                     format,
                     output,
+                    include_blocked,
+                    no_stream,
                 } => {
+                    let model = config::load()?.model(model);
                     let job = db.get_job(job_id)?
-                        .ok_or_else(|| anyhow!("Job #{} not found", job_id))?;
+                        .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+                    guard_blocked_employer(&db, &job, include_blocked)?;
 
                     let job_text = job.raw_text
                         .as_ref()
-                        .ok_or_else(|| anyhow!("Job #{} has no raw text for tailoring", job_id))?;
+                        .ok_or_else(|| HuntError::Validation(format!("Job #{} has no raw text for tailoring", job_id)))?;
 
                     let base_resume = if let Ok(id) = resume.parse::<i64>() {
                         db.get_base_resume(id)?
                     } else {
                         db.get_base_resume_by_name(&resume)?
                     }
-                    .ok_or_else(|| anyhow!("Resume '{}' not found", resume))?;
+                    .ok_or_else(|| HuntError::NotFound(format!("Resume '{}' not found", resume)))?;
 
                     // Gather all resumes: primary first, then others by updated_at DESC
                     let all_resumes_db = db.list_base_resumes()?;
@@ -1264,19 +4563,35 @@ fn main() -> Result<()> {
 
                     for model_name in &model_names {
                         let spec = ai::resolve_model(model_name)?;
-                        let provider = ai::create_provider(&spec)?;
+                        let provider = metered_provider(&db, &spec, "resume tailor")?;
 
                         println!("Generating tailored resume with {} (format: {})...",
                                  spec.short_name, format);
 
-                        let tailored_content = ai::tailor_resume_full(
-                            provider.as_ref(),
-                            &all_resumes,
-                            job_text,
-                            &job.title,
-                            employer_name,
-                            &format,
-                        )?;
+                        let tailored_content = if no_stream {
+                            ai::tailor_resume_full(
+                                provider.as_ref(),
+                                &all_resumes,
+                                job_text,
+                                &job.title,
+                                employer_name,
+                                &format,
+                                None,
+                            )?
+                        } else {
+                            println!("\n--- Tailored Resume (model: {}) ---", spec.short_name);
+                            let content = ai::tailor_resume_full(
+                                provider.as_ref(),
+                                &all_resumes,
+                                job_text,
+                                &job.title,
+                                employer_name,
+                                &format,
+                                Some(&mut print_stream),
+                            )?;
+                            println!("\n");
+                            content
+                        };
 
                         let notes = format!("Tailored for: {} (model: {}, format: {})",
                                            job.title, spec.short_name, format);
@@ -1303,48 +4618,175 @@ fn main() -> Result<()> {
                             std::fs::write(&final_path, &tailored_content)
                                 .with_context(|| format!("Failed to write to {}", final_path.display()))?;
                             println!("Saved to: {}", final_path.display());
-                        } else {
+                        } else if no_stream {
                             println!("\n--- Tailored Resume (model: {}, variant ID: {}) ---\n{}",
                                      spec.short_name, variant_id, tailored_content);
+                        } else {
+                            println!("Saved as variant ID {}", variant_id);
                         }
                         println!();
                     }
                 }
 
-                ResumeCommands::Variants { job_id } => {
-                    let variants = db.list_resume_variants_for_job(job_id)?;
+                ResumeCommands::Variants { job_id, history } => {
+                    let variants = if history {
+                        db.list_resume_variant_history(job_id)?
+                    } else {
+                        db.list_resume_variants_for_job(job_id)?
+                    };
                     if variants.is_empty() {
                         println!("No resume variants found for job #{}.", job_id);
                     } else {
-                        println!("{:<6} {:<15} {:<15} {:<10} {:<20}", "ID", "BASE RESUME", "MODEL", "FORMAT", "CREATED");
-                        println!("{}", "-".repeat(68));
+                        println!("{:<6} {:<15} {:<15} {:<10} {:<4} {:<20}", "ID", "BASE RESUME", "MODEL", "FORMAT", "VER", "CREATED");
+                        println!("{}", "-".repeat(73));
                         for variant in variants {
                             let base_resume = db.get_base_resume(variant.base_resume_id)?
-                                .ok_or_else(|| anyhow!("Base resume not found"))?;
+                                .ok_or_else(|| HuntError::NotFound("Base resume not found".to_string()))?;
                             println!(
-                                "{:<6} {:<15} {:<15} {:<10} {:<20}",
+                                "{:<6} {:<15} {:<15} {:<10} {:<4} {:<20}",
                                 variant.id,
                                 truncate(&base_resume.name, 13),
                                 truncate(variant.source_model.as_deref().unwrap_or("-"), 13),
                                 variant.output_format.as_deref().unwrap_or("-"),
-                                truncate(&variant.created_at, 18)
+                                format!("v{}", variant.version),
+                                timestamps::local(&variant.created_at)
                             );
                         }
+                        if !history {
+                            println!("\nLatest versions only; --history lists older generations.");
+                        }
+                    }
+                }
+
+                ResumeCommands::Render { variant_id, output } => {
+                    let variant = db.get_resume_variant_by_id(variant_id)?
+                        .ok_or_else(|| HuntError::NotFound(format!("Resume variant #{} not found", variant_id)))?;
+                    let format = variant.output_format.as_deref().unwrap_or("markdown");
+                    let renderer = render::choose_renderer(format, |tool| check_binary(tool).is_some())?;
+                    let output = output.unwrap_or_else(|| {
+                        PathBuf::from(format!("resume-{}-v{}.pdf", variant.job_id, variant.id))
+                    });
+
+                    println!("Rendering variant #{} ({}) with {}...", variant.id, format, renderer);
+                    render::render_pdf(&variant.content, &renderer, &output)?;
+                    println!("✓ Saved {}", output.display());
+                }
+
+                ResumeCommands::Check { variant_id, model } => {
+                    let variant = db.get_resume_variant_by_id(variant_id)?
+                        .ok_or_else(|| HuntError::NotFound(format!("Resume variant #{} not found", variant_id)))?;
+                    let job_id = variant.job_id;
+                    let Some(model) = model.or(db.get_latest_keyword_model(job_id)?) else {
+                        return Err(HuntError::Validation(format!(
+                            "Job #{} has no keywords yet. Run 'hunt keywords {}' first", job_id, job_id
+                        )).into());
+                    };
+                    let keywords = db.get_job_keywords(job_id, Some(&model))?;
+                    if keywords.is_empty() {
+                        return Err(HuntError::NotFound(format!("No keywords from {} for job #{}", model, job_id)).into());
+                    }
+                    let job_title = db.get_job(job_id)?.map_or_else(|| "?".to_string(), |j| j.title);
+
+                    let coverage = ats::check(&variant.content, &keywords);
+                    let percent = |found: i32, total: i32| if total == 0 { 0.0 } else { found as f64 * 100.0 / total as f64 };
+                    println!("Variant #{} (v{}) for job #{}: {}", variant.id, variant.version, job_id, job_title);
+                    println!("Keywords: {} ({})", keywords.len(), model);
+                    println!("Coverage: {:.0}% by weight ({}/{} keywords found)\n",
+                             percent(coverage.found_weight(), coverage.total_weight()),
+                             keywords.len() - coverage.missing.len(), keywords.len());
+
+                    println!("{:<20} {:>7} {:>9}", "DOMAIN", "FOUND", "COVERAGE");
+                    println!("{}", "-".repeat(38));
+                    for domain in &coverage.domains {
+                        println!("{:<20} {:>7} {:>8.0}%",
+                                 truncate(&domain.domain, 18),
+                                 format!("{}/{}", domain.found, domain.total),
+                                 percent(domain.found_weight, domain.total_weight));
+                    }
+
+                    let required: Vec<_> = coverage.missing_required().collect();
+                    if !required.is_empty() {
+                        println!("\nMissing required keywords:");
+                        for kw in required {
+                            println!("  - {} ({})", kw.keyword, kw.domain);
+                        }
+                    }
+                    println!("\nSuggestions:");
+                    for tip in ats::suggestions(&coverage) {
+                        println!("  - {}", tip);
+                    }
+                }
+
+                ResumeCommands::ShowVariant { id } => {
+                    let variant = db.get_resume_variant_by_id(id)?
+                        .ok_or_else(|| HuntError::NotFound(format!("Resume variant #{} not found", id)))?;
+                    let base_name = db.get_base_resume(variant.base_resume_id)?
+                        .map_or_else(|| "?".to_string(), |r| r.name);
+                    let job_title = db.get_job(variant.job_id)?
+                        .map_or_else(|| "?".to_string(), |j| j.title);
+                    println!("Variant #{} (v{}) | Job #{}: {} | Base: {} | Model: {} | Format: {}",
+                             variant.id, variant.version, variant.job_id, job_title, base_name,
+                             variant.source_model.as_deref().unwrap_or("unknown"),
+                             variant.output_format.as_deref().unwrap_or("unknown"));
+                    println!("Created: {}", timestamps::local(&variant.created_at));
+                    if let Some(notes) = &variant.tailoring_notes {
+                        println!("Notes: {}", notes);
+                    }
+                    println!("\n{}", variant.content);
+                }
+
+                ResumeCommands::Compare { job_id, diff: true, between } => {
+                    use std::io::IsTerminal;
+                    let color = std::io::stdout().is_terminal();
+                    let job = db.get_job(job_id)?
+                        .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+
+                    if let Some(ids) = between {
+                        let get_variant = |id: i64| -> Result<models::ResumeVariant> {
+                            Ok(db.get_resume_variant_by_id(id)?
+                                .ok_or_else(|| HuntError::NotFound(format!("Resume variant #{} not found", id)))?)
+                        };
+                        let (a, b) = (get_variant(ids[0])?, get_variant(ids[1])?);
+                        println!("Variant #{} ({}) -> variant #{} ({}) for job #{}: {}\n",
+                                 a.id, a.source_model.as_deref().unwrap_or("unknown"),
+                                 b.id, b.source_model.as_deref().unwrap_or("unknown"),
+                                 job_id, job.title);
+                        print_text_diff(&a.content, &b.content, color);
+                        return Ok(());
+                    }
+
+                    let variants = db.list_resume_variants_for_job(job_id)?;
+                    if variants.is_empty() {
+                        println!("No resume variants found for job #{}.", job_id);
+                        return Ok(());
+                    }
+                    println!("Resume variants for job #{}: {} (changes from the base resume)\n", job_id, job.title);
+                    for variant in &variants {
+                        let base_resume = db.get_base_resume(variant.base_resume_id)?
+                            .ok_or_else(|| HuntError::NotFound("Base resume not found".to_string()))?;
+                        println!("{}", "=".repeat(60));
+                        println!("Base: {} -> Variant #{} | Model: {} | Format: {}",
+                                 base_resume.name, variant.id,
+                                 variant.source_model.as_deref().unwrap_or("unknown"),
+                                 variant.output_format.as_deref().unwrap_or("unknown"));
+                        println!("{}", "=".repeat(60));
+                        print_text_diff(&base_resume.content, &variant.content, color);
+                        println!();
                     }
                 }
 
-                ResumeCommands::Compare { job_id } => {
+                ResumeCommands::Compare { job_id, .. } => {
                     let variants = db.list_resume_variants_for_job(job_id)?;
                     if variants.is_empty() {
                         println!("No resume variants found for job #{}.", job_id);
                     } else {
                         let job = db.get_job(job_id)?
-                            .ok_or_else(|| anyhow!("Job #{} not found", job_id))?;
+                            .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
                         println!("Resume variants for job #{}: {}\n", job_id, job.title);
 
                         for variant in &variants {
                             let base_resume = db.get_base_resume(variant.base_resume_id)?
-                                .ok_or_else(|| anyhow!("Base resume not found"))?;
+                                .ok_or_else(|| HuntError::NotFound("Base resume not found".to_string()))?;
 
                             let model_str = variant.source_model.as_deref().unwrap_or("unknown");
                             let format_str = variant.output_format.as_deref().unwrap_or("unknown");
@@ -1352,7 +4794,7 @@ fn main() -> Result<()> {
                             println!("{}", "=".repeat(60));
                             println!("Variant #{} | Base: {} | Model: {} | Format: {}",
                                      variant.id, base_resume.name, model_str, format_str);
-                            println!("Created: {}", variant.created_at);
+                            println!("Created: {}", timestamps::local(&variant.created_at));
                             println!("{}", "=".repeat(60));
                             println!("{}", variant.content);
                             println!();
@@ -1365,16 +4807,29 @@ fn main() -> Result<()> {
         Commands::Cleanup {
             artifacts,
             duplicates,
+            merge,
+            urls,
+            pay,
+            purge,
             all,
             dry_run,
+            interactive,
+            forget_decisions,
         } => {
             db.ensure_initialized()?;
+            if interactive && dry_run {
+                return Err(HuntError::Validation("--interactive and --dry-run can't be used together".to_string()).into());
+            }
+            if forget_decisions {
+                println!("Forgot {} cleanup decision(s)", db.clear_cleanup_decisions()?);
+            }
+            let mut review = if interactive { CleanupReview::interactive(prompt_line) } else { CleanupReview::heuristic() };
 
             let mut total_removed = 0;
 
             if artifacts || all {
                 println!("Checking for navigation artifacts...");
-                let removed = cleanup_artifacts(&db, dry_run)?;
+                let removed = cleanup_artifacts(&db, dry_run, purge, &mut review)?;
                 total_removed += removed;
                 if dry_run {
                     println!("  Would remove {} artifact(s)", removed);
@@ -1383,23 +4838,52 @@ fn main() -> Result<()> {
                 }
             }
 
+            // Before duplicates, so jobs that only differed by tracking parameters match
+            if urls || all {
+                println!("Cleaning up job URLs...");
+                let fixed = cleanup_urls(&db, dry_run)?;
+                if dry_run {
+                    println!("  Would clean up {} URL(s)", fixed);
+                } else {
+                    println!("  Cleaned up {} URL(s)", fixed);
+                }
+            }
+
             if duplicates || all {
                 println!("Checking for duplicate jobs...");
-                let removed = cleanup_duplicates(&db, dry_run)?;
+                let removed = cleanup_duplicates(&db, dry_run, purge, merge, &mut review)?;
                 total_removed += removed;
+                let verb = if merge { "merge" } else { "remove" };
                 if dry_run {
-                    println!("  Would remove {} duplicate(s)", removed);
+                    println!("  Would {} {} duplicate(s)", verb, removed);
+                } else if merge {
+                    println!("  Merged {} duplicate(s)", removed);
                 } else {
                     println!("  Removed {} duplicate(s)", removed);
                 }
             }
 
-            if !artifacts && !duplicates && !all {
-                println!("No cleanup operation specified. Use --artifacts, --duplicates, or --all");
+            if pay || all {
+                println!("Re-reading pay from descriptions...");
+                let fixed = cleanup_pay(&db, dry_run)?;
+                if dry_run {
+                    println!("  Would update pay on {} job(s)", fixed);
+                } else {
+                    println!("  Updated pay on {} job(s)", fixed);
+                }
+            }
+
+            if !artifacts && !duplicates && !urls && !pay && !all {
+                if forget_decisions {
+                    return Ok(());
+                }
+                println!("No cleanup operation specified. Use --artifacts, --duplicates, --urls, --pay, or --all");
             } else if dry_run {
                 println!("\nTotal that would be removed: {}", total_removed);
-            } else {
+            } else if purge || total_removed == 0 {
                 println!("\nTotal removed: {}", total_removed);
+            } else {
+                println!("\nTotal archived: {} (hunt list --archived to see them, hunt restore <id> to undo)", total_removed);
             }
         }
 
@@ -1407,12 +4891,13 @@ fn main() -> Result<()> {
             db.ensure_initialized()?;
             match command {
                 GlassdoorCommands::Fetch { employer, all, force, model, dry_run } => {
+                    let model = config::load()?.model(model);
                     let spec = ai::resolve_model(&model)?;
-                    let provider = ai::create_provider(&spec)?;
+                    let provider = metered_provider(&db, &spec, "glassdoor fetch")?;
 
                     let employers_to_fetch = if let Some(name) = employer {
                         vec![db.get_employer_by_name(&name)?
-                            .ok_or_else(|| anyhow!("Employer '{}' not found", name))?]
+                            .ok_or_else(|| HuntError::NotFound(format!("Employer '{}' not found", name)))?]
                     } else if all {
                         db.list_employers(None)?
                     } else {
@@ -1498,7 +4983,7 @@ fn main() -> Result<()> {
                                      truncate(&emp.name, 28),
                                      emp.glassdoor_rating.unwrap_or(0.0),
                                      emp.glassdoor_review_count.unwrap_or(0),
-                                     emp.last_glassdoor_fetch.as_deref().unwrap_or("-"),
+                                     emp.last_glassdoor_fetch.as_deref().map(timestamps::local).unwrap_or_else(|| "-".to_string()),
                             );
                         }
                         println!("\nTotal: {} employer(s) with Glassdoor data", employers.len());
@@ -1507,12 +4992,26 @@ fn main() -> Result<()> {
 
                 GlassdoorCommands::Show { employer } => {
                     let emp = db.get_employer_by_name(&employer)?
-                        .ok_or_else(|| anyhow!("Employer '{}' not found", employer))?;
+                        .ok_or_else(|| HuntError::NotFound(format!("Employer '{}' not found", employer)))?;
 
                     // Summary
                     let (positive, negative, neutral, avg_rating) = db.get_sentiment_summary(emp.id)?;
                     let total = positive + negative + neutral;
 
+                    if cli.json {
+                        let rating = (total > 0).then_some(avg_rating);
+                        return print_json(&serde_json::json!({
+                            "employer": emp.name,
+                            "rating": rating,
+                            "review_count": total,
+                            "positive": positive,
+                            "neutral": neutral,
+                            "negative": negative,
+                            "last_fetched": emp.last_glassdoor_fetch,
+                            "reviews": db.list_glassdoor_reviews(Some(emp.id))?,
+                        }));
+                    }
+
                     if total == 0 {
                         println!("No Glassdoor reviews found for '{}'.", employer);
                         println!("Run 'hunt glassdoor fetch --employer \"{}\"' to collect.", employer);
@@ -1526,34 +5025,357 @@ fn main() -> Result<()> {
                     println!("  Negative: {} ({:.0}%)", negative, negative as f64 / total as f64 * 100.0);
 
                     if let Some(fetched) = &emp.last_glassdoor_fetch {
-                        println!("  Last fetched: {}", fetched);
+                        println!("  Last fetched: {}", timestamps::local(fetched));
+                    }
+
+                    // Reviews
+                    let reviews = db.list_glassdoor_reviews(Some(emp.id))?;
+                    if !reviews.is_empty() {
+                        println!("\nReviews:\n");
+                        for review in reviews {
+                            println!("{:<6} {:>4.1}★ {:<10} {}",
+                                review.id,
+                                review.rating,
+                                review.sentiment,
+                                review.review_date.as_deref().unwrap_or("-")
+                            );
+                            if let Some(title) = &review.title {
+                                println!("       {}", title);
+                            }
+                            if let Some(pros) = &review.pros {
+                                println!("       Pros: {}", truncate(pros, 60));
+                            }
+                            if let Some(cons) = &review.cons {
+                                println!("       Cons: {}", truncate(cons, 60));
+                            }
+                            println!();
+                        }
+                    }
+                }
+
+                GlassdoorCommands::Trend { employer } => {
+                    let emp = db.get_employer_by_name(&employer)?
+                        .ok_or_else(|| HuntError::NotFound(format!("Employer '{}' not found", employer)))?;
+                    let months = trends::rating_by_month(&db.list_glassdoor_snapshots(emp.id)?);
+                    if months.is_empty() {
+                        println!("No Glassdoor fetches recorded for '{}'.", emp.name);
+                        println!("Run 'hunt glassdoor fetch --employer \"{}\" --force' to record one.", emp.name);
+                        return Ok(());
+                    }
+
+                    let percent = |count: i64, total: i64| if total > 0 { count as f64 / total as f64 * 100.0 } else { 0.0 };
+                    println!("Glassdoor trend: {}\n", emp.name);
+                    println!("{:<9} {:>7} {:>7} {:>8} {:>5} {:>5} {:>5}", "MONTH", "RATING", "CHANGE", "REVIEWS", "POS", "NEU", "NEG");
+                    println!("{}", "-".repeat(53));
+                    for m in &months {
+                        let total = m.positive + m.neutral + m.negative;
+                        println!("{:<9} {:>6.2}★ {:>7} {:>8} {:>4.0}% {:>4.0}% {:>4.0}%",
+                                 m.month,
+                                 m.rating,
+                                 m.change.map(|c| format!("{:+.2}", c)).unwrap_or_else(|| "-".to_string()),
+                                 m.review_count,
+                                 percent(m.positive, total),
+                                 percent(m.neutral, total),
+                                 percent(m.negative, total),
+                        );
+                    }
+
+                    if let (Some(first), Some(last)) = (months.first(), months.last())
+                        && months.len() > 1
+                    {
+                        let ratings: Vec<Option<i64>> = months.iter().map(|m| Some((m.rating * 100.0).round() as i64)).collect();
+                        println!("\nRating trend: {}", trends::sparkline(&ratings));
+                        println!("Change: {:+.2}★ ({:.2} in {} → {:.2} in {})", last.rating - first.rating, first.rating, first.month, last.rating, last.month);
+                    }
+                }
+            }
+        }
+
+        Commands::Prune { older_than, no_check, ignore_robots, dry_run } => {
+            db.ensure_initialized()?;
+            let cutoff = journal::parse_since(&older_than, chrono::Local::now().naive_local())?;
+            let jobs = db.stale_jobs(&timestamps::local_to_db(cutoff))?;
+            if jobs.is_empty() {
+                println!("No open jobs untouched since {}.", cutoff.format("%Y-%m-%d"));
+                return Ok(());
+            }
+            println!("{} open job(s) untouched since {}{}\n",
+                     jobs.len(), cutoff.format("%Y-%m-%d"), if no_check { "" } else { ", checking postings..." });
+
+            let settings = config::load()?;
+            let mut policy = FetchPolicy::new(&settings.fetch_blocklist, !ignore_robots);
+            let mut limiter = RateLimiter::new(&settings.rate_limits);
+            let (mut closed, mut still_open, mut errors) = (0, 0, 0);
+            for job in &jobs {
+                let label = format!("#{} {} at {}", job.id, truncate(&job.title, 40),
+                                    truncate(job.employer_name.as_deref().unwrap_or("Unknown"), 25));
+                // Postings that can't be checked are closed for their age alone
+                let reason = match (&job.url, no_check) {
+                    (_, true) => "stale".to_string(),
+                    (None, false) => "stale, no URL to check".to_string(),
+                    (Some(url), false) => {
+                        if let Some(refusal) = policy.refusal(&db, url, true)? {
+                            format!("stale, not checked: {}", refusal)
+                        } else {
+                            wait_for_domain(&limiter, url);
+                            let checked = browser::check_posting(url);
+                            policy.record(&db, url, checked.as_ref().err())?;
+                            record_fetch(&mut limiter, url, 1, false);
+                            match checked {
+                                Ok(browser::PostingState::Gone(why)) => why,
+                                Ok(browser::PostingState::Unchecked(why)) => format!("stale, couldn't check: {}", why),
+                                Ok(browser::PostingState::Open) => {
+                                    println!("  ✓ {} is still up", label);
+                                    if !dry_run {
+                                        db.touch_job(job.id)?;
+                                    }
+                                    still_open += 1;
+                                    continue;
+                                }
+                                Err(e) => {
+                                    println!("  ✗ {}: {}", label, e);
+                                    errors += 1;
+                                    continue;
+                                }
+                            }
+                        }
+                    }
+                };
+                println!("  ⚠ {} → closed ({})", label, reason);
+                if !dry_run {
+                    db.update_job_status(job.id, "closed")?;
+                    db.add_job_note(job.id, &format!("Closed by hunt prune: {}", reason))?;
+                }
+                closed += 1;
+            }
+
+            println!();
+            if dry_run {
+                println!("Would close {} job(s); {} still up, {} failed to check", closed, still_open, errors);
+                println!("(Dry run - nothing was changed)");
+            } else {
+                println!("✓ Closed {} job(s); {} still up, {} failed to check", closed, still_open, errors);
+            }
+        }
+
+        Commands::Compact { before, archive, dry_run } => {
+            db.ensure_initialized()?;
+            let archive = archive.unwrap_or_else(|| db.default_archive_path());
+
+            let size_before = std::fs::metadata(db.path()).map(|m| m.len()).unwrap_or(0);
+            let stats = db.compact(&before, &archive, dry_run)?;
+
+            if dry_run {
+                println!("Compaction preview (before {}):", before);
+                println!("  Snapshots to prune: {}", stats.snapshots_pruned);
+                println!("  Jobs to archive:    {}", stats.jobs_archived);
+                println!("\n(Dry run - nothing was changed)");
+            } else {
+                let size_after = std::fs::metadata(db.path()).map(|m| m.len()).unwrap_or(0);
+                println!("✓ Compaction complete (before {})", before);
+                println!("  Snapshots pruned: {}", stats.snapshots_pruned);
+                println!("  Jobs archived:    {} → {}", stats.jobs_archived, archive.display());
+                println!("  Database size:    {} KB → {} KB", size_before / 1024, size_after / 1024);
+            }
+        }
+
+        Commands::Trends { command } => {
+            db.ensure_initialized()?;
+            match command {
+                TrendsCommands::Pay { role, window } => {
+                    let months = trends::parse_window(&window)?;
+                    let role = role.map(|r| r.to_lowercase());
+                    if let Some(role) = &role
+                        && !trends::ROLE_FAMILIES.iter().any(|(family, _)| family == role)
+                    {
+                        let families: Vec<&str> = trends::ROLE_FAMILIES.iter().map(|(family, _)| *family).collect();
+                        return Err(HuntError::Validation(format!(
+                            "Unknown role family '{}'. Available: {}", role, families.join(", ")
+                        )).into());
+                    }
+
+                    let jobs = db.list_jobs(None, None)?;
+                    let buckets = trends::pay_by_month(&jobs, role.as_deref(), months, chrono::Local::now().date_naive());
+                    let total: usize = buckets.iter().map(|b| b.jobs).sum();
+                    let label = role.as_deref().unwrap_or("all roles");
+                    if total == 0 {
+                        println!("No jobs with advertised pay for {} in the last {} month(s).", label, months);
+                        return Ok(());
+                    }
+
+                    let money = |pay: Option<i64>| pay.map(|p| format!("${}k", p / 1000)).unwrap_or_else(|| "-".to_string());
+                    println!("Median advertised pay, {} ({} jobs)\n", label, total);
+                    println!("{:<9} {:>5} {:>10} {:>10} {:>10}", "MONTH", "JOBS", "MIN", "MAX", "MIDPOINT");
+                    println!("{}", "-".repeat(48));
+                    for b in &buckets {
+                        println!("{:<9} {:>5} {:>10} {:>10} {:>10}", b.month, b.jobs, money(b.median_min), money(b.median_max), money(b.median_mid));
+                    }
+
+                    let mids: Vec<Option<i64>> = buckets.iter().map(|b| b.median_mid).collect();
+                    println!("\nMidpoint trend: {}", trends::sparkline(&mids));
+                    let present: Vec<i64> = mids.iter().flatten().copied().collect();
+                    if let (Some(first), Some(last)) = (present.first(), present.last())
+                        && present.len() > 1
+                        && *first > 0
+                    {
+                        let change = (*last - *first) as f64 / *first as f64 * 100.0;
+                        println!("Change: {:+.1}% ({} → {})", change, money(Some(*first)), money(Some(*last)));
+                    }
+                }
+            }
+        }
+
+        Commands::Stats { weeks } => {
+            db.ensure_initialized()?;
+            let jobs = db.list_jobs(None, None)?;
+            let statuses = db.list_job_statuses()?;
+            let mut fit_scores = Vec::new();
+            for job in &jobs {
+                fit_scores.extend(db.get_best_fit_score(job.id)?);
+            }
+            let stats = stats::compute(&jobs, &statuses, &fit_scores, weeks, chrono::Local::now().date_naive());
+            if cli.json {
+                println!("{}", serde_json::to_string_pretty(&stats)?);
+            } else {
+                print!("{}", stats::render(&stats));
+            }
+        }
+
+        Commands::Report { command } => {
+            db.ensure_initialized()?;
+            match command {
+                ReportCommands::Funnel { weeks } => {
+                    if weeks == 0 {
+                        return Err(HuntError::Validation("--weeks must be at least 1".to_string()).into());
+                    }
+                    let jobs = db.list_jobs(None, None)?;
+                    let history = db.list_status_history(None)?;
+                    let funnel = stats::funnel(&jobs, &history, &db.list_job_statuses()?, weeks, chrono::Local::now().date_naive());
+                    if cli.json {
+                        println!("{}", serde_json::to_string_pretty(&funnel)?);
+                    } else {
+                        print!("{}", stats::render_funnel(&funnel));
                     }
+                }
+            }
+        }
+
+        Commands::Usage { since } => {
+            db.ensure_initialized()?;
+            let cutoff = journal::parse_since(&since, chrono::Local::now().naive_local())?;
+            let cutoff = timestamps::local_to_db(cutoff);
+            let by_model = db.get_ai_usage(&cutoff, false)?;
+            if by_model.is_empty() {
+                println!("No AI calls recorded since {}.", timestamps::local(&cutoff));
+                return Ok(());
+            }
+            println!("AI usage since {}\n", timestamps::local(&cutoff));
+            for (heading, totals) in [("MODEL", by_model), ("COMMAND", db.get_ai_usage(&cutoff, true)?)] {
+                println!("{:<24} {:>6} {:>12} {:>12} {:>10}", heading, "CALLS", "INPUT TOK", "OUTPUT TOK", "COST");
+                println!("{}", "-".repeat(68));
+                for t in &totals {
+                    let cost = match (t.unpriced_calls, t.calls) {
+                        (u, c) if u == c => "-".to_string(),
+                        (0, _) => format!("${:.2}", t.cost_usd),
+                        _ => format!("${:.2}*", t.cost_usd),
+                    };
+                    println!("{:<24} {:>6} {:>12} {:>12} {:>10}", truncate(&t.key, 23), t.calls, t.input_tokens, t.output_tokens, cost);
+                }
+                let total_cost: f64 = totals.iter().map(|t| t.cost_usd).sum();
+                println!("{:<24} {:>6} {:>12} {:>12} {:>10}\n", "TOTAL",
+                    totals.iter().map(|t| t.calls).sum::<i64>(),
+                    totals.iter().map(|t| t.input_tokens).sum::<i64>(),
+                    totals.iter().map(|t| t.output_tokens).sum::<i64>(),
+                    format!("${:.2}", total_cost));
+            }
+            println!("Costs are list-price estimates. - = no estimate (claude CLI bills to your subscription), * = some calls unpriced.");
+        }
+
+        Commands::Journal { since, polish, model, file, dry_run } => {
+            let model = config::load()?.model(model);
+            db.ensure_initialized()?;
+            let now = chrono::Local::now().naive_local();
+            let since = journal::parse_since(&since, now)?;
+            let activity = db.get_journal_activity(&timestamps::local_to_db(since))?;
+            let log = journal::render_activity(&activity);
+
+            let narrative = if polish {
+                let spec = ai::resolve_model(&model)?;
+                let provider = metered_provider(&db, &spec, "journal")?;
+                info!("Polishing journal entry (model: {})...", spec.short_name);
+                Some(ai::polish_journal(provider.as_ref(), &log)?)
+            } else {
+                None
+            };
+
+            let entry = journal::format_entry(now.date(), since, narrative.as_deref(), &log);
+            print!("{}", entry);
+
+            if !dry_run {
+                let path = file.unwrap_or_else(|| db.default_journal_path());
+                journal::append_entry(&path, &entry)?;
+                println!("\n✓ Appended to {}", path.display());
+            }
+        }
+
+        Commands::Digest { week: _, since, format, top, output, template, email } => {
+            db.ensure_initialized()?;
+            let settings = config::load()?;
+            if email {
+                settings.smtp()?; // before the work, not after
+            }
+            let now = chrono::Local::now().naive_local();
+            let since = journal::parse_since(since.as_deref().unwrap_or("7d"), now)?;
+            let activity = db.get_digest_activity(&timestamps::local_to_db(since), top)?;
+            let rendered = match template {
+                Some(template) => {
+                    let path = templates::resolve(&db, &template)?;
+                    templates::render_file(&path, templates::digest_context(&activity, since.date(), now.date()))?
+                }
+                None => digest::render(&activity, since.date(), now.date(), format),
+            };
+            match output {
+                Some(path) => {
+                    std::fs::write(&path, &rendered).with_context(|| format!("Failed to write {}", path.display()))?;
+                    println!("✓ Wrote digest to {}", path.display());
+                }
+                None => print!("{}", rendered),
+            }
+            if email {
+                let mail = smtp::Outgoing {
+                    to: settings.smtp()?.recipient().to_string(),
+                    subject: format!("Job hunt digest: {} to {}", since.date().format("%Y-%m-%d"), now.date().format("%Y-%m-%d")),
+                    text: digest::render(&activity, since.date(), now.date(), digest::Format::Markdown),
+                    html: Some(digest::render(&activity, since.date(), now.date(), digest::Format::Html)),
+                    attachments: Vec::new(),
+                };
+                send_mail(&settings, &mail)?;
+            }
+        }
 
-                    // Reviews
-                    let reviews = db.list_glassdoor_reviews(Some(emp.id))?;
-                    if !reviews.is_empty() {
-                        println!("\nReviews:\n");
-                        for review in reviews {
-                            println!("{:<6} {:>4.1}★ {:<10} {}",
-                                review.id,
-                                review.rating,
-                                review.sentiment,
-                                review.review_date.as_deref().unwrap_or("-")
-                            );
-                            if let Some(title) = &review.title {
-                                println!("       {}", title);
-                            }
-                            if let Some(pros) = &review.pros {
-                                println!("       Pros: {}", truncate(pros, 60));
-                            }
-                            if let Some(cons) = &review.cons {
-                                println!("       Cons: {}", truncate(cons, 60));
-                            }
-                            println!();
-                        }
+        Commands::Serve { port, host, token_file, allow_origin } => {
+            db.ensure_initialized()?;
+            let token = match &token_file {
+                Some(path) => {
+                    let path = expand_home(path);
+                    let token = std::fs::read_to_string(&path)
+                        .with_context(|| format!("Failed to read token file: {:?}", path))?;
+                    let token = token.trim().to_string();
+                    if token.is_empty() {
+                        return Err(HuntError::Config(format!("Token file {:?} is empty", path)).into());
                     }
+                    Some(token)
                 }
+                None => None,
+            };
+            let loopback = host == "localhost" || host.parse::<std::net::IpAddr>().is_ok_and(|ip| ip.is_loopback());
+            if !loopback && token.is_none() {
+                return Err(HuntError::Validation(format!(
+                    "Serving on {} would let anyone who can reach it change your data; pass --token-file",
+                    host
+                )).into());
             }
+            server::serve(&db, &server::Access { host, port, token, allow_origin })?;
         }
 
         Commands::Destroy { confirm } => {
@@ -1587,55 +5409,70 @@ fn main() -> Result<()> {
             db.ensure_initialized()?;
             match command {
                 StartupCommands::Research { employer } => {
-                    println!("Researching startup info for '{}'...", employer);
-
-                    // Get or create employer
-                    let employer_id = db.get_or_create_employer(&employer)?;
-
-                    // Perform research
-                    let research_data = research_startup(&employer)?;
-
-                    // Update database
-                    db.update_employer_research(
-                        employer_id,
-                        research_data.crunchbase_url.as_deref(),
-                        research_data.funding_stage.as_deref(),
-                        research_data.total_funding,
-                        research_data.last_funding_date.as_deref(),
-                        research_data.yc_batch.as_deref(),
-                        research_data.yc_url.as_deref(),
-                        research_data.hn_mentions_count,
-                        research_data.recent_news.as_deref(),
-                    )?;
+                    run_startup_research(&db, &employer)?;
+                }
+            }
+        }
 
-                    println!("\n✓ Research complete");
-                    if let Some(batch) = &research_data.yc_batch {
-                        println!("  YC Batch: {}", batch);
-                    }
-                    if let Some(stage) = &research_data.funding_stage {
-                        println!("  Funding Stage: {}", stage);
-                    }
-                    if let Some(funding) = research_data.total_funding {
-                        println!("  Total Funding: ${}", funding);
-                    }
-                    if let Some(count) = research_data.hn_mentions_count {
-                        println!("  HN Mentions: {}", count);
-                    }
-                    if let Some(news) = &research_data.recent_news {
-                        println!("  Recent News: {}", news);
+        Commands::Board { command } => {
+            db.ensure_initialized()?;
+            match command {
+                BoardCommands::Fetch { company, source, employer, dry_run } => {
+                    let source = boards::BoardSource::parse(&source)?;
+                    let employer = employer.unwrap_or_else(|| company.clone());
+
+                    println!("Fetching {} board for '{}'...", source.name(), company);
+                    let jobs = boards::fetch_board_jobs(source, &company)?;
+                    let stats = boards::import_board_jobs(&db, &jobs, &employer, source, dry_run)?;
+
+                    println!("\nResults:");
+                    println!("  Open roles: {}", stats.found);
+                    println!("  Jobs added: {}", stats.added);
+                    println!("  Duplicates: {}", stats.duplicates);
+                    if dry_run {
+                        println!("\n(Dry run - no jobs were actually added)");
                     }
                 }
             }
         }
 
-        Commands::Fetch { id, all, force, limit, delay, include_closed, no_headless } => {
-            require_browser_deps()?;
+        Commands::Fetch { id, all, force, limit, delay, include_closed, no_headless, browser_only, driver, ignore_robots, status } => {
             let headless = !no_headless;
+            let driver = driver.as_deref().map(browser::Driver::parse).transpose()?;
             db.ensure_initialized()?;
+            let settings = config::load()?;
+            let mut policy = FetchPolicy::new(&settings.fetch_blocklist, !ignore_robots);
+
+            if status {
+                let domains = db.list_fetch_domains()?;
+                if cli.json {
+                    return print_json(&domains);
+                }
+                if domains.is_empty() {
+                    println!("Nothing fetched yet.");
+                } else {
+                    let now = chrono::Utc::now();
+                    println!("{:<28} {:>5} {:>6} {:>6}  {:<16}  LAST ERROR", "DOMAIN", "OK", "FAILED", "STREAK", "BACKED OFF UNTIL");
+                    for d in &domains {
+                        let until = d.backoff_until.as_deref()
+                            .filter(|until| policy::backing_off(Some(until), now))
+                            .map_or("-".to_string(), timestamps::local);
+                        println!("{:<28} {:>5} {:>6} {:>6}  {:<16}  {}",
+                                 truncate(&d.domain, 28), d.successes, d.failures, d.failure_streak, until,
+                                 d.last_error.as_deref().map_or("-".to_string(), |e| truncate(e, 60)));
+                    }
+                }
+                if !settings.fetch_blocklist.is_empty() {
+                    println!("\nBlocklisted: {}", settings.fetch_blocklist.join(", "));
+                }
+                return Ok(());
+            }
 
             if all {
                 // Fetch all jobs (with or without descriptions based on --force)
                 let jobs = db.get_jobs_to_fetch(limit, force, include_closed)?;
+                let jobs = ratelimit::interleave(jobs, |job| job.url.as_deref());
+                let mut limiter = RateLimiter::new(&settings.rate_limits);
 
                 if jobs.is_empty() {
                     if force {
@@ -1656,7 +5493,7 @@ fn main() -> Result<()> {
                 // Confirmation prompt for large batches
                 if total > 10 {
                     use std::io::{self, Write};
-                    print!("Fetch {} jobs? This will take approximately {} minutes. (y/N): ",
+                    print!("Fetch {} jobs? This can take up to {} minutes. (y/N): ",
                            total, (total as u64 * delay) / 60);
                     io::stdout().flush()?;
                     let mut response = String::new();
@@ -1672,35 +5509,57 @@ fn main() -> Result<()> {
                     println!("⚠ Warning: Short delay ({} seconds) may trigger rate limiting", delay);
                 }
 
-                println!("\nFetching descriptions for {} jobs...\n", total);
+                info!("Fetching descriptions for {} jobs...", total);
 
                 let start_time = std::time::Instant::now();
                 let mut success_count = 0;
                 let mut fail_count = 0;
                 let mut closed_count = 0;
+                let mut changed_count = 0;
                 let mut failed_jobs = Vec::new();
+                let mut skipped_jobs = Vec::new();
+                let mut fetcher = DescriptionFetcher::new(headless, browser_only, driver);
 
                 // Fetch each job
                 for (i, job) in jobs.iter().enumerate() {
                     let job_num = i + 1;
+                    let mut used_browser = false;
                     let employer_name = job.employer_name.as_deref().unwrap_or("Unknown");
-                    println!("[{}/{}] Fetching job #{} ({} at {})",
+                    info!("[{}/{}] Fetching job #{} ({} at {})",
                              job_num, total, job.id,
                              truncate(&job.title, 40),
                              truncate(employer_name, 30));
 
                     if let Some(url) = &job.url {
-                        match fetch_job_description(url, headless) {
-                            Ok(job_desc) => {
+                        if let Some(reason) = policy.refusal(&db, url, true)? {
+                            info!("Skipped: {}", reason);
+                            skipped_jobs.push((job.id, reason));
+                            continue;
+                        }
+                        wait_for_domain(&limiter, url);
+                        // Assume the worst (a browser fetch) for the pause if it fails
+                        used_browser = true;
+                        let fetched = fetcher.fetch(url);
+                        policy.record(&db, url, fetched.as_ref().err())?;
+                        match fetched {
+                            Ok((job_desc, browser)) => {
+                                used_browser = browser;
+                                if report_description_change(job, &job_desc.text) {
+                                    changed_count += 1;
+                                }
                                 match db.update_job_description(job.id, &job_desc.text,
                                                                job_desc.pay_min, job_desc.pay_max) {
                                     Ok(_) => {
                                         if let Some(ref emp_name) = job_desc.employer_name {
                                             let _ = db.update_job_employer(job.id, emp_name);
                                         }
+                                        let _ = db.set_job_easy_apply(job.id, job_desc.easy_apply);
+                                        if let Some(application) = &job_desc.application {
+                                            let _ = db.set_job_application(job.id, application);
+                                        }
                                         if job_desc.no_longer_accepting {
                                             let _ = db.update_job_status(job.id, "closed");
-                                            println!("⚠ No longer accepting applications — marked as closed");
+                                            info!("⚠ No longer accepting applications — marked as closed");
                                             closed_count += 1;
                                         }
                                         let pay_info = match (job_desc.pay_min, job_desc.pay_max) {
@@ -1709,40 +5568,42 @@ fn main() -> Result<()> {
                                             (None, Some(max)) => format!(" | Pay: up to ${}K", max/1000),
                                             (None, None) => String::new(),
                                         };
-                                        println!("✓ Fetched ({} chars{})", job_desc.text.len(), pay_info);
+                                        info!("✓ Fetched ({} chars{})", job_desc.text.len(), pay_info);
                                         success_count += 1;
                                     }
                                     Err(e) => {
-                                        eprintln!("✗ Failed to save: {}", e);
+                                        warn!("Job #{}: failed to save: {}", job.id, e);
                                         fail_count += 1;
                                         failed_jobs.push((job.id, format!("save error: {}", e)));
                                     }
                                 }
                             }
                             Err(e) => {
-                                eprintln!("✗ Failed to fetch: {}", e);
+                                warn!("Job #{}: failed to fetch: {}", job.id, e);
                                 fail_count += 1;
                                 failed_jobs.push((job.id, format!("fetch error: {}", e)));
                             }
                         }
                     } else {
-                        eprintln!("✗ No URL available");
+                        warn!("Job #{}: no URL available", job.id);
                         fail_count += 1;
                         failed_jobs.push((job.id, "no URL".to_string()));
                     }
 
-                    // Delay between fetches (except after last one)
-                    if job_num < total {
-                        let delay_with_jitter = add_jitter(delay);
-                        countdown(delay_with_jitter);
+                    if let Some(url) = &job.url {
+                        record_fetch(&mut limiter, url, delay, used_browser);
                     }
                 }
+                fetcher.finish();
 
                 // Summary
                 let elapsed = start_time.elapsed();
                 println!("\n═══════════════════════════════════════════");
                 println!("Summary:");
                 println!("✓ Successfully fetched: {}/{}", success_count, total);
+                if changed_count > 0 {
+                    println!("↻ Changed since last fetch: {}", changed_count);
+                }
                 if closed_count > 0 {
                     println!("⚠ Closed (no longer accepting): {}", closed_count);
                 }
@@ -1755,19 +5616,36 @@ fn main() -> Result<()> {
                         }
                     }
                 }
+                if !skipped_jobs.is_empty() {
+                    println!("⊘ Skipped by fetch policy: {}/{}", skipped_jobs.len(), total);
+                    for (job_id, reason) in &skipped_jobs {
+                        println!("  Job #{}: {}", job_id, reason);
+                    }
+                }
                 println!("⏱ Total time: {}m {}s", elapsed.as_secs() / 60, elapsed.as_secs() % 60);
                 println!("═══════════════════════════════════════════");
+                if fail_count > 0 {
+                    return Err(HuntError::Partial(format!("{} of {} jobs failed to fetch", fail_count, total)).into());
+                }
 
             } else {
                 // Single job fetch (original behavior)
-                let job_id = id.ok_or_else(|| anyhow!("Job ID required without --all flag"))?;
+                let job_id = id.ok_or_else(|| HuntError::Validation("Job ID required without --all flag".to_string()))?;
                 let job = db.get_job(job_id)?
-                    .ok_or_else(|| anyhow!("Job #{} not found", job_id))?;
+                    .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
 
                 if let Some(url) = &job.url {
+                    if let Some(reason) = policy.refusal(&db, url, false)? {
+                        return Err(HuntError::Validation(format!("Not fetching job #{}: {}", job_id, reason)).into());
+                    }
                     println!("Fetching job description from: {}", url);
                     // Fetch and extract description
-                    let job_desc = fetch_job_description(url, headless)?;
+                    let mut fetcher = DescriptionFetcher::new(headless, browser_only, driver);
+                    let fetched = fetcher.fetch(url);
+                    fetcher.finish();
+                    policy.record(&db, url, fetched.as_ref().err())?;
+                    let (job_desc, _) = fetched?;
+                    report_description_change(&job, &job_desc.text);
 
                     // Update job with description and pay info
                     db.update_job_description(job_id, &job_desc.text, job_desc.pay_min, job_desc.pay_max)?;
@@ -1776,6 +5654,16 @@ fn main() -> Result<()> {
                         db.update_job_employer(job_id, emp_name)?;
                         println!("✓ Employer updated: {}", emp_name);
                     }
+                    db.set_job_easy_apply(job_id, job_desc.easy_apply)?;
+                    if let Some(application) = &job_desc.application {
+                        db.set_job_application(job_id, application)?;
+                        report_application(&db, job_id, application)?;
+                    }
+                    match job_desc.easy_apply {
+                        Some(true) => println!("✓ LinkedIn Easy Apply"),
+                        Some(false) => println!("✓ Applies on the employer's site"),
+                        None => {}
+                    }
 
                     if job_desc.no_longer_accepting {
                         db.update_job_status(job_id, "closed")?;
@@ -1791,35 +5679,276 @@ fn main() -> Result<()> {
                     println!("✓ Job description fetched and stored ({} chars{})", job_desc.text.len(), pay_info);
                 } else {
                     println!("Error: Job #{} has no URL", job_id);
-                    return Err(anyhow!("Job has no URL to fetch from"));
+                    return Err(HuntError::Validation("Job has no URL to fetch from".to_string()).into());
+                }
+            }
+        }
+
+        Commands::Resolve { job_id, all: _, limit, ignore_robots } => {
+            db.ensure_initialized()?;
+            let jobs = match job_id {
+                Some(id) => {
+                    let job = db.get_job(id)?
+                        .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", id)))?;
+                    if job.url.is_none() {
+                        return Err(HuntError::Validation(format!("Job #{} has no URL to resolve", id)).into());
+                    }
+                    vec![job]
+                }
+                None => db.get_jobs_to_resolve(limit)?,
+            };
+            if jobs.is_empty() {
+                println!("Every open job with a URL already has its application URL.");
+                return Ok(());
+            }
+            let single = job_id.is_some();
+            let settings = config::load()?;
+            let mut policy = FetchPolicy::new(&settings.fetch_blocklist, !ignore_robots);
+            let mut limiter = RateLimiter::new(&settings.rate_limits);
+            let jobs = ratelimit::interleave(jobs, |job| job.url.as_deref());
+            let (mut resolved, mut unresolved, mut failed, mut skipped) = (0, 0, 0, 0);
+            for job in &jobs {
+                let url = job.url.as_deref().expect("jobs to resolve have URLs");
+                let label = format!("#{} {}", job.id, truncate(&job.title, 40));
+                if let Some(reason) = policy.refusal(&db, url, !single)? {
+                    if single {
+                        return Err(HuntError::Validation(format!("Not resolving job #{}: {}", job.id, reason)).into());
+                    }
+                    println!("  ⊘ {}: skipped, {}", label, reason);
+                    skipped += 1;
+                    continue;
+                }
+                wait_for_domain(&limiter, url);
+                let result = browser::resolve_application(url);
+                policy.record(&db, url, result.as_ref().err())?;
+                record_fetch(&mut limiter, url, 1, false);
+                match result {
+                    Ok(Some(application)) => {
+                        db.set_job_application(job.id, &application)?;
+                        print!("  {}: ", label);
+                        report_application(&db, job.id, &application)?;
+                        resolved += 1;
+                    }
+                    Ok(None) => {
+                        println!("  - {}: no outbound application link (Easy Apply, or a login wall)", label);
+                        unresolved += 1;
+                    }
+                    Err(e) if single => return Err(e),
+                    Err(e) => {
+                        println!("  ✗ {}: {}", label, e);
+                        failed += 1;
+                    }
+                }
+            }
+            if !single {
+                println!("\n✓ Resolved {} job(s); {} without an outbound link, {} failed, {} skipped by fetch policy",
+                         resolved, unresolved, failed, skipped);
+            }
+        }
+
+        Commands::Diff { job_id, summary } => {
+            use std::io::IsTerminal;
+            db.ensure_initialized()?;
+            let job = db.get_job(job_id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+            let snapshots = db.list_job_snapshots(job_id)?;
+            if snapshots.len() < 2 {
+                println!("Job #{} has {} snapshot(s), nothing to compare. Re-fetch it to take another: hunt fetch {}",
+                         job_id, snapshots.len(), job_id);
+                return Ok(());
+            }
+
+            let color = std::io::stdout().is_terminal();
+            println!("Job #{}: {} ({} snapshots)\n", job_id, job.title, snapshots.len());
+            for pair in snapshots.windows(2) {
+                let (old, new) = (&pair[0], &pair[1]);
+                let change = changes::PostingChange::between(&old.raw_text, &new.raw_text);
+                println!("{} → {}: {}",
+                         timestamps::local(&old.captured_at), timestamps::local(&new.captured_at), change.summary());
+                if !summary && !change.is_empty() {
+                    println!();
+                    print!("{}", diff::render(&diff::diff_lines(&old.raw_text, &new.raw_text), 3, color));
+                    println!();
                 }
             }
         }
 
-        Commands::Analyze { job_id, model } => {
+        Commands::Locate { job_id, model, no_ai } => {
+            let model = config::load()?.model(model);
+            db.ensure_initialized()?;
+            let jobs: Vec<models::Job> = match job_id {
+                Some(id) => vec![db.get_job(id)?.ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", id)))?],
+                None => db.list_jobs(None, None)?.into_iter()
+                    .filter(|job| job.location.is_none() || job.work_mode.is_none())
+                    .collect(),
+            };
+            let jobs: Vec<models::Job> = jobs.into_iter().filter(|job| job.raw_text.is_some()).collect();
+            if jobs.is_empty() {
+                println!("No jobs to locate (they need a description: hunt fetch).");
+                return Ok(());
+            }
+            let provider = if no_ai {
+                None
+            } else {
+                Some(cached_provider(&db, &ai::resolve_model(&model)?, "locate", false)?)
+            };
+
+            let (mut placed, mut asked) = (0, 0);
+            for job in &jobs {
+                let text = job.raw_text.as_deref().unwrap_or_default();
+                let (text_location, text_mode) = location::from_description(text);
+                let mut place = text_location.or_else(|| job.location.clone());
+                let mut mode = text_mode.map(String::from)
+                    .or_else(|| job.work_mode.clone())
+                    .or_else(|| place.as_deref().and_then(location::work_mode_of_location).map(String::from));
+                if (place.is_none() || mode.is_none()) && let Some(provider) = &provider {
+                    asked += 1;
+                    match ai::extract_location(provider.as_ref(), text) {
+                        Ok(found) => {
+                            place = place.or(found.location);
+                            mode = mode.or(found.work_mode.map(String::from));
+                        }
+                        Err(e) => warn!("Job #{}: {}", job.id, e),
+                    }
+                }
+                db.set_job_location(job.id, place.as_deref(), mode.as_deref())?;
+                let located = models::Job { location: place, work_mode: mode, ..job.clone() };
+                if located.location.is_some() || located.work_mode.is_some() {
+                    placed += 1;
+                }
+                println!("#{:<5} {:<40} {}", job.id, truncate(&job.title, 38), location::label(&located));
+            }
+            println!("\n✓ Placed {} of {} job(s) ({} asked the AI)", placed, jobs.len(), asked);
+        }
+
+        Commands::Analyze { job_id, model, no_cache, include_blocked, no_stream } => {
+            let model = config::load()?.model(model);
             db.ensure_initialized()?;
             let job = db.get_job(job_id)?
-                .ok_or_else(|| anyhow!("Job #{} not found", job_id))?;
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+            guard_blocked_employer(&db, &job, include_blocked)?;
 
             let job_text = job.raw_text
                 .as_ref()
-                .ok_or_else(|| anyhow!("Job #{} has no raw text to analyze", job_id))?;
+                .ok_or_else(|| HuntError::Validation(format!("Job #{} has no raw text to analyze", job_id)))?;
 
             let spec = ai::resolve_model(&model)?;
-            let provider = ai::create_provider(&spec)?;
+            let provider = cached_provider(&db, &spec, "analyze", no_cache)?;
 
             println!("Analyzing job posting #{}: {} (model: {})...\n", job_id, job.title, spec.short_name);
 
-            let analysis = ai::analyze_job(provider.as_ref(), job_text)?;
+            if no_stream {
+                let analysis = ai::analyze_job(provider.as_ref(), job_text, None)?;
+                println!("=== AI Analysis ===\n");
+                println!("{}", analysis);
+            } else {
+                println!("=== AI Analysis ===\n");
+                ai::analyze_job(provider.as_ref(), job_text, Some(&mut print_stream))?;
+                println!();
+            }
+        }
+
+        Commands::Summarize { job_id, model, no_cache, include_blocked } => {
+            let model = config::load()?.model(model);
+            db.ensure_initialized()?;
+            let job = db.get_job(job_id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+            guard_blocked_employer(&db, &job, include_blocked)?;
+
+            let job_text = job.raw_text
+                .as_ref()
+                .ok_or_else(|| HuntError::Validation(format!("Job #{} has no raw text to summarize", job_id)))?;
+
+            let spec = ai::resolve_model(&model)?;
+            let provider = cached_provider(&db, &spec, "summarize", no_cache)?;
+
+            println!("Summarizing job #{}: {} (model: {})...\n", job_id, job.title, spec.short_name);
+            let summary = ai::summarize_job(provider.as_ref(), job_text, &job.title)?;
+            db.save_job_summary(job_id, &spec.short_name, &summary)?;
+            for (label, text) in summary.labeled() {
+                println!("  • {}: {}", label, text);
+            }
+        }
+
+        Commands::Flags { job_id: None, .. } => {
+            db.ensure_initialized()?;
+            let jobs: Vec<models::Job> = db.list_jobs(None, None)?.into_iter().filter(|job| job.raw_text.is_some()).collect();
+            let mut flagged = 0;
+            for job in &jobs {
+                let found = db.detect_job_flags(job.id)?;
+                if !found.is_empty() {
+                    flagged += 1;
+                    let names: Vec<&str> = found.iter().map(|f| f.name.as_str()).collect();
+                    println!("#{:<5} {:<40} {}", job.id, truncate(&job.title, 38), names.join(", "));
+                }
+            }
+            println!("\n✓ {} of {} job(s) have red flags", flagged, jobs.len());
+        }
+
+        Commands::Flags { job_id: Some(job_id), all: _, model, no_ai, no_cache, include_blocked } => {
+            let model = config::load()?.model(model);
+            db.ensure_initialized()?;
+            let job = db.get_job(job_id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+            guard_blocked_employer(&db, &job, include_blocked)?;
+            let job_text = job.raw_text
+                .as_ref()
+                .ok_or_else(|| HuntError::Validation(format!("Job #{} has no raw text to check (hunt fetch {})", job_id, job_id)))?;
+
+            db.detect_job_flags(job_id)?;
+            if !no_ai {
+                let spec = ai::resolve_model(&model)?;
+                let provider = cached_provider(&db, &spec, "flags", no_cache)?;
+                println!("Checking job #{}: {} for red flags (model: {})...\n", job_id, job.title, spec.short_name);
+                let found = ai::red_flags(provider.as_ref(), &job.title, job_text)?;
+                db.set_job_flags(job_id, &spec.short_name, &found)?;
+            }
+
+            let job_flags = db.get_job_flags(job_id)?;
+            if job_flags.is_empty() {
+                println!("No red flags in job #{}: {}", job_id, job.title);
+            } else {
+                print_job_flags(&job_flags);
+            }
+        }
 
-            println!("=== AI Analysis ===\n");
-            println!("{}", analysis);
+        Commands::Keywords { command: Some(command), .. } => {
+            db.ensure_initialized()?;
+            match command {
+                KeywordsCommands::Unknown { keywords } => {
+                    let added = db.add_review_cards(&keywords)?;
+                    println!("✓ Marked {} new keyword(s) unknown. Run 'hunt review' to study them.", added);
+                }
+                KeywordsCommands::Known { keywords } => {
+                    let removed = db.remove_review_cards(&keywords)?;
+                    println!("✓ Removed {} keyword(s) from review", removed);
+                }
+            }
         }
 
-        Commands::Keywords { job_id, model, search, show, all, force } => {
+        Commands::Keywords {
+            command: None,
+            job_id,
+            model,
+            models,
+            compare,
+            search,
+            show,
+            all,
+            report,
+            top,
+            force,
+            budget,
+            concurrency,
+            no_cache,
+            include_blocked,
+        } => {
+            let model = config::load()?.model(model);
             db.ensure_initialized()?;
 
-            if let Some(query) = search {
+            if report {
+                print_keyword_report(&db, top)?;
+            } else if let Some(query) = search {
                 // Search mode: find keyword across stored job_keywords
                 let results = db.search_job_keywords(&query)?;
                 if results.is_empty() {
@@ -1842,8 +5971,10 @@ fn main() -> Result<()> {
                     println!("\nTotal: {} matches", results.len());
                 }
             } else if all {
-                // Batch mode: extract keywords from all jobs needing them
-                let jobs = db.get_jobs_needing_keywords(force)?;
+                // Batch mode: extract keywords from jobs needing them, best-ranked first
+                let (jobs, blocked) = db.get_keyword_backfill_queue(force, budget, include_blocked)?;
+                let jobs: Vec<models::Job> = jobs.into_iter().filter(|j| j.raw_text.is_some()).collect();
+                report_blocked_skips(blocked);
 
                 if jobs.is_empty() {
                     if force {
@@ -1855,54 +5986,57 @@ fn main() -> Result<()> {
                 }
 
                 let spec = ai::resolve_model(&model)?;
-                let provider = ai::create_provider(&spec)?;
+                let provider = cached_provider(&db, &spec, "keywords", no_cache)?;
 
                 let total = jobs.len();
+                let budget_note = match budget {
+                    Some(n) => format!(", budget: {}", n),
+                    None => String::new(),
+                };
                 if force {
-                    println!("Extracting keywords from {} jobs (--force: re-extracting all, model: {})\n",
-                             total, spec.short_name);
+                    println!("Extracting keywords from {} jobs (--force: re-extracting all, model: {}{})\n",
+                             total, spec.short_name, budget_note);
                 } else {
-                    println!("Extracting keywords from {} jobs without keywords (model: {})\n",
-                             total, spec.short_name);
+                    println!("Extracting keywords from {} jobs without keywords (model: {}{})\n",
+                             total, spec.short_name, budget_note);
                 }
 
                 let mut success_count = 0;
                 let mut fail_count = 0;
-
-                for (i, job) in jobs.iter().enumerate() {
-                    let job_num = i + 1;
-                    let employer = job.employer_name.as_deref().unwrap_or("?");
-                    print!("[{}/{}] #{} {} at {} ... ",
-                           job_num, total, job.id,
-                           truncate(&job.title, 40), truncate(employer, 25));
-
-                    let job_text = match &job.raw_text {
-                        Some(text) => text,
-                        None => {
-                            println!("SKIP (no text)");
-                            continue;
-                        }
-                    };
-
-                    match ai::extract_domain_keywords(provider.as_ref(), job_text) {
-                        Ok(domain_kw) => {
-                            db.add_job_keywords(job.id, &domain_kw.tech, "tech", &spec.short_name)?;
-                            db.add_job_keywords(job.id, &domain_kw.discipline, "discipline", &spec.short_name)?;
-                            db.add_job_keywords(job.id, &domain_kw.cloud, "cloud", &spec.short_name)?;
-                            db.add_job_keywords(job.id, &domain_kw.soft_skill, "soft_skill", &spec.short_name)?;
-                            if !domain_kw.profile.is_empty() {
-                                db.save_keyword_profile(job.id, &spec.short_name, &domain_kw.profile)?;
+                let mut db_error = None;
+
+                ai::run_batch(
+                    &jobs,
+                    concurrency,
+                    spec.provider.min_request_interval(),
+                    |job| ai::extract_domain_keywords(provider.as_ref(), job.raw_text.as_deref().unwrap_or_default()),
+                    |i, job, result| {
+                        let employer = job.employer_name.as_deref().unwrap_or("?");
+                        print!("[{}/{}] #{} {} at {} ... ",
+                               i + 1, total, job.id,
+                               truncate(&job.title, 40), truncate(employer, 25));
+                        match result {
+                            Ok(domain_kw) => {
+                                match store_domain_keywords(&db, job.id, &spec.short_name, &domain_kw) {
+                                    Ok(kw_count) => {
+                                        println!("{} keywords", kw_count);
+                                        success_count += 1;
+                                    }
+                                    Err(e) => {
+                                        println!("DB ERROR: {}", e);
+                                        db_error.get_or_insert(e);
+                                    }
+                                }
+                            }
+                            Err(e) => {
+                                println!("FAILED: {}", e);
+                                fail_count += 1;
                             }
-                            let kw_count = domain_kw.tech.len() + domain_kw.discipline.len()
-                                + domain_kw.cloud.len() + domain_kw.soft_skill.len();
-                            println!("{} keywords", kw_count);
-                            success_count += 1;
-                        }
-                        Err(e) => {
-                            println!("FAILED: {}", e);
-                            fail_count += 1;
                         }
-                    }
+                    },
+                );
+                if let Some(e) = db_error {
+                    return Err(e);
                 }
 
                 println!("\nDone: {} succeeded, {} failed out of {} jobs",
@@ -1911,7 +6045,7 @@ fn main() -> Result<()> {
                 // Show stored keywords without re-running AI
                 let job_id = job_id.unwrap();
                 let job = db.get_job(job_id)?
-                    .ok_or_else(|| anyhow!("Job #{} not found", job_id))?;
+                    .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
 
                 let source_model = db.get_latest_keyword_model(job_id)?;
                 let source_model = match &source_model {
@@ -1937,18 +6071,66 @@ fn main() -> Result<()> {
                     }
                     println!();
                 }
+            } else if let Some(models) = models {
+                // Multi-model mode: extract with each model, stored side by side by source_model
+                let job_id = job_id.unwrap();
+                let job = db.get_job(job_id)?
+                    .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+                let job_text = job.raw_text
+                    .as_ref()
+                    .ok_or_else(|| HuntError::Validation(format!("Job #{} has no raw text to extract keywords from", job_id)))?;
+                guard_blocked_employer(&db, &job, include_blocked)?;
+
+                let specs = models
+                    .split(',')
+                    .map(str::trim)
+                    .filter(|m| !m.is_empty())
+                    .map(ai::resolve_model)
+                    .collect::<Result<Vec<_>>>()?;
+                if specs.is_empty() {
+                    return Err(HuntError::Validation("--models needs at least one model name".to_string()).into());
+                }
+
+                println!("Extracting keywords from job #{}: {} with {} models...\n", job_id, job.title, specs.len());
+                let mut results: Vec<(String, Vec<models::JobKeyword>)> = Vec::new();
+                for spec in &specs {
+                    print!("  {} ... ", spec.short_name);
+                    let provider = cached_provider(&db, spec, "keywords", no_cache)?;
+                    match ai::extract_domain_keywords(provider.as_ref(), job_text) {
+                        Ok(domain_kw) => {
+                            let count = store_domain_keywords(&db, job_id, &spec.short_name, &domain_kw)?;
+                            println!("{} keywords", count);
+                            results.push((spec.short_name.clone(), db.get_job_keywords(job_id, Some(&spec.short_name))?));
+                        }
+                        Err(e) => println!("FAILED: {}", e),
+                    }
+                }
+                println!();
+                if results.is_empty() {
+                    return Err(HuntError::AiProvider("Keyword extraction failed with every model".to_string()).into());
+                }
+
+                if compare {
+                    print_keyword_comparison(&results);
+                } else {
+                    for (model, keywords) in &results {
+                        println!("Keywords for job #{}: {} (model: {})\n", job_id, job.title, model);
+                        display_domain_keywords(keywords);
+                    }
+                }
             } else {
                 // Extract mode: call AI and store results
                 let job_id = job_id.unwrap();
                 let job = db.get_job(job_id)?
-                    .ok_or_else(|| anyhow!("Job #{} not found", job_id))?;
+                    .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
 
                 let job_text = job.raw_text
                     .as_ref()
-                    .ok_or_else(|| anyhow!("Job #{} has no raw text to extract keywords from", job_id))?;
+                    .ok_or_else(|| HuntError::Validation(format!("Job #{} has no raw text to extract keywords from", job_id)))?;
+                guard_blocked_employer(&db, &job, include_blocked)?;
 
                 let spec = ai::resolve_model(&model)?;
-                let provider = ai::create_provider(&spec)?;
+                let provider = cached_provider(&db, &spec, "keywords", no_cache)?;
 
                 println!("Extracting keywords from job #{}: {} (model: {})...\n",
                          job_id, job.title, spec.short_name);
@@ -1956,14 +6138,7 @@ fn main() -> Result<()> {
                 let domain_kw = ai::extract_domain_keywords(provider.as_ref(), job_text)?;
 
                 // Store in database
-                db.add_job_keywords(job_id, &domain_kw.tech, "tech", &spec.short_name)?;
-                db.add_job_keywords(job_id, &domain_kw.discipline, "discipline", &spec.short_name)?;
-                db.add_job_keywords(job_id, &domain_kw.cloud, "cloud", &spec.short_name)?;
-                db.add_job_keywords(job_id, &domain_kw.soft_skill, "soft_skill", &spec.short_name)?;
-
-                if !domain_kw.profile.is_empty() {
-                    db.save_keyword_profile(job_id, &spec.short_name, &domain_kw.profile)?;
-                }
+                let total = store_domain_keywords(&db, job_id, &spec.short_name, &domain_kw)?;
 
                 // Display results — show only what we just stored
                 let all_keywords = db.get_job_keywords(job_id, Some(&spec.short_name))?;
@@ -1980,13 +6155,12 @@ fn main() -> Result<()> {
                     println!();
                 }
 
-                let total = domain_kw.tech.len() + domain_kw.discipline.len()
-                    + domain_kw.cloud.len() + domain_kw.soft_skill.len();
                 println!("Total: {} keywords stored (model: {})", total, spec.short_name);
             }
         }
 
-        Commands::Fit { job_id, resume, model, all, force } => {
+        Commands::Fit { job_id, resume, model, all, force, concurrency, no_cache, include_blocked } => {
+            let model = config::load()?.model(model);
             db.ensure_initialized()?;
 
             let base_resume = if let Ok(id) = resume.parse::<i64>() {
@@ -1994,17 +6168,20 @@ fn main() -> Result<()> {
             } else {
                 db.get_base_resume_by_name(&resume)?
             }
-            .ok_or_else(|| anyhow!("Resume '{}' not found", resume))?;
+            .ok_or_else(|| HuntError::NotFound(format!("Resume '{}' not found", resume)))?;
 
             let spec = ai::resolve_model(&model)?;
-            let provider = ai::create_provider(&spec)?;
+            let provider = cached_provider(&db, &spec, "fit", no_cache)?;
 
             if all {
-                // Run fit analysis on all non-closed jobs with descriptions
-                let jobs = db.list_jobs(None, None)?;
-                let candidates: Vec<&models::Job> = jobs.iter()
-                    .filter(|j| j.status != "closed" && j.status != "rejected" && j.raw_text.is_some())
+                // Run fit analysis on all active (non-terminal) jobs with descriptions
+                let jobs: Vec<models::Job> = db.list_active_jobs()?
+                    .into_iter()
+                    .filter(|j| j.raw_text.is_some())
                     .collect();
+                let (jobs, blocked) = db.filter_blocked_employers(jobs, include_blocked)?;
+                report_blocked_skips(blocked);
+                let candidates: Vec<&models::Job> = jobs.iter().collect();
 
                 let total = candidates.len();
                 if total == 0 {
@@ -2012,55 +6189,72 @@ fn main() -> Result<()> {
                     return Ok(());
                 }
 
+                // Skip jobs already analyzed (unless --force)
+                let mut to_analyze = Vec::new();
+                for job in candidates {
+                    if force || !db.has_fit_analysis(job.id, base_resume.id, &spec.short_name)? {
+                        to_analyze.push(job);
+                    }
+                }
+                let skipped = total - to_analyze.len();
+
                 let mut analyzed = 0;
-                let mut skipped = 0;
                 let mut errors = 0;
-
-                for (i, job) in candidates.iter().enumerate() {
-                    // Skip if already analyzed (unless --force)
-                    if !force && db.has_fit_analysis(job.id, base_resume.id, &spec.short_name)? {
-                        skipped += 1;
-                        continue;
-                    }
-
-                    let job_text = job.raw_text.as_ref().unwrap();
-                    let title_short: String = job.title.chars().take(40).collect();
-                    print!("[{}/{}] Analyzing job #{}: {}...", i + 1, total, job.id, title_short);
-                    use std::io::Write;
-                    let _ = std::io::stdout().flush();
-
-                    match ai::analyze_fit(provider.as_ref(), &base_resume.content, job_text, &job.title) {
-                        Ok(fit) => {
-                            db.save_fit_analysis(
-                                job.id,
-                                base_resume.id,
-                                &spec.short_name,
-                                fit.fit_score,
-                                &fit.strong_matches,
-                                &fit.gaps,
-                                &fit.stretch_areas,
-                                &fit.narrative,
-                            )?;
-                            println!("  score: {:.0}", fit.fit_score);
-                            analyzed += 1;
-                        }
-                        Err(e) => {
-                            println!("  ERROR: {}", e);
-                            errors += 1;
+                let mut db_error = None;
+
+                ai::run_batch(
+                    &to_analyze,
+                    concurrency,
+                    spec.provider.min_request_interval(),
+                    |job| ai::analyze_fit(provider.as_ref(), &base_resume.content, job.raw_text.as_deref().unwrap_or_default(), &job.title),
+                    |i, job, result| {
+                        let title_short: String = job.title.chars().take(40).collect();
+                        print!("[{}/{}] Analyzing job #{}: {}...", i + 1, to_analyze.len(), job.id, title_short);
+                        match result {
+                            Ok(fit) => {
+                                let saved = db.save_fit_analysis(
+                                    job.id,
+                                    base_resume.id,
+                                    &spec.short_name,
+                                    fit.fit_score,
+                                    &fit.strong_matches,
+                                    &fit.gaps,
+                                    &fit.stretch_areas,
+                                    &fit.narrative,
+                                );
+                                match saved {
+                                    Ok(_) => {
+                                        println!("  score: {:.0}", fit.fit_score);
+                                        analyzed += 1;
+                                    }
+                                    Err(e) => {
+                                        println!("  DB ERROR: {}", e);
+                                        db_error.get_or_insert(e);
+                                    }
+                                }
+                            }
+                            Err(e) => {
+                                println!("  ERROR: {}", e);
+                                errors += 1;
+                            }
                         }
-                    }
+                    },
+                );
+                if let Some(e) = db_error {
+                    return Err(e);
                 }
 
                 println!("\nDone: {} analyzed, {} skipped (existing), {} errors", analyzed, skipped, errors);
             } else {
                 // Single job fit analysis
-                let job_id = job_id.ok_or_else(|| anyhow!("Job ID required (or use --all)"))?;
+                let job_id = job_id.ok_or_else(|| HuntError::Validation("Job ID required (or use --all)".to_string()))?;
                 let job = db.get_job(job_id)?
-                    .ok_or_else(|| anyhow!("Job #{} not found", job_id))?;
+                    .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
 
                 let job_text = job.raw_text
                     .as_ref()
-                    .ok_or_else(|| anyhow!("Job #{} has no raw text for fit analysis", job_id))?;
+                    .ok_or_else(|| HuntError::Validation(format!("Job #{} has no raw text for fit analysis", job_id)))?;
+                guard_blocked_employer(&db, &job, include_blocked)?;
 
                 println!("Analyzing fit for job #{}: {} (model: {})...\n", job_id, job.title, spec.short_name);
 
@@ -2089,168 +6283,502 @@ fn main() -> Result<()> {
                     println!();
                 }
 
-                if !fit.gaps.is_empty() {
-                    println!("Gaps:");
-                    for item in &fit.gaps {
-                        println!("  - {}", item);
-                    }
-                    println!();
-                }
+                if !fit.gaps.is_empty() {
+                    println!("Gaps:");
+                    for item in &fit.gaps {
+                        println!("  - {}", item);
+                    }
+                    println!();
+                }
+
+                if !fit.stretch_areas.is_empty() {
+                    println!("Stretch Areas:");
+                    for item in &fit.stretch_areas {
+                        println!("  ~ {}", item);
+                    }
+                    println!();
+                }
+
+                if !fit.narrative.is_empty() {
+                    println!("Narrative:\n{}", fit.narrative);
+                }
+
+                println!("\n(Stored in DB, model: {})", spec.short_name);
+            }
+        }
+
+        Commands::Prep { job_id, show: true, .. } => {
+            db.ensure_initialized()?;
+            db.get_job(job_id)?.ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+            let notes = db.list_prep_notes(job_id)?;
+            if notes.is_empty() {
+                println!("No prep questions for job #{} yet. Run: hunt prep {} --resume <name>", job_id, job_id);
+            } else {
+                print_prep_notes(&notes);
+            }
+        }
+
+        Commands::Prep { job_id, resume, show: false, model, no_cache, include_blocked } => {
+            let model = config::load()?.model(model);
+            db.ensure_initialized()?;
+            let resume = resume.unwrap_or_default();
+            let base_resume = if let Ok(id) = resume.parse::<i64>() {
+                db.get_base_resume(id)?
+            } else {
+                db.get_base_resume_by_name(&resume)?
+            }
+            .ok_or_else(|| HuntError::NotFound(format!("Resume '{}' not found", resume)))?;
+            let job = db.get_job(job_id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+            guard_blocked_employer(&db, &job, include_blocked)?;
+
+            let job_text = job.raw_text
+                .as_ref()
+                .ok_or_else(|| HuntError::Validation(format!("Job #{} has no raw text to prepare from", job_id)))?;
+            let keywords = match db.get_latest_keyword_model(job_id)? {
+                Some(keyword_model) => db.get_job_keywords(job_id, Some(&keyword_model))?,
+                None => Vec::new(),
+            };
+            let gaps = db.get_resume_gaps(job_id, base_resume.id)?;
+
+            let spec = ai::resolve_model(&model)?;
+            let provider = cached_provider(&db, &spec, "prep", no_cache)?;
+
+            println!("Preparing interview questions for job #{}: {} (resume: {}, model: {})...", job_id, job.title, base_resume.name, spec.short_name);
+            if keywords.is_empty() {
+                println!("  (no keywords yet; `hunt keywords {}` gives the AI weighted skills to aim at)", job_id);
+            }
+            if gaps.is_empty() {
+                println!("  (no fit analysis; `hunt fit {} --resume {}` gives it your gaps)", job_id, base_resume.name);
+            }
+            println!();
+
+            let questions = ai::generate_prep_questions(provider.as_ref(), &base_resume.content, job_text, &job.title, &keywords, &gaps)?;
+            db.save_prep_notes(job_id, base_resume.id, &spec.short_name, &questions)?;
+            print_prep_notes(&db.list_prep_notes(job_id)?);
+        }
+
+        Commands::Questions { job_id, model, no_cache, include_blocked } => {
+            let model = config::load()?.model(model);
+            db.ensure_initialized()?;
+            let job = db.get_job(job_id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+            guard_blocked_employer(&db, &job, include_blocked)?;
+            let employer_name = job.employer_name.as_deref()
+                .ok_or_else(|| HuntError::Validation(format!("Job #{} has no employer to research", job_id)))?;
+            let employer = db.get_employer_by_name(employer_name)?
+                .ok_or_else(|| HuntError::NotFound(format!("Employer '{}' not found", employer_name)))?;
+            let reviews = db.list_glassdoor_reviews(Some(employer.id))?;
+            let brief = interview::employer_brief(&employer, &reviews);
+            if brief.is_empty() && job.raw_text.is_none() {
+                return Err(HuntError::Validation(format!(
+                    "Nothing to base questions on: job #{} has no description and {} hasn't been researched \
+                     (hunt fetch {}, hunt employer research/evil/ownership \"{}\", hunt glassdoor fetch \"{}\")",
+                    job_id, employer.name, job_id, employer.name, employer.name
+                )).into());
+            }
+
+            let spec = ai::resolve_model(&model)?;
+            let provider = cached_provider(&db, &spec, "questions", no_cache)?;
 
-                if !fit.stretch_areas.is_empty() {
-                    println!("Stretch Areas:");
-                    for item in &fit.stretch_areas {
-                        println!("  ~ {}", item);
+            println!("Questions for the interviewer at {} (job #{}: {}, model: {})...", employer.name, job_id, job.title, spec.short_name);
+            if brief.is_empty() {
+                println!("  (no research on {} yet; `hunt employer research \"{}\"` and `hunt glassdoor fetch` make these sharper)", employer.name, employer.name);
+            }
+            println!();
+
+            let questions = ai::interviewer_questions(provider.as_ref(), job.raw_text.as_deref(), &job.title, &employer.name, &brief)?;
+            for (i, q) in questions.iter().enumerate() {
+                println!("  {}. {}", i + 1, q.question);
+                if let Some(basis) = &q.basis {
+                    for (j, line) in textwrap::fill(basis, 68).lines().enumerate() {
+                        println!("     {} {}", if j == 0 { "↳" } else { " " }, line);
                     }
-                    println!();
                 }
+            }
+        }
 
-                if !fit.narrative.is_empty() {
-                    println!("Narrative:\n{}", fit.narrative);
+        Commands::Negotiate { job_id, show: true, .. } => {
+            db.ensure_initialized()?;
+            db.get_job(job_id)?.ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+            match db.get_negotiation_brief(job_id)? {
+                Some(brief) => print_negotiation_brief(&brief),
+                None => println!("No negotiation brief for job #{} yet. Run: hunt negotiate {}", job_id, job_id),
+            }
+        }
+
+        Commands::Negotiate { job_id, show: false, model, no_cache, include_blocked } => {
+            let model = config::load()?.model(model);
+            db.ensure_initialized()?;
+            let job = db.get_job(job_id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+            guard_blocked_employer(&db, &job, include_blocked)?;
+            let employer = match job.employer_name.as_deref() {
+                Some(name) => db.get_employer_by_name(name)?,
+                None => None,
+            };
+            let (bands, brief) = match &employer {
+                Some(employer) => {
+                    let reviews = db.list_glassdoor_reviews(Some(employer.id))?;
+                    (db.list_employer_comp(employer.id)?, interview::employer_brief(employer, &reviews))
                 }
+                None => (Vec::new(), String::new()),
+            };
+            let fit = db.get_best_fit_analysis(job_id)?;
+            let facts = interview::negotiation_facts(&job, &bands, fit.as_ref(), &brief);
+            let employer_name = job.employer_name.as_deref().unwrap_or("(unknown)");
 
-                println!("\n(Stored in DB, model: {})", spec.short_name);
+            let spec = ai::resolve_model(&model)?;
+            let provider = cached_provider(&db, &spec, "negotiate", no_cache)?;
+
+            println!("Writing a negotiation brief for job #{}: {} (model: {})...", job_id, job.title, spec.short_name);
+            if job.pay_min.is_none() && job.pay_max.is_none() && bands.is_empty() {
+                println!("  (no posted pay or comp bands; `hunt employer comp \"{}\"` gives the anchors something to stand on)", employer_name);
+            }
+            if fit.is_none() {
+                println!("  (no fit analysis; `hunt fit {} --resume <name>` adds your leverage)", job_id);
             }
+            println!();
+
+            let (anchors, text) = ai::negotiation_brief(provider.as_ref(), &job.title, employer_name, &facts)?;
+            db.save_negotiation_brief(job_id, &spec.short_name, &anchors, &text)?;
+            let saved = db.get_negotiation_brief(job_id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Negotiation brief for job #{} not found", job_id)))?;
+            print_negotiation_brief(&saved);
         }
 
-        Commands::Browse { status, employer } => {
+        Commands::Browse { status, employer, model, resume, min_pay, max_pay } => {
+            let settings = config::load()?;
+            let model = settings.model(model);
             db.ensure_initialized()?;
-            tui::run_browse(&db, status.as_deref(), employer.as_deref())?;
+            ai::resolve_model(&model)?;
+            let resume_id = match resume {
+                Some(resume) => {
+                    let base = if let Ok(id) = resume.parse::<i64>() {
+                        db.get_base_resume(id)?
+                    } else {
+                        db.get_base_resume_by_name(&resume)?
+                    };
+                    Some(base.ok_or_else(|| HuntError::NotFound(format!("Resume '{}' not found", resume)))?.id)
+                }
+                None => match db.list_base_resumes()?.as_slice() {
+                    [only] => Some(only.id),
+                    _ => None,
+                },
+            };
+            let config = tui::ActionConfig { db_path: db.path().clone(), model, resume_id };
+            let load = models::SavedFilter { status, employer, max_pay, ..Default::default() };
+            let floor = match min_pay {
+                Some(floor) => tui::PayFloor { amount: Some(floor), on: true },
+                None => tui::PayFloor { amount: settings.min_pay, on: false },
+            };
+            tui::run_browse(&db, &load, floor, &config)?;
+        }
+
+        Commands::Auth { command: AuthCommands::Linkedin { driver } } => {
+            let driver = resolve_driver(driver.as_deref().map(browser::Driver::parse).transpose()?)?;
+            let rt = tokio::runtime::Runtime::new()
+                .context("Failed to create tokio runtime")?;
+            let fetcher = rt.block_on(browser::JobFetcher::for_login(driver))?;
+            let signed_in = rt.block_on(async {
+                if fetcher.linkedin_signed_in().await? {
+                    println!("Already signed in to LinkedIn.");
+                    return Ok(true);
+                }
+                fetcher.open_linkedin_login().await?;
+                println!("Sign in to LinkedIn in the browser window, then press Enter here...");
+                let mut line = String::new();
+                std::io::stdin().read_line(&mut line)?;
+                fetcher.linkedin_signed_in().await
+            });
+            // Quit either way so the browser flushes its cookies to the profile
+            rt.block_on(fetcher.quit())?;
+            if !signed_in? {
+                return Err(HuntError::Validation("Still not signed in to LinkedIn; run 'hunt auth linkedin' again".to_string()).into());
+            }
+            println!("✓ LinkedIn session saved in {}", driver.profile_dir()?.display());
+            println!("  Fetches with --driver {} will reuse it, headless or not.", driver.name());
         }
 
         Commands::Check => {
             run_dependency_check();
         }
 
-        Commands::Refresh { username, password_file, days, model, no_headless, delay } => {
-            require_browser_deps()?;
+        Commands::Refresh { mut username, mut password_file, accounts, oauth, days, model, no_headless, delay, browser_only, driver, include_blocked, ignore_robots, notify, notify_score, resume, skip_email, skip_fetch, skip_keywords, fit, fit_resume, only } => {
+            let settings = config::load()?;
+            let model = settings.model(model);
             let headless = !no_headless;
+            let driver = driver.as_deref().map(browser::Driver::parse).transpose()?;
             db.ensure_initialized()?;
+            let last_job_id = db.max_job_id()?;
+            let mut failures = Vec::new();
 
-            // Step 1: Email ingestion
-            println!("═══ Step 1: Fetching job alerts from email ═══\n");
-            let password_path = if password_file.starts_with("~/") {
-                let home = std::env::var("HOME").unwrap_or_default();
-                PathBuf::from(format!("{}/{}", home, &password_file[2..]))
+            let skip: Vec<Stage> = [(skip_email, Stage::Email), (skip_fetch, Stage::Fetch), (skip_keywords, Stage::Keywords)]
+                .into_iter()
+                .filter_map(|(skipped, stage)| skipped.then_some(stage))
+                .collect();
+            let stages = pipeline::select(&only, &skip, fit);
+            // Resolve the fit resume up front rather than fail after the slow stages
+            let fit_resume = if stages.contains(&Stage::Fit) {
+                let base = match &fit_resume {
+                    Some(resume) => if let Ok(id) = resume.parse::<i64>() {
+                        db.get_base_resume(id)?
+                    } else {
+                        db.get_base_resume_by_name(resume)?
+                    }
+                    .ok_or_else(|| HuntError::NotFound(format!("Resume '{}' not found", resume)))?,
+                    None => match db.list_base_resumes()?.as_slice() {
+                        [only] => only.clone(),
+                        _ => return Err(HuntError::Validation(
+                            "The fit stage needs --fit-resume when there isn't exactly one base resume".to_string()
+                        ).into()),
+                    },
+                };
+                Some(base)
             } else {
-                PathBuf::from(&password_file)
+                None
             };
 
-            println!("Connecting to Gmail as {}...", username);
-            match EmailConfig::from_gmail_password_file(&username, &password_path) {
-                Ok(config) => {
-                    let ingester = EmailIngester::new(config);
-                    println!("Searching for job alerts from the last {} days...", days);
-                    match ingester.fetch_job_alerts(&db, days, false, false) {
-                        Ok(stats) => {
-                            println!("  Emails processed: {}", stats.emails_found);
-                            println!("  Jobs added:       {}", stats.jobs_added);
-                            println!("  Duplicates:       {}", stats.duplicates);
-                            if stats.errors > 0 {
-                                println!("  Errors:           {}", stats.errors);
-                            }
-                        }
-                        Err(e) => println!("  Email fetch failed: {}", e),
+            let interrupted = if resume { db.interrupted_pipeline_run()? } else { None };
+            let run = match interrupted {
+                Some(run) => {
+                    info!("Resuming refresh #{} started {}", run.id, timestamps::local(&run.started_at));
+                    run.id
+                }
+                None => {
+                    if resume {
+                        info!("No interrupted refresh to resume; starting a new one");
                     }
+                    db.start_pipeline_run()?
                 }
-                Err(e) => println!("  Skipping email: {}", e),
-            }
-
-            // Step 2: Fetch job descriptions
-            println!("\n═══ Step 2: Fetching job descriptions ═══\n");
-            let jobs_to_fetch = db.get_jobs_to_fetch(None, false, false)?;
-            if jobs_to_fetch.is_empty() {
-                println!("All jobs already have descriptions.");
-            } else {
-                println!("Fetching descriptions for {} unfetched jobs...\n", jobs_to_fetch.len());
-                let mut success = 0;
-                let mut fail = 0;
-
-                for (i, job) in jobs_to_fetch.iter().enumerate() {
-                    let employer = job.employer_name.as_deref().unwrap_or("?");
-                    print!("[{}/{}] #{} {} at {} ... ",
-                           i + 1, jobs_to_fetch.len(), job.id,
-                           truncate(&job.title, 35), truncate(employer, 20));
+            };
 
-                    if let Some(url) = &job.url {
-                        match fetch_job_description(url, headless) {
-                            Ok(desc) => {
-                                let _ = db.update_job_description(job.id, &desc.text, desc.pay_min, desc.pay_max);
-                                if let Some(ref emp_name) = desc.employer_name {
-                                    let _ = db.update_job_employer(job.id, emp_name);
+            for (step, stage) in stages.iter().enumerate() {
+                info!("═══ Step {}: {} ═══", step + 1, stage.title());
+                if db.pipeline_stage_done(run, stage.name())? {
+                    info!("Already done in refresh #{}", run);
+                    continue;
+                }
+                match stage {
+                    Stage::Email => {
+                        match email_accounts(username.take(), password_file.take(), &accounts, oauth, &settings.accounts) {
+                            Ok(configs) => {
+                                for config in &configs {
+                                    info!("Connecting to {} as {}...", config.server, config.username);
                                 }
-                                if desc.no_longer_accepting {
-                                    let _ = db.update_job_status(job.id, "closed");
+                                let ingester = EmailIngester::with_accounts(configs);
+                                info!("Searching for job alerts from the last {} days...", days);
+                                let results = ingester.fetch_job_alerts(&db, days, false);
+                                print_ingest_results(&results);
+                                let failed = results.iter().filter(|r| r.error.is_some()).count();
+                                if failed > 0 {
+                                    failures.push(format!("{} email account(s)", failed));
                                 }
-                                println!("{} chars", desc.text.len());
-                                success += 1;
-                            }
-                            Err(e) => {
-                                println!("FAILED: {}", e);
-                                fail += 1;
                             }
+                            Err(e) => warn!("Skipping email: {}", e),
                         }
-                    } else {
-                        println!("no URL");
-                        fail += 1;
                     }
+                    Stage::Fetch => {
+                        let fetched = db.pipeline_stage_jobs(run, stage.name())?;
+                        let jobs_to_fetch: Vec<_> = db.get_jobs_to_fetch(None, false, false)?
+                            .into_iter()
+                            .filter(|job| !fetched.contains(&job.id))
+                            .collect();
+                        let jobs_to_fetch = ratelimit::interleave(jobs_to_fetch, |job| job.url.as_deref());
+                        let mut limiter = RateLimiter::new(&settings.rate_limits);
+                        let mut policy = FetchPolicy::new(&settings.fetch_blocklist, !ignore_robots);
+                        if jobs_to_fetch.is_empty() {
+                            println!("All jobs already have descriptions.");
+                        } else {
+                            info!("Fetching descriptions for {} unfetched jobs...", jobs_to_fetch.len());
+                            let mut success = 0;
+                            let mut fail = 0;
+                            let mut skipped = 0;
+                            let mut fetcher = DescriptionFetcher::new(headless, browser_only, driver);
+
+                            for (i, job) in jobs_to_fetch.iter().enumerate() {
+                                let mut used_browser = false;
+                                let employer = job.employer_name.as_deref().unwrap_or("?");
+                                let label = format!("[{}/{}] #{} {} at {}",
+                                                    i + 1, jobs_to_fetch.len(), job.id,
+                                                    truncate(&job.title, 35), truncate(employer, 20));
+
+                                if let Some(url) = &job.url {
+                                    if let Some(reason) = policy.refusal(&db, url, true)? {
+                                        info!("{} ... skipped: {}", label, reason);
+                                        db.record_pipeline_step(run, stage.name(), Some(job.id), false)?;
+                                        skipped += 1;
+                                        continue;
+                                    }
+                                    wait_for_domain(&limiter, url);
+                                    used_browser = true;
+                                    let fetched = fetcher.fetch(url);
+                                    policy.record(&db, url, fetched.as_ref().err())?;
+                                    match fetched {
+                                        Ok((desc, browser)) => {
+                                            used_browser = browser;
+                                            let _ = db.update_job_description(job.id, &desc.text, desc.pay_min, desc.pay_max);
+                                            if let Some(ref emp_name) = desc.employer_name {
+                                                let _ = db.update_job_employer(job.id, emp_name);
+                                            }
+                                            let _ = db.set_job_easy_apply(job.id, desc.easy_apply);
+                                            if let Some(application) = &desc.application {
+                                                let _ = db.set_job_application(job.id, application);
+                                            }
+                                            if desc.no_longer_accepting {
+                                                let _ = db.update_job_status(job.id, "closed");
+                                            }
+                                            info!("{} ... {} chars", label, desc.text.len());
+                                            db.record_pipeline_step(run, stage.name(), Some(job.id), true)?;
+                                            success += 1;
+                                        }
+                                        Err(e) => {
+                                            warn!("{} ... FAILED: {}", label, e);
+                                            db.record_pipeline_step(run, stage.name(), Some(job.id), false)?;
+                                            fail += 1;
+                                        }
+                                    }
+                                } else {
+                                    warn!("{} ... no URL", label);
+                                    db.record_pipeline_step(run, stage.name(), Some(job.id), false)?;
+                                    fail += 1;
+                                }
 
-                    if i + 1 < jobs_to_fetch.len() {
-                        let wait = add_jitter(delay);
-                        countdown(wait);
+                                if let Some(url) = &job.url {
+                                    record_fetch(&mut limiter, url, delay, used_browser);
+                                }
+                            }
+                            fetcher.finish();
+                            println!("\n  Fetched: {}, Failed: {}, Skipped: {}", success, fail, skipped);
+                            if fail > 0 {
+                                failures.push(format!("{} job fetch(es)", fail));
+                            }
+                        }
                     }
-                }
-                println!("\n  Fetched: {}, Failed: {}", success, fail);
-            }
-
-            // Step 3: Extract keywords
-            println!("\n═══ Step 3: Extracting keywords ═══\n");
-            let jobs_needing = db.get_jobs_needing_keywords(false)?;
-            if jobs_needing.is_empty() {
-                println!("All jobs with descriptions already have keywords.");
-            } else {
-                let spec = ai::resolve_model(&model)?;
-                let provider = ai::create_provider(&spec)?;
-                println!("Extracting keywords from {} jobs (model: {})\n",
-                         jobs_needing.len(), spec.short_name);
-
-                let mut success = 0;
-                let mut fail = 0;
-
-                for (i, job) in jobs_needing.iter().enumerate() {
-                    let employer = job.employer_name.as_deref().unwrap_or("?");
-                    print!("[{}/{}] #{} {} at {} ... ",
-                           i + 1, jobs_needing.len(), job.id,
-                           truncate(&job.title, 35), truncate(employer, 20));
-
-                    if let Some(text) = &job.raw_text {
-                        match ai::extract_domain_keywords(provider.as_ref(), text) {
-                            Ok(kw) => {
-                                let _ = db.add_job_keywords(job.id, &kw.tech, "tech", &spec.short_name);
-                                let _ = db.add_job_keywords(job.id, &kw.discipline, "discipline", &spec.short_name);
-                                let _ = db.add_job_keywords(job.id, &kw.cloud, "cloud", &spec.short_name);
-                                let _ = db.add_job_keywords(job.id, &kw.soft_skill, "soft_skill", &spec.short_name);
-                                if !kw.profile.is_empty() {
-                                    let _ = db.save_keyword_profile(job.id, &spec.short_name, &kw.profile);
+                    Stage::Keywords => {
+                        let (jobs_needing, blocked) = db.get_keyword_backfill_queue(false, None, include_blocked)?;
+                        report_blocked_skips(blocked);
+                        let extracted = db.pipeline_stage_jobs(run, stage.name())?;
+                        let jobs_needing: Vec<_> = jobs_needing.into_iter().filter(|job| !extracted.contains(&job.id)).collect();
+                        if jobs_needing.is_empty() {
+                            println!("All jobs with descriptions already have keywords.");
+                        } else {
+                            let spec = ai::resolve_model(&model)?;
+                            let provider = metered_provider(&db, &spec, "refresh")?;
+                            info!("Extracting keywords from {} jobs (model: {})", jobs_needing.len(), spec.short_name);
+
+                            let mut success = 0;
+                            let mut fail = 0;
+
+                            for (i, job) in jobs_needing.iter().enumerate() {
+                                let employer = job.employer_name.as_deref().unwrap_or("?");
+                                let label = format!("[{}/{}] #{} {} at {}",
+                                                    i + 1, jobs_needing.len(), job.id,
+                                                    truncate(&job.title, 35), truncate(employer, 20));
+
+                                if let Some(text) = &job.raw_text {
+                                    match ai::extract_domain_keywords(provider.as_ref(), text) {
+                                        Ok(kw) => {
+                                            let _ = db.add_job_keywords(job.id, &kw.tech, "tech", &spec.short_name);
+                                            let _ = db.add_job_keywords(job.id, &kw.discipline, "discipline", &spec.short_name);
+                                            let _ = db.add_job_keywords(job.id, &kw.cloud, "cloud", &spec.short_name);
+                                            let _ = db.add_job_keywords(job.id, &kw.soft_skill, "soft_skill", &spec.short_name);
+                                            if !kw.profile.is_empty() {
+                                                let _ = db.save_keyword_profile(job.id, &spec.short_name, &kw.profile);
+                                            }
+                                            let _ = db.detect_job_flags(job.id);
+                                            let count = kw.tech.len() + kw.discipline.len()
+                                                + kw.cloud.len() + kw.soft_skill.len();
+                                            info!("{} ... {} keywords", label, count);
+                                            db.record_pipeline_step(run, stage.name(), Some(job.id), true)?;
+                                            success += 1;
+                                        }
+                                        Err(e) => {
+                                            warn!("{} ... FAILED: {}", label, e);
+                                            db.record_pipeline_step(run, stage.name(), Some(job.id), false)?;
+                                            fail += 1;
+                                        }
+                                    }
+                                } else {
+                                    info!("{} ... no text", label);
                                 }
-                                let count = kw.tech.len() + kw.discipline.len()
-                                    + kw.cloud.len() + kw.soft_skill.len();
-                                println!("{} keywords", count);
-                                success += 1;
                             }
-                            Err(e) => {
-                                println!("FAILED: {}", e);
-                                fail += 1;
+                            println!("\n  Extracted: {}, Failed: {}", success, fail);
+                            if fail > 0 {
+                                failures.push(format!("{} keyword extraction(s)", fail));
+                            }
+                        }
+                    }
+                    Stage::Fit => {
+                        let base_resume = fit_resume.as_ref().expect("resolved when the fit stage is selected");
+                        let spec = ai::resolve_model(&model)?;
+                        let analyzed = db.pipeline_stage_jobs(run, stage.name())?;
+                        let (jobs, blocked) = db.filter_blocked_employers(db.list_active_jobs()?, include_blocked)?;
+                        report_blocked_skips(blocked);
+                        let mut to_analyze = Vec::new();
+                        for job in jobs {
+                            if job.raw_text.is_some() && !analyzed.contains(&job.id)
+                                && !db.has_fit_analysis(job.id, base_resume.id, &spec.short_name)? {
+                                to_analyze.push(job);
+                            }
+                        }
+                        if to_analyze.is_empty() {
+                            println!("All active jobs already have a fit analysis against '{}'.", base_resume.name);
+                        } else {
+                            let provider = metered_provider(&db, &spec, "refresh")?;
+                            info!("Analyzing fit for {} jobs against '{}' (model: {})", to_analyze.len(), base_resume.name, spec.short_name);
+
+                            let mut success = 0;
+                            let mut fail = 0;
+
+                            for (i, job) in to_analyze.iter().enumerate() {
+                                let employer = job.employer_name.as_deref().unwrap_or("?");
+                                let label = format!("[{}/{}] #{} {} at {}",
+                                                    i + 1, to_analyze.len(), job.id,
+                                                    truncate(&job.title, 35), truncate(employer, 20));
+                                let text = job.raw_text.as_deref().unwrap_or_default();
+                                match ai::analyze_fit(provider.as_ref(), &base_resume.content, text, &job.title) {
+                                    Ok(fit) => {
+                                        db.save_fit_analysis(
+                                            job.id,
+                                            base_resume.id,
+                                            &spec.short_name,
+                                            fit.fit_score,
+                                            &fit.strong_matches,
+                                            &fit.gaps,
+                                            &fit.stretch_areas,
+                                            &fit.narrative,
+                                        )?;
+                                        info!("{} ... score {:.0}", label, fit.fit_score);
+                                        db.record_pipeline_step(run, stage.name(), Some(job.id), true)?;
+                                        success += 1;
+                                    }
+                                    Err(e) => {
+                                        warn!("{} ... FAILED: {}", label, e);
+                                        db.record_pipeline_step(run, stage.name(), Some(job.id), false)?;
+                                        fail += 1;
+                                    }
+                                }
+                            }
+                            println!("\n  Analyzed: {}, Failed: {}", success, fail);
+                            if fail > 0 {
+                                failures.push(format!("{} fit analyses", fail));
                             }
                         }
-                    } else {
-                        println!("no text");
                     }
                 }
-                println!("\n  Extracted: {}, Failed: {}", success, fail);
+                db.record_pipeline_step(run, stage.name(), None, true)?;
             }
+            db.finish_pipeline_run(run)?;
 
             println!("\n═══ Refresh complete ═══");
+            // After keywords and fit, so the scores include them
+            if notify {
+                notify_new_jobs(&db, last_job_id, notify_score)?;
+            }
+            if !failures.is_empty() {
+                return Err(HuntError::Partial(format!("Refresh finished with failures: {}", failures.join(", "))).into());
+            }
         }
     }
 
@@ -2286,32 +6814,32 @@ fn run_dependency_check() {
     // SQLite (bundled)
     println!("  SQLite ............. ok (bundled)");
 
-    // geckodriver
-    match check_binary("geckodriver") {
-        Some(path) => println!("  geckodriver ........ ok ({})", path),
-        None => {
-            println!("  geckodriver ........ MISSING");
-            println!("    Install: https://github.com/mozilla/geckodriver/releases");
-            println!("    Or: cargo install geckodriver");
-            all_ok = false;
+    // WebDriver + browser: one pair is enough
+    let mut any_browser = false;
+    for driver in browser::Driver::ALL {
+        let (label, install) = match driver {
+            browser::Driver::Firefox => ("Firefox", "https://github.com/mozilla/geckodriver/releases"),
+            browser::Driver::Chrome => ("Chrome", "https://googlechromelabs.github.io/chrome-for-testing/"),
+        };
+        let dots = ".".repeat(20usize.saturating_sub(driver.binary().len() + 1));
+        let binary = check_binary(driver.binary());
+        match &binary {
+            Some(path) => println!("  {} {} ok ({})", driver.binary(), dots, path),
+            None => {
+                println!("  {} {} MISSING", driver.binary(), dots);
+                println!("    Install: {}", install);
+            }
         }
-    }
-
-    // Firefox
-    let firefox_found = check_binary("firefox")
-        .or_else(|| check_binary("firefox-esr"))
-        .or_else(|| {
-            // Check snap location
-            let snap = PathBuf::from("/snap/bin/firefox");
-            if snap.exists() { Some(snap.display().to_string()) } else { None }
-        });
-    match firefox_found {
-        Some(path) => println!("  Firefox ............ ok ({})", path),
-        None => {
-            println!("  Firefox ............ MISSING");
-            println!("    Install: https://www.mozilla.org/firefox/");
-            all_ok = false;
+        let dots = ".".repeat(20usize.saturating_sub(label.len() + 1));
+        let browser_path = find_browser(driver);
+        match &browser_path {
+            Some(path) => println!("  {} {} ok ({})", label, dots, path),
+            None => println!("  {} {} MISSING", label, dots),
         }
+        any_browser |= binary.is_some() && browser_path.is_some();
+    }
+    if !any_browser {
+        all_ok = false;
     }
 
     // Gmail password file
@@ -2343,27 +6871,392 @@ fn run_dependency_check() {
         println!("All required dependencies found.");
     } else {
         println!("Some dependencies are missing. Commands needing them will fail.");
-        println!("  geckodriver + Firefox: hunt fetch, hunt refresh");
+        println!("  geckodriver + Firefox or chromedriver + Chrome: hunt fetch, hunt refresh (pages that need JavaScript)");
         println!("  Gmail password: hunt email, hunt refresh");
     }
 }
 
-fn require_browser_deps() -> Result<()> {
-    let mut missing = Vec::new();
-    if check_binary("geckodriver").is_none() {
-        missing.push("geckodriver (install from https://github.com/mozilla/geckodriver/releases)");
+/// A browser executable that works with `driver`, on the PATH or at a snap path.
+fn find_browser(driver: browser::Driver) -> Option<String> {
+    driver.browsers().iter().find_map(|name| {
+        if name.starts_with('/') {
+            PathBuf::from(name).exists().then(|| name.to_string())
+        } else {
+            check_binary(name)
+        }
+    })
+}
+
+/// The WebDriver to fetch with: the one asked for (which must be running or installed),
+/// else one that's already running, else the first installed.
+fn resolve_driver(choice: Option<browser::Driver>) -> Result<browser::Driver> {
+    let installed = |d: browser::Driver| check_binary(d.binary()).is_some() && find_browser(d).is_some();
+    if let Some(driver) = choice {
+        if driver.is_listening() || installed(driver) {
+            return Ok(driver);
+        }
+        let mut missing = Vec::new();
+        if check_binary(driver.binary()).is_none() {
+            missing.push(driver.binary().to_string());
+        }
+        if find_browser(driver).is_none() {
+            missing.push(format!("a browser ({})", driver.browsers().join(", ")));
+        }
+        return Err(HuntError::Config(format!("Missing required dependencies for --driver {}:\n  - {}\n\nRun 'hunt check' to see all dependency status.",
+            driver.name(), missing.join("\n  - "))).into());
+    }
+    browser::detect_driver(browser::Driver::is_listening, installed).ok_or_else(|| {
+        HuntError::Config("No browser automation available: install geckodriver + Firefox or chromedriver + Chrome/Chromium.\n\
+            Run 'hunt check' to see all dependency status.".to_string()).into()
+    })
+}
+
+/// Send `mail` through the config's "smtp" server.
+fn send_mail(settings: &config::Config, mail: &smtp::Outgoing) -> Result<()> {
+    let smtp = settings.smtp()?;
+    info!("Sending \"{}\" to {} via {}...", mail.subject, mail.to, smtp.server);
+    smtp::send(smtp, &expand_home(&smtp.password_file), mail)?;
+    println!("✓ Emailed \"{}\" to {}", mail.subject, mail.to);
+    Ok(())
+}
+
+/// `hunt mail apply`'s message when no --template is given.
+fn application_body(job: &models::Job) -> String {
+    let role = match &job.employer_name {
+        Some(employer) => format!("the {} position at {}", job.title, employer),
+        None => format!("the {} position", job.title),
+    };
+    let reference = job.job_code.as_ref().map(|code| format!(" (ref. {})", code)).unwrap_or_default();
+    format!(
+        "Hello,\n\nI'd like to apply for {}{}. My resume is attached.\n\nThank you for your consideration.\n",
+        role, reference
+    )
+}
+
+/// Expand a leading `~/` to $HOME.
+fn expand_home(path: &str) -> PathBuf {
+    if let Some(rest) = path.strip_prefix("~/") {
+        let home = std::env::var("HOME").unwrap_or_default();
+        PathBuf::from(format!("{}/{}", home, rest))
+    } else {
+        PathBuf::from(path)
     }
-    let has_firefox = check_binary("firefox").is_some()
-        || check_binary("firefox-esr").is_some()
-        || PathBuf::from("/snap/bin/firefox").exists();
-    if !has_firefox {
-        missing.push("Firefox (install from https://www.mozilla.org/firefox/)");
+}
+
+/// Build the list of IMAP accounts for `email`/`refresh`. The Gmail account from
+/// --username/--password-file is used unless only --account specs were given. With no
+/// account flags at all, the config's `accounts` are used instead, if it has any.
+fn email_accounts(
+    username: Option<String>,
+    password_file: Option<String>,
+    accounts: &[String],
+    oauth: bool,
+    configured: &[String],
+) -> Result<Vec<EmailConfig>> {
+    let no_flags = accounts.is_empty() && username.is_none() && password_file.is_none() && !oauth;
+    let accounts = if no_flags && !configured.is_empty() { configured } else { accounts };
+    let mut configs = Vec::new();
+    if accounts.is_empty() || username.is_some() || password_file.is_some() || oauth {
+        let username = username.unwrap_or_else(|| "jciispam@gmail.com".to_string());
+        if oauth {
+            configs.push(EmailConfig::gmail_oauth(&username)?);
+        } else {
+            let password_file = password_file.unwrap_or_else(|| "~/.gmail.app_password.txt".to_string());
+            configs.push(EmailConfig::from_gmail_password_file(&username, &expand_home(&password_file))?);
+        }
     }
-    if missing.is_empty() {
-        Ok(())
+    for spec in accounts {
+        let spec = AccountSpec::parse(spec)?;
+        configs.push(EmailConfig::from_password_file(
+            &spec.server,
+            spec.port,
+            &spec.username,
+            &expand_home(&spec.password_file),
+        )?);
+    }
+    Ok(configs)
+}
+
+/// Default `--notify-score`: a new job with decent pay and some keyword overlap.
+const DEFAULT_NOTIFY_SCORE: f64 = 75.0;
+
+/// Desktop notification for jobs added after `last_job_id` that score at least `min_score`.
+/// A notification that can't be shown is a warning, not a failed run.
+fn notify_new_jobs(db: &Database, last_job_id: i64, min_score: f64) -> Result<()> {
+    let statuses = db.list_job_statuses()?;
+    let new_jobs: Vec<(models::Job, f64)> = db.list_jobs(None, None)?
+        .into_iter()
+        .filter(|j| j.id > last_job_id)
+        .map(|j| {
+            let score = db::calculate_score(&j, db, &statuses);
+            (j, score)
+        })
+        .collect();
+    match notify::summarize(&new_jobs, min_score) {
+        Some(notification) => match notify::send(&notification) {
+            Ok(()) => println!("\nNotified: {}", notification.title.trim_start_matches("hunt: ")),
+            Err(e) => warn!("Could not send desktop notification: {}", e),
+        },
+        None => println!("\nNo new jobs scoring {:.0}+; no notification sent.", min_score),
+    }
+    Ok(())
+}
+
+fn print_ingest_results(results: &[AccountIngestStats]) {
+    let print_stats = |stats: &IngestStats, indent: &str| {
+        println!("{}Emails processed: {}", indent, stats.emails_found);
+        println!("{}Jobs added:       {}", indent, stats.jobs_added);
+        println!("{}Duplicates:       {}", indent, stats.duplicates);
+        if stats.skipped > 0 {
+            println!("{}Already ingested: {} (`hunt email --reprocess` parses them again)", indent, stats.skipped);
+        }
+        if stats.errors > 0 {
+            println!("{}Errors:           {}", indent, stats.errors);
+        }
+    };
+
+    println!("\nResults:");
+    if results.len() > 1 {
+        for r in results {
+            println!("  {}:", r.account);
+            match &r.error {
+                Some(e) => println!("    Failed: {}", e),
+                None => print_stats(&r.stats, "    "),
+            }
+        }
+        println!("  Total:");
+        print_stats(&IngestStats::total(results), "    ");
+    } else if let Some(r) = results.first() {
+        match &r.error {
+            Some(e) => println!("  Email fetch failed: {}", e),
+            None => print_stats(&r.stats, "  "),
+        }
+    }
+}
+
+/// Where a job's applications go, and a hint when another job already points there.
+fn report_application(db: &Database, job_id: i64, application: &domains::Application) -> Result<()> {
+    match application.ats {
+        Some(ats) => println!("✓ Applications go to {} ({})", application.url, ats),
+        None => println!("✓ Applications go to {}", application.url),
+    }
+    if let Some(other) = db.job_sharing_apply_url(job_id)? {
+        println!("  Same posting as job #{}; merge them with 'hunt cleanup --duplicates --merge'", other);
+    }
+    Ok(())
+}
+
+/// `--easy-apply`/`--external-apply` as a `SavedFilter::easy_apply` value.
+fn apply_route(easy_apply: bool, external_apply: bool) -> Option<bool> {
+    match (easy_apply, external_apply) {
+        (true, _) => Some(true),
+        (_, true) => Some(false),
+        _ => None,
+    }
+}
+
+/// Refuse AI work on a job at a never/yuck employer unless `include_blocked`.
+fn guard_blocked_employer(db: &Database, job: &models::Job, include_blocked: bool) -> Result<()> {
+    if include_blocked {
+        return Ok(());
+    }
+    if let Some(status) = db.blocked_employer_status(job)? {
+        return Err(HuntError::Validation(format!(
+            "Job #{} is at {}, marked '{}'. Pass --include-blocked to run AI on it anyway.",
+            job.id,
+            job.employer_name.as_deref().unwrap_or("?"),
+            status
+        )).into());
+    }
+    Ok(())
+}
+
+fn report_blocked_skips(blocked: usize) {
+    if blocked > 0 {
+        println!("Skipping {} job(s) at employers marked never/yuck (--include-blocked to include them)\n", blocked);
+    }
+}
+
+/// After a re-fetch, say whether the description changed since the last fetch and how.
+/// Returns whether it changed; jobs never fetched before say nothing.
+fn report_description_change(job: &models::Job, new_text: &str) -> bool {
+    let (Some(_), Some(old)) = (&job.fetched_at, &job.raw_text) else { return false };
+    let change = changes::PostingChange::between(old, new_text);
+    if change.is_empty() {
+        println!("= Description unchanged since last fetch");
+        return false;
+    }
+    println!("↻ Description changed since last fetch: {} (hunt diff {})", change.summary(), job.id);
+    true
+}
+
+/// Print a line diff of `old` -> `new` with a few lines of context, then a +/- summary.
+fn print_text_diff(old: &str, new: &str, color: bool) {
+    let lines = diff::diff_lines(old, new);
+    let (added, removed) = diff::stats(&lines);
+    if added == 0 && removed == 0 {
+        println!("(identical)");
+        return;
+    }
+    print!("{}", diff::render(&lines, 3, color));
+    println!("{} line(s) added, {} removed", added, removed);
+}
+
+/// A URL as an OSC 8 hyperlink when stdout is a terminal, so it's clickable even
+/// where the terminal wouldn't detect it; plain text otherwise.
+fn hyperlink(url: &str) -> String {
+    use std::io::IsTerminal;
+    if std::io::stdout().is_terminal() {
+        format!("\x1b]8;;{url}\x1b\\{url}\x1b]8;;\x1b\\")
+    } else {
+        url.to_string()
+    }
+}
+
+/// Print streamed AI output as it arrives.
+fn print_stream(text: &str) {
+    use std::io::{self, Write};
+    print!("{}", text);
+    let _ = io::stdout().flush();
+}
+
+/// Provider whose calls are retried on transient failures and recorded in `ai_usage`
+/// under `command` (see `hunt usage`).
+fn metered_provider(db: &Database, spec: &ai::ModelSpec, command: &str) -> Result<Box<dyn ai::AIProvider>> {
+    let provider = ai::RetryingProvider::new(ai::create_provider(spec)?, ai::RetryPolicy::from_env()?);
+    Ok(Box::new(ai::MeteredProvider::new(Box::new(provider), db.ai_usage_log()?, spec, command)))
+}
+
+/// Provider for analyze/keywords/fit. Responses are cached by prompt; with `no_cache`
+/// the provider is always called and the cache just refreshed.
+fn cached_provider(db: &Database, spec: &ai::ModelSpec, command: &str, no_cache: bool) -> Result<Box<dyn ai::AIProvider>> {
+    let provider = ai::CachedProvider::new(metered_provider(db, spec, command)?, db.ai_cache()?, &spec.short_name);
+    Ok(Box::new(if no_cache { provider.refresh() } else { provider }))
+}
+
+/// Save extracted keywords (and profile) for a job; returns the keyword count.
+fn store_domain_keywords(db: &Database, job_id: i64, model: &str, domain_kw: &ai::DomainKeywords) -> Result<usize> {
+    db.add_job_keywords(job_id, &domain_kw.tech, "tech", model)?;
+    db.add_job_keywords(job_id, &domain_kw.discipline, "discipline", model)?;
+    db.add_job_keywords(job_id, &domain_kw.cloud, "cloud", model)?;
+    db.add_job_keywords(job_id, &domain_kw.soft_skill, "soft_skill", model)?;
+    if !domain_kw.profile.is_empty() {
+        db.save_keyword_profile(job_id, model, &domain_kw.profile)?;
+    }
+    // The red-flag rules are free, so they ride along with every extraction
+    db.detect_job_flags(job_id)?;
+    Ok(domain_kw.tech.len() + domain_kw.discipline.len() + domain_kw.cloud.len() + domain_kw.soft_skill.len())
+}
+
+fn print_job_clusters(db: &Database) -> Result<()> {
+    let clusters = db.list_job_clusters()?;
+    if clusters.is_empty() {
+        println!("No clusters yet. Run 'hunt cluster' after extracting keywords.");
+        return Ok(());
+    }
+    for cluster in clusters {
+        println!("Cluster #{}: {} ({} jobs)", cluster.id, cluster.name, cluster.job_ids.len());
+        println!("  {}", cluster.keywords);
+        for job_id in cluster.job_ids {
+            if let Some(job) = db.get_job(job_id)? {
+                println!(
+                    "    #{:<5} {:<40} {}",
+                    job.id,
+                    truncate(&job.title, 38),
+                    job.employer_name.as_deref().unwrap_or("-")
+                );
+            }
+        }
+        println!();
+    }
+    Ok(())
+}
+
+/// `hunt keywords --report`: what open jobs ask for, overall and per domain, and the
+/// required keywords the skill profile doesn't cover.
+fn print_keyword_report(db: &Database, top: usize) -> Result<()> {
+    let (demand, jobs) = db.get_keyword_demand()?;
+    if demand.is_empty() {
+        println!("No keywords on open jobs yet. Extract them with 'hunt keywords --all'.");
+        return Ok(());
+    }
+    println!("Keywords across {} open job(s)\n", jobs);
+
+    let header = || {
+        println!("  {:<28} {:>7} {:>5} {:>9}", "KEYWORD", "WEIGHT", "JOBS", "REQUIRED");
+        println!("  {}", "-".repeat(52));
+    };
+    let row = |d: &db::KeywordDemand| {
+        println!("  {:<28} {:>7} {:>5} {:>9}", truncate(&d.keyword, 27), d.total_weight, d.jobs, d.required);
+    };
+
+    println!("Most-demanded tech:");
+    header();
+    demand.iter().filter(|d| d.domain == "tech").take(top).for_each(row);
+
+    for domain in ["discipline", "cloud", "soft_skill"] {
+        let in_domain: Vec<&db::KeywordDemand> = demand.iter().filter(|d| d.domain == domain).collect();
+        if in_domain.is_empty() {
+            continue;
+        }
+        let weight: i64 = in_domain.iter().map(|d| d.total_weight).sum();
+        println!("\n{} ({} keyword(s), total weight {}):", domain, in_domain.len(), weight);
+        header();
+        in_domain.into_iter().take(top).for_each(row);
+    }
+
+    let skills = db.get_user_skills()?;
+    if skills.is_empty() {
+        println!("\nNo skill profile, so no skills gap. Set one with 'hunt profile set-skills' or 'hunt profile derive-skills'.");
+        return Ok(());
+    }
+    let gaps = db::required_skill_gaps(&demand, &skills);
+    if gaps.is_empty() {
+        println!("\nSkills gap: your skill profile covers every required keyword.");
     } else {
-        Err(anyhow!("Missing required dependencies:\n  - {}\n\nRun 'hunt check' to see all dependency status.", missing.join("\n  - ")))
+        println!("\nSkills gap (required keywords your skill profile doesn't cover):");
+        header();
+        gaps.into_iter().take(top).for_each(row);
+        println!("\nPlan study time for these with 'hunt learn plan'.");
+    }
+    Ok(())
+}
+
+fn print_learning_plan(db: &Database) -> Result<()> {
+    let Some((plan_id, items)) = db.get_latest_learning_plan()? else {
+        println!("No learning plan yet. Create one with 'hunt learn plan'.");
+        return Ok(());
+    };
+    let done = items.iter().filter(|i| i.done_at.is_some()).count();
+    println!("Learning plan #{} ({}/{} done)", plan_id, done, items.len());
+    let mut current_week: Option<&str> = None;
+    for item in &items {
+        if current_week != Some(item.week.as_str()) {
+            println!("\n  Week {}", item.week);
+            current_week = Some(&item.week);
+        }
+        let mark = if item.done_at.is_some() { "x" } else { " " };
+        println!("    [{}] #{:<4} {}: {}", mark, item.id, item.keyword, item.task);
+        if let Some(resource) = &item.resource {
+            println!("              Resource:  {}", resource);
+        }
+        if let Some(milestone) = &item.milestone {
+            println!("              Milestone: {}", milestone);
+        }
     }
+    Ok(())
+}
+
+/// Print `message` and read one trimmed line from stdin ("q" on EOF).
+fn prompt_line(message: &str) -> Result<String> {
+    use std::io::{self, Write};
+    print!("{}", message);
+    io::stdout().flush()?;
+    let mut input = String::new();
+    if io::stdin().read_line(&mut input)? == 0 {
+        return Ok("q".to_string());
+    }
+    Ok(input.trim().to_string())
 }
 
 fn truncate(s: &str, max: usize) -> String {
@@ -2374,23 +7267,75 @@ fn truncate(s: &str, max: usize) -> String {
     }
 }
 
-fn fetch_job_description(url: &str, headless: bool) -> Result<browser::JobDescription> {
-    // Use browser automation to fetch job description
-    // This handles JavaScript-rendered content and "Show more" buttons
-    println!("Initializing browser...");
+/// Fetches job descriptions for one command: plain HTTP first, then a browser session
+/// that is started the first time a page needs it and reused for the rest of the batch.
+struct DescriptionFetcher {
+    headless: bool,
+    browser_only: bool,
+    driver: Option<browser::Driver>,
+    browser: Option<(tokio::runtime::Runtime, browser::JobFetcher)>,
+}
+
+impl DescriptionFetcher {
+    fn new(headless: bool, browser_only: bool, driver: Option<browser::Driver>) -> Self {
+        DescriptionFetcher { headless, browser_only, driver, browser: None }
+    }
+
+    /// The description, and whether it took the browser.
+    fn fetch(&mut self, url: &str) -> Result<(browser::JobDescription, bool)> {
+        // Fast path: most postings render server-side and need no browser at all
+        if !self.browser_only {
+            match browser::fetch_plain(url) {
+                Ok(browser::PlainFetch::Content(desc)) => {
+                    println!("✓ Fetched without browser");
+                    return Ok((desc, false));
+                }
+                Ok(browser::PlainFetch::NeedsBrowser(reason)) => {
+                    println!("Plain fetch insufficient ({}), using browser...", reason);
+                }
+                Err(e) => info!("Plain fetch failed ({}), using browser...", e),
+            }
+        }
+
+        // Use browser automation to fetch job description
+        // This handles JavaScript-rendered content and "Show more" buttons
+        if self.browser.is_none() {
+            let driver = resolve_driver(self.driver)?;
+            println!("Initializing browser ({})...", driver.name());
+            let rt = tokio::runtime::Runtime::new()
+                .context("Failed to create tokio runtime")?;
+            let fetcher = rt.block_on(browser::JobFetcher::new(driver, self.headless))
+                .with_context(|| format!("Failed to initialize browser. Make sure {0} is running.\n\
+                         Start it with: {0} --port {1}", driver.binary(), driver.port()))?;
+            self.browser = Some((rt, fetcher));
+        }
+        let (rt, fetcher) = self.browser.as_ref().expect("browser started above");
+        Ok((rt.block_on(fetcher.fetch_job_description(url))?, true))
+    }
+
+    /// Close the browser session, if one was started.
+    fn finish(self) {
+        if let Some((rt, fetcher)) = self.browser {
+            let _ = rt.block_on(fetcher.quit());
+        }
+    }
+}
 
-    // Create a tokio runtime to run async code
-    let rt = tokio::runtime::Runtime::new()
-        .context("Failed to create tokio runtime")?;
+/// Wait until the URL's domain is free again (`ratelimit::RateLimiter`).
+fn wait_for_domain(limiter: &RateLimiter, url: &str) {
+    countdown(limiter.wait(url, std::time::Instant::now()).as_secs_f64().ceil() as u64);
+}
 
-    rt.block_on(async {
-        let fetcher = browser::JobFetcher::new(headless)
-            .await
-            .context("Failed to initialize browser. Make sure geckodriver is running.\n\
-                     Start it with: geckodriver --port 4444")?;
+/// After fetching `url`: its domain's budget if it has one, else `fetch_pause`.
+fn record_fetch(limiter: &mut RateLimiter, url: &str, delay: u64, used_browser: bool) {
+    let pause = limiter.budget(url).map_or_else(|| fetch_pause(delay, used_browser), add_jitter);
+    limiter.record(url, std::time::Duration::from_secs(pause), std::time::Instant::now());
+}
 
-        fetcher.fetch_job_description(url).await
-    })
+/// Pause between batch fetches. The full delay protects browser sessions (a logged-in
+/// LinkedIn account is what gets rate limited); plain HTTP requests only need a second.
+fn fetch_pause(delay: u64, used_browser: bool) -> u64 {
+    if used_browser { add_jitter(delay) } else { delay.min(1) }
 }
 
 fn add_jitter(seconds: u64) -> u64 {
@@ -2403,6 +7348,10 @@ fn add_jitter(seconds: u64) -> u64 {
 
 fn countdown(seconds: u64) {
     use std::io::{self, Write};
+    if seconds <= 1 || output::quiet() {
+        std::thread::sleep(std::time::Duration::from_secs(seconds));
+        return;
+    }
     print!("Waiting {} seconds before next fetch... ", seconds);
     io::stdout().flush().unwrap();
 
@@ -2449,29 +7398,42 @@ mod tests {
         assert_eq!(result, 0);
     }
 
-    // --- Research stubs ---
-
     #[test]
-    fn test_search_yc_company_returns_none() {
-        let info = search_yc_company("Test Corp").unwrap();
-        assert!(info.batch.is_none());
-        assert!(info.url.is_none());
+    fn test_fetch_pause() {
+        assert_eq!(fetch_pause(5, false), 1, "plain HTTP only needs a second");
+        assert_eq!(fetch_pause(0, false), 0);
+        let browser = fetch_pause(10, true);
+        assert!((8..=12).contains(&browser), "browser fetches keep the full delay, got {}", browser);
     }
 
-    #[test]
-    fn test_search_hn_mentions_returns_zero() {
-        assert_eq!(search_hn_mentions("Test Corp").unwrap(), 0);
-    }
+    // --- compare_keywords ---
 
     #[test]
-    fn test_research_startup_returns_default() {
-        let data = research_startup("Test Corp").unwrap();
-        assert!(data.yc_batch.is_none());
-        assert!(data.yc_url.is_none());
-        assert_eq!(data.hn_mentions_count, Some(0));
-        assert!(data.crunchbase_url.is_none());
+    fn test_compare_keywords_lines_up_models() {
+        let kw = |keyword: &str, weight: i32| models::JobKeyword {
+            id: 0,
+            job_id: 1,
+            keyword: keyword.to_string(),
+            domain: "tech".to_string(),
+            weight,
+            source_model: String::new(),
+            created_at: String::new(),
+        };
+        let rows = compare_keywords(&[
+            vec![kw("Kubernetes", 3), kw("Go", 1), kw("Terraform", 2)],
+            vec![kw("kubernetes", 2), kw("Python", 3), kw("go", 2)],
+        ]);
+        let summary: Vec<(&str, Vec<Option<i32>>)> = rows.iter().map(|r| (r.keyword.as_str(), r.weights.clone())).collect();
+        assert_eq!(summary, vec![
+            ("Kubernetes", vec![Some(3), Some(2)]),
+            ("Go", vec![Some(1), Some(2)]),
+            ("Python", vec![None, Some(3)]),
+            ("Terraform", vec![Some(2), None]),
+        ]);
     }
 
+    // --- Research stubs ---
+
     #[test]
     fn test_research_public_company_returns_summary() {
         let data = research_public_company("Acme Corp").unwrap();
@@ -2530,11 +7492,12 @@ mod tests {
         let db = create_test_db()?;
         db.add_job_full("Hi", None, None, None, None, None, None)?;
         db.add_job_full("Real DevOps Engineer Job", Some("Acme"), None, None, None, None, None)?;
-        let removed = cleanup_artifacts(&db, false)?;
+        let removed = cleanup_artifacts(&db, false, false, &mut CleanupReview::heuristic())?;
         assert_eq!(removed, 1);
         let remaining = db.list_jobs(None, None)?;
         assert_eq!(remaining.len(), 1);
         assert_eq!(remaining[0].title, "Real DevOps Engineer Job");
+        assert_eq!(db.filter_jobs(&models::SavedFilter::default(), true)?.len(), 1, "archived, not deleted");
         Ok(())
     }
 
@@ -2545,7 +7508,7 @@ mod tests {
         db.add_job_full("Click here to apply", None, None, None, None, None, None)?;
         db.add_job_full("View all positions", None, None, None, None, None, None)?;
         db.add_job_full("Senior DevOps Engineer", Some("Co"), None, None, None, None, None)?;
-        let removed = cleanup_artifacts(&db, false)?;
+        let removed = cleanup_artifacts(&db, false, false, &mut CleanupReview::heuristic())?;
         assert_eq!(removed, 3);
         Ok(())
     }
@@ -2555,7 +7518,7 @@ mod tests {
         let db = create_test_db()?;
         db.add_job_full("Some title that is long enough", None, Some("https://www.linkedin.com/comm/jobs/search?keywords=test"), None, None, None, None)?;
         db.add_job_full("Real Job Title Here", Some("Co"), Some("https://www.linkedin.com/comm/jobs/view/12345"), None, None, None, None)?;
-        let removed = cleanup_artifacts(&db, false)?;
+        let removed = cleanup_artifacts(&db, false, false, &mut CleanupReview::heuristic())?;
         assert_eq!(removed, 1);
         Ok(())
     }
@@ -2564,7 +7527,7 @@ mod tests {
     fn test_cleanup_artifacts_dry_run() -> Result<()> {
         let db = create_test_db()?;
         db.add_job_full("Hi", None, None, None, None, None, None)?;
-        let removed = cleanup_artifacts(&db, true)?;
+        let removed = cleanup_artifacts(&db, true, false, &mut CleanupReview::heuristic())?;
         assert_eq!(removed, 1);
         // Dry run should NOT delete
         let remaining = db.list_jobs(None, None)?;
@@ -2577,8 +7540,60 @@ mod tests {
         let db = create_test_db()?;
         db.add_job_full("DevOps Engineer", Some("Acme"), None, None, None, None, None)?;
         db.add_job_full("DevOps Engineer", Some("Acme"), None, None, None, None, None)?;
-        let removed = cleanup_duplicates(&db, false)?;
+        let removed = cleanup_duplicates(&db, false, false, false, &mut CleanupReview::heuristic())?;
         assert_eq!(removed, 1);
+        assert_eq!(cleanup_duplicates(&db, false, false, false, &mut CleanupReview::heuristic())?, 0, "archived duplicates aren't found again");
+
+        db.add_job_full("DevOps Engineer", Some("Acme"), None, None, None, None, None)?;
+        assert_eq!(cleanup_duplicates(&db, false, true, false, &mut CleanupReview::heuristic())?, 1);
+        assert_eq!(db.filter_jobs(&models::SavedFilter::default(), true)?.len(), 1, "purged, not archived");
+        Ok(())
+    }
+
+    #[test]
+    fn test_cleanup_duplicates_merge() -> Result<()> {
+        let db = create_test_db()?;
+        let first = db.add_job_full("DevOps Engineer", Some("Acme"), None, None, None, None, None)?;
+        let second = db.add_job_full("DevOps Engineer", Some("Acme"), Some("https://acme.com/jobs/7"), None, None, None, None)?;
+        let third = db.add_job_full("Senior DevOps Engineer", Some("Acme"), None, None, None, None, None)?;
+        db.add_job_note(third, "Referral from Sam")?;
+
+        assert_eq!(cleanup_duplicates(&db, false, false, true, &mut CleanupReview::heuristic())?, 2);
+        let kept = db.get_job(first)?.unwrap();
+        assert_eq!(kept.url.as_deref(), Some("https://acme.com/jobs/7"));
+        let notes: Vec<String> = db.list_job_notes(first)?.into_iter().map(|n| n.note).collect();
+        assert!(notes.contains(&"Referral from Sam".to_string()));
+        assert_eq!(db.list_jobs(None, None)?.len(), 1);
+        assert!(db.get_job(second)?.unwrap().archived_at.is_some());
+        Ok(())
+    }
+
+    #[test]
+    fn test_cleanup_review_remembers_decisions() -> Result<()> {
+        let db = create_test_db()?;
+        let apply_1 = db.add_job_full("Apply now 1", None, None, None, None, None, None)?;
+        let apply_2 = db.add_job_full("Apply now 2", None, None, None, None, None, None)?;
+        let view = db.add_job_full("View job", None, None, None, None, None, None)?;
+        let sign_in = db.add_job_full("Sign in", None, None, None, None, None, None)?;
+        let log_in = db.add_job_full("Log in", None, None, None, None, None, None)?;
+        let job = |id| db.get_job(id).map(Option::unwrap);
+
+        let mut answers = std::collections::VecDeque::from(["?", "a", "e", "Platform Engineer", "k", "q"]);
+        let mut review = CleanupReview::interactive(move |_| Ok(answers.pop_front().unwrap_or("q").to_string()));
+        assert!(review.should_remove(&db, "artifact", &cleanup_pattern("Apply now 1"), &job(apply_1)?, "link text")?);
+        assert!(review.should_remove(&db, "artifact", &cleanup_pattern("Apply now 2"), &job(apply_2)?, "link text")?, "remembered");
+        assert!(!review.should_remove(&db, "artifact", &cleanup_pattern("View job"), &job(view)?, "link text")?);
+        assert_eq!(job(view)?.title, "Platform Engineer");
+        assert!(!review.should_remove(&db, "artifact", &cleanup_pattern("Sign in"), &job(sign_in)?, "link text")?);
+        assert!(!review.should_remove(&db, "artifact", &cleanup_pattern("Log in"), &job(log_in)?, "link text")?, "quit");
+        assert!(!review.should_remove(&db, "artifact", &cleanup_pattern("Apply now 3"), &job(apply_1)?, "link text")?, "nothing after quit");
+
+        // Later cleanups follow the answers: both "Apply now" jobs and "Log in", not "Sign in"
+        assert_eq!(cleanup_artifacts(&db, true, false, &mut CleanupReview::heuristic())?, 3);
+        assert_eq!(db.clear_cleanup_decisions()?, 2);
+        assert_eq!(cleanup_artifacts(&db, true, false, &mut CleanupReview::heuristic())?, 4);
+
+        assert_eq!(duplicate_pattern("SRE 2", "Site Reliability Engineer"), duplicate_pattern("Site  Reliability Engineer", "sre 3"));
         Ok(())
     }
 
@@ -2587,7 +7602,7 @@ mod tests {
         let db = create_test_db()?;
         db.add_job_full("DevOps Engineer", Some("Acme"), None, None, None, None, None)?;
         db.add_job_full("DevOps Engineer", Some("Acme"), None, None, None, None, None)?;
-        let removed = cleanup_duplicates(&db, true)?;
+        let removed = cleanup_duplicates(&db, true, false, false, &mut CleanupReview::heuristic())?;
         assert_eq!(removed, 1);
         // Dry run should NOT delete
         let remaining = db.list_jobs(None, None)?;
@@ -2595,6 +7610,24 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_cleanup_urls() -> Result<()> {
+        let db = create_test_db()?;
+        let id = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        db.set_job_url(id, "https://www.linkedin.com/comm/jobs/view/42?trk=eml&refId=x")?;
+        let other = db.add_job_full("Site Reliability Engineer", Some("Globex"), Some("https://www.linkedin.com/jobs/view/42"), None, None, None, None)?;
+
+        assert_eq!(cleanup_urls(&db, true)?, 1);
+        assert_eq!(db.get_job(id)?.unwrap().url.as_deref(), Some("https://www.linkedin.com/comm/jobs/view/42?trk=eml&refId=x"), "dry run");
+        assert_eq!(cleanup_urls(&db, false)?, 1);
+        assert_eq!(db.get_job(id)?.unwrap().url.as_deref(), Some("https://www.linkedin.com/jobs/view/42"));
+        assert_eq!(cleanup_urls(&db, false)?, 0);
+
+        let duplicates = db.find_duplicates()?;
+        assert_eq!(duplicates.iter().map(|(a, b, _)| (*a, *b)).collect::<Vec<_>>(), vec![(id, other)]);
+        Ok(())
+    }
+
     // --- check_binary ---
 
     #[test]
@@ -2622,14 +7655,30 @@ mod tests {
         let _ = result;
     }
 
-    // --- require_browser_deps ---
+    // --- resolve_driver ---
 
     #[test]
-    fn test_require_browser_deps() {
-        // Just exercise the code path. If geckodriver/firefox are missing, it returns Err.
-        let result = require_browser_deps();
-        // Don't assert on the result since deps may or may not be installed
-        let _ = result;
+    fn test_resolve_driver() {
+        // Just exercise the code paths. If no driver/browser is installed, they return Err.
+        for choice in [None, Some(browser::Driver::Firefox), Some(browser::Driver::Chrome)] {
+            if let Ok(driver) = resolve_driver(choice) {
+                assert!(choice.is_none() || choice == Some(driver));
+            }
+        }
+    }
+
+    #[test]
+    fn test_application_body() -> Result<()> {
+        let db = Database::open_in_memory()?;
+        db.init()?;
+        let id = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        let mut job = db.get_job(id)?.unwrap();
+        job.job_code = Some("R-123".to_string());
+        assert_eq!(
+            application_body(&job),
+            "Hello,\n\nI'd like to apply for the SRE position at Acme (ref. R-123). My resume is attached.\n\nThank you for your consideration.\n"
+        );
+        Ok(())
     }
 
     // --- display_domain_keywords ---
@@ -39,9 +39,16 @@ pub struct Employer {
     pub glassdoor_rating: Option<f64>,
     pub glassdoor_review_count: Option<i64>,
     pub last_glassdoor_fetch: Option<String>,
+    // Engineering culture signals (blog, talks, AI summary)
+    pub eng_blog_url: Option<String>,
+    pub eng_rss_url: Option<String>,
+    pub eng_talks: Option<String>, // one talk per line: "title | event | year | url"
+    pub eng_culture_summary: Option<String>,
+    pub eng_research_updated: Option<String>,
+    pub careers_url: Option<String>,
 }
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Job {
     pub id: i64,
     pub employer_id: Option<i64>,
@@ -49,7 +56,7 @@ pub struct Job {
     pub title: String,
     pub url: Option<String>,
     pub source: Option<String>, // "linkedin", "indeed", "manual", etc.
-    pub status: String,         // "new", "reviewing", "applied", "rejected", "closed"
+    pub status: String,         // name from job_statuses ("new", "reviewing", "applied", ...)
     pub pay_min: Option<i64>,
     pub pay_max: Option<i64>,
     pub job_code: Option<String>, // Job code/number/requisition ID for deduplication
@@ -57,10 +64,32 @@ pub struct Job {
     pub fetched_at: Option<String>,
     pub created_at: String,
     pub updated_at: String,
+    pub location: Option<String>,  // as the posting puts it, e.g. "Austin, TX (Hybrid)"
+    pub work_mode: Option<String>, // "remote", "hybrid" or "onsite"
+    pub pay_text: Option<String>,  // pay as the posting wrote it; pay_min/pay_max are annual USD
+    pub pay_equity: bool,          // the posting mentions equity, stock options or RSUs
+    pub archived_at: Option<String>, // set by `delete_job`; archived jobs are hidden, not gone
+    pub applied_at: Option<String>,  // first move to applied; kept if the job moves on or back
+    pub rejected_at: Option<String>, // latest move to rejected
+    pub closed_at: Option<String>,   // latest move to closed
+    pub posted_at: Option<String>,   // YYYY-MM-DD the posting went up, when it says
+    pub apply_deadline: Option<String>, // YYYY-MM-DD applications close
+    pub easy_apply: Option<bool>,    // LinkedIn only: Easy Apply (true) or the employer's site (false)
+    pub apply_url: Option<String>,   // where applications go after redirects, canonicalized
+    pub ats: Option<String>,         // the applicant tracking system behind apply_url
+    pub requires_clearance: Option<bool>, // the description asks for a security clearance
+    pub sponsors_visa: Option<bool>, // the description says it sponsors visas (true) or won't/citizens only (false)
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobStatus {
+    pub name: String,
+    pub position: i64,     // display/pipeline order (lower = earlier)
+    pub is_terminal: bool, // terminal statuses are excluded from ranking and hidden in browse
+    pub color: String,     // terminal color name used by the TUI ("green", "yellow", ...)
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
-#[allow(dead_code)]
 pub struct JobSnapshot {
     pub id: i64,
     pub job_id: i64,
@@ -75,6 +104,7 @@ pub struct BaseResume {
     pub format: String, // "markdown", "plain", "json", "latex"
     pub content: String,
     pub notes: Option<String>,
+    pub content_hash: Option<String>, // sha256 of content
     pub created_at: String,
     pub updated_at: String,
 }
@@ -88,6 +118,8 @@ pub struct ResumeVariant {
     pub tailoring_notes: Option<String>,
     pub source_model: Option<String>,
     pub output_format: Option<String>,
+    pub version: i64, // 1 for the first generation, counting up on each re-tailor
+    pub base_content_hash: Option<String>, // base resume's content_hash when tailored
     pub created_at: String,
 }
 
@@ -127,6 +159,296 @@ pub struct FitAnalysis {
     pub created_at: String,
 }
 
+/// The five points of `hunt summarize`, one line each.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct JobSummaryBullets {
+    pub responsibilities: String,
+    pub stack: String,
+    pub seniority: String,
+    pub comp: String,
+    pub red_flags: String,
+}
+
+impl JobSummaryBullets {
+    /// (label, text) in display order.
+    pub fn labeled(&self) -> [(&'static str, &str); 5] {
+        [
+            ("Responsibilities", &self.responsibilities),
+            ("Stack", &self.stack),
+            ("Seniority", &self.seniority),
+            ("Comp", &self.comp),
+            ("Red flags", &self.red_flags),
+        ]
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobSummary {
+    pub id: i64,
+    pub job_id: i64,
+    pub source_model: String,
+    pub bullets: JobSummaryBullets,
+    pub created_at: String,
+}
+
+/// Suggested numbers from `hunt negotiate`, annual USD.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct NegotiationAnchors {
+    pub anchor: Option<i64>,    // the opening ask
+    pub target: Option<i64>,    // what to settle happily at
+    pub walk_away: Option<i64>, // the floor
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NegotiationBrief {
+    pub id: i64,
+    pub job_id: i64,
+    pub source_model: String,
+    pub anchors: NegotiationAnchors,
+    pub brief: String,
+    pub created_at: String,
+}
+
+/// An interview question from `hunt prep`, with what to prepare for it.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct PrepQuestion {
+    pub kind: String, // technical | behavioral
+    pub question: String,
+    pub prep: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PrepNote {
+    pub id: i64,
+    pub job_id: i64,
+    pub base_resume_id: i64,
+    pub resume_name: String,
+    pub source_model: String,
+    pub question: PrepQuestion,
+    pub created_at: String,
+}
+
+/// One job status transition, recorded by `update_job_status`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatusChange {
+    pub id: i64,
+    pub job_id: i64,
+    pub from_status: Option<String>, // None for rows backfilled from pre-history databases
+    pub to_status: String,
+    pub changed_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobNote {
+    pub id: i64,
+    pub job_id: i64,
+    pub note: String,
+    pub created_at: String,
+}
+
+/// One `hunt refresh`; its progress is in `pipeline_run_steps`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PipelineRun {
+    pub id: i64,
+    pub started_at: String,
+    pub finished_at: Option<String>, // None while running or if interrupted
+}
+
+/// Fetch outcomes for one domain (`hunt fetch --status`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FetchDomain {
+    pub domain: String,
+    pub successes: i64,
+    pub failures: i64,
+    pub failure_streak: i64, // failures since the last success
+    pub last_error: Option<String>,
+    pub last_success_at: Option<String>,
+    pub last_failure_at: Option<String>,
+    pub backoff_until: Option<String>,
+}
+
+/// A named set of `hunt list` filters (`hunt filter save`), used with `list --filter`
+/// and the browser's filter picker.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SavedFilter {
+    pub name: String,
+    pub status: Option<String>,
+    pub employer: Option<String>,
+    pub tag: Option<String>,
+    pub keyword: Option<String>, // in the title or extracted keywords
+    pub min_pay: Option<i64>,    // annual USD; jobs that don't advertise pay don't match
+    pub max_pay: Option<i64>,
+    pub remote: bool,
+    pub location: Option<String>,
+    pub easy_apply: Option<bool>, // LinkedIn Easy Apply (true) or the employer's site (false)
+    pub sponsors_visa: bool,     // leave out jobs that won't sponsor a visa or want citizens only
+    pub no_clearance: bool,      // leave out jobs that require a security clearance
+    pub created_at: String,
+}
+
+impl SavedFilter {
+    /// "status new, tag remote, pay $180k+, keyword kubernetes"
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        let fields = [("status", &self.status), ("employer", &self.employer), ("tag", &self.tag), ("location", &self.location)];
+        for (label, value) in fields {
+            if let Some(value) = value {
+                parts.push(format!("{} {}", label, value));
+            }
+        }
+        if self.remote {
+            parts.push("remote".to_string());
+        }
+        match self.easy_apply {
+            Some(true) => parts.push("easy apply".to_string()),
+            Some(false) => parts.push("external apply".to_string()),
+            None => {}
+        }
+        if self.sponsors_visa {
+            parts.push("sponsors visa".to_string());
+        }
+        if self.no_clearance {
+            parts.push("no clearance".to_string());
+        }
+        match (self.min_pay, self.max_pay) {
+            (Some(min), Some(max)) => parts.push(format!("pay ${}k-${}k", min / 1000, max / 1000)),
+            (Some(min), None) => parts.push(format!("pay ${}k+", min / 1000)),
+            (None, Some(max)) => parts.push(format!("pay up to ${}k", max / 1000)),
+            (None, None) => {}
+        }
+        if let Some(keyword) = &self.keyword {
+            parts.push(format!("keyword {}", keyword));
+        }
+        if parts.is_empty() { "everything".to_string() } else { parts.join(", ") }
+    }
+}
+
+/// What to do next on a job and by when (`hunt next`, `hunt todo`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NextAction {
+    pub job_id: i64,
+    pub title: String,
+    pub employer_name: Option<String>,
+    pub action: String,
+    pub due_date: String, // YYYY-MM-DD
+    pub created_at: String,
+}
+
+/// A red flag found in a job's description (`hunt flags`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobFlag {
+    pub job_id: i64,
+    pub flag: String,
+    pub evidence: Option<String>,
+    pub source: String, // 'rules', or the model that found it
+    pub created_at: String,
+}
+
+/// A recruiter, referral or other person at an employer (`hunt contact`).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Contact {
+    pub id: i64,
+    pub name: String,
+    pub role: Option<String>,
+    pub employer_id: Option<i64>,
+    pub employer_name: Option<String>,
+    pub email: Option<String>,
+    pub linkedin: Option<String>,
+    pub last_touch: Option<String>, // YYYY-MM-DD
+    pub created_at: String,
+}
+
+/// A `hunt rule`: points added to (boost) or taken from (penalty) the rank of every
+/// job that asks for `keyword`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RankRule {
+    pub keyword: String,
+    pub points: f64, // positive boosts, negative penalizes
+    pub created_at: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LearningItem {
+    pub id: i64,
+    pub plan_id: i64,
+    pub position: i64,
+    pub week: String, // "1", "2-3", ...
+    pub keyword: String,
+    pub task: String,
+    pub resource: Option<String>,
+    pub milestone: Option<String>,
+    pub done_at: Option<String>,
+}
+
+/// A keyword marked unknown (`hunt keywords unknown`), scheduled for `hunt review`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReviewCard {
+    pub keyword: String,
+    pub interval_days: f64,
+    pub ease: f64,
+    pub reps: i64,
+    pub lapses: i64, // times answered "again" after being learned
+    pub due_at: String,
+    pub last_reviewed_at: Option<String>,
+    pub created_at: String,
+}
+
+/// A group of similar active jobs from `hunt cluster`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct JobCluster {
+    pub id: i64,
+    pub name: String,
+    pub keywords: String, // top keywords, comma-separated
+    pub source_model: Option<String>, // None when named from keywords
+    pub job_ids: Vec<i64>,
+    pub created_at: String,
+}
+
+/// Another name an employer goes by ("Alphabet" for Google); ingestion resolves it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmployerAlias {
+    pub id: i64,
+    pub alias: String,
+    pub employer_id: i64,
+    pub employer_name: String,
+    pub created_at: String,
+}
+
+/// A Hacker News story mentioning an employer (`hunt employer research`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HnStory {
+    pub title: String,
+    pub url: Option<String>, // linked article; None for Ask HN and text posts
+    pub hn_url: String,      // discussion thread
+    pub points: i64,
+    pub num_comments: i64,
+    pub posted_at: Option<String>,
+}
+
+/// Typical pay for one level at an employer (`hunt employer comp`), annual USD.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CompBand {
+    pub level: String, // one of comp::LEVELS, e.g. "senior"
+    pub role: String,  // e.g. "Site Reliability Engineer"
+    pub base_min: Option<i64>,
+    pub base_max: Option<i64>,
+    pub total_min: Option<i64>, // base + stock + bonus
+    pub total_max: Option<i64>,
+}
+
+/// Raised when a funding re-check finds a new round, down round, layoff or acquisition.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EmployerAlert {
+    pub id: i64,
+    pub employer_id: i64,
+    pub employer_name: String,
+    pub kind: String, // "new_round", "down_round", "layoff", "acquisition"
+    pub summary: String,
+    pub event_date: Option<String>,
+    pub acknowledged: bool,
+    pub created_at: String,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GlassdoorReview {
     pub id: i64,
@@ -141,3 +463,16 @@ pub struct GlassdoorReview {
     pub review_date: Option<String>,
     pub captured_at: String,
 }
+
+/// The employer's Glassdoor summary as of one fetch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GlassdoorSnapshot {
+    pub id: i64,
+    pub employer_id: i64,
+    pub rating: f64,
+    pub review_count: i64,
+    pub positive: i64,
+    pub neutral: i64,
+    pub negative: i64,
+    pub fetched_at: String,
+}
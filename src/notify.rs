@@ -0,0 +1,108 @@
+use std::process::Command;
+
+use anyhow::Result;
+
+use crate::error::HuntError;
+use crate::models::Job;
+
+/// How many jobs a notification lists before "and N more".
+const MAX_LISTED: usize = 5;
+
+#[derive(Debug, PartialEq)]
+pub struct Notification {
+    pub title: String,
+    pub body: String,
+}
+
+/// A notification about newly added jobs scoring at least `min_score`, best first, or
+/// None when none do.
+pub fn summarize(new_jobs: &[(Job, f64)], min_score: f64) -> Option<Notification> {
+    let mut top: Vec<&(Job, f64)> = new_jobs.iter().filter(|(_, score)| *score >= min_score).collect();
+    if top.is_empty() {
+        return None;
+    }
+    top.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal));
+
+    let mut lines: Vec<String> = top.iter().take(MAX_LISTED).map(|(job, score)| {
+        match &job.employer_name {
+            Some(employer) => format!("#{} {} at {} ({:.0})", job.id, job.title, employer, score),
+            None => format!("#{} {} ({:.0})", job.id, job.title, score),
+        }
+    }).collect();
+    if top.len() > MAX_LISTED {
+        lines.push(format!("...and {} more", top.len() - MAX_LISTED));
+    }
+    let plural = if top.len() == 1 { "" } else { "s" };
+    Some(Notification {
+        title: format!("hunt: {} new job{} scoring {:.0}+", top.len(), plural, min_score),
+        body: lines.join("\n"),
+    })
+}
+
+/// Show a desktop notification: notify-send on Linux, osascript on macOS.
+pub fn send(notification: &Notification) -> Result<()> {
+    let mut command = if cfg!(target_os = "macos") {
+        let script = format!(
+            "display notification {} with title {}",
+            applescript_string(&notification.body),
+            applescript_string(&notification.title)
+        );
+        let mut c = Command::new("osascript");
+        c.arg("-e").arg(script);
+        c
+    } else {
+        let mut c = Command::new("notify-send");
+        c.arg("--app-name=hunt").arg(&notification.title).arg(&notification.body);
+        c
+    };
+    let program = command.get_program().to_string_lossy().to_string();
+    let output = command.output()
+        .map_err(|e| HuntError::Config(format!("Couldn't run {} for desktop notifications: {}", program, e)))?;
+    if !output.status.success() {
+        return Err(HuntError::Config(format!(
+            "{} failed: {}", program, String::from_utf8_lossy(&output.stderr).trim()
+        )).into());
+    }
+    Ok(())
+}
+
+/// A double-quoted AppleScript string literal.
+fn applescript_string(text: &str) -> String {
+    format!("\"{}\"", text.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(id: i64, title: &str, employer: Option<&str>) -> Job {
+        Job {
+            id, employer_name: employer.map(String::from), title: title.to_string(),
+            status: "new".to_string(), ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_summarize() {
+        let mut jobs: Vec<(Job, f64)> = vec![
+            (job(1, "SRE", Some("Acme")), 72.0),
+            (job(2, "Staff SRE", Some("Globex")), 91.4),
+            (job(3, "Platform Engineer", None), 80.0),
+        ];
+        let n = summarize(&jobs, 75.0).unwrap();
+        assert_eq!(n.title, "hunt: 2 new jobs scoring 75+");
+        assert_eq!(n.body, "#2 Staff SRE at Globex (91)\n#3 Platform Engineer (80)");
+        assert_eq!(summarize(&jobs, 95.0), None);
+
+        jobs.extend((10..16).map(|id| (job(id, "DevOps", Some("Initech")), 76.0)));
+        let n = summarize(&jobs, 75.0).unwrap();
+        assert_eq!(n.body.lines().count(), MAX_LISTED + 1);
+        assert!(n.body.ends_with("...and 3 more"));
+        assert_eq!(summarize(&jobs[..1], 70.0).unwrap().title, "hunt: 1 new job scoring 70+");
+    }
+
+    #[test]
+    fn test_applescript_string() {
+        assert_eq!(applescript_string(r#"say "hi" \o/"#), r#""say \"hi\" \\o/""#);
+    }
+}
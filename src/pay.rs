@@ -0,0 +1,268 @@
+use std::sync::LazyLock;
+
+use regex::Regex;
+
+use crate::models::Job;
+
+/// How often an advertised amount is paid.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Period {
+    Hour,
+    Day,
+    Week,
+    Month,
+    Year,
+}
+
+impl Period {
+    /// Full-time units in a year: 40h weeks, 5-day weeks.
+    fn per_year(self) -> f64 {
+        match self {
+            Period::Hour => 2080.0,
+            Period::Day => 260.0,
+            Period::Week => 52.0,
+            Period::Month => 12.0,
+            Period::Year => 1.0,
+        }
+    }
+
+    /// The period a phrase like "/hr", "per month" or "annually" names.
+    fn from_words(words: &str) -> Option<Period> {
+        let lower = words.to_lowercase();
+        [
+            (Period::Hour, &["hour", "hr", "/h"][..]),
+            (Period::Day, &["day", "daily"]),
+            (Period::Week, &["week", "wk"]),
+            (Period::Month, &["month", "mo"]),
+            (Period::Year, &["year", "yr", "annum", "annual"]),
+        ]
+        .into_iter()
+        .find(|(_, words)| words.iter().any(|w| lower.contains(w)))
+        .map(|(period, _)| period)
+    }
+}
+
+/// Rough USD value of one unit of each currency. Only used to put postings on one
+/// scale for ranking; the original text is what gets shown.
+const USD_RATES: &[(&str, f64)] = &[
+    ("USD", 1.0), ("CAD", 0.73), ("AUD", 0.66), ("EUR", 1.08), ("GBP", 1.27), ("CHF", 1.13), ("INR", 0.012),
+];
+
+/// Annual USD outside this range is something else: a sign-on bonus, a funding round.
+const PLAUSIBLE_ANNUAL_USD: std::ops::RangeInclusive<f64> = 10_000.0..=2_000_000.0;
+
+const CURRENCY: &str = r"US\$|CA\$|C\$|AU\$|A\$|\$|€|£|₹|(?:USD|CAD|AUD|EUR|GBP|CHF|INR)\s?";
+const AMOUNT: &str = r"\d{1,3}(?:[,.]\d{3})+(?:\.\d{1,2})?|\d+(?:\.\d{1,2})?";
+const PER: &str = r"\s*(?:/\s*|per\s+|an?\s+)(?:hour|hr|h|day|week|wk|month|mo|year|yr|annum)\b|\s*(?:hourly|daily|weekly|monthly|annually|yearly)\b";
+
+/// An advertised pay range as the posting wrote it, and what it means.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Pay {
+    pub min: f64,
+    pub max: Option<f64>,
+    pub currency: &'static str,
+    pub period: Period,
+    pub text: String, // e.g. "€60.000 - €75.000 per year"
+}
+
+impl Pay {
+    /// The range in whole US dollars a year, for ranking.
+    pub fn annual_usd(&self) -> (Option<i64>, Option<i64>) {
+        let rate = USD_RATES.iter().find(|(code, _)| *code == self.currency).map_or(1.0, |(_, rate)| *rate);
+        let annual = |amount: f64| (amount * self.period.per_year() * rate).round() as i64;
+        (Some(annual(self.min)), self.max.map(annual))
+    }
+}
+
+fn currency_code(symbol: &str) -> &'static str {
+    match symbol.trim().to_uppercase().as_str() {
+        "CA$" | "C$" | "CAD" => "CAD",
+        "AU$" | "A$" | "AUD" => "AUD",
+        "€" | "EUR" => "EUR",
+        "£" | "GBP" => "GBP",
+        "₹" | "INR" => "INR",
+        "CHF" => "CHF",
+        _ => "USD",
+    }
+}
+
+/// "150,000", "60.000", "52.50" or "150", times an optional k or m.
+fn amount(digits: &str, suffix: Option<&str>) -> Option<f64> {
+    // Separators before three digits group thousands; a last one before fewer is a decimal point
+    let plain = match digits.rfind([',', '.']) {
+        Some(i) if digits.len() - i - 1 < 3 => format!("{}.{}", digits[..i].replace([',', '.'], ""), &digits[i + 1..]),
+        _ => digits.replace([',', '.'], ""),
+    };
+    let value: f64 = plain.parse().ok()?;
+    Some(match suffix.map(str::to_lowercase).as_deref() {
+        Some("k") => value * 1_000.0,
+        Some("m") => value * 1_000_000.0,
+        _ => value,
+    })
+}
+
+// Compiled once: every stored description goes through here, and email ingests add
+// jobs by the thousand
+static PAY_PATTERN: LazyLock<Regex> = LazyLock::new(|| {
+    Regex::new(&format!(
+        r"(?i)(?P<cur>{cur})\s?(?P<a>{amt})(?:\s?(?P<ka>[km]\b))?(?P<per1>{per})?(?:\s*(?:-|–|—|to)\s*(?:{cur})?\s?(?P<b>{amt})(?:\s?(?P<kb>[km]\b))?)?(?:\s?(?P<code>USD|CAD|AUD|EUR|GBP|CHF|INR)\b)?(?P<per>{per})?",
+        cur = CURRENCY, amt = AMOUNT, per = PER,
+    ))
+    .unwrap()
+});
+static EQUITY_PATTERN: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"(?i)\b(?:equity|stock options?|rsus?|restricted stock units?)\b").unwrap());
+
+/// The advertised pay in `text`: the first plausible range, else the first plausible
+/// single amount. Amounts under 1000 with no period or k are read as thousands from
+/// 100 up ("$120 - $160") and as hourly below that ("$45 - $65").
+pub fn parse(text: &str) -> Option<Pay> {
+    let mut single = None;
+    for caps in PAY_PATTERN.captures_iter(text) {
+        let group = |name: &str| caps.name(name).map(|m| m.as_str());
+        let currency = group("code").map_or_else(|| currency_code(group("cur").unwrap_or("$")), currency_code);
+        let (a, k_max) = (group("a").unwrap_or_default(), group("kb"));
+        // "$150-200k": the k on the max applies to a bare min too
+        let Some(mut min) = amount(a, group("ka").or(k_max.filter(|_| a.len() <= 3))) else { continue };
+        let mut max = group("b").and_then(|b| amount(b, k_max));
+
+        let whole = caps.get(0).unwrap();
+        let before = &text[text[..whole.start()].char_indices().rev().nth(40).map_or(0, |(i, _)| i)..whole.start()];
+        let stated = group("per1").or(group("per")).and_then(Period::from_words)
+            .or_else(|| ["hourly", "monthly", "daily", "weekly"].iter().find(|w| before.to_lowercase().contains(*w)).and_then(|w| Period::from_words(w)));
+        let period = match stated {
+            Some(period) => period,
+            None if group("ka").is_none() && k_max.is_none() && min < 1000.0 => {
+                if min >= 100.0 {
+                    min *= 1000.0;
+                    max = max.map(|m| if m < 1000.0 { m * 1000.0 } else { m });
+                    Period::Year
+                } else {
+                    Period::Hour
+                }
+            }
+            None => Period::Year,
+        };
+        if let Some(m) = max && m < min {
+            max = Some(min);
+            min = m;
+        }
+        let pay = Pay { min, max, currency, period, text: whole.as_str().trim().to_string() };
+        let (Some(low), high) = pay.annual_usd() else { continue };
+        if !PLAUSIBLE_ANNUAL_USD.contains(&(low as f64)) || high.is_some_and(|h| !PLAUSIBLE_ANNUAL_USD.contains(&(h as f64))) {
+            continue;
+        }
+        if pay.max.is_some() {
+            return Some(pay);
+        }
+        single.get_or_insert(pay);
+    }
+    single
+}
+
+/// Whether a posting offers equity: stock options, RSUs and the like.
+pub fn mentions_equity(text: &str) -> bool {
+    EQUITY_PATTERN.is_match(text)
+}
+
+/// Pay for detail views: the posting's own words with the annual USD it works out to,
+/// e.g. "$50/hr - $75/hr (~$104k - $156k USD/yr) + equity". Jobs stored before the
+/// wording was kept show the annual range alone.
+pub fn label(job: &Job) -> Option<String> {
+    let thousands = |amount: i64| format!("${}k", (amount as f64 / 1000.0).round());
+    let annual = match (job.pay_min, job.pay_max) {
+        (Some(min), Some(max)) => Some((format!("${} - ${}", min, max), format!("{} - {}", thousands(min), thousands(max)))),
+        (Some(min), None) => Some((format!("${}+", min), format!("{}+", thousands(min)))),
+        (None, Some(max)) => Some((format!("up to ${}", max), format!("up to {}", thousands(max)))),
+        (None, None) => None,
+    };
+    let pay = match (job.pay_text.as_deref(), annual) {
+        // Annual dollars need no translating
+        (Some(text), Some(_)) if parse(text).is_some_and(|p| p.currency == "USD" && p.period == Period::Year) => Some(text.to_string()),
+        (Some(text), Some((_, short))) => Some(format!("{} (~{} USD/yr)", text, short)),
+        (Some(text), None) => Some(text.to_string()),
+        (None, Some((long, _))) => Some(long),
+        (None, None) => None,
+    };
+    match (pay, job.pay_equity) {
+        (Some(pay), true) => Some(format!("{} + equity", pay)),
+        (None, true) => Some("equity".to_string()),
+        (pay, false) => pay,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn annual(text: &str) -> (Option<i64>, Option<i64>) {
+        parse(text).map_or((None, None), |pay| pay.annual_usd())
+    }
+
+    #[test]
+    fn test_parse_annual() {
+        assert_eq!(annual("Salary: $150,000 - $200,000 per year"), (Some(150_000), Some(200_000)));
+        assert_eq!(annual("$150k-200k"), (Some(150_000), Some(200_000)));
+        assert_eq!(annual("$150-200K + equity"), (Some(150_000), Some(200_000)));
+        assert_eq!(annual("$200k to $100k"), (Some(100_000), Some(200_000)));
+        assert_eq!(annual("Base pay $120 - $160"), (Some(120_000), Some(160_000)));
+        let pay = parse("Compensation: USD 180,000 – 220,000 annually").unwrap();
+        assert_eq!((pay.currency, pay.period, pay.text.as_str()), ("USD", Period::Year, "USD 180,000 – 220,000 annually"));
+    }
+
+    #[test]
+    fn test_parse_hourly_and_monthly() {
+        assert_eq!(annual("$50/hr - $75/hr"), (Some(104_000), Some(156_000)));
+        assert_eq!(annual("Pay: $52.50 per hour"), (Some(109_200), None));
+        assert_eq!(annual("Hourly rate: $45 - $65"), (Some(93_600), Some(135_200)));
+        assert_eq!(annual("$60 - $80"), (Some(124_800), Some(166_400)), "small bare amounts are hourly");
+        assert_eq!(annual("$9,000 - $11,000 a month"), (Some(108_000), Some(132_000)));
+    }
+
+    #[test]
+    fn test_parse_currencies() {
+        let pay = parse("€60.000 - €75.000 per year").unwrap();
+        assert_eq!((pay.currency, pay.min, pay.max), ("EUR", 60_000.0, Some(75_000.0)));
+        assert_eq!(pay.annual_usd(), (Some(64_800), Some(81_000)));
+        assert_eq!(parse("£80k - £95k").unwrap().currency, "GBP");
+        assert_eq!(parse("CA$120,000 - CA$140,000").unwrap().currency, "CAD");
+        assert_eq!(parse("$130,000 - $150,000 CAD").unwrap().annual_usd(), (Some(94_900), Some(109_500)));
+    }
+
+    #[test]
+    fn test_parse_skips_implausible_amounts() {
+        assert_eq!(annual("We raised $50M. Sign-on gift: $500. Salary $140,000 - $170,000."), (Some(140_000), Some(170_000)));
+        assert_eq!(annual("A $5,000 bonus on top of $165,000 - $185,000"), (Some(165_000), Some(185_000)), "ranges win");
+        assert_eq!(annual("Salary: $150,000"), (Some(150_000), None));
+        assert_eq!(annual("No salary information here"), (None, None));
+    }
+
+    #[test]
+    fn test_label() {
+        let mut job = Job {
+            id: 1, title: "SRE".to_string(), status: "new".to_string(), pay_min: Some(104_000),
+            pay_max: Some(156_000), pay_text: Some("$50/hr - $75/hr".to_string()), pay_equity: true,
+            ..Default::default()
+        };
+        assert_eq!(label(&job).as_deref(), Some("$50/hr - $75/hr (~$104k - $156k USD/yr) + equity"));
+
+        job.pay_text = Some("$104,000 - $156,000".to_string());
+        job.pay_equity = false;
+        assert_eq!(label(&job).as_deref(), Some("$104,000 - $156,000"));
+
+        job.pay_text = None;
+        assert_eq!(label(&job).as_deref(), Some("$104000 - $156000"), "older jobs keep the stored range");
+
+        job.pay_min = None;
+        job.pay_max = None;
+        assert_eq!(label(&job), None);
+    }
+
+    #[test]
+    fn test_mentions_equity() {
+        assert!(mentions_equity("Competitive salary plus equity"));
+        assert!(mentions_equity("Generous RSU grants"));
+        assert!(mentions_equity("stock options"));
+        assert!(!mentions_equity("Diversity, equitable hiring"));
+    }
+}
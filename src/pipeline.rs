@@ -0,0 +1,61 @@
+use clap::ValueEnum;
+
+/// `hunt refresh` stages, in the order they run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Stage {
+    Email,
+    Fetch,
+    Keywords,
+    Fit,
+}
+
+impl Stage {
+    pub const ALL: &[Stage] = &[Stage::Email, Stage::Fetch, Stage::Keywords, Stage::Fit];
+
+    /// The name recorded in `pipeline_run_steps`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Stage::Email => "email",
+            Stage::Fetch => "fetch",
+            Stage::Keywords => "keywords",
+            Stage::Fit => "fit",
+        }
+    }
+
+    pub fn title(self) -> &'static str {
+        match self {
+            Stage::Email => "Fetching job alerts from email",
+            Stage::Fetch => "Fetching job descriptions",
+            Stage::Keywords => "Extracting keywords",
+            Stage::Fit => "Analyzing fit",
+        }
+    }
+}
+
+/// The stages a refresh runs, in pipeline order: `only` if given, else everything but fit
+/// (which needs `with_fit`) minus `skip`.
+pub fn select(only: &[Stage], skip: &[Stage], with_fit: bool) -> Vec<Stage> {
+    Stage::ALL
+        .iter()
+        .copied()
+        .filter(|stage| {
+            if !only.is_empty() {
+                only.contains(stage)
+            } else {
+                !skip.contains(stage) && (*stage != Stage::Fit || with_fit)
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_select() {
+        assert_eq!(select(&[], &[], false), vec![Stage::Email, Stage::Fetch, Stage::Keywords]);
+        assert_eq!(select(&[], &[Stage::Email, Stage::Fetch], true), vec![Stage::Keywords, Stage::Fit]);
+        assert_eq!(select(&[Stage::Fit, Stage::Keywords], &[], false), vec![Stage::Keywords, Stage::Fit], "pipeline order");
+    }
+}
@@ -0,0 +1,240 @@
+use std::collections::HashMap;
+
+use anyhow::Result;
+use chrono::{DateTime, Duration, Utc};
+use reqwest::Url;
+use tracing::{debug, warn};
+
+use crate::db::Database;
+use crate::ratelimit;
+use crate::timestamps;
+
+/// Failures in a row before a domain is backed off.
+pub const BACKOFF_AFTER: i64 = 3;
+/// Longest a failing domain is skipped for.
+pub const MAX_BACKOFF_HOURS: i64 = 24;
+
+/// How long batches skip a domain after `streak` failures in a row: an hour at
+/// `BACKOFF_AFTER`, doubling with each further failure up to a day.
+pub fn backoff(streak: i64) -> Option<Duration> {
+    let doublings = streak.checked_sub(BACKOFF_AFTER).filter(|d| *d >= 0)?;
+    let hours = 1i64.checked_shl(doublings.min(62) as u32).unwrap_or(i64::MAX);
+    Some(Duration::hours(hours.min(MAX_BACKOFF_HOURS)))
+}
+
+type Rule = (bool, String); // (allow, path pattern)
+type Group = (Vec<String>, Vec<Rule>); // (user agents, rules)
+
+/// The rules of a robots.txt that apply to us: the `hunt` group if there is one, else `*`.
+#[derive(Debug, Default)]
+pub struct Robots {
+    rules: Vec<Rule>,
+}
+
+impl Robots {
+    pub fn parse(text: &str) -> Robots {
+        let mut groups: Vec<Group> = Vec::new();
+        let mut in_agents = false;
+        for line in text.lines() {
+            let line = line.split('#').next().unwrap_or("").trim();
+            let Some((field, value)) = line.split_once(':') else { continue };
+            let (field, value) = (field.trim().to_lowercase(), value.trim());
+            match field.as_str() {
+                "user-agent" => {
+                    // Consecutive user-agent lines share one group of rules
+                    match groups.last_mut() {
+                        Some((agents, _)) if in_agents => agents.push(value.to_lowercase()),
+                        _ => groups.push((vec![value.to_lowercase()], Vec::new())),
+                    }
+                    in_agents = true;
+                }
+                "allow" | "disallow" => {
+                    in_agents = false;
+                    // An empty Disallow allows everything, same as no rule
+                    if let (Some((_, rules)), false) = (groups.last_mut(), value.is_empty()) {
+                        rules.push((field == "allow", value.to_string()));
+                    }
+                }
+                _ => in_agents = false,
+            }
+        }
+        let rules_for = |agent: &str| {
+            let matching: Vec<&Group> = groups.iter().filter(|(agents, _)| agents.iter().any(|a| a == agent)).collect();
+            (!matching.is_empty()).then(|| matching.iter().flat_map(|(_, rules)| rules.clone()).collect())
+        };
+        Robots { rules: rules_for("hunt").or_else(|| rules_for("*")).unwrap_or_default() }
+    }
+
+    /// Whether `path` (with its query) may be fetched: the longest matching rule wins,
+    /// Allow on a tie.
+    pub fn allows(&self, path: &str) -> bool {
+        self.rules
+            .iter()
+            .filter(|(_, pattern)| pattern_matches(pattern, path))
+            .max_by_key(|(allow, pattern)| (pattern.len(), *allow))
+            .is_none_or(|(allow, _)| *allow)
+    }
+}
+
+/// robots.txt patterns: a path prefix where `*` matches anything and a trailing `$`
+/// anchors the end.
+fn pattern_matches(pattern: &str, path: &str) -> bool {
+    let (pattern, anchored) = match pattern.strip_suffix('$') {
+        Some(pattern) => (pattern, true),
+        None => (pattern, false),
+    };
+    let mut parts = pattern.split('*');
+    let Some(mut rest) = path.strip_prefix(parts.next().unwrap_or("")) else { return false };
+    let parts: Vec<&str> = parts.collect();
+    for (i, part) in parts.iter().enumerate() {
+        if anchored && i + 1 == parts.len() {
+            return rest.ends_with(part);
+        }
+        match rest.find(part) {
+            Some(at) => rest = &rest[at + part.len()..],
+            None => return false,
+        }
+    }
+    !anchored || rest.is_empty()
+}
+
+/// Whether a host is on the blocklist, by itself or as a subdomain of an entry.
+pub fn blocked(host: &str, blocklist: &[String]) -> bool {
+    let host = host.to_lowercase();
+    blocklist.iter().any(|entry| {
+        let entry = entry.trim().trim_start_matches("www.").to_lowercase();
+        host == entry || host.ends_with(&format!(".{}", entry))
+    })
+}
+
+/// What `fetch` and `refresh` check before requesting a posting: the config's
+/// `fetch_blocklist`, domain backoff (batches only) and robots.txt, fetched once per
+/// site and treated as allowing everything when it can't be read.
+pub struct FetchPolicy {
+    blocklist: Vec<String>,
+    check_robots: bool,
+    robots: HashMap<String, Robots>, // by origin
+}
+
+impl FetchPolicy {
+    pub fn new(blocklist: &[String], check_robots: bool) -> Self {
+        FetchPolicy { blocklist: blocklist.to_vec(), check_robots, robots: HashMap::new() }
+    }
+
+    /// Why `url` shouldn't be fetched now, if it shouldn't. Backoff only applies to
+    /// batches; asking for one job by ID fetches it anyway.
+    pub fn refusal(&mut self, db: &Database, url: &str, batch: bool) -> Result<Option<String>> {
+        let Ok(parsed) = Url::parse(url.trim()) else { return Ok(None) };
+        let Some(host) = parsed.host_str() else { return Ok(None) };
+        if blocked(host, &self.blocklist) {
+            return Ok(Some(format!("{} is on the fetch blocklist", host)));
+        }
+        if batch {
+            let domain = ratelimit::domain(url).unwrap_or_default();
+            let until = db.get_fetch_domain(&domain)?.and_then(|d| d.backoff_until);
+            if let Some(until) = until.filter(|until| backing_off(Some(until), Utc::now())) {
+                return Ok(Some(format!("{} keeps failing; backed off until {}", domain, timestamps::local(&until))));
+            }
+        }
+        if self.check_robots {
+            let origin = parsed.origin().ascii_serialization();
+            let robots = self.robots.entry(origin.clone()).or_insert_with(|| fetch_robots(&origin));
+            let path = match parsed.query() {
+                Some(query) => format!("{}?{}", parsed.path(), query),
+                None => parsed.path().to_string(),
+            };
+            if !robots.allows(&path) {
+                return Ok(Some(format!("{}/robots.txt disallows {}", origin, path)));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Count a fetch against its domain. Failures back the domain off once there are
+    /// `BACKOFF_AFTER` in a row; a success clears the streak.
+    pub fn record(&self, db: &Database, url: &str, error: Option<&anyhow::Error>) -> Result<()> {
+        let Some(domain) = ratelimit::domain(url) else { return Ok(()) };
+        let Some(error) = error else { return db.record_fetch_success(&domain) };
+        let streak = db.record_fetch_failure(&domain, &format!("{:#}", error))?;
+        if let Some(wait) = backoff(streak) {
+            let until = timestamps::to_db(Utc::now() + wait);
+            warn!("{} failed {} times in a row; skipping it until {}", domain, streak, timestamps::local(&until));
+            db.set_fetch_backoff(&domain, Some(&until))?;
+        }
+        Ok(())
+    }
+}
+
+fn fetch_robots(origin: &str) -> Robots {
+    let url = format!("{}/robots.txt", origin);
+    let response = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(10))
+        .build()
+        .and_then(|client| client.get(&url).send());
+    match response {
+        Ok(response) if response.status().is_success() => Robots::parse(&response.text().unwrap_or_default()),
+        Ok(response) => {
+            debug!("{}: HTTP {}, treating as allow-all", url, response.status());
+            Robots::default()
+        }
+        Err(e) => {
+            debug!("{}: {}, treating as allow-all", url, e);
+            Robots::default()
+        }
+    }
+}
+
+/// Whether a domain's backoff is still running at `now`.
+pub fn backing_off(backoff_until: Option<&str>, now: DateTime<Utc>) -> bool {
+    backoff_until.and_then(timestamps::parse).is_some_and(|until| until > now)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff() {
+        assert_eq!(backoff(2), None);
+        assert_eq!(backoff(3), Some(Duration::hours(1)));
+        assert_eq!(backoff(5), Some(Duration::hours(4)));
+        assert_eq!(backoff(9), Some(Duration::hours(24)), "capped at a day");
+        assert_eq!(backoff(200), Some(Duration::hours(24)));
+    }
+
+    #[test]
+    fn test_robots() {
+        let robots = Robots::parse(
+            "# comments are ignored\n\
+             User-agent: Googlebot\n\
+             Disallow:\n\
+             \n\
+             User-agent: *\n\
+             User-agent: otherbot\n\
+             Disallow: /private\n\
+             Disallow: /*.pdf$\n\
+             Allow: /private/jobs\n\
+             Disallow: /search?\n",
+        );
+        assert!(robots.allows("/jobs/123"));
+        assert!(!robots.allows("/private/notes"));
+        assert!(robots.allows("/private/jobs/123"), "longer Allow wins");
+        assert!(!robots.allows("/files/offer.pdf"));
+        assert!(robots.allows("/files/offer.pdf.html"), "$ anchors the end");
+        assert!(!robots.allows("/search?q=sre"));
+
+        let ours = Robots::parse("User-agent: *\nDisallow: /\n\nUser-agent: hunt\nAllow: /jobs\nDisallow: /\n");
+        assert!(ours.allows("/jobs/1"), "our own group replaces *");
+        assert!(!ours.allows("/feed"));
+        assert!(Robots::parse("").allows("/anything"));
+    }
+
+    #[test]
+    fn test_blocked() {
+        let blocklist = vec!["www.glassdoor.com".to_string(), "acme.com".to_string()];
+        assert!(blocked("www.glassdoor.com", &blocklist));
+        assert!(blocked("jobs.acme.com", &blocklist));
+        assert!(!blocked("notacme.com", &blocklist));
+        assert!(!blocked("boards.greenhouse.io", &blocklist));
+    }
+}
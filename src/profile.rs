@@ -0,0 +1,182 @@
+use std::fs;
+use std::path::PathBuf;
+use std::sync::OnceLock;
+
+use anyhow::{Context, Result};
+
+use crate::error::HuntError;
+
+/// The profile that lives directly in hunt's data and config directories.
+pub const DEFAULT: &str = "default";
+
+static ACTIVE: OnceLock<String> = OnceLock::new();
+
+/// Where profiles live. The default profile is hunt's data and config directories
+/// themselves; a named one is `profiles/<name>` under each, with its own `hunt.db`
+/// (and so its own base resumes) and `config.json` (email accounts, default model).
+pub struct Profiles {
+    data_dir: PathBuf,
+    config_dir: PathBuf,
+}
+
+impl Profiles {
+    /// The XDG directories, or the current directory if there's no home to put them in.
+    pub fn xdg() -> Self {
+        match directories::ProjectDirs::from("", "", "hunt") {
+            Some(dirs) => Self::at(dirs.data_dir().to_path_buf(), dirs.config_dir().to_path_buf()),
+            None => Self::at(PathBuf::from("."), PathBuf::from(".")),
+        }
+    }
+
+    pub fn at(data_dir: PathBuf, config_dir: PathBuf) -> Self {
+        Self { data_dir, config_dir }
+    }
+
+    pub fn data_dir(&self, name: &str) -> PathBuf {
+        if name == DEFAULT { self.data_dir.clone() } else { self.data_dir.join("profiles").join(name) }
+    }
+
+    pub fn config_dir(&self, name: &str) -> PathBuf {
+        if name == DEFAULT { self.config_dir.clone() } else { self.config_dir.join("profiles").join(name) }
+    }
+
+    pub fn db_path(&self, name: &str) -> PathBuf {
+        self.data_dir(name).join("hunt.db")
+    }
+
+    pub fn exists(&self, name: &str) -> bool {
+        name == DEFAULT || self.data_dir(name).is_dir()
+    }
+
+    /// All profiles, the default first.
+    pub fn list(&self) -> Result<Vec<String>> {
+        let mut names = Vec::new();
+        match fs::read_dir(self.data_dir.join("profiles")) {
+            Ok(entries) => {
+                for entry in entries {
+                    let entry = entry?;
+                    if entry.file_type()?.is_dir() {
+                        names.push(entry.file_name().to_string_lossy().into_owned());
+                    }
+                }
+            }
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => {}
+            Err(e) => return Err(e).context("Failed to list profiles"),
+        }
+        names.sort();
+        names.insert(0, DEFAULT.to_string());
+        Ok(names)
+    }
+
+    pub fn create(&self, name: &str) -> Result<()> {
+        validate_name(name)?;
+        if self.exists(name) {
+            return Err(HuntError::Validation(format!("Profile '{}' already exists", name)).into());
+        }
+        fs::create_dir_all(self.data_dir(name))?;
+        fs::create_dir_all(self.config_dir(name))?;
+        Ok(())
+    }
+
+    /// `<config dir>/profile`: the name `hunt profile switch` saved.
+    fn current_file(&self) -> PathBuf {
+        self.config_dir.join("profile")
+    }
+
+    /// The profile used when `--profile` isn't given.
+    pub fn current(&self) -> Result<String> {
+        match fs::read_to_string(self.current_file()) {
+            Ok(text) if !text.trim().is_empty() => Ok(text.trim().to_string()),
+            Ok(_) => Ok(DEFAULT.to_string()),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(DEFAULT.to_string()),
+            Err(e) => Err(e).with_context(|| format!("Failed to read {:?}", self.current_file())),
+        }
+    }
+
+    pub fn switch(&self, name: &str) -> Result<()> {
+        self.check_exists(name)?;
+        fs::create_dir_all(&self.config_dir)?;
+        fs::write(self.current_file(), format!("{}\n", name))?;
+        Ok(())
+    }
+
+    /// `--profile` if given, else the current profile. Either must exist.
+    pub fn resolve(&self, flag: Option<&str>) -> Result<String> {
+        let name = match flag {
+            Some(name) => name.to_string(),
+            None => self.current()?,
+        };
+        self.check_exists(&name)?;
+        Ok(name)
+    }
+
+    /// Validates the name first, so `--profile ..` can't point outside `profiles/`.
+    fn check_exists(&self, name: &str) -> Result<()> {
+        validate_name(name)?;
+        if self.exists(name) {
+            Ok(())
+        } else {
+            Err(HuntError::NotFound(format!("Profile '{}' not found. Create it with 'hunt profile create {}'", name, name)).into())
+        }
+    }
+}
+
+/// Profile names become directory names: letters, digits, '-' and '_'.
+pub fn validate_name(name: &str) -> Result<()> {
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_') {
+        return Err(HuntError::Validation(format!(
+            "Invalid profile name '{}': use letters, digits, '-' and '_'", name
+        )).into());
+    }
+    Ok(())
+}
+
+/// Set the profile for this run. Called once, before anything reads the config.
+pub fn set_active(name: &str) {
+    let _ = ACTIVE.set(name.to_string());
+}
+
+/// The profile for this run.
+pub fn active() -> &'static str {
+    ACTIVE.get().map_or(DEFAULT, String::as_str)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profiles() {
+        let root = std::env::temp_dir().join(format!("hunt-profile-test-{}", std::process::id()));
+        let profiles = Profiles::at(root.join("data"), root.join("config"));
+
+        assert_eq!(profiles.list().unwrap(), vec!["default"]);
+        assert_eq!(profiles.resolve(None).unwrap(), "default");
+        assert_eq!(profiles.db_path(DEFAULT), root.join("data/hunt.db"));
+
+        profiles.create("contracting").unwrap();
+        profiles.create("fulltime").unwrap();
+        assert_eq!(profiles.list().unwrap(), vec!["default", "contracting", "fulltime"]);
+        assert_eq!(profiles.db_path("contracting"), root.join("data/profiles/contracting/hunt.db"));
+        assert!(profiles.config_dir("contracting").is_dir());
+
+        let err = profiles.create("contracting").unwrap_err();
+        assert!(matches!(err.downcast_ref::<HuntError>(), Some(HuntError::Validation(_))));
+        assert!(profiles.create("../escape").is_err());
+        assert!(profiles.create("default").is_err());
+
+        profiles.switch("contracting").unwrap();
+        assert_eq!(profiles.resolve(None).unwrap(), "contracting");
+        assert_eq!(profiles.resolve(Some("fulltime")).unwrap(), "fulltime", "flag wins");
+        fs::create_dir_all(root.join("data/elsewhere")).unwrap();
+        let err = profiles.resolve(Some("../elsewhere")).unwrap_err();
+        assert!(matches!(err.downcast_ref::<HuntError>(), Some(HuntError::Validation(_))));
+        assert!(profiles.resolve(Some("..")).is_err());
+        let err = profiles.switch("nope").unwrap_err();
+        assert!(matches!(err.downcast_ref::<HuntError>(), Some(HuntError::NotFound(_))));
+        profiles.switch(DEFAULT).unwrap();
+        assert_eq!(profiles.resolve(None).unwrap(), "default");
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
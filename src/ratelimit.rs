@@ -0,0 +1,119 @@
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+use reqwest::Url;
+
+use crate::domains;
+
+/// Seconds between requests to a domain, unless the config's `rate_limits` overrides it.
+/// Other domains wait `--delay` after a browser fetch, a second after a plain one.
+pub const DEFAULT_BUDGETS: &[(&str, u64)] = &[
+    ("linkedin.com", 30),
+    ("indeed.com", 30),
+    ("glassdoor.com", 30),
+    ("myworkdayjobs.com", 5),
+    ("greenhouse.io", 2),
+    ("lever.co", 2),
+    ("ashbyhq.com", 2),
+];
+
+/// The registrable domain a URL's requests count against (boards.greenhouse.io and
+/// job-boards.greenhouse.io share greenhouse.io's budget).
+pub fn domain(url: &str) -> Option<String> {
+    let url = Url::parse(url.trim()).ok()?;
+    Some(domains::registrable_domain(url.host_str()?))
+}
+
+/// Per-domain pacing for batch fetches: each domain gets its own "next request not
+/// before" time, so waiting on LinkedIn doesn't hold up a Greenhouse posting.
+pub struct RateLimiter {
+    budgets: HashMap<String, u64>,
+    next: HashMap<String, Instant>,
+}
+
+impl RateLimiter {
+    pub fn new(configured: &HashMap<String, u64>) -> Self {
+        let mut budgets: HashMap<String, u64> =
+            DEFAULT_BUDGETS.iter().map(|(domain, secs)| (domain.to_string(), *secs)).collect();
+        for (name, secs) in configured {
+            budgets.insert(domains::registrable_domain(&name.to_lowercase()), *secs);
+        }
+        RateLimiter { budgets, next: HashMap::new() }
+    }
+
+    /// Seconds between requests to the URL's domain, if it has a budget.
+    pub fn budget(&self, url: &str) -> Option<u64> {
+        self.budgets.get(&domain(url)?).copied()
+    }
+
+    /// How long to wait before requesting `url`.
+    pub fn wait(&self, url: &str, now: Instant) -> Duration {
+        domain(url)
+            .and_then(|domain| self.next.get(&domain))
+            .map_or(Duration::ZERO, |next| next.saturating_duration_since(now))
+    }
+
+    /// Note a request to `url` made at `now`; its domain is free again after `pause`.
+    pub fn record(&mut self, url: &str, pause: Duration, now: Instant) {
+        if let Some(domain) = domain(url) {
+            self.next.insert(domain, now + pause);
+        }
+    }
+}
+
+/// Reorder items round-robin by domain, keeping each domain's own order, so consecutive
+/// requests go to different hosts while one waits out its budget. Items without a
+/// parseable URL count as one more domain.
+pub fn interleave<T>(items: Vec<T>, url: impl Fn(&T) -> Option<&str>) -> Vec<T> {
+    let mut queues: Vec<(Option<String>, Vec<T>)> = Vec::new();
+    for item in items {
+        let key = url(&item).and_then(domain);
+        match queues.iter_mut().find(|(k, _)| *k == key) {
+            Some((_, queue)) => queue.push(item),
+            None => queues.push((key, vec![item])),
+        }
+    }
+    let mut queues: Vec<std::vec::IntoIter<T>> = queues.into_iter().map(|(_, q)| q.into_iter()).collect();
+    let mut ordered = Vec::new();
+    loop {
+        let before = ordered.len();
+        ordered.extend(queues.iter_mut().filter_map(Iterator::next));
+        if ordered.len() == before {
+            return ordered;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rate_limiter_is_per_domain() {
+        let configured = HashMap::from([("www.LinkedIn.com".to_string(), 60)]);
+        let mut limiter = RateLimiter::new(&configured);
+        assert_eq!(limiter.budget("https://www.linkedin.com/jobs/view/1"), Some(60), "config overrides the default");
+        assert_eq!(limiter.budget("https://job-boards.greenhouse.io/acme/jobs/1"), Some(2));
+        assert_eq!(limiter.budget("https://careers.acme.com/jobs/1"), None);
+
+        let now = Instant::now();
+        limiter.record("https://www.linkedin.com/jobs/view/1", Duration::from_secs(60), now);
+        let later = now + Duration::from_secs(20);
+        assert_eq!(limiter.wait("https://linkedin.com/jobs/view/2", later), Duration::from_secs(40));
+        assert_eq!(limiter.wait("https://boards.greenhouse.io/acme/jobs/1", later), Duration::ZERO);
+        assert_eq!(limiter.wait("https://www.linkedin.com/jobs/view/3", now + Duration::from_secs(90)), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_interleave() {
+        let urls = [
+            Some("https://www.linkedin.com/jobs/view/1"),
+            Some("https://www.linkedin.com/jobs/view/2"),
+            Some("https://boards.greenhouse.io/acme/jobs/3"),
+            None,
+            Some("https://www.linkedin.com/jobs/view/4"),
+        ];
+        let ordered = interleave((0..urls.len()).collect(), |i| urls[*i]);
+        assert_eq!(ordered, vec![0, 2, 3, 1, 4]);
+    }
+}
@@ -0,0 +1,128 @@
+use anyhow::{Context, Result};
+use std::path::Path;
+use std::process::Command;
+
+use crate::error::HuntError;
+
+/// External tool that turns a resume variant into a PDF.
+#[derive(Debug, PartialEq)]
+pub enum Renderer {
+    Pdflatex,
+    Pandoc { pdf_engine: &'static str },
+}
+
+impl std::fmt::Display for Renderer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Renderer::Pdflatex => write!(f, "pdflatex"),
+            Renderer::Pandoc { pdf_engine } => write!(f, "pandoc ({})", pdf_engine),
+        }
+    }
+}
+
+/// PDF engines pandoc can drive, in order of preference.
+const PANDOC_ENGINES: &[&str] = &["pdflatex", "typst", "wkhtmltopdf"];
+
+/// Pick a renderer for a variant's `output_format` given which programs are installed.
+pub fn choose_renderer(format: &str, installed: impl Fn(&str) -> bool) -> Result<Renderer> {
+    if format == "latex" {
+        return if installed("pdflatex") {
+            Ok(Renderer::Pdflatex)
+        } else {
+            Err(HuntError::Config("pdflatex not found. Install TeX Live (e.g. texlive-latex-recommended) to render LaTeX resumes".to_string()).into())
+        };
+    }
+    if !installed("pandoc") {
+        return Err(HuntError::Config("pandoc not found. Install pandoc (plus pdflatex or typst) to render markdown resumes".to_string()).into());
+    }
+    PANDOC_ENGINES
+        .iter()
+        .find(|engine| installed(engine))
+        .map(|&pdf_engine| Renderer::Pandoc { pdf_engine })
+        .ok_or_else(|| {
+            HuntError::Config("pandoc needs a PDF engine: install pdflatex, typst or wkhtmltopdf".to_string()).into()
+        })
+}
+
+/// AI output sometimes wraps the whole document in a ``` fence; drop it so the
+/// content compiles.
+pub fn strip_code_fence(content: &str) -> &str {
+    let trimmed = content.trim();
+    let Some(rest) = trimmed.strip_prefix("```") else { return content };
+    let Some(body) = rest.strip_suffix("```") else { return content };
+    // Skip the language tag line (```latex)
+    body.split_once('\n').map_or(body, |(_, body)| body).trim_matches('\n')
+}
+
+/// Render `content` to a PDF at `output`, working in a scratch directory.
+pub fn render_pdf(content: &str, renderer: &Renderer, output: &Path) -> Result<()> {
+    let dir = std::env::temp_dir().join(format!("hunt-render-{}", std::process::id()));
+    std::fs::create_dir_all(&dir).with_context(|| format!("Failed to create {}", dir.display()))?;
+    let result = run_renderer(strip_code_fence(content), renderer, &dir, output);
+    let _ = std::fs::remove_dir_all(&dir);
+    result
+}
+
+fn run_renderer(content: &str, renderer: &Renderer, dir: &Path, output: &Path) -> Result<()> {
+    let (program, source, built) = match renderer {
+        Renderer::Pdflatex => ("pdflatex", dir.join("resume.tex"), dir.join("resume.pdf")),
+        Renderer::Pandoc { .. } => ("pandoc", dir.join("resume.md"), dir.join("resume.pdf")),
+    };
+    std::fs::write(&source, content).with_context(|| format!("Failed to write {}", source.display()))?;
+
+    let mut command = Command::new(program);
+    match renderer {
+        Renderer::Pdflatex => {
+            command
+                .args(["-interaction=nonstopmode", "-halt-on-error", "-output-directory"])
+                .arg(dir)
+                .arg(&source);
+        }
+        Renderer::Pandoc { pdf_engine } => {
+            command.arg(&source).arg("-o").arg(&built).arg(format!("--pdf-engine={}", pdf_engine));
+            if *pdf_engine == "pdflatex" {
+                command.args(["-V", "geometry:margin=0.75in"]);
+            }
+        }
+    }
+    let out = command.output().with_context(|| format!("Failed to run {}", program))?;
+    if !out.status.success() || !built.exists() {
+        // pdflatex reports errors on stdout, pandoc on stderr
+        let log = format!("{}{}", String::from_utf8_lossy(&out.stdout), String::from_utf8_lossy(&out.stderr));
+        let lines: Vec<&str> = log.lines().filter(|l| !l.trim().is_empty()).collect();
+        let tail = lines[lines.len().saturating_sub(8)..].join("\n");
+        return Err(HuntError::Validation(format!("{} could not render this variant:\n{}", program, tail)).into());
+    }
+
+    std::fs::copy(&built, output).with_context(|| format!("Failed to write {}", output.display()))?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_choose_renderer() {
+        let only = |tools: &'static [&'static str]| move |name: &str| tools.contains(&name);
+        assert_eq!(choose_renderer("latex", only(&["pdflatex"])).unwrap(), Renderer::Pdflatex);
+        assert!(choose_renderer("latex", only(&["pandoc", "typst"])).is_err());
+        assert_eq!(
+            choose_renderer("markdown", only(&["pandoc", "typst"])).unwrap(),
+            Renderer::Pandoc { pdf_engine: "typst" }
+        );
+        assert_eq!(
+            choose_renderer("markdown", only(&["pandoc", "typst", "pdflatex"])).unwrap(),
+            Renderer::Pandoc { pdf_engine: "pdflatex" }
+        );
+        assert!(choose_renderer("markdown", only(&["pandoc"])).is_err());
+        assert!(choose_renderer("markdown", only(&["pdflatex"])).is_err());
+    }
+
+    #[test]
+    fn test_strip_code_fence() {
+        assert_eq!(strip_code_fence("```latex\n\\documentclass{article}\n```\n"), "\\documentclass{article}");
+        assert_eq!(strip_code_fence("# Jane Doe\n\nSRE"), "# Jane Doe\n\nSRE");
+        assert_eq!(strip_code_fence("```\nplain\n```"), "plain");
+    }
+}
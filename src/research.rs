@@ -0,0 +1,168 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+
+use crate::error::HuntError;
+use crate::models::HnStory;
+
+const HN_SEARCH_URL: &str = "https://hn.algolia.com/api/v1/search";
+const HN_ITEM_URL: &str = "https://news.ycombinator.com/item?id=";
+
+/// How many of the most relevant stories to keep per employer.
+pub const HN_TOP_STORIES: usize = 5;
+
+#[derive(Debug, Default)]
+pub struct StartupResearchData {
+    pub crunchbase_url: Option<String>,
+    pub funding_stage: Option<String>,
+    pub total_funding: Option<i64>,
+    pub last_funding_date: Option<String>,
+    pub yc_batch: Option<String>,
+    pub yc_url: Option<String>,
+    pub hn_mentions_count: Option<i64>,
+    pub hn_stories: Vec<HnStory>,
+    pub recent_news: Option<String>,
+}
+
+pub fn research_startup(name: &str) -> Result<StartupResearchData> {
+    let mut data = StartupResearchData::default();
+
+    // Research YC companies
+    if let Ok(yc_info) = search_yc_company(name) {
+        data.yc_batch = yc_info.batch;
+        data.yc_url = yc_info.url;
+    }
+
+    // HN is the one real source so far, so don't swallow its failures
+    let hn = search_hn(name)?;
+    data.hn_mentions_count = Some(hn.mentions);
+    data.hn_stories = hn.stories;
+
+    // Note: Crunchbase requires API access or scraping, which is more complex
+    // For now, we'll leave this as a placeholder for future implementation
+    // data.crunchbase_url = search_crunchbase(name)?;
+
+    Ok(data)
+}
+
+#[derive(Debug)]
+pub struct YCCompanyInfo {
+    pub batch: Option<String>,
+    pub url: Option<String>,
+}
+
+pub fn search_yc_company(_name: &str) -> Result<YCCompanyInfo> {
+    // YC has a companies list at https://www.ycombinator.com/companies
+    // For now, this is a stub implementation that could be enhanced with actual API/scraping
+    // TODO: Implement actual YC company search
+    Ok(YCCompanyInfo {
+        batch: None,
+        url: None,
+    })
+}
+
+/// Hacker News mentions of a company: how many stories and comments match the exact
+/// name, and the most relevant stories.
+#[derive(Debug, Default)]
+pub struct HnSearch {
+    pub mentions: i64,
+    pub stories: Vec<HnStory>,
+}
+
+// --- HN Algolia: GET /api/v1/search?query=...&tags=story ---
+
+#[derive(Deserialize)]
+struct AlgoliaResponse {
+    #[serde(rename = "nbHits")]
+    nb_hits: i64,
+    hits: Vec<AlgoliaHit>,
+}
+
+#[derive(Deserialize)]
+struct AlgoliaHit {
+    #[serde(rename = "objectID")]
+    object_id: String,
+    title: Option<String>,
+    url: Option<String>,
+    points: Option<i64>,
+    num_comments: Option<i64>,
+    created_at: Option<String>,
+}
+
+/// Search HN via the Algolia API: one request for the top stories, one for the
+/// total count of matching stories and comments.
+pub fn search_hn(name: &str) -> Result<HnSearch> {
+    let client = reqwest::blocking::Client::builder()
+        .timeout(std::time::Duration::from_secs(30))
+        .build()?;
+    // Quoted so "Acme Labs" doesn't match every "acme" and every "labs"
+    let phrase = format!("\"{}\"", name.trim());
+    let top = HN_TOP_STORIES.to_string();
+    let fetch = |tags: &str, hits: &str| -> Result<AlgoliaResponse> {
+        let response = client
+            .get(HN_SEARCH_URL)
+            .query(&[("query", phrase.as_str()), ("tags", tags), ("hitsPerPage", hits)])
+            .send()
+            .context("Failed to reach the HN Algolia API")?;
+        if !response.status().is_success() {
+            return Err(HuntError::Network(format!("HN Algolia request failed with status {}", response.status())).into());
+        }
+        parse_algolia(&response.text()?)
+    };
+
+    let stories = fetch("story", &top)?;
+    let all = fetch("(story,comment)", "0")?;
+    Ok(HnSearch {
+        mentions: all.nb_hits,
+        stories: stories.hits.into_iter().filter_map(hit_to_story).collect(),
+    })
+}
+
+fn parse_algolia(json: &str) -> Result<AlgoliaResponse> {
+    serde_json::from_str(json).context("Unexpected response from the HN Algolia API")
+}
+
+fn hit_to_story(hit: AlgoliaHit) -> Option<HnStory> {
+    Some(HnStory {
+        hn_url: format!("{}{}", HN_ITEM_URL, hit.object_id),
+        title: hit.title.filter(|t| !t.is_empty())?,
+        url: hit.url.filter(|u| !u.is_empty()),
+        points: hit.points.unwrap_or(0),
+        num_comments: hit.num_comments.unwrap_or(0),
+        posted_at: hit.created_at,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_search_yc_company_returns_none() {
+        let info = search_yc_company("Test Corp").unwrap();
+        assert!(info.batch.is_none());
+        assert!(info.url.is_none());
+    }
+
+    #[test]
+    fn test_parse_algolia_stories() {
+        let json = r#"{
+            "nbHits": 42,
+            "hits": [
+                {"objectID": "101", "title": "Acme raises $20M Series A", "url": "https://acme.com/blog/a",
+                 "points": 312, "num_comments": 140, "created_at": "2025-03-01T12:00:00.000Z"},
+                {"objectID": "102", "title": "Ask HN: Anyone worked at Acme?", "url": null,
+                 "points": null, "num_comments": 7, "created_at": "2025-04-01T12:00:00.000Z"},
+                {"objectID": "103", "title": null, "url": null}
+            ]
+        }"#;
+        let response = parse_algolia(json).unwrap();
+        assert_eq!(response.nb_hits, 42);
+        let stories: Vec<HnStory> = response.hits.into_iter().filter_map(hit_to_story).collect();
+        assert_eq!(stories.len(), 2, "hits without a title are dropped");
+        assert_eq!(stories[0].points, 312);
+        assert_eq!(stories[0].hn_url, "https://news.ycombinator.com/item?id=101");
+        assert_eq!(stories[1].url, None);
+        assert_eq!(stories[1].points, 0);
+        assert!(parse_algolia("<html>").is_err());
+    }
+}
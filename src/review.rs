@@ -0,0 +1,96 @@
+/// Spaced-repetition scheduling for `hunt review`, a simplified SM-2: each card has
+/// an interval that grows by its ease factor on every successful review.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CardState {
+    pub interval_days: f64,
+    pub ease: f64,
+    pub reps: i64, // successful reviews in a row
+}
+
+impl Default for CardState {
+    fn default() -> Self {
+        Self { interval_days: 0.0, ease: 2.5, reps: 0 }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Grade {
+    Again,
+    Hard,
+    Good,
+    Easy,
+}
+
+impl Grade {
+    /// 1-4 as typed at the review prompt.
+    pub fn from_input(input: &str) -> Option<Self> {
+        match input.trim() {
+            "1" => Some(Grade::Again),
+            "2" => Some(Grade::Hard),
+            "3" => Some(Grade::Good),
+            "4" => Some(Grade::Easy),
+            _ => None,
+        }
+    }
+}
+
+const MIN_EASE: f64 = 1.3;
+
+/// The card's state after answering with `grade`; `interval_days` is when it's due next.
+pub fn next_state(state: CardState, grade: Grade) -> CardState {
+    let good_interval = match state.reps {
+        0 => 1.0,
+        1 => 3.0,
+        _ => state.interval_days * state.ease,
+    };
+    let (interval_days, ease, reps) = match grade {
+        // Forgotten: start over tomorrow
+        Grade::Again => (1.0, state.ease - 0.2, 0),
+        Grade::Hard => ((state.interval_days * 1.2).max(1.0), state.ease - 0.15, state.reps + 1),
+        Grade::Good => (good_interval, state.ease, state.reps + 1),
+        Grade::Easy => (good_interval * 1.3, state.ease + 0.15, state.reps + 1),
+    };
+    CardState { interval_days, ease: ease.max(MIN_EASE), reps }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_good_answers_grow_the_interval() {
+        let s = next_state(CardState::default(), Grade::Good);
+        assert_eq!((s.interval_days, s.reps), (1.0, 1));
+        let s = next_state(s, Grade::Good);
+        assert_eq!(s.interval_days, 3.0);
+        let s = next_state(s, Grade::Good);
+        assert_eq!(s.interval_days, 7.5);
+        assert_eq!(s.ease, 2.5);
+    }
+
+    #[test]
+    fn test_again_resets_and_lowers_ease() {
+        let learned = CardState { interval_days: 20.0, ease: 2.5, reps: 4 };
+        let s = next_state(learned, Grade::Again);
+        assert_eq!((s.interval_days, s.reps), (1.0, 0));
+        assert!((s.ease - 2.3).abs() < 1e-9);
+
+        let floor = next_state(CardState { interval_days: 1.0, ease: 1.3, reps: 0 }, Grade::Again);
+        assert_eq!(floor.ease, MIN_EASE);
+    }
+
+    #[test]
+    fn test_hard_and_easy() {
+        let learned = CardState { interval_days: 10.0, ease: 2.0, reps: 3 };
+        assert_eq!(next_state(learned, Grade::Hard).interval_days, 12.0);
+        let easy = next_state(learned, Grade::Easy);
+        assert_eq!(easy.interval_days, 26.0);
+        assert!((easy.ease - 2.15).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_grade_from_input() {
+        assert_eq!(Grade::from_input(" 3\n"), Some(Grade::Good));
+        assert_eq!(Grade::from_input("5"), None);
+    }
+}
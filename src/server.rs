@@ -0,0 +1,429 @@
+use anyhow::Result;
+use percent_encoding::percent_decode_str;
+use reqwest::Url;
+use serde::Deserialize;
+use serde_json::{Value, json};
+use tiny_http::{Header, HeaderField, Method, Response, Server};
+use tracing::{info, warn};
+
+use crate::db::{self, Database};
+use crate::error::{self, HuntError};
+use crate::models::SavedFilter;
+use crate::stats;
+use crate::templates;
+
+/// A JSON reply: the status code and body `handle` settled on.
+#[derive(Debug)]
+pub struct Reply {
+    pub status: u16,
+    pub body: Value,
+}
+
+impl Reply {
+    fn ok(body: Value) -> Reply {
+        Reply { status: 200, body }
+    }
+
+    /// Errors use the CLI's `--json` shape: {"error": {"kind", "exit_code", "message"}}.
+    fn error(err: &anyhow::Error) -> Reply {
+        let report = error::report(err);
+        let status = match report.kind {
+            "not_found" => 404,
+            "validation" => 400,
+            "auth" => 401,
+            _ => 500,
+        };
+        Reply { status, body: json!({ "error": report }) }
+    }
+}
+
+#[derive(Deserialize)]
+struct StatusChange {
+    status: String,
+    #[serde(default)]
+    force: bool,
+}
+
+#[derive(Deserialize)]
+struct NewNote {
+    note: String,
+}
+
+/// Query string values by name, for the filters list-style endpoints take.
+struct Query(Vec<(String, String)>);
+
+impl Query {
+    fn get(&self, name: &str) -> Option<String> {
+        self.0.iter().find(|(k, _)| k == name).map(|(_, v)| v.clone()).filter(|v| !v.is_empty())
+    }
+
+    fn flag(&self, name: &str) -> bool {
+        self.get(name).is_some_and(|v| matches!(v.as_str(), "1" | "true" | "yes"))
+    }
+
+    /// A parameter that may be absent, true (1/true/yes) or false (0/false/no).
+    fn boolean(&self, name: &str) -> Result<Option<bool>> {
+        match self.get(name).as_deref() {
+            None => Ok(None),
+            Some("1" | "true" | "yes") => Ok(Some(true)),
+            Some("0" | "false" | "no") => Ok(Some(false)),
+            Some(v) => Err(HuntError::Validation(format!("'{}' must be true or false, got '{}'", name, v)).into()),
+        }
+    }
+
+    fn number<T: std::str::FromStr>(&self, name: &str) -> Result<Option<T>> {
+        self.get(name)
+            .map(|v| v.parse().map_err(|_| HuntError::Validation(format!("'{}' must be a number, got '{}'", name, v)).into()))
+            .transpose()
+    }
+}
+
+fn parse_body<'a, T: Deserialize<'a>>(body: &'a str) -> Result<T> {
+    serde_json::from_str(body).map_err(|e| HuntError::Validation(format!("Invalid JSON body: {}", e)).into())
+}
+
+fn job_id(segment: &str) -> Result<i64> {
+    segment.parse().map_err(|_| HuntError::Validation(format!("'{}' is not a job ID", segment)).into())
+}
+
+/// Answer one API request. `url` is the request target as received ("/api/jobs?status=new").
+pub fn handle(db: &Database, method: &Method, url: &str, body: &str) -> Reply {
+    let Ok(parsed) = Url::parse(&format!("http://localhost{}", url)) else {
+        return Reply::error(&HuntError::Validation(format!("Bad request target '{}'", url)).into());
+    };
+    let segments: Vec<String> = parsed
+        .path_segments()
+        // Path segments arrive percent-encoded ("Acme%20Corp")
+        .map(|s| s.filter(|s| !s.is_empty()).map(|s| percent_decode_str(s).decode_utf8_lossy().into_owned()).collect())
+        .unwrap_or_default();
+    let query = Query(parsed.query_pairs().map(|(k, v)| (k.into_owned(), v.into_owned())).collect());
+    let segments: Vec<&str> = segments.iter().map(String::as_str).collect();
+    match route(db, method, &segments, &query, body) {
+        Ok(reply) => reply,
+        Err(e) => Reply::error(&e),
+    }
+}
+
+fn route(db: &Database, method: &Method, segments: &[&str], query: &Query, body: &str) -> Result<Reply> {
+    let not_found = || -> Result<Reply> { Err(HuntError::NotFound(format!("No endpoint {} /{}", method, segments.join("/"))).into()) };
+    let Some(("api", segments)) = segments.split_first().map(|(first, rest)| (*first, rest)) else { return not_found() };
+    match (method, segments) {
+        (Method::Get, ["jobs"]) => list_jobs(db, query),
+        (Method::Get, ["jobs", id]) => {
+            let id = job_id(id)?;
+            let job = db.get_job(id)?.ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", id)))?;
+            Ok(Reply::ok(json!(templates::job_context(db, job)?)))
+        }
+        (Method::Post, ["jobs", id, "status"]) => {
+            let id = job_id(id)?;
+            let change: StatusChange = parse_body(body)?;
+            db.set_job_status(id, &change.status, change.force)?;
+            let job = db.get_job(id)?.ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", id)))?;
+            Ok(Reply::ok(json!(job)))
+        }
+        (Method::Get, ["jobs", id, "notes"]) => {
+            let id = job_id(id)?;
+            db.get_job(id)?.ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", id)))?;
+            Ok(Reply::ok(json!(db.list_job_notes(id)?)))
+        }
+        (Method::Post, ["jobs", id, "notes"]) => {
+            let id = job_id(id)?;
+            db.get_job(id)?.ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", id)))?;
+            let new: NewNote = parse_body(body)?;
+            if new.note.trim().is_empty() {
+                return Err(HuntError::Validation("Note is empty".to_string()).into());
+            }
+            let note_id = db.add_job_note(id, new.note.trim())?;
+            let note = db.list_job_notes(id)?.into_iter().find(|n| n.id == note_id);
+            Ok(Reply { status: 201, body: json!(note) })
+        }
+        (Method::Get, ["employers"]) => Ok(Reply::ok(json!(db.list_employers(query.get("status").as_deref())?))),
+        (Method::Get, ["employers", name]) => {
+            let employer = db.get_employer_by_name(name)?
+                .ok_or_else(|| HuntError::NotFound(format!("Employer '{}' not found", name)))?;
+            Ok(Reply::ok(json!(templates::employer_context(db, employer)?)))
+        }
+        (Method::Get, ["rank"]) => {
+            let limit = query.number("limit")?.unwrap_or(20);
+            let filter = SavedFilter {
+                min_pay: query.number("min_pay")?,
+                max_pay: query.number("max_pay")?,
+                sponsors_visa: query.flag("sponsors_visa"),
+                no_clearance: query.flag("no_clearance"),
+                ..Default::default()
+            };
+            let jobs = db.rank_jobs(limit, &filter)?;
+            let jobs = jobs.into_iter().map(|(job, _)| templates::job_context(db, job)).collect::<Result<Vec<_>>>()?;
+            Ok(Reply::ok(json!(jobs)))
+        }
+        (Method::Get, ["stats"]) => {
+            let weeks = query.number("weeks")?.unwrap_or(8);
+            let jobs = db.list_jobs(None, None)?;
+            let mut fit_scores = Vec::new();
+            for job in &jobs {
+                fit_scores.extend(db.get_best_fit_score(job.id)?);
+            }
+            let stats = stats::compute(&jobs, &db.list_job_statuses()?, &fit_scores, weeks, chrono::Local::now().date_naive());
+            Ok(Reply::ok(json!(stats)))
+        }
+        (Method::Get, ["statuses"]) => Ok(Reply::ok(json!(db.list_job_statuses()?))),
+        (_, ["jobs"] | ["jobs", _] | ["jobs", _, "status" | "notes"] | ["employers"] | ["employers", _] | ["rank"] | ["stats"] | ["statuses"]) => {
+            Ok(Reply { status: 405, body: json!({ "error": { "kind": "validation", "exit_code": 7, "message": format!("{} not allowed here", method) } }) })
+        }
+        _ => not_found(),
+    }
+}
+
+/// `hunt list` over HTTP: the same filters (plus a saved `filter`) and the same
+/// negative-keyword hiding, unless `show_excluded`.
+fn list_jobs(db: &Database, query: &Query) -> Result<Reply> {
+    let saved = match query.get("filter") {
+        Some(name) => db.get_saved_filter(&name)?
+            .ok_or_else(|| HuntError::NotFound(format!("No saved filter '{}' (see 'hunt filter list')", name)))?,
+        None => SavedFilter::default(),
+    };
+    let filter = SavedFilter {
+        status: query.get("status").or(saved.status),
+        employer: query.get("employer").or(saved.employer),
+        tag: query.get("tag").or(saved.tag),
+        keyword: query.get("keyword").or(saved.keyword),
+        location: query.get("location").or(saved.location),
+        min_pay: query.number("min_pay")?.or(saved.min_pay),
+        max_pay: query.number("max_pay")?.or(saved.max_pay),
+        remote: query.flag("remote") || saved.remote,
+        easy_apply: query.boolean("easy_apply")?.or(saved.easy_apply),
+        sponsors_visa: query.flag("sponsors_visa") || saved.sponsors_visa,
+        no_clearance: query.flag("no_clearance") || saved.no_clearance,
+        ..saved
+    };
+    let mut excluded = Vec::new();
+    if !query.flag("show_excluded") {
+        excluded = db::normalize_terms(&db.get_negative_keywords()?);
+    }
+    let mut jobs = Vec::new();
+    for job in db.filter_jobs(&filter, query.flag("archived"))? {
+        if excluded.is_empty() || db.find_excluded_keyword(&job, &excluded)?.is_none() {
+            jobs.push(templates::job_context(db, job)?);
+        }
+    }
+    Ok(Reply::ok(json!(jobs)))
+}
+
+fn header(name: &str, value: &str) -> Header {
+    Header::from_bytes(name.as_bytes(), value.as_bytes()).expect("valid header")
+}
+
+/// Who may talk to the API: the address it's bound to, the token clients must send,
+/// and the one browser origin (if any) allowed to read its responses.
+pub struct Access {
+    pub host: String,
+    pub port: u16,
+    pub token: Option<String>,
+    pub allow_origin: Option<String>,
+}
+
+/// "localhost:8080" or "[::1]:8080" split into host and port.
+fn split_host_port(value: &str) -> (&str, Option<&str>) {
+    let value = value.trim();
+    if let Some(rest) = value.strip_prefix('[') {
+        return match rest.split_once(']') {
+            Some((host, port)) => (host, port.strip_prefix(':')),
+            None => (value, None),
+        };
+    }
+    match value.rsplit_once(':') {
+        Some((host, port)) => (host, Some(port)),
+        None => (value, None),
+    }
+}
+
+fn is_loopback(host: &str) -> bool {
+    host.eq_ignore_ascii_case("localhost") || host.parse::<std::net::IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
+impl Access {
+    /// Whether a Host header names this server. Browsers send the name the page used, so
+    /// a DNS-rebound name pointing at 127.0.0.1 still says "evil.example" and is refused.
+    /// On a wildcard address (0.0.0.0) any name will do, as long as the port matches;
+    /// those servers need a token anyway.
+    fn host_allowed(&self, value: &str) -> bool {
+        let (host, port) = split_host_port(value);
+        let port_ok = port.unwrap_or("80") == self.port.to_string();
+        let bound = self.host.trim_start_matches('[').trim_end_matches(']');
+        let unspecified = bound.parse::<std::net::IpAddr>().is_ok_and(|ip| ip.is_unspecified());
+        let host_ok = unspecified || host.eq_ignore_ascii_case(bound) || (is_loopback(bound) && is_loopback(host));
+        port_ok && host_ok
+    }
+
+    /// The reply refusing a request before it reaches `handle`, or None to let it through.
+    fn refusal(&self, method: &Method, headers: &[Header]) -> Option<Reply> {
+        let value = |name: &'static str| {
+            let field: HeaderField = name.parse().expect("valid header name");
+            headers.iter().find(|h| h.field == field).map(|h| h.value.as_str())
+        };
+        if !value("Host").is_some_and(|host| self.host_allowed(host)) {
+            let err = HuntError::Auth(format!("Host header doesn't name this server ({}:{})", self.host, self.port));
+            return Some(Reply { status: 403, ..Reply::error(&err.into()) });
+        }
+        if *method == Method::Options {
+            return Some(Reply { status: 204, body: Value::Null });
+        }
+        let authorized = self.token.as_deref().is_none_or(|token| {
+            value("Authorization").and_then(|v| v.strip_prefix("Bearer ")).is_some_and(|t| t.trim() == token)
+        });
+        if !authorized {
+            return Some(Reply::error(&HuntError::Auth("Missing or wrong API token (send Authorization: Bearer <token>)".to_string()).into()));
+        }
+        // A JSON content type can't be sent cross-origin without a CORS preflight, which
+        // only --allow-origin answers; form and text/plain posts could
+        let json = value("Content-Type")
+            .and_then(|v| v.split(';').next())
+            .is_some_and(|v| v.trim().eq_ignore_ascii_case("application/json"));
+        if *method == Method::Post && !json {
+            let err = HuntError::Validation("POST bodies must be sent as Content-Type: application/json".to_string());
+            return Some(Reply { status: 415, ..Reply::error(&err.into()) });
+        }
+        None
+    }
+
+    /// CORS headers, only when an origin was allowed: without them browsers keep other
+    /// sites' pages from reading anything.
+    fn cors_headers(&self) -> Vec<Header> {
+        let Some(origin) = &self.allow_origin else { return Vec::new() };
+        vec![
+            header("Access-Control-Allow-Origin", origin),
+            header("Vary", "Origin"),
+            header("Access-Control-Allow-Methods", "GET, POST, OPTIONS"),
+            header("Access-Control-Allow-Headers", "Authorization, Content-Type"),
+        ]
+    }
+}
+
+/// Serve the API until killed, one request at a time, refusing what `Access` doesn't allow.
+pub fn serve(db: &Database, access: &Access) -> Result<()> {
+    let server = Server::http((access.host.as_str(), access.port))
+        .map_err(|e| HuntError::Network(format!("Can't listen on {}:{}: {}", access.host, access.port, e)))?;
+    info!("Serving the hunt API on http://{}:{}/api (Ctrl-C to stop)", access.host, access.port);
+    for mut request in server.incoming_requests() {
+        let method = request.method().clone();
+        let url = request.url().to_string();
+        let reply = match access.refusal(&method, request.headers()) {
+            Some(reply) => reply,
+            None => {
+                let mut body = String::new();
+                match request.as_reader().read_to_string(&mut body) {
+                    Ok(_) => handle(db, &method, &url, &body),
+                    Err(e) => Reply::error(&HuntError::Validation(format!("Unreadable request body: {}", e)).into()),
+                }
+            }
+        };
+        info!("{} {} -> {}", method, url, reply.status);
+        let body = if reply.body.is_null() { String::new() } else { serde_json::to_string_pretty(&reply.body)? };
+        let mut response = Response::from_string(body)
+            .with_status_code(reply.status)
+            .with_header(header("Content-Type", "application/json"));
+        for cors in access.cors_headers() {
+            response.add_header(cors);
+        }
+        if let Err(e) = request.respond(response) {
+            warn!("Couldn't answer {} {}: {}", method, url, e);
+        }
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_handle() -> Result<()> {
+        let db = Database::open_in_memory()?;
+        db.init()?;
+        let id = db.add_job_full("SRE", Some("Acme Corp"), None, None, Some(150000), Some(180000), None)?;
+        db.add_job_full("Intern", Some("Globex"), None, None, None, None, None)?;
+
+        let reply = handle(&db, &Method::Get, "/api/jobs?min_pay=100000", "");
+        assert_eq!(reply.status, 200);
+        assert_eq!(reply.body.as_array().unwrap().len(), 1);
+        assert_eq!(reply.body[0]["job"]["title"], "SRE");
+
+        let reply = handle(&db, &Method::Post, &format!("/api/jobs/{}/status", id), r#"{"status": "applied"}"#);
+        assert_eq!((reply.status, reply.body["status"].as_str()), (200, Some("applied")));
+        let reply = handle(&db, &Method::Post, &format!("/api/jobs/{}/status", id), r#"{"status": "new"}"#);
+        assert_eq!(reply.status, 400, "guarded like `status set` without --force");
+        assert_eq!(reply.body["error"]["kind"], "validation");
+
+        let reply = handle(&db, &Method::Post, &format!("/api/jobs/{}/notes", id), r#"{"note": "Recruiter called"}"#);
+        assert_eq!((reply.status, reply.body["note"].as_str()), (201, Some("Recruiter called")));
+        assert_eq!(handle(&db, &Method::Get, &format!("/api/jobs/{}", id), "").body["notes"][0]["note"], "Recruiter called");
+        assert_eq!(handle(&db, &Method::Post, &format!("/api/jobs/{}/notes", id), "not json").status, 400);
+
+        let reply = handle(&db, &Method::Get, "/api/employers/Acme%20Corp", "");
+        assert_eq!(reply.body["employer"]["name"], "Acme Corp");
+        assert_eq!(handle(&db, &Method::Get, "/api/rank?limit=1", "").body.as_array().unwrap().len(), 1);
+        assert_eq!(handle(&db, &Method::Get, "/api/stats", "").status, 200);
+
+        assert_eq!(handle(&db, &Method::Get, "/api/jobs/99", "").status, 404);
+        assert_eq!(handle(&db, &Method::Get, "/api/nope", "").status, 404);
+        assert_eq!(handle(&db, &Method::Delete, "/api/jobs", "").status, 405);
+        assert_eq!(handle(&db, &Method::Get, "/api/rank?limit=ten", "").status, 400);
+        Ok(())
+    }
+
+    fn access(host: &str, token: Option<&str>, allow_origin: Option<&str>) -> Access {
+        Access { host: host.to_string(), port: 8080, token: token.map(String::from), allow_origin: allow_origin.map(String::from) }
+    }
+
+    fn headers(pairs: &[(&str, &str)]) -> Vec<Header> {
+        pairs.iter().map(|(name, value)| header(name, value)).collect()
+    }
+
+    #[test]
+    fn test_refusal_host() {
+        let local = access("127.0.0.1", None, None);
+        let status = |access: &Access, host: &str| {
+            access.refusal(&Method::Get, &headers(&[("Host", host)])).map(|r| r.status)
+        };
+        assert_eq!(status(&local, "127.0.0.1:8080"), None);
+        assert_eq!(status(&local, "localhost:8080"), None, "any loopback name");
+        assert_eq!(status(&local, "[::1]:8080"), None);
+        assert_eq!(status(&local, "evil.example:8080"), Some(403), "DNS rebinding");
+        assert_eq!(status(&local, "127.0.0.1:9090"), Some(403));
+        assert_eq!(status(&local, "127.0.0.1"), Some(403), "no port means 80");
+        assert_eq!(local.refusal(&Method::Get, &[]).map(|r| r.status), Some(403), "no Host header");
+
+        let any = access("0.0.0.0", Some("secret"), None);
+        let authed = |host: &str| headers(&[("Host", host), ("Authorization", "Bearer secret")]);
+        assert!(any.refusal(&Method::Get, &authed("laptop.lan:8080")).is_none());
+        assert_eq!(any.refusal(&Method::Get, &authed("laptop.lan:80")).map(|r| r.status), Some(403));
+        assert_eq!(any.refusal(&Method::Get, &headers(&[("Host", "laptop.lan:8080")])).map(|r| r.status), Some(401));
+    }
+
+    #[test]
+    fn test_refusal_content_type() {
+        let local = access("localhost", None, None);
+        let status = |content_type: Option<&str>| {
+            let mut pairs = vec![("Host", "localhost:8080")];
+            pairs.extend(content_type.map(|c| ("Content-Type", c)));
+            local.refusal(&Method::Post, &headers(&pairs)).map(|r| r.status)
+        };
+        assert_eq!(status(Some("application/json")), None);
+        assert_eq!(status(Some("application/json; charset=utf-8")), None);
+        assert_eq!(status(Some("text/plain")), Some(415), "a simple request that skips the preflight");
+        assert_eq!(status(Some("application/x-www-form-urlencoded")), Some(415));
+        assert_eq!(status(None), Some(415));
+        let get = local.refusal(&Method::Get, &headers(&[("Host", "localhost:8080")]));
+        assert!(get.is_none(), "GETs have no body");
+        let preflight = local.refusal(&Method::Options, &headers(&[("Host", "localhost:8080")]));
+        assert_eq!(preflight.map(|r| r.status), Some(204));
+    }
+
+    #[test]
+    fn test_cors_headers() {
+        assert!(access("127.0.0.1", None, None).cors_headers().is_empty(), "no wildcard by default");
+        let dashboard = access("127.0.0.1", None, Some("http://localhost:3000")).cors_headers();
+        let allow = dashboard.iter().find(|h| h.field.equiv("Access-Control-Allow-Origin")).unwrap();
+        assert_eq!(allow.value.as_str(), "http://localhost:3000");
+    }
+}
@@ -0,0 +1,170 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use lettre::message::header::ContentType;
+use lettre::message::{Attachment, Mailbox, MultiPart, SinglePart};
+use lettre::transport::smtp::authentication::Credentials;
+use lettre::{Message, SmtpTransport, Transport};
+use serde::Deserialize;
+
+use crate::error::HuntError;
+
+/// The config file's "smtp" section: where outgoing mail goes out from.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct SmtpConfig {
+    pub server: String,
+    /// 587 (STARTTLS) unless set; 465 means TLS from the start
+    #[serde(default = "default_port")]
+    pub port: u16,
+    pub username: String,
+    pub password_file: String,
+    /// Sender address, e.g. "Pat Doe <pat@example.com>" (default: username)
+    pub from: Option<String>,
+    /// Where digests and reminders go (default: the sender)
+    pub to: Option<String>,
+}
+
+fn default_port() -> u16 {
+    587
+}
+
+impl SmtpConfig {
+    pub fn sender(&self) -> &str {
+        self.from.as_deref().unwrap_or(&self.username)
+    }
+
+    /// The address mail to yourself goes to.
+    pub fn recipient(&self) -> &str {
+        self.to.as_deref().unwrap_or_else(|| self.sender())
+    }
+}
+
+/// One outgoing email: plain text, optionally with an HTML version, plus files.
+#[derive(Debug, Clone, Default)]
+pub struct Outgoing {
+    pub to: String,
+    pub subject: String,
+    pub text: String,
+    pub html: Option<String>,
+    pub attachments: Vec<PathBuf>,
+}
+
+fn mailbox(address: &str) -> Result<Mailbox> {
+    address
+        .trim()
+        .parse()
+        .map_err(|e| HuntError::Validation(format!("Invalid email address '{}': {}", address, e)).into())
+}
+
+fn content_type(path: &Path) -> ContentType {
+    let mime = match path.extension().and_then(|e| e.to_str()).map(str::to_lowercase).as_deref() {
+        Some("pdf") => "application/pdf",
+        Some("md" | "txt" | "tex") => "text/plain; charset=utf-8",
+        Some("html") => "text/html; charset=utf-8",
+        Some("docx") => "application/vnd.openxmlformats-officedocument.wordprocessingml.document",
+        _ => "application/octet-stream",
+    };
+    ContentType::parse(mime).expect("known MIME type")
+}
+
+/// The message as it will be sent, attachments read from disk.
+pub fn build(config: &SmtpConfig, mail: &Outgoing) -> Result<Message> {
+    let body = match &mail.html {
+        Some(html) => MultiPart::alternative_plain_html(mail.text.clone(), html.clone()),
+        None => MultiPart::mixed().singlepart(SinglePart::plain(mail.text.clone())),
+    };
+    let body = mail.attachments.iter().try_fold(body, |body, path| -> Result<MultiPart> {
+        let content = fs::read(path).with_context(|| format!("Failed to read attachment {}", path.display()))?;
+        let filename = path.file_name().map_or("attachment".into(), |n| n.to_string_lossy().into_owned());
+        Ok(MultiPart::mixed().multipart(body).singlepart(Attachment::new(filename).body(content, content_type(path))))
+    })?;
+    Message::builder()
+        .from(mailbox(config.sender())?)
+        .to(mailbox(&mail.to)?)
+        .subject(&mail.subject)
+        .multipart(body)
+        .map_err(|e| HuntError::Validation(format!("Could not build email: {}", e)).into())
+}
+
+/// Send through the configured server, logging in with the password in `password_file`.
+pub fn send(config: &SmtpConfig, password_file: &Path, mail: &Outgoing) -> Result<()> {
+    let message = build(config, mail)?;
+    let password = fs::read_to_string(password_file)
+        .with_context(|| format!("Failed to read password file: {:?}", password_file))?;
+    let builder = if config.port == 465 {
+        SmtpTransport::relay(&config.server)
+    } else {
+        SmtpTransport::starttls_relay(&config.server)
+    };
+    let transport = builder
+        .map_err(|e| HuntError::Network(format!("SMTP server {}: {}", config.server, e)))?
+        .port(config.port)
+        .credentials(Credentials::new(config.username.clone(), password.trim().to_string()))
+        .build();
+    transport.send(&message).map_err(|e| {
+        // 535: authentication credentials invalid
+        if e.status().is_some_and(|code| code.to_string() == "535") {
+            HuntError::Auth(format!("SMTP login to {} as {} was rejected: {}", config.server, config.username, e))
+        } else {
+            HuntError::Network(format!("Sending via {}:{} failed: {}", config.server, config.port, e))
+        }
+    })?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config() -> SmtpConfig {
+        SmtpConfig {
+            server: "smtp.example.com".to_string(),
+            port: 587,
+            username: "pat@example.com".to_string(),
+            password_file: "~/.smtp".to_string(),
+            from: Some("Pat Doe <pat@example.com>".to_string()),
+            to: None,
+        }
+    }
+
+    #[test]
+    fn test_addresses() {
+        let mut config = config();
+        assert_eq!(config.recipient(), "Pat Doe <pat@example.com>");
+        config.from = None;
+        config.to = Some("inbox@example.com".to_string());
+        assert_eq!((config.sender(), config.recipient()), ("pat@example.com", "inbox@example.com"));
+    }
+
+    #[test]
+    fn test_build() {
+        let dir = std::env::temp_dir().join(format!("hunt-smtp-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let resume = dir.join("resume.pdf");
+        fs::write(&resume, b"%PDF-1.4").unwrap();
+
+        let mail = Outgoing {
+            to: "jobs@acme.com".to_string(),
+            subject: "Application: SRE".to_string(),
+            text: "Hello".to_string(),
+            html: Some("<p>Hello</p>".to_string()),
+            attachments: vec![resume],
+        };
+        let raw = String::from_utf8(build(&config(), &mail).unwrap().formatted()).unwrap();
+        assert!(raw.contains("From: \"Pat Doe\" <pat@example.com>"), "{}", raw);
+        assert!(raw.contains("To: jobs@acme.com"));
+        assert!(raw.contains("Subject: Application: SRE"));
+        assert!(raw.contains("Content-Type: text/html"));
+        assert!(raw.contains("Content-Disposition: attachment; filename=\"resume.pdf\""), "{}", raw);
+        assert!(raw.contains("Content-Type: application/pdf"));
+
+        let bad = Outgoing { to: "not an address".to_string(), ..mail.clone() };
+        let err = build(&config(), &bad).unwrap_err();
+        assert!(matches!(err.downcast_ref::<HuntError>(), Some(HuntError::Validation(_))));
+
+        let missing = Outgoing { attachments: vec![dir.join("gone.pdf")], ..mail };
+        assert!(build(&config(), &missing).is_err());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,455 @@
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use serde::Serialize;
+use std::collections::HashMap;
+
+use crate::models::{Job, JobStatus, StatusChange};
+use crate::timestamps;
+
+/// Width of a pay histogram bucket, in dollars.
+pub const PAY_BUCKET: i64 = 25_000;
+const BAR_WIDTH: usize = 30;
+const TOP_EMPLOYERS: usize = 10;
+
+#[derive(Debug, Serialize)]
+pub struct StatusCount {
+    pub status: String,
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct WeekCount {
+    pub week_of: String, // Monday, YYYY-MM-DD
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct EmployerCount {
+    pub employer: String,
+    pub jobs: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct PayBin {
+    pub from: i64,
+    pub to: i64, // exclusive
+    pub count: usize,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Conversion {
+    pub total: usize,
+    pub applied: usize,
+    pub interviewing: usize,
+    pub applied_rate: Option<f64>,   // applied / total
+    pub interview_rate: Option<f64>, // interviewing / applied
+}
+
+#[derive(Debug, Serialize)]
+pub struct Stats {
+    pub total_jobs: usize,
+    pub by_status: Vec<StatusCount>,
+    pub applications_per_week: Vec<WeekCount>,
+    pub avg_fit_score: Option<f64>,
+    pub fit_scored_jobs: usize,
+    pub top_employers: Vec<EmployerCount>,
+    pub pay_histogram: Vec<PayBin>,
+    pub conversion: Conversion,
+}
+
+/// Whether a job in `status` got at least as far as `stage`. Without a status history
+/// only the current status is known: exit statuses after the first terminal one
+/// (rejected, withdrawn, closed) could have happened at any stage, so they don't count.
+fn reached(statuses: &[JobStatus], status: &str, stage: &str) -> bool {
+    let position = |name: &str| statuses.iter().find(|s| s.name == name);
+    let (Some(current), Some(stage)) = (position(status), position(stage)) else { return false };
+    let first_terminal = statuses.iter().filter(|s| s.is_terminal).map(|s| s.position).min();
+    let exit = current.is_terminal && first_terminal.is_some_and(|p| current.position > p);
+    !exit && current.position >= stage.position
+}
+
+/// Mondays of the last `weeks` weeks, oldest first, ending with this week's.
+fn week_starts(weeks: u32, today: NaiveDate) -> Vec<NaiveDate> {
+    let this_monday = today - Duration::days(today.weekday().num_days_from_monday() as i64);
+    (0..weeks as i64).rev().map(|w| this_monday - Duration::weeks(w)).collect()
+}
+
+/// Which of `mondays` a stored timestamp's local date falls in; None if before the first.
+fn week_index(mondays: &[NaiveDate], timestamp: &str) -> Option<usize> {
+    let date = timestamps::parse(timestamp)?.with_timezone(&Local).date_naive();
+    mondays.iter().rposition(|monday| date >= *monday)
+}
+
+fn rate(part: usize, whole: usize) -> Option<f64> {
+    (whole > 0).then(|| part as f64 / whole as f64)
+}
+
+/// Build the dashboard. `fit_scores` are the best fit score of each analyzed job;
+/// applications are bucketed by the local week of the job's last update.
+pub fn compute(jobs: &[Job], statuses: &[JobStatus], fit_scores: &[f64], weeks: u32, today: NaiveDate) -> Stats {
+    let mut by_status: Vec<StatusCount> = statuses.iter()
+        .map(|s| StatusCount { status: s.name.clone(), count: jobs.iter().filter(|j| j.status == s.name).count() })
+        .collect();
+    // Statuses no longer in the pipeline still show up
+    for job in jobs {
+        if !by_status.iter().any(|s| s.status == job.status) {
+            let count = jobs.iter().filter(|j| j.status == job.status).count();
+            by_status.push(StatusCount { status: job.status.clone(), count });
+        }
+    }
+
+    let mondays = week_starts(weeks, today);
+    let mut per_week = vec![0; mondays.len()];
+    for job in jobs.iter().filter(|j| reached(statuses, &j.status, "applied")) {
+        if let Some(i) = week_index(&mondays, &job.updated_at) {
+            per_week[i] += 1;
+        }
+    }
+    let applications_per_week = mondays.iter().zip(per_week)
+        .map(|(monday, count)| WeekCount { week_of: monday.format("%Y-%m-%d").to_string(), count })
+        .collect();
+
+    let mut employer_counts: HashMap<&str, usize> = HashMap::new();
+    for name in jobs.iter().filter_map(|j| j.employer_name.as_deref()) {
+        *employer_counts.entry(name).or_default() += 1;
+    }
+    let mut top_employers: Vec<EmployerCount> = employer_counts.into_iter()
+        .map(|(employer, jobs)| EmployerCount { employer: employer.to_string(), jobs })
+        .collect();
+    top_employers.sort_by(|a, b| b.jobs.cmp(&a.jobs).then(a.employer.cmp(&b.employer)));
+    top_employers.truncate(TOP_EMPLOYERS);
+
+    let pays: Vec<i64> = jobs.iter()
+        .filter_map(|j| match (j.pay_min, j.pay_max) {
+            (Some(min), Some(max)) => Some((min + max) / 2),
+            (Some(pay), None) | (None, Some(pay)) => Some(pay),
+            (None, None) => None,
+        })
+        .collect();
+    let pay_histogram = match (pays.iter().min(), pays.iter().max()) {
+        (Some(&low), Some(&high)) => {
+            let first = low.div_euclid(PAY_BUCKET);
+            (first..=high.div_euclid(PAY_BUCKET))
+                .map(|b| PayBin {
+                    from: b * PAY_BUCKET,
+                    to: (b + 1) * PAY_BUCKET,
+                    count: pays.iter().filter(|p| p.div_euclid(PAY_BUCKET) == b).count(),
+                })
+                .collect()
+        }
+        _ => Vec::new(),
+    };
+
+    let applied = jobs.iter().filter(|j| reached(statuses, &j.status, "applied")).count();
+    let interviewing = jobs.iter().filter(|j| reached(statuses, &j.status, "interviewing")).count();
+
+    Stats {
+        total_jobs: jobs.len(),
+        by_status,
+        applications_per_week,
+        avg_fit_score: (!fit_scores.is_empty()).then(|| fit_scores.iter().sum::<f64>() / fit_scores.len() as f64),
+        fit_scored_jobs: fit_scores.len(),
+        top_employers,
+        pay_histogram,
+        conversion: Conversion {
+            total: jobs.len(),
+            applied,
+            interviewing,
+            applied_rate: rate(applied, jobs.len()),
+            interview_rate: rate(interviewing, applied),
+        },
+    }
+}
+
+#[derive(Debug, Serialize)]
+pub struct FunnelWeek {
+    pub week_of: String,
+    pub added: usize,
+    pub entered: Vec<StatusCount>, // jobs moved into each status that week
+}
+
+#[derive(Debug, Serialize)]
+pub struct FunnelStage {
+    pub status: String,
+    pub reached: usize,
+    pub rate: Option<f64>, // reached / jobs added in the window
+}
+
+#[derive(Debug, Serialize)]
+pub struct Funnel {
+    pub since: String, // first Monday of the window
+    pub added: usize,
+    pub weeks: Vec<FunnelWeek>,
+    pub stages: Vec<FunnelStage>,
+}
+
+/// How jobs moved through the pipeline week by week, from `status_history`, and how far
+/// the jobs added in the window got. A job reached a stage if any status it has held
+/// (not counting exits like rejected) is at or past that stage.
+pub fn funnel(jobs: &[Job], history: &[StatusChange], statuses: &[JobStatus], weeks: u32, today: NaiveDate) -> Funnel {
+    let mondays = week_starts(weeks, today);
+    // The first status is where jobs start: its column is "added", by created_at
+    let moved_to: Vec<&JobStatus> = statuses.iter().skip(1).collect();
+
+    let mut added = vec![0; mondays.len()];
+    for job in jobs {
+        if let Some(i) = week_index(&mondays, &job.created_at) {
+            added[i] += 1;
+        }
+    }
+    // A job that bounces in and out of a status within a week counts once
+    let mut entered: Vec<HashMap<&str, Vec<i64>>> = vec![HashMap::new(); mondays.len()];
+    for change in history {
+        if let Some(i) = week_index(&mondays, &change.changed_at) {
+            let jobs = entered[i].entry(change.to_status.as_str()).or_default();
+            if !jobs.contains(&change.job_id) {
+                jobs.push(change.job_id);
+            }
+        }
+    }
+    let weeks = mondays.iter().zip(added).zip(&entered)
+        .map(|((monday, added), entered)| FunnelWeek {
+            week_of: monday.format("%Y-%m-%d").to_string(),
+            added,
+            entered: moved_to.iter()
+                .map(|s| StatusCount { status: s.name.clone(), count: entered.get(s.name.as_str()).map_or(0, Vec::len) })
+                .collect(),
+        })
+        .collect();
+
+    let since = mondays.first().map(|m| m.format("%Y-%m-%d").to_string()).unwrap_or_default();
+    let cohort: Vec<&Job> = jobs.iter().filter(|j| week_index(&mondays, &j.created_at).is_some()).collect();
+    // Every job starts in the first status, even one rejected straight from it
+    let held: Vec<Vec<&str>> = cohort.iter()
+        .map(|job| {
+            history.iter()
+                .filter(|h| h.job_id == job.id)
+                .map(|h| h.to_status.as_str())
+                .chain([job.status.as_str()])
+                .chain(statuses.first().map(|s| s.name.as_str()))
+                .collect()
+        })
+        .collect();
+    let stages = statuses.iter()
+        .filter(|stage| reached(statuses, &stage.name, &stage.name)) // exits aren't stages
+        .map(|stage| {
+            let count = held.iter()
+                .filter(|job_statuses| job_statuses.iter().any(|s| reached(statuses, s, &stage.name)))
+                .count();
+            FunnelStage { status: stage.name.clone(), reached: count, rate: rate(count, cohort.len()) }
+        })
+        .collect();
+
+    Funnel { since, added: cohort.len(), weeks, stages }
+}
+
+/// The funnel report: a week-by-status table, then the cohort funnel.
+pub fn render_funnel(funnel: &Funnel) -> String {
+    let mut out = String::new();
+    let Some(first) = funnel.weeks.first() else { return out };
+
+    let widths: Vec<usize> = first.entered.iter().map(|s| s.status.len().max(5)).collect();
+    out.push_str(&format!("{:<10}  {:>5}", "WEEK OF", "ADDED"));
+    for (s, width) in first.entered.iter().zip(&widths) {
+        out.push_str(&format!("  {:>width$}", s.status.to_uppercase(), width = width));
+    }
+    out.push('\n');
+    out.push_str(&"-".repeat(17 + widths.iter().map(|w| w + 2).sum::<usize>()));
+    out.push('\n');
+    for week in &funnel.weeks {
+        out.push_str(&format!("{:<10}  {:>5}", week.week_of, week.added));
+        for (s, width) in week.entered.iter().zip(&widths) {
+            out.push_str(&format!("  {:>width$}", s.count, width = width));
+        }
+        out.push('\n');
+    }
+
+    out.push_str(&format!("\nJobs added since {}: {}\n", funnel.since, funnel.added));
+    let max = funnel.stages.iter().map(|s| s.reached).max().unwrap_or(0);
+    for stage in &funnel.stages {
+        out.push_str(&format!(
+            "  {:<14} {:>5} {:>5}  {}\n",
+            stage.status, stage.reached, percent(stage.rate), bar(stage.reached, max)
+        ));
+    }
+    out
+}
+
+fn bar(count: usize, max: usize) -> String {
+    if max == 0 || count == 0 {
+        return String::new();
+    }
+    "█".repeat((count * BAR_WIDTH).div_ceil(max))
+}
+
+fn percent(value: Option<f64>) -> String {
+    value.map_or_else(|| "-".to_string(), |v| format!("{:.0}%", v * 100.0))
+}
+
+/// The text dashboard.
+pub fn render(stats: &Stats) -> String {
+    let mut out = format!("Jobs tracked: {}\n", stats.total_jobs);
+
+    out.push_str("\nBy status\n");
+    let max = stats.by_status.iter().map(|s| s.count).max().unwrap_or(0);
+    for s in &stats.by_status {
+        out.push_str(&format!("  {:<14} {:>5}  {}\n", s.status, s.count, bar(s.count, max)));
+    }
+
+    let c = &stats.conversion;
+    out.push_str("\nConversion\n");
+    out.push_str(&format!("  new -> applied        {:>5}  ({} of {})\n", percent(c.applied_rate), c.applied, c.total));
+    out.push_str(&format!("  applied -> interview  {:>5}  ({} of {})\n", percent(c.interview_rate), c.interviewing, c.applied));
+
+    out.push_str("\nApplications per week (by last status change)\n");
+    let max = stats.applications_per_week.iter().map(|w| w.count).max().unwrap_or(0);
+    for w in &stats.applications_per_week {
+        out.push_str(&format!("  {}  {:>3}  {}\n", w.week_of, w.count, bar(w.count, max)));
+    }
+
+    out.push_str("\nFit\n");
+    match stats.avg_fit_score {
+        Some(avg) => out.push_str(&format!("  Average best fit score: {:.0}/100 across {} job(s)\n", avg, stats.fit_scored_jobs)),
+        None => out.push_str("  No fit analyses yet (hunt fit)\n"),
+    }
+
+    if !stats.top_employers.is_empty() {
+        out.push_str("\nTop employers\n");
+        for e in &stats.top_employers {
+            out.push_str(&format!("  {:<30} {:>4}\n", e.employer, e.jobs));
+        }
+    }
+
+    if !stats.pay_histogram.is_empty() {
+        out.push_str("\nAdvertised pay (midpoint)\n");
+        let max = stats.pay_histogram.iter().map(|b| b.count).max().unwrap_or(0);
+        for b in &stats.pay_histogram {
+            let range = format!("${}k-{}k", b.from / 1000, b.to / 1000);
+            out.push_str(&format!("  {:<12} {:>4}  {}\n", range, b.count, bar(b.count, max)));
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::default_job_statuses;
+
+    fn job(status: &str, employer: Option<&str>, pay: (Option<i64>, Option<i64>), updated_at: &str) -> Job {
+        Job {
+            employer_name: employer.map(String::from),
+            title: "SRE".to_string(),
+            status: status.to_string(),
+            pay_min: pay.0,
+            pay_max: pay.1,
+            created_at: updated_at.to_string(),
+            updated_at: updated_at.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_reached() {
+        let statuses = default_job_statuses();
+        assert!(reached(&statuses, "interviewing", "applied"));
+        assert!(reached(&statuses, "accepted", "interviewing"));
+        assert!(!reached(&statuses, "reviewing", "applied"));
+        assert!(!reached(&statuses, "rejected", "applied"), "unknown without history");
+        assert!(!reached(&statuses, "bogus", "applied"));
+    }
+
+    #[test]
+    fn test_compute() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 18).unwrap(); // a Wednesday
+        let jobs = vec![
+            job("new", Some("Acme"), (Some(100_000), Some(140_000)), "2026-03-16T12:00:00Z"),
+            job("applied", Some("Acme"), (Some(150_000), None), "2026-03-17T12:00:00Z"),
+            job("interviewing", Some("Globex"), (None, None), "2026-03-10T12:00:00Z"),
+            job("applied", None, (None, Some(130_000)), "2026-01-01T12:00:00Z"),
+            job("rejected", Some("Acme"), (None, None), "2026-03-17T12:00:00Z"),
+        ];
+        let stats = compute(&jobs, &default_job_statuses(), &[80.0, 60.0], 2, today);
+
+        assert_eq!(stats.total_jobs, 5);
+        let count = |status: &str| stats.by_status.iter().find(|s| s.status == status).map(|s| s.count);
+        assert_eq!((count("applied"), count("closed")), (Some(2), Some(0)));
+
+        let weeks: Vec<(&str, usize)> = stats.applications_per_week.iter().map(|w| (w.week_of.as_str(), w.count)).collect();
+        assert_eq!(weeks, vec![("2026-03-09", 1), ("2026-03-16", 1)]);
+
+        assert_eq!(stats.avg_fit_score, Some(70.0));
+        assert_eq!(stats.top_employers[0].employer, "Acme");
+        assert_eq!(stats.top_employers[0].jobs, 3);
+
+        let bins: Vec<(i64, usize)> = stats.pay_histogram.iter().map(|b| (b.from, b.count)).collect();
+        assert_eq!(bins, vec![(100_000, 1), (125_000, 1), (150_000, 1)]);
+
+        assert_eq!((stats.conversion.applied, stats.conversion.interviewing), (3, 1));
+        assert_eq!(stats.conversion.applied_rate, Some(0.6));
+
+        let text = render(&stats);
+        assert!(text.contains("new -> applied          60%  (3 of 5)"));
+        assert!(text.contains("$125k-150k"));
+    }
+
+    #[test]
+    fn test_funnel() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 18).unwrap();
+        let change = |job_id: i64, to: &str, at: &str| StatusChange {
+            id: 0,
+            job_id,
+            from_status: None,
+            to_status: to.to_string(),
+            changed_at: at.to_string(),
+        };
+        let mut jobs = vec![
+            job("rejected", None, (None, None), "2026-03-10T12:00:00Z"),
+            job("interviewing", None, (None, None), "2026-03-10T12:00:00Z"),
+            job("new", None, (None, None), "2026-03-16T12:00:00Z"),
+            job("applied", None, (None, None), "2026-01-05T12:00:00Z"), // before the window
+            job("rejected", None, (None, None), "2026-03-16T12:00:00Z"),
+        ];
+        for (i, job) in jobs.iter_mut().enumerate() {
+            job.id = i as i64 + 1;
+        }
+        let history = vec![
+            change(1, "applied", "2026-03-11T12:00:00Z"),
+            change(1, "rejected", "2026-03-17T12:00:00Z"),
+            change(2, "applied", "2026-03-11T12:00:00Z"),
+            change(2, "reviewing", "2026-03-11T13:00:00Z"),
+            change(2, "applied", "2026-03-11T14:00:00Z"),
+            change(2, "interviewing", "2026-03-16T12:00:00Z"),
+            change(4, "applied", "2026-01-06T12:00:00Z"),
+            change(5, "rejected", "2026-03-17T12:00:00Z"),
+        ];
+        let funnel = funnel(&jobs, &history, &default_job_statuses(), 2, today);
+
+        assert_eq!(funnel.since, "2026-03-09");
+        let entered = |week: usize, status: &str| {
+            funnel.weeks[week].entered.iter().find(|s| s.status == status).unwrap().count
+        };
+        assert_eq!((funnel.weeks[0].added, funnel.weeks[1].added), (2, 2));
+        assert_eq!(entered(0, "applied"), 2, "job 2 applying twice in a week counts once");
+        assert_eq!((entered(1, "interviewing"), entered(1, "rejected")), (1, 2));
+        assert!(funnel.weeks[0].entered.iter().all(|s| s.status != "new"));
+
+        let stages: Vec<(&str, usize)> = funnel.stages.iter().map(|s| (s.status.as_str(), s.reached)).collect();
+        assert_eq!(stages, vec![
+            ("new", 4), ("reviewing", 2), ("applied", 2), ("interviewing", 1), ("offer", 0), ("accepted", 0),
+        ]);
+        assert_eq!(funnel.added, 4);
+
+        let text = render_funnel(&funnel);
+        assert!(text.starts_with("WEEK OF     ADDED  REVIEWING  APPLIED"));
+        assert!(text.contains("Jobs added since 2026-03-09: 4"));
+    }
+
+    #[test]
+    fn test_compute_empty() {
+        let stats = compute(&[], &default_job_statuses(), &[], 4, NaiveDate::from_ymd_opt(2026, 3, 18).unwrap());
+        assert_eq!(stats.applications_per_week.len(), 4);
+        assert!(stats.pay_histogram.is_empty());
+        assert_eq!(stats.conversion.applied_rate, None);
+        assert!(render(&stats).contains("No fit analyses yet"));
+    }
+}
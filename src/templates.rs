@@ -0,0 +1,222 @@
+use anyhow::Result;
+use minijinja::Environment;
+use chrono::NaiveDate;
+use serde::Serialize;
+use std::path::{Path, PathBuf};
+
+use crate::db::{self, Database, DigestActivity};
+use crate::error::HuntError;
+use crate::models::{CompBand, Contact, Employer, FitAnalysis, HnStory, Job, JobKeyword, JobNote, JobSummary, NextAction};
+use crate::timestamps;
+
+/// What `show --template` renders against, and what each entry of `list --template`'s
+/// `jobs` holds. Also what `list`, `show` and `rank` print with `--json`, so fields are
+/// only ever added, not renamed.
+#[derive(Debug, Serialize)]
+pub struct JobContext {
+    pub job: Job,
+    pub score: f64,
+    pub tags: Vec<String>,
+    pub notes: Vec<JobNote>,
+    pub keyword_model: Option<String>,
+    pub keywords: Vec<JobKeyword>,
+    pub keyword_profile: Option<String>,
+    pub summary: Option<JobSummary>,
+    pub fit: Option<FitAnalysis>,
+    pub skill_match: Option<f64>, // 0.0-1.0
+    pub next_action: Option<NextAction>,
+    pub contacts: Vec<Contact>,
+}
+
+pub fn job_context(db: &Database, job: Job) -> Result<JobContext> {
+    let keyword_model = db.get_latest_keyword_model(job.id)?;
+    let keywords = match &keyword_model {
+        Some(model) => db.get_job_keywords(job.id, Some(model))?,
+        None => Vec::new(),
+    };
+    Ok(JobContext {
+        score: db::calculate_score(&job, db, &db.list_job_statuses()?),
+        tags: db.get_job_tags(job.id)?,
+        notes: db.list_job_notes(job.id)?,
+        keyword_profile: db.get_keyword_profile(job.id)?.map(|p| p.profile),
+        summary: db.get_job_summary(job.id)?,
+        fit: db.get_best_fit_analysis(job.id)?,
+        skill_match: db.get_skill_match(job.id)?,
+        next_action: db.get_next_action(job.id)?,
+        contacts: db.list_job_contacts(&job)?,
+        keyword_model,
+        keywords,
+        job,
+    })
+}
+
+/// What `employer show --template` renders against, and what `employer show --json` prints.
+#[derive(Debug, Serialize)]
+pub struct EmployerContext {
+    pub employer: Employer,
+    pub aliases: Vec<String>,
+    pub hn_stories: Vec<HnStory>,
+    pub comp: Vec<CompBand>,
+    pub contacts: Vec<Contact>,
+    pub jobs: Vec<Job>,
+}
+
+pub fn employer_context(db: &Database, employer: Employer) -> Result<EmployerContext> {
+    Ok(EmployerContext {
+        aliases: db.list_employer_aliases(Some(employer.id))?.into_iter().map(|a| a.alias).collect(),
+        hn_stories: db.list_employer_hn_stories(employer.id)?,
+        comp: db.list_employer_comp(employer.id)?,
+        contacts: db.list_contacts(Some(employer.id))?,
+        jobs: db.list_jobs(None, Some(&employer.name))?,
+        employer,
+    })
+}
+
+/// A job in `digest --template`'s `top_unapplied`.
+#[derive(Debug, Serialize)]
+pub struct RankedJob<'a> {
+    pub job: &'a Job,
+    pub score: f64,
+}
+
+/// What `digest --template` renders against: the window (`from`/`to`, YYYY-MM-DD) and its activity.
+#[derive(Debug, Serialize)]
+pub struct DigestContext<'a> {
+    pub from: String,
+    pub to: String,
+    pub new_jobs: &'a [Job],
+    pub applied: &'a [Job],
+    pub interviews: &'a [Job],
+    pub top_unapplied: Vec<RankedJob<'a>>,
+    pub employers_researched: &'a [Employer],
+}
+
+pub fn digest_context(activity: &DigestActivity, from: NaiveDate, to: NaiveDate) -> DigestContext<'_> {
+    DigestContext {
+        from: from.format("%Y-%m-%d").to_string(),
+        to: to.format("%Y-%m-%d").to_string(),
+        new_jobs: &activity.new_jobs,
+        applied: &activity.applied,
+        interviews: &activity.interviews,
+        top_unapplied: activity.top_unapplied.iter().map(|(job, score)| RankedJob { job, score: *score }).collect(),
+        employers_researched: &activity.employers_researched,
+    }
+}
+
+/// Find a template: the path as given, else `templates/<name>` next to the database.
+pub fn resolve(db: &Database, name: &Path) -> Result<PathBuf> {
+    if name.exists() {
+        return Ok(name.to_path_buf());
+    }
+    let in_data_dir = db.path().with_file_name("templates").join(name);
+    if in_data_dir.exists() {
+        return Ok(in_data_dir);
+    }
+    Err(HuntError::NotFound(format!(
+        "Template '{}' not found (also looked in {})",
+        name.display(),
+        in_data_dir.display()
+    )).into())
+}
+
+pub fn render_file(path: &Path, context: impl Serialize) -> Result<String> {
+    let source = std::fs::read_to_string(path)?;
+    render(&source, context)
+        .map_err(|e| HuntError::Validation(format!("Template {}: {}", path.display(), e)).into())
+}
+
+/// Render with the extra filters: `local_time` (stored timestamp → local time),
+/// `money` (150000 → $150k) and `percent` (0.75 → 75%).
+fn render(source: &str, context: impl Serialize) -> Result<String, minijinja::Error> {
+    let mut env = Environment::new();
+    env.set_keep_trailing_newline(true);
+    env.add_filter("local_time", |value: String| timestamps::local(&value));
+    env.add_filter("money", |value: i64| format!("${}k", value / 1000));
+    env.add_filter("percent", |value: f64| format!("{:.0}%", value * 100.0));
+    env.render_str(source, context)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_context() -> JobContext {
+        let db = Database::open_in_memory().unwrap();
+        db.init().unwrap();
+        let id = db.add_job_full("SRE", Some("Acme"), Some("https://acme.example/jobs/1"), None, Some(150000), Some(200000), None).unwrap();
+        db.add_job_tags(id, &["remote".to_string()]).unwrap();
+        db.add_job_note(id, "Referral from Sam").unwrap();
+        db.add_job_keywords(id, &[("Kubernetes".to_string(), 3)], "tech", "m").unwrap();
+        db.set_user_skills(&["kubernetes".to_string()], "manual").unwrap();
+        let job = db.get_job(id).unwrap().unwrap();
+        job_context(&db, job).unwrap()
+    }
+
+    #[test]
+    fn test_render_job_context() {
+        let template = "# {{ job.title }} @ {{ job.employer_name }}\n\
+            pay: {{ job.pay_min | money }}-{{ job.pay_max | money }}\n\
+            tags: {% for t in tags %}#{{ t }} {% endfor %}\n\
+            {% for k in keywords %}- {{ k.keyword }} ({{ k.weight }})\n{% endfor %}\
+            skills: {{ skill_match | percent }}\n\
+            {% for n in notes %}> {{ n.note }}\n{% endfor %}";
+        let out = render(template, test_context()).unwrap();
+        assert_eq!(
+            out,
+            "# SRE @ Acme\npay: $150k-$200k\ntags: #remote \n- Kubernetes (3)\nskills: 100%\n> Referral from Sam\n"
+        );
+    }
+
+    #[test]
+    fn test_job_context_json() {
+        let value = serde_json::to_value(test_context()).unwrap();
+        assert_eq!(value["job"]["title"], "SRE");
+        assert_eq!(value["tags"][0], "remote");
+        assert_eq!(value["keywords"][0]["keyword"], "Kubernetes");
+        assert_eq!(value["skill_match"], 1.0);
+        assert!(value["next_action"].is_null());
+        assert_eq!(value["contacts"], serde_json::json!([]));
+    }
+
+    #[test]
+    fn test_render_employer_context() {
+        let db = Database::open_in_memory().unwrap();
+        db.init().unwrap();
+        db.add_job_full("SRE", Some("Acme"), None, None, None, None, None).unwrap();
+        let employer = db.get_employer_by_name("Acme").unwrap().unwrap();
+        db.add_employer_alias("Acme Corp", employer.id).unwrap();
+        let template = "# {{ employer.name }} ({{ employer.status }})\n\
+            aka: {{ aliases | join(\", \") }}\n\
+            {% for j in jobs %}- {{ j.title }}\n{% endfor %}";
+        let out = render(template, employer_context(&db, employer).unwrap()).unwrap();
+        assert_eq!(out, "# Acme (ok)\naka: Acme Corp\n- SRE\n");
+    }
+
+    #[test]
+    fn test_render_digest_context() {
+        let db = Database::open_in_memory().unwrap();
+        db.init().unwrap();
+        let applied = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None).unwrap();
+        db.add_job_full("Platform Lead", Some("Globex"), None, None, None, None, None).unwrap();
+        db.update_job_status(applied, "applied").unwrap();
+        let activity = db.get_digest_activity("2000-01-01T00:00:00Z", 5).unwrap();
+        let from = NaiveDate::from_ymd_opt(2026, 3, 8).unwrap();
+        let to = NaiveDate::from_ymd_opt(2026, 3, 15).unwrap();
+        let template = "{{ from }}..{{ to }}: {{ new_jobs | length }} new\n\
+            {% for j in applied %}applied: {{ j.title }}\n{% endfor %}\
+            {% for r in top_unapplied %}todo: {{ r.job.title }}\n{% endfor %}";
+        let out = render(template, digest_context(&activity, from, to)).unwrap();
+        assert_eq!(out, "2026-03-08..2026-03-15: 2 new\napplied: SRE\ntodo: Platform Lead\n");
+    }
+
+    #[test]
+    fn test_render_local_time_filter() {
+        let out = render("{{ ts | local_time }}", minijinja::context! { ts => "not a time" }).unwrap();
+        assert_eq!(out, "not a time");
+    }
+
+    #[test]
+    fn test_render_reports_syntax_errors() {
+        assert!(render("{% for x in %}", test_context()).is_err());
+    }
+}
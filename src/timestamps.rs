@@ -0,0 +1,73 @@
+use chrono::{DateTime, Local, NaiveDateTime, SecondsFormat, TimeZone, Utc};
+
+/// Parse a stored timestamp. Current rows are RFC3339 UTC; older rows (and some
+/// archives) use SQLite's `YYYY-MM-DD HH:MM:SS`, which was also UTC.
+pub fn parse(value: &str) -> Option<DateTime<Utc>> {
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+    ["%Y-%m-%d %H:%M:%S", "%Y-%m-%dT%H:%M:%S"]
+        .iter()
+        .find_map(|fmt| NaiveDateTime::parse_from_str(value, fmt).ok())
+        .map(|naive| naive.and_utc())
+}
+
+/// Format for storage and for comparing against stored values in SQL.
+pub fn to_db(dt: DateTime<Utc>) -> String {
+    dt.to_rfc3339_opts(SecondsFormat::Secs, true)
+}
+
+/// A stored timestamp in the user's local time zone, e.g. `2026-03-01 09:15`.
+/// Unparseable values are shown as-is.
+pub fn local(value: &str) -> String {
+    format_in(value, &Local)
+}
+
+/// A local wall-clock time (e.g. midnight of a `--since` date) in storage format.
+pub fn local_to_db(naive: NaiveDateTime) -> String {
+    match Local.from_local_datetime(&naive).earliest() {
+        Some(dt) => to_db(dt.with_timezone(&Utc)),
+        // Skipped by a DST jump; treating it as UTC is off by at most the offset
+        None => to_db(naive.and_utc()),
+    }
+}
+
+fn format_in<Tz: TimeZone>(value: &str, tz: &Tz) -> String
+where
+    Tz::Offset: std::fmt::Display,
+{
+    match parse(value) {
+        Some(dt) => dt.with_timezone(tz).format("%Y-%m-%d %H:%M").to_string(),
+        None => value.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::FixedOffset;
+
+    #[test]
+    fn test_parse_accepts_rfc3339_and_legacy() {
+        let expected = Utc.with_ymd_and_hms(2026, 3, 1, 14, 30, 0).unwrap();
+        assert_eq!(parse("2026-03-01T14:30:00Z"), Some(expected));
+        assert_eq!(parse("2026-03-01T09:30:00-05:00"), Some(expected));
+        assert_eq!(parse("2026-03-01 14:30:00"), Some(expected));
+        assert_eq!(parse("yesterday"), None);
+    }
+
+    #[test]
+    fn test_to_db_roundtrip() {
+        let dt = Utc.with_ymd_and_hms(2026, 3, 1, 14, 30, 0).unwrap();
+        assert_eq!(to_db(dt), "2026-03-01T14:30:00Z");
+        assert_eq!(parse(&to_db(dt)), Some(dt));
+    }
+
+    #[test]
+    fn test_format_in_converts_zone() {
+        let eastern = FixedOffset::west_opt(5 * 3600).unwrap();
+        assert_eq!(format_in("2026-03-01T02:30:00Z", &eastern), "2026-02-28 21:30");
+        assert_eq!(format_in("2026-03-01 02:30:00", &eastern), "2026-02-28 21:30");
+        assert_eq!(format_in("not a time", &eastern), "not a time");
+    }
+}
@@ -0,0 +1,124 @@
+use std::collections::{HashMap, HashSet};
+
+use anyhow::Result;
+use chrono::{Duration, Local, NaiveDate};
+
+use crate::error::HuntError;
+use crate::models::Job;
+use crate::timestamps;
+
+/// Statuses that go quiet, how many days of silence warrant a nudge, and the nudge.
+pub const FOLLOW_UPS: &[(&str, i64, &str)] = &[
+    ("applied", 10, "with no response — follow up?"),
+    ("interviewing", 7, "with no update — check in?"),
+    ("offer", 3, "with the offer open — respond or negotiate?"),
+];
+
+/// Parse `--due`: `today`, `tomorrow`, days or weeks from today (`3d`, `2w`) or `YYYY-MM-DD`.
+pub fn parse_due(spec: &str, today: NaiveDate) -> Result<NaiveDate> {
+    let spec = spec.trim().to_lowercase();
+    if let Ok(date) = NaiveDate::parse_from_str(&spec, "%Y-%m-%d") {
+        return Ok(date);
+    }
+    let invalid = || HuntError::Validation(format!(
+        "Invalid due date '{}'. Use today, tomorrow, e.g. 3d or 2w, or YYYY-MM-DD", spec
+    ));
+    let days = match spec.as_str() {
+        "today" => 0,
+        "tomorrow" => 1,
+        _ => {
+            let (amount, unit) = spec.split_at(spec.len().saturating_sub(1));
+            let amount: i64 = amount.parse().map_err(|_| invalid())?;
+            match unit {
+                "d" => amount,
+                "w" => amount * 7,
+                _ => return Err(invalid().into()),
+            }
+        }
+    };
+    Ok(today + Duration::days(days))
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Suggestion {
+    pub job_id: i64,
+    pub days: i64, // since the job entered its status
+    pub text: String,
+}
+
+/// Nudges for jobs sitting in a `FOLLOW_UPS` status past its limit. `entered` is when each
+/// job entered its current status (else its `updated_at` counts); jobs with a next action
+/// already planned are left alone. Longest waits first.
+pub fn suggestions(
+    jobs: &[Job],
+    entered: &HashMap<i64, String>,
+    planned: &HashSet<i64>,
+    today: NaiveDate,
+) -> Vec<Suggestion> {
+    let mut found: Vec<Suggestion> = jobs
+        .iter()
+        .filter(|job| !planned.contains(&job.id))
+        .filter_map(|job| {
+            let (status, limit, nudge) = FOLLOW_UPS.iter().find(|(status, _, _)| *status == job.status)?;
+            let since = entered.get(&job.id).unwrap_or(&job.updated_at);
+            let date = timestamps::parse(since)?.with_timezone(&Local).date_naive();
+            let days = (today - date).num_days();
+            (days >= *limit).then(|| Suggestion {
+                job_id: job.id,
+                days,
+                text: format!("{} {} days ago {}", status, days, nudge),
+            })
+        })
+        .collect();
+    found.sort_by_key(|s| (-s.days, s.job_id));
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(id: i64, status: &str, updated_at: &str) -> Job {
+        Job {
+            id,
+            title: "SRE".to_string(),
+            status: status.to_string(),
+            created_at: updated_at.to_string(),
+            updated_at: updated_at.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_due() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 20).unwrap();
+        assert_eq!(parse_due("today", today).unwrap(), today);
+        assert_eq!(parse_due("Tomorrow", today).unwrap(), NaiveDate::from_ymd_opt(2026, 3, 21).unwrap());
+        assert_eq!(parse_due("3d", today).unwrap(), NaiveDate::from_ymd_opt(2026, 3, 23).unwrap());
+        assert_eq!(parse_due("2w", today).unwrap(), NaiveDate::from_ymd_opt(2026, 4, 3).unwrap());
+        assert_eq!(parse_due("2026-04-01", today).unwrap(), NaiveDate::from_ymd_opt(2026, 4, 1).unwrap());
+        assert!(parse_due("soon", today).is_err());
+        assert!(parse_due("3m", today).is_err());
+    }
+
+    #[test]
+    fn test_suggestions() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 20).unwrap();
+        let jobs = vec![
+            job(1, "applied", "2026-03-19T12:00:00Z"),
+            job(2, "applied", "2026-03-19T12:00:00Z"),
+            job(3, "applied", "2026-03-15T12:00:00Z"),
+            job(4, "interviewing", "2026-03-01T12:00:00Z"),
+            job(5, "new", "2026-01-01T12:00:00Z"),
+            job(6, "applied", "2026-02-01T12:00:00Z"),
+        ];
+        // Job 2 was touched yesterday but applied to two weeks ago
+        let entered = HashMap::from([(2, "2026-03-06T12:00:00Z".to_string())]);
+        let planned = HashSet::from([6]);
+
+        let found = suggestions(&jobs, &entered, &planned, today);
+        assert_eq!(found.iter().map(|s| s.job_id).collect::<Vec<_>>(), vec![4, 2]);
+        assert_eq!(found[0].text, "interviewing 19 days ago with no update — check in?");
+        assert_eq!(found[1].text, "applied 14 days ago with no response — follow up?");
+    }
+}
@@ -0,0 +1,266 @@
+use anyhow::Result;
+use chrono::{Datelike, Local, NaiveDate};
+
+use crate::error::HuntError;
+use crate::models::{GlassdoorSnapshot, Job};
+use crate::timestamps;
+
+/// Role families matched against lowercased titles, first match wins (so an
+/// "Engineering Manager, Infrastructure" counts as management).
+pub const ROLE_FAMILIES: &[(&str, &[&str])] = &[
+    ("management", &["manager", "director", "head of", "vp", "vice president"]),
+    ("sre", &["sre", "site reliability", "reliability engineer"]),
+    ("devops", &["devops", "dev ops", "build engineer", "release engineer"]),
+    ("data", &["data engineer", "data platform", "analytics engineer", "etl"]),
+    ("platform", &["platform"]),
+    ("infrastructure", &["infrastructure", "cloud engineer", "systems engineer"]),
+    ("security", &["security", "appsec", "devsecops"]),
+    ("ml", &["machine learning", "ml engineer", "mlops", "ai engineer"]),
+    ("frontend", &["frontend", "front-end", "front end", "ui engineer"]),
+    ("backend", &["backend", "back-end", "back end", "api engineer"]),
+    ("fullstack", &["full stack", "full-stack", "fullstack"]),
+    ("mobile", &["ios", "android", "mobile"]),
+];
+
+/// Which role family a job title belongs to, if any. Short patterns ("sre", "vp")
+/// must be whole words; longer ones can appear anywhere.
+pub fn role_family(title: &str) -> Option<&'static str> {
+    let title = title.to_lowercase();
+    let words: Vec<&str> = title.split(|c: char| !c.is_alphanumeric()).filter(|w| !w.is_empty()).collect();
+    ROLE_FAMILIES
+        .iter()
+        .find(|(_, patterns)| {
+            patterns.iter().any(|p| if p.len() <= 3 { words.contains(p) } else { title.contains(p) })
+        })
+        .map(|(family, _)| *family)
+}
+
+/// Parse `--window`: a number of months (`6m`) or years (`1y`).
+pub fn parse_window(spec: &str) -> Result<u32> {
+    let spec = spec.trim();
+    let invalid = || HuntError::Validation(format!("Invalid --window '{}'. Use e.g. 6m or 1y", spec));
+    let (amount, unit) = spec.split_at(spec.len().saturating_sub(1));
+    let amount: u32 = amount.parse().map_err(|_| invalid())?;
+    let months = match unit {
+        "m" => amount,
+        "y" => amount * 12,
+        _ => return Err(invalid().into()),
+    };
+    if months == 0 {
+        return Err(invalid().into());
+    }
+    Ok(months)
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct PayBucket {
+    pub month: String, // YYYY-MM, local time
+    pub jobs: usize,   // jobs with any advertised pay
+    pub median_min: Option<i64>,
+    pub median_max: Option<i64>,
+    pub median_mid: Option<i64>, // midpoint of each range, or the one end given
+}
+
+/// Median advertised pay per month for the last `months` months up to `today`,
+/// oldest first. Months without data are kept (with `jobs: 0`) so gaps show.
+pub fn pay_by_month(jobs: &[Job], family: Option<&str>, months: u32, today: NaiveDate) -> Vec<PayBucket> {
+    let month_index = |date: NaiveDate| date.year() * 12 + date.month0() as i32;
+    let last = month_index(today);
+    let first = last - months as i32 + 1;
+
+    let mut buckets: Vec<(Vec<i64>, Vec<i64>, Vec<i64>)> = vec![Default::default(); months as usize];
+    for job in jobs {
+        if family.is_some() && role_family(&job.title) != family {
+            continue;
+        }
+        let mid = match (job.pay_min, job.pay_max) {
+            (Some(min), Some(max)) => (min + max) / 2,
+            (Some(pay), None) | (None, Some(pay)) => pay,
+            (None, None) => continue,
+        };
+        let Some(created) = timestamps::parse(&job.created_at) else { continue };
+        let index = month_index(created.with_timezone(&Local).date_naive());
+        if index < first || index > last {
+            continue;
+        }
+        let (mins, maxes, mids) = &mut buckets[(index - first) as usize];
+        mins.extend(job.pay_min);
+        maxes.extend(job.pay_max);
+        mids.push(mid);
+    }
+
+    buckets
+        .into_iter()
+        .enumerate()
+        .map(|(offset, (mut mins, mut maxes, mut mids))| {
+            let index = first + offset as i32;
+            PayBucket {
+                month: format!("{:04}-{:02}", index.div_euclid(12), index.rem_euclid(12) + 1),
+                jobs: mids.len(),
+                median_min: median(&mut mins),
+                median_max: median(&mut maxes),
+                median_mid: median(&mut mids),
+            }
+        })
+        .collect()
+}
+
+fn median(values: &mut [i64]) -> Option<i64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_unstable();
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        Some((values[mid - 1] + values[mid]) / 2)
+    } else {
+        Some(values[mid])
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct RatingMonth {
+    pub month: String, // YYYY-MM, local time
+    pub rating: f64,
+    pub review_count: i64,
+    pub positive: i64,
+    pub neutral: i64,
+    pub negative: i64,
+    pub change: Option<f64>, // vs. the previous month with a fetch
+}
+
+/// The last Glassdoor fetch of each month (snapshots oldest first), with the rating's
+/// move since the month before. Only months with a fetch are listed.
+pub fn rating_by_month(snapshots: &[GlassdoorSnapshot]) -> Vec<RatingMonth> {
+    let mut months: Vec<RatingMonth> = Vec::new();
+    for snapshot in snapshots {
+        let Some(fetched) = timestamps::parse(&snapshot.fetched_at) else { continue };
+        let month = fetched.with_timezone(&Local).format("%Y-%m").to_string();
+        let entry = RatingMonth {
+            month,
+            rating: snapshot.rating,
+            review_count: snapshot.review_count,
+            positive: snapshot.positive,
+            neutral: snapshot.neutral,
+            negative: snapshot.negative,
+            change: None,
+        };
+        match months.last_mut() {
+            Some(last) if last.month == entry.month => *last = RatingMonth { change: last.change, ..entry },
+            _ => months.push(entry),
+        }
+    }
+    for i in 1..months.len() {
+        months[i].change = Some(months[i].rating - months[i - 1].rating);
+    }
+    months
+}
+
+/// One block character per value, scaled between the smallest and largest; gaps are spaces.
+pub fn sparkline(values: &[Option<i64>]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    let present: Vec<i64> = values.iter().flatten().copied().collect();
+    let (Some(&low), Some(&high)) = (present.iter().min(), present.iter().max()) else {
+        return " ".repeat(values.len());
+    };
+    values
+        .iter()
+        .map(|value| match value {
+            Some(v) if high > low => BARS[((v - low) * 7 / (high - low)) as usize],
+            Some(_) => BARS[3],
+            None => ' ',
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn job(title: &str, pay: (Option<i64>, Option<i64>), created_at: &str) -> Job {
+        Job {
+            title: title.to_string(),
+            status: "new".to_string(),
+            pay_min: pay.0,
+            pay_max: pay.1,
+            created_at: created_at.to_string(),
+            updated_at: created_at.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_role_family() {
+        assert_eq!(role_family("Senior SRE"), Some("sre"));
+        assert_eq!(role_family("Site Reliability Engineer II"), Some("sre"));
+        assert_eq!(role_family("Staff DevOps Engineer"), Some("devops"));
+        assert_eq!(role_family("Engineering Manager, Infrastructure"), Some("management"));
+        assert_eq!(role_family("Platform Engineer - Kubernetes"), Some("platform"));
+        assert_eq!(role_family("SRE/DevOps (remote)"), Some("sre"));
+        assert_eq!(role_family("Presales Consultant"), None, "'sre' inside a word doesn't count");
+    }
+
+    #[test]
+    fn test_parse_window() {
+        assert_eq!(parse_window("6m").unwrap(), 6);
+        assert_eq!(parse_window("2y").unwrap(), 24);
+        assert!(parse_window("0m").is_err());
+        assert!(parse_window("6w").is_err());
+    }
+
+    #[test]
+    fn test_pay_by_month() {
+        let today = NaiveDate::from_ymd_opt(2026, 3, 20).unwrap();
+        let jobs = vec![
+            job("SRE", (Some(150_000), Some(190_000)), "2026-01-15T12:00:00Z"),
+            job("SRE", (Some(170_000), None), "2026-01-16T12:00:00Z"),
+            job("Senior SRE", (Some(180_000), Some(220_000)), "2026-03-10T12:00:00Z"),
+            job("SRE", (None, None), "2026-03-11T12:00:00Z"),
+            job("Frontend Engineer", (Some(90_000), Some(100_000)), "2026-03-12T12:00:00Z"),
+            job("SRE", (Some(100_000), Some(100_000)), "2025-06-15T12:00:00Z"),
+        ];
+        let buckets = pay_by_month(&jobs, Some("sre"), 3, today);
+        assert_eq!(buckets.iter().map(|b| b.month.as_str()).collect::<Vec<_>>(), vec!["2026-01", "2026-02", "2026-03"]);
+        assert_eq!(buckets[0].jobs, 2);
+        assert_eq!(buckets[0].median_min, Some(160_000));
+        assert_eq!(buckets[0].median_max, Some(190_000));
+        assert_eq!(buckets[0].median_mid, Some(170_000));
+        assert_eq!(buckets[1].jobs, 0);
+        assert_eq!(buckets[1].median_mid, None);
+        assert_eq!(buckets[2].median_mid, Some(200_000));
+
+        let all = pay_by_month(&jobs, None, 3, today);
+        assert_eq!(all[2].jobs, 2, "frontend counted without a role filter");
+    }
+
+    #[test]
+    fn test_rating_by_month() {
+        let snapshot = |rating: f64, positive: i64, fetched_at: &str| GlassdoorSnapshot {
+            id: 0,
+            employer_id: 1,
+            rating,
+            review_count: positive + 1,
+            positive,
+            neutral: 0,
+            negative: 1,
+            fetched_at: fetched_at.to_string(),
+        };
+        let months = rating_by_month(&[
+            snapshot(3.5, 3, "2026-01-10T12:00:00Z"),
+            snapshot(3.8, 5, "2026-01-20T12:00:00Z"),
+            snapshot(3.2, 4, "2026-03-15T12:00:00Z"),
+            snapshot(9.9, 9, "not a timestamp"),
+        ]);
+        assert_eq!(months.iter().map(|m| m.month.as_str()).collect::<Vec<_>>(), vec!["2026-01", "2026-03"]);
+        assert_eq!((months[0].rating, months[0].positive, months[0].change), (3.8, 5, None), "last fetch of the month wins");
+        assert!((months[1].change.unwrap() + 0.6).abs() < 1e-9);
+        assert!(rating_by_month(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_sparkline() {
+        assert_eq!(sparkline(&[Some(100), None, Some(200), Some(150)]), "▁ █▄");
+        assert_eq!(sparkline(&[Some(5), Some(5)]), "▄▄");
+        assert_eq!(sparkline(&[None, None]), "  ");
+    }
+}
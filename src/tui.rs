@@ -6,14 +6,30 @@ use crossterm::{
 };
 use ratatui::{
     prelude::*,
-    widgets::{Block, Borders, List, ListItem, ListState, Paragraph, Wrap},
+    widgets::{Block, Borders, Clear, List, ListItem, ListState, Paragraph, Tabs, Wrap},
 };
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
 use std::io::stdout;
-
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc::{self, Receiver, TryRecvError};
+use std::time::{Duration, Instant};
+
+use crate::ai;
+use crate::browser::{self, PlainFetch};
+use crate::dates;
 use crate::db::{self, Database};
-use crate::models::{FitAnalysis, Job, JobKeyword, JobKeywordProfile};
-
-#[derive(Clone, Copy, Debug, PartialEq)]
+use crate::domains::{self, EmployerLinks};
+use crate::error::HuntError;
+use crate::flags;
+use crate::location;
+use crate::pay;
+use crate::models::{Contact, Employer, FitAnalysis, Job, JobKeyword, JobKeywordProfile, JobNote, JobStatus, JobSummary, SavedFilter};
+use crate::timestamps;
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
 enum SortField {
     Score,
     Salary,
@@ -32,56 +48,645 @@ impl SortField {
     }
 }
 
+/// Optional job list columns, between the status/id and the title.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Column {
+    Score,
+    Fit,
+    Salary,
+    Age,
+    Source,
+    Mode,     // remote/hybrid/onsite
+    Location,
+    Applied, // days since applied
+    Deadline, // days until applications close
+    Flags,    // red flags found in the description (`hunt flags`)
+    Tags,    // appended to the title rather than a column of its own
+}
+
+impl Column {
+    const ALL: [Column; 11] = [
+        Column::Score, Column::Fit, Column::Salary, Column::Age, Column::Source, Column::Mode, Column::Location,
+        Column::Applied, Column::Deadline, Column::Flags, Column::Tags,
+    ];
+    const DEFAULT: [Column; 3] = [Column::Fit, Column::Salary, Column::Tags];
+
+    fn name(self) -> &'static str {
+        match self {
+            Column::Score => "score",
+            Column::Fit => "fit",
+            Column::Salary => "salary",
+            Column::Age => "age",
+            Column::Source => "source",
+            Column::Mode => "mode",
+            Column::Location => "location",
+            Column::Applied => "applied",
+            Column::Deadline => "deadline",
+            Column::Flags => "flags",
+            Column::Tags => "tags",
+        }
+    }
+
+    /// Width including the trailing space.
+    fn width(self) -> usize {
+        match self {
+            Column::Score => 5,
+            Column::Fit => 4,
+            Column::Salary => 6,
+            Column::Age => 5,
+            Column::Source => 9,
+            Column::Mode => 7,
+            Column::Location => 17,
+            Column::Applied => 5,
+            Column::Deadline => 5,
+            Column::Flags => 4,
+            Column::Tags => 0,
+        }
+    }
+
+    /// Columns from the config file's "columns" list; an empty list shows none.
+    fn parse_list(names: &[String]) -> Result<Vec<Column>> {
+        let mut columns = Vec::new();
+        for name in names {
+            let Some(column) = Column::ALL.iter().find(|c| c.name() == name.trim().to_lowercase()) else {
+                let all: Vec<&str> = Column::ALL.iter().map(|c| c.name()).collect();
+                return Err(HuntError::Config(format!("Unknown column '{}' (expected one of: {})", name, all.join(", "))).into());
+            };
+            if columns.contains(column) {
+                return Err(HuntError::Config(format!("Column '{}' is listed twice", column.name())).into());
+            }
+            columns.push(*column);
+        }
+        Ok(columns)
+    }
+}
+
+/// Sort and filters, saved next to the database when the browser closes and restored
+/// the next time it opens.
+#[derive(Debug, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+struct SavedView {
+    sort_field: SortField,
+    sort_ascending: bool,
+    hide_closed: bool,
+    search: String,
+    employer: Option<(i64, String)>,
+}
+
+impl Default for SavedView {
+    fn default() -> Self {
+        SavedView { sort_field: SortField::Score, sort_ascending: false, hide_closed: true, search: String::new(), employer: None }
+    }
+}
+
+impl SavedView {
+    fn path(db: &Database) -> PathBuf {
+        db.path().with_file_name("tui-state.json")
+    }
+
+    /// The saved view, or the defaults if there's none or it can't be read.
+    fn load(path: &Path) -> SavedView {
+        std::fs::read_to_string(path)
+            .ok()
+            .and_then(|text| serde_json::from_str(&text).ok())
+            .unwrap_or_default()
+    }
+
+    fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+/// The two tabs, switched with Tab.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum View {
+    Jobs,
+    Employers,
+}
+
+/// Pipeline steps that can run on the selected job without leaving the browser.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Action {
+    Fetch,
+    Keywords,
+    Fit,
+}
+
+impl Action {
+    fn label(self) -> &'static str {
+        match self {
+            Action::Fetch => "Fetching description",
+            Action::Keywords => "Extracting keywords",
+            Action::Fit => "Analyzing fit",
+        }
+    }
+}
+
+/// The pay floor `p` toggles: `hunt browse --min-pay` (starting on) or the config's `min_pay`.
+pub struct PayFloor {
+    pub amount: Option<i64>, // annual USD; None until one is configured
+    pub on: bool,
+}
+
+/// What the `F`/`W`/`T` actions run with.
+pub struct ActionConfig {
+    pub db_path: PathBuf,
+    pub model: String,          // for keywords and fit
+    pub resume_id: Option<i64>, // base resume for fit; None disables `T`
+}
+
+enum TaskEvent {
+    Progress(String),
+    Done(Result<String, String>),
+}
+
+/// An action running on a background thread with its own database connection.
+struct Task {
+    action: Action,
+    job_id: i64,
+    progress: String,
+    started: Instant,
+    events: Receiver<TaskEvent>,
+}
+
+/// Actions whose keys can be remapped in the config file's "keys" section.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Binding {
+    StatusNew,
+    StatusReviewing,
+    StatusApplied,
+    StatusRejected,
+    StatusClosed,
+    NextStatus,
+    OpenJob,
+    OpenCareers,
+    Fetch,
+    Keywords,
+    Fit,
+    Note,
+    EditNote,
+    HideClosed,
+    Archive,
+    Filters,
+    PayFloor,
+    Board,
+    EmployerNever,
+    EmployerYuck,
+    EmployerOk,
+}
+
+impl Binding {
+    const ALL: [Binding; 21] = [
+        Binding::StatusNew, Binding::StatusReviewing, Binding::StatusApplied, Binding::StatusRejected,
+        Binding::StatusClosed, Binding::NextStatus, Binding::OpenJob, Binding::OpenCareers,
+        Binding::Fetch, Binding::Keywords, Binding::Fit, Binding::Note, Binding::EditNote,
+        Binding::HideClosed, Binding::Archive, Binding::Filters, Binding::PayFloor, Binding::Board,
+        Binding::EmployerNever, Binding::EmployerYuck, Binding::EmployerOk,
+    ];
+
+    /// (config name, default key, help text)
+    fn info(self) -> (&'static str, char, &'static str) {
+        match self {
+            Binding::StatusNew => ("status-new", 'n', "Set status: new"),
+            Binding::StatusReviewing => ("status-reviewing", 'r', "Set status: reviewing"),
+            Binding::StatusApplied => ("status-applied", 'a', "Set status: applied"),
+            Binding::StatusRejected => ("status-rejected", 'x', "Set status: rejected"),
+            Binding::StatusClosed => ("status-closed", 'c', "Set status: closed"),
+            Binding::NextStatus => ("next-status", 's', "Move to the next pipeline status"),
+            Binding::OpenJob => ("open-job", 'o', "Open the job posting"),
+            Binding::OpenCareers => ("open-careers", 'O', "Open the employer's careers page"),
+            Binding::Fetch => ("fetch", 'F', "Fetch the description"),
+            Binding::Keywords => ("keywords", 'W', "Extract keywords"),
+            Binding::Fit => ("fit", 'T', "Run a fit analysis"),
+            Binding::Note => ("note", 'e', "Add a note"),
+            Binding::EditNote => ("edit-note", 'E', "Edit the latest note in $EDITOR"),
+            Binding::HideClosed => ("hide-closed", 'H', "Show/hide closed jobs"),
+            Binding::Archive => ("archive", 'd', "Archive the job (hunt restore brings it back)"),
+            Binding::Filters => ("filters", 'f', "Pick a saved filter (hunt filter save)"),
+            Binding::PayFloor => ("pay-floor", 'p', "Show/hide jobs paying under the floor (config min_pay)"),
+            Binding::Board => ("board", 'b', "Switch between the list and the status board"),
+            Binding::EmployerNever => ("employer-never", 'b', "Employer status: never"),
+            Binding::EmployerYuck => ("employer-yuck", 'y', "Employer status: yuck"),
+            Binding::EmployerOk => ("employer-ok", 'u', "Employer status: ok"),
+        }
+    }
+
+    fn view(self) -> View {
+        match self {
+            Binding::EmployerNever | Binding::EmployerYuck | Binding::EmployerOk => View::Employers,
+            _ => View::Jobs,
+        }
+    }
+}
+
+/// Keys that can't be remapped: navigation, search, sorting, help and quit.
+const FIXED_KEYS: &[(&str, &str)] = &[
+    ("j/k, Down/Up", "Next/previous"),
+    ("Ctrl-D/Ctrl-U", "Page down/up"),
+    ("g/G", "First/last"),
+    ("J/K, PgDn/PgUp", "Scroll the detail pane"),
+    ("/", "Search (Enter keeps, Esc clears)"),
+    ("1-4", "Sort by score/salary/fit/company (again to reverse)"),
+    ("h/l", "Board: move the job to the previous/next column"),
+    ("Left/Right", "Board: previous/next column"),
+    ("Tab", "Switch between jobs and employers"),
+    ("Enter", "Employers tab: show the employer's jobs"),
+    ("Esc", "Clear the employer filter, saved filter or search, then quit"),
+    ("?", "This help"),
+    ("q", "Quit"),
+];
+const RESERVED_CHARS: &str = "jkhlgGJK/1234?q";
+
+/// The built-in statuses that get a board column. Every other non-terminal status gets
+/// one too, in pipeline order; jobs in the remaining terminal statuses stay off the board.
+const BOARD_STATUSES: [&str; 6] = ["new", "reviewing", "applied", "interviewing", "offer", "rejected"];
+
+/// Key for each `Binding`, with the config file's overrides applied.
+#[derive(Debug, Clone)]
+struct Keymap {
+    keys: Vec<(Binding, char)>,
+}
+
+impl Default for Keymap {
+    fn default() -> Self {
+        Keymap { keys: Binding::ALL.iter().map(|&b| (b, b.info().1)).collect() }
+    }
+}
+
+impl Keymap {
+    /// Apply overrides like {"status-applied": "A"}. Unknown actions, keys that aren't
+    /// a single character, reserved keys and two actions on one key are config errors.
+    fn with_overrides(overrides: &HashMap<String, String>) -> Result<Self> {
+        let mut keymap = Keymap::default();
+        for (name, key) in overrides {
+            let Some(entry) = keymap.keys.iter_mut().find(|(b, _)| b.info().0 == name) else {
+                let names: Vec<&str> = Binding::ALL.iter().map(|b| b.info().0).collect();
+                return Err(HuntError::Config(format!("Unknown key action '{}' (expected one of: {})", name, names.join(", "))).into());
+            };
+            let mut chars = key.chars();
+            let (Some(c), None) = (chars.next(), chars.next()) else {
+                return Err(HuntError::Config(format!("Key for '{}' must be a single character, got '{}'", name, key)).into());
+            };
+            if c.is_whitespace() || RESERVED_CHARS.contains(c) {
+                return Err(HuntError::Config(format!("Key '{}' for '{}' is reserved", c, name)).into());
+            }
+            entry.1 = c;
+        }
+        for (i, (a, key)) in keymap.keys.iter().enumerate() {
+            if let Some((b, _)) = keymap.keys[i + 1..].iter().find(|(b, k)| k == key && b.view() == a.view()) {
+                return Err(HuntError::Config(format!("Key '{}' is bound to both '{}' and '{}'", key, a.info().0, b.info().0)).into());
+            }
+        }
+        Ok(keymap)
+    }
+
+    fn key(&self, binding: Binding) -> char {
+        self.keys.iter().find(|(b, _)| *b == binding).map(|(_, k)| *k).unwrap_or(binding.info().1)
+    }
+
+    fn binding(&self, view: View, key: char) -> Option<Binding> {
+        self.keys.iter().find(|(b, k)| *k == key && b.view() == view).map(|(b, _)| *b)
+    }
+}
+
 struct AppState {
     jobs: Vec<Job>,
     scores: Vec<f64>,              // ranking score per job (parallel to jobs)
     fit_scores: Vec<Option<f64>>,  // raw fit score per job (parallel to jobs)
+    tags: HashMap<i64, Vec<String>>, // tags by job id
+    flags: HashMap<i64, Vec<String>>, // red flag names by job id (`hunt flags`)
+    clusters: HashMap<i64, String>, // cluster name by job id (`hunt cluster`)
     visible: Vec<usize>,           // indices into jobs matching current filter, sorted by score
     selected: usize,               // index into visible
     scroll_offset: u16,
     keywords: Vec<JobKeyword>,
     profile: Option<JobKeywordProfile>,
+    summary: Option<JobSummary>,   // `hunt summarize` bullets, shown instead of the raw text
     keyword_model: Option<String>,
     fit_analysis: Option<FitAnalysis>,
+    notes: Vec<JobNote>,
+    contacts: Vec<Contact>,        // linked to the selected job, then others at its employer
+    employer_links: EmployerLinks, // website/careers page of the selected job's employer
+    skills: Vec<String>,           // user skill profile, for the keyword match line
     search_active: bool,
     search_query: String,
-    hide_closed: bool,
+    hide_closed: bool,             // hide jobs in terminal statuses
+    statuses: Vec<JobStatus>,      // pipeline statuses, ordered by position
     sort_field: SortField,
     sort_ascending: bool,
+    message: Option<String>,       // shown in the footer until the next key press
+    task: Option<Task>,            // at most one background action at a time
+    view: View,
+    employers: Vec<Employer>,      // employers tab, busiest first
+    employer_selected: usize,      // index into employers
+    employer_filter: Option<(i64, String)>, // jobs tab limited to one employer (id, name)
+    saved_filter: Option<(String, HashSet<i64>)>, // jobs tab limited to a saved filter's matches (name, job ids)
+    filter_picker: Option<(Vec<SavedFilter>, usize)>, // saved filters overlay (filters, selected)
+    pay_floor: Option<i64>,        // annual USD, from --min-pay or the config
+    pay_floor_jobs: Option<HashSet<i64>>, // while the floor is on, the jobs paying at least it
+    note_input: Option<String>,    // new note being typed in the overlay
+    keys: Keymap,
+    show_help: bool,
+    columns: Vec<Column>,          // job list columns from the config file
+    board: bool,                   // jobs tab shows the status board instead of the list
 }
 
 impl AppState {
     fn new(jobs: Vec<Job>, db: &Database) -> Self {
-        let scores: Vec<f64> = jobs.iter().map(|j| db::calculate_score(j, db)).collect();
+        let statuses = db.list_job_statuses().unwrap_or_else(|_| db::default_job_statuses());
+        let scores: Vec<f64> = jobs.iter().map(|j| db::calculate_score(j, db, &statuses)).collect();
         let fit_scores: Vec<Option<f64>> = jobs.iter().map(|j| {
             db.get_best_fit_score(j.id).ok().flatten()
         }).collect();
 
+        let tags = db.get_all_job_tags().unwrap_or_default();
+        let flags = db.get_all_job_flags().unwrap_or_default();
+        let clusters = db.get_job_cluster_names().unwrap_or_default();
+        let skills = db.get_user_skills().unwrap_or_default();
+
         let mut s = Self {
             visible: Vec::new(),
             jobs,
             scores,
             fit_scores,
+            tags,
+            flags,
+            clusters,
             selected: 0,
             scroll_offset: 0,
             keywords: Vec::new(),
             profile: None,
+            summary: None,
             keyword_model: None,
             fit_analysis: None,
+            notes: Vec::new(),
+            contacts: Vec::new(),
+            employer_links: EmployerLinks::default(),
+            skills,
             search_active: false,
             search_query: String::new(),
             hide_closed: true,
+            statuses,
             sort_field: SortField::Score,
             sort_ascending: false,
+            message: None,
+            task: None,
+            view: View::Jobs,
+            employers: db.list_employers(None).unwrap_or_default(),
+            employer_selected: 0,
+            employer_filter: None,
+            saved_filter: None,
+            filter_picker: None,
+            pay_floor: None,
+            pay_floor_jobs: None,
+            note_input: None,
+            keys: Keymap::default(),
+            show_help: false,
+            columns: Column::DEFAULT.to_vec(),
+            board: false,
         };
+        s.sort_employers();
         s.update_filter();
         s
     }
 
+    fn is_terminal(&self, status: &str) -> bool {
+        self.statuses.iter().any(|s| s.name == status && s.is_terminal)
+    }
+
+    fn status_color(&self, status: &str) -> Option<Color> {
+        self.statuses.iter()
+            .find(|s| s.name == status)
+            .and_then(|s| s.color.parse().ok())
+    }
+
+    /// Next status in pipeline order after `status`, wrapping around.
+    fn next_status(&self, status: &str) -> Option<&str> {
+        if self.statuses.is_empty() {
+            return None;
+        }
+        let next = match self.statuses.iter().position(|s| s.name == status) {
+            Some(i) => (i + 1) % self.statuses.len(),
+            None => 0,
+        };
+        Some(&self.statuses[next].name)
+    }
+
     fn current_job(&self) -> Option<&Job> {
         self.visible.get(self.selected).and_then(|&i| self.jobs.get(i))
     }
 
+    /// The URL `o` (job posting) or `O` (employer careers page) opens, or why there is none.
+    fn url_to_open(&self, careers: bool) -> Result<&str, String> {
+        let job = self.current_job().ok_or_else(|| "No job selected".to_string())?;
+        if !careers {
+            return job.url.as_deref().ok_or_else(|| format!("Job #{} has no URL", job.id));
+        }
+        match (&self.employer_links.careers_url, &job.employer_name) {
+            (Some(url), _) => Ok(url),
+            (None, Some(employer)) => Err(format!("No careers page known for {} (try: hunt employer enrich)", employer)),
+            (None, None) => Err(format!("Job #{} has no employer", job.id)),
+        }
+    }
+
+    fn open_current(&mut self, careers: bool) {
+        self.message = Some(match self.url_to_open(careers) {
+            Ok(url) => match open_url(url) {
+                Ok(()) => format!("Opened {}", url),
+                Err(e) => format!("Couldn't open {}: {}", url, e),
+            },
+            Err(reason) => reason,
+        });
+    }
+
+    /// Why `action` can't run on the selected job right now, if it can't.
+    fn action_blocker(&self, action: Action, config: &ActionConfig) -> Option<String> {
+        if let Some(task) = &self.task {
+            return Some(format!("Still busy: {} for job #{}", task.action.label().to_lowercase(), task.job_id));
+        }
+        let Some(job) = self.current_job() else { return Some("No job selected".to_string()) };
+        match action {
+            Action::Fetch if job.url.is_none() => Some(format!("Job #{} has no URL to fetch", job.id)),
+            Action::Keywords | Action::Fit if job.raw_text.is_none() => {
+                Some(format!("Job #{} has no description yet (press F to fetch it)", job.id))
+            }
+            Action::Fit if config.resume_id.is_none() => {
+                Some("No base resume chosen: start with 'hunt browse --resume <name>'".to_string())
+            }
+            _ => None,
+        }
+    }
+
+    fn start_action(&mut self, action: Action, config: &ActionConfig) {
+        if let Some(reason) = self.action_blocker(action, config) {
+            self.message = Some(reason);
+            return;
+        }
+        let Some(job_id) = self.current_job().map(|j| j.id) else { return };
+
+        let (tx, rx) = mpsc::channel();
+        let db_path = config.db_path.clone();
+        let model = config.model.clone();
+        let resume_id = config.resume_id;
+        std::thread::spawn(move || {
+            let progress = |message: String| {
+                let _ = tx.send(TaskEvent::Progress(message));
+            };
+            let result = run_action(&db_path, action, job_id, &model, resume_id, &progress)
+                .map_err(|e| e.to_string());
+            let _ = tx.send(TaskEvent::Done(result));
+        });
+        self.task = Some(Task {
+            action,
+            job_id,
+            progress: String::new(),
+            started: Instant::now(),
+            events: rx,
+        });
+    }
+
+    /// Take progress from the background action. When it finishes, reload its job and
+    /// the detail pane. Returns true if the job list may have changed.
+    fn poll_task(&mut self, db: &Database) -> bool {
+        let Some(task) = &mut self.task else { return false };
+        let finished = loop {
+            match task.events.try_recv() {
+                Ok(TaskEvent::Progress(message)) => task.progress = message,
+                Ok(TaskEvent::Done(result)) => break Some(result),
+                Err(TryRecvError::Empty) => break None,
+                Err(TryRecvError::Disconnected) => break Some(Err("stopped unexpectedly".to_string())),
+            }
+        };
+        let Some(result) = finished else { return false };
+
+        let (action, job_id) = (task.action, task.job_id);
+        self.task = None;
+        self.message = Some(match result {
+            Ok(summary) => summary,
+            Err(e) => format!("{} failed for job #{}: {}", action.label(), job_id, e),
+        });
+        if let (Some(idx), Ok(Some(job))) = (self.jobs.iter().position(|j| j.id == job_id), db.get_job(job_id)) {
+            self.jobs[idx] = job;
+            self.scores[idx] = db::calculate_score(&self.jobs[idx], db, &self.statuses);
+            self.fit_scores[idx] = db.get_best_fit_score(job_id).ok().flatten();
+        }
+        self.flags = db.get_all_job_flags().unwrap_or_default();
+        self.update_filter();
+        self.load_keywords(db);
+        true
+    }
+
+    /// (all, active) loaded jobs per employer id.
+    fn employer_job_counts(&self) -> HashMap<i64, (usize, usize)> {
+        let mut counts: HashMap<i64, (usize, usize)> = HashMap::new();
+        for job in &self.jobs {
+            if let Some(id) = job.employer_id {
+                let entry = counts.entry(id).or_default();
+                entry.0 += 1;
+                if !self.is_terminal(&job.status) {
+                    entry.1 += 1;
+                }
+            }
+        }
+        counts
+    }
+
+    /// Most active jobs first, then by name. Done once on load so rows don't jump around.
+    fn sort_employers(&mut self) {
+        let counts = self.employer_job_counts();
+        let active = |e: &Employer| counts.get(&e.id).map_or(0, |c| c.1);
+        let mut employers = std::mem::take(&mut self.employers);
+        employers.sort_by(|a, b| active(b).cmp(&active(a)).then_with(|| a.name.to_lowercase().cmp(&b.name.to_lowercase())));
+        self.employers = employers;
+    }
+
+    fn current_employer(&self) -> Option<&Employer> {
+        self.employers.get(self.employer_selected)
+    }
+
+    fn next_employer(&mut self) {
+        if self.employer_selected + 1 < self.employers.len() {
+            self.employer_selected += 1;
+        }
+    }
+
+    fn prev_employer(&mut self) {
+        self.employer_selected = self.employer_selected.saturating_sub(1);
+    }
+
+    fn toggle_view(&mut self) {
+        self.view = match self.view {
+            View::Jobs => View::Employers,
+            View::Employers => View::Jobs,
+        };
+    }
+
+    /// Set the selected employer's status (ok/yuck/never) and rescore its jobs, since
+    /// employer status is a ranking factor.
+    fn set_current_employer_status(&mut self, db: &Database, status: &str) {
+        let Some(employer) = self.employers.get_mut(self.employer_selected) else { return };
+        if let Err(e) = db.set_employer_status(&employer.name, status) {
+            self.message = Some(format!("Couldn't update {}: {}", employer.name, e));
+            return;
+        }
+        employer.status = status.to_string();
+        let employer_id = employer.id;
+        self.message = Some(format!("{} marked '{}'", employer.name, status));
+        for idx in 0..self.jobs.len() {
+            if self.jobs[idx].employer_id == Some(employer_id) {
+                self.scores[idx] = db::calculate_score(&self.jobs[idx], db, &self.statuses);
+            }
+        }
+        self.update_filter();
+    }
+
+    /// Switch to the jobs tab showing only the selected employer's jobs.
+    fn show_employer_jobs(&mut self) {
+        let Some(employer) = self.current_employer() else { return };
+        self.employer_filter = Some((employer.id, employer.name.clone()));
+        self.view = View::Jobs;
+        self.selected = 0;
+        self.update_filter();
+    }
+
+    /// Save the overlay's text as a new note on the selected job.
+    fn save_note_input(&mut self, db: &Database) {
+        let Some(text) = self.note_input.take() else { return };
+        let Some(job_id) = self.current_job().map(|j| j.id) else { return };
+        if text.trim().is_empty() {
+            return;
+        }
+        self.message = Some(match db.add_job_note(job_id, text.trim()) {
+            Ok(_) => format!("Note added to job #{}", job_id),
+            Err(e) => format!("Couldn't save note: {}", e),
+        });
+        self.notes = db.list_job_notes(job_id).unwrap_or_default();
+    }
+
+    /// Store text from $EDITOR: it replaces the latest note (emptying it deletes it),
+    /// or becomes the first note.
+    fn save_edited_note(&mut self, db: &Database, text: &str) {
+        let Some(job_id) = self.current_job().map(|j| j.id) else { return };
+        let text = text.trim();
+        let result = match self.notes.last() {
+            Some(latest) if latest.note == text => return,
+            Some(latest) => db.update_job_note(latest.id, text)
+                .map(|_| if text.is_empty() { "Note deleted" } else { "Note updated" }),
+            None if text.is_empty() => return,
+            None => db.add_job_note(job_id, text).map(|_| "Note added"),
+        };
+        self.message = Some(match result {
+            Ok(done) => format!("{} on job #{}", done, job_id),
+            Err(e) => format!("Couldn't save note: {}", e),
+        });
+        self.notes = db.list_job_notes(job_id).unwrap_or_default();
+    }
+
+    fn job_tags(&self, job_id: i64) -> &[String] {
+        self.tags.get(&job_id).map(Vec::as_slice).unwrap_or_default()
+    }
+
     fn load_keywords(&mut self, db: &Database) {
         let Some(job) = self.current_job() else { return };
         let job_id = job.id;
@@ -95,19 +700,45 @@ impl AppState {
             self.profile = None;
         }
 
+        self.summary = db.get_job_summary(job_id).ok().flatten();
         self.fit_analysis = db.get_best_fit_analysis(job_id).ok().flatten();
+        self.notes = db.list_job_notes(job_id).unwrap_or_default();
+        self.contacts = self.current_job().and_then(|job| db.list_job_contacts(job).ok()).unwrap_or_default();
+        let employer = self.current_job()
+            .and_then(|job| job.employer_name.as_deref())
+            .and_then(|name| db.get_employer_by_name(name).ok().flatten());
+        self.employer_links = EmployerLinks {
+            domain: employer.as_ref().and_then(|e| e.domain.clone()),
+            careers_url: employer.and_then(|e| e.careers_url),
+        };
     }
 
     fn update_filter(&mut self) {
         let query = self.search_query.to_lowercase();
         self.visible = self.jobs.iter().enumerate()
             .filter(|(_, job)| {
-                if self.hide_closed && job.status == "closed" {
+                if self.hide_closed && self.is_terminal(&job.status) {
+                    return false;
+                }
+                if let Some((employer_id, _)) = &self.employer_filter
+                    && job.employer_id != Some(*employer_id)
+                {
+                    return false;
+                }
+                if let Some((_, ids)) = &self.saved_filter
+                    && !ids.contains(&job.id)
+                {
+                    return false;
+                }
+                if let Some(ids) = &self.pay_floor_jobs
+                    && !ids.contains(&job.id)
+                {
                     return false;
                 }
                 if !query.is_empty() {
                     return job.title.to_lowercase().contains(&query)
-                        || job.employer_name.as_deref().unwrap_or("").to_lowercase().contains(&query);
+                        || job.employer_name.as_deref().unwrap_or("").to_lowercase().contains(&query)
+                        || self.job_tags(job.id).iter().any(|t| t.contains(&query));
                 }
                 true
             })
@@ -180,6 +811,81 @@ impl AppState {
         self.scroll_offset = self.scroll_offset.saturating_sub(3);
     }
 
+    fn saved_view(&self) -> SavedView {
+        SavedView {
+            sort_field: self.sort_field,
+            sort_ascending: self.sort_ascending,
+            hide_closed: self.hide_closed,
+            search: self.search_query.clone(),
+            employer: self.employer_filter.clone(),
+        }
+    }
+
+    /// Apply a saved view. An employer filter is dropped if none of the loaded jobs are
+    /// theirs any more.
+    fn restore_view(&mut self, saved: SavedView) {
+        self.sort_field = saved.sort_field;
+        self.sort_ascending = saved.sort_ascending;
+        self.hide_closed = saved.hide_closed;
+        self.search_query = saved.search;
+        self.employer_filter = saved.employer
+            .filter(|(id, _)| self.jobs.iter().any(|j| j.employer_id == Some(*id)));
+        self.selected = 0;
+        self.update_filter();
+    }
+
+    /// Open the saved filter picker on the active filter, or say how to save one.
+    fn open_filter_picker(&mut self, db: &Database) {
+        match db.list_saved_filters() {
+            Ok(filters) if filters.is_empty() => {
+                self.message = Some("No saved filters yet (hunt filter save <name> --status new ...)".to_string());
+            }
+            Ok(filters) => {
+                let active = self.saved_filter.as_ref()
+                    .and_then(|(name, _)| filters.iter().position(|f| f.name == *name))
+                    .unwrap_or(0);
+                self.filter_picker = Some((filters, active));
+            }
+            Err(e) => self.message = Some(e.to_string()),
+        }
+    }
+
+    /// Limit the jobs tab to what `filter` matches now; jobs edited afterwards stay
+    /// until the filter is picked again.
+    fn apply_saved_filter(&mut self, db: &Database, filter: &SavedFilter) {
+        match db.filter_jobs(filter, false) {
+            Ok(jobs) => {
+                self.saved_filter = Some((filter.name.clone(), jobs.iter().map(|j| j.id).collect()));
+                self.selected = 0;
+                self.update_filter();
+                self.message = Some(format!("Filter '{}': {}", filter.name, filter.describe()));
+            }
+            Err(e) => self.message = Some(e.to_string()),
+        }
+    }
+
+    /// Turn the pay floor on (asking the database which jobs clear it) or off.
+    fn toggle_pay_floor(&mut self, db: &Database) {
+        if self.pay_floor_jobs.take().is_some() {
+            self.update_filter();
+            self.message = Some("Pay floor off".to_string());
+            return;
+        }
+        let Some(floor) = self.pay_floor else {
+            self.message = Some("No pay floor set (hunt config set min_pay 180000, or hunt browse --min-pay)".to_string());
+            return;
+        };
+        match db.filter_jobs(&SavedFilter { min_pay: Some(floor), ..Default::default() }, false) {
+            Ok(jobs) => {
+                self.pay_floor_jobs = Some(jobs.iter().map(|j| j.id).collect());
+                self.selected = 0;
+                self.update_filter();
+                self.message = Some(format!("Only jobs paying ${}k+", floor / 1000));
+            }
+            Err(e) => self.message = Some(e.to_string()),
+        }
+    }
+
     fn set_sort(&mut self, field: SortField) {
         if self.sort_field == field {
             self.sort_ascending = !self.sort_ascending;
@@ -191,36 +897,292 @@ impl AppState {
         self.update_filter();
     }
 
+    fn cycle_current_job_status(&mut self, db: &Database) {
+        let Some(job) = self.current_job() else { return };
+        if let Some(next) = self.next_status(&job.status).map(str::to_string) {
+            self.update_current_job_status(db, &next);
+        }
+    }
+
+    /// Archive the selected job (`Database::delete_job`) and drop it from the list.
+    fn archive_current_job(&mut self, db: &Database) {
+        let Some(&idx) = self.visible.get(self.selected) else { return };
+        let job_id = self.jobs[idx].id;
+        if let Err(e) = db.delete_job(job_id) {
+            self.message = Some(e.to_string());
+            return;
+        }
+        self.jobs.remove(idx);
+        self.scores.remove(idx);
+        self.fit_scores.remove(idx);
+        self.update_filter();
+        self.message = Some(format!("Archived job #{} (hunt restore {} to undo)", job_id, job_id));
+    }
+
     fn update_current_job_status(&mut self, db: &Database, status: &str) {
         if let Some(&idx) = self.visible.get(self.selected) {
             let job_id = self.jobs[idx].id;
-            let _ = db.update_job_status(job_id, status);
-            self.jobs[idx].status = status.to_string();
+            match db.transition_refusal(&self.jobs[idx].status, status) {
+                Ok(Some(reason)) => {
+                    self.message = Some(format!("{} (hunt status set {} {} --force)", reason, job_id, status));
+                    return;
+                }
+                Ok(None) => {}
+                Err(e) => {
+                    self.message = Some(e.to_string());
+                    return;
+                }
+            }
+            if db.update_job_status(job_id, status).is_err() {
+                return;
+            }
+            // Picks up applied_at and the other status timestamps
+            match db.get_job(job_id) {
+                Ok(Some(job)) => self.jobs[idx] = job,
+                _ => self.jobs[idx].status = status.to_string(),
+            }
             // Recompute score for this job
-            self.scores[idx] = db::calculate_score(&self.jobs[idx], db);
+            self.scores[idx] = db::calculate_score(&self.jobs[idx], db, &self.statuses);
+        }
+    }
+
+    /// The board's columns, left to right: `statuses` (ordered by position) that are
+    /// in `BOARD_STATUSES` or not terminal.
+    fn board_statuses(&self) -> Vec<&str> {
+        self.statuses.iter()
+            .filter(|s| !s.is_terminal || BOARD_STATUSES.contains(&s.name.as_str()))
+            .map(|s| s.name.as_str())
+            .collect()
+    }
+
+    /// Board column of the selected job, if it's on the board.
+    fn board_column(&self) -> Option<usize> {
+        let job = self.current_job()?;
+        self.board_statuses().iter().position(|&s| s == job.status)
+    }
+
+    /// Positions in `visible` of the jobs in board column `column`, in list order.
+    fn board_jobs(&self, column: usize) -> Vec<usize> {
+        let status = self.board_statuses()[column];
+        self.visible.iter().enumerate()
+            .filter(|&(_, &idx)| self.jobs[idx].status == status)
+            .map(|(pos, _)| pos)
+            .collect()
+    }
+
+    fn toggle_board(&mut self) {
+        self.board = !self.board;
+        if self.board {
+            self.board_step(0);
+        }
+    }
+
+    /// Move `delta` jobs down (up when negative) the selected job's column. With the
+    /// selected job off the board, pick the first job in the leftmost non-empty column.
+    fn board_step(&mut self, delta: isize) {
+        let Some(column) = self.board_column() else {
+            if let Some(pos) = (0..self.board_statuses().len()).find_map(|c| self.board_jobs(c).first().copied()) {
+                self.selected = pos;
+                self.scroll_offset = 0;
+            }
+            return;
+        };
+        let jobs = self.board_jobs(column);
+        let row = jobs.iter().position(|&pos| pos == self.selected).unwrap_or(0);
+        self.selected = jobs[row.saturating_add_signed(delta).min(jobs.len() - 1)];
+        self.scroll_offset = 0;
+    }
+
+    /// Select in the nearest non-empty column `delta` steps left or right, on the same
+    /// row where that column is long enough.
+    fn board_focus(&mut self, delta: isize) {
+        let Some(column) = self.board_column() else {
+            self.board_step(0);
+            return;
+        };
+        let row = self.board_jobs(column).iter().position(|&pos| pos == self.selected).unwrap_or(0);
+        let columns = self.board_statuses().len();
+        let mut next = column;
+        while let Some(c) = next.checked_add_signed(delta).filter(|&c| c < columns) {
+            next = c;
+            let jobs = self.board_jobs(c);
+            if !jobs.is_empty() {
+                self.selected = jobs[row.min(jobs.len() - 1)];
+                self.scroll_offset = 0;
+                return;
+            }
+        }
+    }
+
+    /// Move the selected job to the board column `delta` steps away. The transition
+    /// guard applies as for any other status change.
+    fn move_on_board(&mut self, db: &Database, delta: isize) {
+        let Some(column) = self.board_column() else { return };
+        let status = column.checked_add_signed(delta)
+            .and_then(|c| self.board_statuses().get(c).map(|s| s.to_string()));
+        if let Some(status) = status {
+            self.update_current_job_status(db, &status);
+        }
+    }
+}
+
+/// Run one pipeline step on a job, as `hunt fetch` / `hunt keywords` / `hunt fit` would,
+/// and summarize the outcome in one line. Fetching only tries the plain HTTP path:
+/// driving a browser from inside the TUI would draw over it.
+fn run_action(
+    db_path: &Path,
+    action: Action,
+    job_id: i64,
+    model: &str,
+    resume_id: Option<i64>,
+    progress: &dyn Fn(String),
+) -> Result<String> {
+    let db = Database::open_at(db_path)?;
+    let job = db.get_job(job_id)?
+        .ok_or_else(|| HuntError::NotFound(format!("Job #{} not found", job_id)))?;
+    let description = || {
+        job.raw_text.as_deref()
+            .ok_or_else(|| HuntError::Validation(format!("Job #{} has no description", job_id)))
+    };
+
+    match action {
+        Action::Fetch => {
+            let url = job.url.as_deref()
+                .ok_or_else(|| HuntError::Validation(format!("Job #{} has no URL", job_id)))?;
+            progress(format!("GET {}", url));
+            let desc = match browser::fetch_plain(url)? {
+                PlainFetch::Content(desc) => desc,
+                PlainFetch::NeedsBrowser(reason) => {
+                    return Err(HuntError::Validation(format!(
+                        "{}; run 'hunt fetch {}' to use the browser", reason, job_id
+                    )).into());
+                }
+            };
+            db.update_job_description(job_id, &desc.text, desc.pay_min, desc.pay_max)?;
+            if let Some(employer) = &desc.employer_name {
+                db.update_job_employer(job_id, employer)?;
+            }
+            db.set_job_easy_apply(job_id, desc.easy_apply)?;
+            if let Some(application) = &desc.application {
+                db.set_job_application(job_id, application)?;
+            }
+            if desc.no_longer_accepting {
+                db.update_job_status(job_id, "closed")?;
+                return Ok(format!("Job #{} is no longer accepting applications — marked as closed", job_id));
+            }
+            Ok(format!("Fetched job #{} ({} chars)", job_id, desc.text.len()))
+        }
+        Action::Keywords => {
+            let text = description()?;
+            crate::guard_blocked_employer(&db, &job, false)?;
+            let spec = ai::resolve_model(model)?;
+            let provider = crate::cached_provider(&db, &spec, "keywords", false)?;
+            progress(format!("waiting for {}", spec.short_name));
+            let keywords = ai::extract_domain_keywords(provider.as_ref(), text)?;
+            let count = crate::store_domain_keywords(&db, job_id, &spec.short_name, &keywords)?;
+            Ok(format!("Stored {} keywords for job #{} ({})", count, job_id, spec.short_name))
+        }
+        Action::Fit => {
+            let text = description()?;
+            crate::guard_blocked_employer(&db, &job, false)?;
+            let resume_id = resume_id.ok_or_else(|| HuntError::Validation("No base resume chosen".to_string()))?;
+            let resume = db.get_base_resume(resume_id)?
+                .ok_or_else(|| HuntError::NotFound(format!("Resume #{} not found", resume_id)))?;
+            let spec = ai::resolve_model(model)?;
+            let provider = crate::cached_provider(&db, &spec, "fit", false)?;
+            progress(format!("waiting for {} (resume: {})", spec.short_name, resume.name));
+            let fit = ai::analyze_fit(provider.as_ref(), &resume.content, text, &job.title)?;
+            db.save_fit_analysis(
+                job_id,
+                resume.id,
+                &spec.short_name,
+                fit.fit_score,
+                &fit.strong_matches,
+                &fit.gaps,
+                &fit.stretch_areas,
+                &fit.narrative,
+            )?;
+            Ok(format!("Fit score {:.0}/100 for job #{} ({}, resume: {})", fit.fit_score, job_id, spec.short_name, resume.name))
         }
     }
 }
 
-pub fn run_browse(db: &Database, status: Option<&str>, employer: Option<&str>) -> Result<()> {
-    let jobs = db.list_jobs(status, employer)?;
+/// $VISUAL, then $EDITOR, then vi.
+fn editor_command() -> String {
+    std::env::var("VISUAL")
+        .or_else(|_| std::env::var("EDITOR"))
+        .ok()
+        .filter(|e| !e.trim().is_empty())
+        .unwrap_or_else(|| "vi".to_string())
+}
+
+/// Let the user edit `text` in `editor` (which may carry arguments, like "code -w")
+/// and return the result. The caller has to hand the terminal over first.
+fn edit_in_editor(editor: &str, text: &str) -> Result<String> {
+    let path = std::env::temp_dir().join(format!("hunt-note-{}.md", std::process::id()));
+    std::fs::write(&path, text)?;
+    let mut words = editor.split_whitespace();
+    let program = words.next().unwrap_or("vi");
+    let status = Command::new(program).args(words).arg(&path).status();
+    let edited = std::fs::read_to_string(&path);
+    let _ = std::fs::remove_file(&path);
+    let status = status.map_err(|e| HuntError::Config(format!("Couldn't run editor '{}': {}", editor, e)))?;
+    if !status.success() {
+        return Err(HuntError::Validation(format!("Editor '{}' exited with {}", editor, status)).into());
+    }
+    Ok(edited?)
+}
+
+/// Open a URL in the default browser without waiting for it.
+pub fn open_url(url: &str) -> std::io::Result<()> {
+    let opener = if cfg!(target_os = "macos") { "open" } else { "xdg-open" };
+    Command::new(opener)
+        .arg(url)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map(|_| ())
+}
+
+/// Browse the jobs `load` matches (its SQL criteria: status, employer, tag, pay).
+pub fn run_browse(db: &Database, load: &SavedFilter, floor: PayFloor, config: &ActionConfig) -> Result<()> {
+    let settings = crate::config::load()?;
+    let keys = Keymap::with_overrides(&settings.keys)?;
+    let columns = match &settings.columns {
+        Some(names) => Column::parse_list(names)?,
+        None => Column::DEFAULT.to_vec(),
+    };
+    let jobs = db.filter_jobs(load, false)?;
     if jobs.is_empty() {
         println!("No jobs found.");
         return Ok(());
     }
 
     let mut state = AppState::new(jobs, db);
+    state.keys = keys;
+    state.columns = columns;
+    let view_path = SavedView::path(db);
+    state.restore_view(SavedView::load(&view_path));
+    state.pay_floor = floor.amount;
+    if floor.on {
+        state.toggle_pay_floor(db);
+    }
     state.load_keywords(db);
 
     enable_raw_mode()?;
     stdout().execute(EnterAlternateScreen)?;
     let mut terminal = Terminal::new(CrosstermBackend::new(stdout()))?;
 
-    let result = run_loop(&mut terminal, &mut state, db);
+    let result = run_loop(&mut terminal, &mut state, db, config);
 
     disable_raw_mode()?;
     stdout().execute(LeaveAlternateScreen)?;
 
+    if let Err(e) = state.saved_view().save(&view_path) {
+        tracing::warn!("Could not save browser state: {}", e);
+    }
+
     result
 }
 
@@ -228,18 +1190,74 @@ fn run_loop(
     terminal: &mut Terminal<CrosstermBackend<std::io::Stdout>>,
     state: &mut AppState,
     db: &Database,
+    config: &ActionConfig,
 ) -> Result<()> {
     let mut list_state = ListState::default();
     list_state.select(Some(0));
+    let mut employer_list_state = ListState::default();
+    employer_list_state.select(Some(0));
 
     loop {
-        terminal.draw(|frame| draw(frame, state, &mut list_state))?;
+        if state.poll_task(db) {
+            list_state.select(Some(state.selected));
+        }
+        terminal.draw(|frame| {
+            match state.view {
+                View::Jobs => draw(frame, state, &mut list_state),
+                View::Employers => draw(frame, state, &mut employer_list_state),
+            }
+            if state.show_help {
+                draw_help(frame, state);
+            }
+        })?;
 
+        // Wake up regularly while a background action runs, to show its progress
+        if !event::poll(Duration::from_millis(250))? {
+            continue;
+        }
         if let Event::Key(key) = event::read()? {
             if key.kind != KeyEventKind::Press {
                 continue;
             }
 
+            // Note overlay
+            if state.note_input.is_some() {
+                match key.code {
+                    KeyCode::Esc => state.note_input = None,
+                    KeyCode::Enter => state.save_note_input(db),
+                    KeyCode::Backspace => {
+                        if let Some(input) = &mut state.note_input {
+                            input.pop();
+                        }
+                    }
+                    KeyCode::Char(c) => {
+                        if let Some(input) = &mut state.note_input {
+                            input.push(c);
+                        }
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            // Saved filter picker
+            if let Some((filters, at)) = &mut state.filter_picker {
+                match key.code {
+                    KeyCode::Esc => state.filter_picker = None,
+                    KeyCode::Down | KeyCode::Char('j') => *at = (*at + 1).min(filters.len() - 1),
+                    KeyCode::Up | KeyCode::Char('k') => *at = at.saturating_sub(1),
+                    KeyCode::Enter => {
+                        let filter = filters[*at].clone();
+                        state.filter_picker = None;
+                        state.apply_saved_filter(db, &filter);
+                        list_state.select(Some(state.selected));
+                        state.load_keywords(db);
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
             // Search input mode
             if state.search_active {
                 match key.code {
@@ -273,78 +1291,176 @@ fn run_loop(
                 continue;
             }
 
+            // Any key closes the help
+            if state.show_help {
+                state.show_help = false;
+                continue;
+            }
+
             // Normal mode
+            state.message = None;
+            let binding = match key.code {
+                KeyCode::Char(c) if !key.modifiers.contains(KeyModifiers::CONTROL) => state.keys.binding(state.view, c),
+                _ => None,
+            };
+
+            if state.view == View::Employers {
+                match (binding, key.code) {
+                    (Some(Binding::EmployerNever), _) => state.set_current_employer_status(db, "never"),
+                    (Some(Binding::EmployerYuck), _) => state.set_current_employer_status(db, "yuck"),
+                    (Some(Binding::EmployerOk), _) => state.set_current_employer_status(db, "ok"),
+                    (Some(_), _) => {}
+                    (None, KeyCode::Char('q')) => break,
+                    (None, KeyCode::Char('?')) => state.show_help = true,
+                    (None, KeyCode::Tab | KeyCode::Esc) => state.toggle_view(),
+                    (None, KeyCode::Down | KeyCode::Char('j')) => state.next_employer(),
+                    (None, KeyCode::Up | KeyCode::Char('k')) => state.prev_employer(),
+                    (None, KeyCode::Char('g')) => state.employer_selected = 0,
+                    (None, KeyCode::Char('G')) => state.employer_selected = state.employers.len().saturating_sub(1),
+                    (None, KeyCode::Enter) => {
+                        state.show_employer_jobs();
+                        list_state.select(Some(state.selected));
+                        state.load_keywords(db);
+                    }
+                    _ => {}
+                }
+                employer_list_state.select(Some(state.employer_selected));
+                continue;
+            }
+
             let prev_selected = state.selected;
             let page_size = (terminal.size()?.height as usize).saturating_sub(4) / 2;
 
-            match key.code {
-                KeyCode::Char('q') => break,
-                KeyCode::Esc => {
-                    if !state.search_query.is_empty() {
-                        state.search_query.clear();
+            if let Some(binding) = binding {
+                match binding {
+                    Binding::StatusNew => state.update_current_job_status(db, "new"),
+                    Binding::StatusReviewing => state.update_current_job_status(db, "reviewing"),
+                    Binding::StatusApplied => state.update_current_job_status(db, "applied"),
+                    Binding::StatusRejected => state.update_current_job_status(db, "rejected"),
+                    Binding::StatusClosed => state.update_current_job_status(db, "closed"),
+                    Binding::NextStatus => state.cycle_current_job_status(db),
+                    Binding::Archive => {
+                        state.archive_current_job(db);
+                        list_state.select(Some(state.selected));
+                        state.load_keywords(db);
+                    }
+                    Binding::OpenJob => state.open_current(false),
+                    Binding::OpenCareers => state.open_current(true),
+                    Binding::Fetch => state.start_action(Action::Fetch, config),
+                    Binding::Keywords => state.start_action(Action::Keywords, config),
+                    Binding::Fit => state.start_action(Action::Fit, config),
+                    Binding::Note => state.note_input = state.current_job().map(|_| String::new()),
+                    Binding::EditNote if state.current_job().is_some() => {
+                        let latest = state.notes.last().map(|n| n.note.clone()).unwrap_or_default();
+                        disable_raw_mode()?;
+                        stdout().execute(LeaveAlternateScreen)?;
+                        let edited = edit_in_editor(&editor_command(), &latest);
+                        enable_raw_mode()?;
+                        stdout().execute(EnterAlternateScreen)?;
+                        terminal.clear()?;
+                        match edited {
+                            Ok(text) => state.save_edited_note(db, &text),
+                            Err(e) => state.message = Some(e.to_string()),
+                        }
+                    }
+                    Binding::Filters => state.open_filter_picker(db),
+                    Binding::PayFloor => {
+                        state.toggle_pay_floor(db);
+                        list_state.select(Some(state.selected));
+                        state.load_keywords(db);
+                    }
+                    Binding::HideClosed => {
+                        state.hide_closed = !state.hide_closed;
                         state.update_filter();
                         list_state.select(Some(state.selected));
                         state.load_keywords(db);
-                    } else {
-                        break;
                     }
+                    Binding::Board => state.toggle_board(),
+                    _ => {}
                 }
-                KeyCode::Down | KeyCode::Char('j') => state.next(),
-                KeyCode::Up | KeyCode::Char('k') => state.prev(),
-                KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    state.page_down(page_size);
-                }
-                KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
-                    state.page_up(page_size);
-                }
-                KeyCode::Char('g') => {
-                    state.selected = 0;
-                    state.scroll_offset = 0;
-                }
-                KeyCode::Char('G') => {
-                    if !state.visible.is_empty() {
+            } else {
+                match key.code {
+                    KeyCode::Char('q') => break,
+                    KeyCode::Char('?') => state.show_help = true,
+                    KeyCode::Tab => state.toggle_view(),
+                    KeyCode::Esc => {
+                        if state.employer_filter.is_some() {
+                            state.employer_filter = None;
+                            state.update_filter();
+                            list_state.select(Some(state.selected));
+                            state.load_keywords(db);
+                        } else if state.saved_filter.is_some() {
+                            state.saved_filter = None;
+                            state.update_filter();
+                            list_state.select(Some(state.selected));
+                            state.load_keywords(db);
+                        } else if !state.search_query.is_empty() {
+                            state.search_query.clear();
+                            state.update_filter();
+                            list_state.select(Some(state.selected));
+                            state.load_keywords(db);
+                        } else {
+                            break;
+                        }
+                    }
+                    KeyCode::Down | KeyCode::Char('j') if state.board => state.board_step(1),
+                    KeyCode::Up | KeyCode::Char('k') if state.board => state.board_step(-1),
+                    KeyCode::Left if state.board => state.board_focus(-1),
+                    KeyCode::Right if state.board => state.board_focus(1),
+                    KeyCode::Char('h') if state.board => state.move_on_board(db, -1),
+                    KeyCode::Char('l') if state.board => state.move_on_board(db, 1),
+                    KeyCode::Down | KeyCode::Char('j') => state.next(),
+                    KeyCode::Up | KeyCode::Char('k') => state.prev(),
+                    KeyCode::Char('d') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        if state.board {
+                            state.board_step(page_size as isize);
+                        } else {
+                            state.page_down(page_size);
+                        }
+                    }
+                    KeyCode::Char('u') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                        if state.board {
+                            state.board_step(-(page_size as isize));
+                        } else {
+                            state.page_up(page_size);
+                        }
+                    }
+                    KeyCode::Char('g') => {
+                        state.selected = 0;
+                        state.scroll_offset = 0;
+                    }
+                    KeyCode::Char('G') if !state.visible.is_empty() => {
                         state.selected = state.visible.len() - 1;
                         state.scroll_offset = 0;
                     }
+                    KeyCode::Char('J') | KeyCode::PageDown => state.scroll_down(),
+                    KeyCode::Char('K') | KeyCode::PageUp => state.scroll_up(),
+                    KeyCode::Char('/') => {
+                        state.search_active = true;
+                        state.search_query.clear();
+                    }
+                    KeyCode::Char('1') => {
+                        state.set_sort(SortField::Score);
+                        list_state.select(Some(state.selected));
+                        state.load_keywords(db);
+                    }
+                    KeyCode::Char('2') => {
+                        state.set_sort(SortField::Salary);
+                        list_state.select(Some(state.selected));
+                        state.load_keywords(db);
+                    }
+                    KeyCode::Char('3') => {
+                        state.set_sort(SortField::Fit);
+                        list_state.select(Some(state.selected));
+                        state.load_keywords(db);
+                    }
+                    KeyCode::Char('4') => {
+                        state.set_sort(SortField::Company);
+                        list_state.select(Some(state.selected));
+                        state.load_keywords(db);
+                    }
+                    _ => {}
                 }
-                KeyCode::Char('J') | KeyCode::PageDown => state.scroll_down(),
-                KeyCode::Char('K') | KeyCode::PageUp => state.scroll_up(),
-                KeyCode::Char('/') => {
-                    state.search_active = true;
-                    state.search_query.clear();
-                }
-                KeyCode::Char('n') => state.update_current_job_status(db, "new"),
-                KeyCode::Char('r') => state.update_current_job_status(db, "reviewing"),
-                KeyCode::Char('a') => state.update_current_job_status(db, "applied"),
-                KeyCode::Char('x') => state.update_current_job_status(db, "rejected"),
-                KeyCode::Char('c') => state.update_current_job_status(db, "closed"),
-                KeyCode::Char('1') => {
-                    state.set_sort(SortField::Score);
-                    list_state.select(Some(state.selected));
-                    state.load_keywords(db);
-                }
-                KeyCode::Char('2') => {
-                    state.set_sort(SortField::Salary);
-                    list_state.select(Some(state.selected));
-                    state.load_keywords(db);
-                }
-                KeyCode::Char('3') => {
-                    state.set_sort(SortField::Fit);
-                    list_state.select(Some(state.selected));
-                    state.load_keywords(db);
-                }
-                KeyCode::Char('4') => {
-                    state.set_sort(SortField::Company);
-                    list_state.select(Some(state.selected));
-                    state.load_keywords(db);
-                }
-                KeyCode::Char('H') => {
-                    state.hide_closed = !state.hide_closed;
-                    state.update_filter();
-                    list_state.select(Some(state.selected));
-                    state.load_keywords(db);
-                }
-                _ => {}
             }
             if state.selected != prev_selected {
                 list_state.select(Some(state.selected));
@@ -369,6 +1485,74 @@ fn truncate_str(s: &str, max: usize) -> String {
     }
 }
 
+/// Days since the job was added: "12d", then weeks once past 99 days.
+fn format_age(created_at: &str, now: chrono::DateTime<chrono::Utc>) -> String {
+    let Some(added) = timestamps::parse(created_at) else { return "-".to_string() };
+    let days = (now - added).num_days().max(0);
+    if days < 100 { format!("{}d", days) } else { format!("{}w", (days / 7).min(999)) }
+}
+
+/// Yellow for a deadline coming up on a job not applied to yet, red once it has passed.
+fn deadline_color(job: &Job, today: chrono::NaiveDate) -> Option<Color> {
+    match dates::closing_soon(job, today)? {
+        days if days < 0 => Some(Color::Red),
+        _ => Some(Color::Yellow),
+    }
+}
+
+/// One cell of the job list, padded to the column's width.
+fn column_cell(state: &AppState, idx: usize, column: Column, now: chrono::DateTime<chrono::Utc>) -> Span<'static> {
+    let job = &state.jobs[idx];
+    let dim = Style::default().fg(Color::DarkGray);
+    match column {
+        Column::Score => Span::raw(format!("{:>4.0} ", state.scores[idx])),
+        Column::Fit => {
+            let (text, color) = match state.fit_scores[idx] {
+                Some(s) if s >= 75.0 => (format!("{:>3.0}", s), Color::Green),
+                Some(s) if s >= 50.0 => (format!("{:>3.0}", s), Color::Yellow),
+                Some(s) => (format!("{:>3.0}", s), Color::Red),
+                None => ("  -".to_string(), Color::DarkGray),
+            };
+            Span::styled(format!("{} ", text), Style::default().fg(color))
+        }
+        Column::Salary => Span::styled(format!("{} ", format_pay(job)), dim),
+        Column::Age => Span::styled(format!("{:>4} ", format_age(&job.created_at, now)), dim),
+        Column::Source => Span::styled(format!("{:<8} ", truncate_str(job.source.as_deref().unwrap_or("-"), 8)), dim),
+        Column::Mode => {
+            let color = match job.work_mode.as_deref() {
+                Some("remote") => Color::Green,
+                Some("hybrid") => Color::Yellow,
+                _ => Color::DarkGray,
+            };
+            Span::styled(format!("{:<6} ", job.work_mode.as_deref().unwrap_or("-")), Style::default().fg(color))
+        }
+        Column::Location => Span::styled(format!("{:<16} ", truncate_str(job.location.as_deref().unwrap_or("-"), 16)), dim),
+        Column::Applied => match &job.applied_at {
+            Some(applied_at) => {
+                let stalled = job.status == "applied"
+                    && timestamps::parse(applied_at).is_some_and(|at| (now - at).num_days() >= db::STALLED_DAYS);
+                let style = if stalled { Style::default().fg(Color::Yellow) } else { dim };
+                Span::styled(format!("{:>4} ", format_age(applied_at, now)), style)
+            }
+            None => Span::styled("   - ", dim),
+        },
+        Column::Deadline => {
+            let today = now.with_timezone(&chrono::Local).date_naive();
+            let style = deadline_color(job, today).map_or(dim, |c| Style::default().fg(c));
+            match dates::days_left(job, today) {
+                Some(days) if days < 0 => Span::styled("past ", style),
+                Some(days) => Span::styled(format!("{:>4} ", format!("{}d", days.min(999))), style),
+                None => Span::styled("   - ", dim),
+            }
+        }
+        Column::Flags => match state.flags.get(&job.id) {
+            Some(names) => Span::styled(format!("{:>3} ", format!("⚑{}", names.len().min(99))), Style::default().fg(Color::Red)),
+            None => Span::styled("  - ", dim),
+        },
+        Column::Tags => Span::raw(""),
+    }
+}
+
 fn format_pay(job: &Job) -> String {
     let pay = job.pay_max.or(job.pay_min);
     match pay {
@@ -379,12 +1563,20 @@ fn format_pay(job: &Job) -> String {
 }
 
 fn draw(frame: &mut Frame, state: &AppState, list_state: &mut ListState) {
-    // Main layout: content + footer
+    // Main layout: tabs + content + footer
     let main_chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Min(0), Constraint::Length(1)])
+        .constraints([Constraint::Length(1), Constraint::Min(0), Constraint::Length(1)])
         .split(frame.area());
 
+    let tabs = Tabs::new(vec![" Jobs ", " Employers "])
+        .select(match state.view {
+            View::Jobs => 0,
+            View::Employers => 1,
+        })
+        .highlight_style(Style::default().add_modifier(Modifier::BOLD | Modifier::REVERSED));
+    frame.render_widget(tabs, main_chunks[0]);
+
     // Left/right split: 55% list / 45% detail
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
@@ -392,15 +1584,28 @@ fn draw(frame: &mut Frame, state: &AppState, list_state: &mut ListState) {
             Constraint::Percentage(55),
             Constraint::Percentage(45),
         ])
-        .split(main_chunks[0]);
+        .split(main_chunks[1]);
+
+    if state.view == View::Employers {
+        draw_employers(frame, state, list_state, chunks[0], chunks[1]);
+        draw_footer(frame, state, main_chunks[2]);
+        return;
+    }
+
+    if state.board {
+        draw_board(frame, state, main_chunks[1]);
+        draw_footer(frame, state, main_chunks[2]);
+        draw_popups(frame, state);
+        return;
+    }
 
     // Compute column widths for job list
     // highlight symbol "> " = 2, borders = 2
     let usable = (chunks[0].width as usize).saturating_sub(4);
     // Format: "S #NNNN  85 $210k  Title                Employer"
-    //          1 5      3  5      variable             variable
-    // "S #NNNN SSS $NNNk " = status(1)+' '(1)+'#'(1)+id(4)+' '(1)+score(3)+' '(1)+pay(5)+' '(1) = 18
-    let prefix_w = 18;
+    // "S #NNNN " = status(1)+' '(1)+'#'(1)+id(4)+' '(1) = 8, then the configured columns
+    let prefix_w = 8 + state.columns.iter().map(|c| c.width()).sum::<usize>();
+    let now = chrono::Utc::now();
     let remaining = usable.saturating_sub(prefix_w);
     let emp_w = (remaining * 35 / 100).max(6).min(18);
     let title_w = remaining.saturating_sub(emp_w + 1); // +1 for space between title and employer
@@ -412,45 +1617,60 @@ fn draw(frame: &mut Frame, state: &AppState, list_state: &mut ListState) {
             "new" => " ",
             "reviewing" => "*",
             "applied" => "+",
+            "interviewing" => "i",
+            "offer" => "$",
+            "accepted" => "=",
             "rejected" => "x",
+            "withdrawn" => "w",
             "closed" => "-",
             _ => "?",
         };
 
-        let score_str = match state.fit_scores[idx] {
-            Some(s) => format!("{:>3.0}", s),
-            None => "  -".to_string(),
-        };
-
-        let pay_str = format_pay(job);
         let employer = job.employer_name.as_deref().unwrap_or("?");
         let title = truncate_str(&job.title, title_w);
         let emp = truncate_str(employer, emp_w);
-
-        let score_color = match state.fit_scores[idx] {
-            Some(s) if s >= 75.0 => Color::Green,
-            Some(s) if s >= 50.0 => Color::Yellow,
-            Some(_) => Color::Red,
-            None => Color::DarkGray,
+        // Tags fill whatever room the title leaves
+        let tags = state.job_tags(job.id);
+        let tag_room = title_w.saturating_sub(title.chars().count());
+        let tag_str = if tags.is_empty() || tag_room < 4 || !state.columns.contains(&Column::Tags) {
+            String::new()
+        } else {
+            let joined: Vec<String> = tags.iter().map(|t| format!("+{}", t)).collect();
+            truncate_str(&format!(" {}", joined.join(" ")), tag_room)
         };
-
-        ListItem::new(Line::from(vec![
-            Span::raw(format!("{} #{:<4} ", status_icon, job.id)),
-            Span::styled(score_str, Style::default().fg(score_color)),
-            Span::styled(format!(" {} ", pay_str), Style::default().fg(Color::DarkGray)),
-            Span::raw(format!("{:<width$}", title, width = title_w)),
+        let title_pad = title_w.saturating_sub(title.chars().count() + tag_str.chars().count());
+
+        let mut spans = vec![Span::raw(format!("{} #{:<4} ", status_icon, job.id))];
+        spans.extend(state.columns.iter().map(|&column| column_cell(state, idx, column, now)));
+        spans.extend([
+            Span::raw(title),
+            Span::styled(tag_str, Style::default().fg(Color::Cyan)),
+            Span::raw(" ".repeat(title_pad)),
             Span::styled(
                 format!(" {:<width$}", emp, width = emp_w),
                 Style::default().fg(Color::DarkGray),
             ),
-        ]))
+        ]);
+        ListItem::new(Line::from(spans))
     }).collect();
 
     let sort_arrow = if state.sort_ascending { "\u{25b2}" } else { "\u{25bc}" };
     let sort_indicator = format!(" [{}{}]", state.sort_field.label(), sort_arrow);
 
+    let mut scope = match &state.employer_filter {
+        Some((_, name)) => format!(" @ {}", name),
+        None => String::new(),
+    };
+    if let Some((name, _)) = &state.saved_filter {
+        scope.push_str(&format!(" ~{}", name));
+    }
+    if let (Some(floor), Some(_)) = (state.pay_floor, &state.pay_floor_jobs) {
+        scope.push_str(&format!(" ${}k+", floor / 1000));
+    }
     let list_title = if !state.search_query.is_empty() {
-        format!(" Jobs ({}/{}) \"{}\"{} ", state.visible.len(), state.jobs.len(), state.search_query, sort_indicator)
+        format!(" Jobs ({}/{}){} \"{}\"{} ", state.visible.len(), state.jobs.len(), scope, state.search_query, sort_indicator)
+    } else if !scope.is_empty() {
+        format!(" Jobs ({}/{}){}{} ", state.visible.len(), state.jobs.len(), scope, sort_indicator)
     } else if state.visible.len() < state.jobs.len() {
         format!(" Jobs ({}/{}){} ", state.visible.len(), state.jobs.len(), sort_indicator)
     } else {
@@ -462,31 +1682,283 @@ fn draw(frame: &mut Frame, state: &AppState, list_state: &mut ListState) {
         .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
         .highlight_symbol("> ");
 
-    frame.render_stateful_widget(list, chunks[0], list_state);
+    frame.render_stateful_widget(list, chunks[0], list_state);
+
+    // Right panel: job detail
+    let detail = build_detail(state);
+    let detail_widget = Paragraph::new(detail)
+        .block(Block::default().borders(Borders::ALL).title(" Detail "))
+        .wrap(Wrap { trim: false })
+        .scroll((state.scroll_offset, 0));
+
+    frame.render_widget(detail_widget, chunks[1]);
+
+    draw_footer(frame, state, main_chunks[2]);
+    draw_popups(frame, state);
+}
+
+/// The note input and saved filter picker, over whatever the jobs tab shows.
+fn draw_popups(frame: &mut Frame, state: &AppState) {
+    if let (Some(input), Some(job)) = (&state.note_input, state.current_job()) {
+        let area = frame.area();
+        let popup = centered(area, (area.width * 3 / 5).max(30), 5, area.height / 3);
+        frame.render_widget(Clear, popup);
+        let overlay = Paragraph::new(format!("{}\u{2588}", input))
+            .block(Block::default().borders(Borders::ALL)
+                .title(format!(" Note for #{} (Enter: save, Esc: cancel) ", job.id))
+                .border_style(Style::default().fg(Color::Yellow)))
+            .wrap(Wrap { trim: false });
+        frame.render_widget(overlay, popup);
+    }
+
+    if let Some((filters, at)) = &state.filter_picker {
+        let lines: Vec<Line> = filters.iter().enumerate().map(|(i, filter)| {
+            let style = if i == *at {
+                Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD)
+            } else {
+                Style::default()
+            };
+            Line::from(vec![
+                Span::styled(format!(" {:<20}", filter.name), style.fg(Color::Cyan)),
+                Span::styled(format!(" {}", filter.describe()), style),
+            ])
+        }).collect();
+        let area = frame.area();
+        let popup = centered(area, (area.width * 3 / 5).max(40), lines.len() as u16 + 2, area.height / 4);
+        frame.render_widget(Clear, popup);
+        let overlay = Paragraph::new(lines)
+            .block(Block::default().borders(Borders::ALL)
+                .title(" Saved filters (Enter: apply, Esc: cancel) ")
+                .border_style(Style::default().fg(Color::Yellow)));
+        frame.render_widget(overlay, popup);
+    }
+}
+
+/// One column per `AppState::board_statuses` entry, each job as its title over its
+/// employer, with the selected job's column outlined.
+fn draw_board(frame: &mut Frame, state: &AppState, area: Rect) {
+    let statuses = state.board_statuses();
+    let areas = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints(vec![Constraint::Ratio(1, statuses.len().max(1) as u32); statuses.len()])
+        .split(area);
+    let focused = state.board_column();
+
+    for (column, (&status, &area)) in statuses.iter().zip(areas.iter()).enumerate() {
+        let jobs = state.board_jobs(column);
+        // highlight symbol "> " = 2, borders = 2
+        let width = (area.width as usize).saturating_sub(4);
+        let items: Vec<ListItem> = jobs.iter().map(|&pos| {
+            let job = &state.jobs[state.visible[pos]];
+            ListItem::new(vec![
+                Line::from(truncate_str(&format!("#{} {}", job.id, job.title), width)),
+                Line::styled(
+                    truncate_str(job.employer_name.as_deref().unwrap_or("?"), width),
+                    Style::default().fg(Color::DarkGray),
+                ),
+            ])
+        }).collect();
+
+        let title_style = state.status_color(status).map_or(Style::default(), |c| Style::default().fg(c));
+        let mut block = Block::default()
+            .borders(Borders::ALL)
+            .title(Span::styled(format!(" {} ({}) ", status, jobs.len()), title_style));
+        let mut column_state = ListState::default();
+        if focused == Some(column) {
+            block = block.border_style(Style::default().fg(Color::Yellow));
+            column_state.select(jobs.iter().position(|&pos| pos == state.selected));
+        }
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+            .highlight_symbol("> ");
+        frame.render_stateful_widget(list, area, &mut column_state);
+    }
+}
 
-    // Right panel: job detail
-    let detail = build_detail(state);
-    let detail_widget = Paragraph::new(detail)
-        .block(Block::default().borders(Borders::ALL).title(" Detail "))
-        .wrap(Wrap { trim: false })
-        .scroll((state.scroll_offset, 0));
+/// A `width` x `height` box centered horizontally, `top` rows down, clipped to `area`.
+fn centered(area: Rect, width: u16, height: u16, top: u16) -> Rect {
+    let width = width.min(area.width);
+    Rect::new(area.x + (area.width - width) / 2, area.y + top, width, height).intersection(area)
+}
 
-    frame.render_widget(detail_widget, chunks[1]);
+/// Every key, with remapped ones as configured.
+fn help_lines(keys: &Keymap) -> Vec<Line<'static>> {
+    let heading = |text: &'static str| Line::from(Span::styled(text, Style::default().add_modifier(Modifier::BOLD)));
+    let entry = |key: String, text: &'static str| Line::from(vec![
+        Span::styled(format!("  {:<16}", key), Style::default().fg(Color::Cyan)),
+        Span::raw(text),
+    ]);
+
+    let mut lines = vec![heading("Navigation")];
+    lines.extend(FIXED_KEYS.iter().map(|(key, text)| entry(key.to_string(), text)));
+    for (title, view) in [("Jobs", View::Jobs), ("Employers", View::Employers)] {
+        lines.push(Line::default());
+        lines.push(heading(title));
+        lines.extend(Binding::ALL.iter()
+            .filter(|b| b.view() == view)
+            .map(|&b| entry(keys.key(b).to_string(), b.info().2)));
+    }
+    lines.push(Line::default());
+    lines.push(Line::styled("Remap with \"keys\" in config.json, e.g. {\"status-applied\": \"A\"}", Style::default().fg(Color::DarkGray)));
+    lines
+}
+
+fn draw_help(frame: &mut Frame, state: &AppState) {
+    let lines = help_lines(&state.keys);
+    let area = frame.area();
+    let popup = centered(area, 72, lines.len() as u16 + 2, 1);
+    frame.render_widget(Clear, popup);
+    let help = Paragraph::new(lines)
+        .block(Block::default().borders(Borders::ALL)
+            .title(" Keys (any key to close) ")
+            .border_style(Style::default().fg(Color::Yellow)));
+    frame.render_widget(help, popup);
+}
 
-    // Footer
+fn draw_footer(frame: &mut Frame, state: &AppState, area: Rect) {
     let footer_text = if state.search_active {
         format!("/{}", state.search_query)
+    } else if let Some(message) = &state.message {
+        format!(" {}", message)
+    } else if let Some(task) = &state.task {
+        format!(" {} for job #{}... {}s  {}", task.action.label(), task.job_id, task.started.elapsed().as_secs(), task.progress)
+    } else if state.view == View::Employers {
+        let k = |b| state.keys.key(b);
+        format!(" j/k:nav  g/G:top/end  Enter:jobs  {}/{}/{}:never/yuck/ok  Tab:jobs tab  ?:help  q:quit",
+            k(Binding::EmployerNever), k(Binding::EmployerYuck), k(Binding::EmployerOk))
+    } else if state.board {
+        let k = |b| state.keys.key(b);
+        format!(" j/k:nav  Left/Right:column  h/l:move job  {}:next status  {}/{}:open job/careers  {}:note  {}:{}  {}:list  ?:help  q:quit",
+            k(Binding::NextStatus), k(Binding::OpenJob), k(Binding::OpenCareers), k(Binding::Note),
+            k(Binding::HideClosed), if state.hide_closed { "show closed" } else { "hide closed" }, k(Binding::Board))
     } else {
-        format!(" j/k:nav  ^D/^U:page  g/G:top/end  /:search  J/K:scroll  1-4:sort  n/r/a/x/c:status  H:{}  q:quit",
-            if state.hide_closed { "show closed" } else { "hide closed" })
+        let k = |b| state.keys.key(b);
+        format!(" j/k:nav  /:search  {}:filters  {}:pay floor  1-4:sort  {}/{}/{}/{}/{}:status  {}:next status  {}/{}:open job/careers  {}/{}/{}:fetch/keywords/fit  {}/{}:note/edit  {}:{}  {}:archive  {}:board  ?:help  Tab:employers  q:quit",
+            k(Binding::Filters), k(Binding::PayFloor),
+            k(Binding::StatusNew), k(Binding::StatusReviewing), k(Binding::StatusApplied), k(Binding::StatusRejected),
+            k(Binding::StatusClosed), k(Binding::NextStatus), k(Binding::OpenJob), k(Binding::OpenCareers),
+            k(Binding::Fetch), k(Binding::Keywords), k(Binding::Fit), k(Binding::Note), k(Binding::EditNote),
+            k(Binding::HideClosed), if state.hide_closed { "show closed" } else { "hide closed" }, k(Binding::Archive),
+            k(Binding::Board))
     };
-    let footer_style = if state.search_active {
+    let footer_style = if state.search_active || state.message.is_some() || state.task.is_some() {
         Style::default().fg(Color::Yellow)
     } else {
         Style::default().fg(Color::DarkGray)
     };
     let footer = Paragraph::new(footer_text).style(footer_style);
-    frame.render_widget(footer, main_chunks[1]);
+    frame.render_widget(footer, area);
+}
+
+/// Which kinds of research have been run: Startup, Public, Ownership, Engineering, Glassdoor.
+fn research_flags(employer: &Employer) -> String {
+    [
+        ('S', &employer.research_updated_at),
+        ('P', &employer.public_research_updated_at),
+        ('O', &employer.ownership_research_updated),
+        ('E', &employer.eng_research_updated),
+        ('G', &employer.last_glassdoor_fetch),
+    ]
+    .iter()
+    .map(|(flag, done)| if done.is_some() { *flag } else { '.' })
+    .collect()
+}
+
+fn employer_status_color(status: &str) -> Color {
+    match status {
+        "never" => Color::Red,
+        "yuck" => Color::Yellow,
+        _ => Color::Green,
+    }
+}
+
+fn draw_employers(frame: &mut Frame, state: &AppState, list_state: &mut ListState, list_area: Rect, detail_area: Rect) {
+    let counts = state.employer_job_counts();
+    // "ST   4.2  12/3 SPOEG " = status(5)+rating(5)+jobs(7)+flags(6)
+    let name_w = (list_area.width as usize).saturating_sub(4 + 24).max(8);
+    let items: Vec<ListItem> = state.employers.iter().map(|e| {
+        let (all, active) = counts.get(&e.id).copied().unwrap_or_default();
+        let rating = e.glassdoor_rating.map_or_else(|| "   -".to_string(), |r| format!("{:>4.1}", r));
+        ListItem::new(Line::from(vec![
+            Span::styled(format!("{:<5}", e.status), Style::default().fg(employer_status_color(&e.status))),
+            Span::raw(format!(" {:<width$}", truncate_str(&e.name, name_w), width = name_w)),
+            Span::styled(format!(" {}", rating), Style::default().fg(Color::Yellow)),
+            Span::raw(format!(" {:>3}/{:<3}", active, all)),
+            Span::styled(format!(" {}", research_flags(e)), Style::default().fg(Color::DarkGray)),
+        ]))
+    }).collect();
+
+    let list = List::new(items)
+        .block(Block::default().borders(Borders::ALL).title(format!(" Employers ({}) [active/all jobs] ", state.employers.len())))
+        .highlight_style(Style::default().bg(Color::DarkGray).add_modifier(Modifier::BOLD))
+        .highlight_symbol("> ");
+    frame.render_stateful_widget(list, list_area, list_state);
+
+    let detail = Paragraph::new(build_employer_detail(state, &counts))
+        .block(Block::default().borders(Borders::ALL).title(" Employer "))
+        .wrap(Wrap { trim: false });
+    frame.render_widget(detail, detail_area);
+}
+
+fn build_employer_detail<'a>(state: &'a AppState, counts: &HashMap<i64, (usize, usize)>) -> Text<'a> {
+    let Some(e) = state.current_employer() else {
+        return Text::raw("No employers yet");
+    };
+    let mut lines: Vec<Line> = vec![
+        Line::from(Span::styled(&e.name, Style::default().add_modifier(Modifier::BOLD))),
+        Line::from(vec![
+            Span::raw("Status: "),
+            Span::styled(&e.status, Style::default().fg(employer_status_color(&e.status))),
+        ]),
+    ];
+    let (all, active) = counts.get(&e.id).copied().unwrap_or_default();
+    lines.push(Line::from(format!("Jobs: {} ({} active)", all, active)));
+    if let Some(domain) = &e.domain {
+        lines.push(Line::from(Span::styled(format!("https://{}", domain), Style::default().fg(Color::Blue))));
+    }
+    if let Some(careers) = &e.careers_url {
+        lines.push(Line::from(vec![Span::raw("Careers: "), Span::styled(careers.as_str(), Style::default().fg(Color::Blue))]));
+    }
+    if let Some(rating) = e.glassdoor_rating {
+        lines.push(Line::from(format!("Glassdoor: {:.1}/5 ({} reviews)", rating, e.glassdoor_review_count.unwrap_or(0))));
+    }
+
+    let facts: Vec<String> = [
+        e.funding_stage.as_ref().map(|s| format!("Funding: {}", s)),
+        e.yc_batch.as_ref().map(|b| format!("YC: {}", b)),
+        e.hn_mentions_count.map(|n| format!("HN mentions: {}", n)),
+        e.ownership_type.as_ref().map(|o| format!("Ownership: {}", o)),
+        e.pe_owner.as_ref().map(|o| format!("PE owner: {}", o)),
+        e.parent_company.as_ref().map(|p| format!("Parent: {}", p)),
+    ]
+    .into_iter()
+    .flatten()
+    .collect();
+    if !facts.is_empty() {
+        lines.push(Line::from(""));
+        lines.extend(facts.into_iter().map(Line::from));
+    }
+
+    for (heading, text) in [
+        ("Evil summary", &e.evil_summary),
+        ("Ownership concerns", &e.ownership_concerns),
+        ("Engineering culture", &e.eng_culture_summary),
+        ("Notes", &e.notes),
+    ] {
+        if let Some(text) = text.as_deref().filter(|t| !t.trim().is_empty()) {
+            lines.push(Line::from(""));
+            lines.push(Line::from(Span::styled(heading, Style::default().add_modifier(Modifier::BOLD))));
+            lines.extend(text.lines().map(Line::from));
+        }
+    }
+
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        format!("Research: {}  (Startup, Public, Ownership, Engineering, Glassdoor)", research_flags(e)),
+        Style::default().fg(Color::DarkGray),
+    )));
+    Text::from(lines)
 }
 
 fn build_detail<'a>(state: &'a AppState) -> Text<'a> {
@@ -503,31 +1975,91 @@ fn build_detail<'a>(state: &'a AppState) -> Text<'a> {
     )));
 
     if let Some(employer) = &job.employer_name {
-        lines.push(Line::from(format!("at {}", employer)));
+        let mut spans = vec![Span::raw(format!("at {}", employer))];
+        if let Some(domain) = &state.employer_links.domain {
+            spans.push(Span::styled(format!("  https://{}", domain), Style::default().fg(Color::Blue)));
+        }
+        lines.push(Line::from(spans));
+        if let Some(careers) = &state.employer_links.careers_url {
+            lines.push(Line::from(vec![
+                Span::raw("Careers: "),
+                Span::styled(careers.as_str(), Style::default().fg(Color::Blue)),
+            ]));
+        }
     }
 
-    let status_style = match job.status.as_str() {
-        "new" => Style::default().fg(Color::Green),
-        "reviewing" => Style::default().fg(Color::Yellow),
-        "applied" => Style::default().fg(Color::Cyan),
-        "rejected" => Style::default().fg(Color::Red),
-        "closed" => Style::default().fg(Color::DarkGray),
-        _ => Style::default(),
+    let status_style = match state.status_color(&job.status) {
+        Some(color) => Style::default().fg(color),
+        None => Style::default(),
     };
     lines.push(Line::from(Span::styled(
         format!("Status: {}", job.status),
         status_style,
     )));
+    if let Some(days) = db::days_since_applied(job) {
+        let style = if days >= db::STALLED_DAYS && job.status == "applied" {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(Span::styled(format!("Applied: {} days ago", days), style)));
+    }
+
+    let tags = state.job_tags(job.id);
+    if !tags.is_empty() {
+        let joined: Vec<String> = tags.iter().map(|t| format!("+{}", t)).collect();
+        lines.push(Line::from(vec![
+            Span::raw("Tags: "),
+            Span::styled(joined.join(" "), Style::default().fg(Color::Cyan)),
+        ]));
+    }
+
+    if let Some(names) = state.flags.get(&job.id) {
+        lines.push(Line::from(vec![
+            Span::raw("Red flags: "),
+            Span::styled(names.join(", "), Style::default().fg(Color::Red)),
+        ]));
+    }
+
+    if let Some(cluster) = state.clusters.get(&job.id) {
+        lines.push(Line::from(vec![
+            Span::raw("Cluster: "),
+            Span::styled(cluster.as_str(), Style::default().fg(Color::Magenta)),
+        ]));
+    }
 
     if let Some(url) = &job.url {
         lines.push(Line::from(format!("URL: {}", url)));
     }
 
-    match (job.pay_min, job.pay_max) {
-        (Some(min), Some(max)) => lines.push(Line::from(format!("Pay: ${} - ${}", min, max))),
-        (Some(min), None) => lines.push(Line::from(format!("Pay: ${}+", min))),
-        (None, Some(max)) => lines.push(Line::from(format!("Pay: up to ${}", max))),
-        (None, None) => {}
+    if job.location.is_some() || job.work_mode.is_some() {
+        lines.push(Line::from(format!("Location: {}", location::label(job))));
+    }
+
+    if let Some(pay) = pay::label(job) {
+        lines.push(Line::from(format!("Pay: {}", pay)));
+    }
+
+    if let Some(apply) = domains::apply_label(job) {
+        lines.push(Line::from(format!("Apply: {}", apply)));
+    }
+
+    if let Some(authorization) = flags::authorization_label(job) {
+        let style = if job.requires_clearance == Some(true) || job.sponsors_visa == Some(false) {
+            Style::default().fg(Color::Yellow)
+        } else {
+            Style::default()
+        };
+        lines.push(Line::from(Span::styled(format!("Work authorization: {}", authorization), style)));
+    }
+
+    let today = dates::today();
+    if let Some(posted) = dates::posted_label(job, today) {
+        lines.push(Line::from(format!("Posted: {}", posted)));
+    }
+    if let Some(deadline) = dates::deadline_label(job, today) {
+        let style = deadline_color(job, today).map_or(Style::default(), |c| Style::default().fg(c));
+        lines.push(Line::from(Span::styled(format!("Deadline: {}", deadline), style)));
     }
 
     // Fit analysis summary
@@ -565,6 +2097,59 @@ fn build_detail<'a>(state: &'a AppState) -> Text<'a> {
 
     lines.push(Line::from(""));
 
+    if !state.notes.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("Notes ({})", state.notes.len()),
+            Style::default().add_modifier(Modifier::BOLD),
+        )));
+        for note in &state.notes {
+            let mut text = note.note.lines();
+            lines.push(Line::from(vec![
+                Span::styled(format!("  {} ", timestamps::local(&note.created_at)), Style::default().fg(Color::DarkGray)),
+                Span::raw(text.next().unwrap_or_default()),
+            ]));
+            // Multi-line notes from $EDITOR continue under the first line
+            lines.extend(text.map(|line| Line::from(format!("    {}", line))));
+        }
+        lines.push(Line::from(""));
+    }
+
+    if !state.contacts.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("Contacts ({})", state.contacts.len()),
+            Style::default().add_modifier(Modifier::BOLD),
+        )));
+        for contact in &state.contacts {
+            let mut spans = vec![Span::raw(format!("  {}", contact.name))];
+            if let Some(role) = &contact.role {
+                spans.push(Span::styled(format!(" ({})", role), Style::default().fg(Color::DarkGray)));
+            }
+            for detail in [&contact.email, &contact.linkedin].into_iter().flatten() {
+                spans.push(Span::raw(" · "));
+                spans.push(Span::styled(detail.as_str(), Style::default().fg(Color::Blue)));
+            }
+            if let Some(date) = &contact.last_touch {
+                spans.push(Span::styled(format!(" · last touch {}", date), Style::default().fg(Color::DarkGray)));
+            }
+            lines.push(Line::from(spans));
+        }
+        lines.push(Line::from(""));
+    }
+
+    if let Some(summary) = &state.summary {
+        lines.push(Line::from(Span::styled(
+            format!("Summary ({})", summary.source_model),
+            Style::default().add_modifier(Modifier::BOLD),
+        )));
+        for (label, text) in summary.bullets.labeled() {
+            lines.push(Line::from(vec![
+                Span::styled(format!("  • {}: ", label), Style::default().fg(Color::DarkGray)),
+                Span::raw(text),
+            ]));
+        }
+        lines.push(Line::from(""));
+    }
+
     // Keywords
     if !state.keywords.is_empty() {
         let model = state.keyword_model.as_deref().unwrap_or("?");
@@ -575,6 +2160,20 @@ fn build_detail<'a>(state: &'a AppState) -> Text<'a> {
         lines.push(Line::from(
             Span::styled("*** required  ** important  * nice-to-have", Style::default().fg(Color::DarkGray))
         ));
+        if let Some(ratio) = db::skill_match_ratio(&state.keywords, &state.skills) {
+            let color = if ratio >= 0.75 {
+                Color::Green
+            } else if ratio >= 0.5 {
+                Color::Yellow
+            } else {
+                Color::Red
+            };
+            lines.push(Line::from(vec![
+                Span::raw("Skill match: "),
+                Span::styled(format!("{:.0}%", ratio * 100.0), Style::default().fg(color).add_modifier(Modifier::BOLD)),
+                Span::styled(" of keyword weight", Style::default().fg(Color::DarkGray)),
+            ]));
+        }
         lines.push(Line::from(""));
 
         let domains = [
@@ -637,8 +2236,13 @@ fn build_detail<'a>(state: &'a AppState) -> Text<'a> {
         )));
         lines.push(Line::from(""));
 
-        // Show raw text if no keywords
-        if let Some(text) = &job.raw_text {
+        // Show raw text if there's neither keywords nor a summary
+        if state.summary.is_some() {
+            lines.push(Line::from(Span::styled(
+                "(Raw description hidden — see hunt show --raw)",
+                Style::default().fg(Color::DarkGray),
+            )));
+        } else if let Some(text) = &job.raw_text {
             lines.push(Line::from(Span::styled(
                 "Raw Description",
                 Style::default().add_modifier(Modifier::BOLD),
@@ -660,6 +2264,7 @@ fn build_detail<'a>(state: &'a AppState) -> Text<'a> {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::JobSummaryBullets;
 
     #[test]
     fn test_truncate_str_short() {
@@ -691,11 +2296,8 @@ mod tests {
     #[test]
     fn test_format_pay_high() {
         let job = Job {
-            id: 1, employer_id: None, employer_name: None,
-            title: "Test".to_string(), url: None, source: None,
-            status: "new".to_string(), raw_text: None,
-            pay_min: Some(150000), pay_max: Some(200000),
-            job_code: None, fetched_at: None, created_at: String::new(), updated_at: String::new(),
+            id: 1, title: "Test".to_string(), status: "new".to_string(), pay_min: Some(150000),
+            pay_max: Some(200000), ..Default::default()
         };
         assert_eq!(format_pay(&job), "$200k");
     }
@@ -703,11 +2305,8 @@ mod tests {
     #[test]
     fn test_format_pay_max_only() {
         let job = Job {
-            id: 1, employer_id: None, employer_name: None,
-            title: "Test".to_string(), url: None, source: None,
-            status: "new".to_string(), raw_text: None,
-            pay_min: None, pay_max: Some(175000),
-            job_code: None, fetched_at: None, created_at: String::new(), updated_at: String::new(),
+            id: 1, title: "Test".to_string(), status: "new".to_string(), pay_max: Some(175000),
+            ..Default::default()
         };
         assert_eq!(format_pay(&job), "$175k");
     }
@@ -715,11 +2314,8 @@ mod tests {
     #[test]
     fn test_format_pay_min_only() {
         let job = Job {
-            id: 1, employer_id: None, employer_name: None,
-            title: "Test".to_string(), url: None, source: None,
-            status: "new".to_string(), raw_text: None,
-            pay_min: Some(120000), pay_max: None,
-            job_code: None, fetched_at: None, created_at: String::new(), updated_at: String::new(),
+            id: 1, title: "Test".to_string(), status: "new".to_string(), pay_min: Some(120000),
+            ..Default::default()
         };
         assert_eq!(format_pay(&job), "$120k");
     }
@@ -727,11 +2323,7 @@ mod tests {
     #[test]
     fn test_format_pay_none() {
         let job = Job {
-            id: 1, employer_id: None, employer_name: None,
-            title: "Test".to_string(), url: None, source: None,
-            status: "new".to_string(), raw_text: None,
-            pay_min: None, pay_max: None,
-            job_code: None, fetched_at: None, created_at: String::new(), updated_at: String::new(),
+            id: 1, title: "Test".to_string(), status: "new".to_string(), ..Default::default()
         };
         assert_eq!(format_pay(&job), "   - ");
     }
@@ -739,11 +2331,8 @@ mod tests {
     #[test]
     fn test_format_pay_small_value() {
         let job = Job {
-            id: 1, employer_id: None, employer_name: None,
-            title: "Test".to_string(), url: None, source: None,
-            status: "new".to_string(), raw_text: None,
-            pay_min: None, pay_max: Some(500),
-            job_code: None, fetched_at: None, created_at: String::new(), updated_at: String::new(),
+            id: 1, title: "Test".to_string(), status: "new".to_string(), pay_max: Some(500),
+            ..Default::default()
         };
         assert_eq!(format_pay(&job), "$ 500");
     }
@@ -758,11 +2347,8 @@ mod tests {
 
     fn make_job(id: i64, title: &str, employer: Option<&str>, status: &str, pay_max: Option<i64>) -> Job {
         Job {
-            id, employer_id: None, employer_name: employer.map(|s| s.to_string()),
-            title: title.to_string(), url: None, source: None,
-            status: status.to_string(), raw_text: None,
-            pay_min: None, pay_max,
-            job_code: None, fetched_at: None, created_at: String::new(), updated_at: String::new(),
+            id, employer_name: employer.map(|s| s.to_string()), title: title.to_string(),
+            status: status.to_string(), pay_max, ..Default::default()
         }
     }
 
@@ -772,22 +2358,74 @@ mod tests {
             jobs,
             scores,
             fit_scores,
+            tags: HashMap::new(),
+            flags: HashMap::new(),
+            clusters: HashMap::new(),
             selected: 0,
             scroll_offset: 0,
             keywords: Vec::new(),
             profile: None,
+            summary: None,
             keyword_model: None,
             fit_analysis: None,
+            notes: Vec::new(),
+            contacts: Vec::new(),
+            employer_links: EmployerLinks::default(),
+            skills: Vec::new(),
             search_active: false,
             search_query: String::new(),
             hide_closed: true,
+            statuses: db::default_job_statuses(),
             sort_field: SortField::Score,
             sort_ascending: false,
+            message: None,
+            task: None,
+            view: View::Jobs,
+            employers: Vec::new(),
+            employer_selected: 0,
+            employer_filter: None,
+            saved_filter: None,
+            filter_picker: None,
+            pay_floor: None,
+            pay_floor_jobs: None,
+            note_input: None,
+            keys: Keymap::default(),
+            show_help: false,
+            columns: Column::DEFAULT.to_vec(),
+            board: false,
         };
         s.update_filter();
         s
     }
 
+    #[test]
+    fn test_update_filter_hides_terminal_statuses() {
+        let jobs = vec![
+            make_job(1, "Interviewing", Some("Co"), "interviewing", None),
+            make_job(2, "Withdrawn", Some("Co"), "withdrawn", None),
+            make_job(3, "Rejected", Some("Co"), "rejected", None),
+        ];
+        let state = make_state(jobs, vec![50.0, 60.0, 70.0], vec![None, None, None]);
+        assert_eq!(state.visible, vec![0]);
+    }
+
+    #[test]
+    fn test_next_status_follows_pipeline_order() {
+        let state = make_state(vec![], vec![], vec![]);
+        assert_eq!(state.next_status("new"), Some("reviewing"));
+        assert_eq!(state.next_status("applied"), Some("interviewing"));
+        assert_eq!(state.next_status("closed"), Some("new"));
+        assert_eq!(state.next_status("bogus"), Some("new"));
+    }
+
+    #[test]
+    fn test_status_color_from_config() {
+        let state = make_state(vec![], vec![], vec![]);
+        assert_eq!(state.status_color("reviewing"), Some(Color::Yellow));
+        assert_eq!(state.status_color("closed"), Some(Color::DarkGray));
+        assert_eq!(state.status_color("bogus"), None);
+    }
+
     #[test]
     fn test_update_filter_hides_closed() {
         let jobs = vec![
@@ -836,6 +2474,20 @@ mod tests {
         assert_eq!(state.visible.len(), 1);
     }
 
+    #[test]
+    fn test_update_filter_search_by_tag() {
+        let jobs = vec![
+            make_job(1, "Engineer", Some("Google"), "new", None),
+            make_job(2, "Engineer", Some("Meta"), "new", None),
+        ];
+        let mut state = make_state(jobs, vec![50.0, 50.0], vec![None, None]);
+        state.tags.insert(2, vec!["remote".to_string()]);
+        state.search_query = "remote".to_string();
+        state.update_filter();
+        assert_eq!(state.visible.len(), 1);
+        assert_eq!(state.current_job().unwrap().id, 2);
+    }
+
     #[test]
     fn test_sort_by_score_descending() {
         let jobs = vec![
@@ -1038,6 +2690,51 @@ mod tests {
         assert!(content.contains("https://example.com/job/1"));
     }
 
+    #[test]
+    fn test_build_detail_with_tags_and_notes() {
+        let jobs = vec![make_job(1, "DevOps Engineer", Some("Acme Corp"), "applied", None)];
+        let mut state = make_state(jobs, vec![50.0], vec![None]);
+        state.tags.insert(1, vec!["golang".to_string(), "remote".to_string()]);
+        state.clusters.insert(1, "Platform eng - k8s/Go".to_string());
+        state.notes.push(JobNote {
+            id: 1,
+            job_id: 1,
+            note: "Recruiter call went well".to_string(),
+            created_at: "2026-03-01 10:00:00".to_string(),
+        });
+        let text = build_detail(&state);
+        let content: String = text.lines.iter()
+            .flat_map(|l| l.spans.iter().map(|s| s.content.to_string()))
+            .collect();
+        assert!(content.contains("Tags: +golang +remote"));
+        assert!(content.contains("Cluster: Platform eng - k8s/Go"));
+        assert!(content.contains("Notes (1)"));
+        assert!(content.contains("Recruiter call went well"));
+    }
+
+    #[test]
+    fn test_build_detail_with_contacts() {
+        let jobs = vec![make_job(1, "DevOps Engineer", Some("Acme Corp"), "applied", None)];
+        let mut state = make_state(jobs, vec![50.0], vec![None]);
+        state.contacts.push(Contact {
+            id: 1,
+            name: "Jane Doe".to_string(),
+            role: Some("recruiter".to_string()),
+            employer_id: Some(1),
+            employer_name: Some("Acme Corp".to_string()),
+            email: Some("jane@acme.com".to_string()),
+            linkedin: None,
+            last_touch: Some("2026-03-02".to_string()),
+            created_at: "2026-03-01T10:00:00Z".to_string(),
+        });
+        let text = build_detail(&state);
+        let content: String = text.lines.iter()
+            .flat_map(|l| l.spans.iter().map(|s| s.content.to_string()))
+            .collect();
+        assert!(content.contains("Contacts (1)"));
+        assert!(content.contains("Jane Doe (recruiter) · jane@acme.com · last touch 2026-03-02"));
+    }
+
     #[test]
     fn test_build_detail_with_pay_range() {
         let job = make_job(1, "Engineer", Some("Co"), "reviewing", Some(200000));
@@ -1185,6 +2882,33 @@ mod tests {
         assert!(content.contains("Leadership"));
     }
 
+    #[test]
+    fn test_build_detail_skill_match() {
+        let job = make_job(1, "Eng", Some("Co"), "new", None);
+        let mut state = make_state(vec![job], vec![50.0], vec![None]);
+        state.keywords = vec![
+            JobKeyword {
+                id: 1, job_id: 1, keyword: "Kubernetes".to_string(),
+                domain: "tech".to_string(), weight: 3,
+                source_model: "gpt-5.2".to_string(), created_at: String::new(),
+            },
+            JobKeyword {
+                id: 2, job_id: 1, keyword: "Python".to_string(),
+                domain: "tech".to_string(), weight: 1,
+                source_model: "gpt-5.2".to_string(), created_at: String::new(),
+            },
+        ];
+        let render = |state: &AppState| -> String {
+            build_detail(state).lines.iter()
+                .flat_map(|l| l.spans.iter().map(|s| s.content.to_string()))
+                .collect()
+        };
+        assert!(!render(&state).contains("Skill match"), "hidden without a skill profile");
+
+        state.skills = vec!["kubernetes".to_string()];
+        assert!(render(&state).contains("Skill match: 75%"));
+    }
+
     #[test]
     fn test_build_detail_with_profile() {
         let job = make_job(1, "Eng", Some("Co"), "new", None);
@@ -1223,6 +2947,25 @@ mod tests {
         assert!(content.contains("Full job description here"));
     }
 
+    #[test]
+    fn test_build_detail_summary_replaces_raw_text() {
+        let mut job = make_job(1, "Eng", Some("Co"), "new", None);
+        job.raw_text = Some("Full job description here".to_string());
+        let mut state = make_state(vec![job], vec![50.0], vec![None]);
+        state.summary = Some(JobSummary {
+            id: 1, job_id: 1, source_model: "mock".to_string(),
+            bullets: JobSummaryBullets { stack: "Go, Postgres".to_string(), ..Default::default() },
+            created_at: String::new(),
+        });
+        let text = build_detail(&state);
+        let content: String = text.lines.iter()
+            .flat_map(|l| l.spans.iter().map(|s| s.content.to_string()))
+            .collect();
+        assert!(content.contains("Summary (mock)"));
+        assert!(content.contains("• Stack: Go, Postgres"));
+        assert!(!content.contains("Full job description here"));
+    }
+
     #[test]
     fn test_build_detail_no_description() {
         let job = make_job(1, "Eng", Some("Co"), "new", None);
@@ -1234,6 +2977,425 @@ mod tests {
         assert!(content.contains("No description fetched"));
     }
 
+    #[test]
+    fn test_build_detail_shows_employer_links() {
+        let job = make_job(1, "SRE", Some("Acme"), "new", None);
+        let mut state = make_state(vec![job], vec![50.0], vec![None]);
+        state.employer_links = EmployerLinks {
+            domain: Some("acme.com".to_string()),
+            careers_url: Some("https://jobs.lever.co/acme".to_string()),
+        };
+        let text = build_detail(&state);
+        let content: String = text.lines.iter()
+            .flat_map(|l| l.spans.iter().map(|s| s.content.to_string()))
+            .collect();
+        assert!(content.contains("at Acme  https://acme.com"));
+        assert!(content.contains("Careers: https://jobs.lever.co/acme"));
+    }
+
+    #[test]
+    fn test_url_to_open() {
+        let mut job = make_job(1, "SRE", Some("Acme"), "new", None);
+        job.url = Some("https://boards.greenhouse.io/acme/jobs/1".to_string());
+        let mut state = make_state(vec![job, make_job(2, "Solo", None, "new", None)], vec![50.0, 40.0], vec![None, None]);
+
+        assert_eq!(state.url_to_open(false), Ok("https://boards.greenhouse.io/acme/jobs/1"));
+        assert_eq!(state.url_to_open(true), Err("No careers page known for Acme (try: hunt employer enrich)".to_string()));
+        state.employer_links.careers_url = Some("https://boards.greenhouse.io/acme".to_string());
+        assert_eq!(state.url_to_open(true), Ok("https://boards.greenhouse.io/acme"));
+
+        state.next();
+        state.employer_links = EmployerLinks::default();
+        assert_eq!(state.url_to_open(false), Err("Job #2 has no URL".to_string()));
+        assert_eq!(state.url_to_open(true), Err("Job #2 has no employer".to_string()));
+    }
+
+    #[test]
+    fn test_action_blocker() {
+        let mut fetched = make_job(1, "SRE", Some("Acme"), "new", None);
+        fetched.url = Some("https://acme.com/careers/1".to_string());
+        fetched.raw_text = Some("Run Kubernetes".to_string());
+        let mut state = make_state(vec![fetched, make_job(2, "Bare", None, "new", None)], vec![50.0, 40.0], vec![None, None]);
+        let mut config = ActionConfig { db_path: PathBuf::from(":memory:"), model: "gpt-5.2".to_string(), resume_id: Some(1) };
+
+        assert_eq!(state.action_blocker(Action::Fetch, &config), None);
+        assert_eq!(state.action_blocker(Action::Keywords, &config), None);
+        assert_eq!(state.action_blocker(Action::Fit, &config), None);
+        config.resume_id = None;
+        assert!(state.action_blocker(Action::Fit, &config).unwrap().contains("hunt browse --resume"));
+
+        state.next();
+        assert_eq!(state.action_blocker(Action::Fetch, &config), Some("Job #2 has no URL to fetch".to_string()));
+        assert!(state.action_blocker(Action::Keywords, &config).unwrap().contains("press F"));
+
+        let (_tx, rx) = mpsc::channel();
+        state.task = Some(Task { action: Action::Keywords, job_id: 1, progress: String::new(), started: Instant::now(), events: rx });
+        assert_eq!(state.action_blocker(Action::Fetch, &config), Some("Still busy: extracting keywords for job #1".to_string()));
+    }
+
+    #[test]
+    fn test_poll_task_reloads_job() -> Result<()> {
+        let db = Database::open_in_memory()?;
+        db.init()?;
+        let id = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        let mut state = AppState::new(db.list_jobs(None, None)?, &db);
+        db.update_job_description(id, "Run Kubernetes", Some(150_000), None)?;
+
+        let (tx, rx) = mpsc::channel();
+        state.task = Some(Task { action: Action::Fetch, job_id: id, progress: String::new(), started: Instant::now(), events: rx });
+        tx.send(TaskEvent::Progress("GET https://acme.com".to_string()))?;
+        assert!(!state.poll_task(&db));
+        assert_eq!(state.task.as_ref().unwrap().progress, "GET https://acme.com");
+
+        tx.send(TaskEvent::Done(Ok("Fetched job #1 (14 chars)".to_string())))?;
+        assert!(state.poll_task(&db));
+        assert!(state.task.is_none());
+        assert_eq!(state.message.as_deref(), Some("Fetched job #1 (14 chars)"));
+        assert_eq!(state.jobs[0].raw_text.as_deref(), Some("Run Kubernetes"));
+        assert_eq!(state.jobs[0].pay_min, Some(150_000));
+
+        let (tx, rx) = mpsc::channel::<TaskEvent>();
+        state.task = Some(Task { action: Action::Fit, job_id: id, progress: String::new(), started: Instant::now(), events: rx });
+        drop(tx);
+        assert!(state.poll_task(&db));
+        assert_eq!(state.message.as_deref(), Some("Analyzing fit failed for job #1: stopped unexpectedly"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_archive_current_job() -> Result<()> {
+        let db = Database::open_in_memory()?;
+        db.init()?;
+        db.add_job_full("SRE", Some("Acme"), None, None, None, Some(200000), None)?;
+        db.add_job_full("DevOps", Some("Globex"), None, None, None, None, None)?;
+        let mut state = AppState::new(db.list_jobs(None, None)?, &db);
+        let id = state.current_job().unwrap().id;
+
+        state.archive_current_job(&db);
+        assert_eq!((state.jobs.len(), state.scores.len(), state.fit_scores.len(), state.visible.len()), (1, 1, 1, 1));
+        assert_ne!(state.current_job().unwrap().id, id);
+        assert_eq!(state.message, Some(format!("Archived job #{} (hunt restore {} to undo)", id, id)));
+        assert!(db.get_job(id)?.unwrap().archived_at.is_some(), "archived, not deleted");
+        assert_eq!(db.list_jobs(None, None)?.len(), 1);
+        Ok(())
+    }
+
+    #[test]
+    fn test_guarded_status_change() -> Result<()> {
+        let db = Database::open_in_memory()?;
+        db.init()?;
+        let id = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        let mut state = AppState::new(db.list_jobs(None, None)?, &db);
+
+        state.update_current_job_status(&db, "applied");
+        assert!(state.current_job().unwrap().applied_at.is_some(), "reloaded with its timestamp");
+        state.update_current_job_status(&db, "new");
+        assert_eq!(state.current_job().unwrap().status, "applied");
+        assert_eq!(
+            state.message.as_deref(),
+            Some(format!("'new' comes before 'applied' (hunt status set {} new --force)", id).as_str())
+        );
+        assert_eq!(db.get_job(id)?.unwrap().status, "applied");
+        Ok(())
+    }
+
+    #[test]
+    fn test_board() -> Result<()> {
+        let db = Database::open_in_memory()?;
+        db.init()?;
+        let sre = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        let dba = db.add_job_full("DBA", Some("Acme"), None, None, None, None, None)?;
+        let platform = db.add_job_full("Platform", Some("Globex"), None, None, None, None, None)?;
+        let closed = db.add_job_full("Closed", Some("Globex"), None, None, None, None, None)?;
+        db.update_job_status(platform, "applied")?;
+        db.update_job_status(closed, "closed")?;
+        let mut state = AppState::new(db.list_jobs(None, None)?, &db);
+        state.hide_closed = false;
+        state.sort_field = SortField::Company;
+        state.sort_ascending = true;
+        state.update_filter();
+        let ids = |state: &AppState, column| -> Vec<i64> {
+            state.board_jobs(column).iter().map(|&pos| state.jobs[state.visible[pos]].id).collect()
+        };
+
+        // Closed sorts first but isn't on the board
+        state.selected = state.visible.iter().position(|&i| state.jobs[i].id == closed).unwrap();
+        state.toggle_board();
+        assert!(state.board);
+        assert_eq!(state.board_column(), Some(0));
+        assert_eq!(ids(&state, 0).len(), 2);
+        assert_eq!(ids(&state, 2), vec![platform]);
+
+        state.board_step(1);
+        state.board_step(1);
+        assert_eq!(state.current_job().unwrap().id, ids(&state, 0)[1], "stops at the bottom");
+        state.board_focus(1);
+        assert_eq!(state.current_job().unwrap().id, platform, "skips the empty reviewing column");
+        state.board_focus(1);
+        assert_eq!(state.current_job().unwrap().id, platform, "nothing further right");
+
+        state.board_focus(-1);
+        let moved = state.current_job().unwrap().id;
+        assert!([sre, dba].contains(&moved));
+        state.move_on_board(&db, 1);
+        assert_eq!(state.board_column(), Some(1));
+        assert_eq!(db.get_job(moved)?.unwrap().status, "reviewing");
+        state.move_on_board(&db, 1);
+        assert_eq!(ids(&state, 2).len(), 2);
+        state.move_on_board(&db, -1);
+        assert_eq!(db.get_job(moved)?.unwrap().status, "applied", "guarded like any status change");
+        assert!(state.message.as_deref().unwrap().ends_with("reviewing --force)"));
+        state.move_on_board(&db, 4);
+        assert_eq!(db.get_job(moved)?.unwrap().status, "applied", "no column that far");
+
+        // Custom statuses get columns in pipeline order; terminal ones besides rejected don't
+        db.upsert_job_status("phone-screen", Some(35), false, "blue")?;
+        db.upsert_job_status("ghosted", Some(75), true, "gray")?;
+        let mut state = AppState::new(db.list_jobs(None, None)?, &db);
+        state.update_filter();
+        assert_eq!(
+            state.board_statuses(),
+            vec!["new", "reviewing", "applied", "phone-screen", "interviewing", "offer", "rejected"]
+        );
+        state.selected = state.visible.iter().position(|&i| state.jobs[i].id == moved).unwrap();
+        state.toggle_board();
+        state.move_on_board(&db, 1);
+        assert_eq!(db.get_job(moved)?.unwrap().status, "phone-screen");
+        assert_eq!(state.board_column(), Some(3));
+        state.move_on_board(&db, 1);
+        assert_eq!(db.get_job(moved)?.unwrap().status, "interviewing");
+        Ok(())
+    }
+
+    #[test]
+    fn test_employers_tab() -> Result<()> {
+        let db = Database::open_in_memory()?;
+        db.init()?;
+        db.add_job_full("SRE", Some("Zeta"), None, None, None, None, None)?;
+        let rejected = db.add_job_full("Platform", Some("Zeta"), None, None, None, None, None)?;
+        db.update_job_status(rejected, "rejected")?;
+        db.add_job_full("DevOps", Some("Acme"), None, None, None, None, None)?;
+        db.add_job_full("Infra", Some("Acme"), None, None, None, None, None)?;
+        db.add_job_full("Solo", None, None, None, None, None, None)?;
+        db.get_or_create_employer("Idle Co")?;
+        let mut state = AppState::new(db.list_jobs(None, None)?, &db);
+
+        let names: Vec<&str> = state.employers.iter().map(|e| e.name.as_str()).collect();
+        assert_eq!(names, vec!["Acme", "Zeta", "Idle Co"], "most active jobs first");
+        let zeta = state.employers[1].id;
+        assert_eq!(state.employer_job_counts()[&zeta], (2, 1));
+
+        state.toggle_view();
+        state.next_employer();
+        state.show_employer_jobs();
+        assert_eq!(state.view, View::Jobs);
+        assert_eq!(state.visible.len(), 1, "Zeta's rejected job stays hidden");
+        assert_eq!(state.current_job().unwrap().title, "SRE");
+        state.employer_filter = None;
+        state.update_filter();
+        assert_eq!(state.visible.len(), 4);
+
+        let before = state.scores.clone();
+        state.set_current_employer_status(&db, "never");
+        assert_eq!(state.employers[1].status, "never");
+        assert_eq!(db.get_employer_by_name("Zeta")?.unwrap().status, "never");
+        assert_eq!(state.message.as_deref(), Some("Zeta marked 'never'"));
+        let zeta_job = state.jobs.iter().position(|j| j.title == "SRE").unwrap();
+        assert!(state.scores[zeta_job] < before[zeta_job], "blocked employer ranks lower");
+
+        let counts = state.employer_job_counts();
+        let content: String = build_employer_detail(&state, &counts).lines.iter()
+            .flat_map(|l| l.spans.iter().map(|s| s.content.to_string()))
+            .collect();
+        assert!(content.contains("Status: never"));
+        assert!(content.contains("Jobs: 2 (1 active)"));
+        assert!(content.contains("Research: ....."));
+        Ok(())
+    }
+
+    #[test]
+    fn test_saved_filter_picker() -> Result<()> {
+        let db = Database::open_in_memory()?;
+        db.init()?;
+        db.add_job_full("SRE", Some("Acme"), None, None, None, Some(200000), None)?;
+        db.add_job_full("DevOps", Some("Globex"), None, None, None, Some(120000), None)?;
+        let mut state = AppState::new(db.list_jobs(None, None)?, &db);
+
+        state.open_filter_picker(&db);
+        assert!(state.filter_picker.is_none());
+        assert!(state.message.as_deref().unwrap().contains("hunt filter save"));
+
+        db.save_filter(&SavedFilter { name: "senior".to_string(), min_pay: Some(180000), ..Default::default() })?;
+        state.open_filter_picker(&db);
+        let (filters, _) = state.filter_picker.take().unwrap();
+        state.apply_saved_filter(&db, &filters[0]);
+        assert_eq!(state.visible.len(), 1);
+        assert_eq!(state.current_job().unwrap().title, "SRE");
+        assert_eq!(state.message.as_deref(), Some("Filter 'senior': pay $180k+"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_pay_floor_toggle() -> Result<()> {
+        let db = Database::open_in_memory()?;
+        db.init()?;
+        db.add_job_full("SRE", Some("Acme"), None, None, None, Some(200000), None)?;
+        db.add_job_full("DevOps", Some("Globex"), None, None, None, Some(120000), None)?;
+        db.add_job_full("Platform", Some("Initech"), None, None, None, None, None)?;
+        let mut state = AppState::new(db.list_jobs(None, None)?, &db);
+
+        state.toggle_pay_floor(&db);
+        assert!(state.message.as_deref().unwrap().starts_with("No pay floor set"));
+        assert_eq!(state.visible.len(), 3);
+
+        state.pay_floor = Some(150000);
+        state.toggle_pay_floor(&db);
+        assert_eq!(state.visible.len(), 1, "unadvertised pay doesn't clear the floor");
+        assert_eq!(state.current_job().unwrap().title, "SRE");
+        state.toggle_pay_floor(&db);
+        assert_eq!(state.visible.len(), 3);
+        assert_eq!(state.message.as_deref(), Some("Pay floor off"));
+        Ok(())
+    }
+
+    #[test]
+    fn test_notes_overlay_and_editor() -> Result<()> {
+        let db = Database::open_in_memory()?;
+        db.init()?;
+        let id = db.add_job_full("SRE", Some("Acme"), None, None, None, None, None)?;
+        let mut state = AppState::new(db.list_jobs(None, None)?, &db);
+
+        state.note_input = Some("   ".to_string());
+        state.save_note_input(&db);
+        assert!(state.note_input.is_none());
+        assert!(state.notes.is_empty(), "blank notes aren't saved");
+
+        state.note_input = Some("Phone screen Tuesday ".to_string());
+        state.save_note_input(&db);
+        assert_eq!(state.message.as_deref(), Some("Note added to job #1"));
+        assert_eq!(db.list_job_notes(id)?[0].note, "Phone screen Tuesday");
+
+        let edited = edit_in_editor("sed -i s/Tuesday/Wednesday/", &state.notes[0].note)?;
+        state.save_edited_note(&db, &edited);
+        assert_eq!(state.message.as_deref(), Some("Note updated on job #1"));
+        assert_eq!(state.notes.len(), 1);
+        assert_eq!(state.notes[0].note, "Phone screen Wednesday");
+
+        state.save_edited_note(&db, "\n");
+        assert_eq!(state.message.as_deref(), Some("Note deleted on job #1"));
+        assert!(db.list_job_notes(id)?.is_empty());
+
+        assert!(edit_in_editor("false", "x").is_err());
+        assert!(edit_in_editor("no-such-editor-hunt", "x").is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_keymap_overrides() {
+        let defaults = Keymap::default();
+        assert_eq!(defaults.binding(View::Jobs, 'a'), Some(Binding::StatusApplied));
+        assert_eq!(defaults.binding(View::Employers, 'u'), Some(Binding::EmployerOk));
+        assert_eq!(defaults.binding(View::Jobs, 'j'), None);
+
+        let overrides = |pairs: &[(&str, &str)]| -> HashMap<String, String> {
+            pairs.iter().map(|(a, k)| (a.to_string(), k.to_string())).collect()
+        };
+        let keys = Keymap::with_overrides(&overrides(&[("status-applied", "A"), ("status-new", "N"), ("employer-never", "x")])).unwrap();
+        assert_eq!(keys.key(Binding::StatusApplied), 'A');
+        assert_eq!(keys.binding(View::Jobs, 'a'), None);
+        assert_eq!(keys.binding(View::Jobs, 'N'), Some(Binding::StatusNew));
+        assert_eq!(keys.binding(View::Employers, 'x'), Some(Binding::EmployerNever), "views don't conflict");
+        assert_eq!(keys.binding(View::Jobs, 'x'), Some(Binding::StatusRejected));
+
+        for bad in [&[("status-applied", "j")][..], &[("status-applied", "AA")], &[("applied", "A")],
+                    &[("status-applied", "r")], &[("status-new", "?")]] {
+            let err = Keymap::with_overrides(&overrides(bad)).unwrap_err();
+            assert!(matches!(err.downcast_ref::<HuntError>(), Some(HuntError::Config(_))), "{:?}", bad);
+        }
+        // Swapping two keys is fine
+        let swapped = Keymap::with_overrides(&overrides(&[("status-new", "r"), ("status-reviewing", "n")])).unwrap();
+        assert_eq!(swapped.binding(View::Jobs, 'r'), Some(Binding::StatusNew));
+
+        let help: Vec<String> = help_lines(&keys).iter().map(|l| l.to_string()).collect();
+        assert!(help.iter().any(|l| l.contains("A") && l.ends_with("Set status: applied")));
+        assert!(help.iter().any(|l| l.trim_start().starts_with("?") && l.ends_with("This help")));
+    }
+
+    #[test]
+    fn test_columns() {
+        let names = |list: &[&str]| list.iter().map(|n| n.to_string()).collect::<Vec<_>>();
+        assert_eq!(Column::parse_list(&names(&["Age", "score", "tags"])).unwrap(), vec![Column::Age, Column::Score, Column::Tags]);
+        assert!(Column::parse_list(&[]).unwrap().is_empty());
+        for bad in [&["salary", "pay"][..], &["fit", "fit"]] {
+            let err = Column::parse_list(&names(bad)).unwrap_err();
+            assert!(matches!(err.downcast_ref::<HuntError>(), Some(HuntError::Config(_))), "{:?}", bad);
+        }
+
+        let now = timestamps::parse("2026-05-01T12:00:00Z").unwrap();
+        assert_eq!(format_age("2026-04-20T08:00:00Z", now), "11d");
+        assert_eq!(format_age("2025-05-01T12:00:00Z", now), "52w");
+        assert_eq!(format_age("", now), "-");
+
+        let mut job = make_job(1, "SRE", Some("Co"), "new", Some(185000));
+        job.source = Some("greenhouse".to_string());
+        job.created_at = "2026-04-28T12:00:00Z".to_string();
+        job.location = Some("San Francisco, CA (Hybrid)".to_string());
+        job.work_mode = Some("hybrid".to_string());
+        job.status = "applied".to_string();
+        job.applied_at = Some("2026-04-10T12:00:00Z".to_string());
+        job.apply_deadline = Some("2026-05-06".to_string());
+        let mut state = make_state(vec![job.clone()], vec![42.4], vec![Some(80.0)]);
+        state.flags.insert(1, vec!["on-call".to_string(), "unlimited-pto".to_string()]);
+        let cells: Vec<String> = Column::ALL.iter().map(|&c| column_cell(&state, 0, c, now).content.to_string()).collect();
+        assert_eq!(cells, vec!["  42 ", " 80 ", "$185k ", "  3d ", "greenh.. ", "hybrid ", "San Francisco,.. ", " 21d ", "  5d ", " ⚑2 ", ""]);
+        assert_eq!(column_cell(&state, 0, Column::Applied, now).style.fg, Some(Color::Yellow), "stalled");
+        assert_eq!(column_cell(&state, 0, Column::Deadline, now).style.fg, Some(Color::DarkGray), "already applied");
+        job.status = "new".to_string();
+        let state = make_state(vec![job], vec![42.4], vec![Some(80.0)]);
+        assert_eq!(column_cell(&state, 0, Column::Deadline, now).style.fg, Some(Color::Yellow), "closing soon");
+        for (cell, column) in cells.iter().zip(Column::ALL) {
+            assert_eq!(cell.chars().count(), column.width(), "{:?}", column);
+        }
+    }
+
+    #[test]
+    fn test_saved_view_round_trip() {
+        let mut jobs = vec![
+            make_job(1, "SRE", Some("Acme"), "new", Some(150000)),
+            make_job(2, "Platform", Some("Globex"), "closed", Some(200000)),
+        ];
+        jobs[0].employer_id = Some(7);
+        let mut state = make_state(jobs, vec![1.0, 2.0], vec![None, None]);
+        state.set_sort(SortField::Salary);
+        state.set_sort(SortField::Salary);
+        state.hide_closed = false;
+        state.employer_filter = Some((7, "Acme".to_string()));
+        state.search_query = "sre".to_string();
+
+        let path = std::env::temp_dir().join(format!("hunt-tui-state-{}.json", std::process::id()));
+        state.saved_view().save(&path).unwrap();
+        let saved = SavedView::load(&path);
+        std::fs::remove_file(&path).unwrap();
+        assert_eq!(saved, state.saved_view());
+        assert_eq!(SavedView::load(&path), SavedView::default(), "missing file means defaults");
+
+        let mut restored = make_state(state.jobs.clone(), vec![1.0, 2.0], vec![None, None]);
+        restored.restore_view(saved);
+        assert_eq!((restored.sort_field, restored.sort_ascending, restored.hide_closed), (SortField::Salary, true, false));
+        assert_eq!(restored.visible, vec![0]);
+
+        // An employer with no jobs any more isn't restored
+        let mut gone = state.saved_view();
+        gone.employer = Some((99, "Initech".to_string()));
+        gone.search.clear();
+        restored.restore_view(gone);
+        assert_eq!(restored.employer_filter, None);
+        assert_eq!(restored.visible, vec![0, 1], "salary ascending, closed jobs shown");
+
+        let partial: SavedView = serde_json::from_str(r#"{"sort_field": "fit"}"#).unwrap();
+        assert_eq!((partial.sort_field, partial.hide_closed), (SortField::Fit, true));
+    }
+
     #[test]
     fn test_build_detail_no_employer() {
         let job = make_job(1, "Solo Job", None, "new", None);
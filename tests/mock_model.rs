@@ -0,0 +1,158 @@
+//! End-to-end runs of AI commands against `--model mock`, which replays fixtures
+//! from `HUNT_AI_FIXTURES` instead of calling a provider.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+
+const JOB: &str = "Senior SRE at Acme\n\nWe run Kubernetes on AWS with Terraform. You will lead on-call \
+                   and mentor engineers. Python required.";
+
+const KEYWORDS: &str = "TECH: Kubernetes/3, Terraform/2, Python/3\n\
+                        DISCIPLINE: SRE/3, on-call/2\n\
+                        CLOUD: AWS/3\n\
+                        SOFT_SKILL: mentoring/2\n\
+                        PROFILE: Hands-on reliability role on a Kubernetes/AWS stack.";
+
+const FIT: &str = "SCORE: 85\n\
+                   STRONG_MATCHES: Kubernetes, AWS\n\
+                   GAPS: Terraform\n\
+                   STRETCH_AREAS: mentoring\n\
+                   NARRATIVE:\n\
+                   A close match.";
+
+/// A scratch home and database per test, so nothing touches the real ones.
+struct Sandbox {
+    dir: PathBuf,
+}
+
+impl Sandbox {
+    fn new(name: &str) -> Self {
+        let dir = std::env::temp_dir().join(format!("hunt-mock-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        let sandbox = Sandbox { dir };
+        sandbox.ok(&["init"], &[]);
+        sandbox
+    }
+
+    fn path(&self, name: &str) -> PathBuf {
+        self.dir.join(name)
+    }
+
+    /// A fixtures directory that answers every prompt with `response`.
+    fn fixtures(&self, name: &str, response: &str) -> PathBuf {
+        let dir = self.path(name);
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("fallback.txt"), response).unwrap();
+        dir
+    }
+
+    fn hunt(&self, args: &[&str], env: &[(&str, &Path)]) -> Output {
+        let mut command = Command::new(env!("CARGO_BIN_EXE_hunt"));
+        command
+            .arg("--db")
+            .arg(self.path("hunt.db"))
+            .args(args)
+            .env("HOME", &self.dir)
+            .env_remove("XDG_CONFIG_HOME")
+            .env_remove("XDG_DATA_HOME")
+            .env_remove("HUNT_DB")
+            .env_remove("HUNT_AI_FIXTURES")
+            .env_remove("HUNT_AI_RECORD");
+        for (name, value) in env {
+            command.env(name, value);
+        }
+        command.output().unwrap()
+    }
+
+    /// Run and require success; returns stdout.
+    fn ok(&self, args: &[&str], env: &[(&str, &Path)]) -> String {
+        let output = self.hunt(args, env);
+        assert!(
+            output.status.success(),
+            "hunt {:?} failed: {}",
+            args,
+            String::from_utf8_lossy(&output.stderr)
+        );
+        String::from_utf8_lossy(&output.stdout).into_owned()
+    }
+
+    fn with_job(self) -> Self {
+        self.ok(&["add", JOB], &[]);
+        self
+    }
+
+    fn with_resume(self) -> Self {
+        let resume = self.path("resume.md");
+        fs::write(&resume, "# Pat\n\nSRE, 8 years of Kubernetes and AWS.").unwrap();
+        self.ok(&["resume", "add", "base", resume.to_str().unwrap()], &[]);
+        self
+    }
+
+    fn drop_dir(self) {
+        fs::remove_dir_all(&self.dir).unwrap();
+    }
+}
+
+#[test]
+fn keywords() {
+    let sandbox = Sandbox::new("keywords").with_job();
+    let fixtures = sandbox.fixtures("fixtures", KEYWORDS);
+    sandbox.ok(&["keywords", "1", "--model", "mock"], &[("HUNT_AI_FIXTURES", &fixtures)]);
+
+    let shown = sandbox.ok(&["keywords", "1", "--show", "--model", "mock"], &[]);
+    assert!(shown.contains("Kubernetes"), "{}", shown);
+    assert!(shown.contains("mentoring"), "{}", shown);
+    sandbox.drop_dir();
+}
+
+#[test]
+fn fit() {
+    let sandbox = Sandbox::new("fit").with_job().with_resume();
+    let fixtures = sandbox.fixtures("fixtures", FIT);
+    let out = sandbox.ok(&["fit", "1", "--resume", "base", "--model", "mock"], &[("HUNT_AI_FIXTURES", &fixtures)]);
+    assert!(out.contains("Fit Score: 85/100"), "{}", out);
+    sandbox.drop_dir();
+}
+
+#[test]
+fn tailor() {
+    let sandbox = Sandbox::new("tailor").with_job().with_resume();
+    let fixtures = sandbox.fixtures("fixtures", "# Pat\n\nKubernetes and AWS reliability engineer.");
+    let tailored = sandbox.path("tailored.md");
+    sandbox.ok(
+        &["resume", "tailor", "1", "--resume", "base", "--model", "mock", "--output", tailored.to_str().unwrap()],
+        &[("HUNT_AI_FIXTURES", &fixtures)],
+    );
+    assert!(fs::read_to_string(&tailored).unwrap().contains("reliability engineer"));
+    sandbox.drop_dir();
+}
+
+#[test]
+fn recorded_responses_replay() {
+    let sandbox = Sandbox::new("record").with_job();
+    let catch_all = sandbox.fixtures("catch-all", KEYWORDS);
+    let recorded = sandbox.path("recorded");
+    sandbox.ok(
+        &["keywords", "1", "--model", "mock", "--no-cache"],
+        &[("HUNT_AI_FIXTURES", &catch_all), ("HUNT_AI_RECORD", &recorded)],
+    );
+    assert_eq!(fs::read_dir(&recorded).unwrap().count(), 1, "one prompt, one fixture");
+
+    // Only the recorded fixture now: the same prompt replays, anything else fails
+    sandbox.ok(&["keywords", "1", "--model", "mock", "--no-cache"], &[("HUNT_AI_FIXTURES", &recorded)]);
+    let missing = sandbox.hunt(&["analyze", "1", "--model", "mock"], &[("HUNT_AI_FIXTURES", &recorded)]);
+    assert_eq!(missing.status.code(), Some(5), "ai_provider exit code");
+    assert!(String::from_utf8_lossy(&missing.stderr).contains("No fixture"));
+    sandbox.drop_dir();
+}
+
+#[test]
+fn mock_needs_fixtures() {
+    let sandbox = Sandbox::new("unset").with_job();
+    let output = sandbox.hunt(&["keywords", "1", "--model", "mock"], &[]);
+    assert!(!output.status.success());
+    assert!(String::from_utf8_lossy(&output.stderr).contains("HUNT_AI_FIXTURES"));
+    sandbox.drop_dir();
+}